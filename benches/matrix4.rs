@@ -0,0 +1,75 @@
+//! Compares the `Matrix4` products, which go through the SIMD kernels when
+//! the `simd` feature is enabled, with the same products written as scalar
+//! column sums.
+//!
+//! ```text
+//! cargo bench --bench matrix4
+//! cargo bench --bench matrix4 --features simd
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use math_engine::matrix4::Matrix4;
+use math_engine::vector3::Vector3;
+use math_engine::vector4::Vector4;
+
+fn scalar_mul_vec(a: &Matrix4, v: &Vector4) -> Vector4 {
+    a[0] * v.x + a[1] * v.y + a[2] * v.z + a[3] * v.w
+}
+
+fn scalar_mul_cols(a: &Matrix4, b: &Matrix4) -> Matrix4 {
+    Matrix4::new_with_vecs(
+        scalar_mul_vec(a, &b[0]),
+        scalar_mul_vec(a, &b[1]),
+        scalar_mul_vec(a, &b[2]),
+        scalar_mul_vec(a, &b[3]),
+    )
+}
+
+fn matrices() -> (Matrix4, Matrix4) {
+    let a = Matrix4::perspective_rh(1.0, 1.5, 0.1, 100.0) * Matrix4::make_rotation_y(0.3);
+    let b = Matrix4::make_translation(&Vector3::new(1.0, 2.0, 3.0))
+        * Matrix4::make_scale(2.0, 0.5, 1.5);
+    (a, b)
+}
+
+fn mul_cols(c: &mut Criterion) {
+    let (a, b) = matrices();
+    let mut group = c.benchmark_group("mul_cols");
+    group.bench_function("matrix4", |bench| {
+        bench.iter(|| *black_box(&a) * *black_box(&b))
+    });
+    group.bench_function("scalar", |bench| {
+        bench.iter(|| scalar_mul_cols(black_box(&a), black_box(&b)))
+    });
+    group.finish();
+}
+
+fn mul_vec(c: &mut Criterion) {
+    let (a, _) = matrices();
+    let v = Vector4::new(1.0, -2.0, 3.0, 1.0);
+    let mut group = c.benchmark_group("mul_vec");
+    group.bench_function("matrix4", |bench| {
+        bench.iter(|| *black_box(&a) * *black_box(&v))
+    });
+    group.bench_function("scalar", |bench| {
+        bench.iter(|| scalar_mul_vec(black_box(&a), black_box(&v)))
+    });
+    // The batch is transformed over and over, so a rotation keeps it from
+    // growing or shrinking into infinities or subnormals.
+    let r = Matrix4::make_rotation_y(0.3);
+    let mut vs = [v; 1024];
+    group.bench_function("transform_slice", |bench| {
+        bench.iter(|| black_box(&r).transform_slice(black_box(&mut vs)))
+    });
+    group.bench_function("scalar_slice", |bench| {
+        bench.iter(|| {
+            for v in black_box(&mut vs).iter_mut() {
+                *v = scalar_mul_vec(black_box(&r), v);
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, mul_cols, mul_vec);
+criterion_main!(benches);
@@ -0,0 +1,122 @@
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An axis-aligned rectangle described by its minimum and maximum corners.
+#[repr(C)]
+pub struct Aabb2 {
+    /// The corner with the smallest coordinates.
+    pub min: Point2,
+    /// The corner with the largest coordinates.
+    pub max: Point2,
+}
+
+impl Aabb2 {
+    /// Returns a rectangle with the given minimum and maximum corners.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The corner with the smallest coordinates.
+    /// * `max` - The corner with the largest coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb2::Aabb2;
+    /// use math_engine::point2::Point2;
+    /// let r = Aabb2::new(Point2::new(-1.0, -1.0), Point2::new(1.0, 2.0));
+    /// assert!(r.max.y == 2.0);
+    /// ```
+    pub const fn new(min: Point2, max: Point2) -> Aabb2 {
+        Aabb2 { min, max }
+    }
+
+    /// Returns the center of this rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb2::Aabb2;
+    /// use math_engine::point2::Point2;
+    /// let r = Aabb2::new(Point2::new(0.0, 0.0), Point2::new(2.0, 4.0));
+    /// assert!(r.center() == Point2::new(1.0, 2.0));
+    /// ```
+    pub fn center(&self) -> Point2 {
+        self.min.midpoint(&self.max)
+    }
+
+    /// Returns half the size of this rectangle along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb2::Aabb2;
+    /// use math_engine::point2::Point2;
+    /// use math_engine::vector2::Vector2;
+    /// let r = Aabb2::new(Point2::new(0.0, 0.0), Point2::new(2.0, 4.0));
+    /// assert!(r.half_extents() == Vector2::new(1.0, 2.0));
+    /// ```
+    pub fn half_extents(&self) -> Vector2 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Returns true if the point p lies inside or on this rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb2::Aabb2;
+    /// use math_engine::point2::Point2;
+    /// let r = Aabb2::new(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0));
+    /// assert!(r.contains_point(&Point2::new(0.5, 1.0)));
+    /// assert!(!r.contains_point(&Point2::new(0.5, 1.5)));
+    /// ```
+    pub fn contains_point(&self, p: &Point2) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
+    }
+
+    /// Returns true if this rectangle and other overlap or touch.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to an aabb2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb2::Aabb2;
+    /// use math_engine::point2::Point2;
+    /// let a = Aabb2::new(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0));
+    /// let b = Aabb2::new(Point2::new(1.0, 0.5), Point2::new(2.0, 2.0));
+    /// assert!(a.intersects_aabb(&b));
+    /// ```
+    pub fn intersects_aabb(&self, other: &Aabb2) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+    }
+
+    /// Returns the point of this rectangle closest to p, which is p itself
+    /// when it lies inside.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb2::Aabb2;
+    /// use math_engine::point2::Point2;
+    /// let r = Aabb2::new(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0));
+    /// assert!(r.closest_point(&Point2::new(3.0, 0.5)) == Point2::new(1.0, 0.5));
+    /// ```
+    pub fn closest_point(&self, p: &Point2) -> Point2 {
+        p.clamp(&self.min, &self.max)
+    }
+}
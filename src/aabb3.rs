@@ -0,0 +1,201 @@
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An axis-aligned bounding box described by its minimum and maximum corners.
+#[repr(C)]
+pub struct Aabb3 {
+    /// The corner with the smallest coordinates.
+    pub min: Point3,
+    /// The corner with the largest coordinates.
+    pub max: Point3,
+}
+
+impl Aabb3 {
+    /// Returns a box with the given minimum and maximum corners.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The corner with the smallest coordinates.
+    /// * `max` - The corner with the largest coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::point3::Point3;
+    /// let b = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+    /// assert!(b.max.x == 1.0);
+    /// ```
+    pub const fn new(min: Point3, max: Point3) -> Aabb3 {
+        Aabb3 { min, max }
+    }
+
+    /// Returns the center of this box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::point3::Point3;
+    /// let b = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 4.0, 6.0));
+    /// assert!(b.center() == Point3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn center(&self) -> Point3 {
+        Point3::new(
+            (self.min.x + self.max.x) * 0.5,
+            (self.min.y + self.max.y) * 0.5,
+            (self.min.z + self.max.z) * 0.5,
+        )
+    }
+
+    /// Returns half the size of this box along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// let b = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 4.0, 6.0));
+    /// assert!(b.half_extents() == Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn half_extents(&self) -> Vector3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Returns true if the point p lies inside or on this box.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::point3::Point3;
+    /// let b = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    /// assert!(b.contains_point(&Point3::new(0.5, 1.0, 0.0)));
+    /// assert!(!b.contains_point(&Point3::new(0.5, 1.5, 0.0)));
+    /// ```
+    pub fn contains_point(&self, p: &Point3) -> bool {
+        p.x >= self.min.x
+            && p.x <= self.max.x
+            && p.y >= self.min.y
+            && p.y <= self.max.y
+            && p.z >= self.min.z
+            && p.z <= self.max.z
+    }
+
+    /// Returns true if this box and other overlap or touch.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to an aabb3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::point3::Point3;
+    /// let a = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    /// let b = Aabb3::new(Point3::new(1.0, 0.5, 0.5), Point3::new(2.0, 2.0, 2.0));
+    /// assert!(a.intersects_aabb(&b));
+    /// ```
+    pub fn intersects_aabb(&self, other: &Aabb3) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    /// Returns the smallest box containing both this box and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to an aabb3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::point3::Point3;
+    /// let a = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    /// let b = Aabb3::new(Point3::new(2.0, -1.0, 0.0), Point3::new(3.0, 0.0, 1.0));
+    /// let m = a.merge(&b);
+    /// assert!(m.min == Point3::new(0.0, -1.0, 0.0) && m.max == Point3::new(3.0, 1.0, 1.0));
+    /// ```
+    pub fn merge(&self, other: &Aabb3) -> Aabb3 {
+        Aabb3::new(self.min.min(&other.min), self.max.max(&other.max))
+    }
+
+    /// Returns the surface area of this box, the usual cost measure when
+    /// building bounding volume hierarchies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::point3::Point3;
+    /// let b = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 2.0, 3.0));
+    /// assert!(b.surface_area() == 22.0);
+    /// ```
+    pub fn surface_area(&self) -> f32 {
+        let d = self.max - self.min;
+        2.0 * (d.x * d.y + d.y * d.z + d.z * d.x)
+    }
+
+    /// Returns the smallest t >= 0 at which the ray p + t v enters this box,
+    /// or None if it misses. A ray starting inside the box returns 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The origin of the ray.
+    /// * `v` - The direction of the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// let b = Aabb3::new(Point3::new(4.0, -1.0, -1.0), Point3::new(6.0, 1.0, 1.0));
+    /// let t = b.ray_intersect(&Point3::new(0.0, 0.0, 0.0), &Vector3::new(2.0, 0.0, 0.0));
+    /// assert!(t == Some(2.0));
+    /// ```
+    pub fn ray_intersect(&self, p: &Point3, v: &Vector3) -> Option<f32> {
+        let inv = Vector3::new(1.0 / v.x, 1.0 / v.y, 1.0 / v.z);
+        self.ray_intersect_inverse(p, &inv, f32::MAX)
+    }
+
+    // The slab test with the reciprocal of the ray direction precomputed,
+    // giving up past t_max. Infinite reciprocals from zero components make
+    // the slabs of those axes either everything or nothing.
+    pub(crate) fn ray_intersect_inverse(
+        &self,
+        p: &Point3,
+        inv: &Vector3,
+        t_max: f32,
+    ) -> Option<f32> {
+        let (lo, hi, o): ([f32; 3], [f32; 3], [f32; 3]) =
+            (self.min.into(), self.max.into(), (*p).into());
+        let mut t_min = 0.0f32;
+        let mut t_max = t_max;
+        for i in 0..3 {
+            let t1 = (lo[i] - o[i]) * inv[i];
+            let t2 = (hi[i] - o[i]) * inv[i];
+            // NaN from 0 * inf, a ray in the plane of a face, compares false
+            // and leaves the interval alone.
+            t_min = t_min.max(t1.min(t2));
+            t_max = t_max.min(t1.max(t2));
+        }
+        if t_min <= t_max {
+            Some(t_min)
+        } else {
+            None
+        }
+    }
+}
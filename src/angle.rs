@@ -0,0 +1,208 @@
+//! Strongly typed angles. The rotation constructors take `impl Into<Radians>`,
+//! so a plain `f32` is still read as radians while a `Degrees` value is
+//! converted rather than silently misread.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use core::f32::consts::{PI, TAU};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An angle in radians.
+#[repr(transparent)]
+pub struct Radians(pub f32);
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An angle in degrees.
+#[repr(transparent)]
+pub struct Degrees(pub f32);
+
+impl Radians {
+    /// Returns this angle converted to degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::angle::{Degrees, Radians};
+    /// let d = Radians(core::f32::consts::PI).to_degrees();
+    /// assert!((d.0 - 180.0).abs() < 1e-4);
+    /// ```
+    pub fn to_degrees(self) -> Degrees {
+        Degrees(self.0 * (180.0 / PI))
+    }
+
+    /// Returns the equivalent angle in the range (-π, π].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::angle::Radians;
+    /// use core::f32::consts::PI;
+    /// assert!((Radians(1.5 * PI).wrap().0 + 0.5 * PI).abs() < 1e-5);
+    /// assert!((Radians(-PI).wrap().0 - PI).abs() < 1e-5);
+    /// ```
+    pub fn wrap(self) -> Radians {
+        let turns = ((self.0 + PI) / TAU).ceil() - 1.0;
+        Radians(self.0 - TAU * turns)
+    }
+
+    /// Returns the sine of this angle.
+    pub fn sin(self) -> f32 {
+        self.0.sin()
+    }
+
+    /// Returns the cosine of this angle.
+    pub fn cos(self) -> f32 {
+        self.0.cos()
+    }
+
+    /// Returns the tangent of this angle.
+    pub fn tan(self) -> f32 {
+        self.0.tan()
+    }
+}
+
+impl Degrees {
+    /// Returns this angle converted to radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::angle::Degrees;
+    /// let r = Degrees(90.0).to_radians();
+    /// assert!((r.0 - core::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    /// ```
+    pub fn to_radians(self) -> Radians {
+        Radians(self.0 * (PI / 180.0))
+    }
+
+    /// Returns the equivalent angle in the range (-180, 180].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::angle::Degrees;
+    /// assert!(Degrees(270.0).wrap() == Degrees(-90.0));
+    /// assert!(Degrees(-180.0).wrap() == Degrees(180.0));
+    /// ```
+    pub fn wrap(self) -> Degrees {
+        let turns = ((self.0 + 180.0) / 360.0).ceil() - 1.0;
+        Degrees(self.0 - 360.0 * turns)
+    }
+}
+
+impl From<f32> for Radians {
+    fn from(a: f32) -> Self {
+        Radians(a)
+    }
+}
+
+impl From<Degrees> for Radians {
+    fn from(d: Degrees) -> Self {
+        d.to_radians()
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(r: Radians) -> Self {
+        r.to_degrees()
+    }
+}
+
+impl From<Radians> for f32 {
+    fn from(r: Radians) -> Self {
+        r.0
+    }
+}
+
+macro_rules! arithmetic {
+    ($t:ident) => {
+        impl Add for $t {
+            type Output = Self;
+
+            fn add(self, other: $t) -> Self::Output {
+                $t(self.0 + other.0)
+            }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, other: $t) {
+                self.0 += other.0;
+            }
+        }
+
+        impl Sub for $t {
+            type Output = Self;
+
+            fn sub(self, other: $t) -> Self::Output {
+                $t(self.0 - other.0)
+            }
+        }
+
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, other: $t) {
+                self.0 -= other.0;
+            }
+        }
+
+        impl Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                $t(-self.0)
+            }
+        }
+
+        impl Mul<f32> for $t {
+            type Output = Self;
+
+            fn mul(self, other: f32) -> Self::Output {
+                $t(self.0 * other)
+            }
+        }
+
+        impl MulAssign<f32> for $t {
+            fn mul_assign(&mut self, other: f32) {
+                self.0 *= other;
+            }
+        }
+
+        impl Div<f32> for $t {
+            type Output = Self;
+
+            fn div(self, other: f32) -> Self::Output {
+                $t(self.0 / other)
+            }
+        }
+
+        impl DivAssign<f32> for $t {
+            fn div_assign(&mut self, other: f32) {
+                self.0 /= other;
+            }
+        }
+
+        impl Div for $t {
+            type Output = f32;
+
+            fn div(self, other: $t) -> Self::Output {
+                self.0 / other.0
+            }
+        }
+    };
+}
+
+arithmetic!(Radians);
+arithmetic!(Degrees);
+
+#[test]
+fn conversions() {
+    let a = Degrees(45.0) + Degrees(135.0);
+    assert!((Radians::from(a).0 - PI).abs() < 1e-6);
+    assert!((Degrees::from(Radians(PI / 3.0)).0 - 60.0).abs() < 1e-4);
+    assert!(-Radians(1.0) * 2.0 == Radians(-2.0));
+    assert!(Degrees(720.0 + 30.0).wrap() == Degrees(30.0));
+    assert!((Radians(-3.0 * PI - 0.25).wrap().0 - (PI - 0.25)).abs() < 1e-5);
+}
@@ -0,0 +1,126 @@
+//! Implementations of the `approx` crate's `AbsDiffEq`, `RelativeEq` and
+//! `UlpsEq` traits, enabled with the `approx` feature.
+//!
+//! Vectors, points, quaternions and colors compare component by component,
+//! and matrices compare column by column, so a value is approximately equal
+//! to another only if every one of its entries is.
+
+use crate::prelude::*;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+macro_rules! components {
+    ($t:ty, $s:ty, $($c:ident),+) => {
+        impl AbsDiffEq for $t {
+            type Epsilon = $s;
+
+            fn default_epsilon() -> $s {
+                <$s>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: $s) -> bool {
+                $(self.$c.abs_diff_eq(&other.$c, epsilon))&&+
+            }
+        }
+
+        impl RelativeEq for $t {
+            fn default_max_relative() -> $s {
+                <$s>::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: $s, max_relative: $s) -> bool {
+                $(self.$c.relative_eq(&other.$c, epsilon, max_relative))&&+
+            }
+        }
+
+        impl UlpsEq for $t {
+            fn default_max_ulps() -> u32 {
+                <$s>::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: $s, max_ulps: u32) -> bool {
+                $(self.$c.ulps_eq(&other.$c, epsilon, max_ulps))&&+
+            }
+        }
+    };
+}
+
+macro_rules! columns {
+    ($t:ty, $s:ty, $($i:expr),+) => {
+        impl AbsDiffEq for $t {
+            type Epsilon = $s;
+
+            fn default_epsilon() -> $s {
+                <$s>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: $s) -> bool {
+                $(self[$i].abs_diff_eq(&other[$i], epsilon))&&+
+            }
+        }
+
+        impl RelativeEq for $t {
+            fn default_max_relative() -> $s {
+                <$s>::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: $s, max_relative: $s) -> bool {
+                $(self[$i].relative_eq(&other[$i], epsilon, max_relative))&&+
+            }
+        }
+
+        impl UlpsEq for $t {
+            fn default_max_ulps() -> u32 {
+                <$s>::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: $s, max_ulps: u32) -> bool {
+                $(self[$i].ulps_eq(&other[$i], epsilon, max_ulps))&&+
+            }
+        }
+    };
+}
+
+components!(Vector2, f32, x, y);
+components!(Vector3, f32, x, y, z);
+components!(Vector4, f32, x, y, z, w);
+components!(Point2, f32, x, y);
+components!(Point3, f32, x, y, z);
+components!(Quarternion, f32, x, y, z, w);
+components!(Rotor2, f32, re, im);
+components!(Twist, f32, angular, linear);
+components!(RGB, f32, r, g, b);
+components!(RGBA, f32, r, g, b, a);
+columns!(Matrix2, f32, 0, 1);
+columns!(Matrix3, f32, 0, 1, 2);
+columns!(Matrix4, f32, 0, 1, 2, 3);
+columns!(Transform2, f32, 0, 1, 2);
+columns!(Transform4, f32, 0, 1, 2, 3);
+
+components!(DVector2, f64, x, y);
+components!(DVector3, f64, x, y, z);
+components!(DVector4, f64, x, y, z, w);
+components!(DPoint2, f64, x, y);
+components!(DPoint3, f64, x, y, z);
+components!(DQuarternion, f64, x, y, z, w);
+columns!(DMatrix2, f64, 0, 1);
+columns!(DMatrix3, f64, 0, 1, 2);
+columns!(DMatrix4, f64, 0, 1, 2, 3);
+columns!(DTransform4, f64, 0, 1, 2, 3);
+
+#[test]
+fn chained_transforms() {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_ne};
+
+    let r = Matrix3::make_rotation_x(0.1);
+    let mut m = Matrix3::identity();
+    for _ in 0..10 {
+        m *= r;
+    }
+    assert_relative_eq!(m, Matrix3::make_rotation_x(1.0), epsilon = 1.0e-5);
+    assert_abs_diff_eq!(
+        Vector3::new(0.1, 0.2, 0.3) * 3.0,
+        Vector3::new(0.3, 0.6, 0.9),
+        epsilon = 1.0e-6
+    );
+    assert_ulps_ne!(RGB::new(0.0, 0.0, 0.0), RGB::new(0.0, 0.0, 0.1));
+}
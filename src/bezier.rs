@@ -0,0 +1,436 @@
+//! Quadratic and cubic Bezier curves in 2D and 3D, for gameplay paths,
+//! camera rails and vector graphics. A curve of degree n is given by n + 1
+//! control points; it starts at the first, ends at the last and is pulled
+//! toward the ones between. The parameter t runs from 0 at the start to 1
+//! at the end, but not at constant speed, so distances along the curve go
+//! through `arc_length`.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+
+// The nodes and weights of 5 point Gauss-Legendre quadrature on [-1, 1].
+const GAUSS_NODES: [f32; 5] = [0.0, -0.538_469_3, 0.538_469_3, -0.906_179_85, 0.906_179_85];
+const GAUSS_WEIGHTS: [f32; 5] = [
+    0.568_888_9,
+    0.478_628_67,
+    0.478_628_67,
+    0.236_926_88,
+    0.236_926_88,
+];
+// The number of pieces [0, 1] is cut into for the quadrature. Each piece is
+// integrated exactly for polynomial speeds up to degree 9, which the smooth
+// speed of a curve without cusps is close to.
+const ARC_LENGTH_PIECES: usize = 16;
+// The number of samples the nearest point search starts from, and the
+// Newton steps refining the best.
+const PROJECT_SAMPLES: usize = 16;
+const PROJECT_STEPS: usize = 8;
+
+// Returns the integral of speed over [0, t].
+fn integrate(t: f32, speed: impl Fn(f32) -> f32) -> f32 {
+    let h = t / ARC_LENGTH_PIECES as f32;
+    let mut total = 0.0;
+    for i in 0..ARC_LENGTH_PIECES {
+        let mid = (i as f32 + 0.5) * h;
+        for (x, w) in GAUSS_NODES.iter().zip(GAUSS_WEIGHTS.iter()) {
+            total += w * speed(mid + 0.5 * h * x);
+        }
+    }
+    total * 0.5 * h
+}
+
+// Returns the roots in (0, 1) of a t² + b t + c.
+fn unit_roots(a: f32, b: f32, c: f32) -> [Option<f32>; 2] {
+    let inside = |t: f32| if t > 0.0 && t < 1.0 { Some(t) } else { None };
+    if a.abs() < 1e-12 {
+        if b.abs() < 1e-12 {
+            return [None, None];
+        }
+        return [inside(-c / b), None];
+    }
+    let d = b * b - 4.0 * a * c;
+    if d < 0.0 {
+        return [None, None];
+    }
+    let s = d.sqrt();
+    [inside((-b + s) / (2.0 * a)), inside((-b - s) / (2.0 * a))]
+}
+
+macro_rules! bezier {
+    ($quad:ident, $cubic:ident, $p:ident, $v:ident, $n:expr) => {
+        #[derive(Default, Copy, Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        /// A quadratic Bezier curve, given by its two end points and one
+        /// control point.
+        pub struct $quad {
+            /// The start of the curve.
+            pub p0: $p,
+            /// The control point.
+            pub p1: $p,
+            /// The end of the curve.
+            pub p2: $p,
+        }
+
+        impl $quad {
+            /// Returns the curve with the given control points.
+            pub const fn new(p0: $p, p1: $p, p2: $p) -> $quad {
+                $quad { p0, p1, p2 }
+            }
+
+            /// Returns the point on the curve at t.
+            ///
+            /// # Arguments
+            ///
+            /// * `t` - The curve parameter in [0, 1].
+            pub fn eval(&self, t: f32) -> $p {
+                let s = 1.0 - t;
+                self.p0 + (self.p1 - self.p0) * (2.0 * s * t) + (self.p2 - self.p0) * (t * t)
+            }
+
+            /// Returns the derivative of the curve at t, the tangent whose
+            /// length is the speed of the point moving along the curve.
+            ///
+            /// # Arguments
+            ///
+            /// * `t` - The curve parameter in [0, 1].
+            pub fn derivative(&self, t: f32) -> $v {
+                ((self.p1 - self.p0) * (1.0 - t) + (self.p2 - self.p1) * t) * 2.0
+            }
+
+            fn second_derivative(&self, _t: f32) -> $v {
+                ((self.p2 - self.p1) - (self.p1 - self.p0)) * 2.0
+            }
+
+            /// Returns the two halves of the curve before and after t, each
+            /// a quadratic curve of its own.
+            ///
+            /// # Arguments
+            ///
+            /// * `t` - The curve parameter in [0, 1] to split at.
+            pub fn split(&self, t: f32) -> ($quad, $quad) {
+                let a = self.p0.lerp(&self.p1, t);
+                let b = self.p1.lerp(&self.p2, t);
+                let m = a.lerp(&b, t);
+                ($quad::new(self.p0, a, m), $quad::new(m, b, self.p2))
+            }
+
+            /// Returns the cubic curve tracing the same path.
+            pub fn to_cubic(&self) -> $cubic {
+                $cubic::new(
+                    self.p0,
+                    self.p0.lerp(&self.p1, 2.0 / 3.0),
+                    self.p2.lerp(&self.p1, 2.0 / 3.0),
+                    self.p2,
+                )
+            }
+
+            /// Returns the minimum and maximum corners of the tightest
+            /// axis-aligned box around the curve, which can be much smaller
+            /// than the box around the control points.
+            pub fn bounds(&self) -> ($p, $p) {
+                let (p0, p1, p2): ([f32; $n], [f32; $n], [f32; $n]) =
+                    (self.p0.into(), self.p1.into(), self.p2.into());
+                let (mut lo, mut hi) = (self.p0.min(&self.p2), self.p0.max(&self.p2));
+                for i in 0..$n {
+                    // The derivative is 2 ((p1 - p0) + (p0 - 2 p1 + p2) t).
+                    let a = p0[i] - 2.0 * p1[i] + p2[i];
+                    let t = unit_roots(0.0, a, p1[i] - p0[i])[0];
+                    if let Some(t) = t {
+                        let e = self.eval(t);
+                        lo = lo.min(&e);
+                        hi = hi.max(&e);
+                    }
+                }
+                (lo, hi)
+            }
+
+            /// Returns the length of the curve.
+            pub fn arc_length(&self) -> f32 {
+                self.arc_length_to(1.0)
+            }
+
+            /// Returns the length of the curve from its start to t.
+            ///
+            /// # Arguments
+            ///
+            /// * `t` - The curve parameter in [0, 1].
+            pub fn arc_length_to(&self, t: f32) -> f32 {
+                integrate(t, |t| self.derivative(t).magnitude())
+            }
+
+            /// Returns the parameter and position of the point on the curve
+            /// nearest to p.
+            ///
+            /// # Arguments
+            ///
+            /// * `p` - A reference to the point to project.
+            pub fn project(&self, p: &$p) -> (f32, $p) {
+                project(
+                    p,
+                    |t| self.eval(t),
+                    |t| self.derivative(t),
+                    |t| self.second_derivative(t),
+                )
+            }
+        }
+
+        #[derive(Default, Copy, Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        /// A cubic Bezier curve, given by its two end points and two
+        /// control points.
+        pub struct $cubic {
+            /// The start of the curve.
+            pub p0: $p,
+            /// The control point the curve leaves the start toward.
+            pub p1: $p,
+            /// The control point the curve arrives at the end from.
+            pub p2: $p,
+            /// The end of the curve.
+            pub p3: $p,
+        }
+
+        impl $cubic {
+            /// Returns the curve with the given control points.
+            pub const fn new(p0: $p, p1: $p, p2: $p, p3: $p) -> $cubic {
+                $cubic { p0, p1, p2, p3 }
+            }
+
+            /// Returns the point on the curve at t.
+            ///
+            /// # Arguments
+            ///
+            /// * `t` - The curve parameter in [0, 1].
+            pub fn eval(&self, t: f32) -> $p {
+                let s = 1.0 - t;
+                self.p0
+                    + (self.p1 - self.p0) * (3.0 * s * s * t)
+                    + (self.p2 - self.p0) * (3.0 * s * t * t)
+                    + (self.p3 - self.p0) * (t * t * t)
+            }
+
+            /// Returns the derivative of the curve at t, the tangent whose
+            /// length is the speed of the point moving along the curve.
+            ///
+            /// # Arguments
+            ///
+            /// * `t` - The curve parameter in [0, 1].
+            pub fn derivative(&self, t: f32) -> $v {
+                let s = 1.0 - t;
+                ((self.p1 - self.p0) * (s * s)
+                    + (self.p2 - self.p1) * (2.0 * s * t)
+                    + (self.p3 - self.p2) * (t * t))
+                    * 3.0
+            }
+
+            fn second_derivative(&self, t: f32) -> $v {
+                let a = (self.p2 - self.p1) - (self.p1 - self.p0);
+                let b = (self.p3 - self.p2) - (self.p2 - self.p1);
+                (a * (1.0 - t) + b * t) * 6.0
+            }
+
+            /// Returns the two halves of the curve before and after t, each
+            /// a cubic curve of its own.
+            ///
+            /// # Arguments
+            ///
+            /// * `t` - The curve parameter in [0, 1] to split at.
+            pub fn split(&self, t: f32) -> ($cubic, $cubic) {
+                let a = self.p0.lerp(&self.p1, t);
+                let b = self.p1.lerp(&self.p2, t);
+                let c = self.p2.lerp(&self.p3, t);
+                let ab = a.lerp(&b, t);
+                let bc = b.lerp(&c, t);
+                let m = ab.lerp(&bc, t);
+                (
+                    $cubic::new(self.p0, a, ab, m),
+                    $cubic::new(m, bc, c, self.p3),
+                )
+            }
+
+            /// Returns the minimum and maximum corners of the tightest
+            /// axis-aligned box around the curve, which can be much smaller
+            /// than the box around the control points.
+            pub fn bounds(&self) -> ($p, $p) {
+                let (p0, p1, p2, p3): ([f32; $n], [f32; $n], [f32; $n], [f32; $n]) = (
+                    self.p0.into(),
+                    self.p1.into(),
+                    self.p2.into(),
+                    self.p3.into(),
+                );
+                let (mut lo, mut hi) = (self.p0.min(&self.p3), self.p0.max(&self.p3));
+                for i in 0..$n {
+                    // The derivative over 3 is a t² + b t + c.
+                    let a = -p0[i] + 3.0 * p1[i] - 3.0 * p2[i] + p3[i];
+                    let b = 2.0 * (p0[i] - 2.0 * p1[i] + p2[i]);
+                    let c = p1[i] - p0[i];
+                    for t in unit_roots(a, b, c).into_iter().flatten() {
+                        let e = self.eval(t);
+                        lo = lo.min(&e);
+                        hi = hi.max(&e);
+                    }
+                }
+                (lo, hi)
+            }
+
+            /// Returns the length of the curve.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use math_engine::bezier::CubicBezier2;
+            /// use math_engine::point2::Point2;
+            /// // Control points a third of the way along trace a straight line.
+            /// let line = CubicBezier2::new(
+            ///     Point2::new(0.0, 0.0),
+            ///     Point2::new(1.0, 1.0),
+            ///     Point2::new(2.0, 2.0),
+            ///     Point2::new(3.0, 3.0),
+            /// );
+            /// assert!((line.arc_length() - 18.0f32.sqrt()).abs() < 1e-5);
+            /// ```
+            pub fn arc_length(&self) -> f32 {
+                self.arc_length_to(1.0)
+            }
+
+            /// Returns the length of the curve from its start to t.
+            ///
+            /// # Arguments
+            ///
+            /// * `t` - The curve parameter in [0, 1].
+            pub fn arc_length_to(&self, t: f32) -> f32 {
+                integrate(t, |t| self.derivative(t).magnitude())
+            }
+
+            /// Returns the parameter and position of the point on the curve
+            /// nearest to p.
+            ///
+            /// # Arguments
+            ///
+            /// * `p` - A reference to the point to project.
+            pub fn project(&self, p: &$p) -> (f32, $p) {
+                project(
+                    p,
+                    |t| self.eval(t),
+                    |t| self.derivative(t),
+                    |t| self.second_derivative(t),
+                )
+            }
+        }
+    };
+}
+
+// Project is shared by the curve types through a trait over their point
+// and vector types.
+trait Space: Copy + core::ops::Sub<Output = Self::Vector> {
+    type Vector: Copy;
+    fn dot(a: &Self::Vector, b: &Self::Vector) -> f32;
+}
+
+impl Space for Point2 {
+    type Vector = Vector2;
+    fn dot(a: &Vector2, b: &Vector2) -> f32 {
+        a.dot(b)
+    }
+}
+
+impl Space for Point3 {
+    type Vector = Vector3;
+    fn dot(a: &Vector3, b: &Vector3) -> f32 {
+        a.dot(b)
+    }
+}
+
+// Returns the parameter and point of a curve nearest to p: the best of
+// evenly spaced samples, refined by Newton's method on the derivative of
+// the squared distance and kept within [0, 1].
+fn project<P: Space>(
+    p: &P,
+    eval: impl Fn(f32) -> P,
+    d1: impl Fn(f32) -> P::Vector,
+    d2: impl Fn(f32) -> P::Vector,
+) -> (f32, P) {
+    let dist = |t: f32| {
+        let d = eval(t) - *p;
+        P::dot(&d, &d)
+    };
+    let mut best = (0.0, dist(0.0));
+    for i in 1..=PROJECT_SAMPLES {
+        let t = i as f32 / PROJECT_SAMPLES as f32;
+        let d = dist(t);
+        if d < best.1 {
+            best = (t, d);
+        }
+    }
+    let mut t: f32 = best.0;
+    for _ in 0..PROJECT_STEPS {
+        let r = eval(t) - *p;
+        let (v, a) = (d1(t), d2(t));
+        let f = P::dot(&r, &v);
+        let df = P::dot(&v, &v) + P::dot(&r, &a);
+        if df <= 0.0 {
+            break;
+        }
+        let next = (t - f / df).clamp(0.0, 1.0);
+        if dist(next) > dist(t) {
+            break;
+        }
+        t = next;
+    }
+    (t, eval(t))
+}
+
+bezier!(QuadraticBezier2, CubicBezier2, Point2, Vector2, 2);
+bezier!(QuadraticBezier3, CubicBezier3, Point3, Vector3, 3);
+
+#[test]
+fn curves() {
+    let c = CubicBezier2::new(
+        Point2::new(0.0, 0.0),
+        Point2::new(0.0, 2.0),
+        Point2::new(3.0, 2.0),
+        Point2::new(3.0, 0.0),
+    );
+    assert!(c.eval(0.0) == c.p0 && c.eval(1.0) == c.p3);
+    // The curve peaks at 3/4 of the control points' height, midway.
+    let (lo, hi) = c.bounds();
+    assert!(lo == Point2::new(0.0, 0.0) && (hi.y - 1.5).abs() < 1e-6 && hi.x == 3.0);
+    // The halves of a split meet and keep the shape.
+    let (a, b) = c.split(0.3);
+    assert!(a.p3 == b.p0 && (a.eval(0.5) - c.eval(0.15)).magnitude() < 1e-6);
+    assert!((b.eval(0.5) - c.eval(0.65)).magnitude() < 1e-6);
+    assert!((a.arc_length() + b.arc_length() - c.arc_length()).abs() < 1e-4);
+    // The derivative matches a finite difference.
+    let h = 1e-3;
+    let fd = (c.eval(0.4 + h) - c.eval(0.4 - h)) / (2.0 * h);
+    assert!((fd - c.derivative(0.4)).magnitude() < 1e-2);
+    // Projecting a point on the curve gives it back.
+    let (t, p) = c.project(&c.eval(0.7));
+    assert!((t - 0.7).abs() < 1e-3 && (p - c.eval(0.7)).magnitude() < 1e-4);
+    // Points off the curve project along its normal.
+    let (t, p) = c.project(&Point2::new(1.5, 3.0));
+    assert!((t - 0.5).abs() < 1e-4 && (p.y - 1.5).abs() < 1e-4);
+
+    let q = QuadraticBezier3::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Point3::new(2.0, 0.0, 1.0),
+    );
+    let cubic = q.to_cubic();
+    for t in [0.0, 0.25, 0.6, 1.0] {
+        assert!((q.eval(t) - cubic.eval(t)).magnitude() < 1e-6);
+    }
+    assert!((q.arc_length() - cubic.arc_length()).abs() < 1e-4);
+    let (lo, hi) = q.bounds();
+    assert!(lo == Point3::new(0.0, 0.0, 0.0) && (hi.y - 1.0).abs() < 1e-6);
+    // A parabola's arc length has a closed form: for y = x² over [0, 1],
+    // (2√5 + asinh 2) / 4.
+    let parabola = QuadraticBezier2::new(
+        Point2::new(0.0, 0.0),
+        Point2::new(0.5, 0.0),
+        Point2::new(1.0, 1.0),
+    );
+    let exact = (2.0 * 5.0f32.sqrt() + (2.0f32 + 5.0f32.sqrt()).ln()) / 4.0;
+    assert!((parabola.arc_length() - exact).abs() < 1e-5);
+}
@@ -0,0 +1,160 @@
+//! Separable blend modes, the layer modes of image editors, for software
+//! compositing and tinting. Each mode combines a base color with a layer
+//! color channel by channel, following the formulas of the W3C Compositing
+//! and Blending specification. Blending is meant for linear colors.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+
+/// How a layer color is combined with the base color beneath it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlendMode {
+    /// The layer replaces the base.
+    #[default]
+    Normal,
+    /// The product of the colors, which only darkens.
+    Multiply,
+    /// The inverse of the product of the inverses, which only lightens.
+    Screen,
+    /// Multiply or screen depending on the base, raising its contrast.
+    Overlay,
+    /// A gentler hard light, like shining a diffuse light on the base.
+    SoftLight,
+    /// Multiply or screen depending on the layer, like a harsh spotlight.
+    HardLight,
+    /// The sum of the colors, unclamped so that HDR particles and glows can
+    /// accumulate.
+    Additive,
+    /// The base minus the layer, clamped at zero.
+    Subtractive,
+}
+
+impl BlendMode {
+    /// Returns one channel of the blend of a layer channel over a base
+    /// channel, both in [0, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The channel of the color beneath.
+    /// * `layer` - The channel of the color on top.
+    pub fn channel(&self, base: f32, layer: f32) -> f32 {
+        let multiply = |b: f32, s: f32| b * s;
+        let screen = |b: f32, s: f32| b + s - b * s;
+        let hard_light = |b: f32, s: f32| {
+            if s <= 0.5 {
+                multiply(b, 2.0 * s)
+            } else {
+                screen(b, 2.0 * s - 1.0)
+            }
+        };
+        match self {
+            BlendMode::Normal => layer,
+            BlendMode::Multiply => multiply(base, layer),
+            BlendMode::Screen => screen(base, layer),
+            BlendMode::Overlay => hard_light(layer, base),
+            BlendMode::SoftLight => {
+                if layer <= 0.5 {
+                    base - (1.0 - 2.0 * layer) * base * (1.0 - base)
+                } else {
+                    let d = if base <= 0.25 {
+                        ((16.0 * base - 12.0) * base + 4.0) * base
+                    } else {
+                        base.sqrt()
+                    };
+                    base + (2.0 * layer - 1.0) * (d - base)
+                }
+            }
+            BlendMode::HardLight => hard_light(base, layer),
+            BlendMode::Additive => base + layer,
+            BlendMode::Subtractive => (base - layer).max(0.0),
+        }
+    }
+
+    /// Returns the blend of an opaque layer color over a base color.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - A reference to the color beneath.
+    /// * `layer` - A reference to the color on top.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::blend::BlendMode;
+    /// use math_engine::rgb::RGB;
+    /// let base = RGB::new(0.5, 0.25, 1.0);
+    /// let tint = RGB::new(1.0, 0.5, 0.5);
+    /// assert!(BlendMode::Multiply.rgb(&base, &tint) == RGB::new(0.5, 0.125, 0.5));
+    /// assert!(BlendMode::Screen.rgb(&base, &tint) == RGB::new(1.0, 0.625, 1.0));
+    /// ```
+    pub fn rgb(&self, base: &RGB, layer: &RGB) -> RGB {
+        RGB::new(
+            self.channel(base.r, layer.r),
+            self.channel(base.g, layer.g),
+            self.channel(base.b, layer.b),
+        )
+    }
+
+    /// Returns the blend of a translucent layer color over a base color.
+    /// The blended color is mixed into the base by the layer's alpha, and
+    /// the alphas combine as in normal "over" compositing.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - A reference to the color beneath.
+    /// * `layer` - A reference to the color on top.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::blend::BlendMode;
+    /// use math_engine::rgba::RGBA;
+    /// let base = RGBA::new(0.25, 0.25, 0.25, 1.0);
+    /// let glow = RGBA::new(0.5, 0.25, 0.0, 0.5);
+    /// assert!(BlendMode::Additive.rgba(&base, &glow) == RGBA::new(0.5, 0.375, 0.25, 1.0));
+    /// ```
+    pub fn rgba(&self, base: &RGBA, layer: &RGBA) -> RGBA {
+        let mix = |b: f32, s: f32| b + (self.channel(b, s) - b) * layer.a;
+        RGBA {
+            r: mix(base.r, layer.r),
+            g: mix(base.g, layer.g),
+            b: mix(base.b, layer.b),
+            a: layer.a + base.a * (1.0 - layer.a),
+        }
+    }
+}
+
+#[test]
+fn modes() {
+    let modes = [
+        BlendMode::Normal,
+        BlendMode::Multiply,
+        BlendMode::Screen,
+        BlendMode::Overlay,
+        BlendMode::SoftLight,
+        BlendMode::HardLight,
+    ];
+    // Black and white layers behave as the formulas' fixed points, and every
+    // mode but normal leaves the base alone under a neutral layer.
+    for b in [0.0, 0.1, 0.3, 0.5, 0.8, 1.0] {
+        assert!(BlendMode::Multiply.channel(b, 1.0) == b);
+        assert!(BlendMode::Screen.channel(b, 0.0) == b);
+        assert!(BlendMode::SoftLight.channel(b, 0.5) == b);
+        assert!(BlendMode::HardLight.channel(b, 0.5) == b);
+        assert!(BlendMode::Overlay.channel(b, 0.5) == BlendMode::HardLight.channel(0.5, b));
+        for mode in modes {
+            let c = mode.channel(b, 0.7);
+            assert!((0.0..=1.0).contains(&c));
+        }
+    }
+    assert!(BlendMode::Subtractive.channel(0.25, 0.5) == 0.0);
+    assert!(BlendMode::Additive.channel(0.75, 0.5) == 1.25);
+    // A transparent layer changes nothing.
+    let base = RGBA::new(0.3, 0.6, 0.9, 0.5);
+    for mode in modes {
+        assert!(mode.rgba(&base, &RGBA::new(1.0, 0.0, 0.5, 0.0)) == base);
+    }
+}
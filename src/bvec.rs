@@ -0,0 +1,187 @@
+//! Boolean vectors, the masks that component-wise comparisons of the float
+//! vectors produce. A mask selects components with `select`, so per-component
+//! conditions can be written without branching, the way shader code does.
+
+use crate::prelude::*;
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+macro_rules! bvec {
+    ($(#[$doc:meta])* $b:ident, $n:expr, $($c:ident: $i:expr),+) => {
+        $(#[$doc])*
+        #[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[repr(C)]
+        pub struct $b {
+            $(
+                #[doc = concat!("The ", stringify!($c), " component.")]
+                pub $c: bool,
+            )+
+        }
+
+        impl $b {
+            /// Returns a mask with the given components.
+            pub const fn new($($c: bool),+) -> $b {
+                $b { $($c),+ }
+            }
+
+            /// Returns a mask with every component set to b.
+            pub const fn splat(b: bool) -> $b {
+                $b { $($c: b),+ }
+            }
+
+            /// Returns true if any component is set.
+            pub fn any(&self) -> bool {
+                false $(|| self.$c)+
+            }
+
+            /// Returns true if every component is set.
+            pub fn all(&self) -> bool {
+                true $(&& self.$c)+
+            }
+
+            /// Returns the components packed into the low bits of an
+            /// integer, x in bit 0, y in bit 1 and so on.
+            pub fn bitmask(&self) -> u32 {
+                0 $(| (self.$c as u32) << $i)+
+            }
+        }
+
+        impl Not for $b {
+            type Output = Self;
+
+            fn not(self) -> Self::Output {
+                $b { $($c: !self.$c),+ }
+            }
+        }
+
+        impl BitAnd for $b {
+            type Output = Self;
+
+            fn bitand(self, rhs: $b) -> Self::Output {
+                $b { $($c: self.$c & rhs.$c),+ }
+            }
+        }
+
+        impl BitOr for $b {
+            type Output = Self;
+
+            fn bitor(self, rhs: $b) -> Self::Output {
+                $b { $($c: self.$c | rhs.$c),+ }
+            }
+        }
+
+        impl BitXor for $b {
+            type Output = Self;
+
+            fn bitxor(self, rhs: $b) -> Self::Output {
+                $b { $($c: self.$c ^ rhs.$c),+ }
+            }
+        }
+
+        impl From<[bool; $n]> for $b {
+            fn from(a: [bool; $n]) -> Self {
+                $b { $($c: a[$i]),+ }
+            }
+        }
+
+        impl From<$b> for [bool; $n] {
+            fn from(b: $b) -> Self {
+                [$(b.$c),+]
+            }
+        }
+    };
+}
+
+macro_rules! compare {
+    ($t:ident, $b:ident, $($c:ident),+) => {
+        impl $t {
+            /// Returns the mask of the components of this vector that are
+            /// less than those of other.
+            pub fn cmplt(&self, other: &$t) -> $b {
+                $b { $($c: self.$c < other.$c),+ }
+            }
+
+            /// Returns the mask of the components of this vector that are
+            /// less than or equal to those of other.
+            pub fn cmple(&self, other: &$t) -> $b {
+                $b { $($c: self.$c <= other.$c),+ }
+            }
+
+            /// Returns the mask of the components of this vector that are
+            /// greater than those of other.
+            pub fn cmpgt(&self, other: &$t) -> $b {
+                $b { $($c: self.$c > other.$c),+ }
+            }
+
+            /// Returns the mask of the components of this vector that are
+            /// greater than or equal to those of other.
+            pub fn cmpge(&self, other: &$t) -> $b {
+                $b { $($c: self.$c >= other.$c),+ }
+            }
+
+            /// Returns the mask of the components of this vector that equal
+            /// those of other. NaN components are never equal.
+            pub fn cmpeq(&self, other: &$t) -> $b {
+                $b { $($c: self.$c == other.$c),+ }
+            }
+
+            /// Returns the mask of the components of this vector that differ
+            /// from those of other. NaN components always differ.
+            pub fn cmpne(&self, other: &$t) -> $b {
+                $b { $($c: self.$c != other.$c),+ }
+            }
+
+            /// Returns the vector whose components come from if_true where
+            /// the mask is set and from if_false where it is not.
+            ///
+            /// # Arguments
+            ///
+            /// * `mask` - The components to take from if_true.
+            /// * `if_true` - The vector for set components.
+            /// * `if_false` - The vector for clear components.
+            pub fn select(mask: $b, if_true: &$t, if_false: &$t) -> $t {
+                $t {
+                    $($c: if mask.$c { if_true.$c } else { if_false.$c }),+
+                }
+            }
+        }
+    };
+}
+
+bvec!(
+    /// A two dimensional boolean vector, the mask of a `Vector2` comparison.
+    BVec2, 2, x: 0, y: 1
+);
+bvec!(
+    /// A three dimensional boolean vector, the mask of a `Vector3`
+    /// comparison.
+    BVec3, 3, x: 0, y: 1, z: 2
+);
+bvec!(
+    /// A four dimensional boolean vector, the mask of a `Vector4` comparison.
+    BVec4, 4, x: 0, y: 1, z: 2, w: 3
+);
+
+compare!(Vector2, BVec2, x, y);
+compare!(Vector3, BVec3, x, y, z);
+compare!(Vector4, BVec4, x, y, z, w);
+compare!(DVector2, BVec2, x, y);
+compare!(DVector3, BVec3, x, y, z);
+compare!(DVector4, BVec4, x, y, z, w);
+
+#[test]
+fn masks() {
+    let a = Vector3::new(1.0, 5.0, -2.0);
+    let b = Vector3::new(3.0, 5.0, -6.0);
+    assert!(a.cmplt(&b) == BVec3::new(true, false, false));
+    assert!(a.cmple(&b) == BVec3::new(true, true, false));
+    assert!(a.cmpeq(&b) == !a.cmpne(&b));
+    assert!((a.cmplt(&b) | a.cmpgt(&b)) == a.cmpne(&b));
+    assert!(a.cmpge(&b).bitmask() == 0b110);
+    assert!(a.cmpgt(&b).any() && !a.cmpgt(&b).all());
+    assert!(Vector3::select(a.cmplt(&b), &a, &b) == a.min(&b));
+
+    let nan = Vector2::new(f32::NAN, 0.0);
+    assert!(nan.cmpeq(&nan) == BVec2::new(false, true));
+    assert!(BVec4::splat(true).all() && !BVec4::default().any());
+}
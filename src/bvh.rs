@@ -0,0 +1,416 @@
+//! A bounding volume hierarchy over `Aabb3` bounds, for picking, culling and
+//! broad-phase collision. The hierarchy is built once over a slice of items
+//! and refers to them by their index in it. Queries hand candidate indices
+//! to a callback, which does the exact test against the item itself.
+//!
+//! When items move without changing much, `refit` updates the boxes in place
+//! instead of rebuilding. The tree keeps its shape, so queries stay correct
+//! but slow down as the items drift from where they were when it was built.
+
+use crate::prelude::*;
+use alloc::vec::Vec;
+
+// The most items a leaf holds before it is split.
+const MAX_LEAF: usize = 4;
+// The number of buckets the surface area heuristic sorts centroids into.
+const SAH_BINS: usize = 12;
+
+/// How `Bvh::build` chooses where to split a node.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BvhSplit {
+    /// Split at the median centroid along the longest axis, fast to build
+    /// and always balanced.
+    Median,
+    /// Split where the surface area heuristic estimates queries are
+    /// cheapest, slower to build but faster to query.
+    #[default]
+    Sah,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Node {
+    bounds: Aabb3,
+    // For a leaf, the first of its count entries in the item order. For an
+    // internal node, with count 0, the index of its left child; the right
+    // child follows it.
+    first: usize,
+    count: usize,
+}
+
+/// A bounding volume hierarchy over the bounds of a slice of items.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::aabb3::Aabb3;
+/// use math_engine::bvh::{Bvh, BvhSplit};
+/// use math_engine::point3::Point3;
+/// use math_engine::sphere::Sphere;
+/// use math_engine::vector3::Vector3;
+/// let spheres: Vec<Sphere> = (0..100)
+///     .map(|i| Sphere::new(Point3::new(i as f32 * 3.0, 0.0, 0.0), 1.0))
+///     .collect();
+/// let bounds = |s: &Sphere| {
+///     let r = Vector3::new(s.radius, s.radius, s.radius);
+///     Aabb3::new(s.center - r, s.center + r)
+/// };
+/// let bvh = Bvh::build(&spheres, BvhSplit::Sah, bounds);
+/// let (p, v) = (Point3::new(100.5, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+/// let hit = bvh.ray_cast(&p, &v, |i| spheres[i].ray_intersect(&p, &v));
+/// assert!(hit == Some((34, 0.5)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Bvh {
+    nodes: Vec<Node>,
+    // The item indices, ordered so that each leaf's items are contiguous.
+    items: Vec<usize>,
+    // The bounds of each entry of items.
+    boxes: Vec<Aabb3>,
+}
+
+impl Bvh {
+    /// Returns the hierarchy over items, whose bounds are given by the
+    /// function bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The items to build over, referred to by index.
+    /// * `split` - How to choose where to split nodes.
+    /// * `bounds` - The function returning the bounds of an item.
+    pub fn build<T>(items: &[T], split: BvhSplit, bounds: impl Fn(&T) -> Aabb3) -> Bvh {
+        let boxes: Vec<Aabb3> = items.iter().map(&bounds).collect();
+        let centroids: Vec<Point3> = boxes.iter().map(|b| b.center()).collect();
+        let mut bvh = Bvh {
+            nodes: Vec::with_capacity(2 * items.len()),
+            items: (0..items.len()).collect(),
+            boxes: Vec::new(),
+        };
+        if !items.is_empty() {
+            bvh.nodes.push(Node {
+                bounds: boxes[0],
+                first: 0,
+                count: items.len(),
+            });
+            bvh.subdivide(0, &boxes, &centroids, split);
+        }
+        bvh.boxes = bvh.items.iter().map(|&j| boxes[j]).collect();
+        bvh
+    }
+
+    // Sets the bounds of the leaf node i and splits it if it holds too many
+    // items, recursing into the halves.
+    fn subdivide(&mut self, i: usize, boxes: &[Aabb3], centroids: &[Point3], split: BvhSplit) {
+        let Node { first, count, .. } = self.nodes[i];
+        let range = first..first + count;
+        let items = &mut self.items[range.clone()];
+        self.nodes[i].bounds = items
+            .iter()
+            .fold(boxes[items[0]], |b, &j| b.merge(&boxes[j]));
+        if count <= MAX_LEAF {
+            return;
+        }
+        let (lo, hi) = items.iter().fold(
+            (centroids[items[0]], centroids[items[0]]),
+            |(lo, hi), &j| (lo.min(&centroids[j]), hi.max(&centroids[j])),
+        );
+        let extent: [f32; 3] = (hi - lo).into();
+        let axis = if extent[0] >= extent[1] && extent[0] >= extent[2] {
+            0
+        } else if extent[1] >= extent[2] {
+            1
+        } else {
+            2
+        };
+        let key = |j: usize| -> f32 { <[f32; 3]>::from(centroids[j])[axis] };
+        let mid = match split {
+            BvhSplit::Sah => sah_split(items, boxes, axis, lo, extent[axis], &key),
+            BvhSplit::Median => None,
+        }
+        .unwrap_or_else(|| {
+            items.select_nth_unstable_by(count / 2, |&a, &b| key(a).total_cmp(&key(b)));
+            count / 2
+        });
+        let left = self.nodes.len();
+        self.nodes[i].first = left;
+        self.nodes[i].count = 0;
+        for (first, count) in [(first, mid), (first + mid, count - mid)] {
+            self.nodes.push(Node {
+                bounds: Aabb3::default(),
+                first,
+                count,
+            });
+        }
+        self.subdivide(left, boxes, centroids, split);
+        self.subdivide(left + 1, boxes, centroids, split);
+    }
+
+    /// Returns the bounds of all the items, or None if there are none.
+    pub fn bounds(&self) -> Option<Aabb3> {
+        self.nodes.first().map(|n| n.bounds)
+    }
+
+    /// Updates the boxes of the hierarchy after its items have moved,
+    /// keeping its structure. The items must be the ones it was built over,
+    /// in the same order.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The items the hierarchy was built over.
+    /// * `bounds` - The function returning the bounds of an item.
+    pub fn refit<T>(&mut self, items: &[T], bounds: impl Fn(&T) -> Aabb3) {
+        // Children always come after their parent, so going backwards
+        // updates both children before the node above them.
+        for (b, &j) in self.boxes.iter_mut().zip(&self.items) {
+            *b = bounds(&items[j]);
+        }
+        for i in (0..self.nodes.len()).rev() {
+            let Node { first, count, .. } = self.nodes[i];
+            self.nodes[i].bounds = if count > 0 {
+                let leaf = &self.boxes[first..first + count];
+                leaf.iter().fold(leaf[0], |b, c| b.merge(c))
+            } else {
+                self.nodes[first]
+                    .bounds
+                    .merge(&self.nodes[first + 1].bounds)
+            };
+        }
+    }
+
+    /// Returns the index of the nearest item the ray p + t v hits, and the t
+    /// at which it hits, or None if it hits none. The function hit does the
+    /// exact test for an item whose box the ray reaches, returning the t of
+    /// the hit if there is one. Items whose boxes start beyond the nearest
+    /// hit so far are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The origin of the ray.
+    /// * `v` - The direction of the ray.
+    /// * `hit` - The function testing the ray against the item of an index.
+    pub fn ray_cast(
+        &self,
+        p: &Point3,
+        v: &Vector3,
+        mut hit: impl FnMut(usize) -> Option<f32>,
+    ) -> Option<(usize, f32)> {
+        let inv = Vector3::new(1.0 / v.x, 1.0 / v.y, 1.0 / v.z);
+        let mut best: Option<(usize, f32)> = None;
+        // Nodes to visit with the t at which the ray enters them.
+        let mut stack = Vec::new();
+        if let Some(root) = self.nodes.first() {
+            if let Some(t) = root.bounds.ray_intersect_inverse(p, &inv, f32::MAX) {
+                stack.push((0, t));
+            }
+        }
+        while let Some((i, t)) = stack.pop() {
+            let limit = best.map_or(f32::MAX, |b| b.1);
+            if t > limit {
+                continue;
+            }
+            let node = &self.nodes[i];
+            if node.count > 0 {
+                for k in node.first..node.first + node.count {
+                    let limit = best.map_or(f32::MAX, |b| b.1);
+                    if self.boxes[k]
+                        .ray_intersect_inverse(p, &inv, limit)
+                        .is_none()
+                    {
+                        continue;
+                    }
+                    let j = self.items[k];
+                    if let Some(t) = hit(j) {
+                        if t < limit {
+                            best = Some((j, t));
+                        }
+                    }
+                }
+                continue;
+            }
+            let (a, b) = (node.first, node.first + 1);
+            let ta = self.nodes[a].bounds.ray_intersect_inverse(p, &inv, limit);
+            let tb = self.nodes[b].bounds.ray_intersect_inverse(p, &inv, limit);
+            // Push the farther child first so the nearer is searched first.
+            match (ta, tb) {
+                (Some(ta), Some(tb)) if ta <= tb => stack.extend([(b, tb), (a, ta)]),
+                (Some(ta), Some(tb)) => stack.extend([(a, ta), (b, tb)]),
+                (Some(ta), None) => stack.push((a, ta)),
+                (None, Some(tb)) => stack.push((b, tb)),
+                (None, None) => {}
+            }
+        }
+        best
+    }
+
+    /// Calls f with the index of every item whose box overlaps the box b.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - A reference to the box to query.
+    /// * `f` - The function called with each index found.
+    pub fn query_aabb(&self, b: &Aabb3, f: impl FnMut(usize)) {
+        self.query(|n| n.intersects_aabb(b), f);
+    }
+
+    /// Calls f with the index of every item whose box may lie inside the
+    /// frustum, with the conservative test of `Frustum::intersects_aabb`.
+    ///
+    /// # Arguments
+    ///
+    /// * `frustum` - A reference to the frustum to query.
+    /// * `f` - The function called with each index found.
+    pub fn query_frustum(&self, frustum: &Frustum, f: impl FnMut(usize)) {
+        self.query(|n| frustum.intersects_aabb(n), f);
+    }
+
+    // Calls f with every item whose box passes the test, descending only
+    // into the nodes whose boxes pass it.
+    fn query(&self, test: impl Fn(&Aabb3) -> bool, mut f: impl FnMut(usize)) {
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            if !test(&node.bounds) {
+                continue;
+            }
+            if node.count > 0 {
+                for k in node.first..node.first + node.count {
+                    if test(&self.boxes[k]) {
+                        f(self.items[k]);
+                    }
+                }
+            } else {
+                stack.extend([node.first, node.first + 1]);
+            }
+        }
+    }
+}
+
+// Returns where to split items along axis by the binned surface area
+// heuristic, or None if every centroid falls in one bin or no split is
+// cheaper than the leaf it would replace. On success the items are
+// partitioned and the returned count are left of the split.
+fn sah_split(
+    items: &mut [usize],
+    boxes: &[Aabb3],
+    axis: usize,
+    lo: Point3,
+    extent: f32,
+    key: &impl Fn(usize) -> f32,
+) -> Option<usize> {
+    if extent <= 0.0 {
+        return None;
+    }
+    let start = <[f32; 3]>::from(lo)[axis];
+    let bin = |j: usize| (((key(j) - start) / extent * SAH_BINS as f32) as usize).min(SAH_BINS - 1);
+    let mut counts = [0usize; SAH_BINS];
+    let mut bounds: [Option<Aabb3>; SAH_BINS] = [None; SAH_BINS];
+    for &j in items.iter() {
+        let k = bin(j);
+        counts[k] += 1;
+        bounds[k] = Some(bounds[k].map_or(boxes[j], |b| b.merge(&boxes[j])));
+    }
+    let merge = |a: Option<Aabb3>, b: Option<Aabb3>| match (a, b) {
+        (Some(a), Some(b)) => Some(a.merge(&b)),
+        (a, b) => a.or(b),
+    };
+    let area = |b: Option<Aabb3>| b.map_or(0.0, |b| b.surface_area());
+    // The cost of the items right of each boundary, swept from the right.
+    let mut right = [0.0f32; SAH_BINS];
+    let (mut acc, mut n) = (None, 0);
+    for k in (1..SAH_BINS).rev() {
+        acc = merge(acc, bounds[k]);
+        n += counts[k];
+        right[k] = area(acc) * n as f32;
+    }
+    let (mut acc, mut n) = (None, 0);
+    let mut best: Option<(usize, f32)> = None;
+    for k in 1..SAH_BINS {
+        acc = merge(acc, bounds[k - 1]);
+        n += counts[k - 1];
+        let cost = area(acc) * n as f32 + right[k];
+        if n > 0 && n < items.len() && best.is_none_or(|b| cost < b.1) {
+            best = Some((k, cost));
+        }
+    }
+    let (k, cost) = best?;
+    let whole = items
+        .iter()
+        .fold(boxes[items[0]], |b, &j| b.merge(&boxes[j]));
+    if cost >= whole.surface_area() * items.len() as f32 {
+        return None;
+    }
+    // Partition the items left of bin boundary k to the front.
+    let mut mid = 0;
+    for i in 0..items.len() {
+        if bin(items[i]) < k {
+            items.swap(i, mid);
+            mid += 1;
+        }
+    }
+    Some(mid)
+}
+
+#[test]
+fn hierarchy() {
+    // A grid of unit boxes, checked against brute force.
+    let mut cells = Vec::new();
+    for x in 0..10 {
+        for y in 0..10 {
+            for z in 0..3 {
+                let min = Point3::new(x as f32 * 2.0, y as f32 * 2.0, z as f32 * 2.0);
+                cells.push(Aabb3::new(min, min + Vector3::new(1.0, 1.0, 1.0)));
+            }
+        }
+    }
+    for split in [BvhSplit::Median, BvhSplit::Sah] {
+        let mut bvh = Bvh::build(&cells, split, |b| *b);
+        let all = bvh.bounds().unwrap();
+        assert!(all.min == Point3::new(0.0, 0.0, 0.0) && all.max == Point3::new(19.0, 19.0, 5.0));
+        let q = Aabb3::new(Point3::new(3.5, 3.5, -1.0), Point3::new(8.5, 6.5, 2.5));
+        let mut found = Vec::new();
+        bvh.query_aabb(&q, |i| found.push(i));
+        found.sort();
+        let expected: Vec<usize> = (0..cells.len())
+            .filter(|&i| cells[i].intersects_aabb(&q))
+            .collect();
+        assert!(found == expected && found.len() == 3 * 2 * 2);
+        // Rays find the nearest box and report misses.
+        let (p, v) = (Point3::new(4.5, 4.5, 20.0), Vector3::new(0.0, 0.0, -1.0));
+        let hit = bvh.ray_cast(&p, &v, |i| cells[i].ray_intersect(&p, &v));
+        assert!(hit == Some((2 * 30 + 2 * 3 + 2, 15.0)));
+        let miss = Point3::new(5.5, 4.5, 20.0);
+        assert!(bvh
+            .ray_cast(&miss, &v, |i| cells[i].ray_intersect(&miss, &v))
+            .is_none());
+        // After the items move, refitting keeps queries exact.
+        let moved: Vec<Aabb3> = cells
+            .iter()
+            .map(|b| {
+                Aabb3::new(
+                    b.min + Vector3::new(0.0, 0.0, 10.0),
+                    b.max + Vector3::new(0.0, 0.0, 10.0),
+                )
+            })
+            .collect();
+        bvh.refit(&moved, |b| *b);
+        let hit = bvh.ray_cast(&p, &v, |i| moved[i].ray_intersect(&p, &v));
+        assert!(hit == Some((2 * 30 + 2 * 3 + 2, 5.0)));
+        let mut count = 0;
+        let f = Frustum::from_matrix(
+            &(Matrix4::perspective_infinite_reverse_rh(0.5, 1.0, 0.1)
+                * Matrix4::look_at_rh(
+                    &Point3::new(9.5, 9.5, 40.0),
+                    &Point3::new(9.5, 9.5, 0.0),
+                    &Vector3::new(0.0, 1.0, 0.0),
+                )),
+        );
+        bvh.query_frustum(&f, |_| count += 1);
+        assert!(count > 0 && count < moved.len());
+    }
+    let empty = Bvh::build(&[] as &[Aabb3], BvhSplit::Sah, |b| *b);
+    assert!(empty.bounds().is_none());
+    empty.query_aabb(&Aabb3::default(), |_| panic!());
+}
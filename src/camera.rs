@@ -0,0 +1,321 @@
+use crate::prelude::*;
+
+/// The lens of a `Camera`. Both kinds are right-handed, looking down the
+/// negative z axis of camera space, and map depth from near to far onto
+/// [0, 1], like `Matrix4::perspective_rh` and `Matrix4::orthographic_rh`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Projection {
+    /// A perspective projection.
+    Perspective {
+        /// The vertical field of view in radians.
+        fovy: f32,
+        /// The width of the viewport divided by its height.
+        aspect: f32,
+        /// The distance to the near plane.
+        near: f32,
+        /// The distance to the far plane.
+        far: f32,
+    },
+    /// An orthographic projection centered on the view direction.
+    Orthographic {
+        /// The height of the view volume.
+        height: f32,
+        /// The width of the viewport divided by its height.
+        aspect: f32,
+        /// The distance to the near plane.
+        near: f32,
+        /// The distance to the far plane.
+        far: f32,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A camera placed in the world by a position and an orientation, with a
+/// projection. In camera space it looks down the negative z axis with up
+/// along positive y, and the orientation rotates camera space into the
+/// world.
+pub struct Camera {
+    /// The position of the camera.
+    pub position: Point3,
+    /// The unit quaternion rotating camera space into world space.
+    pub orientation: Quarternion,
+    /// The projection of the camera.
+    pub projection: Projection,
+    /// An offset of the image in normalized device coordinates, zero for
+    /// none. Temporal antialiasing sets a different sub-pixel offset each
+    /// frame; see `Camera::halton_jitter`.
+    pub jitter: Vector2,
+}
+
+impl Camera {
+    /// Returns a camera at position with the given orientation and
+    /// projection, and no jitter.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the camera.
+    /// * `orientation` - A unit quaternion rotating camera space into world
+    ///   space.
+    /// * `projection` - The projection of the camera.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::camera::{Camera, Projection};
+    /// use math_engine::point3::Point3;
+    /// use math_engine::quarternion::Quarternion;
+    /// let lens = Projection::Perspective { fovy: 1.0, aspect: 1.5, near: 0.1, far: 100.0 };
+    /// let c = Camera::new(Point3::new(0.0, 1.0, 0.0), Quarternion::new(0.0, 0.0, 0.0, 1.0), lens);
+    /// assert!(c.forward().z == -1.0);
+    /// ```
+    pub fn new(position: Point3, orientation: Quarternion, projection: Projection) -> Camera {
+        Camera {
+            position,
+            orientation,
+            projection,
+            jitter: Vector2::new(0.0, 0.0),
+        }
+    }
+
+    /// Returns a camera at eye looking toward target, with the given
+    /// projection and no jitter.
+    ///
+    /// # Arguments
+    ///
+    /// * `eye` - The position of the camera.
+    /// * `target` - The point the camera looks at.
+    /// * `up` - The approximate up direction, not parallel to target - eye.
+    /// * `projection` - The projection of the camera.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::camera::{Camera, Projection};
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// let lens = Projection::Orthographic { height: 10.0, aspect: 1.0, near: 0.0, far: 50.0 };
+    /// let c = Camera::look_at(&Point3::new(5.0, 0.0, 0.0), &Point3::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 1.0, 0.0), lens);
+    /// assert!((c.forward() - Vector3::new(-1.0, 0.0, 0.0)).magnitude() < 1e-6);
+    /// ```
+    pub fn look_at(eye: &Point3, target: &Point3, up: &Vector3, projection: Projection) -> Camera {
+        let h = Transform4::look_at(eye, target, up);
+        let mut orientation = Quarternion::new_with_scalar(1.0);
+        orientation.set_rotation_matrix(&Matrix3::new_with_vecs(h[0], h[1], h[2]));
+        Camera::new(*eye, orientation, projection)
+    }
+
+    /// Returns the unit direction the camera looks in.
+    pub fn forward(&self) -> Vector3 {
+        self.orientation.transform(&Vector3::new(0.0, 0.0, -1.0))
+    }
+
+    /// Returns the unit direction to the right of the view.
+    pub fn right(&self) -> Vector3 {
+        self.orientation.transform(&Vector3::new(1.0, 0.0, 0.0))
+    }
+
+    /// Returns the unit direction up the view.
+    pub fn up(&self) -> Vector3 {
+        self.orientation.transform(&Vector3::new(0.0, 1.0, 0.0))
+    }
+
+    /// Returns the view matrix, mapping world space into camera space. As
+    /// the orientation is a rotation it is inverted by transposing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::camera::{Camera, Projection};
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// use math_engine::vector4::Vector4;
+    /// let lens = Projection::Perspective { fovy: 1.0, aspect: 1.0, near: 0.1, far: 100.0 };
+    /// let c = Camera::look_at(&Point3::new(0.0, 0.0, 5.0), &Point3::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 1.0, 0.0), lens);
+    /// assert!(c.view_matrix() * Point3::new(0.0, 0.0, 0.0) == Vector4::new(0.0, 0.0, -5.0, 1.0));
+    /// ```
+    pub fn view_matrix(&self) -> Matrix4 {
+        let r = self
+            .orientation
+            .normalize()
+            .get_rotation_matrix()
+            .transpose();
+        let t = r * -Vector3::from(self.position);
+        Matrix4::from(Transform4::new_with_vecs(r[0], r[1], r[2], Point3::from(t)))
+    }
+
+    /// Returns the projection matrix, offset by the jitter.
+    pub fn projection_matrix(&self) -> Matrix4 {
+        let j = self.jitter;
+        Matrix4::make_translation(&Vector3::new(j.x, j.y, 0.0)) * self.lens()
+    }
+
+    /// Returns the projection matrix times the view matrix, mapping world
+    /// space into clip space.
+    pub fn view_projection_matrix(&self) -> Matrix4 {
+        self.projection_matrix() * self.view_matrix()
+    }
+
+    /// Returns the world space frustum of this camera, for culling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::camera::{Camera, Projection};
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// let lens = Projection::Perspective { fovy: 1.0, aspect: 1.0, near: 0.1, far: 100.0 };
+    /// let c = Camera::look_at(&Point3::new(0.0, 0.0, 5.0), &Point3::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 1.0, 0.0), lens);
+    /// let f = c.frustum();
+    /// assert!(f.contains_point(&Point3::new(0.0, 0.0, 0.0)));
+    /// assert!(!f.contains_point(&Point3::new(0.0, 0.0, 6.0)));
+    /// ```
+    pub fn frustum(&self) -> Frustum {
+        Frustum::from_matrix(&self.view_projection_matrix())
+    }
+
+    /// Returns the world space ray from the near plane through the window
+    /// coordinates x and y, measured up from the bottom of viewport, as
+    /// `projection::pick_ray` does. The jitter is left out, so picking is
+    /// steady from frame to frame. Returns None if the projection is
+    /// degenerate.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The window x coordinate.
+    /// * `y` - The window y coordinate, measured up from the bottom.
+    /// * `viewport` - The viewport as (x, y, width, height).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::camera::{Camera, Projection};
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// use math_engine::vector4::Vector4;
+    /// let lens = Projection::Perspective { fovy: 1.0, aspect: 2.0, near: 0.5, far: 100.0 };
+    /// let c = Camera::look_at(&Point3::new(3.0, 0.0, 0.0), &Point3::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 1.0, 0.0), lens);
+    /// let r = c.screen_ray(400.0, 200.0, &Vector4::new(0.0, 0.0, 800.0, 400.0)).unwrap();
+    /// assert!((r.origin - Point3::new(2.5, 0.0, 0.0)).magnitude() < 1e-5);
+    /// assert!(r.direction.normalize().x < -0.99999);
+    /// ```
+    pub fn screen_ray(&self, x: f32, y: f32, viewport: &Vector4) -> Option<Ray3> {
+        crate::projection::pick_ray(x, y, &self.view_matrix(), &self.lens(), viewport)
+    }
+
+    /// Returns a sub-pixel jitter for the given frame, in normalized device
+    /// coordinates for a viewport of width by height pixels, to assign to
+    /// `jitter`. The offsets follow the Halton sequence in bases 2 and 3,
+    /// which covers the pixel evenly; callers usually cycle through the
+    /// first 8 or 16 frames. Every frame maps to an element of the sequence,
+    /// which starts over after `u32::MAX` frames, so frame `u32::MAX` gives
+    /// the same offset as frame 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The index of the frame.
+    /// * `width` - The width of the viewport in pixels.
+    /// * `height` - The height of the viewport in pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::camera::Camera;
+    /// // The first offset is a sixth of a pixel down from the centre, and a
+    /// // pixel is 2 / 600 high in normalized device coordinates.
+    /// let j = Camera::halton_jitter(0, 800.0, 600.0);
+    /// assert!(j.x == 0.0 && (j.y + 1.0 / 1800.0).abs() < 1e-9);
+    /// ```
+    pub fn halton_jitter(frame: u32, width: f32, height: f32) -> Vector2 {
+        // Index 0 of the sequence is the pixel corner, which is skipped.
+        let i = frame % u32::MAX + 1;
+        let x = halton(i, 2) - 0.5;
+        let y = halton(i, 3) - 0.5;
+        Vector2::new(2.0 * x / width, 2.0 * y / height)
+    }
+
+    // Returns the projection matrix without the jitter.
+    fn lens(&self) -> Matrix4 {
+        match self.projection {
+            Projection::Perspective {
+                fovy,
+                aspect,
+                near,
+                far,
+            } => Matrix4::perspective_rh(fovy, aspect, near, far),
+            Projection::Orthographic {
+                height,
+                aspect,
+                near,
+                far,
+            } => {
+                let (w, h) = (0.5 * height * aspect, 0.5 * height);
+                Matrix4::orthographic_rh(-w, w, -h, h, near, far)
+            }
+        }
+    }
+}
+
+// Returns the element of the van der Corput sequence in the given base
+// for index i, the digits of i mirrored about the radix point.
+fn halton(mut i: u32, base: u32) -> f32 {
+    let (mut f, mut r) = (1.0, 0.0);
+    while i > 0 {
+        f /= base as f32;
+        r += f * (i % base) as f32;
+        i /= base;
+    }
+    r
+}
+
+#[test]
+fn cameras() {
+    let lens = Projection::Perspective {
+        fovy: 1.2,
+        aspect: 1.5,
+        near: 0.5,
+        far: 50.0,
+    };
+    let eye = Point3::new(2.0, 3.0, -4.0);
+    let target = Point3::new(-1.0, 0.5, 2.0);
+    let up = Vector3::new(0.0, 1.0, 0.0);
+    let mut c = Camera::look_at(&eye, &target, &up, lens);
+    // The view matches the one built directly from the same frame.
+    let m = Matrix4::look_at_rh(&eye, &target, &up);
+    let view = c.view_matrix();
+    for i in 0..4 {
+        assert!((view[i] - m[i]).magnitude() < 1e-5);
+    }
+    assert!((c.forward() - (target - eye).normalize()).magnitude() < 1e-5);
+    assert!(c.right().dot(&up).abs() < 1e-5 && c.up().dot(&c.forward()).abs() < 1e-5);
+
+    // Points along the ray through a window position project back onto it.
+    let viewport = Vector4::new(0.0, 0.0, 300.0, 200.0);
+    let r = c.screen_ray(75.0, 150.0, &viewport).unwrap();
+    let q = r.origin + r.direction.normalize() * 10.0;
+    let w = crate::projection::project(&q, &view, &c.projection_matrix(), &viewport);
+    assert!((w.x - 75.0).abs() < 1e-3 && (w.y - 150.0).abs() < 1e-3);
+    assert!(c.frustum().contains_point(&q));
+
+    // Jitter moves every projected point by the same sub-pixel offset.
+    let p = eye + c.forward() * 5.0 + c.right();
+    let before = c.view_projection_matrix() * p;
+    c.jitter = Camera::halton_jitter(3, 300.0, 200.0);
+    let after = c.view_projection_matrix() * p;
+    let shift = Vector2::new(
+        after.x / after.w - before.x / before.w,
+        after.y / after.w - before.y / before.w,
+    );
+    assert!((shift - c.jitter).magnitude() < 1e-5);
+    // The first few offsets stay within the pixel and differ from frame to
+    // frame.
+    for i in 0..8 {
+        let j = Camera::halton_jitter(i, 2.0, 2.0);
+        assert!(j.x.abs() < 0.5 && j.y.abs() < 0.5);
+        assert!(j != Camera::halton_jitter(i + 1, 2.0, 2.0));
+    }
+    // The last frame index starts the sequence over rather than overflowing.
+    let j = Camera::halton_jitter(u32::MAX, 2.0, 2.0);
+    assert!(j == Camera::halton_jitter(0, 2.0, 2.0));
+}
@@ -0,0 +1,185 @@
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A capsule, the set of points within radius of a line segment.
+#[repr(C)]
+pub struct Capsule {
+    /// The core segment of the capsule.
+    pub segment: Segment3,
+    /// The radius around the segment.
+    pub radius: f32,
+}
+
+impl Capsule {
+    /// Returns a capsule around the segment from a to b with the given radius.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The start of the core segment.
+    /// * `b` - The end of the core segment.
+    /// * `radius` - The radius around the segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::capsule::Capsule;
+    /// use math_engine::point3::Point3;
+    /// let c = Capsule::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0), 0.5);
+    /// assert!(c.radius == 0.5);
+    /// ```
+    pub const fn new(a: Point3, b: Point3, radius: f32) -> Capsule {
+        Capsule {
+            segment: Segment3::new(a, b),
+            radius,
+        }
+    }
+
+    /// Returns true if the point p lies inside or on this capsule.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::capsule::Capsule;
+    /// use math_engine::point3::Point3;
+    /// let c = Capsule::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0), 0.5);
+    /// assert!(c.contains_point(&Point3::new(0.4, 2.2, 0.0)));
+    /// ```
+    pub fn contains_point(&self, p: &Point3) -> bool {
+        self.segment.distance_to_point(p) <= self.radius
+    }
+
+    /// Returns the point on the surface of this capsule closest to p. When p
+    /// lies on the core segment the segment point itself is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::capsule::Capsule;
+    /// use math_engine::point3::Point3;
+    /// let c = Capsule::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0), 0.5);
+    /// assert!(c.closest_point(&Point3::new(3.0, 1.0, 0.0)) == Point3::new(0.5, 1.0, 0.0));
+    /// ```
+    pub fn closest_point(&self, p: &Point3) -> Point3 {
+        let c = self.segment.closest_point(p);
+        surface_point(&c, p, self.radius)
+    }
+
+    /// Returns the distance between the surfaces of this capsule and other.
+    /// The result is negative when the capsules overlap and is then the depth
+    /// of the overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::capsule::Capsule;
+    /// use math_engine::point3::Point3;
+    /// let a = Capsule::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0), 0.5);
+    /// let b = Capsule::new(Point3::new(2.0, 0.0, 0.0), Point3::new(2.0, 2.0, 0.0), 0.5);
+    /// assert!(a.distance_capsule(&b) == 1.0);
+    /// ```
+    pub fn distance_capsule(&self, other: &Capsule) -> f32 {
+        self.segment.distance(&other.segment) - self.radius - other.radius
+    }
+
+    /// Returns the pair of closest points on the surfaces of this capsule and
+    /// other, the first lying on this capsule and the second on other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::capsule::Capsule;
+    /// use math_engine::point3::Point3;
+    /// let a = Capsule::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0), 0.5);
+    /// let b = Capsule::new(Point3::new(2.0, 1.0, 0.0), Point3::new(2.0, 3.0, 0.0), 0.5);
+    /// let (p, q) = a.closest_points_capsule(&b);
+    /// assert!(p.x == 0.5 && q.x == 1.5);
+    /// ```
+    pub fn closest_points_capsule(&self, other: &Capsule) -> (Point3, Point3) {
+        let (p, q) = self.segment.closest_points(&other.segment);
+        (
+            surface_point(&p, &q, self.radius),
+            surface_point(&q, &p, other.radius),
+        )
+    }
+
+    /// Returns true if this capsule and other overlap or touch.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a capsule.
+    pub fn intersects_capsule(&self, other: &Capsule) -> bool {
+        self.distance_capsule(other) <= 0.0
+    }
+
+    /// Returns the distance between the surfaces of this capsule and the
+    /// sphere s. The result is negative when they overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - A reference to a sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::capsule::Capsule;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// let c = Capsule::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0), 0.5);
+    /// let s = Sphere::new(Point3::new(0.0, 4.0, 0.0), 1.0);
+    /// assert!(c.distance_sphere(&s) == 0.5);
+    /// ```
+    pub fn distance_sphere(&self, s: &Sphere) -> f32 {
+        self.segment.distance_to_point(&s.center) - self.radius - s.radius
+    }
+
+    /// Returns the pair of closest points on the surfaces of this capsule and
+    /// the sphere s, the first lying on this capsule and the second on s.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - A reference to a sphere.
+    pub fn closest_points_sphere(&self, s: &Sphere) -> (Point3, Point3) {
+        let p = self.segment.closest_point(&s.center);
+        (
+            surface_point(&p, &s.center, self.radius),
+            surface_point(&s.center, &p, s.radius),
+        )
+    }
+
+    /// Returns true if this capsule and the sphere s overlap or touch.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - A reference to a sphere.
+    pub fn intersects_sphere(&self, s: &Sphere) -> bool {
+        self.distance_sphere(s) <= 0.0
+    }
+}
+
+// Returns the point at distance r from c in the direction of p, or c when the
+// two points coincide and the direction is undefined.
+fn surface_point(c: &Point3, p: &Point3, r: f32) -> Point3 {
+    let d = *p - *c;
+    let m = d.magnitude();
+    if m <= f32::EPSILON {
+        return *c;
+    }
+    *c + d * (r / m)
+}
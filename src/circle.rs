@@ -0,0 +1,128 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A circle in the plane described by its center point and radius.
+#[repr(C)]
+pub struct Circle {
+    /// The center of the circle.
+    pub center: Point2,
+    /// The radius of the circle.
+    pub radius: f32,
+}
+
+impl Circle {
+    /// Returns a circle with the given center and radius.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The center of the circle.
+    /// * `radius` - The radius of the circle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::circle::Circle;
+    /// use math_engine::point2::Point2;
+    /// let c = Circle::new(Point2::new(0.0, 0.0), 1.0);
+    /// assert!(c.radius == 1.0);
+    /// ```
+    pub const fn new(center: Point2, radius: f32) -> Circle {
+        Circle { center, radius }
+    }
+
+    /// Returns true if the point p lies inside or on this circle.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::circle::Circle;
+    /// use math_engine::point2::Point2;
+    /// let c = Circle::new(Point2::new(0.0, 0.0), 1.0);
+    /// assert!(c.contains_point(&Point2::new(0.6, 0.8)));
+    /// assert!(!c.contains_point(&Point2::new(0.8, 0.8)));
+    /// ```
+    pub fn contains_point(&self, p: &Point2) -> bool {
+        self.center.distance_squared(p) <= self.radius * self.radius
+    }
+
+    /// Returns true if this circle and other overlap or touch.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a circle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::circle::Circle;
+    /// use math_engine::point2::Point2;
+    /// let a = Circle::new(Point2::new(0.0, 0.0), 1.0);
+    /// assert!(a.intersects_circle(&Circle::new(Point2::new(3.0, 0.0), 2.0)));
+    /// assert!(!a.intersects_circle(&Circle::new(Point2::new(3.0, 0.0), 1.5)));
+    /// ```
+    pub fn intersects_circle(&self, other: &Circle) -> bool {
+        let r = self.radius + other.radius;
+        self.center.distance_squared(&other.center) <= r * r
+    }
+
+    /// Returns true if this circle and the rectangle b overlap or touch.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - A reference to an aabb2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb2::Aabb2;
+    /// use math_engine::circle::Circle;
+    /// use math_engine::point2::Point2;
+    /// let r = Aabb2::new(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0));
+    /// // Beside an edge, and diagonally past a corner.
+    /// assert!(Circle::new(Point2::new(2.9, 1.0), 1.0).intersects_aabb(&r));
+    /// assert!(!Circle::new(Point2::new(2.8, 2.8), 1.0).intersects_aabb(&r));
+    /// ```
+    pub fn intersects_aabb(&self, b: &Aabb2) -> bool {
+        self.contains_point(&b.closest_point(&self.center))
+    }
+
+    /// Returns the parameters t0 <= t1 at which the line through p with
+    /// direction v enters and leaves this circle, or None if it misses. The
+    /// points are p + v * t0 and p + v * t1, and either may be negative.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A point on the line.
+    /// * `v` - The direction of the line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::circle::Circle;
+    /// use math_engine::point2::Point2;
+    /// use math_engine::vector2::Vector2;
+    /// let c = Circle::new(Point2::new(5.0, 0.0), 1.0);
+    /// let t = c.line_intersect(&Point2::new(0.0, 0.0), &Vector2::new(1.0, 0.0));
+    /// assert!(t == Some((4.0, 6.0)));
+    /// ```
+    pub fn line_intersect(&self, p: &Point2, v: &Vector2) -> Option<(f32, f32)> {
+        let m = *p - self.center;
+        let a = v.dot(v);
+        let b = m.dot(v);
+        let c = m.dot(&m) - self.radius * self.radius;
+        let disc = b * b - a * c;
+        if disc < 0.0 || a == 0.0 {
+            return None;
+        }
+        let sq = disc.sqrt();
+        Some(((-b - sq) / a, (-b + sq) / a))
+    }
+}
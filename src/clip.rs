@@ -0,0 +1,121 @@
+//! Sutherland–Hodgman clipping of convex polygons against planes.
+//!
+//! A polygon is a slice of points in winding order, with an implicit edge
+//! from the last point back to the first. Clipping keeps the part of the
+//! polygon on the front side of a plane, the side its normal points into,
+//! which for a `Frustum` is the inside.
+
+use crate::prelude::*;
+use alloc::vec::Vec;
+
+/// Returns the part of polygon lying on the front side of the plane f. The
+/// result is empty when the polygon lies entirely behind the plane.
+///
+/// # Arguments
+///
+/// * `polygon` - The vertices of a convex polygon in winding order.
+/// * `f` - A reference to a plane.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::clip::clip_polygon_plane;
+/// use math_engine::plane::Plane;
+/// use math_engine::point3::Point3;
+/// let square = [
+///     Point3::new(-1.0, -1.0, 0.0),
+///     Point3::new(1.0, -1.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(-1.0, 1.0, 0.0),
+/// ];
+/// let half = clip_polygon_plane(&square, &Plane::new(1.0, 0.0, 0.0, 0.0));
+/// assert!(half.len() == 4);
+/// assert!(half.iter().all(|p| p.x >= 0.0));
+/// ```
+pub fn clip_polygon_plane(polygon: &[Point3], f: &Plane) -> Vec<Point3> {
+    let mut out = Vec::with_capacity(polygon.len() + 1);
+    let Some(last) = polygon.last() else {
+        return out;
+    };
+    let mut a = *last;
+    let mut da = f.point_dot(&a);
+    for b in polygon {
+        let db = f.point_dot(b);
+        if db >= 0.0 {
+            if da < 0.0 {
+                out.push(a + (*b - a) * (da / (da - db)));
+            }
+            out.push(*b);
+        } else if da >= 0.0 {
+            out.push(a + (*b - a) * (da / (da - db)));
+        }
+        a = *b;
+        da = db;
+    }
+    out
+}
+
+/// Returns the part of polygon lying inside the frustum, clipping against
+/// each of its planes in turn.
+///
+/// # Arguments
+///
+/// * `polygon` - The vertices of a convex polygon in winding order.
+/// * `frustum` - A reference to a frustum.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::clip::clip_polygon_frustum;
+/// use math_engine::frustum::Frustum;
+/// use math_engine::plane::Plane;
+/// use math_engine::point3::Point3;
+/// let cube = Frustum::new([
+///     Plane::new(1.0, 0.0, 0.0, 1.0),
+///     Plane::new(-1.0, 0.0, 0.0, 1.0),
+///     Plane::new(0.0, 1.0, 0.0, 1.0),
+///     Plane::new(0.0, -1.0, 0.0, 1.0),
+///     Plane::new(0.0, 0.0, 1.0, 1.0),
+///     Plane::new(0.0, 0.0, -1.0, 1.0),
+/// ]);
+/// let big = [
+///     Point3::new(-4.0, -4.0, 0.0),
+///     Point3::new(4.0, -4.0, 0.0),
+///     Point3::new(4.0, 4.0, 0.0),
+///     Point3::new(-4.0, 4.0, 0.0),
+/// ];
+/// let clipped = clip_polygon_frustum(&big, &cube);
+/// assert!(clipped.len() == 4);
+/// assert!(clipped.iter().all(|p| p.x.abs() == 1.0 && p.y.abs() == 1.0));
+/// ```
+pub fn clip_polygon_frustum(polygon: &[Point3], frustum: &Frustum) -> Vec<Point3> {
+    let mut out = polygon.to_vec();
+    for f in &frustum.planes {
+        if out.is_empty() {
+            break;
+        }
+        out = clip_polygon_plane(&out, f);
+    }
+    out
+}
+
+#[test]
+fn triangle_corner() {
+    let tri = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+    ];
+    // Cutting off the corner at (2, 0) turns the triangle into a quad.
+    let quad = clip_polygon_plane(&tri, &Plane::new(-1.0, 0.0, 0.0, 1.0));
+    assert!(
+        quad == [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 0.0),
+            Point3::new(0.0, 2.0, 0.0),
+        ]
+    );
+    assert!(clip_polygon_plane(&tri, &Plane::new(0.0, 0.0, 1.0, -1.0)).is_empty());
+    assert!(clip_polygon_plane(&tri, &Plane::new(0.0, 0.0, 1.0, 1.0)) == tri);
+}
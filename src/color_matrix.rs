@@ -0,0 +1,176 @@
+//! Color matrices for grading colors on the CPU the way a shader would,
+//! after the filter functions of the CSS Filter Effects specification.
+//! The linear adjustments are `Matrix3`s applied as `color * m`. Contrast
+//! also needs an offset, so it is a `Matrix4` acting on (r, g, b, 1), and
+//! the `Matrix3` adjustments convert into `Matrix4`s to be chained with it.
+//! The luma weights are the Rec. 709 ones the specification uses.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use core::ops::{Mul, MulAssign};
+
+impl Matrix3 {
+    /// Returns the matrix rotating the hue of colors by the given angle
+    /// while keeping their luma, the CSS `hue-rotate` filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle to rotate the hue by.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::angle::Degrees;
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::rgb::RGB;
+    /// let gray = RGB::new(0.5, 0.5, 0.5) * Matrix3::make_hue_rotation(Degrees(120.0));
+    /// assert!((gray.r - 0.5).abs() < 1e-6 && (gray.g - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn make_hue_rotation(a: impl Into<Radians>) -> Matrix3 {
+        let a = a.into().0;
+        let c = a.cos();
+        let s = a.sin();
+        Matrix3::new(
+            0.213 + c * 0.787 - s * 0.213,
+            0.715 - c * 0.715 - s * 0.715,
+            0.072 - c * 0.072 + s * 0.928,
+            0.213 - c * 0.213 + s * 0.143,
+            0.715 + c * 0.285 + s * 0.140,
+            0.072 - c * 0.072 - s * 0.283,
+            0.213 - c * 0.213 - s * 0.787,
+            0.715 - c * 0.715 + s * 0.715,
+            0.072 + c * 0.928 + s * 0.072,
+        )
+    }
+
+    /// Returns the matrix scaling the saturation of colors, the CSS
+    /// `saturate` filter. 0 gives grayscale, 1 leaves colors as they are
+    /// and larger amounts oversaturate.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The amount to scale the saturation by.
+    pub fn make_saturation(s: f32) -> Matrix3 {
+        Matrix3::new(
+            0.213 + 0.787 * s,
+            0.715 - 0.715 * s,
+            0.072 - 0.072 * s,
+            0.213 - 0.213 * s,
+            0.715 + 0.285 * s,
+            0.072 - 0.072 * s,
+            0.213 - 0.213 * s,
+            0.715 - 0.715 * s,
+            0.072 + 0.928 * s,
+        )
+    }
+
+    /// Returns the matrix replacing colors with the gray of their luma.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::rgb::RGB;
+    /// let g = RGB::new(0.0, 1.0, 0.0) * Matrix3::make_grayscale();
+    /// assert!(g.r == g.g && g.g == g.b && (g.r - 0.715).abs() < 1e-6);
+    /// ```
+    pub fn make_grayscale() -> Matrix3 {
+        Matrix3::make_saturation(0.0)
+    }
+
+    /// Returns the matrix scaling colors by the given amount, the CSS
+    /// `brightness` filter. 0 gives black and 1 leaves colors as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The amount to scale the channels by.
+    pub fn make_brightness(b: f32) -> Matrix3 {
+        Matrix3::make_scale(b, b, b)
+    }
+}
+
+impl Matrix4 {
+    /// Returns the matrix scaling the contrast of colors about mid gray, the
+    /// CSS `contrast` filter, acting on (r, g, b, 1). 0 gives mid gray and 1
+    /// leaves colors as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The amount to scale the contrast by.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::rgb::RGB;
+    /// let grade = Matrix4::make_contrast(2.0) * Matrix4::from(Matrix3::make_brightness(0.5));
+    /// assert!(RGB::new(1.0, 0.5, 0.25) * grade == RGB::new(0.5, 0.0, -0.25));
+    /// ```
+    pub fn make_contrast(c: f32) -> Matrix4 {
+        let offset = 0.5 - 0.5 * c;
+        Matrix4::new(
+            c, 0.0, 0.0, offset, 0.0, c, 0.0, offset, 0.0, 0.0, c, offset, 0.0, 0.0, 0.0, 1.0,
+        )
+    }
+}
+
+impl Mul<Matrix3> for RGB {
+    type Output = RGB;
+
+    /// Applies the color matrix m to this color, as m times the column
+    /// (r, g, b).
+    fn mul(self, m: Matrix3) -> RGB {
+        let v = m * Vector3::new(self.r, self.g, self.b);
+        RGB::new(v.x, v.y, v.z)
+    }
+}
+
+impl MulAssign<Matrix3> for RGB {
+    fn mul_assign(&mut self, m: Matrix3) {
+        *self = *self * m;
+    }
+}
+
+impl Mul<Matrix4> for RGB {
+    type Output = RGB;
+
+    /// Applies the affine color matrix m to this color, as m times the
+    /// column (r, g, b, 1).
+    fn mul(self, m: Matrix4) -> RGB {
+        let v = m * Vector4::new(self.r, self.g, self.b, 1.0);
+        RGB::new(v.x, v.y, v.z)
+    }
+}
+
+impl MulAssign<Matrix4> for RGB {
+    fn mul_assign(&mut self, m: Matrix4) {
+        *self = *self * m;
+    }
+}
+
+#[test]
+fn color_matrices() {
+    let close = |a: RGB, b: RGB| {
+        (a.r - b.r).abs() < 1e-5 && (a.g - b.g).abs() < 1e-5 && (a.b - b.b).abs() < 1e-5
+    };
+    let c = RGB::new(0.8, 0.3, 0.1);
+    assert!(close(c * Matrix3::make_hue_rotation(0.0), c));
+    assert!(close(c * Matrix3::make_hue_rotation(Degrees(360.0)), c));
+    assert!(close(c * Matrix3::make_saturation(1.0), c));
+    // The luma weights of every adjustment sum to one, so grays stay gray.
+    let gray = RGB::new(0.4, 0.4, 0.4);
+    for m in [
+        Matrix3::make_hue_rotation(Degrees(73.0)),
+        Matrix3::make_saturation(1.7),
+        Matrix3::make_grayscale(),
+    ] {
+        assert!(close(gray * m, gray));
+    }
+    let mut graded = c;
+    graded *= Matrix4::make_contrast(0.0);
+    assert!(close(graded, RGB::new(0.5, 0.5, 0.5)));
+    assert!(close(c * Matrix4::make_contrast(1.0), c));
+}
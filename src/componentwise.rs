@@ -0,0 +1,182 @@
+//! The component-wise helpers of the vector and point types, generated for
+//! both precisions from one definition: minimum and maximum, clamping,
+//! rounding, sign and reciprocal, the extreme components, linear
+//! interpolation and the raw component pointer.
+
+use crate::prelude::*;
+
+macro_rules! componentwise {
+    ($t:ident, $s:ident, $what:literal, $module:literal, $first:ident $(, $rest:ident)*) => {
+        impl $t {
+            #[doc = concat!("Returns the component-wise minimum of this ", $what, " and other.")]
+            ///
+            /// # Arguments
+            ///
+            #[doc = concat!("* `other` - A reference to a ", $module, ".")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use math_engine::", $module, "::", stringify!($t), ";")]
+            #[doc = concat!("let (a, b) = (", stringify!($t), "::splat(1.0), ", stringify!($t), "::splat(-2.0));")]
+            #[doc = concat!("assert!(a.min(&b) == ", stringify!($t), "::splat(-2.0));")]
+            #[doc = concat!("assert!(a.max(&b) == ", stringify!($t), "::splat(1.0));")]
+            /// ```
+            pub fn min(&self, other: &$t) -> $t {
+                $t::new(self.$first.min(other.$first) $(, self.$rest.min(other.$rest))*)
+            }
+
+            #[doc = concat!("Returns the component-wise maximum of this ", $what, " and other.")]
+            ///
+            /// # Arguments
+            ///
+            #[doc = concat!("* `other` - A reference to a ", $module, ".")]
+            pub fn max(&self, other: &$t) -> $t {
+                $t::new(self.$first.max(other.$first) $(, self.$rest.max(other.$rest))*)
+            }
+
+            #[doc = concat!("Returns this ", $what, " with each component clamped between the matching")]
+            /// components of lo and hi, which must not be greater than hi.
+            ///
+            /// # Arguments
+            ///
+            /// * `lo` - The lower bounds.
+            /// * `hi` - The upper bounds.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use math_engine::", $module, "::", stringify!($t), ";")]
+            #[doc = concat!("let (lo, hi) = (", stringify!($t), "::splat(0.0), ", stringify!($t), "::splat(1.0));")]
+            #[doc = concat!("assert!(", stringify!($t), "::splat(-3.0).clamp(&lo, &hi) == lo);")]
+            #[doc = concat!("assert!(", stringify!($t), "::splat(0.5).clamp(&lo, &hi) == ", stringify!($t), "::splat(0.5));")]
+            /// ```
+            pub fn clamp(&self, lo: &$t, hi: &$t) -> $t {
+                $t::new(
+                    self.$first.clamp(lo.$first, hi.$first)
+                    $(, self.$rest.clamp(lo.$rest, hi.$rest))*
+                )
+            }
+
+            /// Returns the absolute value of each component.
+            pub fn abs(&self) -> $t {
+                $t::new(self.$first.abs() $(, self.$rest.abs())*)
+            }
+
+            /// Returns the largest integer not greater than each component.
+            pub fn floor(&self) -> $t {
+                $t::new(self.$first.floor() $(, self.$rest.floor())*)
+            }
+
+            /// Returns the smallest integer not less than each component.
+            pub fn ceil(&self) -> $t {
+                $t::new(self.$first.ceil() $(, self.$rest.ceil())*)
+            }
+
+            /// Returns each component rounded to the nearest integer, with halfway
+            /// cases rounded away from zero.
+            pub fn round(&self) -> $t {
+                $t::new(self.$first.round() $(, self.$rest.round())*)
+            }
+
+            /// Returns the sign of each component, 1.0 or -1.0 as given by its sign
+            /// bit, or NaN for a NaN component.
+            pub fn signum(&self) -> $t {
+                $t::new(self.$first.signum() $(, self.$rest.signum())*)
+            }
+
+            /// Returns the reciprocal 1 / c of each component c.
+            pub fn recip(&self) -> $t {
+                $t::new(self.$first.recip() $(, self.$rest.recip())*)
+            }
+
+            /// Returns the smallest component.
+            pub fn min_element(&self) -> $s {
+                self.$first $(.min(self.$rest))*
+            }
+
+            /// Returns the largest component.
+            pub fn max_element(&self) -> $s {
+                self.$first $(.max(self.$rest))*
+            }
+
+            #[doc = concat!("Returns the ", $what, " interpolated linearly between this ", $what, " at t = 0 and")]
+            /// other at t = 1.
+            ///
+            /// # Arguments
+            ///
+            #[doc = concat!("* `other` - The ", $what, " at t = 1.")]
+            /// * `t` - The interpolation parameter.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use math_engine::", $module, "::", stringify!($t), ";")]
+            #[doc = concat!("let (a, b) = (", stringify!($t), "::splat(0.0), ", stringify!($t), "::splat(4.0));")]
+            #[doc = concat!("assert!(a.lerp(&b, 0.25) == ", stringify!($t), "::splat(1.0));")]
+            /// assert!(a.lerp(&b, 1.0) == b);
+            /// ```
+            pub fn lerp(&self, other: &$t, t: $s) -> $t {
+                $t::new(
+                    (1.0 - t) * self.$first + t * other.$first
+                    $(, (1.0 - t) * self.$rest + t * other.$rest)*
+                )
+            }
+
+            #[doc = concat!("Returns a raw pointer to the components of this ", $what, ", which are")]
+            #[doc = concat!("laid out contiguously as ", stringify!($first $(, $rest)*), ".")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use math_engine::", $module, "::", stringify!($t), ";")]
+            #[doc = concat!("let v = ", stringify!($t), "::splat(2.0);")]
+            /// let p = v.as_ptr();
+            /// assert!(unsafe { *p.add(1) } == 2.0);
+            /// ```
+            pub fn as_ptr(&self) -> *const $s {
+                (self as *const $t).cast::<$s>()
+            }
+        }
+    };
+}
+
+componentwise!(Vector2, f32, "vector", "vector2", x, y);
+componentwise!(Vector3, f32, "vector", "vector3", x, y, z);
+componentwise!(Vector4, f32, "vector", "vector4", x, y, z, w);
+componentwise!(Point2, f32, "point", "point2", x, y);
+componentwise!(Point3, f32, "point", "point3", x, y, z);
+componentwise!(DVector2, f64, "vector", "dvector2", x, y);
+componentwise!(DVector3, f64, "vector", "dvector3", x, y, z);
+componentwise!(DVector4, f64, "vector", "dvector4", x, y, z, w);
+componentwise!(DPoint2, f64, "point", "dpoint2", x, y);
+componentwise!(DPoint3, f64, "point", "dpoint3", x, y, z);
+
+#[test]
+fn componentwise() {
+    let a = Vector3::new(1.0, 5.0, -2.0);
+    let b = Vector3::new(3.0, 4.0, -6.0);
+    assert!(a.min(&b) == Vector3::new(1.0, 4.0, -6.0));
+    assert!(a.max(&b) == Vector3::new(3.0, 5.0, -2.0));
+    let v = Vector2::new(-3.0, 0.5).clamp(&Vector2::new(0.0, 0.0), &Vector2::new(1.0, 1.0));
+    assert!(v == Vector2::new(0.0, 0.5));
+    let ps = [Point3::new(1.0, 5.0, -2.0), Point3::new(3.0, 4.0, -6.0)];
+    let lo = ps.iter().fold(ps[0], |m, p| m.min(p));
+    assert!(lo == Point3::new(1.0, 4.0, -6.0));
+    let (a, b) = (Point3::new(0.0, 2.0, 4.0), Point3::new(4.0, 2.0, 0.0));
+    assert!(a.lerp(&b, 0.25) == Point3::new(1.0, 2.0, 3.0));
+    let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert!(unsafe { *v.as_ptr().add(3) } == 4.0);
+
+    // The double precision types agree with the single precision ones.
+    let v = DVector4::new(-1.5, 2.5, 0.25, -4.0);
+    assert!(v.abs() == DVector4::new(1.5, 2.5, 0.25, 4.0));
+    assert!(v.floor() == DVector4::new(-2.0, 2.0, 0.0, -4.0));
+    assert!(v.ceil() == DVector4::new(-1.0, 3.0, 1.0, -4.0));
+    assert!(v.round() == DVector4::new(-2.0, 3.0, 0.0, -4.0));
+    assert!(v.signum() == DVector4::new(-1.0, 1.0, 1.0, -1.0));
+    assert!(v.recip() == DVector4::new(-1.0 / 1.5, 0.4, 4.0, -0.25));
+    assert!(v.min_element() == -4.0 && v.max_element() == 2.5);
+    assert!(DVector4::from(v.to_f32().round()) == v.round());
+    assert!(DPoint2::new(1.0, -3.0).max_element() == 1.0);
+}
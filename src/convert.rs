@@ -0,0 +1,241 @@
+//! Conversions between the math types and plain arrays, tuples and slices,
+//! for interop with asset loaders and other libraries.
+//!
+//! Vectors, points, quaternions and colors convert to and from arrays and
+//! tuples of their components in memory order. Matrices and transforms
+//! convert to and from flat arrays of their entries in column-major order,
+//! the layout OpenGL and glTF use. Every type can also be read from a slice
+//! of exactly the right length with `TryFrom`, which fails with
+//! `TryFromSliceError` otherwise.
+//!
+//! Matrices and transforms also have inherent methods for these layouts,
+//! `from_cols_array`, `to_cols_array`, `to_cols_array_2d`, `from_rows_array`,
+//! `to_rows_array`, `as_slice` and `as_ptr`, in both precisions.
+
+use crate::prelude::*;
+use core::array::TryFromSliceError;
+
+macro_rules! slice {
+    ($t:ident, $s:ty, $n:expr) => {
+        impl TryFrom<&[$s]> for $t {
+            type Error = TryFromSliceError;
+
+            fn try_from(s: &[$s]) -> Result<Self, Self::Error> {
+                <[$s; $n]>::try_from(s).map($t::from)
+            }
+        }
+    };
+}
+
+macro_rules! components {
+    ($t:ident, $s:ty, $n:expr, $($c:ident: $i:tt),+) => {
+        impl From<[$s; $n]> for $t {
+            fn from(a: [$s; $n]) -> Self {
+                $t { $($c: a[$i]),+ }
+            }
+        }
+
+        impl From<$t> for [$s; $n] {
+            fn from(v: $t) -> Self {
+                [$(v.$c),+]
+            }
+        }
+
+        tuples!($t, $s, $($c: $i),+);
+        slice!($t, $s, $n);
+    };
+}
+
+macro_rules! tuples {
+    ($t:ident, $s:ty, $($c:ident: $i:tt),+) => {
+        impl From<($(tuples!(@ty $s, $c)),+)> for $t {
+            fn from(a: ($(tuples!(@ty $s, $c)),+)) -> Self {
+                $t { $($c: a.$i),+ }
+            }
+        }
+
+        impl From<$t> for ($(tuples!(@ty $s, $c)),+) {
+            fn from(v: $t) -> Self {
+                ($(v.$c),+)
+            }
+        }
+    };
+    (@ty $s:ty, $c:ident) => {
+        $s
+    };
+}
+
+macro_rules! columns {
+    ($t:ident, $s:ty, $cols:expr, $rows:expr, $what:literal, $module:literal) => {
+        impl From<[$s; $cols * $rows]> for $t {
+            fn from(a: [$s; $cols * $rows]) -> Self {
+                let mut m = $t::default();
+                for j in 0..$cols {
+                    for i in 0..$rows {
+                        m[j][i] = a[j * $rows + i];
+                    }
+                }
+                m
+            }
+        }
+
+        impl From<$t> for [$s; $cols * $rows] {
+            fn from(m: $t) -> Self {
+                core::array::from_fn(|k| m[k / $rows][k % $rows])
+            }
+        }
+
+        slice!($t, $s, $cols * $rows);
+        arrays!($t, $s, $cols, $rows, $what, $module);
+    };
+}
+
+macro_rules! arrays {
+    ($t:ident, $s:ty, $cols:expr, $rows:expr, $what:literal, $module:literal) => {
+        impl $t {
+            #[doc = concat!("Returns the ", $what, " whose entries are given column by column, the")]
+            /// inverse of `to_cols_array`.
+            ///
+            /// # Arguments
+            ///
+            /// * `a` - The entries in column-major order.
+            pub fn from_cols_array(a: &[$s; $cols * $rows]) -> $t {
+                Self::from(*a)
+            }
+
+            #[doc = concat!("Returns the entries of this ", $what, " column by column, the layout it")]
+            /// has in memory and the one OpenGL expects.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use math_engine::", $module, "::", stringify!($t), ";")]
+            #[doc = concat!("let a = ", stringify!($t), "::identity().to_cols_array();")]
+            #[doc = concat!("assert!(a[0] == 1.0 && a[1] == 0.0 && a[", stringify!($rows), "] == 0.0);")]
+            #[doc = concat!("assert!(", stringify!($t), "::from_cols_array(&a).to_cols_array() == a);")]
+            /// ```
+            pub fn to_cols_array(&self) -> [$s; $cols * $rows] {
+                (*self).into()
+            }
+
+            #[doc = concat!("Returns the columns of this ", $what, " as arrays, so that `a[j][i]` is")]
+            /// the entry at row i and column j.
+            pub fn to_cols_array_2d(&self) -> [[$s; $rows]; $cols] {
+                core::array::from_fn(|j| self[j].into())
+            }
+
+            #[doc = concat!("Returns the entries of this ", $what, " row by row, the layout row-major")]
+            /// APIs such as Direct3D's expect.
+            pub fn to_rows_array(&self) -> [$s; $cols * $rows] {
+                core::array::from_fn(|k| self[k % $cols][k / $cols])
+            }
+
+            #[doc = concat!("Returns the ", $what, " whose entries are given row by row, the inverse of")]
+            /// `to_rows_array`.
+            ///
+            /// # Arguments
+            ///
+            #[doc = concat!("* `a` - The entries of the ", stringify!($rows), "x", stringify!($cols), " ", $what, " in row-major order.")]
+            pub fn from_rows_array(a: [$s; $cols * $rows]) -> $t {
+                let mut m = $t::default();
+                for i in 0..$rows {
+                    for j in 0..$cols {
+                        m[j][i] = a[i * $cols + j];
+                    }
+                }
+                m
+            }
+
+            #[doc = concat!("Returns a raw pointer to the entries of this ", $what, ", which are laid")]
+            /// out contiguously in column-major order, see `to_cols_array`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use math_engine::", $module, "::", stringify!($t), ";")]
+            #[doc = concat!("let m = ", stringify!($t), "::identity();")]
+            #[doc = concat!("assert!(unsafe { *m.as_ptr().add(", stringify!($rows), " + 1) } == 1.0);")]
+            /// ```
+            pub fn as_ptr(&self) -> *const $s {
+                (self as *const $t).cast::<$s>()
+            }
+
+            #[doc = concat!("Returns the entries of this ", $what, " as a slice in column-major order,")]
+            /// see `to_cols_array`.
+            pub fn as_slice(&self) -> &[$s] {
+                // SAFETY: the type is repr(C) over its columns of contiguous
+                // components with no padding, so it is cols * rows
+                // contiguous scalars.
+                unsafe { core::slice::from_raw_parts(self.as_ptr(), $cols * $rows) }
+            }
+        }
+    };
+}
+
+components!(Vector2, f32, 2, x: 0, y: 1);
+components!(Vector3, f32, 3, x: 0, y: 1, z: 2);
+components!(Vector4, f32, 4, x: 0, y: 1, z: 2, w: 3);
+components!(Point2, f32, 2, x: 0, y: 1);
+components!(Point3, f32, 3, x: 0, y: 1, z: 2);
+components!(Quarternion, f32, 4, x: 0, y: 1, z: 2, w: 3);
+components!(RGBA, f32, 4, r: 0, g: 1, b: 2, a: 3);
+// RGB has had its array conversions since before this module.
+tuples!(RGB, f32, r: 0, g: 1, b: 2);
+slice!(RGB, f32, 3);
+columns!(Matrix2, f32, 2, 2, "matrix", "matrix2");
+columns!(Matrix3, f32, 3, 3, "matrix", "matrix3");
+columns!(Matrix4, f32, 4, 4, "matrix", "matrix4");
+columns!(Transform2, f32, 3, 2, "transform", "transform2");
+columns!(Transform4, f32, 4, 3, "transform", "transform4");
+
+components!(DVector2, f64, 2, x: 0, y: 1);
+components!(DVector3, f64, 3, x: 0, y: 1, z: 2);
+components!(DVector4, f64, 4, x: 0, y: 1, z: 2, w: 3);
+components!(DPoint2, f64, 2, x: 0, y: 1);
+components!(DPoint3, f64, 3, x: 0, y: 1, z: 2);
+components!(DQuarternion, f64, 4, x: 0, y: 1, z: 2, w: 3);
+columns!(DMatrix2, f64, 2, 2, "matrix", "dmatrix2");
+columns!(DMatrix3, f64, 3, 3, "matrix", "dmatrix3");
+columns!(DMatrix4, f64, 4, 4, "matrix", "dmatrix4");
+columns!(DTransform4, f64, 4, 3, "transform", "dtransform4");
+
+#[test]
+fn round_trips() {
+    let v = Vector3::from([1.0, 2.0, 3.0]);
+    assert!(v == Vector3::new(1.0, 2.0, 3.0));
+    assert!(<(f32, f32, f32)>::from(v) == (1.0, 2.0, 3.0));
+    assert!(Point2::from((4.0, 5.0)) == Point2::new(4.0, 5.0));
+    assert!(Quarternion::try_from(&[0.0, 0.0, 0.0, 1.0][..]).ok() == Some(Quarternion::IDENTITY));
+    assert!(Vector4::try_from(&[1.0f32, 2.0, 3.0][..]).is_err());
+    assert!(RGB::try_from(&[0.25f32, 0.5, 1.0][..]).ok() == Some(RGB::new(0.25, 0.5, 1.0)));
+
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let a: [f32; 9] = m.into();
+    assert!(a == [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]);
+    assert!(Matrix3::from(a) == m);
+    let h = Transform4::make_translation(&Vector3::new(5.0, 6.0, 7.0));
+    let a: [f32; 12] = h.into();
+    assert!(a[9..] == [5.0, 6.0, 7.0]);
+    assert!(Transform4::try_from(&a[..]).ok() == Some(h));
+
+    // The inherent array methods use the same layouts.
+    assert!(m.to_cols_array() == [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]);
+    assert!(m.to_rows_array() == [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    assert!(m.to_cols_array_2d()[2] == [3.0, 6.0, 9.0]);
+    assert!(m.as_slice() == m.to_cols_array());
+    assert!(unsafe { *m.as_ptr().add(1) } == 4.0);
+    assert!(Matrix3::from_rows_array(m.to_rows_array()) == m);
+    assert!(h.to_cols_array()[9..] == [5.0, 6.0, 7.0]);
+    assert!(h.to_rows_array()[..4] == [1.0, 0.0, 0.0, 5.0]);
+    assert!(Transform4::from_rows_array(h.to_rows_array()) == h);
+    let m = Matrix4::from_cols_array(&[
+        1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 5.0, 6.0, 7.0, 1.0,
+    ]);
+    assert!(m[(0, 3)] == 5.0 && m[(2, 3)] == 7.0);
+    assert!(DMatrix4::from(m).to_rows_array() == m.to_rows_array().map(f64::from));
+    let t = Transform2::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    assert!(t.to_rows_array() == [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let d = DTransform4::from(h);
+    assert!(DTransform4::from_rows_array(d.to_rows_array()) == d);
+    assert!(d.as_slice()[9..] == [5.0, 6.0, 7.0]);
+}
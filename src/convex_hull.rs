@@ -0,0 +1,299 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The convex hull of a set of points, as a closed triangle mesh whose faces
+/// wind counterclockwise seen from outside, with the plane of each face.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConvexHull {
+    vertices: Vec<Point3>,
+    triangles: Vec<[usize; 3]>,
+    planes: Vec<Plane>,
+    // The distance from a face within which points count as on it.
+    eps: f32,
+}
+
+// A face while the hull is being built, with the points still outside it.
+struct Face {
+    v: [usize; 3],
+    normal: Vector3,
+    d: f32,
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+impl Face {
+    fn distance(&self, p: &Point3) -> f32 {
+        self.normal.dot(&Vector3::from(*p)) - self.d
+    }
+}
+
+impl ConvexHull {
+    /// Returns the convex hull of the points, built by quickhull, or None if
+    /// they all lie on one plane. Points within a small tolerance of a face,
+    /// scaled to the extent of the input, are left off the hull.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points to enclose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::convex_hull::ConvexHull;
+    /// use math_engine::point3::Point3;
+    /// let mut points = vec![Point3::new(0.2, 0.3, 0.1), Point3::new(0.5, 0.5, 0.5)];
+    /// for i in 0..8 {
+    ///     let c = |b: usize| if i & b != 0 { 1.0 } else { 0.0 };
+    ///     points.push(Point3::new(c(1), c(2), c(4)));
+    /// }
+    /// let hull = ConvexHull::new(&points).unwrap();
+    /// // The corners of the cube, two triangles to each of its faces.
+    /// assert!(hull.vertices().len() == 8 && hull.triangles().len() == 12);
+    /// assert!(hull.contains_point(&Point3::new(0.5, 0.5, 0.9)));
+    /// ```
+    pub fn new(points: &[Point3]) -> Option<ConvexHull> {
+        let scale = points.iter().fold(Vector3::new(0.0, 0.0, 0.0), |m, p| {
+            Vector3::new(m.x.max(p.x.abs()), m.y.max(p.y.abs()), m.z.max(p.z.abs()))
+        });
+        let eps = 3.0 * f32::EPSILON * (scale.x + scale.y + scale.z);
+        let [a, b, c, d] = initial_simplex(points, eps)?;
+
+        let mut faces: Vec<Face> = Vec::new();
+        let mut edges: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+        // Wind the first face away from the fourth point, and close the
+        // tetrahedron with faces on the reverse of each of its edges.
+        let n = (points[b] - points[a]).cross(&(points[c] - points[a]));
+        let (b, c) = if n.dot(&(points[d] - points[a])) > 0.0 {
+            (c, b)
+        } else {
+            (b, c)
+        };
+        for v in [[a, b, c], [b, a, d], [c, b, d], [a, c, d]] {
+            add_face(points, &mut faces, &mut edges, v);
+        }
+        for (i, p) in points.iter().enumerate() {
+            if [a, b, c, d].contains(&i) {
+                continue;
+            }
+            if let Some(f) = faces.iter_mut().find(|f| f.distance(p) > eps) {
+                f.outside.push(i);
+            }
+        }
+
+        let mut visited = Vec::new();
+        while let Some(seed) = faces.iter().position(|f| f.alive && !f.outside.is_empty()) {
+            // Take the point furthest out from the face.
+            let face = &faces[seed];
+            let &apex = face
+                .outside
+                .iter()
+                .max_by(|&&i, &&j| {
+                    face.distance(&points[i])
+                        .total_cmp(&face.distance(&points[j]))
+                })
+                .unwrap_or(&face.outside[0]);
+            let p = points[apex];
+            // Flood out from the seed over the faces that can see the point;
+            // the edges to those that cannot form the horizon.
+            visited.clear();
+            visited.resize(faces.len(), false);
+            visited[seed] = true;
+            let (mut stack, mut visible, mut horizon) = (vec![seed], vec![seed], Vec::new());
+            while let Some(f) = stack.pop() {
+                let v = faces[f].v;
+                for k in 0..3 {
+                    let (i, j) = (v[k], v[(k + 1) % 3]);
+                    let g = edges[&(j, i)];
+                    if visited[g] {
+                        continue;
+                    }
+                    if faces[g].distance(&p) > eps {
+                        visited[g] = true;
+                        stack.push(g);
+                        visible.push(g);
+                    } else {
+                        horizon.push((i, j));
+                    }
+                }
+            }
+            let mut orphans = Vec::new();
+            for &f in &visible {
+                let face = &mut faces[f];
+                face.alive = false;
+                orphans.append(&mut face.outside);
+                for k in 0..3 {
+                    edges.remove(&(face.v[k], face.v[(k + 1) % 3]));
+                }
+            }
+            let first = faces.len();
+            for (i, j) in horizon {
+                add_face(points, &mut faces, &mut edges, [i, j, apex]);
+            }
+            for i in orphans {
+                if i == apex {
+                    continue;
+                }
+                if let Some(f) = faces[first..]
+                    .iter_mut()
+                    .find(|f| f.distance(&points[i]) > eps)
+                {
+                    f.outside.push(i);
+                }
+            }
+        }
+
+        // Keep the faces left standing and the points they use.
+        let mut index = vec![usize::MAX; points.len()];
+        let mut hull = ConvexHull {
+            eps,
+            ..ConvexHull::default()
+        };
+        for f in faces.iter().filter(|f| f.alive) {
+            let v = f.v.map(|i| {
+                if index[i] == usize::MAX {
+                    index[i] = hull.vertices.len();
+                    hull.vertices.push(points[i]);
+                }
+                index[i]
+            });
+            hull.triangles.push(v);
+            let n = f.normal;
+            hull.planes.push(Plane::new(n.x, n.y, n.z, -f.d));
+        }
+        Some(hull)
+    }
+
+    /// Returns the corners of this hull.
+    pub fn vertices(&self) -> &[Point3] {
+        &self.vertices
+    }
+
+    /// Returns the faces of this hull as indices into `vertices`, wound
+    /// counterclockwise seen from outside.
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    /// Returns the plane of each face, in the order of `triangles`, with unit
+    /// normals pointing out of the hull.
+    pub fn planes(&self) -> &[Plane] {
+        &self.planes
+    }
+
+    /// Returns true if the point p lies inside or on this hull, counting
+    /// points within the tolerance used to build it as on a face, so that
+    /// every point the hull was built from is contained.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point3.
+    pub fn contains_point(&self, p: &Point3) -> bool {
+        self.planes.iter().all(|f| f.point_dot(p) <= self.eps)
+    }
+}
+
+// Adds the face with corners v, recording it as the face that holds each
+// of its edges in that direction.
+fn add_face(
+    points: &[Point3],
+    faces: &mut Vec<Face>,
+    edges: &mut BTreeMap<(usize, usize), usize>,
+    v: [usize; 3],
+) {
+    let (pa, pb, pc) = (points[v[0]], points[v[1]], points[v[2]]);
+    let normal = (pb - pa).cross(&(pc - pa)).normalize_or_zero();
+    for k in 0..3 {
+        edges.insert((v[k], v[(k + 1) % 3]), faces.len());
+    }
+    faces.push(Face {
+        v,
+        normal,
+        d: normal.dot(&Vector3::from(pa)),
+        outside: Vec::new(),
+        alive: true,
+    });
+}
+
+// Returns four points spanning a tetrahedron of nonzero volume, or None if
+// the points are coplanar.
+fn initial_simplex(points: &[Point3], eps: f32) -> Option<[usize; 4]> {
+    let furthest = |score: &dyn Fn(&Point3) -> f32| {
+        (0..points.len()).max_by(|&i, &j| score(&points[i]).total_cmp(&score(&points[j])))
+    };
+    let a = furthest(&|p| -p.x)?;
+    let pa = points[a];
+    let b = furthest(&|p| p.distance_squared(&pa))?;
+    let ab = points[b] - pa;
+    if ab.magnitude() <= eps {
+        return None;
+    }
+    let c = furthest(&|p| (*p - pa).cross(&ab).magnitude())?;
+    let n = ab.cross(&(points[c] - pa)).normalize_or_zero();
+    if (points[c] - pa).cross(&ab).magnitude() <= eps * ab.magnitude() {
+        return None;
+    }
+    let d = furthest(&|p| n.dot(&(*p - pa)).abs())?;
+    if n.dot(&(points[d] - pa)).abs() <= eps {
+        return None;
+    }
+    Some([a, b, c, d])
+}
+
+#[test]
+fn hulls() {
+    // Points spread over a sphere are all corners, and the hull holds every
+    // input.
+    let mut points = Vec::new();
+    let golden = core::f32::consts::PI * (3.0 - 5.0f32.sqrt());
+    for i in 0..500 {
+        let z = 1.0 - 2.0 * (i as f32 + 0.5) / 500.0;
+        let (r, phi) = ((1.0 - z * z).sqrt(), golden * i as f32);
+        let v = Vector3::new(r * phi.cos(), r * phi.sin(), z);
+        points.push(Point3::new(10.0, 0.0, 0.0) + v * 3.0);
+    }
+    for i in 0..200 {
+        points.push(Point3::new(10.0, 0.0, 0.0) + Vector3::new(0.0, 0.01 * i as f32, 0.0));
+    }
+    let hull = ConvexHull::new(&points).unwrap();
+    assert!(hull.vertices().len() == 500);
+    // A closed mesh of genus zero: V - E + F = 2 with E = 3F / 2.
+    let f = hull.triangles().len();
+    assert!(f == 2 * hull.vertices().len() - 4);
+    let slack = 1e-4;
+    for (t, plane) in hull.triangles().iter().zip(hull.planes()) {
+        assert!(t
+            .iter()
+            .all(|&i| plane.point_dot(&hull.vertices()[i]).abs() < slack));
+        assert!(points.iter().all(|p| plane.point_dot(p) < slack));
+    }
+    assert!(points.iter().all(|p| hull.contains_point(p)));
+    // A lattice is full of coplanar points, which must not split its faces
+    // into anything but the cube's twelve triangles.
+    let mut lattice = Vec::new();
+    for i in 0..125 {
+        lattice.push(Point3::new(
+            (i % 5) as f32,
+            (i / 5 % 5) as f32,
+            (i / 25) as f32,
+        ));
+    }
+    let cube = ConvexHull::new(&lattice).unwrap();
+    assert!(cube.vertices().len() == 8 && cube.triangles().len() == 12);
+    assert!(lattice.iter().all(|p| cube.contains_point(p)));
+    // Coplanar and collinear input has no hull.
+    let flat = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+    ];
+    assert!(ConvexHull::new(&flat).is_none());
+    assert!(ConvexHull::new(&flat[..2]).is_none());
+    assert!(ConvexHull::new(&[]).is_none());
+}
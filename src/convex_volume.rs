@@ -0,0 +1,260 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::plane::three_planes_intersect;
+use crate::prelude::*;
+use alloc::vec::Vec;
+
+/// A convex region of space bounded by planes whose normals point into it,
+/// such as a light volume or a trigger region. Like a `Frustum`, but with any
+/// number of planes; it may be unbounded or empty.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConvexVolume {
+    planes: Vec<Plane>,
+}
+
+// The distance, relative to the size of the coordinates, within which a
+// point counts as lying on a plane when enumerating corners.
+const EPSILON: f32 = 1.0e-4;
+
+impl ConvexVolume {
+    /// Returns the region on the inner side of every one of the planes. The
+    /// planes are normalized, so they can be given at any scale.
+    ///
+    /// # Arguments
+    ///
+    /// * `planes` - The bounding planes, with normals pointing inward.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::convex_volume::ConvexVolume;
+    /// use math_engine::plane::Plane;
+    /// use math_engine::point3::Point3;
+    /// // The half-space above z = 1, cut by the slab 0 <= x <= 2.
+    /// let v = ConvexVolume::new(vec![
+    ///     Plane::new(0.0, 0.0, 2.0, -2.0),
+    ///     Plane::new(1.0, 0.0, 0.0, 0.0),
+    ///     Plane::new(-1.0, 0.0, 0.0, 2.0),
+    /// ]);
+    /// assert!(v.contains_point(&Point3::new(1.0, 50.0, 3.0)));
+    /// assert!(!v.contains_point(&Point3::new(3.0, 0.0, 3.0)));
+    /// ```
+    pub fn new(planes: Vec<Plane>) -> ConvexVolume {
+        ConvexVolume {
+            planes: planes.iter().map(Plane::normalize).collect(),
+        }
+    }
+
+    /// Returns the volume bounded by the six planes of the frustum f.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A reference to a frustum.
+    pub fn from_frustum(f: &Frustum) -> ConvexVolume {
+        ConvexVolume::new(f.planes.to_vec())
+    }
+
+    /// Returns the volume bounded by the six faces of the box b.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - A reference to an aabb3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::convex_volume::ConvexVolume;
+    /// use math_engine::point3::Point3;
+    /// let b = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 2.0, 3.0));
+    /// let v = ConvexVolume::from_aabb(&b);
+    /// assert!(v.planes().len() == 6 && v.vertices().len() == 8);
+    /// ```
+    pub fn from_aabb(b: &Aabb3) -> ConvexVolume {
+        ConvexVolume::new(alloc::vec![
+            Plane::new(1.0, 0.0, 0.0, -b.min.x),
+            Plane::new(-1.0, 0.0, 0.0, b.max.x),
+            Plane::new(0.0, 1.0, 0.0, -b.min.y),
+            Plane::new(0.0, -1.0, 0.0, b.max.y),
+            Plane::new(0.0, 0.0, 1.0, -b.min.z),
+            Plane::new(0.0, 0.0, -1.0, b.max.z),
+        ])
+    }
+
+    /// Returns the bounding planes of this volume, normalized, with normals
+    /// pointing inward.
+    pub fn planes(&self) -> &[Plane] {
+        &self.planes
+    }
+
+    /// Returns true if the point p lies inside or on this volume.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point3.
+    pub fn contains_point(&self, p: &Point3) -> bool {
+        self.planes.iter().all(|f| f.point_dot(p) >= 0.0)
+    }
+
+    /// Returns true unless the sphere s lies entirely outside one of the
+    /// planes of this volume. As with `Frustum::intersects_sphere`, spheres
+    /// near an edge or corner may be reported as intersecting when they are
+    /// not.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - A reference to a sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::convex_volume::ConvexVolume;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// let v = ConvexVolume::from_aabb(&Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0)));
+    /// assert!(v.intersects_sphere(&Sphere::new(Point3::new(1.5, 0.5, 0.5), 1.0)));
+    /// assert!(!v.intersects_sphere(&Sphere::new(Point3::new(0.5, -1.5, 0.5), 1.0)));
+    /// ```
+    pub fn intersects_sphere(&self, s: &Sphere) -> bool {
+        self.planes
+            .iter()
+            .all(|f| f.point_dot(&s.center) >= -s.radius)
+    }
+
+    /// Returns true unless the box b lies entirely outside one of the planes
+    /// of this volume, testing against each plane the corner of the box
+    /// furthest along its normal. Like `intersects_sphere` the test is
+    /// conservative.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - A reference to an aabb3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::convex_volume::ConvexVolume;
+    /// use math_engine::plane::Plane;
+    /// use math_engine::point3::Point3;
+    /// // The half-space x + y >= 2.
+    /// let v = ConvexVolume::new(vec![Plane::new(1.0, 1.0, 0.0, -2.0)]);
+    /// let b = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    /// assert!(v.intersects_aabb(&b));
+    /// assert!(!v.intersects_aabb(&Aabb3::new(b.min, Point3::new(0.9, 1.0, 1.0))));
+    /// ```
+    pub fn intersects_aabb(&self, b: &Aabb3) -> bool {
+        self.planes.iter().all(|f| {
+            let corner = Point3::new(
+                if f.x >= 0.0 { b.max.x } else { b.min.x },
+                if f.y >= 0.0 { b.max.y } else { b.min.y },
+                if f.z >= 0.0 { b.max.z } else { b.min.z },
+            );
+            f.point_dot(&corner) >= 0.0
+        })
+    }
+
+    /// Returns the corners of this volume: the points where three of its
+    /// planes meet that lie inside the rest. Corners shared by more than
+    /// three planes are only listed once. An unbounded volume gives the
+    /// corners it has, and an empty one none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::convex_volume::ConvexVolume;
+    /// use math_engine::plane::Plane;
+    /// use math_engine::point3::Point3;
+    /// // The corner of the positive octant, cut off by x + y + z <= 1.
+    /// let v = ConvexVolume::new(vec![
+    ///     Plane::new(1.0, 0.0, 0.0, 0.0),
+    ///     Plane::new(0.0, 1.0, 0.0, 0.0),
+    ///     Plane::new(0.0, 0.0, 1.0, 0.0),
+    ///     Plane::new(-1.0, -1.0, -1.0, 1.0),
+    /// ]);
+    /// let corners = v.vertices();
+    /// assert!(corners.len() == 4);
+    /// assert!(corners.contains(&Point3::new(0.0, 1.0, 0.0)));
+    /// ```
+    pub fn vertices(&self) -> Vec<Point3> {
+        let n = self.planes.len();
+        let mut vertices: Vec<Point3> = Vec::new();
+        for i in 0..n {
+            for j in i + 1..n {
+                for k in j + 1..n {
+                    let p = match three_planes_intersect(
+                        &self.planes[i],
+                        &self.planes[j],
+                        &self.planes[k],
+                    ) {
+                        Some(p) => p,
+                        None => continue,
+                    };
+                    let eps = EPSILON * (1.0 + p.x.abs().max(p.y.abs()).max(p.z.abs()));
+                    if self.planes.iter().all(|f| f.point_dot(&p) >= -eps)
+                        && !vertices.iter().any(|q| q.distance(&p) <= eps)
+                    {
+                        vertices.push(p);
+                    }
+                }
+            }
+        }
+        vertices
+    }
+}
+
+impl From<Frustum> for ConvexVolume {
+    fn from(f: Frustum) -> Self {
+        ConvexVolume::from_frustum(&f)
+    }
+}
+
+impl From<Aabb3> for ConvexVolume {
+    fn from(b: Aabb3) -> Self {
+        ConvexVolume::from_aabb(&b)
+    }
+}
+
+#[test]
+fn convex_volumes() {
+    // A frustum's corners are the eight points the view volume spans.
+    let m = Matrix4::perspective_rh(1.0, 1.5, 1.0, 10.0);
+    let v = ConvexVolume::from(Frustum::from_matrix(&m));
+    let corners = v.vertices();
+    assert!(corners.len() == 8);
+    let inverse = m.inverse();
+    for i in 0..8 {
+        // Clip space spans [-1, 1] across and [0, 1] in depth.
+        let c = |b: usize| if i & b != 0 { 1.0 } else { 0.0 };
+        let p = inverse * Vector4::new(2.0 * c(1) - 1.0, 2.0 * c(2) - 1.0, c(4), 1.0);
+        let p = Point3::new(p.x / p.w, p.y / p.w, p.z / p.w);
+        assert!(corners.iter().any(|q| q.distance(&p) < 1e-3));
+    }
+    assert!(v.contains_point(&Point3::new(0.0, 0.0, -5.0)));
+    assert!(!v.contains_point(&Point3::new(0.0, 0.0, -11.0)));
+
+    // A cube cut by a plane through four of its corners leaves a prism.
+    let b = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    let mut planes = ConvexVolume::from(b).planes().to_vec();
+    planes.push(Plane::new(-1.0, -1.0, 0.0, 1.0));
+    let prism = ConvexVolume::new(planes);
+    assert!(prism.vertices().len() == 6);
+    assert!(prism.intersects_sphere(&Sphere::new(Point3::new(0.0, 0.0, 0.5), 0.1)));
+    assert!(!prism.intersects_sphere(&Sphere::new(Point3::new(1.0, 1.0, 0.5), 0.5)));
+    assert!(prism.intersects_aabb(&Aabb3::new(
+        Point3::new(-1.0, -1.0, 0.0),
+        Point3::new(0.1, 0.1, 1.0)
+    )));
+
+    // Disjoint half-spaces bound nothing.
+    let empty = ConvexVolume::new(alloc::vec![
+        Plane::new(1.0, 0.0, 0.0, -1.0),
+        Plane::new(-1.0, 0.0, 0.0, -1.0),
+        Plane::new(0.0, 1.0, 0.0, 0.0),
+        Plane::new(0.0, 0.0, 1.0, 0.0),
+    ]);
+    assert!(empty.vertices().is_empty());
+}
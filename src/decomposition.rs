@@ -0,0 +1,619 @@
+//! Matrix decompositions for `Matrix3` and `Matrix4`.
+//!
+//! These are small, fixed-size versions of the standard dense algorithms,
+//! written for the 3x3 and 4x4 cases that come up in geometry: inertia
+//! tensors, covariance matrices, best-fit rotations and small least-squares
+//! problems.
+
+use crate::prelude::*;
+
+// The most sweeps the Jacobi eigenvalue iteration makes. Jacobi converges
+// quadratically, so a 3x3 matrix is diagonal to f32 precision after a
+// handful of sweeps; the limit only guards against pathological input.
+const MAX_SWEEPS: usize = 32;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The singular value decomposition m = u * diag(sigma) * v_t of a `Matrix3`,
+/// as returned by `Matrix3::svd`.
+pub struct Svd3 {
+    /// The orthogonal matrix of left singular vectors.
+    pub u: Matrix3,
+    /// The singular values in descending order, all non-negative.
+    pub sigma: Vector3,
+    /// The transpose of the orthogonal matrix of right singular vectors.
+    pub v_t: Matrix3,
+}
+
+impl Svd3 {
+    /// Returns the matrix u * diag(sigma) * v_t.
+    pub fn recompose(&self) -> Matrix3 {
+        self.u * Matrix3::from_diagonal(&self.sigma) * self.v_t
+    }
+}
+
+impl Matrix3 {
+    /// Returns the eigenvalues and eigenvectors of this symmetric matrix.
+    ///
+    /// The eigenvalues are returned in descending order, and column i of the
+    /// returned matrix is the unit eigenvector for eigenvalue i. The
+    /// eigenvectors are orthonormal and form a right-handed basis, so the
+    /// matrix is a rotation, and `m == v * diag(values) * v^T`.
+    ///
+    /// The decomposition uses cyclic Jacobi iteration, which zeroes the
+    /// off-diagonal entries with a sequence of plane rotations until they are
+    /// negligible relative to the matrix. Only the upper triangle is read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::vector3::Vector3;
+    /// let m = Matrix3::new(2.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 5.0);
+    /// let (values, vectors) = m.symmetric_eigen();
+    /// assert!((values - Vector3::new(5.0, 3.0, 1.0)).magnitude() < 1e-5);
+    /// for i in 0..3 {
+    ///     let v = vectors[i];
+    ///     assert!((m * v - v * values[i]).magnitude() < 1e-5);
+    /// }
+    /// ```
+    pub fn symmetric_eigen(&self) -> (Vector3, Matrix3) {
+        let mut a = *self;
+        for (r, c) in [(1, 0), (2, 0), (2, 1)] {
+            a[(r, c)] = a[(c, r)];
+        }
+        let mut v = Matrix3::identity();
+
+        let norm = (0..3).map(|i| a[i].dot(&a[i])).sum::<f32>();
+        for _ in 0..MAX_SWEEPS {
+            let off = a[(0, 1)] * a[(0, 1)] + a[(0, 2)] * a[(0, 2)] + a[(1, 2)] * a[(1, 2)];
+            if off <= norm * f32::EPSILON * f32::EPSILON {
+                break;
+            }
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                let apq = a[(p, q)];
+                if apq == 0.0 {
+                    continue;
+                }
+                // The rotation angle that zeroes a[p][q], taking the smaller
+                // root for stability.
+                let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for k in 0..3 {
+                    let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                    a[(k, p)] = c * akp - s * akq;
+                    a[(k, q)] = s * akp + c * akq;
+                }
+                for k in 0..3 {
+                    let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                    a[(p, k)] = c * apk - s * aqk;
+                    a[(q, k)] = s * apk + c * aqk;
+                }
+                for k in 0..3 {
+                    let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                    v[(k, p)] = c * vkp - s * vkq;
+                    v[(k, q)] = s * vkp + c * vkq;
+                }
+            }
+        }
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| a[(j, j)].total_cmp(&a[(i, i)]));
+        let values = Vector3::new(
+            a[(order[0], order[0])],
+            a[(order[1], order[1])],
+            a[(order[2], order[2])],
+        );
+        let mut vectors = Matrix3::new_with_vecs(v[order[0]], v[order[1]], v[order[2]]);
+        if vectors.determinant() < 0.0 {
+            vectors[2] *= -1.0;
+        }
+        (values, vectors)
+    }
+
+    /// Returns the singular value decomposition of this matrix.
+    ///
+    /// The decomposition uses one-sided Jacobi iteration: plane rotations
+    /// applied on the right make the columns of the matrix mutually
+    /// orthogonal, and those rotations accumulate into v. A sweep visits
+    /// each pair of columns once, and iteration stops after the first sweep
+    /// in which every pair is already orthogonal to within `f32::EPSILON`
+    /// relative to the column lengths, or after a fixed number of sweeps.
+    /// The singular values are then the column lengths and u is the
+    /// normalized columns. For rank-deficient input, the columns of u for
+    /// zero singular values are completed to an orthonormal basis.
+    ///
+    /// Working on the matrix directly rather than on m^T * m keeps the small
+    /// singular values accurate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::vector3::Vector3;
+    /// let m = Matrix3::make_rotation_z(0.5) * Matrix3::make_scale(1.0, 3.0, 2.0);
+    /// let svd = m.svd();
+    /// assert!((svd.sigma - Vector3::new(3.0, 2.0, 1.0)).magnitude() < 1e-5);
+    /// let r = svd.recompose();
+    /// assert!((0..3).all(|i| (r[i] - m[i]).magnitude() < 1e-5));
+    /// ```
+    pub fn svd(&self) -> Svd3 {
+        let mut a = *self;
+        let mut v = Matrix3::identity();
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                let alpha = a[p].dot(&a[p]);
+                let beta = a[q].dot(&a[q]);
+                let gamma = a[p].dot(&a[q]);
+                if gamma.abs() <= f32::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + (zeta * zeta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                let (ap, aq) = (a[p], a[q]);
+                a[p] = ap * c - aq * s;
+                a[q] = ap * s + aq * c;
+                let (vp, vq) = (v[p], v[q]);
+                v[p] = vp * c - vq * s;
+                v[q] = vp * s + vq * c;
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut order = [0, 1, 2];
+        let lengths = Vector3::new(a[0].magnitude(), a[1].magnitude(), a[2].magnitude());
+        order.sort_by(|&i, &j| lengths[j].total_cmp(&lengths[i]));
+        let sigma = Vector3::new(lengths[order[0]], lengths[order[1]], lengths[order[2]]);
+        let v = Matrix3::new_with_vecs(v[order[0]], v[order[1]], v[order[2]]);
+
+        // Columns of u whose singular value is negligible carry no
+        // information from a, so they are chosen to complete the basis.
+        let tiny = sigma.x * 3.0 * f32::EPSILON;
+        let mut u = Matrix3::identity();
+        if sigma.x > 0.0 {
+            u[0] = a[order[0]] / sigma.x;
+            u[1] = if sigma.y > tiny {
+                a[order[1]] / sigma.y
+            } else {
+                u[0].any_orthonormal_pair().0
+            };
+            u[2] = if sigma.z > tiny {
+                a[order[2]] / sigma.z
+            } else {
+                u[0].cross(&u[1])
+            };
+        }
+        Svd3 {
+            u,
+            sigma,
+            v_t: v.transpose(),
+        }
+    }
+
+    /// Returns the polar decomposition of this matrix as a rotation r and a
+    /// symmetric positive semi-definite stretch s with m = r * s.
+    ///
+    /// When m contains a reflection, r is still a proper rotation and the
+    /// reflection is folded into s, which then has one negative eigenvalue.
+    /// r is the rotation closest to m, which makes this the way to extract a
+    /// best-fit rotation from a matrix that has drifted or been sheared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// let rotation = Matrix3::make_rotation_y(0.8);
+    /// let m = rotation * Matrix3::make_scale(2.0, 0.5, 1.5);
+    /// let (r, s) = m.polar_decomposition();
+    /// assert!((0..3).all(|i| (r[i] - rotation[i]).magnitude() < 1e-5));
+    /// assert!((0..3).all(|i| ((r * s)[i] - m[i]).magnitude() < 1e-5));
+    /// ```
+    pub fn polar_decomposition(&self) -> (Matrix3, Matrix3) {
+        let Svd3 {
+            mut u,
+            mut sigma,
+            v_t,
+        } = self.svd();
+        if (u * v_t).determinant() < 0.0 {
+            u[2] *= -1.0;
+            sigma.z = -sigma.z;
+        }
+        let r = u * v_t;
+        let s = v_t.transpose() * Matrix3::from_diagonal(&sigma) * v_t;
+        (r, s)
+    }
+}
+
+impl Matrix3 {
+    /// Returns the QR decomposition of this matrix, an orthogonal q and an
+    /// upper triangular r with m = q * r.
+    ///
+    /// The decomposition uses Householder reflections, which keep q
+    /// orthogonal to working precision even when the columns of m are
+    /// nearly dependent. The diagonal of r is made non-negative, so for an
+    /// invertible matrix the decomposition is unique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// let m = Matrix3::new(12.0, -51.0, 4.0, 6.0, 167.0, -68.0, -4.0, 24.0, -41.0);
+    /// let (q, r) = m.qr();
+    /// assert!(r.at(1, 0) == 0.0 && r.at(2, 0) == 0.0 && r.at(2, 1) == 0.0);
+    /// assert!((r.at(0, 0) - 14.0).abs() < 1e-4);
+    /// let p = q * r;
+    /// assert!((0..3).all(|i| (p[i] - m[i]).magnitude() < 1e-3));
+    /// ```
+    pub fn qr(&self) -> (Matrix3, Matrix3) {
+        let a = [0, 1, 2].map(|i| [0, 1, 2].map(|j| self[(i, j)]));
+        let (q, r) = householder_qr(a);
+        let mut mq = Matrix3::default();
+        let mut mr = Matrix3::default();
+        for i in 0..3 {
+            for j in 0..3 {
+                mq[(i, j)] = q[i][j];
+                mr[(i, j)] = r[i][j];
+            }
+        }
+        (mq, mr)
+    }
+
+    /// Returns this matrix with its columns made orthonormal by modified
+    /// Gram–Schmidt: the first column is normalized and each later column
+    /// has its components along the earlier ones removed before it is
+    /// normalized. Applied to a rotation matrix that has drifted after many
+    /// incremental multiplications, it returns a nearby exact rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// let mut m = Matrix3::identity();
+    /// for _ in 0..10000 {
+    ///     m = m * Matrix3::make_rotation_x(0.01) * Matrix3::make_rotation_y(0.02);
+    /// }
+    /// let r = m.orthonormalize();
+    /// let p = r * r.transpose();
+    /// assert!((0..3).all(|i| (p[i] - Matrix3::identity()[i]).magnitude() < 1e-6));
+    /// ```
+    pub fn orthonormalize(&self) -> Matrix3 {
+        let mut m = *self;
+        for i in 0..3 {
+            let mut c = m[i];
+            for j in 0..i {
+                c = c - m[j] * c.dot(&m[j]);
+            }
+            m[i] = c.normalize();
+        }
+        m
+    }
+}
+
+impl Matrix4 {
+    /// Returns the QR decomposition of this matrix, an orthogonal q and an
+    /// upper triangular r with m = q * r. See `Matrix3::qr`.
+    pub fn qr(&self) -> (Matrix4, Matrix4) {
+        let a = [0, 1, 2, 3].map(|i| [0, 1, 2, 3].map(|j| self[(i, j)]));
+        let (q, r) = householder_qr(a);
+        let mut mq = Matrix4::default();
+        let mut mr = Matrix4::default();
+        for i in 0..4 {
+            for j in 0..4 {
+                mq[(i, j)] = q[i][j];
+                mr[(i, j)] = r[i][j];
+            }
+        }
+        (mq, mr)
+    }
+
+    /// Returns this matrix with its columns made orthonormal by modified
+    /// Gram–Schmidt. See `Matrix3::orthonormalize`.
+    pub fn orthonormalize(&self) -> Matrix4 {
+        let mut m = *self;
+        for i in 0..4 {
+            let mut c = m[i];
+            for j in 0..i {
+                c = c - m[j] * c.dot(&m[j]);
+            }
+            m[i] = c.normalize();
+        }
+        m
+    }
+}
+
+// Returns q and r, as arrays of rows, with a = q * r, q orthogonal and r
+// upper triangular with a non-negative diagonal.
+#[allow(clippy::needless_range_loop)]
+fn householder_qr<const N: usize>(a: [[f32; N]; N]) -> ([[f32; N]; N], [[f32; N]; N]) {
+    let mut r = a;
+    let mut q = [[0.0; N]; N];
+    for i in 0..N {
+        q[i][i] = 1.0;
+    }
+    for k in 0..N - 1 {
+        // The reflection that maps column k below the diagonal onto a
+        // multiple of the k-th axis, with the sign chosen to avoid
+        // cancellation.
+        let mut v = [0.0; N];
+        for i in k..N {
+            v[i] = r[i][k];
+        }
+        let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        v[k] += if v[k] < 0.0 { -norm } else { norm };
+        let vv = v.iter().map(|x| x * x).sum::<f32>();
+        for j in 0..N {
+            let d = (k..N).map(|i| v[i] * r[i][j]).sum::<f32>() * 2.0 / vv;
+            for i in k..N {
+                r[i][j] -= d * v[i];
+            }
+        }
+        for i in 0..N {
+            let d = (k..N).map(|l| q[i][l] * v[l]).sum::<f32>() * 2.0 / vv;
+            for l in k..N {
+                q[i][l] -= d * v[l];
+            }
+        }
+    }
+    for i in 0..N {
+        for j in 0..i {
+            r[i][j] = 0.0;
+        }
+        if r[i][i] < 0.0 {
+            for j in i..N {
+                r[i][j] = -r[i][j];
+            }
+            for l in 0..N {
+                q[l][i] = -q[l][i];
+            }
+        }
+    }
+    (q, r)
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The Cholesky factorization m = l * l^T of a symmetric positive-definite
+/// `Matrix3`, as returned by `Matrix3::cholesky`.
+pub struct Cholesky3 {
+    /// The lower triangular factor, with a positive diagonal.
+    pub l: Matrix3,
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The Cholesky factorization m = l * l^T of a symmetric positive-definite
+/// `Matrix4`, as returned by `Matrix4::cholesky`.
+pub struct Cholesky4 {
+    /// The lower triangular factor, with a positive diagonal.
+    pub l: Matrix4,
+}
+
+impl Matrix3 {
+    /// Returns the Cholesky factorization of this symmetric positive-definite
+    /// matrix, or None if it is not positive-definite. Only the lower
+    /// triangle is read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::vector3::Vector3;
+    /// let m = Matrix3::new(4.0, 2.0, 0.0, 2.0, 5.0, 1.0, 0.0, 1.0, 3.0);
+    /// let c = m.cholesky().unwrap();
+    /// let x = c.solve(&Vector3::new(2.0, 1.0, 4.0));
+    /// assert!((m * x - Vector3::new(2.0, 1.0, 4.0)).magnitude() < 1e-5);
+    /// assert!(Matrix3::make_scale(1.0, -1.0, 1.0).cholesky().is_none());
+    /// ```
+    pub fn cholesky(&self) -> Option<Cholesky3> {
+        let a = [0, 1, 2].map(|i| [0, 1, 2].map(|j| self[(i, j)]));
+        let l = cholesky_factor(a)?;
+        let mut m = Matrix3::default();
+        for i in 0..3 {
+            for j in 0..=i {
+                m[(i, j)] = l[i][j];
+            }
+        }
+        Some(Cholesky3 { l: m })
+    }
+}
+
+impl Cholesky3 {
+    /// Returns the solution x of m * x = b, by forward substitution with l
+    /// and back substitution with l^T.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - A reference to a vector3, the right-hand side.
+    pub fn solve(&self, b: &Vector3) -> Vector3 {
+        let l = [0, 1, 2].map(|i| [0, 1, 2].map(|j| self.l[(i, j)]));
+        let x = cholesky_solve(&l, [b.x, b.y, b.z]);
+        Vector3::new(x[0], x[1], x[2])
+    }
+
+    /// Returns the determinant of the factored matrix, the square of the
+    /// product of the diagonal of l.
+    pub fn determinant(&self) -> f32 {
+        let d = self.l.at(0, 0) * self.l.at(1, 1) * self.l.at(2, 2);
+        d * d
+    }
+}
+
+impl Matrix4 {
+    /// Returns the Cholesky factorization of this symmetric positive-definite
+    /// matrix, or None if it is not positive-definite. See
+    /// `Matrix3::cholesky`.
+    pub fn cholesky(&self) -> Option<Cholesky4> {
+        let a = [0, 1, 2, 3].map(|i| [0, 1, 2, 3].map(|j| self[(i, j)]));
+        let l = cholesky_factor(a)?;
+        let mut m = Matrix4::default();
+        for i in 0..4 {
+            for j in 0..=i {
+                m[(i, j)] = l[i][j];
+            }
+        }
+        Some(Cholesky4 { l: m })
+    }
+}
+
+impl Cholesky4 {
+    /// Returns the solution x of m * x = b.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - A reference to a vector4, the right-hand side.
+    pub fn solve(&self, b: &Vector4) -> Vector4 {
+        let l = [0, 1, 2, 3].map(|i| [0, 1, 2, 3].map(|j| self.l[(i, j)]));
+        let x = cholesky_solve(&l, [b.x, b.y, b.z, b.w]);
+        Vector4::new(x[0], x[1], x[2], x[3])
+    }
+
+    /// Returns the determinant of the factored matrix.
+    pub fn determinant(&self) -> f32 {
+        let d = self.l.at(0, 0) * self.l.at(1, 1) * self.l.at(2, 2) * self.l.at(3, 3);
+        d * d
+    }
+}
+
+// Returns the lower triangular l, as an array of rows, with a = l * l^T, or
+// None if a pivot is not positive.
+#[allow(clippy::needless_range_loop)]
+fn cholesky_factor<const N: usize>(a: [[f32; N]; N]) -> Option<[[f32; N]; N]> {
+    let mut l = [[0.0; N]; N];
+    for j in 0..N {
+        let d = a[j][j] - (0..j).map(|k| l[j][k] * l[j][k]).sum::<f32>();
+        if d <= 0.0 || d.is_nan() {
+            return None;
+        }
+        l[j][j] = d.sqrt();
+        for i in j + 1..N {
+            let s = a[i][j] - (0..j).map(|k| l[i][k] * l[j][k]).sum::<f32>();
+            l[i][j] = s / l[j][j];
+        }
+    }
+    Some(l)
+}
+
+// Returns x with l * l^T * x = b.
+#[allow(clippy::needless_range_loop)]
+fn cholesky_solve<const N: usize>(l: &[[f32; N]; N], b: [f32; N]) -> [f32; N] {
+    let mut y = b;
+    for i in 0..N {
+        for k in 0..i {
+            y[i] -= l[i][k] * y[k];
+        }
+        y[i] /= l[i][i];
+    }
+    for i in (0..N).rev() {
+        for k in i + 1..N {
+            y[i] -= l[k][i] * y[k];
+        }
+        y[i] /= l[i][i];
+    }
+    y
+}
+
+#[test]
+fn symmetric_eigen() {
+    let m = Matrix3::new(4.0, -2.0, 1.5, -2.0, 3.0, 0.5, 1.5, 0.5, -1.0);
+    let (values, v) = m.symmetric_eigen();
+    assert!(values.x >= values.y && values.y >= values.z);
+    assert!((v.determinant() - 1.0).abs() < 1e-5);
+    let d = Matrix3::from_diagonal(&values);
+    let r = v * d * v.transpose();
+    assert!((0..3).all(|i| (r[i] - m[i]).magnitude() < 1e-4));
+    assert!((values.x + values.y + values.z - m.trace()).abs() < 1e-4);
+
+    // Repeated eigenvalues still give an orthonormal basis.
+    let (values, v) = Matrix3::identity().symmetric_eigen();
+    assert!(values == Vector3::new(1.0, 1.0, 1.0));
+    assert!(v == Matrix3::identity());
+}
+
+#[test]
+fn svd() {
+    let close = |a: &Matrix3, b: &Matrix3| (0..3).all(|i| (a[i] - b[i]).magnitude() < 1e-4);
+    let m = Matrix3::new(1.0, -2.0, 0.5, 3.0, 0.25, -1.0, -0.5, 2.0, 4.0);
+    let svd = m.svd();
+    assert!(svd.sigma.x >= svd.sigma.y && svd.sigma.y >= svd.sigma.z);
+    assert!(close(&svd.recompose(), &m));
+    assert!(close(&(svd.u.transpose() * svd.u), &Matrix3::identity()));
+    assert!(close(
+        &(svd.v_t * svd.v_t.transpose()),
+        &Matrix3::identity()
+    ));
+    let product = svd.sigma.x * svd.sigma.y * svd.sigma.z;
+    assert!((product - m.determinant().abs()).abs() < 1e-3);
+
+    // Rank one: u is still orthonormal.
+    let r = Matrix3::outer_product(&Vector3::new(1.0, 2.0, 2.0), &Vector3::new(0.0, 3.0, 4.0));
+    let svd = r.svd();
+    assert!((svd.sigma.x - 15.0).abs() < 1e-4 && svd.sigma.y < 1e-4);
+    assert!(close(&svd.recompose(), &r));
+    assert!(close(&(svd.u.transpose() * svd.u), &Matrix3::identity()));
+
+    // A reflection ends up in the stretch, not the rotation.
+    let f = Matrix3::make_rotation_x(0.3) * Matrix3::make_scale(1.0, -2.0, 3.0);
+    let (rot, s) = f.polar_decomposition();
+    assert!((rot.determinant() - 1.0).abs() < 1e-5);
+    assert!(close(&(rot * s), &f));
+    assert!(close(&s, &s.transpose()));
+}
+
+#[test]
+fn qr() {
+    let m = Matrix4::new(
+        2.0, -1.0, 0.5, 3.0, 1.0, 4.0, -2.0, 0.0, 0.0, 1.5, 1.0, -1.0, 0.5, 0.0, 2.0, 1.0,
+    );
+    let (q, r) = m.qr();
+    let p = q * r;
+    let qtq = q.transpose() * q;
+    for i in 0..4 {
+        assert!((p[i] - m[i]).magnitude() < 1e-5);
+        assert!((qtq[i] - Matrix4::identity()[i]).magnitude() < 1e-5);
+        assert!(r.at(i, i) >= 0.0);
+        for j in 0..i {
+            assert!(r.at(i, j) == 0.0);
+        }
+    }
+    let o = m.orthonormalize();
+    let oto = o.transpose() * o;
+    for i in 0..4 {
+        assert!((oto[i] - Matrix4::identity()[i]).magnitude() < 1e-5);
+    }
+    // Gram–Schmidt and QR agree on the orthonormal basis.
+    for i in 0..4 {
+        assert!((o[i] - q[i]).magnitude() < 1e-4);
+    }
+}
+
+#[test]
+fn cholesky() {
+    // Normal equations of a small least-squares fit are symmetric positive-definite.
+    let a = Matrix4::new(
+        1.0, 0.5, 0.0, 2.0, 0.0, 1.0, -1.0, 0.5, 3.0, 0.0, 1.0, 1.0, 0.5, 2.0, 0.0, 1.0,
+    );
+    let m = a.transpose() * a;
+    let c = m.cholesky().unwrap();
+    let llt = c.l * c.l.transpose();
+    for i in 0..4 {
+        assert!((llt[i] - m[i]).magnitude() < 1e-4);
+        for j in i + 1..4 {
+            assert!(c.l.at(i, j) == 0.0);
+        }
+    }
+    let b = Vector4::new(1.0, -2.0, 0.5, 3.0);
+    assert!((m * c.solve(&b) - b).magnitude() < 1e-3);
+    assert!((c.determinant() - m.determinant()).abs() < 1e-2 * m.determinant().abs());
+    assert!(Matrix4::default().cholesky().is_none());
+}
@@ -0,0 +1,172 @@
+//! `Display` implementations for the value types.
+//!
+//! Vectors, points, quaternions and colors print as a tuple of their
+//! components, `(1, 2, 3)`. Matrices and transforms print one row per line
+//! in logical (row, column) order, with each column right-aligned, so that
+//! they read the way they are written on paper. Transforms include their
+//! implicit last row. A precision such as `{:.3}` applies to every entry.
+
+use crate::prelude::*;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+macro_rules! components {
+    ($t:ty, $first:ident $(, $c:ident)*) => {
+        impl Display for $t {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("(")?;
+                Display::fmt(&self.$first, f)?;
+                $(
+                    f.write_str(", ")?;
+                    Display::fmt(&self.$c, f)?;
+                )*
+                f.write_str(")")
+            }
+        }
+    };
+}
+
+macro_rules! matrix {
+    ($t:ty, $rows:expr, $cols:expr) => {
+        impl Display for $t {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                grid(f, $rows, $cols, |i, j| self[j][i])
+            }
+        }
+    };
+    ($t:ty, $rows:expr, $cols:expr, affine) => {
+        impl Display for $t {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                grid(f, $rows + 1, $cols, |i, j| {
+                    if i < $rows {
+                        self[j][i]
+                    } else if j + 1 == $cols {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                })
+            }
+        }
+    };
+}
+
+// Writes a rows x cols grid of entries, one bracketed row per line, padding
+// every column to the width of its widest entry.
+fn grid<S: Display>(
+    f: &mut Formatter<'_>,
+    rows: usize,
+    cols: usize,
+    entry: impl Fn(usize, usize) -> S,
+) -> fmt::Result {
+    let cells: Vec<String> = (0..rows * cols)
+        .map(|k| {
+            let v = entry(k / cols, k % cols);
+            match f.precision() {
+                Some(p) => format!("{:.*}", p, v),
+                None => format!("{}", v),
+            }
+        })
+        .collect();
+    let widths: Vec<usize> = (0..cols)
+        .map(|j| {
+            (0..rows)
+                .map(|i| cells[i * cols + j].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    for i in 0..rows {
+        if i > 0 {
+            f.write_str("\n")?;
+        }
+        f.write_str("[ ")?;
+        for j in 0..cols {
+            let gap = if j > 0 { "  " } else { "" };
+            write!(f, "{}{:>w$}", gap, cells[i * cols + j], w = widths[j])?;
+        }
+        f.write_str(" ]")?;
+    }
+    Ok(())
+}
+
+components!(Vector2, x, y);
+components!(Vector3, x, y, z);
+components!(Vector4, x, y, z, w);
+components!(Point2, x, y);
+components!(Point3, x, y, z);
+components!(Quarternion, x, y, z, w);
+components!(Plane, x, y, z, w);
+components!(Rotor2, re, im);
+components!(RGB, r, g, b);
+components!(RGBA, r, g, b, a);
+components!(RGBu8, r, g, b);
+components!(RGBAu8, r, g, b, a);
+components!(RGBu32, r, g, b);
+components!(Xyz, x, y, z);
+components!(Lab, l, a, b);
+components!(YCbCr, y, cb, cr);
+components!(DVector2, x, y);
+components!(DVector3, x, y, z);
+components!(DVector4, x, y, z, w);
+components!(DPoint2, x, y);
+components!(DPoint3, x, y, z);
+components!(DQuarternion, x, y, z, w);
+components!(FixedVec2, x, y);
+components!(FixedVec3, x, y, z);
+matrix!(Matrix2, 2, 2);
+matrix!(Matrix3, 3, 3);
+matrix!(Matrix4, 4, 4);
+matrix!(Transform2, 2, 3, affine);
+matrix!(Transform4, 3, 4, affine);
+matrix!(DMatrix2, 2, 2);
+matrix!(DMatrix3, 3, 3);
+matrix!(DMatrix4, 4, 4);
+matrix!(DTransform4, 3, 4, affine);
+
+impl Display for UnitVector3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl Display for UnitQuaternion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_quarternion(), f)
+    }
+}
+
+impl Display for Fixed32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl Display for Radians {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)?;
+        f.write_str(" rad")
+    }
+}
+
+impl Display for Degrees {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)?;
+        f.write_str("°")
+    }
+}
+
+#[test]
+fn formatting() {
+    use alloc::string::ToString;
+
+    assert!(Vector3::new(1.0, -2.5, 3.0).to_string() == "(1, -2.5, 3)");
+    assert!(format!("{:.2}", Point2::new(0.5, 1.0)) == "(0.50, 1.00)");
+    assert!(Degrees(90.0).to_string() == "90°");
+    let m = Matrix3::new(1.0, -20.0, 3.0, 4.0, 5.0, 6.5, 7.0, 8.0, 9.0);
+    assert!(m.to_string() == "[ 1  -20    3 ]\n[ 4    5  6.5 ]\n[ 7    8    9 ]");
+    let h = Transform2::make_translation(&Vector2::new(5.0, -6.0));
+    assert!(format!("{:.1}", h) == "[ 1.0  0.0   5.0 ]\n[ 0.0  1.0  -6.0 ]\n[ 0.0  0.0   1.0 ]");
+}
@@ -0,0 +1,437 @@
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+/// A double precision 2x2 matrix.
+#[repr(C)]
+pub struct DMatrix2 {
+    /// The column entries of the matrix.
+    pub n: [DVector2; 2],
+}
+
+impl DMatrix2 {
+    /// Returns a matrix initialized with the four entries supplied, with the
+    /// nij parameter specifies the entry in i-th row and j-th column.
+    ///
+    /// # Arguments
+    ///
+    /// * `nij` - The value of the entry in row i and column j.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// let m = DMatrix2::new(1.0,0.0,0.0,1.0);
+    /// ```
+    pub const fn new(n00: f64, n01: f64, n10: f64, n11: f64) -> DMatrix2 {
+        let n: [DVector2; 2] = [DVector2::new(n00, n10), DVector2::new(n01, n11)];
+        Self { n }
+    }
+
+    /// Returns a matrix initialized with the two vectors initialize as the two
+    /// columns of the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The value of the entry in the first column.
+    /// * `b` - The value of the entry in the second column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// use math_engine::dvector2::DVector2;
+    /// let m = DMatrix2::new_with_vecs(DVector2::new(1.0,0.0),DVector2::new(0.0,1.0));
+    /// ```
+    pub fn new_with_vecs(a: DVector2, b: DVector2) -> DMatrix2 {
+        let n: [DVector2; 2] = [a, b];
+        Self { n }
+    }
+
+    pub fn vec_at(&self, i: usize) -> DVector2 {
+        self[i]
+    }
+
+    pub fn at(&self, i: usize, j: usize) -> f64 {
+        self[j][i]
+    }
+
+    /// Sets all four entries of this matrix, with the
+    /// nij parameter specifies the entry in i-th row and j-th column.
+    ///
+    /// # Arguments
+    ///
+    /// * `nij` - The value of the entry residing in row i and column j.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// let mut m = DMatrix2::new(1.0,1.0,1.0,1.0);
+    /// m.set(1.0,0.0,1.0,0.0);
+    /// ```
+    pub fn set(&mut self, n00: f64, n01: f64, n10: f64, n11: f64) {
+        self[0][0] = n00;
+        self[1][0] = n01;
+        self[0][1] = n10;
+        self[1][1] = n11;
+    }
+
+    /// Sets all four entries of this matrix to the two column vectors given.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The value of the entry in the first column.
+    /// * `b` - The value of the entry in the second column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// use math_engine::dvector2::DVector2;
+    /// let mut m = DMatrix2::new(1.0,1.0,1.0,1.0);
+    /// m.set_vecs(DVector2::new(1.0,0.0),DVector2::new(1.0,0.0));
+    /// ```
+    pub fn set_vecs(&mut self, a: DVector2, b: DVector2) {
+        self[0] = a;
+        self[1] = b;
+    }
+
+    /// Sets this matrix to the 2x2 identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// use math_engine::dvector2::DVector2;
+    /// let mut m = DMatrix2::new_with_vecs(DVector2::new(1.0,1.0),DVector2::new(1.0,1.0));
+    /// m.set_identity();
+    /// ```
+    pub fn set_identity(&mut self) {
+        self[0][0] = 1.0;
+        self[1][0] = 0.0;
+        self[0][1] = 0.0;
+        self[1][1] = 1.0;
+    }
+
+    /// Returns the determinant of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// use math_engine::dvector2::DVector2;
+    /// let m = DMatrix2::new_with_vecs(DVector2::new(1.0,0.0),DVector2::new(0.0,1.0));
+    /// let det = m.determinant();
+    /// ```
+    pub fn determinant(&self) -> f64 {
+        self.n[0][0] * self.n[1][1] - self.n[1][0] * self.n[0][1]
+    }
+
+    /// Returns the inverse of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// use math_engine::dvector2::DVector2;
+    /// let m = DMatrix2::new_with_vecs(DVector2::new(1.0,0.0),DVector2::new(0.0,1.0));
+    /// let m2 = m.inverse();
+    /// ```
+    pub fn inverse(&self) -> DMatrix2 {
+        let inv = 1.0 / self.determinant();
+        Self::new(
+            self.n[1][1] * inv,
+            -self.n[1][0] * inv,
+            -self.n[0][1] * inv,
+            self.n[0][0] * inv,
+        )
+    }
+
+    /// Returns the transpose of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// use math_engine::dvector2::DVector2;
+    /// let m = DMatrix2::new_with_vecs(DVector2::new(1.0,0.0),DVector2::new(0.0,1.0));
+    /// let m2 = m.transpose();
+    /// ```
+    pub fn transpose(&self) -> DMatrix2 {
+        Self::new(self.n[0][0], self.n[0][1], self.n[1][0], self.n[1][1])
+    }
+
+    /// Returns 2x2 identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// let m = DMatrix2::identity();
+    /// ```
+    pub const fn identity() -> DMatrix2 {
+        Self::new(1.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Returns a matrix that represents a rotation through the angle given.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle through which to rotate, in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// let m = DMatrix2::make_rotation(2.5);
+    /// ```
+    pub fn make_rotation(a: f64) -> DMatrix2 {
+        let c = a.cos();
+        let s = a.sin();
+        Self::new(c, -s, s, c)
+    }
+
+    /// Returns a matrix that represents a scale along the x axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `sx` - The scale along the x axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// let m = DMatrix2::make_scale_x(2.5);
+    /// ```
+    pub fn make_scale_x(sx: f64) -> DMatrix2 {
+        Self::new(sx, 0.0, 0.0, 1.0)
+    }
+
+    /// Returns a matrix that represents a scale along the y axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `sy` - The scale along the y axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// let m = DMatrix2::make_scale_y(2.5);
+    /// ```
+    pub fn make_scale_y(sy: f64) -> DMatrix2 {
+        Self::new(1.0, 0.0, 0.0, sy)
+    }
+
+    /// Returns a matrix that represents a scale along the both x and y axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The scale along both the x and y axes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// let m = DMatrix2::make_scale(2.5);
+    /// ```
+    pub fn make_scale(s: f64) -> DMatrix2 {
+        Self::new(s, 0.0, 0.0, s)
+    }
+
+    /// Returns a matrix that represents a scale along the both x and y axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `sx` - The scale along the x axis.
+    /// * `sy` - The scale along the y axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// let m = DMatrix2::make_scale_xy(2.5, 2.5);
+    /// ```
+    pub fn make_scale_xy(sx: f64, sy: f64) -> DMatrix2 {
+        Self::new(sx, 0.0, 0.0, sy)
+    }
+
+    /// Returns this matrix converted to single precision, rounding each
+    /// entry to the nearest f32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix2::DMatrix2;
+    /// use math_engine::matrix2::Matrix2;
+    /// let m = DMatrix2::identity();
+    /// assert!(m.to_f32() == Matrix2::identity());
+    /// ```
+    pub fn to_f32(&self) -> Matrix2 {
+        Matrix2::new_with_vecs(self.n[0].to_f32(), self.n[1].to_f32())
+    }
+}
+
+impl Index<(usize, usize)> for DMatrix2 {
+    type Output = f64;
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(col < 2 && row < 2);
+        &self.n[col][row]
+    }
+}
+
+impl Index<usize> for DMatrix2 {
+    type Output = DVector2;
+    fn index(&self, col: usize) -> &Self::Output {
+        assert!(col < 2);
+        &self.n[col]
+    }
+}
+
+impl IndexMut<usize> for DMatrix2 {
+    fn index_mut(&mut self, col: usize) -> &mut DVector2 {
+        assert!(col < 2);
+        &mut self.n[col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for DMatrix2 {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        assert!(col < 2 && row < 2);
+        &mut self.n[col][row]
+    }
+}
+
+impl Mul<f64> for DMatrix2 {
+    type Output = Self;
+
+    fn mul(self, s: f64) -> Self::Output {
+        Self::new(
+            self.n[0][0] * s,
+            self.n[1][0] * s,
+            self.n[0][1] * s,
+            self.n[1][1] * s,
+        )
+    }
+}
+
+impl Mul<DMatrix2> for f64 {
+    type Output = DMatrix2;
+
+    fn mul(self, rhs: DMatrix2) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<DVector2> for DMatrix2 {
+    type Output = DVector2;
+
+    fn mul(self, other: DVector2) -> Self::Output {
+        DVector2::new(
+            self.n[0][0] * other.x + self.n[1][0] * other.y,
+            self.n[0][1] * other.x + self.n[1][1] * other.y,
+        )
+    }
+}
+
+impl Neg for DMatrix2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            n: self.n.map(Neg::neg),
+        }
+    }
+}
+
+impl Mul<DMatrix2> for DMatrix2 {
+    type Output = Self;
+
+    fn mul(self, other: DMatrix2) -> Self::Output {
+        Self::new(
+            self.n[0][0] * other[(0, 0)] + self.n[1][0] * other[(1, 0)],
+            self.n[0][0] * other[(0, 1)] + self.n[1][0] * other[(1, 1)],
+            self.n[0][1] * other[(0, 0)] + self.n[1][1] * other[(1, 0)],
+            self.n[0][1] * other[(0, 1)] + self.n[1][1] * other[(1, 1)],
+        )
+    }
+}
+
+impl MulAssign<DMatrix2> for DMatrix2 {
+    fn mul_assign(&mut self, other: DMatrix2) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign<f64> for DMatrix2 {
+    fn mul_assign(&mut self, other: f64) {
+        self.n[0][0] *= other;
+        self.n[1][0] *= other;
+        self.n[0][1] *= other;
+        self.n[1][1] *= other;
+    }
+}
+
+impl Div<f64> for DMatrix2 {
+    type Output = Self;
+
+    fn div(self, other: f64) -> Self::Output {
+        DMatrix2::new(
+            self.n[0][0] / other,
+            self.n[1][0] / other,
+            self.n[0][1] / other,
+            self.n[1][1] / other,
+        )
+    }
+}
+
+impl DivAssign<f64> for DMatrix2 {
+    fn div_assign(&mut self, other: f64) {
+        self.n[0][0] /= other;
+        self.n[1][0] /= other;
+        self.n[0][1] /= other;
+        self.n[1][1] /= other;
+    }
+}
+
+impl From<Matrix2> for DMatrix2 {
+    fn from(m: Matrix2) -> Self {
+        DMatrix2::new_with_vecs(DVector2::from(m[0]), DVector2::from(m[1]))
+    }
+}
+
+impl Add for DMatrix2 {
+    type Output = Self;
+
+    fn add(self, rhs: DMatrix2) -> Self::Output {
+        Self {
+            n: core::array::from_fn(|i| self.n[i] + rhs.n[i]),
+        }
+    }
+}
+
+impl Sub for DMatrix2 {
+    type Output = Self;
+
+    fn sub(self, rhs: DMatrix2) -> Self::Output {
+        Self {
+            n: core::array::from_fn(|i| self.n[i] - rhs.n[i]),
+        }
+    }
+}
+
+impl AddAssign for DMatrix2 {
+    fn add_assign(&mut self, rhs: DMatrix2) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for DMatrix2 {
+    fn sub_assign(&mut self, rhs: DMatrix2) {
+        *self = *self - rhs;
+    }
+}
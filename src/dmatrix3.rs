@@ -0,0 +1,897 @@
+use crate::prelude::*;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+/// A double precision 3x3 matrix.
+#[repr(C)]
+pub struct DMatrix3 {
+    /// The column entries of the matrix.
+    n: [DVector3; 3],
+}
+
+impl DMatrix3 {
+    /// Returns a matrix initialized with the nine entries supplied, with the
+    /// nij parameter specifies the entry in i-th row and j-th column.
+    ///
+    /// # Arguments
+    ///
+    /// * `nij` - The value of the entry in row i and column j.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let m = DMatrix3::new(1.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,1.0);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+        e: f64,
+        f: f64,
+        g: f64,
+        h: f64,
+        i: f64,
+    ) -> DMatrix3 {
+        let n: [DVector3; 3] = [
+            DVector3::new(a, d, g),
+            DVector3::new(b, e, h),
+            DVector3::new(c, f, i),
+        ];
+        Self { n }
+    }
+
+    /// Returns a matrix initialized with the three vectors initialize as the three
+    /// columns of the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The value of the entry in the first column.
+    /// * `b` - The value of the entry in the second column.
+    /// * `c` - The value of the entry in the third column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// use math_engine::dvector3::DVector3;
+    /// let m = DMatrix3::new_with_vecs(DVector3::new(1.0,0.0,0.0),DVector3::new(0.0,1.0,0.0), DVector3::new(0.0,0.0,1.0));
+    /// ```
+    pub fn new_with_vecs(a: DVector3, b: DVector3, c: DVector3) -> DMatrix3 {
+        let n: [DVector3; 3] = [a, b, c];
+        Self { n }
+    }
+
+    pub fn vec_at(&self, index: usize) -> DVector3 {
+        self[index]
+    }
+
+    pub fn at(&self, i: usize, j: usize) -> f64 {
+        self[j][i]
+    }
+
+    /// Sets all nine entries of this matrix, with the
+    /// nij parameter specifies the entry in i-th row and j-th column.
+    ///
+    /// # Arguments
+    ///
+    /// * `nij` - The value of the entry residing in row i and column j.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let mut m = DMatrix3::new(1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0);
+    /// m.set(0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn set(
+        &mut self,
+        n00: f64,
+        n01: f64,
+        n02: f64,
+        n10: f64,
+        n11: f64,
+        n12: f64,
+        n20: f64,
+        n21: f64,
+        n22: f64,
+    ) {
+        self[0][0] = n00;
+        self[1][0] = n01;
+        self[2][0] = n02;
+        self[0][1] = n10;
+        self[1][1] = n11;
+        self[2][1] = n12;
+        self[0][2] = n20;
+        self[1][2] = n21;
+        self[2][2] = n22;
+    }
+
+    /// Sets all nine entries of this matrix to the three column vectors given.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The value of the entry in the first column.
+    /// * `b` - The value of the entry in the second column.
+    /// * `c` - The value of the entry in the third column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// use math_engine::dvector3::DVector3;
+    /// let mut m = DMatrix3::new(0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0);
+    /// m.set_vecs(DVector3::new(1.0,0.0,0.0),DVector3::new(0.0,1.0,0.0), DVector3::new(0.0,0.0,1.0));
+    /// ```
+    pub fn set_vecs(&mut self, a: DVector3, b: DVector3, c: DVector3) {
+        self[0] = a;
+        self[1] = b;
+        self[2] = c;
+    }
+
+    /// Sets this matrix to the 3x3 identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let mut m = DMatrix3::new(0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0);
+    /// m.set_identity();
+    /// ```
+    pub fn set_identity(&mut self) {
+        self[0][0] = 1.0;
+        self[1][0] = 0.0;
+        self[2][0] = 0.0;
+        self[0][1] = 0.0;
+        self[1][1] = 1.0;
+        self[2][1] = 0.0;
+        self[0][2] = 0.0;
+        self[1][2] = 0.0;
+        self[2][2] = 1.0;
+    }
+
+    /// Returns the determinant of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let m = DMatrix3::new(1.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,1.0);
+    /// let det = m.determinant();
+    /// ```
+    pub fn determinant(&self) -> f64 {
+        self.n[0][0] * (self.n[1][1] * self.n[2][2] - self.n[2][1] * self.n[1][2])
+            - self.n[1][0] * (self.n[0][1] * self.n[2][2] - self.n[2][1] * self.n[0][2])
+            + self.n[2][0] * (self.n[0][1] * self.n[1][2] - self.n[1][1] * self.n[0][2])
+    }
+
+    /// Returns the inverse of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let m = DMatrix3::new(1.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,1.0);
+    /// let det = m.inverse();
+    /// ```
+    pub fn inverse(&self) -> DMatrix3 {
+        let a = self[0];
+        let b = self[1];
+        let c = self[2];
+        let r0 = b.cross(&c);
+        let r1 = c.cross(&a);
+        let r2 = a.cross(&b);
+        let inv = 1.0 / r2.dot(&c);
+        Self::new(
+            r0.x * inv,
+            r0.y * inv,
+            r0.z * inv,
+            r1.x * inv,
+            r1.y * inv,
+            r1.z * inv,
+            r2.x * inv,
+            r2.y * inv,
+            r2.z * inv,
+        )
+    }
+
+    /// Returns the transpose of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let m = DMatrix3::new(1.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,1.0);
+    /// let det = m.transpose();
+    /// ```
+    pub fn transpose(&self) -> DMatrix3 {
+        Self::new(
+            self.n[0][0],
+            self.n[0][1],
+            self.n[0][2],
+            self.n[1][0],
+            self.n[1][1],
+            self.n[1][2],
+            self.n[2][0],
+            self.n[2][1],
+            self.n[2][2],
+        )
+    }
+
+    /// Returns 3x3 identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let m = DMatrix3::identity();
+    /// ```
+    pub const fn identity() -> DMatrix3 {
+        Self::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0)
+    }
+
+    pub fn make_rotation(a: f64, v: &DVector3) -> DMatrix3 {
+        let c = a.cos();
+        let s = a.sin();
+        let d = 1.0 - c;
+        let x = v.x * d;
+        let y = v.y * d;
+        let z = v.z * d;
+        let axay = x * v.y;
+        let axaz = x * v.z;
+        let ayaz = y * v.z;
+        Self::new(
+            c + x * v.x,
+            axay - s * v.z,
+            axaz + s * v.y,
+            axay + s * v.z,
+            c + y * v.y,
+            ayaz - s * v.x,
+            axaz - s * v.y,
+            ayaz + s * v.x,
+            c + z * v.z,
+        )
+    }
+
+    pub fn make_rotation_x(a: f64) -> DMatrix3 {
+        let c = a.cos();
+        let s = a.sin();
+        Self::new(1.0, 0.0, 0.0, 0.0, c, -s, 0.0, s, c)
+    }
+
+    pub fn make_rotation_y(a: f64) -> DMatrix3 {
+        let c = a.cos();
+        let s = a.sin();
+        Self::new(c, 0.0, s, 0.0, 1.0, 0.0, -s, 0.0, c)
+    }
+
+    pub fn make_rotation_z(a: f64) -> DMatrix3 {
+        let c = a.cos();
+        let s = a.sin();
+        Self::new(c, -s, 0.0, s, c, 0.0, 0.0, 0.0, 1.0)
+    }
+
+    pub fn make_skew(angle: f64, a: &DVector3, b: &DVector3) -> DMatrix3 {
+        let t = angle.tan();
+        let x = a.x * t;
+        let y = a.y * t;
+        let z = a.z * t;
+        Self::new(
+            x * b.x + 1.0,
+            x * b.y,
+            x * b.z,
+            y * b.x,
+            y * b.y + 1.0,
+            y * b.z,
+            z * b.x,
+            z * b.y,
+            z * b.z + 1.0,
+        )
+    }
+
+    pub fn make_scale_vec(s: f64, a: &DVector3) -> DMatrix3 {
+        let ss = s - 1.0;
+        let x = a.x * ss;
+        let y = a.y * ss;
+        let z = a.z * ss;
+        let axay = x * a.y;
+        let axaz = x * a.z;
+        let ayaz = y * a.z;
+        Self::new(
+            x * a.x + 1.0,
+            axay,
+            axaz,
+            axay,
+            y * a.y + 1.0,
+            ayaz,
+            axaz,
+            ayaz,
+            z * a.z + 1.0,
+        )
+    }
+
+    pub fn make_involution(a: &DVector3) -> DMatrix3 {
+        let x = a.x * 2.0;
+        let y = a.y * 2.0;
+        let z = a.z * 2.0;
+        let axay = x * a.y;
+        let axaz = x * a.z;
+        let ayaz = y * a.z;
+        Self::new(
+            x * a.x - 1.0,
+            axay,
+            axaz,
+            axay,
+            y * a.y - 1.0,
+            ayaz,
+            axaz,
+            ayaz,
+            z * a.z - 1.0,
+        )
+    }
+
+    pub fn make_scale(sx: f64, sy: f64, sz: f64) -> DMatrix3 {
+        Self::new(sx, 0.0, 0.0, 0.0, sy, 0.0, 0.0, 0.0, sz)
+    }
+
+    pub fn make_scale_x(sx: f64) -> DMatrix3 {
+        Self::new(sx, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0)
+    }
+
+    pub fn make_scale_y(sy: f64) -> DMatrix3 {
+        Self::new(1.0, 0.0, 0.0, 0.0, sy, 0.0, 0.0, 0.0, 1.0)
+    }
+
+    pub fn make_scale_z(sz: f64) -> DMatrix3 {
+        Self::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, sz)
+    }
+
+    pub fn make_reflection(a: &DVector3) -> DMatrix3 {
+        let x = a.x * -2.0;
+        let y = a.y * -2.0;
+        let z = a.z * -2.0;
+        let axay = x * a.y;
+        let axaz = x * a.z;
+        let ayaz = y * a.z;
+
+        Self::new(
+            x * a.x + 1.0,
+            axay,
+            axaz,
+            axay,
+            y * a.y + 1.0,
+            ayaz,
+            axaz,
+            ayaz,
+            z * a.z + 1.0,
+        )
+    }
+
+    /// Returns this matrix converted to single precision, rounding each
+    /// entry to the nearest f32.
+    pub fn to_f32(&self) -> Matrix3 {
+        Matrix3::new_with_vecs(self.n[0].to_f32(), self.n[1].to_f32(), self.n[2].to_f32())
+    }
+
+    /// Returns the trace of this matrix, the sum of its diagonal entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let m = DMatrix3::new(1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0);
+    /// assert!(m.trace() == 15.0);
+    /// ```
+    pub fn trace(&self) -> f64 {
+        self.n[0][0] + self.n[1][1] + self.n[2][2]
+    }
+
+    /// Returns the cofactor matrix, whose entry (i, j) is the signed minor
+    /// of this matrix obtained by removing row i and column j. For a
+    /// transform m, `m.cofactor_matrix()` transforms normals as
+    /// `det(m) * m.inverse().transpose()` does, and stays valid when m is
+    /// singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let m = DMatrix3::make_scale(2.0, 3.0, 4.0);
+    /// assert!(m.cofactor_matrix() == DMatrix3::make_scale(12.0, 8.0, 6.0));
+    /// ```
+    pub fn cofactor_matrix(&self) -> DMatrix3 {
+        let a = self[0];
+        let b = self[1];
+        let c = self[2];
+        Self::new_with_vecs(b.cross(&c), c.cross(&a), a.cross(&b))
+    }
+
+    /// Returns the adjugate of this matrix, the transpose of its cofactor
+    /// matrix, so that `m * m.adjugate()` is `det(m)` times the identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let m = DMatrix3::new(2.0,1.0,0.0,0.0,1.0,3.0,1.0,0.0,1.0);
+    /// let p = m * m.adjugate();
+    /// assert!(p == DMatrix3::identity() * m.determinant());
+    /// ```
+    pub fn adjugate(&self) -> DMatrix3 {
+        self.cofactor_matrix().transpose()
+    }
+
+    /// Returns the outer product a * b^T, whose entry (i, j) is a[i] * b[j].
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - A reference to a dvector3, the column factor.
+    /// * `b` - A reference to a dvector3, the row factor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// use math_engine::dvector3::DVector3;
+    /// let m = DMatrix3::outer_product(&DVector3::new(1.0,2.0,3.0), &DVector3::new(0.0,1.0,0.0));
+    /// assert!(m[1] == DVector3::new(1.0,2.0,3.0) && m[0] == DVector3::default());
+    /// ```
+    pub fn outer_product(a: &DVector3, b: &DVector3) -> DMatrix3 {
+        Self::new_with_vecs(*a * b.x, *a * b.y, *a * b.z)
+    }
+
+    /// Returns the diagonal matrix with the entries of v on its diagonal.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A reference to a dvector3.
+    pub fn from_diagonal(v: &DVector3) -> DMatrix3 {
+        Self::new(v.x, 0.0, 0.0, 0.0, v.y, 0.0, 0.0, 0.0, v.z)
+    }
+
+    /// Returns the diagonal entries of this matrix.
+    pub fn diagonal(&self) -> DVector3 {
+        DVector3::new(self.n[0][0], self.n[1][1], self.n[2][2])
+    }
+
+    /// Returns the row at index i.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The row index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// use math_engine::dvector3::DVector3;
+    /// let m = DMatrix3::new(1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0);
+    /// assert!(m.row(1) == DVector3::new(4.0,5.0,6.0));
+    /// ```
+    pub fn row(&self, i: usize) -> DVector3 {
+        DVector3::new(self.n[0][i], self.n[1][i], self.n[2][i])
+    }
+
+    /// Sets the row at index i to v.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The row index.
+    /// * `v` - The new row.
+    pub fn set_row(&mut self, i: usize, v: &DVector3) {
+        self.n[0][i] = v.x;
+        self.n[1][i] = v.y;
+        self.n[2][i] = v.z;
+    }
+
+    /// Returns the matrix with the rows a, b and c.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first row.
+    /// * `b` - The second row.
+    /// * `c` - The third row.
+    pub fn from_rows(a: &DVector3, b: &DVector3, c: &DVector3) -> DMatrix3 {
+        Self::new(a.x, a.y, a.z, b.x, b.y, b.z, c.x, c.y, c.z)
+    }
+
+    /// Returns the rotation matrix composed of rotations through x, y and z
+    /// radians about the x, y and z axes, applied in the given order.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order in which the rotations are applied.
+    /// * `x` - The angle about the x axis in radians.
+    /// * `y` - The angle about the y axis in radians.
+    /// * `z` - The angle about the z axis in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::euler::EulerOrder;
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let m = DMatrix3::from_euler(EulerOrder::XYZ, 0.1, 0.2, 0.3);
+    /// let n = DMatrix3::make_rotation_z(0.3) * DMatrix3::make_rotation_y(0.2) * DMatrix3::make_rotation_x(0.1);
+    /// assert!(m == n);
+    /// ```
+    pub fn from_euler(order: EulerOrder, x: f64, y: f64, z: f64) -> DMatrix3 {
+        let r = [
+            Self::make_rotation_x(x),
+            Self::make_rotation_y(y),
+            Self::make_rotation_z(z),
+        ];
+        let [i, j, k] = order.axes();
+        r[k] * r[j] * r[i]
+    }
+
+    /// Returns the angles (x, y, z) in radians about the x, y and z axes that
+    /// `DMatrix3::from_euler` turns back into this rotation matrix. The angle
+    /// about the middle axis lies in [-pi/2, pi/2] and the others in
+    /// [-pi, pi]. At the singularity where the middle angle is +-pi/2 only
+    /// the sum or difference of the other two is determined, and the angle
+    /// about the last axis is returned as 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order in which the rotations are applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::euler::EulerOrder;
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let m = DMatrix3::from_euler(EulerOrder::ZXY, 0.1, 0.2, 0.3);
+    /// let (x, y, z) = m.to_euler(EulerOrder::ZXY);
+    /// assert!((x - 0.1).abs() < 1e-12 && (y - 0.2).abs() < 1e-12 && (z - 0.3).abs() < 1e-12);
+    /// ```
+    pub fn to_euler(&self, order: EulerOrder) -> (f64, f64, f64) {
+        let [i, j, k] = order.axes();
+        let p = f64::from(order.parity());
+        let m = |r: usize, c: usize| self[(r, c)];
+        let sb = (-p * m(k, i)).clamp(-1.0, 1.0);
+        let b = sb.asin();
+        let (a, c) = if sb.abs() < 1.0 - 1.0e-6 {
+            ((p * m(k, j)).atan2(m(k, k)), (p * m(j, i)).atan2(m(i, i)))
+        } else {
+            ((-p * m(j, k)).atan2(m(j, j)), 0.0)
+        };
+        let mut angles = [0.0; 3];
+        angles[i] = a;
+        angles[j] = b;
+        angles[k] = c;
+        (angles[0], angles[1], angles[2])
+    }
+
+    /// Returns the cross product matrix of v, the skew-symmetric matrix with
+    /// `DMatrix3::make_cross_product(v) * u == v.cross(u)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A reference to a dvector3.
+    pub fn make_cross_product(v: &DVector3) -> DMatrix3 {
+        Self::new(0.0, -v.z, v.y, v.z, 0.0, -v.x, -v.y, v.x, 0.0)
+    }
+
+    /// Returns the vector v whose cross product matrix is the skew-symmetric
+    /// part of this matrix, (m - m^T) / 2.
+    pub fn axial_vector(&self) -> DVector3 {
+        DVector3::new(
+            self[(2, 1)] - self[(1, 2)],
+            self[(0, 2)] - self[(2, 0)],
+            self[(1, 0)] - self[(0, 1)],
+        ) * 0.5
+    }
+
+    /// Returns the rotation through |w| radians about w, the exponential of
+    /// the rotation vector w.
+    ///
+    /// # Arguments
+    ///
+    /// * `w` - The rotation vector, the axis scaled by the angle.
+    pub fn from_rotation_vector(w: &DVector3) -> DMatrix3 {
+        let angle = w.magnitude();
+        if angle < 1.0e-8 {
+            // The first order term of the exponential series.
+            let k = Self::make_cross_product(w);
+            return Self::new_with_vecs(
+                DVector3::new(1.0, 0.0, 0.0) + k[0],
+                DVector3::new(0.0, 1.0, 0.0) + k[1],
+                DVector3::new(0.0, 0.0, 1.0) + k[2],
+            );
+        }
+        Self::make_rotation(angle, &(*w / angle))
+    }
+
+    /// Returns the rotation vector of this rotation matrix, the axis scaled
+    /// by the angle, with the angle in [0, π].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// use math_engine::dvector3::DVector3;
+    /// let w = DVector3::new(0.3, -1.2, 0.8);
+    /// let r = DMatrix3::from_rotation_vector(&w);
+    /// assert!((r.to_rotation_vector() - w).magnitude() < 1e-12);
+    /// ```
+    pub fn to_rotation_vector(&self) -> DVector3 {
+        let s = self.axial_vector();
+        let sin = s.magnitude();
+        let cos = (self.trace() - 1.0) * 0.5;
+        let angle = sin.atan2(cos);
+        if cos >= 0.0 {
+            if sin < 1.0e-6 {
+                return s;
+            }
+            return s * (angle / sin);
+        }
+        // Near a half turn sin loses the axis, so it is read from the
+        // symmetric part (m + m^T) / 2 - cos * I = (1 - cos) * u * u^T
+        // instead, and s only supplies the sign.
+        let b = |i: usize, j: usize| (self[(i, j)] + self[(j, i)]) * 0.5;
+        let d = DVector3::new(b(0, 0) - cos, b(1, 1) - cos, b(2, 2) - cos);
+        let axis = if d.x >= d.y && d.x >= d.z {
+            DVector3::new(d.x, b(1, 0), b(2, 0))
+        } else if d.y >= d.z {
+            DVector3::new(b(0, 1), d.y, b(2, 1))
+        } else {
+            DVector3::new(b(0, 2), b(1, 2), d.z)
+        }
+        .normalize();
+        if axis.dot(&s) < 0.0 {
+            axis * -angle
+        } else {
+            axis * angle
+        }
+    }
+
+    /// Returns the matrix exponential of this skew-symmetric matrix, the
+    /// rotation given by Rodrigues' formula. Only the skew-symmetric part of
+    /// the matrix is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// use math_engine::dvector3::DVector3;
+    /// let k = DMatrix3::make_cross_product(&DVector3::new(0.0, 0.0, 0.5));
+    /// let r = k.exp();
+    /// let z = DMatrix3::make_rotation_z(0.5);
+    /// assert!((0..3).all(|i| (r[i] - z[i]).magnitude() < 1e-12));
+    /// ```
+    pub fn exp(&self) -> DMatrix3 {
+        Self::from_rotation_vector(&self.axial_vector())
+    }
+
+    /// Returns the matrix logarithm of this rotation matrix, the
+    /// skew-symmetric matrix k with the smallest angle such that
+    /// `k.exp() == m`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let r = DMatrix3::make_rotation_x(0.6) * DMatrix3::make_rotation_y(-0.4);
+    /// let half = (r.ln() * 0.5).exp();
+    /// let p = half * half;
+    /// assert!((0..3).all(|i| (p[i] - r[i]).magnitude() < 1e-12));
+    /// ```
+    pub fn ln(&self) -> DMatrix3 {
+        Self::make_cross_product(&self.to_rotation_vector())
+    }
+}
+
+impl Index<usize> for DMatrix3 {
+    type Output = DVector3;
+    fn index(&self, col: usize) -> &Self::Output {
+        assert!(col < 3);
+        &self.n[col]
+    }
+}
+
+impl Index<(usize, usize)> for DMatrix3 {
+    type Output = f64;
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(col < 3 && row < 3);
+        &self.n[col][row]
+    }
+}
+
+impl IndexMut<(usize, usize)> for DMatrix3 {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        assert!(col < 3 && row < 3);
+        &mut self.n[col][row]
+    }
+}
+
+impl IndexMut<usize> for DMatrix3 {
+    fn index_mut(&mut self, col: usize) -> &mut DVector3 {
+        assert!(col < 3);
+        &mut self.n[col]
+    }
+}
+
+impl Mul<f64> for DMatrix3 {
+    type Output = Self;
+
+    fn mul(self, s: f64) -> Self::Output {
+        Self::new(
+            self.n[0][0] * s,
+            self.n[1][0] * s,
+            self.n[2][0] * s,
+            self.n[0][1] * s,
+            self.n[1][1] * s,
+            self.n[2][1] * s,
+            self.n[0][2] * s,
+            self.n[1][2] * s,
+            self.n[2][2] * s,
+        )
+    }
+}
+
+impl Mul<DMatrix3> for f64 {
+    type Output = DMatrix3;
+
+    fn mul(self, rhs: DMatrix3) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Neg for DMatrix3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            n: self.n.map(Neg::neg),
+        }
+    }
+}
+
+impl Mul<DVector3> for DMatrix3 {
+    type Output = DVector3;
+
+    fn mul(self, other: DVector3) -> Self::Output {
+        DVector3::new(
+            self.n[0][0] * other.x + self.n[1][0] * other.y + self.n[2][0] * other.z,
+            self.n[0][1] * other.x + self.n[1][1] * other.y + self.n[2][1] * other.z,
+            self.n[0][2] * other.x + self.n[1][2] * other.y + self.n[2][2] * other.z,
+        )
+    }
+}
+
+impl Mul<DMatrix3> for DMatrix3 {
+    type Output = Self;
+
+    fn mul(self, other: DMatrix3) -> Self::Output {
+        Self::new(
+            self.n[0][0] * other[(0, 0)]
+                + self.n[1][0] * other[(1, 0)]
+                + self.n[2][0] * other[(2, 0)],
+            self.n[0][0] * other[(0, 1)]
+                + self.n[1][0] * other[(1, 1)]
+                + self.n[2][0] * other[(2, 1)],
+            self.n[0][0] * other[(0, 2)]
+                + self.n[1][0] * other[(1, 2)]
+                + self.n[2][0] * other[(2, 2)],
+            self.n[0][1] * other[(0, 0)]
+                + self.n[1][1] * other[(1, 0)]
+                + self.n[2][1] * other[(2, 0)],
+            self.n[0][1] * other[(0, 1)]
+                + self.n[1][1] * other[(1, 1)]
+                + self.n[2][1] * other[(2, 1)],
+            self.n[0][1] * other[(0, 2)]
+                + self.n[1][1] * other[(1, 2)]
+                + self.n[2][1] * other[(2, 2)],
+            self.n[0][2] * other[(0, 0)]
+                + self.n[1][2] * other[(1, 0)]
+                + self.n[2][2] * other[(2, 0)],
+            self.n[0][2] * other[(0, 1)]
+                + self.n[1][2] * other[(1, 1)]
+                + self.n[2][2] * other[(2, 1)],
+            self.n[0][2] * other[(0, 2)]
+                + self.n[1][2] * other[(1, 2)]
+                + self.n[2][2] * other[(2, 2)],
+        )
+    }
+}
+
+impl MulAssign<DMatrix3> for DMatrix3 {
+    fn mul_assign(&mut self, other: DMatrix3) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign<f64> for DMatrix3 {
+    fn mul_assign(&mut self, other: f64) {
+        self.n[0][0] *= other;
+        self.n[1][0] *= other;
+        self.n[2][0] *= other;
+        self.n[0][1] *= other;
+        self.n[1][1] *= other;
+        self.n[2][1] *= other;
+        self.n[0][2] *= other;
+        self.n[1][2] *= other;
+        self.n[2][2] *= other;
+    }
+}
+
+impl Div<f64> for DMatrix3 {
+    type Output = Self;
+
+    fn div(self, other: f64) -> Self::Output {
+        let s = 1.0 / other;
+        Self::new(
+            self.n[0][0] * s,
+            self.n[1][0] * s,
+            self.n[2][0] * s,
+            self.n[0][1] * s,
+            self.n[1][1] * s,
+            self.n[2][1] * s,
+            self.n[0][2] * s,
+            self.n[1][2] * s,
+            self.n[2][2] * s,
+        )
+    }
+}
+
+impl DivAssign<f64> for DMatrix3 {
+    fn div_assign(&mut self, other: f64) {
+        let other = 1.0 / other;
+        self.n[0][0] *= other;
+        self.n[1][0] *= other;
+        self.n[2][0] *= other;
+        self.n[0][1] *= other;
+        self.n[1][1] *= other;
+        self.n[2][1] *= other;
+        self.n[0][2] *= other;
+        self.n[1][2] *= other;
+        self.n[2][2] *= other;
+    }
+}
+
+impl From<Matrix3> for DMatrix3 {
+    fn from(m: Matrix3) -> Self {
+        DMatrix3::new_with_vecs(
+            DVector3::from(m[0]),
+            DVector3::from(m[1]),
+            DVector3::from(m[2]),
+        )
+    }
+}
+
+impl Add for DMatrix3 {
+    type Output = Self;
+
+    fn add(self, rhs: DMatrix3) -> Self::Output {
+        Self {
+            n: core::array::from_fn(|i| self.n[i] + rhs.n[i]),
+        }
+    }
+}
+
+impl Sub for DMatrix3 {
+    type Output = Self;
+
+    fn sub(self, rhs: DMatrix3) -> Self::Output {
+        Self {
+            n: core::array::from_fn(|i| self.n[i] - rhs.n[i]),
+        }
+    }
+}
+
+impl AddAssign for DMatrix3 {
+    fn add_assign(&mut self, rhs: DMatrix3) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for DMatrix3 {
+    fn sub_assign(&mut self, rhs: DMatrix3) {
+        *self = *self - rhs;
+    }
+}
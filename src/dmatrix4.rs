@@ -0,0 +1,1069 @@
+use crate::prelude::*;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+/// A double precision 4x4 matrix.
+#[repr(C)]
+pub struct DMatrix4 {
+    n: [DVector4; 4],
+}
+
+impl DMatrix4 {
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+        e: f64,
+        f: f64,
+        g: f64,
+        h: f64,
+        i: f64,
+        j: f64,
+        k: f64,
+        l: f64,
+        m: f64,
+        n: f64,
+        o: f64,
+        p: f64,
+    ) -> DMatrix4 {
+        let n: [DVector4; 4] = [
+            DVector4::new(a, e, i, m),
+            DVector4::new(b, f, j, n),
+            DVector4::new(c, g, k, o),
+            DVector4::new(d, h, l, p),
+        ];
+        Self { n }
+    }
+
+    pub fn new_with_vecs(a: DVector4, b: DVector4, c: DVector4, d: DVector4) -> DMatrix4 {
+        let n: [DVector4; 4] = [a, b, c, d];
+        Self { n }
+    }
+
+    pub fn at(&self, i: usize, j: usize) -> f64 {
+        self[j][i]
+    }
+
+    fn vec3_at(&self, i: usize) -> DVector3 {
+        DVector3::new(self[i].x, self[i].y, self[i].z)
+    }
+
+    pub fn vec_at(&self, i: usize) -> DVector4 {
+        self[i]
+    }
+
+    pub fn determinant(&self) -> f64 {
+        let a = self.vec3_at(0);
+        let b = self.vec3_at(1);
+        let c = self.vec3_at(2);
+        let d = self.vec3_at(3);
+
+        let x = self.n[0][3];
+        let y = self.n[1][3];
+        let z = self.n[2][3];
+        let w = self.n[3][3];
+
+        let s = a.cross(&b);
+        let t = c.cross(&d);
+        let u = a * y - b * x;
+        let v = c * w - d * z;
+        s.dot(&v) + t.dot(&u)
+    }
+
+    pub fn inverse(&self) -> DMatrix4 {
+        let a = self.vec3_at(0);
+        let b = self.vec3_at(1);
+        let c = self.vec3_at(2);
+        let d = self.vec3_at(3);
+
+        let x = self.n[0][3];
+        let y = self.n[1][3];
+        let z = self.n[2][3];
+        let w = self.n[3][3];
+
+        let mut s = a.cross(&b);
+        let mut t = c.cross(&d);
+        let mut u = a * y - b * x;
+        let mut v = c * w - d * z;
+
+        let inv = 1.0 / (s.dot(&v) + t.dot(&u));
+        s *= inv;
+        t *= inv;
+        u *= inv;
+        v *= inv;
+        let r0 = b.cross(&v) + t * y;
+        let r1 = v.cross(&a) - t * x;
+        let r2 = d.cross(&u) + s * w;
+        let r3 = u.cross(&c) - s * z;
+        Self::new(
+            r0.x,
+            r0.y,
+            r0.z,
+            -b.dot(&t),
+            r1.x,
+            r1.y,
+            r1.z,
+            a.dot(&t),
+            r2.x,
+            r2.y,
+            r2.z,
+            -d.dot(&s),
+            r3.x,
+            r3.y,
+            r3.z,
+            c.dot(&s),
+        )
+    }
+
+    pub fn transpose(&self) -> DMatrix4 {
+        Self::new(
+            self.n[0][0],
+            self.n[0][1],
+            self.n[0][2],
+            self.n[0][3],
+            self.n[1][0],
+            self.n[1][1],
+            self.n[1][2],
+            self.n[1][3],
+            self.n[2][0],
+            self.n[2][1],
+            self.n[2][2],
+            self.n[2][3],
+            self.n[3][0],
+            self.n[3][1],
+            self.n[3][2],
+            self.n[3][3],
+        )
+    }
+
+    pub const fn identity() -> DMatrix4 {
+        Self::new(
+            1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        )
+    }
+
+    pub fn to_f32(&self) -> Matrix4 {
+        Matrix4::new_with_vecs(
+            self.n[0].to_f32(),
+            self.n[1].to_f32(),
+            self.n[2].to_f32(),
+            self.n[3].to_f32(),
+        )
+    }
+
+    /// Returns the inverse of this matrix, or None if it is singular.
+    ///
+    /// The inverse is found from an LU decomposition with scaled partial
+    /// pivoting: each pivot is the entry largest relative to the rest of its
+    /// row, so the result stays accurate for badly scaled matrices where
+    /// `inverse` loses precision or overflows. The matrix is treated as
+    /// singular when the best relative pivot falls below `f64::EPSILON`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix4::DMatrix4;
+    /// let big = 2.0f64.powi(600);
+    /// let m = DMatrix4::make_scale(big, 1.0 / big, big);
+    /// let inv = m.inverse_checked().unwrap();
+    /// assert!(inv == DMatrix4::make_scale(1.0 / big, big, 1.0 / big));
+    /// assert!(DMatrix4::make_scale(1.0, 0.0, 1.0).inverse_checked().is_none());
+    /// ```
+    #[allow(clippy::needless_range_loop)]
+    pub fn inverse_checked(&self) -> Option<DMatrix4> {
+        // The rows of the matrix, overwritten by L below the diagonal (with
+        // an implicit unit diagonal) and by U on and above it.
+        let mut a = [self.row(0), self.row(1), self.row(2), self.row(3)];
+        let mut perm = [0, 1, 2, 3];
+        let mut scale = [0.0; 4];
+        for (s, r) in scale.iter_mut().zip(a.iter()) {
+            *s = r.x.abs().max(r.y.abs()).max(r.z.abs()).max(r.w.abs());
+            if *s == 0.0 {
+                return None;
+            }
+        }
+
+        for k in 0..4 {
+            let mut p = k;
+            let mut best = 0.0;
+            for r in k..4 {
+                let v = a[r][k].abs() / scale[r];
+                if v > best {
+                    best = v;
+                    p = r;
+                }
+            }
+            if best <= f64::EPSILON {
+                return None;
+            }
+            a.swap(p, k);
+            perm.swap(p, k);
+            scale.swap(p, k);
+            for r in k + 1..4 {
+                let f = a[r][k] / a[k][k];
+                a[r][k] = f;
+                for c in k + 1..4 {
+                    a[r][c] -= f * a[k][c];
+                }
+            }
+        }
+
+        // Solve L U x = P e_j for each column j of the inverse.
+        let mut inv = DMatrix4::default();
+        for j in 0..4 {
+            let mut x = DVector4::default();
+            for i in 0..4 {
+                x[i] = if perm[i] == j { 1.0 } else { 0.0 };
+                for c in 0..i {
+                    x[i] -= a[i][c] * x[c];
+                }
+            }
+            for i in (0..4).rev() {
+                for c in i + 1..4 {
+                    x[i] -= a[i][c] * x[c];
+                }
+                x[i] /= a[i][i];
+            }
+            inv[j] = x;
+        }
+        Some(inv)
+    }
+
+    /// Sets this matrix to the identity.
+    pub fn set_identity(&mut self) {
+        *self = Self::identity();
+    }
+
+    /// Returns the trace of this matrix, the sum of its diagonal entries.
+    pub fn trace(&self) -> f64 {
+        self.n[0][0] + self.n[1][1] + self.n[2][2] + self.n[3][3]
+    }
+
+    /// Returns the row at index i.
+    pub fn row(&self, i: usize) -> DVector4 {
+        DVector4::new(self.n[0][i], self.n[1][i], self.n[2][i], self.n[3][i])
+    }
+
+    /// Returns the rotation through the angle a about the x axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle in radians.
+    pub fn make_rotation_x(a: f64) -> DMatrix4 {
+        Self::from(DMatrix3::make_rotation_x(a))
+    }
+
+    /// Returns the rotation through the angle a about the y axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle in radians.
+    pub fn make_rotation_y(a: f64) -> DMatrix4 {
+        Self::from(DMatrix3::make_rotation_y(a))
+    }
+
+    /// Returns the rotation through the angle a about the z axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle in radians.
+    pub fn make_rotation_z(a: f64) -> DMatrix4 {
+        Self::from(DMatrix3::make_rotation_z(a))
+    }
+
+    /// Returns the rotation through the angle a about the unit vector v.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle in radians.
+    /// * `v` - The unit length axis of rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix4::DMatrix4;
+    /// use math_engine::dvector3::DVector3;
+    /// let m = DMatrix4::make_rotation(0.3, &DVector3::new(0.0, 1.0, 0.0));
+    /// assert!(m == DMatrix4::make_rotation_y(0.3));
+    /// ```
+    pub fn make_rotation(a: f64, v: &DVector3) -> DMatrix4 {
+        Self::from(DMatrix3::make_rotation(a, v))
+    }
+
+    /// Returns the scale by sx, sy and sz along the x, y and z axes.
+    pub fn make_scale(sx: f64, sy: f64, sz: f64) -> DMatrix4 {
+        Self::new(
+            sx, 0.0, 0.0, 0.0, 0.0, sy, 0.0, 0.0, 0.0, 0.0, sz, 0.0, 0.0, 0.0, 0.0, 1.0,
+        )
+    }
+
+    /// Returns the scale by sx along the x axis.
+    pub fn make_scale_x(sx: f64) -> DMatrix4 {
+        Self::make_scale(sx, 1.0, 1.0)
+    }
+
+    /// Returns the scale by sy along the y axis.
+    pub fn make_scale_y(sy: f64) -> DMatrix4 {
+        Self::make_scale(1.0, sy, 1.0)
+    }
+
+    /// Returns the scale by sz along the z axis.
+    pub fn make_scale_z(sz: f64) -> DMatrix4 {
+        Self::make_scale(1.0, 1.0, sz)
+    }
+
+    /// Returns the translation by v.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix4::DMatrix4;
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::dvector3::DVector3;
+    /// use math_engine::dvector4::DVector4;
+    /// let m = DMatrix4::make_translation(&DVector3::new(1.0, 2.0, 3.0));
+    /// assert!(m * DPoint3::new(1.0, 1.0, 1.0) == DVector4::new(2.0, 3.0, 4.0, 1.0));
+    /// ```
+    pub fn make_translation(v: &DVector3) -> DMatrix4 {
+        Self::new(
+            1.0, 0.0, 0.0, v.x, 0.0, 1.0, 0.0, v.y, 0.0, 0.0, 1.0, v.z, 0.0, 0.0, 0.0, 1.0,
+        )
+    }
+
+    /// Returns a right-handed view matrix for a camera at eye looking toward
+    /// target. The camera looks down its negative z axis with up along
+    /// positive y, as in OpenGL.
+    ///
+    /// # Arguments
+    ///
+    /// * `eye` - The position of the camera.
+    /// * `target` - The point the camera looks at.
+    /// * `up` - The approximate up direction, not parallel to target - eye.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix4::DMatrix4;
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::dvector3::DVector3;
+    /// use math_engine::dvector4::DVector4;
+    /// let eye = DPoint3::new(0.0, 0.0, 5.0);
+    /// let m = DMatrix4::look_at_rh(&eye, &DPoint3::new(0.0, 0.0, 0.0), &DVector3::new(0.0, 1.0, 0.0));
+    /// assert!(m * DPoint3::new(0.0, 0.0, 0.0) == DVector4::new(0.0, 0.0, -5.0, 1.0));
+    /// ```
+    pub fn look_at_rh(eye: &DPoint3, target: &DPoint3, up: &DVector3) -> DMatrix4 {
+        let f = (*target - *eye).normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(&f);
+        Self::view(&s, &u, &-f, eye)
+    }
+
+    /// Returns a left-handed view matrix for a camera at eye looking toward
+    /// target. The camera looks down its positive z axis with up along
+    /// positive y, as in Direct3D.
+    ///
+    /// # Arguments
+    ///
+    /// * `eye` - The position of the camera.
+    /// * `target` - The point the camera looks at.
+    /// * `up` - The approximate up direction, not parallel to target - eye.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix4::DMatrix4;
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::dvector3::DVector3;
+    /// use math_engine::dvector4::DVector4;
+    /// let eye = DPoint3::new(0.0, 0.0, 5.0);
+    /// let m = DMatrix4::look_at_lh(&eye, &DPoint3::new(0.0, 0.0, 0.0), &DVector3::new(0.0, 1.0, 0.0));
+    /// assert!(m * DPoint3::new(0.0, 0.0, 0.0) == DVector4::new(0.0, 0.0, 5.0, 1.0));
+    /// ```
+    pub fn look_at_lh(eye: &DPoint3, target: &DPoint3, up: &DVector3) -> DMatrix4 {
+        let f = (*target - *eye).normalize();
+        let s = up.cross(&f).normalize();
+        let u = f.cross(&s);
+        Self::view(&s, &u, &f, eye)
+    }
+
+    /// Returns a right-handed perspective projection with a vertical field of
+    /// view fovy in radians, mapping depth from near to far onto [0, 1] as in
+    /// Vulkan, Direct3D and wgpu.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix4::DMatrix4;
+    /// use math_engine::dpoint3::DPoint3;
+    /// let m = DMatrix4::perspective_rh(1.0, 1.5, 1.0, 100.0);
+    /// let v = m * DPoint3::new(0.0, 0.0, -1.0);
+    /// assert!(v.z / v.w == 0.0);
+    /// ```
+    pub fn perspective_rh(fovy: f64, aspect: f64, near: f64, far: f64) -> DMatrix4 {
+        let d = far / (near - far);
+        Self::perspective(fovy, aspect, d, near * d, -1.0)
+    }
+
+    /// Returns a left-handed perspective projection mapping depth from near
+    /// to far onto [0, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    pub fn perspective_lh(fovy: f64, aspect: f64, near: f64, far: f64) -> DMatrix4 {
+        let d = far / (far - near);
+        Self::perspective(fovy, aspect, d, -near * d, 1.0)
+    }
+
+    /// Returns a right-handed perspective projection with reversed depth,
+    /// mapping the near plane to 1 and the far plane to 0. Paired with a
+    /// floating point depth buffer cleared to 0 and a greater-than depth test
+    /// this spreads precision far more evenly than the classic form.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix4::DMatrix4;
+    /// use math_engine::dpoint3::DPoint3;
+    /// let m = DMatrix4::perspective_reverse_rh(1.0, 1.5, 1.0, 100.0);
+    /// let v = m * DPoint3::new(0.0, 0.0, -1.0);
+    /// assert!((v.z / v.w - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn perspective_reverse_rh(fovy: f64, aspect: f64, near: f64, far: f64) -> DMatrix4 {
+        let d = near / (far - near);
+        Self::perspective(fovy, aspect, d, far * d, -1.0)
+    }
+
+    /// Returns a left-handed perspective projection with reversed depth,
+    /// mapping the near plane to 1 and the far plane to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    pub fn perspective_reverse_lh(fovy: f64, aspect: f64, near: f64, far: f64) -> DMatrix4 {
+        let d = near / (far - near);
+        Self::perspective(fovy, aspect, -d, far * d, 1.0)
+    }
+
+    /// Returns a right-handed perspective projection with the far plane at
+    /// infinity, mapping the near plane to 0 and infinity to 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    pub fn perspective_infinite_rh(fovy: f64, aspect: f64, near: f64) -> DMatrix4 {
+        Self::perspective(fovy, aspect, -1.0, -near, -1.0)
+    }
+
+    /// Returns a left-handed perspective projection with the far plane at
+    /// infinity, mapping the near plane to 0 and infinity to 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    pub fn perspective_infinite_lh(fovy: f64, aspect: f64, near: f64) -> DMatrix4 {
+        Self::perspective(fovy, aspect, 1.0, -near, 1.0)
+    }
+
+    /// Returns a right-handed perspective projection with reversed depth and
+    /// the far plane at infinity, mapping the near plane to 1 and infinity to
+    /// 0. This is the usual choice for reversed-Z rendering.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix4::DMatrix4;
+    /// use math_engine::dpoint3::DPoint3;
+    /// let m = DMatrix4::perspective_infinite_reverse_rh(1.0, 1.5, 0.1);
+    /// let v = m * DPoint3::new(0.0, 0.0, -0.1);
+    /// assert!(v.z / v.w == 1.0);
+    /// let v = m * DPoint3::new(0.0, 0.0, -1.0e30);
+    /// assert!(v.z / v.w < 1.0e-6);
+    /// ```
+    pub fn perspective_infinite_reverse_rh(fovy: f64, aspect: f64, near: f64) -> DMatrix4 {
+        Self::perspective(fovy, aspect, 0.0, near, -1.0)
+    }
+
+    /// Returns a left-handed perspective projection with reversed depth and
+    /// the far plane at infinity, mapping the near plane to 1 and infinity to
+    /// 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    pub fn perspective_infinite_reverse_lh(fovy: f64, aspect: f64, near: f64) -> DMatrix4 {
+        Self::perspective(fovy, aspect, 0.0, near, 1.0)
+    }
+
+    /// Returns a right-handed orthographic projection of the box bounded by
+    /// left, right, bottom, top and the near and far distances, mapping depth
+    /// onto [0, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The x coordinate of the left plane.
+    /// * `right` - The x coordinate of the right plane.
+    /// * `bottom` - The y coordinate of the bottom plane.
+    /// * `top` - The y coordinate of the top plane.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix4::DMatrix4;
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::dvector4::DVector4;
+    /// let m = DMatrix4::orthographic_rh(-2.0, 2.0, -1.0, 1.0, 0.0, 10.0);
+    /// assert!(m * DPoint3::new(2.0, -1.0, -10.0) == DVector4::new(1.0, -1.0, 1.0, 1.0));
+    /// ```
+    pub fn orthographic_rh(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> DMatrix4 {
+        let d = 1.0 / (near - far);
+        Self::orthographic(left, right, bottom, top, d, near * d)
+    }
+
+    /// Returns a left-handed orthographic projection mapping depth onto
+    /// [0, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The x coordinate of the left plane.
+    /// * `right` - The x coordinate of the right plane.
+    /// * `bottom` - The y coordinate of the bottom plane.
+    /// * `top` - The y coordinate of the top plane.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    pub fn orthographic_lh(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> DMatrix4 {
+        let d = 1.0 / (far - near);
+        Self::orthographic(left, right, bottom, top, d, -near * d)
+    }
+
+    /// Returns a right-handed orthographic projection with reversed depth,
+    /// mapping the near plane to 1 and the far plane to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The x coordinate of the left plane.
+    /// * `right` - The x coordinate of the right plane.
+    /// * `bottom` - The y coordinate of the bottom plane.
+    /// * `top` - The y coordinate of the top plane.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    pub fn orthographic_reverse_rh(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> DMatrix4 {
+        let d = 1.0 / (far - near);
+        Self::orthographic(left, right, bottom, top, d, far * d)
+    }
+
+    /// Returns a left-handed orthographic projection with reversed depth,
+    /// mapping the near plane to 1 and the far plane to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The x coordinate of the left plane.
+    /// * `right` - The x coordinate of the right plane.
+    /// * `bottom` - The y coordinate of the bottom plane.
+    /// * `top` - The y coordinate of the top plane.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    pub fn orthographic_reverse_lh(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> DMatrix4 {
+        let d = 1.0 / (far - near);
+        Self::orthographic(left, right, bottom, top, -d, far * d)
+    }
+
+    // Returns the perspective projection whose depth row is (0 0 a b) and
+    // whose last row is (0 0 w 0), w being -1 for right-handed and 1 for
+    // left-handed view spaces.
+    fn perspective(fovy: f64, aspect: f64, a: f64, b: f64, w: f64) -> DMatrix4 {
+        let f = 1.0 / (fovy * 0.5).tan();
+        Self::new(
+            f / aspect,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            f,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            a,
+            b,
+            0.0,
+            0.0,
+            w,
+            0.0,
+        )
+    }
+
+    // Returns the orthographic projection whose depth row is (0 0 a b).
+    fn orthographic(left: f64, right: f64, bottom: f64, top: f64, a: f64, b: f64) -> DMatrix4 {
+        let w = 1.0 / (right - left);
+        let h = 1.0 / (top - bottom);
+        Self::new(
+            2.0 * w,
+            0.0,
+            0.0,
+            -(right + left) * w,
+            0.0,
+            2.0 * h,
+            0.0,
+            -(top + bottom) * h,
+            0.0,
+            0.0,
+            a,
+            b,
+            0.0,
+            0.0,
+            0.0,
+            1.0,
+        )
+    }
+
+    // Returns the matrix whose rows are the camera axes x, y and z, moving
+    // the eye to the origin.
+    fn view(x: &DVector3, y: &DVector3, z: &DVector3, eye: &DPoint3) -> DMatrix4 {
+        let e = DVector3::from(*eye);
+        Self::new(
+            x.x,
+            x.y,
+            x.z,
+            -x.dot(&e),
+            y.x,
+            y.y,
+            y.z,
+            -y.dot(&e),
+            z.x,
+            z.y,
+            z.z,
+            -z.dot(&e),
+            0.0,
+            0.0,
+            0.0,
+            1.0,
+        )
+    }
+
+    /// Transforms every vector in vs in place by this matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `vs` - The vectors to transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dmatrix4::DMatrix4;
+    /// use math_engine::dvector4::DVector4;
+    /// let m = DMatrix4::identity() * 2.0;
+    /// let mut vs = [DVector4::new(1.0, 2.0, 3.0, 1.0); 8];
+    /// m.transform_slice(&mut vs);
+    /// assert!(vs[7] == DVector4::new(2.0, 4.0, 6.0, 2.0));
+    /// ```
+    pub fn transform_slice(&self, vs: &mut [DVector4]) {
+        for v in vs.iter_mut() {
+            *v = *self * *v;
+        }
+    }
+}
+
+impl Index<(usize, usize)> for DMatrix4 {
+    type Output = f64;
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < 4 && col < 4);
+        &self.n[col][row]
+    }
+}
+
+impl IndexMut<(usize, usize)> for DMatrix4 {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        assert!(row < 4 && col < 4);
+        &mut self.n[col][row]
+    }
+}
+
+impl Index<usize> for DMatrix4 {
+    type Output = DVector4;
+    fn index(&self, col: usize) -> &Self::Output {
+        assert!(col < 4);
+        &self.n[col]
+    }
+}
+
+impl IndexMut<usize> for DMatrix4 {
+    fn index_mut(&mut self, col: usize) -> &mut DVector4 {
+        assert!(col < 4);
+        &mut self.n[col]
+    }
+}
+
+impl Mul<DVector3> for DMatrix4 {
+    type Output = DVector4;
+
+    fn mul(self, rhs: DVector3) -> Self::Output {
+        DVector4::new(
+            self.n[0][0] * rhs.x + self.n[1][0] * rhs.y + self.n[2][0] * rhs.z,
+            self.n[0][1] * rhs.x + self.n[1][1] * rhs.y + self.n[2][1] * rhs.z,
+            self.n[0][2] * rhs.x + self.n[1][2] * rhs.y + self.n[2][2] * rhs.z,
+            self.n[0][3] * rhs.x + self.n[1][3] * rhs.y + self.n[2][3] * rhs.z,
+        )
+    }
+}
+
+impl Mul<DVector4> for DMatrix4 {
+    type Output = DVector4;
+
+    fn mul(self, rhs: DVector4) -> Self::Output {
+        DVector4::new(
+            self.n[0][0] * rhs.x
+                + self.n[1][0] * rhs.y
+                + self.n[2][0] * rhs.z
+                + self.n[3][0] * rhs.w,
+            self.n[0][1] * rhs.x
+                + self.n[1][1] * rhs.y
+                + self.n[2][1] * rhs.z
+                + self.n[3][1] * rhs.w,
+            self.n[0][2] * rhs.x
+                + self.n[1][2] * rhs.y
+                + self.n[2][2] * rhs.z
+                + self.n[3][2] * rhs.w,
+            self.n[0][3] * rhs.x
+                + self.n[1][3] * rhs.y
+                + self.n[2][3] * rhs.z
+                + self.n[3][3] * rhs.w,
+        )
+    }
+}
+
+impl Neg for DMatrix4 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            n: self.n.map(Neg::neg),
+        }
+    }
+}
+
+impl Mul<DPoint3> for DMatrix4 {
+    type Output = DVector4;
+
+    fn mul(self, rhs: DPoint3) -> Self::Output {
+        DVector4::new(
+            self.n[0][0] * rhs.x + self.n[1][0] * rhs.y + self.n[2][0] * rhs.z + self.n[3][0],
+            self.n[0][1] * rhs.x + self.n[1][1] * rhs.y + self.n[2][1] * rhs.z + self.n[3][1],
+            self.n[0][2] * rhs.x + self.n[1][2] * rhs.y + self.n[2][2] * rhs.z + self.n[3][2],
+            self.n[0][3] * rhs.x + self.n[1][3] * rhs.y + self.n[2][3] * rhs.z + self.n[3][3],
+        )
+    }
+}
+
+impl Mul<DPoint2> for DMatrix4 {
+    type Output = DVector4;
+
+    fn mul(self, rhs: DPoint2) -> Self::Output {
+        DVector4::new(
+            self.n[0][0] * rhs.x + self.n[1][0] * rhs.y + self.n[3][0],
+            self.n[0][1] * rhs.x + self.n[1][1] * rhs.y + self.n[3][1],
+            self.n[0][2] * rhs.x + self.n[1][2] * rhs.y + self.n[3][2],
+            self.n[0][3] * rhs.x + self.n[1][3] * rhs.y + self.n[3][3],
+        )
+    }
+}
+
+impl Mul<f64> for DMatrix4 {
+    type Output = Self;
+
+    fn mul(self, s: f64) -> Self::Output {
+        Self::new(
+            self.n[0][0] * s,
+            self.n[1][0] * s,
+            self.n[2][0] * s,
+            self.n[3][0] * s,
+            self.n[0][1] * s,
+            self.n[1][1] * s,
+            self.n[2][1] * s,
+            self.n[3][1] * s,
+            self.n[0][2] * s,
+            self.n[1][2] * s,
+            self.n[2][2] * s,
+            self.n[3][2] * s,
+            self.n[0][3] * s,
+            self.n[1][3] * s,
+            self.n[2][3] * s,
+            self.n[3][3] * s,
+        )
+    }
+}
+
+impl Mul<DMatrix4> for f64 {
+    type Output = DMatrix4;
+
+    fn mul(self, rhs: DMatrix4) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<DMatrix4> for DMatrix4 {
+    type Output = Self;
+    fn mul(self, rhs: DMatrix4) -> Self::Output {
+        Self::new(
+            self.n[0][0] * rhs[(0, 0)]
+                + self.n[1][0] * rhs[(1, 0)]
+                + self.n[2][0] * rhs[(2, 0)]
+                + self.n[3][0] * rhs[(3, 0)],
+            self.n[0][0] * rhs[(0, 1)]
+                + self.n[1][0] * rhs[(1, 1)]
+                + self.n[2][0] * rhs[(2, 1)]
+                + self.n[3][0] * rhs[(3, 1)],
+            self.n[0][0] * rhs[(0, 2)]
+                + self.n[1][0] * rhs[(1, 2)]
+                + self.n[2][0] * rhs[(2, 2)]
+                + self.n[3][0] * rhs[(3, 2)],
+            self.n[0][0] * rhs[(0, 3)]
+                + self.n[1][0] * rhs[(1, 3)]
+                + self.n[2][0] * rhs[(2, 3)]
+                + self.n[3][0] * rhs[(3, 3)],
+            self.n[0][1] * rhs[(0, 0)]
+                + self.n[1][1] * rhs[(1, 0)]
+                + self.n[2][1] * rhs[(2, 0)]
+                + self.n[3][1] * rhs[(3, 0)],
+            self.n[0][1] * rhs[(0, 1)]
+                + self.n[1][1] * rhs[(1, 1)]
+                + self.n[2][1] * rhs[(2, 1)]
+                + self.n[3][1] * rhs[(3, 1)],
+            self.n[0][1] * rhs[(0, 2)]
+                + self.n[1][1] * rhs[(1, 2)]
+                + self.n[2][1] * rhs[(2, 2)]
+                + self.n[3][1] * rhs[(3, 2)],
+            self.n[0][1] * rhs[(0, 3)]
+                + self.n[1][1] * rhs[(1, 3)]
+                + self.n[2][1] * rhs[(2, 3)]
+                + self.n[3][1] * rhs[(3, 3)],
+            self.n[0][2] * rhs[(0, 0)]
+                + self.n[1][2] * rhs[(1, 0)]
+                + self.n[2][2] * rhs[(2, 0)]
+                + self.n[3][2] * rhs[(3, 0)],
+            self.n[0][2] * rhs[(0, 1)]
+                + self.n[1][2] * rhs[(1, 1)]
+                + self.n[2][2] * rhs[(2, 1)]
+                + self.n[3][2] * rhs[(3, 1)],
+            self.n[0][2] * rhs[(0, 2)]
+                + self.n[1][2] * rhs[(1, 2)]
+                + self.n[2][2] * rhs[(2, 2)]
+                + self.n[3][2] * rhs[(3, 2)],
+            self.n[0][2] * rhs[(0, 3)]
+                + self.n[1][2] * rhs[(1, 3)]
+                + self.n[2][2] * rhs[(2, 3)]
+                + self.n[3][2] * rhs[(3, 3)],
+            self.n[0][3] * rhs[(0, 0)]
+                + self.n[1][3] * rhs[(1, 0)]
+                + self.n[2][3] * rhs[(2, 0)]
+                + self.n[3][3] * rhs[(3, 0)],
+            self.n[0][3] * rhs[(0, 1)]
+                + self.n[1][3] * rhs[(1, 1)]
+                + self.n[2][3] * rhs[(2, 1)]
+                + self.n[3][3] * rhs[(3, 1)],
+            self.n[0][3] * rhs[(0, 2)]
+                + self.n[1][3] * rhs[(1, 2)]
+                + self.n[2][3] * rhs[(2, 2)]
+                + self.n[3][3] * rhs[(3, 2)],
+            self.n[0][3] * rhs[(0, 3)]
+                + self.n[1][3] * rhs[(1, 3)]
+                + self.n[2][3] * rhs[(2, 3)]
+                + self.n[3][3] * rhs[(3, 3)],
+        )
+    }
+}
+
+impl MulAssign<DMatrix4> for DMatrix4 {
+    fn mul_assign(&mut self, rhs: DMatrix4) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<f64> for DMatrix4 {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.n[0][0] *= rhs;
+        self.n[1][0] *= rhs;
+        self.n[2][0] *= rhs;
+        self.n[3][0] *= rhs;
+        self.n[0][1] *= rhs;
+        self.n[1][1] *= rhs;
+        self.n[2][1] *= rhs;
+        self.n[3][1] *= rhs;
+        self.n[0][2] *= rhs;
+        self.n[1][2] *= rhs;
+        self.n[2][2] *= rhs;
+        self.n[3][2] *= rhs;
+        self.n[0][3] *= rhs;
+        self.n[1][3] *= rhs;
+        self.n[2][3] *= rhs;
+        self.n[3][3] *= rhs;
+    }
+}
+
+impl Div<f64> for DMatrix4 {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        let s = 1.0 / rhs;
+        Self::new(
+            self.n[0][0] * s,
+            self.n[1][0] * s,
+            self.n[2][0] * s,
+            self.n[3][0] * s,
+            self.n[0][1] * s,
+            self.n[1][1] * s,
+            self.n[2][1] * s,
+            self.n[3][1] * s,
+            self.n[0][2] * s,
+            self.n[1][2] * s,
+            self.n[2][2] * s,
+            self.n[3][2] * s,
+            self.n[0][3] * s,
+            self.n[1][3] * s,
+            self.n[2][3] * s,
+            self.n[3][3] * s,
+        )
+    }
+}
+
+impl DivAssign<f64> for DMatrix4 {
+    fn div_assign(&mut self, rhs: f64) {
+        self.n[0][0] /= rhs;
+        self.n[1][0] /= rhs;
+        self.n[2][0] /= rhs;
+        self.n[3][0] /= rhs;
+        self.n[0][1] /= rhs;
+        self.n[1][1] /= rhs;
+        self.n[2][1] /= rhs;
+        self.n[3][1] /= rhs;
+        self.n[0][2] /= rhs;
+        self.n[1][2] /= rhs;
+        self.n[2][2] /= rhs;
+        self.n[3][2] /= rhs;
+        self.n[0][3] /= rhs;
+        self.n[1][3] /= rhs;
+        self.n[2][3] /= rhs;
+        self.n[3][3] /= rhs;
+    }
+}
+
+impl From<DMatrix3> for DMatrix4 {
+    /// Returns the 4x4 matrix with m as its upper left 3x3 block.
+    fn from(m: DMatrix3) -> Self {
+        DMatrix4::new_with_vecs(
+            DVector4::new(m[0].x, m[0].y, m[0].z, 0.0),
+            DVector4::new(m[1].x, m[1].y, m[1].z, 0.0),
+            DVector4::new(m[2].x, m[2].y, m[2].z, 0.0),
+            DVector4::new(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+}
+
+impl From<Matrix4> for DMatrix4 {
+    fn from(m: Matrix4) -> Self {
+        DMatrix4::new_with_vecs(
+            DVector4::from(m[0]),
+            DVector4::from(m[1]),
+            DVector4::from(m[2]),
+            DVector4::from(m[3]),
+        )
+    }
+}
+
+impl Add for DMatrix4 {
+    type Output = Self;
+
+    fn add(self, rhs: DMatrix4) -> Self::Output {
+        Self {
+            n: core::array::from_fn(|i| self.n[i] + rhs.n[i]),
+        }
+    }
+}
+
+impl Sub for DMatrix4 {
+    type Output = Self;
+
+    fn sub(self, rhs: DMatrix4) -> Self::Output {
+        Self {
+            n: core::array::from_fn(|i| self.n[i] - rhs.n[i]),
+        }
+    }
+}
+
+impl AddAssign for DMatrix4 {
+    fn add_assign(&mut self, rhs: DMatrix4) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for DMatrix4 {
+    fn sub_assign(&mut self, rhs: DMatrix4) {
+        *self = *self - rhs;
+    }
+}
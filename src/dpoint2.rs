@@ -0,0 +1,160 @@
+use crate::prelude::*;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A double precision two dimensional positional vector having float components
+/// x, and y. It's w coordinated it's assumed to be 0.
+#[repr(C)]
+pub struct DPoint2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl DPoint2 {
+    /// The zero point, the origin.
+    pub const ZERO: DPoint2 = DPoint2::new(0.0, 0.0);
+
+    /// Returns a positional vector initialized with the floating point components x, and y.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinates.
+    /// * `y` - The y coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint2::DPoint2;
+    /// let p = DPoint2::new(1.0,0.0);
+    /// ```
+    pub const fn new(x: f64, y: f64) -> DPoint2 {
+        DPoint2 { x, y }
+    }
+
+    /// Returns the point with every component set to s.
+    pub const fn splat(s: f64) -> DPoint2 {
+        DPoint2::new(s, s)
+    }
+
+    /// Returns this point converted to single precision, rounding each
+    /// component to the nearest f32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint2::DPoint2;
+    /// let p = DPoint2::new(1.0, 2.0).to_f32();
+    /// assert!(p.x == 1.0 && p.y == 2.0);
+    /// ```
+    pub fn to_f32(&self) -> Point2 {
+        Point2::new(self.x as f32, self.y as f32)
+    }
+
+    /// Returns the distance between this point and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dpoint2.
+    pub fn distance(&self, other: &DPoint2) -> f64 {
+        (*self - *other).magnitude()
+    }
+
+    /// Returns the squared distance between this point and other, which
+    /// orders points by distance without a square root.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dpoint2.
+    pub fn distance_squared(&self, other: &DPoint2) -> f64 {
+        let d = *self - *other;
+        d.dot(&d)
+    }
+
+    /// Returns the point halfway between this point and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dpoint2.
+    pub fn midpoint(&self, other: &DPoint2) -> DPoint2 {
+        DPoint2::new((self.x + other.x) * 0.5, (self.y + other.y) * 0.5)
+    }
+}
+
+impl Add<DVector2> for DPoint2 {
+    type Output = Self;
+
+    fn add(self, other: DVector2) -> Self::Output {
+        DPoint2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub<DVector2> for DPoint2 {
+    type Output = Self;
+
+    fn sub(self, other: DVector2) -> Self::Output {
+        DPoint2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for DPoint2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Sub<DPoint2> for DPoint2 {
+    type Output = DVector2;
+
+    fn sub(self, other: DPoint2) -> Self::Output {
+        DVector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<DMatrix4> for DVector2 {
+    type Output = DVector4;
+
+    fn mul(self, other: DMatrix4) -> Self::Output {
+        DVector4::new(
+            other[(0, 0)] * self.x + other[(1, 0)] * self.y,
+            other[(0, 1)] * self.x + other[(1, 1)] * self.y,
+            other[(0, 2)] * self.x + other[(1, 2)] * self.y,
+            other[(0, 3)] * self.x + other[(1, 3)] * self.y,
+        )
+    }
+}
+
+impl Mul<DMatrix4> for DPoint2 {
+    type Output = DVector4;
+
+    fn mul(self, other: DMatrix4) -> Self::Output {
+        DVector4::new(
+            other[(0, 0)] * self.x + other[(1, 0)] * self.y + other[(3, 0)],
+            other[(0, 1)] * self.x + other[(1, 1)] * self.y + other[(3, 1)],
+            other[(0, 2)] * self.x + other[(1, 2)] * self.y + other[(3, 2)],
+            other[(0, 3)] * self.x + other[(1, 3)] * self.y + other[(3, 3)],
+        )
+    }
+}
+
+impl From<Point2> for DPoint2 {
+    fn from(p: Point2) -> Self {
+        DPoint2::new(f64::from(p.x), f64::from(p.y))
+    }
+}
+
+impl AddAssign<DVector2> for DPoint2 {
+    fn add_assign(&mut self, rhs: DVector2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl SubAssign<DVector2> for DPoint2 {
+    fn sub_assign(&mut self, rhs: DVector2) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
@@ -0,0 +1,285 @@
+use crate::prelude::*;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A double precision three dimensional positional vector having float components
+/// x, y, and z. It's w coordinated it's assumed to be 0.
+#[repr(C)]
+pub struct DPoint3 {
+    /// The x component.
+    pub x: f64,
+    /// The y component.
+    pub y: f64,
+    /// The z component.
+    pub z: f64,
+}
+
+impl DPoint3 {
+    /// The zero point, the origin.
+    pub const ZERO: DPoint3 = DPoint3::new(0.0, 0.0, 0.0);
+
+    /// Returns a positional vector initialized with the floating point components x, y, and z.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinates.
+    /// * `y` - The y coordinates.
+    /// * `z` - The z coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint3::DPoint3;
+    /// let dpoint3 = DPoint3::new(1.0,0.0,0.0);
+    /// ```
+    pub const fn new(x: f64, y: f64, z: f64) -> DPoint3 {
+        DPoint3 { x, y, z }
+    }
+
+    /// Returns the point with every component set to s.
+    pub const fn splat(s: f64) -> DPoint3 {
+        DPoint3::new(s, s, s)
+    }
+
+    /// Returns this point converted to single precision, rounding each
+    /// component to the nearest f32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::point3::Point3;
+    /// let p = DPoint3::new(1.0, 2.0, 3.0);
+    /// assert!(p.to_f32() == Point3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn to_f32(&self) -> Point3 {
+        Point3::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+
+    /// Returns the distance between this point and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dpoint3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint3::DPoint3;
+    /// let a = DPoint3::new(1.0, 2.0, 3.0);
+    /// let b = DPoint3::new(3.0, 5.0, -3.0);
+    /// assert!(a.distance(&b) == 7.0);
+    /// assert!(a.distance_squared(&b) == 49.0);
+    /// ```
+    pub fn distance(&self, other: &DPoint3) -> f64 {
+        (*self - *other).magnitude()
+    }
+
+    /// Returns the squared distance between this point and other, which
+    /// orders points by distance without a square root.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dpoint3.
+    pub fn distance_squared(&self, other: &DPoint3) -> f64 {
+        let d = *self - *other;
+        d.dot(&d)
+    }
+
+    /// Returns the point halfway between this point and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dpoint3.
+    pub fn midpoint(&self, other: &DPoint3) -> DPoint3 {
+        DPoint3::new(
+            (self.x + other.x) * 0.5,
+            (self.y + other.y) * 0.5,
+            (self.z + other.z) * 0.5,
+        )
+    }
+}
+
+impl Add<DVector3> for DPoint3 {
+    type Output = Self;
+
+    fn add(self, other: DVector3) -> Self::Output {
+        DPoint3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub<DVector3> for DPoint3 {
+    type Output = Self;
+
+    fn sub(self, other: DVector3) -> Self::Output {
+        DPoint3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for DPoint3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Sub for DPoint3 {
+    type Output = DVector3;
+
+    fn sub(self, other: DPoint3) -> Self::Output {
+        DVector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<DMatrix4> for DVector3 {
+    type Output = DVector4;
+
+    fn mul(self, other: DMatrix4) -> DVector4 {
+        DVector4::new(
+            other[(0, 0)] * self.x + other[(1, 0)] * self.y + other[(2, 0)] * self.z,
+            other[(0, 1)] * self.x + other[(1, 1)] * self.y + other[(2, 1)] * self.z,
+            other[(0, 2)] * self.x + other[(1, 2)] * self.y + other[(2, 2)] * self.z,
+            other[(0, 3)] * self.x + other[(1, 3)] * self.y + other[(2, 3)] * self.z,
+        )
+    }
+}
+
+impl Mul<DMatrix4> for DPoint3 {
+    type Output = DVector4;
+
+    fn mul(self, other: DMatrix4) -> Self::Output {
+        DVector4::new(
+            other[(0, 0)] * self.x
+                + other[(1, 0)] * self.y
+                + other[(2, 0)] * self.z
+                + other[(3, 0)],
+            other[(0, 1)] * self.x
+                + other[(1, 1)] * self.y
+                + other[(2, 1)] * self.z
+                + other[(3, 1)],
+            other[(0, 2)] * self.x
+                + other[(1, 2)] * self.y
+                + other[(2, 2)] * self.z
+                + other[(3, 2)],
+            other[(0, 3)] * self.x
+                + other[(1, 3)] * self.y
+                + other[(2, 3)] * self.z
+                + other[(3, 3)],
+        )
+    }
+}
+
+impl From<DVector3> for DPoint3 {
+    fn from(p: DVector3) -> Self {
+        DPoint3 {
+            x: p.x,
+            y: p.y,
+            z: p.z,
+        }
+    }
+}
+
+impl From<Point3> for DPoint3 {
+    fn from(p: Point3) -> Self {
+        DPoint3::new(f64::from(p.x), f64::from(p.y), f64::from(p.z))
+    }
+}
+
+// Returns the distance between the point q and the line determined by the point
+// p and the direction v.
+pub fn point_line_distance(q: &DPoint3, p: &DPoint3, v: &DVector3) -> f64 {
+    let a = (*q - *p).cross(v);
+    f64::sqrt(a.dot(&a) / v.dot(v))
+}
+
+// Returns the distance between two lines determined by the points p1 and p2 and the
+// directions v1 and v2.
+pub fn line_line_distance(p1: &DPoint3, v1: &DVector3, p2: &DPoint3, v2: &DVector3) -> f64 {
+    let dp = *p2 - *p1;
+
+    let v12 = v1.dot(v1);
+    let v22 = v2.dot(v2);
+    let v1v2 = v1.dot(v2);
+
+    let mut det = v1v2 * v1v2 - v12 * v22;
+
+    if det.abs() > f64::MIN {
+        det = 1.0 / det;
+
+        let dpv1 = dp.dot(v1);
+        let dpv2 = dp.dot(v2);
+        let t1 = (v1v2 * dpv2 - v22 * dpv1) * det;
+        let t2 = (v12 * dpv2 - v1v2 * dpv1) * det;
+        (dp + *v2 * t2 - *v1 * t1).magnitude()
+    } else {
+        let a = dp.cross(v1);
+        f64::sqrt(a.dot(&a) / v12)
+    }
+}
+
+/// Returns the centroid of the points, their average, or None if there are
+/// none.
+///
+/// # Arguments
+///
+/// * `points` - A slice of points.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::dpoint3::{centroid, DPoint3};
+/// let ps = [DPoint3::new(0.0, 0.0, 0.0), DPoint3::new(4.0, 0.0, 0.0), DPoint3::new(2.0, 6.0, 3.0)];
+/// assert!(centroid(&ps) == Some(DPoint3::new(2.0, 2.0, 1.0)));
+/// assert!(centroid(&[]).is_none());
+/// ```
+pub fn centroid(points: &[DPoint3]) -> Option<DPoint3> {
+    if points.is_empty() {
+        return None;
+    }
+    let sum = points
+        .iter()
+        .fold(DVector3::default(), |s, p| s + DVector3::new(p.x, p.y, p.z));
+    let c = sum / points.len() as f64;
+    Some(DPoint3::new(c.x, c.y, c.z))
+}
+
+/// Returns the component-wise minimum and maximum of the points, the corners
+/// of their axis-aligned bounding box, or None if there are none.
+///
+/// # Arguments
+///
+/// * `points` - A slice of points.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::dpoint3::{bounding_points, DPoint3};
+/// let ps = [DPoint3::new(1.0, -2.0, 0.0), DPoint3::new(-1.0, 4.0, 2.0)];
+/// let (lo, hi) = bounding_points(&ps).unwrap();
+/// assert!(lo == DPoint3::new(-1.0, -2.0, 0.0) && hi == DPoint3::new(1.0, 4.0, 2.0));
+/// ```
+pub fn bounding_points(points: &[DPoint3]) -> Option<(DPoint3, DPoint3)> {
+    let (first, rest) = points.split_first()?;
+    Some(
+        rest.iter()
+            .fold((*first, *first), |(lo, hi), p| (lo.min(p), hi.max(p))),
+    )
+}
+
+impl AddAssign<DVector3> for DPoint3 {
+    fn add_assign(&mut self, rhs: DVector3) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl SubAssign<DVector3> for DPoint3 {
+    fn sub_assign(&mut self, rhs: DVector3) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
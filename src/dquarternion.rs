@@ -0,0 +1,739 @@
+use crate::prelude::*;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a double precision hamiltonian quaternion having the form xi + yj + zk + w.
+/// https://en.wikipedia.org/wiki/Quaternion
+#[repr(C)]
+pub struct DQuarternion {
+    /// The x coordinate of the vector part.
+    pub x: f64,
+    /// The y coordinate of the vector part.
+    pub y: f64,
+    /// The z coordinate of the vector part.
+    pub z: f64,
+    /// The w coordinate is the scalar part.
+    pub w: f64,
+}
+
+impl DQuarternion {
+    /// The identity quaternion 1, representing no rotation.
+    pub const IDENTITY: DQuarternion = DQuarternion {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 1.0,
+    };
+
+    /// Returns a quaternion initialized with the floating point components x, y, z, and w.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate of the vector part.
+    /// * `y` - The y coordinate of the vector part.
+    /// * `z` - The z coordinate of the vector part.
+    /// * `w` - The scalar part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let q = DQuarternion::new(1.0,0.0,0.0,1.0);
+    /// ```
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> DQuarternion {
+        DQuarternion { x, y, z, w }
+    }
+
+    /// Returns a quaternion initialized with the vector components assigned to x, y, and z,
+    /// with the w coordinated set to s.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - A 3D vector.
+    /// * `s` - The scalar part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// use math_engine::dvector3::DVector3;
+    /// let q = DQuarternion::new_with_vec_and_scalar(&DVector3::new(1.0,0.0,0.0),1.0);
+    /// ```
+    pub fn new_with_vec_and_scalar(a: &DVector3, s: f64) -> DQuarternion {
+        DQuarternion {
+            x: a.x,
+            y: a.y,
+            z: a.z,
+            w: s,
+        }
+    }
+
+    /// Returns a quaternion initialized with the vector components assigned to x, y, and z,
+    /// with the w coordinated set to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - A 3D vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// use math_engine::dvector3::DVector3;
+    /// let q = DQuarternion::new_with_vec(&DVector3::new(1.0,0.0,0.0));
+    /// ```
+    pub fn new_with_vec(a: &DVector3) -> DQuarternion {
+        DQuarternion {
+            x: a.x,
+            y: a.y,
+            z: a.z,
+            w: 0.0,
+        }
+    }
+
+    /// Returns a quaternion initialized x, y, and z components to 0 and
+    /// sets the w coordinated set to s.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The scalar part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let q = DQuarternion::new_with_scalar(1.0);
+    /// ```
+    pub fn new_with_scalar(s: f64) -> DQuarternion {
+        DQuarternion {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: s,
+        }
+    }
+
+    pub fn get_vector_part(&self) -> DVector3 {
+        DVector3::new(self.x, self.y, self.z)
+    }
+
+    /// Returns a converted quaternion to a 3x3 matrix.
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let q = DQuarternion::new_with_scalar(1.0);
+    /// let m = q.get_rotation_matrix();
+    /// ```
+    pub fn get_rotation_matrix(&self) -> DMatrix3 {
+        let x2 = self.x * self.x;
+        let y2 = self.y * self.y;
+        let z2 = self.z * self.z;
+        let xy = self.x * self.y;
+        let xz = self.x * self.z;
+        let yz = self.y * self.z;
+        let wx = self.w * self.x;
+        let wy = self.w * self.y;
+        let wz = self.w * self.z;
+        DMatrix3::new(
+            1.0 - 2.0 * (y2 + z2),
+            2.0 * (xy - wz),
+            2.0 * (xz + wy),
+            2.0 * (xy + wz),
+            1.0 - 2.0 * (x2 + z2),
+            2.0 * (yz - wx),
+            2.0 * (xz - wy),
+            2.0 * (yz + wx),
+            1.0 - 2.0 * (x2 + y2),
+        )
+    }
+
+    /// Sets the component of the quaternion to represent the same rotation
+    /// as specified by the matrix m.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - A 3x3 matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// use math_engine::dmatrix3::DMatrix3;
+    /// let mut q = DQuarternion::new_with_scalar(1.0);
+    /// q.set_rotation_matrix(&DMatrix3::identity());
+    /// ```
+    pub fn set_rotation_matrix(&mut self, m: &DMatrix3) {
+        let m00 = m[(0, 0)];
+        let m11 = m[(1, 1)];
+        let m22 = m[(2, 2)];
+        let sum = m00 + m11 + m22;
+
+        if sum > 0.0 {
+            self.w = f64::sqrt(sum + 1.0) * 0.5;
+            let f = 0.25 / self.w;
+            self.x = (m[(2, 1)] - m[(1, 2)]) * f;
+            self.y = (m[(0, 2)] - m[(2, 0)]) * f;
+            self.z = (m[(1, 0)] - m[(0, 1)]) * f;
+        } else if (m00 > m11) && (m00 > m22) {
+            self.x = f64::sqrt(m00 - m11 - m22 + 1.0) * 0.5;
+            let f = 0.25 / self.x;
+            self.y = (m[(1, 0)] + m[(0, 1)]) * f;
+            self.z = (m[(0, 2)] + m[(2, 0)]) * f;
+            self.w = (m[(2, 1)] - m[(1, 2)]) * f;
+        } else if m11 > m22 {
+            self.y = f64::sqrt(m11 - m00 - m22 + 1.0) * 0.5;
+            let f = 0.25 / self.y;
+            self.x = (m[(1, 0)] + m[(0, 1)]) * f;
+            self.z = (m[(2, 1)] + m[(1, 2)]) * f;
+            self.w = (m[(0, 2)] - m[(2, 0)]) * f;
+        } else {
+            self.z = f64::sqrt(m22 - m00 - m11 + 1.0) * 0.5;
+            let f = 0.25 / self.z;
+            self.x = (m[(0, 2)] + m[(2, 0)]) * f;
+            self.y = (m[(2, 1)] + m[(1, 2)]) * f;
+            self.w = (m[(1, 0)] - m[(0, 1)]) * f;
+        }
+    }
+
+    /// Returns the transformation of the vector v with the quaternion.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A 3D vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// use math_engine::dvector3::DVector3;
+    /// let q = DQuarternion::new_with_scalar(1.0);
+    /// let v = q.transform(&DVector3::new(1.0,0.0,0.0));
+    /// ```
+    pub fn transform(&self, v: &DVector3) -> DVector3 {
+        let b = self.get_vector_part();
+        let b2 = b.x * b.x + b.y * b.y + b.z * b.z;
+        *v * (self.w * self.w - b2) + b * (v.dot(&b) * 2.0) + b.cross(v) * (self.w * 2.0)
+    }
+
+    /// Returns this quaternion converted to single precision, rounding each
+    /// component to the nearest f32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let q = DQuarternion::new(0.0, 0.0, 0.0, 1.0).to_f32();
+    /// assert!(q.w == 1.0);
+    /// ```
+    pub fn to_f32(&self) -> Quarternion {
+        Quarternion::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+
+    /// Returns the identity quaternion, representing no rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let q = DQuarternion::identity();
+    /// assert!(q == DQuarternion::IDENTITY);
+    /// ```
+    pub const fn identity() -> DQuarternion {
+        Self::IDENTITY
+    }
+
+    /// Returns the dot product of the quaternion with other, treating both as
+    /// 4D vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let q = DQuarternion::new(1.0, 2.0, 3.0, 4.0);
+    /// assert!(q.dot(&q) == 30.0);
+    /// ```
+    pub fn dot(&self, other: &DQuarternion) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Returns the magnitude, or norm, of the quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let q = DQuarternion::new(1.0, 1.0, 1.0, 1.0);
+    /// assert!(q.magnitude() == 2.0);
+    /// ```
+    pub fn magnitude(&self) -> f64 {
+        f64::sqrt(self.dot(self))
+    }
+
+    /// Returns the quaternion scaled to unit magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let q = DQuarternion::new(0.0, 0.0, 3.0, 4.0).normalize();
+    /// assert!(q == DQuarternion::new(0.0, 0.0, 0.6, 0.8));
+    /// ```
+    pub fn normalize(&self) -> DQuarternion {
+        *self / self.magnitude()
+    }
+
+    /// Scales the quaternion to unit magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let mut q = DQuarternion::new(0.0, 0.0, 3.0, 4.0);
+    /// q.normalize_mut();
+    /// assert!(q.magnitude() == 1.0);
+    /// ```
+    pub fn normalize_mut(&mut self) {
+        *self = self.normalize();
+    }
+
+    /// Returns this quaternion normalized to unit length, or None if its
+    /// length is zero or not finite, where `normalize` would return NaNs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// assert!(DQuarternion::new(0.0, 0.0, 0.0, 0.0).try_normalize().is_none());
+    /// assert!(DQuarternion::new(0.0, 0.0, 0.0, 2.0).try_normalize() == Some(DQuarternion::IDENTITY));
+    /// ```
+    pub fn try_normalize(&self) -> Option<DQuarternion> {
+        let m = self.magnitude();
+        if m > 0.0 && m.is_finite() && (1.0 / m).is_finite() {
+            Some(*self / m)
+        } else {
+            None
+        }
+    }
+
+    /// Returns this quaternion normalized to unit length, or the zero
+    /// quaternion if it cannot be normalized, see `try_normalize`.
+    pub fn normalize_or_zero(&self) -> DQuarternion {
+        self.try_normalize()
+            .unwrap_or(DQuarternion::new(0.0, 0.0, 0.0, 0.0))
+    }
+
+    /// Returns the conjugate of the quaternion, which negates the vector
+    /// part. For a unit quaternion this is the inverse rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let q = DQuarternion::new(1.0, 2.0, 3.0, 4.0);
+    /// assert!(q.conjugate() == DQuarternion::new(-1.0, -2.0, -3.0, 4.0));
+    /// ```
+    pub fn conjugate(&self) -> DQuarternion {
+        DQuarternion::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the multiplicative inverse of the quaternion, the conjugate
+    /// divided by the squared magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let q = DQuarternion::new(0.0, 0.0, 0.0, 2.0);
+    /// assert!(q.inverse() == DQuarternion::new(-0.0, -0.0, -0.0, 0.5));
+    /// assert!(q * q.inverse() == DQuarternion::IDENTITY);
+    /// ```
+    pub fn inverse(&self) -> DQuarternion {
+        self.conjugate() / self.dot(self)
+    }
+
+    /// Returns the rotation interpolated linearly between this unit
+    /// quaternion at t = 0 and other at t = 1, then normalized. It takes the
+    /// shorter way round, like `slerp`, but does not turn at constant speed.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the unit quaternion at t = 1.
+    /// * `t` - The interpolation parameter.
+    pub fn nlerp(&self, other: &DQuarternion, t: f64) -> DQuarternion {
+        let other = if self.dot(other) < 0.0 {
+            -*other
+        } else {
+            *other
+        };
+        (*self * (1.0 - t) + other * t).normalize()
+    }
+
+    /// Returns the rotation interpolated along the shorter great arc between
+    /// this unit quaternion at t = 0 and other at t = 1, turning at constant
+    /// angular speed.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the unit quaternion at t = 1.
+    /// * `t` - The interpolation parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let a = DQuarternion::from_rotation_z(0.0);
+    /// let b = DQuarternion::from_rotation_z(2.0);
+    /// let q = a.slerp(&b, 0.25);
+    /// assert!((q - DQuarternion::from_rotation_z(0.5)).magnitude() < 1e-12);
+    /// ```
+    pub fn slerp(&self, other: &DQuarternion, t: f64) -> DQuarternion {
+        let mut cos = self.dot(other);
+        let other = if cos < 0.0 {
+            cos = -cos;
+            -*other
+        } else {
+            *other
+        };
+        // Nearly equal rotations divide by a vanishing sine.
+        if cos > 0.9995 {
+            return self.nlerp(&other, t);
+        }
+        let sin = (1.0 - cos * cos).sqrt();
+        let angle = sin.atan2(cos);
+        (*self * ((1.0 - t) * angle).sin() + other * (t * angle).sin()) / sin
+    }
+
+    /// Returns the unit quaternion representing a rotation through angle
+    /// radians about axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - A reference to the unit length axis of rotation.
+    /// * `angle` - The angle in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// use math_engine::dvector3::DVector3;
+    /// let q = DQuarternion::from_axis_angle(&DVector3::new(0.0, 0.0, 1.0), core::f64::consts::PI);
+    /// let v = q.transform(&DVector3::new(1.0, 0.0, 0.0));
+    /// assert!((v - DVector3::new(-1.0, 0.0, 0.0)).magnitude() < 1e-12);
+    /// ```
+    pub fn from_axis_angle(axis: &DVector3, angle: f64) -> DQuarternion {
+        let h = angle * 0.5;
+        DQuarternion::new_with_vec_and_scalar(&(*axis * h.sin()), h.cos())
+    }
+
+    /// Returns the unit axis and the angle in radians, in [0, 2pi], of the
+    /// rotation represented by the quaternion, undoing
+    /// `DQuarternion::from_axis_angle`. The quaternion need not be normalized.
+    /// For a rotation through a zero angle the x axis is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// use math_engine::dvector3::DVector3;
+    /// let q = DQuarternion::from_axis_angle(&DVector3::new(0.0, 1.0, 0.0), 0.5);
+    /// let (axis, angle) = q.to_axis_angle();
+    /// assert!((axis - DVector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-12);
+    /// assert!((angle - 0.5).abs() < 1e-12);
+    /// ```
+    pub fn to_axis_angle(&self) -> (DVector3, f64) {
+        let v = self.get_vector_part();
+        let s = v.magnitude();
+        if s <= f64::EPSILON * self.w.abs() {
+            return (DVector3::new(1.0, 0.0, 0.0), 0.0);
+        }
+        (v / s, 2.0 * s.atan2(self.w))
+    }
+
+    /// Returns the unit quaternion representing a rotation through angle
+    /// radians about the x axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let q = DQuarternion::from_rotation_x(1.0);
+    /// assert!(q.x == 0.5f64.sin() && q.w == 0.5f64.cos());
+    /// ```
+    pub fn from_rotation_x(angle: f64) -> DQuarternion {
+        let h = angle * 0.5;
+        DQuarternion::new(h.sin(), 0.0, 0.0, h.cos())
+    }
+
+    /// Returns the unit quaternion representing a rotation through angle
+    /// radians about the y axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    pub fn from_rotation_y(angle: f64) -> DQuarternion {
+        let h = angle * 0.5;
+        DQuarternion::new(0.0, h.sin(), 0.0, h.cos())
+    }
+
+    /// Returns the unit quaternion representing a rotation through angle
+    /// radians about the z axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    pub fn from_rotation_z(angle: f64) -> DQuarternion {
+        let h = angle * 0.5;
+        DQuarternion::new(0.0, 0.0, h.sin(), h.cos())
+    }
+
+    /// Returns the unit quaternion composed of rotations through x, y and z
+    /// radians about the x, y and z axes, applied in the given order.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order in which the rotations are applied.
+    /// * `x` - The angle about the x axis in radians.
+    /// * `y` - The angle about the y axis in radians.
+    /// * `z` - The angle about the z axis in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::euler::EulerOrder;
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let q = DQuarternion::from_euler(EulerOrder::ZYX, 0.0, 0.0, 1.0);
+    /// assert!(q == DQuarternion::from_rotation_z(1.0));
+    /// ```
+    pub fn from_euler(order: EulerOrder, x: f64, y: f64, z: f64) -> DQuarternion {
+        let q = [
+            Self::from_rotation_x(x),
+            Self::from_rotation_y(y),
+            Self::from_rotation_z(z),
+        ];
+        let [i, j, k] = order.axes();
+        q[k] * q[j] * q[i]
+    }
+
+    /// Returns the angles (x, y, z) in radians about the x, y and z axes that
+    /// `DQuarternion::from_euler` turns back into this rotation. See
+    /// `DMatrix3::to_euler` for the ranges of the angles. The quaternion is
+    /// expected to be normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order in which the rotations are applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::euler::EulerOrder;
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let q = DQuarternion::from_euler(EulerOrder::YXZ, 0.1, 0.2, 0.3);
+    /// let (x, y, z) = q.to_euler(EulerOrder::YXZ);
+    /// assert!((x - 0.1).abs() < 1e-12 && (y - 0.2).abs() < 1e-12 && (z - 0.3).abs() < 1e-12);
+    /// ```
+    pub fn to_euler(&self, order: EulerOrder) -> (f64, f64, f64) {
+        self.get_rotation_matrix().to_euler(order)
+    }
+
+    /// Returns the rate of change of this orientation while it spins with
+    /// the angular velocity ω, the quaternion ½ ω q, where ω is taken as a
+    /// pure quaternion in world space.
+    ///
+    /// # Arguments
+    ///
+    /// * `angular_velocity` - The angular velocity in world space, in
+    ///   radians per unit time about its direction.
+    pub fn derivative(&self, angular_velocity: &DVector3) -> DQuarternion {
+        DQuarternion::new_with_vec(angular_velocity) * *self * 0.5
+    }
+
+    /// Returns this orientation stepped forward by dt while spinning with
+    /// the angular velocity ω, for physics integrators. The step applies the
+    /// exact rotation through |ω| dt about ω, the exponential map, which
+    /// unlike adding `derivative` times dt stays on the unit sphere for any
+    /// step size. The result is renormalized so rounding does not build up
+    /// over many steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `angular_velocity` - The angular velocity in world space, in
+    ///   radians per unit time about its direction.
+    /// * `dt` - The time step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// use math_engine::dvector3::DVector3;
+    /// // A quarter turn per second about z, stepped at 60 Hz for a second.
+    /// let w = DVector3::new(0.0, 0.0, core::f64::consts::FRAC_PI_2);
+    /// let mut q = DQuarternion::IDENTITY;
+    /// for _ in 0..60 {
+    ///     q = q.integrate(&w, 1.0 / 60.0);
+    /// }
+    /// let x = q.transform(&DVector3::new(1.0, 0.0, 0.0));
+    /// assert!((x - DVector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-12);
+    /// ```
+    pub fn integrate(&self, angular_velocity: &DVector3, dt: f64) -> DQuarternion {
+        let half = angular_velocity.magnitude() * dt * 0.5;
+        // sin(half) / |ω|, from its series for small angles.
+        let s = if half < 1.0e-4 {
+            dt * 0.5 * (1.0 - half * half / 6.0)
+        } else {
+            half.sin() / angular_velocity.magnitude()
+        };
+        let step = DQuarternion::new_with_vec_and_scalar(&(*angular_velocity * s), half.cos());
+        (step * *self).normalize()
+    }
+
+    /// Returns a raw pointer to the components of this quaternion, which are
+    /// laid out contiguously as x, y, z, w.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dquarternion::DQuarternion;
+    /// let v = DQuarternion::new(1.0, 2.0, 3.0, 4.0);
+    /// let p = v.as_ptr();
+    /// assert!(unsafe { *p.add(3) } == 4.0);
+    /// ```
+    pub fn as_ptr(&self) -> *const f64 {
+        (self as *const DQuarternion).cast::<f64>()
+    }
+}
+
+impl Mul<DQuarternion> for DQuarternion {
+    type Output = Self;
+
+    fn mul(self, rhs: DQuarternion) -> Self::Output {
+        DQuarternion::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+impl Neg for DQuarternion {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl MulAssign<DQuarternion> for DQuarternion {
+    fn mul_assign(&mut self, rhs: DQuarternion) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<f64> for DQuarternion {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+        self.w *= rhs;
+    }
+}
+
+impl From<Quarternion> for DQuarternion {
+    fn from(q: Quarternion) -> Self {
+        DQuarternion::new(
+            f64::from(q.x),
+            f64::from(q.y),
+            f64::from(q.z),
+            f64::from(q.w),
+        )
+    }
+}
+
+impl Add for DQuarternion {
+    type Output = Self;
+
+    fn add(self, rhs: DQuarternion) -> Self::Output {
+        DQuarternion::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+impl Sub for DQuarternion {
+    type Output = Self;
+
+    fn sub(self, rhs: DQuarternion) -> Self::Output {
+        DQuarternion::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
+
+impl Mul<f64> for DQuarternion {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        DQuarternion::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+impl Mul<DQuarternion> for f64 {
+    type Output = DQuarternion;
+
+    fn mul(self, rhs: DQuarternion) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Div<f64> for DQuarternion {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        DQuarternion::new(self.x / rhs, self.y / rhs, self.z / rhs, self.w / rhs)
+    }
+}
+
+impl AddAssign for DQuarternion {
+    fn add_assign(&mut self, rhs: DQuarternion) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+        self.w += rhs.w;
+    }
+}
+
+impl SubAssign for DQuarternion {
+    fn sub_assign(&mut self, rhs: DQuarternion) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+        self.w -= rhs.w;
+    }
+}
+
+impl DivAssign<f64> for DQuarternion {
+    fn div_assign(&mut self, rhs: f64) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+        self.w /= rhs;
+    }
+}
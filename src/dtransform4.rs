@@ -0,0 +1,818 @@
+use crate::prelude::*;
+use core::ops::{Div, DivAssign, Index, IndexMut, Mul, MulAssign};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+/// A double precision 4x4 transformation matrix whose fourth row is assumed to be [0 0 0 1].
+#[repr(C)]
+pub struct DTransform4 {
+    n: [DVector3; 4],
+}
+
+impl DTransform4 {
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+        e: f64,
+        f: f64,
+        g: f64,
+        h: f64,
+        i: f64,
+        j: f64,
+        k: f64,
+        l: f64,
+    ) -> DTransform4 {
+        let n: [DVector3; 4] = [
+            DVector3::new(a, e, i),
+            DVector3::new(b, f, j),
+            DVector3::new(c, g, k),
+            DVector3::new(d, h, l),
+        ];
+        Self { n }
+    }
+
+    pub fn new_with_vecs(a: DVector3, b: DVector3, c: DVector3, p: DPoint3) -> DTransform4 {
+        let n: [DVector3; 4] = [a, b, c, DVector3::new(p.x, p.y, p.z)];
+        Self { n }
+    }
+
+    pub fn vec_at(&self, i: usize) -> DVector3 {
+        self[i]
+    }
+
+    pub fn at(&self, i: usize, j: usize) -> f64 {
+        self[j][i]
+    }
+
+    pub fn get_translation(&self) -> DPoint3 {
+        DPoint3::new(self.n[3][0], self.n[3][1], self.n[3][2])
+    }
+
+    pub fn set_translation(&mut self, p: &DPoint3) {
+        self.n[3][0] = p.x;
+        self.n[3][1] = p.y;
+        self.n[3][2] = p.z;
+    }
+
+    pub fn determinant(&self) -> f64 {
+        self.n[0][0] * (self.n[1][1] * self.n[2][2] - self.n[2][1] * self.n[1][2])
+            - self.n[1][0] * (self.n[0][1] * self.n[2][2] - self.n[2][1] * self.n[0][2])
+            + self.n[2][0] * (self.n[0][1] * self.n[1][2] - self.n[1][1] * self.n[0][2])
+    }
+
+    pub fn inverse(&self) -> DTransform4 {
+        let a = self.vec_at(0);
+        let b = self.vec_at(1);
+        let c = self.vec_at(2);
+        let d = self.vec_at(3);
+
+        let mut s = a.cross(&b);
+        let mut t = c.cross(&d);
+
+        let inv_det = 1.0 / s.dot(&c);
+        s *= inv_det;
+        t *= inv_det;
+        let v = c * inv_det;
+        let r0 = b.cross(&v);
+        let r1 = v.cross(&a);
+        Self::new(
+            r0.x,
+            r0.y,
+            r0.z,
+            -b.dot(&t),
+            r1.x,
+            r1.y,
+            r1.z,
+            a.dot(&t),
+            s.x,
+            s.y,
+            s.z,
+            -d.dot(&s),
+        )
+    }
+
+    pub const fn identity() -> DTransform4 {
+        Self::new(1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0)
+    }
+
+    // Returns 4x4 transformation matrix that represents a reflection
+    // through the plane f. The plane f is assumed to be normalized.
+    pub fn make_plane_reflection(f: &Plane) -> DTransform4 {
+        let (fx, fy, fz, fw) = (
+            f64::from(f.x),
+            f64::from(f.y),
+            f64::from(f.z),
+            f64::from(f.w),
+        );
+        let x = fx * -2.0;
+        let y = fy * -2.0;
+        let z = fz * -2.0;
+        let nxny = x * fy;
+        let nxnz = x * fz;
+        let nynz = y * fz;
+        Self::new(
+            x * fx + 1.0,
+            nxny,
+            nxnz,
+            x * fw,
+            nxny,
+            y * fy + 1.0,
+            nynz,
+            y * fw,
+            nxnz,
+            nynz,
+            z * fz + 1.0,
+            z * fw,
+        )
+    }
+
+    pub fn make_vec_reflection(v: &DVector3) -> DTransform4 {
+        let x = v.x * -2.0;
+        let y = v.y * -2.0;
+        let z = v.z * -2.0;
+        let axay = x * v.y;
+        let axaz = x * v.z;
+        let ayaz = y * v.z;
+        Self::new(
+            x * v.x + 1.0,
+            axay,
+            axaz,
+            0.0,
+            axay,
+            y * v.y + 1.0,
+            ayaz,
+            0.0,
+            axaz,
+            ayaz,
+            z * v.z + 1.0,
+            0.0,
+        )
+    }
+
+    pub fn make_scale_x(sx: f64) -> DTransform4 {
+        Self::new(sx, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0)
+    }
+
+    pub fn make_scale_y(sy: f64) -> DTransform4 {
+        Self::new(1.0, 0.0, 0.0, 0.0, 0.0, sy, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0)
+    }
+
+    pub fn make_scale_z(sz: f64) -> DTransform4 {
+        Self::new(1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, sz, 0.0)
+    }
+
+    pub fn make_scale_xyz(sx: f64, sy: f64, sz: f64) -> DTransform4 {
+        Self::new(sx, 0.0, 0.0, 0.0, 0.0, sy, 0.0, 0.0, 0.0, 0.0, sz, 0.0)
+    }
+
+    pub fn make_scale_vec(s: f64, v: &DVector3) -> DTransform4 {
+        let scale = s - 1.0;
+        let x = v.x * scale;
+        let y = v.y * scale;
+        let z = v.z * scale;
+        let axay = x * v.y;
+        let axaz = x * v.z;
+        let ayaz = y * v.z;
+        Self::new(
+            x * v.x + 1.0,
+            axay,
+            axaz,
+            0.0,
+            axay,
+            y * v.y + 1.0,
+            ayaz,
+            0.0,
+            axaz,
+            ayaz,
+            z * v.z + 1.0,
+            0.0,
+        )
+    }
+
+    pub fn make_translation(v: &DVector3) -> DTransform4 {
+        Self::new(1.0, 0.0, 0.0, v.x, 0.0, 1.0, 0.0, v.y, 0.0, 0.0, 1.0, v.z)
+    }
+
+    pub fn make_involution(v: &DVector3) -> DTransform4 {
+        let x = v.x * 2.0;
+        let y = v.y * 2.0;
+        let z = v.z * 2.0;
+        let axay = x * v.y;
+        let axaz = x * v.z;
+        let ayaz = y * v.z;
+        Self::new(
+            x * v.x - 1.0,
+            axay,
+            axaz,
+            0.0,
+            axay,
+            y * v.y - 1.0,
+            ayaz,
+            0.0,
+            axaz,
+            ayaz,
+            z * v.z - 1.0,
+            0.0,
+        )
+    }
+
+    pub fn make_skew(angle: f64, a: &DVector3, b: &DVector3) -> DTransform4 {
+        let t = angle.tan();
+        let x = a.x * t;
+        let y = a.y * t;
+        let z = a.z * t;
+        Self::new(
+            x * b.x + 1.0,
+            x * b.y,
+            x * b.z,
+            0.0,
+            y * b.x,
+            y * b.y + 1.0,
+            y * b.z,
+            0.0,
+            z * b.x,
+            z * b.y,
+            z * b.z + 1.0,
+            0.0,
+        )
+    }
+
+    pub fn make_rotation_x(angle: f64) -> DTransform4 {
+        let c = angle.cos();
+        let s = angle.sin();
+        Self::new(1.0, 0.0, 0.0, 0.0, 0.0, c, -s, 0.0, 0.0, s, c, 0.0)
+    }
+
+    pub fn make_rotation_y(angle: f64) -> DTransform4 {
+        let c = angle.cos();
+        let s = angle.sin();
+        Self::new(c, 0.0, s, 0.0, 0.0, 1.0, 0.0, 0.0, -s, 0.0, c, 0.0)
+    }
+
+    pub fn make_rotation_z(angle: f64) -> DTransform4 {
+        let c = angle.cos();
+        let s = angle.sin();
+        Self::new(c, -s, 0.0, 0.0, s, c, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0)
+    }
+
+    pub fn make_rotation(angle: f64, v: &DVector3) -> DTransform4 {
+        let c = angle.cos();
+        let s = angle.sin();
+        let d = 1.0 - c;
+        let x = v.x * d;
+        let y = v.y * d;
+        let z = v.z * d;
+        let axay = x * v.y;
+        let axaz = x * v.z;
+        let ayaz = y * v.z;
+        Self::new(
+            c + x * v.x,
+            axay - s * v.z,
+            axaz + s * v.y,
+            0.0,
+            axay + s * v.z,
+            c + y * v.y,
+            ayaz - s * v.x,
+            0.0,
+            axaz - s * v.y,
+            ayaz + s * v.x,
+            c + z * v.z,
+            0.0,
+        )
+    }
+
+    pub fn to_f32(&self) -> Transform4 {
+        Transform4::new_with_vecs(
+            self.n[0].to_f32(),
+            self.n[1].to_f32(),
+            self.n[2].to_f32(),
+            Point3::from(self.n[3].to_f32()),
+        )
+    }
+
+    /// Returns the transform that scales by s, then rotates by the unit
+    /// quaternion r and finally translates to t.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The translation.
+    /// * `r` - A unit quaternion giving the rotation.
+    /// * `s` - The scale along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::dquarternion::DQuarternion;
+    /// use math_engine::dtransform4::DTransform4;
+    /// use math_engine::dvector3::DVector3;
+    /// let h = DTransform4::from_trs(
+    ///     &DPoint3::new(1.0, 2.0, 3.0),
+    ///     &DQuarternion::new(0.0, 0.0, 0.0, 1.0),
+    ///     &DVector3::new(2.0, 2.0, 2.0),
+    /// );
+    /// assert!(h * DPoint3::new(1.0, 1.0, 1.0) == DPoint3::new(3.0, 4.0, 5.0));
+    /// ```
+    pub fn from_trs(t: &DPoint3, r: &DQuarternion, s: &DVector3) -> DTransform4 {
+        let m = r.get_rotation_matrix();
+        Self::new_with_vecs(m[0] * s.x, m[1] * s.y, m[2] * s.z, *t)
+    }
+
+    /// Splits this transform into a translation, a rotation and a scale such
+    /// that `DTransform4::from_trs` rebuilds it. A reflection is folded into a
+    /// negative x scale. Transforms containing skew have no such
+    /// decomposition, and the rotation returned for them is approximate.
+    ///
+    /// An axis scaled to zero has no direction to recover. Its scale is 0
+    /// and the rotation is completed from the remaining axes: a missing axis
+    /// is the cross product of the other two, and when two or all three are
+    /// missing any rotation taking the x, y and z axes onto the surviving
+    /// directions is returned, or the identity if none survive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::dtransform4::DTransform4;
+    /// use math_engine::dvector3::DVector3;
+    /// let h = DTransform4::make_translation(&DVector3::new(1.0, 2.0, 3.0))
+    ///     * DTransform4::make_scale_xyz(1.0, 2.0, 3.0);
+    /// let (t, r, s) = h.decompose();
+    /// assert!(t == DPoint3::new(1.0, 2.0, 3.0));
+    /// assert!(r.w == 1.0);
+    /// assert!(s == DVector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn decompose(&self) -> (DPoint3, DQuarternion, DVector3) {
+        let mut s = DVector3::new(
+            self.n[0].magnitude(),
+            self.n[1].magnitude(),
+            self.n[2].magnitude(),
+        );
+        if self.determinant() < 0.0 {
+            s.x = -s.x;
+        }
+        let tiny = f64::EPSILON * s.x.abs().max(s.y).max(s.z);
+        let mut axes = [
+            DVector3::new(1.0, 0.0, 0.0),
+            DVector3::new(0.0, 1.0, 0.0),
+            DVector3::new(0.0, 0.0, 1.0),
+        ];
+        let mut kept = 0;
+        for i in 0..3 {
+            if s[i].abs() > tiny {
+                axes[i] = self.n[i] / s[i];
+                kept += 1;
+            }
+        }
+        // Rebuild the missing axes so that the three stay right-handed.
+        if kept == 2 {
+            let k = (0..3).find(|&i| s[i].abs() <= tiny).unwrap();
+            let c = axes[(k + 1) % 3].cross(&axes[(k + 2) % 3]);
+            match c.try_normalize() {
+                Some(c) => axes[k] = c,
+                None => kept = 1,
+            }
+        }
+        if kept == 1 {
+            let k = (0..3).find(|&i| s[i].abs() > tiny).unwrap();
+            let (t, b) = axes[k].any_orthonormal_pair();
+            axes[(k + 1) % 3] = t;
+            axes[(k + 2) % 3] = b;
+        }
+        let m = DMatrix3::new_with_vecs(axes[0], axes[1], axes[2]);
+        let mut r = DQuarternion::default();
+        r.set_rotation_matrix(&m);
+        (self.get_translation(), r, s)
+    }
+
+    /// Returns the rigid transform that rotates by the unit quaternion q and
+    /// then translates to t.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - A unit quaternion giving the rotation.
+    /// * `t` - The translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::dquarternion::DQuarternion;
+    /// use math_engine::dtransform4::DTransform4;
+    /// let q = DQuarternion::new(0.0, 0.0, 0.0, 1.0);
+    /// let h = DTransform4::from_rotation_translation(&q, &DPoint3::new(1.0, 2.0, 3.0));
+    /// assert!(h.get_translation() == DPoint3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn from_rotation_translation(q: &DQuarternion, t: &DPoint3) -> DTransform4 {
+        let m = q.get_rotation_matrix();
+        Self::new_with_vecs(m[0], m[1], m[2], *t)
+    }
+
+    /// Returns the rotation and translation of this transform, undoing
+    /// `DTransform4::from_rotation_translation`. The transform is assumed to
+    /// be rigid; use `DTransform4::decompose` when it may contain scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::dtransform4::DTransform4;
+    /// use math_engine::dvector3::DVector3;
+    /// let h = DTransform4::make_translation(&DVector3::new(1.0, 2.0, 3.0))
+    ///     * DTransform4::make_rotation_z(0.5);
+    /// let (q, t) = h.to_rotation_translation();
+    /// assert!(t == DPoint3::new(1.0, 2.0, 3.0));
+    /// assert!((q.z - 0.25f64.sin()).abs() < 1e-12);
+    /// ```
+    pub fn to_rotation_translation(&self) -> (DQuarternion, DPoint3) {
+        let mut q = DQuarternion::default();
+        q.set_rotation_matrix(&DMatrix3::new_with_vecs(self.n[0], self.n[1], self.n[2]));
+        (q, self.get_translation())
+    }
+
+    /// Returns the rigid transform placing a right-handed camera at eye
+    /// looking toward target, mapping camera space to world space. The camera
+    /// looks down its negative z axis, so the result is the inverse of
+    /// `DMatrix4::look_at_rh`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eye` - The position of the camera.
+    /// * `target` - The point the camera looks at.
+    /// * `up` - The approximate up direction, not parallel to target - eye.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::dtransform4::DTransform4;
+    /// use math_engine::dvector3::DVector3;
+    /// let h = DTransform4::look_at(
+    ///     &DPoint3::new(0.0, 0.0, 5.0),
+    ///     &DPoint3::new(0.0, 0.0, 0.0),
+    ///     &DVector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// assert!(h * DPoint3::new(0.0, 0.0, -5.0) == DPoint3::new(0.0, 0.0, 0.0));
+    /// ```
+    pub fn look_at(eye: &DPoint3, target: &DPoint3, up: &DVector3) -> DTransform4 {
+        let f = (*target - *eye).normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(&f);
+        Self::new_with_vecs(s, u, -f, *eye)
+    }
+
+    /// Returns the inverse of `DTransform4::look_at`, the view transform
+    /// mapping world space to the space of a right-handed camera at eye.
+    /// Since the rotation part is orthonormal it is inverted by transposing.
+    ///
+    /// # Arguments
+    ///
+    /// * `eye` - The position of the camera.
+    /// * `target` - The point the camera looks at.
+    /// * `up` - The approximate up direction, not parallel to target - eye.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::dtransform4::DTransform4;
+    /// use math_engine::dvector3::DVector3;
+    /// let h = DTransform4::look_at_inverse(
+    ///     &DPoint3::new(0.0, 0.0, 5.0),
+    ///     &DPoint3::new(0.0, 0.0, 0.0),
+    ///     &DVector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// assert!(h * DPoint3::new(0.0, 0.0, 0.0) == DPoint3::new(0.0, 0.0, -5.0));
+    /// ```
+    pub fn look_at_inverse(eye: &DPoint3, target: &DPoint3, up: &DVector3) -> DTransform4 {
+        let f = (*target - *eye).normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(&f);
+        let e = DVector3::from(*eye);
+        Self::new(
+            s.x,
+            s.y,
+            s.z,
+            -s.dot(&e),
+            u.x,
+            u.y,
+            u.z,
+            -u.dot(&e),
+            -f.x,
+            -f.y,
+            -f.z,
+            f.dot(&e),
+        )
+    }
+
+    /// Returns the rigid transform produced by the twist with angular part
+    /// w and linear part v, the exponential map from se(3), as
+    /// `Transform4::exp` does for a `Twist`. The rotation is
+    /// `DMatrix3::from_rotation_vector` of w and the translation is v
+    /// carried along the screw motion.
+    ///
+    /// # Arguments
+    ///
+    /// * `w` - The rotation vector, the axis scaled by the angle.
+    /// * `v` - The linear velocity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::dtransform4::DTransform4;
+    /// use math_engine::dvector3::DVector3;
+    /// // A half turn about z while moving along x traces half a circle.
+    /// let w = DVector3::new(0.0, 0.0, core::f64::consts::PI);
+    /// let p = DTransform4::exp(&w, &DVector3::new(1.0, 0.0, 0.0)).get_translation();
+    /// assert!((p - DPoint3::new(0.0, 2.0 / core::f64::consts::PI, 0.0)).magnitude() < 1e-12);
+    /// ```
+    pub fn exp(w: &DVector3, v: &DVector3) -> DTransform4 {
+        let w = *w;
+        let r = DMatrix3::from_rotation_vector(&w);
+        let theta2 = w.dot(&w);
+        let (a, b) = if theta2 < 1.0e-6 {
+            (0.5 - theta2 / 24.0, 1.0 / 6.0 - theta2 / 120.0)
+        } else {
+            let theta = theta2.sqrt();
+            (
+                (1.0 - theta.cos()) / theta2,
+                (theta - theta.sin()) / (theta2 * theta),
+            )
+        };
+        let wv = w.cross(v);
+        let p = *v + wv * a + w.cross(&wv) * b;
+        Self::new_with_vecs(r[0], r[1], r[2], DPoint3::new(p.x, p.y, p.z))
+    }
+
+    /// Returns the angular and linear parts of the twist that produces this
+    /// rigid transform, the logarithm map to se(3), as `Transform4::ln`
+    /// does. The rotation angle of the result is in [0, π].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dtransform4::DTransform4;
+    /// use math_engine::dvector3::DVector3;
+    /// let h = DTransform4::make_translation(&DVector3::new(1.0, 2.0, 0.0))
+    ///     * DTransform4::make_rotation_y(1.1);
+    /// let (w, v) = h.ln();
+    /// let g = DTransform4::exp(&w, &v);
+    /// assert!((0..4).all(|i| (g[i] - h[i]).magnitude() < 1e-12));
+    /// ```
+    pub fn ln(&self) -> (DVector3, DVector3) {
+        let r = DMatrix3::new_with_vecs(self[0], self[1], self[2]);
+        let w = r.to_rotation_vector();
+        let theta2 = w.dot(&w);
+        let c = if theta2 < 1.0e-6 {
+            1.0 / 12.0 + theta2 / 720.0
+        } else {
+            let theta = theta2.sqrt();
+            (1.0 - theta * theta.sin() / (2.0 * (1.0 - theta.cos()))) / theta2
+        };
+        let p = self[3];
+        let wp = w.cross(&p);
+        (w, p - wp * 0.5 + w.cross(&wp) * c)
+    }
+
+    /// Returns the transform interpolated between this transform at t = 0
+    /// and other at t = 1 along a screw motion, turning about and sliding
+    /// along a single axis at constant speed, as `Transform4::interpolate`
+    /// does. The screw is the logarithm of the rigid motion between the two.
+    /// Scale is interpolated linearly.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the transform at t = 1.
+    /// * `t` - The interpolation parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dpoint3::DPoint3;
+    /// use math_engine::dtransform4::DTransform4;
+    /// use math_engine::dvector3::DVector3;
+    /// // A door hinged at the origin swings its handle around an arc.
+    /// let open = DTransform4::make_rotation_y(core::f64::consts::FRAC_PI_2);
+    /// let half = DTransform4::identity().interpolate(&open, 0.5);
+    /// let handle = half * DPoint3::new(1.0, 0.0, 0.0);
+    /// assert!((DVector3::new(handle.x, handle.y, handle.z).magnitude() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn interpolate(&self, other: &DTransform4, t: f64) -> DTransform4 {
+        let (ta, ra, sa) = self.decompose();
+        let (tb, rb, sb) = other.decompose();
+        let a = Self::from_rotation_translation(&ra, &ta);
+        let b = Self::from_rotation_translation(&rb, &tb);
+        let (w, v) = (a.inverse() * b).ln();
+        let (r, p) = (a * Self::exp(&(w * t), &(v * t))).to_rotation_translation();
+        Self::from_trs(&p, &r, &sa.lerp(&sb, t))
+    }
+}
+
+impl Mul<DVector3> for DTransform4 {
+    type Output = DVector3;
+
+    fn mul(self, rhs: DVector3) -> Self::Output {
+        DVector3::new(
+            self.n[0][0] * rhs.x + self.n[1][0] * rhs.y + self.n[2][0] * rhs.z,
+            self.n[0][1] * rhs.x + self.n[1][1] * rhs.y + self.n[2][1] * rhs.z,
+            self.n[0][2] * rhs.x + self.n[1][2] * rhs.y + self.n[2][2] * rhs.z,
+        )
+    }
+}
+
+impl Mul<DPoint3> for DTransform4 {
+    type Output = DPoint3;
+
+    fn mul(self, rhs: DPoint3) -> Self::Output {
+        DPoint3::new(
+            self.n[0][0] * rhs.x + self.n[1][0] * rhs.y + self.n[2][0] * rhs.z + self.n[3][0],
+            self.n[0][1] * rhs.x + self.n[1][1] * rhs.y + self.n[2][1] * rhs.z + self.n[3][1],
+            self.n[0][2] * rhs.x + self.n[1][2] * rhs.y + self.n[2][2] * rhs.z + self.n[3][2],
+        )
+    }
+}
+
+impl Mul<DMatrix3> for DTransform4 {
+    type Output = DMatrix3;
+
+    fn mul(self, rhs: DMatrix3) -> Self::Output {
+        DMatrix3::new(
+            self.n[0][0] * rhs[(0, 0)] + self.n[1][0] * rhs[(1, 0)] + self.n[2][0] * rhs[(2, 0)],
+            self.n[0][0] * rhs[(0, 1)] + self.n[1][0] * rhs[(1, 1)] + self.n[2][0] * rhs[(2, 1)],
+            self.n[0][0] * rhs[(0, 2)] + self.n[1][0] * rhs[(1, 2)] + self.n[2][0] * rhs[(2, 2)],
+            self.n[0][1] * rhs[(0, 0)] + self.n[1][1] * rhs[(1, 0)] + self.n[2][1] * rhs[(2, 0)],
+            self.n[0][1] * rhs[(0, 1)] + self.n[1][1] * rhs[(1, 1)] + self.n[2][1] * rhs[(2, 1)],
+            self.n[0][1] * rhs[(0, 2)] + self.n[1][1] * rhs[(1, 2)] + self.n[2][1] * rhs[(2, 2)],
+            self.n[0][2] * rhs[(0, 0)] + self.n[1][2] * rhs[(1, 0)] + self.n[2][2] * rhs[(2, 0)],
+            self.n[0][2] * rhs[(0, 1)] + self.n[1][2] * rhs[(1, 1)] + self.n[2][2] * rhs[(2, 1)],
+            self.n[0][2] * rhs[(0, 2)] + self.n[1][2] * rhs[(1, 2)] + self.n[2][2] * rhs[(2, 2)],
+        )
+    }
+}
+
+impl Mul<DVector2> for DTransform4 {
+    type Output = DVector2;
+
+    fn mul(self, rhs: DVector2) -> Self::Output {
+        DVector2::new(
+            self.n[0][0] * rhs.x + self.n[1][0] * rhs.y,
+            self.n[0][1] * rhs.x + self.n[1][1] * rhs.y,
+        )
+    }
+}
+
+impl Mul<DPoint2> for DTransform4 {
+    type Output = DVector2;
+
+    fn mul(self, rhs: DPoint2) -> Self::Output {
+        DVector2::new(
+            self.n[0][0] * rhs.x + self.n[1][0] * rhs.y + self.n[3][0],
+            self.n[0][1] * rhs.x + self.n[1][1] * rhs.y + self.n[3][1],
+        )
+    }
+}
+
+impl Mul<DTransform4> for DTransform4 {
+    type Output = Self;
+
+    fn mul(self, rhs: DTransform4) -> Self::Output {
+        Self::new(
+            self.n[0][0] * rhs[(0, 0)] + self.n[1][0] * rhs[(1, 0)] + self.n[2][0] * rhs[(2, 0)],
+            self.n[0][0] * rhs[(0, 1)] + self.n[1][0] * rhs[(1, 1)] + self.n[2][0] * rhs[(2, 1)],
+            self.n[0][0] * rhs[(0, 2)] + self.n[1][0] * rhs[(1, 2)] + self.n[2][0] * rhs[(2, 2)],
+            self.n[0][0] * rhs[(0, 3)]
+                + self.n[1][0] * rhs[(1, 3)]
+                + self.n[2][0] * rhs[(2, 3)]
+                + self.n[3][0],
+            self.n[0][1] * rhs[(0, 0)] + self.n[1][1] * rhs[(1, 0)] + self.n[2][1] * rhs[(2, 0)],
+            self.n[0][1] * rhs[(0, 1)] + self.n[1][1] * rhs[(1, 1)] + self.n[2][1] * rhs[(2, 1)],
+            self.n[0][1] * rhs[(0, 2)] + self.n[1][1] * rhs[(1, 2)] + self.n[2][1] * rhs[(2, 2)],
+            self.n[0][1] * rhs[(0, 3)]
+                + self.n[1][1] * rhs[(1, 3)]
+                + self.n[2][1] * rhs[(2, 3)]
+                + self.n[3][1],
+            self.n[0][2] * rhs[(0, 0)] + self.n[1][2] * rhs[(1, 0)] + self.n[2][2] * rhs[(2, 0)],
+            self.n[0][2] * rhs[(0, 1)] + self.n[1][2] * rhs[(1, 1)] + self.n[2][2] * rhs[(2, 1)],
+            self.n[0][2] * rhs[(0, 2)] + self.n[1][2] * rhs[(1, 2)] + self.n[2][2] * rhs[(2, 2)],
+            self.n[0][2] * rhs[(0, 3)]
+                + self.n[1][2] * rhs[(1, 3)]
+                + self.n[2][2] * rhs[(2, 3)]
+                + self.n[3][2],
+        )
+    }
+}
+
+impl Index<(usize, usize)> for DTransform4 {
+    type Output = f64;
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(col < 4 && row < 4);
+        &self.n[col][row]
+    }
+}
+
+impl IndexMut<(usize, usize)> for DTransform4 {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        assert!(col < 4 && row < 4);
+        &mut self.n[col][row]
+    }
+}
+
+impl Index<usize> for DTransform4 {
+    type Output = DVector3;
+    fn index(&self, col: usize) -> &Self::Output {
+        assert!(col < 4);
+        &self.n[col]
+    }
+}
+
+impl IndexMut<usize> for DTransform4 {
+    fn index_mut(&mut self, col: usize) -> &mut DVector3 {
+        assert!(col < 4);
+        &mut self.n[col]
+    }
+}
+
+impl Div<f64> for DTransform4 {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(
+            self.n[0][0] / rhs,
+            self.n[1][0] / rhs,
+            self.n[2][0] / rhs,
+            self.n[3][0] / rhs,
+            self.n[0][1] / rhs,
+            self.n[1][1] / rhs,
+            self.n[2][1] / rhs,
+            self.n[3][1] / rhs,
+            self.n[0][2] / rhs,
+            self.n[1][2] / rhs,
+            self.n[2][2] / rhs,
+            self.n[3][2] / rhs,
+        )
+    }
+}
+
+impl DivAssign<f64> for DTransform4 {
+    fn div_assign(&mut self, rhs: f64) {
+        self.n[0][0] /= rhs;
+        self.n[1][0] /= rhs;
+        self.n[2][0] /= rhs;
+        self.n[3][0] /= rhs;
+        self.n[0][1] /= rhs;
+        self.n[1][1] /= rhs;
+        self.n[2][1] /= rhs;
+        self.n[3][1] /= rhs;
+        self.n[0][2] /= rhs;
+        self.n[1][2] /= rhs;
+        self.n[2][2] /= rhs;
+        self.n[3][2] /= rhs;
+    }
+}
+
+impl MulAssign<DTransform4> for DTransform4 {
+    fn mul_assign(&mut self, rhs: DTransform4) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<f64> for DTransform4 {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.n[0][0] *= rhs;
+        self.n[1][0] *= rhs;
+        self.n[2][0] *= rhs;
+        self.n[3][0] *= rhs;
+        self.n[0][1] *= rhs;
+        self.n[1][1] *= rhs;
+        self.n[2][1] *= rhs;
+        self.n[3][1] *= rhs;
+        self.n[0][2] *= rhs;
+        self.n[1][2] *= rhs;
+        self.n[2][2] *= rhs;
+        self.n[3][2] *= rhs;
+    }
+}
+
+impl From<Transform4> for DTransform4 {
+    fn from(h: Transform4) -> Self {
+        DTransform4::new_with_vecs(
+            DVector3::from(h[0]),
+            DVector3::from(h[1]),
+            DVector3::from(h[2]),
+            DPoint3::from(h.get_translation()),
+        )
+    }
+}
+
+#[test]
+fn f32_round_trip() {
+    let h = Transform4::make_rotation_z(0.5)
+        * Transform4::make_translation(&Vector3::new(1.0, 2.0, 3.0));
+    let d = DTransform4::from(h);
+    let p = d * DPoint3::new(1.0e9, 0.0, 0.0);
+    assert!(d.to_f32().get_translation() == h.get_translation());
+    assert!(((p - d * DPoint3::new(1.0e9 + 1.0, 0.0, 0.0)).magnitude() - 1.0).abs() < 1.0e-6);
+}
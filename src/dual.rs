@@ -0,0 +1,308 @@
+//! Dual numbers for forward-mode automatic differentiation. A dual number
+//! a + bε, with ε² = 0, carries a value and its derivative together, and
+//! every operation on it applies the chain rule, so evaluating a function on
+//! `Dual::variable(x)` yields both f(x) and f'(x) exactly, without finite
+//! differences or a hand-derived formula.
+//!
+//! The vector types store plain floats, so functions of several variables
+//! take their arguments as an array of duals; `Vector2::gradient`,
+//! `Vector3::gradient` and their f64 counterparts evaluate such a function
+//! once per variable to collect its gradient.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A dual number: a value and its derivative with respect to one variable.
+#[repr(C)]
+pub struct Dual<T> {
+    /// The value.
+    pub re: T,
+    /// The derivative of the value.
+    pub du: T,
+}
+
+impl<T> Dual<T> {
+    /// Returns the dual number with the given value and derivative.
+    pub const fn new(re: T, du: T) -> Dual<T> {
+        Dual { re, du }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, other: Dual<T>) -> Self::Output {
+        Dual::new(self.re + other.re, self.du + other.du)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, other: Dual<T>) -> Self::Output {
+        Dual::new(self.re - other.re, self.du - other.du)
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T>> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, other: Dual<T>) -> Self::Output {
+        Dual::new(self.re * other.re, self.du * other.re + self.re * other.du)
+    }
+}
+
+impl<T: Copy + Sub<Output = T> + Mul<Output = T> + Div<Output = T>> Div for Dual<T> {
+    type Output = Self;
+
+    fn div(self, other: Dual<T>) -> Self::Output {
+        Dual::new(
+            self.re / other.re,
+            (self.du * other.re - self.re * other.du) / (other.re * other.re),
+        )
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Dual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Dual::new(-self.re, -self.du)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Dual<T> {
+    fn add_assign(&mut self, other: Dual<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Dual<T> {
+    fn sub_assign(&mut self, other: Dual<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T>> MulAssign for Dual<T> {
+    fn mul_assign(&mut self, other: Dual<T>) {
+        *self = *self * other;
+    }
+}
+
+impl<T: Copy + Sub<Output = T> + Mul<Output = T> + Div<Output = T>> DivAssign for Dual<T> {
+    fn div_assign(&mut self, other: Dual<T>) {
+        *self = *self / other;
+    }
+}
+
+macro_rules! dual {
+    ($s:ident) => {
+        impl Dual<$s> {
+            /// Returns a constant, whose derivative is zero.
+            pub const fn constant(re: $s) -> Dual<$s> {
+                Dual { re, du: 0.0 }
+            }
+
+            /// Returns the variable being differentiated with respect to,
+            /// whose derivative is one.
+            pub const fn variable(re: $s) -> Dual<$s> {
+                Dual { re, du: 1.0 }
+            }
+
+            /// Returns the value and derivative of f at x.
+            ///
+            /// # Arguments
+            ///
+            /// * `f` - The function to differentiate.
+            /// * `x` - The point to evaluate it at.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use math_engine::dual::Dual;
+            #[doc = concat!("let (y, dy) = Dual::<", stringify!($s), ">::derivative(|x| x * x * x, 2.0);")]
+            /// assert!(y == 8.0 && dy == 12.0);
+            /// ```
+            pub fn derivative(f: impl Fn(Dual<$s>) -> Dual<$s>, x: $s) -> ($s, $s) {
+                let d = f(Dual::<$s>::variable(x));
+                (d.re, d.du)
+            }
+
+            /// Returns the square root.
+            pub fn sqrt(self) -> Dual<$s> {
+                let r = self.re.sqrt();
+                Dual::new(r, self.du / (2.0 * r))
+            }
+
+            /// Returns the sine.
+            pub fn sin(self) -> Dual<$s> {
+                Dual::new(self.re.sin(), self.du * self.re.cos())
+            }
+
+            /// Returns the cosine.
+            pub fn cos(self) -> Dual<$s> {
+                Dual::new(self.re.cos(), -self.du * self.re.sin())
+            }
+
+            /// Returns the tangent.
+            pub fn tan(self) -> Dual<$s> {
+                let t = self.re.tan();
+                Dual::new(t, self.du * (1.0 + t * t))
+            }
+
+            /// Returns e raised to this number.
+            pub fn exp(self) -> Dual<$s> {
+                let e = self.re.exp();
+                Dual::new(e, self.du * e)
+            }
+
+            /// Returns the natural logarithm.
+            pub fn ln(self) -> Dual<$s> {
+                Dual::new(self.re.ln(), self.du / self.re)
+            }
+
+            /// Returns the absolute value. Its derivative at zero is taken
+            /// to be that of the positive side.
+            pub fn abs(self) -> Dual<$s> {
+                if self.re < 0.0 {
+                    -self
+                } else {
+                    self
+                }
+            }
+
+            /// Returns the reciprocal, 1 / self.
+            pub fn recip(self) -> Dual<$s> {
+                Dual::new(1.0 / self.re, -self.du / (self.re * self.re))
+            }
+
+            /// Returns this number raised to the integer power n.
+            pub fn powi(self, n: i32) -> Dual<$s> {
+                // Exponentiation by squaring, so that no_std builds need no
+                // powi.
+                let pow = |x: $s, n: i32| {
+                    let mut p = 1.0;
+                    let mut b = if n < 0 { 1.0 / x } else { x };
+                    let mut e = n.unsigned_abs();
+                    while e > 0 {
+                        if e & 1 == 1 {
+                            p *= b;
+                        }
+                        b *= b;
+                        e >>= 1;
+                    }
+                    p
+                };
+                if n == 0 {
+                    return Dual::<$s>::constant(1.0);
+                }
+                Dual::new(pow(self.re, n), self.du * n as $s * pow(self.re, n - 1))
+            }
+        }
+
+        impl From<$s> for Dual<$s> {
+            fn from(re: $s) -> Self {
+                Dual::<$s>::constant(re)
+            }
+        }
+
+        impl Add<$s> for Dual<$s> {
+            type Output = Self;
+
+            fn add(self, other: $s) -> Self::Output {
+                Dual::new(self.re + other, self.du)
+            }
+        }
+
+        impl Sub<$s> for Dual<$s> {
+            type Output = Self;
+
+            fn sub(self, other: $s) -> Self::Output {
+                Dual::new(self.re - other, self.du)
+            }
+        }
+
+        impl Mul<$s> for Dual<$s> {
+            type Output = Self;
+
+            fn mul(self, other: $s) -> Self::Output {
+                Dual::new(self.re * other, self.du * other)
+            }
+        }
+
+        impl Mul<Dual<$s>> for $s {
+            type Output = Dual<$s>;
+
+            fn mul(self, other: Dual<$s>) -> Self::Output {
+                other * self
+            }
+        }
+
+        impl Div<$s> for Dual<$s> {
+            type Output = Self;
+
+            fn div(self, other: $s) -> Self::Output {
+                Dual::new(self.re / other, self.du / other)
+            }
+        }
+    };
+}
+
+macro_rules! gradient {
+    ($t:ident, $s:ident, $n:expr, $($c:ident: $i:expr),+) => {
+        impl $t {
+            /// Returns the gradient of f at p, the vector of its partial
+            /// derivatives, by evaluating f once per component with that
+            /// component as the variable.
+            ///
+            /// # Arguments
+            ///
+            /// * `f` - The function to differentiate, taking the components
+            ///   as duals.
+            /// * `p` - The point to evaluate the gradient at.
+            pub fn gradient(f: impl Fn([Dual<$s>; $n]) -> Dual<$s>, p: &$t) -> $t {
+                let a = [$(p.$c),+];
+                let seeded = |k: usize| {
+                    f(core::array::from_fn(|i| Dual::new(a[i], if i == k { 1.0 } else { 0.0 })))
+                        .du
+                };
+                $t { $($c: seeded($i)),+ }
+            }
+        }
+    };
+}
+
+dual!(f32);
+dual!(f64);
+gradient!(Vector2, f32, 2, x: 0, y: 1);
+gradient!(Vector3, f32, 3, x: 0, y: 1, z: 2);
+gradient!(DVector2, f64, 2, x: 0, y: 1);
+gradient!(DVector3, f64, 3, x: 0, y: 1, z: 2);
+
+#[test]
+fn derivatives() {
+    let (y, dy) = Dual::<f64>::derivative(|x| x.sin() * x.exp() / (x * x + 1.0), 0.5);
+    let x = 0.5f64;
+    let expected = ((x.cos() + x.sin()) * (x * x + 1.0) - 2.0 * x * x.sin()) * x.exp()
+        / ((x * x + 1.0) * (x * x + 1.0));
+    assert!((y - x.sin() * x.exp() / 1.25).abs() < 1e-15);
+    assert!((dy - expected).abs() < 1e-15);
+    assert!(Dual::<f32>::derivative(|x| x.powi(-2), 2.0) == (0.25, -0.25));
+    assert!(Dual::<f32>::derivative(|x| x.powi(3), 0.0) == (0.0, 0.0));
+    assert!(Dual::<f32>::derivative(|x| x.ln().tan(), 1.0).1 == 1.0);
+
+    // The gradient of the distance to a sphere is the unit direction away
+    // from its center.
+    let center = Vector3::new(1.0, 2.0, 3.0);
+    let sphere = |p: [Dual<f32>; 3]| {
+        let d = [p[0] - center.x, p[1] - center.y, p[2] - center.z];
+        (d[0] * d[0] + d[1] * d[1] + d[2] * d[2]).sqrt() - 1.0
+    };
+    let g = Vector3::gradient(sphere, &Vector3::new(4.0, 6.0, 3.0));
+    assert!(g == Vector3::new(0.6, 0.8, 0.0));
+}
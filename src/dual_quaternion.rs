@@ -0,0 +1,268 @@
+use crate::prelude::*;
+use core::ops::{Mul, MulAssign};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A dual quaternion real + dual * e, with e^2 = 0. Unit dual quaternions
+/// represent rigid transforms: the real part is the rotation and the dual
+/// part encodes the translation t as (t * real) / 2.
+#[repr(C)]
+pub struct DualQuaternion {
+    /// The real part, the rotation.
+    pub real: Quarternion,
+    /// The dual part, the translation combined with the rotation.
+    pub dual: Quarternion,
+}
+
+impl DualQuaternion {
+    /// Returns a dual quaternion with the given real and dual parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part.
+    /// * `dual` - The dual part.
+    pub const fn new(real: Quarternion, dual: Quarternion) -> DualQuaternion {
+        DualQuaternion { real, dual }
+    }
+
+    /// Returns the identity dual quaternion, representing no motion.
+    pub const fn identity() -> DualQuaternion {
+        DualQuaternion {
+            real: Quarternion::IDENTITY,
+            dual: Quarternion::new(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Returns the unit dual quaternion that rotates by the unit quaternion q
+    /// and then translates to t.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - A unit quaternion giving the rotation.
+    /// * `t` - The translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dual_quaternion::DualQuaternion;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::quarternion::Quarternion;
+    /// let q = Quarternion::from_rotation_z(0.5);
+    /// let d = DualQuaternion::from_rotation_translation(&q, &Point3::new(1.0, 2.0, 3.0));
+    /// assert!((d.get_translation() - Point3::new(1.0, 2.0, 3.0)).magnitude() < 1e-6);
+    /// ```
+    pub fn from_rotation_translation(q: &Quarternion, t: &Point3) -> DualQuaternion {
+        let t = Quarternion::new(t.x, t.y, t.z, 0.0);
+        DualQuaternion {
+            real: *q,
+            dual: t * *q * 0.5,
+        }
+    }
+
+    /// Returns the unit dual quaternion representing the rigid transform h.
+    ///
+    /// # Arguments
+    ///
+    /// * `h` - A reference to a rigid transform4.
+    pub fn from_transform(h: &Transform4) -> DualQuaternion {
+        let (q, t) = h.to_rotation_translation();
+        Self::from_rotation_translation(&q, &t)
+    }
+
+    /// Returns the rigid transform represented by this unit dual quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dual_quaternion::DualQuaternion;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// let h = Transform4::make_translation(&Vector3::new(1.0, 0.0, 0.0))
+    ///     * Transform4::make_rotation_x(0.3);
+    /// let g = DualQuaternion::from_transform(&h).to_transform();
+    /// assert!((0..4).all(|i| (g[i] - h[i]).magnitude() < 1e-6));
+    /// ```
+    pub fn to_transform(&self) -> Transform4 {
+        Transform4::from_rotation_translation(&self.real, &self.get_translation())
+    }
+
+    /// Returns the rotation part of this unit dual quaternion.
+    pub fn get_rotation(&self) -> Quarternion {
+        self.real
+    }
+
+    /// Returns the translation part of this unit dual quaternion.
+    pub fn get_translation(&self) -> Point3 {
+        Point3::from((self.dual * self.real.conjugate()).get_vector_part() * 2.0)
+    }
+
+    /// Returns the dual quaternion scaled so that its real part has unit
+    /// magnitude, with the dual part made orthogonal to the real part. The
+    /// result is a unit dual quaternion representing a rigid transform.
+    pub fn normalize(&self) -> DualQuaternion {
+        let n = self.real.magnitude();
+        let real = self.real / n;
+        let dual = self.dual / n;
+        DualQuaternion {
+            real,
+            dual: dual - real * real.dot(&dual),
+        }
+    }
+
+    /// Normalizes this dual quaternion, see `DualQuaternion::normalize`.
+    pub fn normalize_mut(&mut self) {
+        *self = self.normalize();
+    }
+
+    /// Returns the quaternion conjugate of both parts. For a unit dual
+    /// quaternion this is the inverse transform.
+    pub fn conjugate(&self) -> DualQuaternion {
+        DualQuaternion {
+            real: self.real.conjugate(),
+            dual: self.dual.conjugate(),
+        }
+    }
+
+    /// Returns the point p transformed by this unit dual quaternion.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dual_quaternion::DualQuaternion;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::quarternion::Quarternion;
+    /// let q = Quarternion::from_rotation_z(core::f32::consts::FRAC_PI_2);
+    /// let d = DualQuaternion::from_rotation_translation(&q, &Point3::new(0.0, 0.0, 1.0));
+    /// let p = d.transform_point(&Point3::new(1.0, 0.0, 0.0));
+    /// assert!((p - Point3::new(0.0, 1.0, 1.0)).magnitude() < 1e-6);
+    /// ```
+    pub fn transform_point(&self, p: &Point3) -> Point3 {
+        self.get_translation() + self.real.transform(&Vector3::from(*p))
+    }
+
+    /// Returns the direction v transformed by this unit dual quaternion,
+    /// which only rotates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A reference to a vector3.
+    pub fn transform_vector(&self, v: &Vector3) -> Vector3 {
+        self.real.transform(v)
+    }
+
+    /// Returns the screw-linear interpolation (ScLERP) between this unit dual
+    /// quaternion at t = 0 and other at t = 1. The motion in between is a
+    /// constant speed screw motion along the shortest path, rotating about
+    /// and translating along a single axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The unit dual quaternion at t = 1.
+    /// * `t` - The interpolation parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dual_quaternion::DualQuaternion;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::quarternion::Quarternion;
+    /// let a = DualQuaternion::identity();
+    /// let b = DualQuaternion::from_rotation_translation(
+    ///     &Quarternion::from_rotation_z(1.0),
+    ///     &Point3::new(0.0, 0.0, 4.0),
+    /// );
+    /// let m = a.sclerp(&b, 0.5);
+    /// assert!((m.real - Quarternion::from_rotation_z(0.5)).magnitude() < 1e-6);
+    /// assert!((m.get_translation() - Point3::new(0.0, 0.0, 2.0)).magnitude() < 1e-6);
+    /// ```
+    pub fn sclerp(&self, other: &DualQuaternion, t: f32) -> DualQuaternion {
+        let mut other = *other;
+        if self.real.dot(&other.real) < 0.0 {
+            other.real *= -1.0;
+            other.dual *= -1.0;
+        }
+        *self * (self.conjugate() * other).pow(t)
+    }
+
+    // Returns this unit dual quaternion raised to the power t, which scales
+    // the angle and the distance of its screw motion by t.
+    fn pow(&self, t: f32) -> DualQuaternion {
+        let v = self.real.get_vector_part();
+        let s = v.magnitude();
+        if s < 1.0e-6 {
+            // A pure translation, whose screw axis is undefined.
+            return DualQuaternion {
+                real: Quarternion::IDENTITY,
+                dual: Quarternion::new_with_vec(&(self.dual.get_vector_part() * t)),
+            };
+        }
+        let half = s.atan2(self.real.w);
+        let axis = v / s;
+        let dist = -2.0 * self.dual.w / s;
+        let moment = (self.dual.get_vector_part() - axis * (dist * 0.5 * self.real.w)) / s;
+
+        let half = half * t;
+        let dist = dist * t;
+        let (sin, cos) = (half.sin(), half.cos());
+        DualQuaternion {
+            real: Quarternion::new_with_vec_and_scalar(&(axis * sin), cos),
+            dual: Quarternion::new_with_vec_and_scalar(
+                &(moment * sin + axis * (dist * 0.5 * cos)),
+                -dist * 0.5 * sin,
+            ),
+        }
+    }
+}
+
+impl Default for DualQuaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Mul<DualQuaternion> for DualQuaternion {
+    type Output = Self;
+
+    /// Composes two rigid transforms, so that `a * b` applies b first.
+    fn mul(self, rhs: DualQuaternion) -> Self::Output {
+        DualQuaternion {
+            real: self.real * rhs.real,
+            dual: self.real * rhs.dual + self.dual * rhs.real,
+        }
+    }
+}
+
+impl MulAssign<DualQuaternion> for DualQuaternion {
+    fn mul_assign(&mut self, rhs: DualQuaternion) {
+        *self = *self * rhs;
+    }
+}
+
+#[test]
+fn rigid_motion() {
+    let a = Transform4::make_translation(&Vector3::new(1.0, -2.0, 0.5))
+        * Transform4::make_rotation(0.8, &Vector3::new(1.0, 1.0, 0.0).to_unit());
+    let b = Transform4::make_translation(&Vector3::new(0.0, 3.0, -1.0))
+        * Transform4::make_rotation_y(-1.3);
+    let da = DualQuaternion::from_transform(&a);
+    let db = DualQuaternion::from_transform(&b);
+    let p = Point3::new(0.3, 0.7, -2.0);
+    assert!(((da * db).transform_point(&p) - (a * b) * p).magnitude() < 1e-5);
+    assert!((da.conjugate().transform_point(&(a * p)) - p).magnitude() < 1e-5);
+
+    let n = DualQuaternion::new(da.real * 2.0, da.dual * 2.0).normalize();
+    assert!((n.transform_point(&p) - a * p).magnitude() < 1e-5);
+
+    let start = da.sclerp(&db, 0.0);
+    let end = da.sclerp(&db, 1.0);
+    assert!((start.transform_point(&p) - a * p).magnitude() < 1e-5);
+    assert!((end.transform_point(&p) - b * p).magnitude() < 1e-4);
+    // Half of the motion twice gives the whole motion.
+    let half = da.sclerp(&db, 0.5);
+    let twice = half * da.conjugate() * half;
+    assert!((twice.transform_point(&p) - b * p).magnitude() < 1e-4);
+}
@@ -0,0 +1,434 @@
+use crate::prelude::*;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+#[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A double precision two dimensional direction vector having float components
+/// x, and y. It's w coordinated it's assumed to be 0.
+#[repr(C)]
+pub struct DVector2 {
+    /// The x component.
+    pub x: f64,
+    /// The y component.
+    pub y: f64,
+}
+
+impl DVector2 {
+    /// The zero vector.
+    pub const ZERO: DVector2 = DVector2::new(0.0, 0.0);
+
+    /// The vector with every component 1.
+    pub const ONE: DVector2 = DVector2::new(1.0, 1.0);
+
+    /// The unit vector along the x axis.
+    pub const X: DVector2 = DVector2::new(1.0, 0.0);
+
+    /// The unit vector along the y axis.
+    pub const Y: DVector2 = DVector2::new(0.0, 1.0);
+
+    /// Returns a vector initialized with the floating point components x, and y.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinates.
+    /// * `y` - The y coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector2::DVector2;
+    /// let v = DVector2::new(1.0,0.0);
+    /// ```
+    pub const fn new(x: f64, y: f64) -> DVector2 {
+        Self { x, y }
+    }
+
+    /// Returns the vector with every component set to s.
+    pub const fn splat(s: f64) -> DVector2 {
+        DVector2::new(s, s)
+    }
+
+    /// Returns the dot product between this vector and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dvector3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector2::DVector2;
+    /// let v1 = DVector2::new(1.0,0.0);
+    /// let v2 = DVector2::new(1.0,0.0);
+    /// let d = v1.dot(&v2);
+    /// ```
+    pub fn dot(&self, other: &DVector2) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the length of this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector2::DVector2;
+    /// let v = DVector2::new(1.0,0.0);
+    /// let length = v.magnitude();
+    /// ```
+    pub fn magnitude(&self) -> f64 {
+        ((self.x * self.x) + (self.y * self.y)).sqrt()
+    }
+
+    /// Returns the projection of this vector onto other, under
+    /// the assumption that magnitude of other is 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dvector2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector2::DVector2;
+    /// let v1 = DVector2::new(1.0,0.0);
+    /// let v2 = DVector2::new(1.0,0.0);
+    /// let v3 = v1.project(&v2);
+    /// ```
+    pub fn project(&self, other: &DVector2) -> DVector2 {
+        *other * self.dot(other)
+    }
+
+    /// Returns the rejection of this vector from other, under
+    /// the assumption that magnitude of other is 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dvector2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector2::DVector2;
+    /// let v1 = DVector2::new(1.0,0.0);
+    /// let v2 = DVector2::new(1.0,0.0);
+    /// let v3 = v1.reject(&v2);
+    /// ```
+    pub fn reject(&self, other: &DVector2) -> DVector2 {
+        *self - *other * self.dot(other)
+    }
+
+    /// Returns this vector reflected about the plane with the given normal,
+    /// v - 2 (v · n) n, as GLSL's `reflect`. A vector pointing into a surface
+    /// comes back out of it with its tangential part unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - A reference to a unit dvector2.
+    pub fn reflect(&self, normal: &DVector2) -> DVector2 {
+        *self - *normal * (2.0 * self.dot(normal))
+    }
+
+    /// Returns the direction this unit vector takes after refracting through
+    /// a surface with the given normal, as GLSL's `refract`, or None on total
+    /// internal reflection. The normal should face against this vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - A reference to a unit dvector2.
+    /// * `eta` - The ratio of the refractive index this vector travels in to
+    ///   the refractive index of the surface.
+    pub fn refract(&self, normal: &DVector2, eta: f64) -> Option<DVector2> {
+        let d = self.dot(normal);
+        let k = 1.0 - eta * eta * (1.0 - d * d);
+        if k < 0.0 {
+            None
+        } else {
+            Some(*self * eta - *normal * (eta * d + k.sqrt()))
+        }
+    }
+
+    /// Returns this normal if it faces against the incident vector, that is
+    /// if reference · incident is negative, and the negated normal otherwise,
+    /// as GLSL's `faceforward`.
+    ///
+    /// # Arguments
+    ///
+    /// * `incident` - The incident direction.
+    /// * `reference` - The normal whose facing is tested, usually the
+    ///   geometric normal when this is an interpolated one.
+    pub fn faceforward(&self, incident: &DVector2, reference: &DVector2) -> DVector2 {
+        if reference.dot(incident) < 0.0 {
+            *self
+        } else {
+            -*self
+        }
+    }
+
+    /// Returns this vector multiplied by the inverse of it's magnitude
+    /// normalizing to unit length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector2::DVector2;
+    /// let v1 = DVector2::new(1.0,2.0);
+    /// let v2 = v1.normalize();
+    /// ```
+    pub fn normalize(&self) -> DVector2 {
+        *self / self.magnitude()
+    }
+
+    /// Multiplies this vector by the inverse of it's magnitude
+    /// normalizing to unit length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector2::DVector2;
+    /// let mut v = DVector2::new(1.0,2.0);
+    /// v.normalize_mut();
+    /// ```
+    pub fn normalize_mut(&mut self) {
+        let m = self.magnitude();
+        self.x /= m;
+        self.y /= m;
+    }
+
+    /// Returns this vector normalized to unit length, or None if its length
+    /// is zero or not finite, where `normalize` would return NaNs.
+    pub fn try_normalize(&self) -> Option<DVector2> {
+        let m = self.magnitude();
+        if m > 0.0 && m.is_finite() && (1.0 / m).is_finite() {
+            Some(*self / m)
+        } else {
+            None
+        }
+    }
+
+    /// Returns this vector normalized to unit length, or the zero vector if
+    /// it cannot be normalized, see `try_normalize`.
+    pub fn normalize_or_zero(&self) -> DVector2 {
+        self.try_normalize().unwrap_or_default()
+    }
+
+    /// Returns this vector converted to single precision, rounding each
+    /// component to the nearest f32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector2::DVector2;
+    /// use math_engine::vector2::Vector2;
+    /// let v = DVector2::new(1.0, 2.0);
+    /// assert!(v.to_f32() == Vector2::new(1.0, 2.0));
+    /// ```
+    pub fn to_f32(&self) -> Vector2 {
+        Vector2::new(self.x as f32, self.y as f32)
+    }
+
+    /// Returns this vector rotated a quarter turn counterclockwise, (-y, x).
+    pub fn perp(&self) -> DVector2 {
+        DVector2::new(-self.y, self.x)
+    }
+
+    /// Returns the perp dot product of this vector and other, the z
+    /// component of their 3D cross product. It is positive when other lies
+    /// counterclockwise of this vector and zero when they are parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dvector2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector2::DVector2;
+    /// let a = DVector2::new(2.0, 0.0);
+    /// assert!(a.perp_dot(&DVector2::new(1.0, 3.0)) == 6.0);
+    /// assert!(a.perp_dot(&DVector2::new(1.0, -3.0)) == -6.0);
+    /// ```
+    pub fn perp_dot(&self, other: &DVector2) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns the angle from the positive x axis to this vector, in radians
+    /// in the range (-π, π].
+    pub fn angle(&self) -> f64 {
+        self.y.atan2(self.x)
+    }
+
+    /// Returns the signed angle that turns this vector to the direction of
+    /// other, in radians in the range (-π, π], positive counterclockwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dvector2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector2::DVector2;
+    /// let a = DVector2::new(1.0, 1.0);
+    /// let b = DVector2::new(-2.0, 2.0);
+    /// assert!((a.angle_between(&b) - core::f64::consts::FRAC_PI_2).abs() < 1e-6);
+    /// assert!((b.angle_between(&a) + core::f64::consts::FRAC_PI_2).abs() < 1e-6);
+    /// ```
+    pub fn angle_between(&self, other: &DVector2) -> f64 {
+        self.perp_dot(other).atan2(self.dot(other))
+    }
+
+    /// Returns this vector rotated counterclockwise through the angle a.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector2::DVector2;
+    /// let v = DVector2::new(1.0, 0.0).rotate(core::f64::consts::FRAC_PI_2);
+    /// assert!((v - DVector2::new(0.0, 1.0)).magnitude() < 1e-12);
+    /// ```
+    pub fn rotate(&self, a: f64) -> DVector2 {
+        let (s, c) = (a.sin(), a.cos());
+        DVector2::new(c * self.x - s * self.y, s * self.x + c * self.y)
+    }
+}
+
+impl Index<usize> for DVector2 {
+    type Output = f64;
+    fn index(&self, i: usize) -> &Self::Output {
+        assert!(i < 2);
+        if i == 0 {
+            return &self.x;
+        }
+        &self.y
+    }
+}
+
+impl IndexMut<usize> for DVector2 {
+    fn index_mut(&mut self, i: usize) -> &mut f64 {
+        assert!(i < 2);
+        if i == 0 {
+            return &mut self.x;
+        }
+        &mut self.y
+    }
+}
+
+impl PartialEq for DVector2 {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl Mul<f64> for DVector2 {
+    type Output = Self;
+
+    fn mul(self, other: f64) -> Self::Output {
+        DVector2::new(self.x * other, self.y * other)
+    }
+}
+
+impl Mul<DVector2> for f64 {
+    type Output = DVector2;
+
+    fn mul(self, rhs: DVector2) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<DVector2> for DVector2 {
+    type Output = Self;
+
+    fn mul(self, other: DVector2) -> Self::Output {
+        DVector2::new(self.x * other.x, self.y * other.y)
+    }
+}
+
+impl Mul<DMatrix2> for DVector2 {
+    type Output = Self;
+
+    fn mul(self, other: DMatrix2) -> Self::Output {
+        DVector2::new(
+            other[(0, 0)] * self.x + other[(0, 1)] * self.y,
+            other[(1, 0)] * self.x + other[(1, 1)] * self.y,
+        )
+    }
+}
+
+impl Div<f64> for DVector2 {
+    type Output = Self;
+
+    fn div(self, other: f64) -> Self::Output {
+        DVector2::new(self.x / other, self.y / other)
+    }
+}
+
+impl Add<DVector2> for DVector2 {
+    type Output = Self;
+
+    fn add(self, other: DVector2) -> Self::Output {
+        DVector2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub<DVector2> for DVector2 {
+    type Output = Self;
+
+    fn sub(self, other: DVector2) -> Self::Output {
+        DVector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for DVector2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl From<Vector2> for DVector2 {
+    fn from(v: Vector2) -> Self {
+        DVector2::new(f64::from(v.x), f64::from(v.y))
+    }
+}
+
+impl AddAssign for DVector2 {
+    fn add_assign(&mut self, rhs: DVector2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl SubAssign for DVector2 {
+    fn sub_assign(&mut self, rhs: DVector2) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl MulAssign<f64> for DVector2 {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl MulAssign<DVector2> for DVector2 {
+    fn mul_assign(&mut self, rhs: DVector2) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+    }
+}
+
+impl DivAssign<f64> for DVector2 {
+    fn div_assign(&mut self, rhs: f64) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
@@ -0,0 +1,508 @@
+use crate::prelude::*;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A double precision three dimensional direction vector having float components
+/// x, y, and z. It's w coordinated it's assumed to be 0.
+#[repr(C)]
+pub struct DVector3 {
+    /// The x component.
+    pub x: f64,
+    /// The y component.
+    pub y: f64,
+    /// The z component.
+    pub z: f64,
+}
+
+impl DVector3 {
+    /// The zero vector.
+    pub const ZERO: DVector3 = DVector3::new(0.0, 0.0, 0.0);
+
+    /// The vector with every component 1.
+    pub const ONE: DVector3 = DVector3::new(1.0, 1.0, 1.0);
+
+    /// The unit vector along the x axis.
+    pub const X: DVector3 = DVector3::new(1.0, 0.0, 0.0);
+
+    /// The unit vector along the y axis.
+    pub const Y: DVector3 = DVector3::new(0.0, 1.0, 0.0);
+
+    /// The unit vector along the z axis.
+    pub const Z: DVector3 = DVector3::new(0.0, 0.0, 1.0);
+
+    /// Returns a directional vector initialized with the floating point components x, y, and z.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinates.
+    /// * `y` - The y coordinates.
+    /// * `z` - The z coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector3::DVector3;
+    /// let vec3 = DVector3::new(1.0,0.0,0.0);
+    /// assert!(vec3.x == 1.0 && vec3.y == 0.0 && vec3.z == 0.0);
+    /// ```
+    pub const fn new(x: f64, y: f64, z: f64) -> DVector3 {
+        Self { x, y, z }
+    }
+
+    /// Returns the vector with every component set to s.
+    pub const fn splat(s: f64) -> DVector3 {
+        DVector3::new(s, s, s)
+    }
+
+    /// Returns the dot product between this vector and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dvector3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector3::DVector3;
+    /// let v1 = DVector3::new(1.0,0.0,0.0);
+    /// let v2 = DVector3::new(1.0,0.0,1.0);
+    /// assert!(v1.dot(&v2) == 1.0);
+    /// ```
+    pub fn dot(&self, other: &DVector3) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the length of this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector3::DVector3;
+    /// let v = DVector3::new(1.0,0.0,0.0);
+    /// assert!(v.magnitude() == 1.0);
+    /// ```
+    pub fn magnitude(&self) -> f64 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Returns the cross product between this vector and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dvector3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector3::DVector3;
+    /// let v1 = DVector3::new(1.0,0.0,0.0);
+    /// let v2 = DVector3::new(1.0,0.0,1.0);
+    /// let v3 = v1.cross(&v2);
+    /// ```
+    pub fn cross(&self, other: &DVector3) -> DVector3 {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Returns the projection of this vector onto other, under
+    /// the assumption that the magnitude of other is 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dvector3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector3::DVector3;
+    /// let v1 = DVector3::new(1.0,0.0,0.0);
+    /// let v2 = DVector3::new(1.0,0.0,1.0);
+    /// let v3 = v1.project(&v2);
+    /// ```
+    pub fn project(&self, other: &DVector3) -> DVector3 {
+        *other * self.dot(other)
+    }
+
+    /// Returns the rejection of this vector from other, under
+    /// the assumption that the magnitude of other is 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a dvector3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector3::DVector3;
+    /// let v1 = DVector3::new(1.0,0.0,0.0);
+    /// let v2 = DVector3::new(1.0,0.0,1.0);
+    /// let v3 = v1.reject(&v2);
+    /// ```
+    pub fn reject(&self, other: &DVector3) -> DVector3 {
+        *self - *other * self.dot(other)
+    }
+
+    /// Returns this vector reflected about the plane with the given normal,
+    /// v - 2 (v · n) n, as GLSL's `reflect`. A vector pointing into a surface
+    /// comes back out of it with its tangential part unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - A reference to a unit dvector3.
+    pub fn reflect(&self, normal: &DVector3) -> DVector3 {
+        *self - *normal * (2.0 * self.dot(normal))
+    }
+
+    /// Returns the direction this unit vector takes after refracting through
+    /// a surface with the given normal, as GLSL's `refract`, or None on total
+    /// internal reflection. The normal should face against this vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - A reference to a unit dvector3.
+    /// * `eta` - The ratio of the refractive index this vector travels in to
+    ///   the refractive index of the surface.
+    pub fn refract(&self, normal: &DVector3, eta: f64) -> Option<DVector3> {
+        let d = self.dot(normal);
+        let k = 1.0 - eta * eta * (1.0 - d * d);
+        if k < 0.0 {
+            None
+        } else {
+            Some(*self * eta - *normal * (eta * d + k.sqrt()))
+        }
+    }
+
+    /// Returns this normal if it faces against the incident vector, that is
+    /// if reference · incident is negative, and the negated normal otherwise,
+    /// as GLSL's `faceforward`.
+    ///
+    /// # Arguments
+    ///
+    /// * `incident` - The incident direction.
+    /// * `reference` - The normal whose facing is tested, usually the
+    ///   geometric normal when this is an interpolated one.
+    pub fn faceforward(&self, incident: &DVector3, reference: &DVector3) -> DVector3 {
+        if reference.dot(incident) < 0.0 {
+            *self
+        } else {
+            -*self
+        }
+    }
+
+    /// Returns this vector multiplied by the inverse of it's magnitude
+    /// normalizing to unit length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector3::DVector3;
+    /// let v1 = DVector3::new(1.0,0.0,0.0);
+    /// let v2 = v1.normalize();
+    /// assert!(v1 == v2);
+    /// ```
+    pub fn normalize(&self) -> DVector3 {
+        *self / self.magnitude()
+    }
+
+    /// Multiplies this vector by the inverse of it's magnitude
+    /// normalizing to unit length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector3::DVector3;
+    /// let mut v = DVector3::new(1.0,0.0,0.0);
+    /// v.normalize_mut();
+    /// assert!(v == DVector3::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn normalize_mut(&mut self) {
+        let m = self.magnitude();
+        self.x /= m;
+        self.y /= m;
+        self.z /= m;
+    }
+
+    /// Returns this vector normalized to unit length, or None if its length
+    /// is zero or not finite, where `normalize` would return NaNs.
+    pub fn try_normalize(&self) -> Option<DVector3> {
+        let m = self.magnitude();
+        if m > 0.0 && m.is_finite() && (1.0 / m).is_finite() {
+            Some(*self / m)
+        } else {
+            None
+        }
+    }
+
+    /// Returns this vector normalized to unit length, or the zero vector if
+    /// it cannot be normalized, see `try_normalize`.
+    pub fn normalize_or_zero(&self) -> DVector3 {
+        self.try_normalize().unwrap_or_default()
+    }
+
+    /// Returns this vector converted to single precision, rounding each
+    /// component to the nearest f32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector3::DVector3;
+    /// use math_engine::vector3::Vector3;
+    /// let v = DVector3::new(1.0, 2.0, 3.0);
+    /// assert!(v.to_f32() == Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn to_f32(&self) -> Vector3 {
+        Vector3::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+
+    /// Returns the vector interpolated along the great arc between this
+    /// vector at t = 0 and other at t = 1, turning at constant angular speed.
+    /// The length is interpolated linearly, so unit vectors stay unit
+    /// length. Opposite vectors turn about an arbitrary perpendicular axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The vector at t = 1.
+    /// * `t` - The interpolation parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector3::DVector3;
+    /// let a = DVector3::new(1.0, 0.0, 0.0);
+    /// let b = DVector3::new(0.0, 1.0, 0.0);
+    /// let h = core::f64::consts::FRAC_1_SQRT_2;
+    /// assert!((a.slerp(&b, 0.5) - DVector3::new(h, h, 0.0)).magnitude() < 1e-6);
+    /// ```
+    pub fn slerp(&self, other: &DVector3, t: f64) -> DVector3 {
+        let (ma, mb) = (self.magnitude(), other.magnitude());
+        if ma == 0.0 || mb == 0.0 {
+            return self.lerp(other, t);
+        }
+        let a = *self / ma;
+        let b = *other / mb;
+        let cos = a.dot(&b);
+        let sin = a.cross(&b).magnitude();
+        let dir = if sin > 1e-6 {
+            let angle = sin.atan2(cos);
+            (a * ((1.0 - t) * angle).sin() + b * (t * angle).sin()) / sin
+        } else if cos > 0.0 {
+            a.lerp(&b, t).normalize_or_zero()
+        } else {
+            let c = a.any_orthonormal_pair().0;
+            let angle = t * core::f64::consts::PI;
+            a * angle.cos() + c * angle.sin()
+        };
+        dir * ((1.0 - t) * ma + t * mb)
+    }
+
+    /// Returns two unit vectors that together with this unit vector form a
+    /// right-handed orthonormal basis, so that the first crossed with the
+    /// second is this vector. It uses the branchless construction of Duff et
+    /// al., "Building an Orthonormal Basis, Revisited" (2017), which is
+    /// continuous everywhere except across the plane z = 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector3::DVector3;
+    /// let n = DVector3::new(1.0, 2.0, -2.0) / 3.0;
+    /// let (t, b) = n.any_orthonormal_pair();
+    /// assert!(t.dot(&n).abs() < 1e-6 && b.dot(&n).abs() < 1e-6 && t.dot(&b).abs() < 1e-6);
+    /// assert!((t.cross(&b) - n).magnitude() < 1e-6);
+    /// ```
+    pub fn any_orthonormal_pair(&self) -> (DVector3, DVector3) {
+        let sign = 1.0f64.copysign(self.z);
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            DVector3::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            DVector3::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+
+    /// Returns the rotation whose columns are `any_orthonormal_pair` and
+    /// this unit vector, which maps the z axis to this vector. It takes a
+    /// direction sampled about the z axis, such as a point on a cone or
+    /// hemisphere, to the same direction about this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::dvector3::DVector3;
+    /// let n = DVector3::new(0.0, -0.6, 0.8);
+    /// let m = n.orthonormal_basis();
+    /// assert!((m * DVector3::new(0.0, 0.0, 1.0) - n).magnitude() < 1e-6);
+    /// assert!((m.determinant() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn orthonormal_basis(&self) -> DMatrix3 {
+        let (t, b) = self.any_orthonormal_pair();
+        DMatrix3::new_with_vecs(t, b, *self)
+    }
+}
+
+impl Index<usize> for DVector3 {
+    type Output = f64;
+    fn index(&self, i: usize) -> &Self::Output {
+        assert!(i < 3);
+        if i == 0 {
+            return &self.x;
+        } else if i == 1 {
+            return &self.y;
+        }
+        &self.z
+    }
+}
+
+impl IndexMut<usize> for DVector3 {
+    fn index_mut(&mut self, i: usize) -> &mut f64 {
+        assert!(i < 3);
+        if i == 0 {
+            return &mut self.x;
+        } else if i == 1 {
+            return &mut self.y;
+        }
+        &mut self.z
+    }
+}
+
+impl Div<f64> for DVector3 {
+    type Output = Self;
+
+    fn div(self, other: f64) -> Self::Output {
+        let s = 1.0 / other;
+        Self::new(self.x * s, self.y * s, self.z * s)
+    }
+}
+
+impl DivAssign<f64> for DVector3 {
+    fn div_assign(&mut self, other: f64) {
+        let s = 1.0 / other;
+        self.x *= s;
+        self.y *= s;
+        self.z *= s;
+    }
+}
+
+impl Add for DVector3 {
+    type Output = Self;
+
+    fn add(self, other: DVector3) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl AddAssign for DVector3 {
+    fn add_assign(&mut self, other: Self) {
+        *self = Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        };
+    }
+}
+
+impl Sub for DVector3 {
+    type Output = Self;
+
+    fn sub(self, other: DVector3) -> Self::Output {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for DVector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl SubAssign for DVector3 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        };
+    }
+}
+
+impl Mul<f64> for DVector3 {
+    type Output = Self;
+
+    fn mul(self, other: f64) -> Self::Output {
+        Self::new(self.x * other, self.y * other, self.z * other)
+    }
+}
+
+impl Mul<DVector3> for f64 {
+    type Output = DVector3;
+
+    fn mul(self, rhs: DVector3) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul for DVector3 {
+    type Output = Self;
+
+    fn mul(self, other: DVector3) -> Self::Output {
+        Self::new(self.x * other.x, self.y * other.y, self.z * other.z)
+    }
+}
+
+impl Mul<DMatrix3> for DVector3 {
+    type Output = Self;
+
+    fn mul(self, other: DMatrix3) -> Self::Output {
+        Self::new(
+            other[(0, 0)] * self.x + other[(0, 1)] * self.y + other[(0, 2)] * self.z,
+            other[(1, 0)] * self.x + other[(1, 1)] * self.y + other[(1, 2)] * self.z,
+            other[(2, 0)] * self.x + other[(2, 1)] * self.y + other[(2, 2)] * self.z,
+        )
+    }
+}
+
+impl MulAssign<DMatrix3> for DVector3 {
+    fn mul_assign(&mut self, other: DMatrix3) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign for DVector3 {
+    fn mul_assign(&mut self, other: DVector3) {
+        self.x *= other.x;
+        self.y *= other.y;
+        self.z *= other.z;
+    }
+}
+
+impl MulAssign<f64> for DVector3 {
+    fn mul_assign(&mut self, other: f64) {
+        self.x *= other;
+        self.y *= other;
+        self.z *= other;
+    }
+}
+
+impl From<DPoint3> for DVector3 {
+    fn from(p: DPoint3) -> Self {
+        DVector3 {
+            x: p.x,
+            y: p.y,
+            z: p.z,
+        }
+    }
+}
+
+impl From<Vector3> for DVector3 {
+    fn from(v: Vector3) -> Self {
+        DVector3::new(f64::from(v.x), f64::from(v.y), f64::from(v.z))
+    }
+}
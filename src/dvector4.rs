@@ -0,0 +1,255 @@
+use crate::prelude::*;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+#[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A double precision four dimensional vector.
+#[repr(C)]
+pub struct DVector4 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl DVector4 {
+    /// The zero vector.
+    pub const ZERO: DVector4 = DVector4::new(0.0, 0.0, 0.0, 0.0);
+
+    /// The vector with every component 1.
+    pub const ONE: DVector4 = DVector4::new(1.0, 1.0, 1.0, 1.0);
+
+    /// The unit vector along the x axis.
+    pub const X: DVector4 = DVector4::new(1.0, 0.0, 0.0, 0.0);
+
+    /// The unit vector along the y axis.
+    pub const Y: DVector4 = DVector4::new(0.0, 1.0, 0.0, 0.0);
+
+    /// The unit vector along the z axis.
+    pub const Z: DVector4 = DVector4::new(0.0, 0.0, 1.0, 0.0);
+
+    /// The unit vector along the w axis.
+    pub const W: DVector4 = DVector4::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> DVector4 {
+        Self { x, y, z, w }
+    }
+
+    /// Returns the vector with every component set to s.
+    pub const fn splat(s: f64) -> DVector4 {
+        DVector4::new(s, s, s, s)
+    }
+
+    pub fn dot(&self, rhs: &DVector4) -> f64 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        ((self.x * self.x) + (self.y * self.y) + (self.z * self.z) + (self.w * self.w)).sqrt()
+    }
+
+    pub fn project(&self, rhs: &DVector4) -> DVector4 {
+        *rhs * self.dot(rhs)
+    }
+
+    pub fn reject(&self, rhs: &DVector4) -> DVector4 {
+        *self - *rhs * self.dot(rhs)
+    }
+
+    pub fn normalize(&self) -> DVector4 {
+        *self / self.magnitude()
+    }
+
+    pub fn normalize_mut(&mut self) {
+        let m = self.magnitude();
+        self.x /= m;
+        self.y /= m;
+        self.z /= m;
+        self.w /= m;
+    }
+
+    /// Returns this vector normalized to unit length, or None if its length
+    /// is zero or not finite, where `normalize` would return NaNs.
+    pub fn try_normalize(&self) -> Option<DVector4> {
+        let m = self.magnitude();
+        if m > 0.0 && m.is_finite() && (1.0 / m).is_finite() {
+            Some(*self / m)
+        } else {
+            None
+        }
+    }
+
+    /// Returns this vector normalized to unit length, or the zero vector if
+    /// it cannot be normalized, see `try_normalize`.
+    pub fn normalize_or_zero(&self) -> DVector4 {
+        self.try_normalize().unwrap_or_default()
+    }
+
+    pub fn to_f32(&self) -> Vector4 {
+        Vector4::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+}
+
+impl Index<usize> for DVector4 {
+    type Output = f64;
+    fn index(&self, i: usize) -> &Self::Output {
+        assert!(i < 4);
+        if i == 0 {
+            return &self.x;
+        } else if i == 1 {
+            return &self.y;
+        } else if i == 2 {
+            return &self.z;
+        }
+        &self.w
+    }
+}
+
+impl IndexMut<usize> for DVector4 {
+    fn index_mut(&mut self, i: usize) -> &mut f64 {
+        assert!(i < 4);
+        if i == 0 {
+            return &mut self.x;
+        } else if i == 1 {
+            return &mut self.y;
+        } else if i == 2 {
+            return &mut self.z;
+        }
+        &mut self.w
+    }
+}
+
+impl PartialEq for DVector4 {
+    fn eq(&self, rhs: &DVector4) -> bool {
+        self.x == rhs.x && self.y == rhs.y && self.z == rhs.z && self.w == rhs.w
+    }
+}
+
+impl Mul<f64> for DVector4 {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+impl Mul<DVector4> for f64 {
+    type Output = DVector4;
+
+    fn mul(self, rhs: DVector4) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<DVector4> for DVector4 {
+    type Output = Self;
+
+    fn mul(self, rhs: DVector4) -> Self::Output {
+        Self::new(
+            self.x * rhs.x,
+            self.y * rhs.y,
+            self.z * rhs.z,
+            self.w * rhs.w,
+        )
+    }
+}
+
+impl Div<f64> for DVector4 {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs, self.w / rhs)
+    }
+}
+
+impl Add<DVector4> for DVector4 {
+    type Output = Self;
+
+    fn add(self, rhs: DVector4) -> Self::Output {
+        Self::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+impl Sub<DVector4> for DVector4 {
+    type Output = Self;
+
+    fn sub(self, rhs: DVector4) -> Self::Output {
+        Self::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
+
+impl Neg for DVector4 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl MulAssign<DVector4> for DVector4 {
+    fn mul_assign(&mut self, rhs: DVector4) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+        self.w *= rhs.w;
+    }
+}
+
+impl MulAssign<f64> for DVector4 {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+        self.w *= rhs;
+    }
+}
+
+impl From<Vector4> for DVector4 {
+    fn from(v: Vector4) -> Self {
+        DVector4::new(
+            f64::from(v.x),
+            f64::from(v.y),
+            f64::from(v.z),
+            f64::from(v.w),
+        )
+    }
+}
+
+impl AddAssign for DVector4 {
+    fn add_assign(&mut self, rhs: DVector4) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+        self.w += rhs.w;
+    }
+}
+
+impl SubAssign for DVector4 {
+    fn sub_assign(&mut self, rhs: DVector4) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+        self.w -= rhs.w;
+    }
+}
+
+impl DivAssign<f64> for DVector4 {
+    fn div_assign(&mut self, rhs: f64) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+        self.w /= rhs;
+    }
+}
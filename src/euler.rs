@@ -0,0 +1,43 @@
+//! Euler angle orders shared by `Quarternion::from_euler` and
+//! `Matrix3::from_euler`.
+
+/// The order in which the three Euler rotations are applied.
+///
+/// Each variant names the axes in the order their rotations act on a vector,
+/// about the fixed (extrinsic) axes. So `EulerOrder::XYZ` rotates about x
+/// first, then y, then z, giving the matrix `Rz * Ry * Rx`. Read right to
+/// left, the same name describes intrinsic rotations about the moving axes:
+/// `XYZ` is also z, then the new y, then the newer x.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    // Returns the indices of the axes in application order.
+    pub(crate) fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+        }
+    }
+
+    // Returns 1 if the axes are an even permutation of x, y, z and -1 if odd.
+    pub(crate) fn parity(&self) -> f32 {
+        match self {
+            EulerOrder::XYZ | EulerOrder::YZX | EulerOrder::ZXY => 1.0,
+            EulerOrder::XZY | EulerOrder::YXZ | EulerOrder::ZYX => -1.0,
+        }
+    }
+}
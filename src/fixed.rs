@@ -0,0 +1,397 @@
+//! Fixed-point numbers for deterministic simulation. `Fixed32` is a Q16.16
+//! number, an i32 counting units of 2^-16, so its arithmetic is integer
+//! arithmetic and gives the same bits on every platform and compiler, which
+//! float arithmetic does not promise. That is what lockstep multiplayer
+//! needs: every peer runs the same simulation and must stay in sync.
+//!
+//! The range is [-32768, 32768) with a resolution of about 1.5e-5.
+//! Arithmetic wraps on overflow, in debug and release builds alike, and
+//! division by zero panics. Conversion from f32 is exact up to rounding to
+//! the nearest step, so it is deterministic too.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A Q16.16 fixed-point number, stored as its raw count of 2^-16 steps.
+#[repr(transparent)]
+pub struct Fixed32(pub i32);
+
+impl Fixed32 {
+    /// The number of fractional bits.
+    pub const FRAC_BITS: u32 = 16;
+    /// Zero.
+    pub const ZERO: Fixed32 = Fixed32(0);
+    /// One.
+    pub const ONE: Fixed32 = Fixed32(1 << 16);
+    /// The smallest positive value, 2^-16.
+    pub const EPSILON: Fixed32 = Fixed32(1);
+    /// The smallest value, -32768.
+    pub const MIN: Fixed32 = Fixed32(i32::MIN);
+    /// The largest value, just under 32768.
+    pub const MAX: Fixed32 = Fixed32(i32::MAX);
+
+    /// Returns the integer i as a fixed-point number, wrapping if it is
+    /// outside the range.
+    pub const fn from_int(i: i32) -> Fixed32 {
+        Fixed32(i.wrapping_shl(16))
+    }
+
+    /// Returns the fixed-point number nearest to f, saturating at the ends of
+    /// the range. NaN converts to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::fixed::Fixed32;
+    /// assert!(Fixed32::from_f32(1.5) == Fixed32(0x18000));
+    /// assert!(Fixed32::from_f32(1e9) == Fixed32::MAX);
+    /// assert!(Fixed32::from_f32(-0.25).to_f32() == -0.25);
+    /// ```
+    pub fn from_f32(f: f32) -> Fixed32 {
+        Fixed32((f * 65536.0).round() as i32)
+    }
+
+    /// Returns the fixed-point number nearest to f, saturating at the ends of
+    /// the range. NaN converts to zero.
+    pub fn from_f64(f: f64) -> Fixed32 {
+        // Rounds half away from zero like f32::round, without needing the
+        // f64 functions in no_std builds.
+        let x = f * 65536.0;
+        Fixed32(if x < 0.0 {
+            (x - 0.5) as i32
+        } else {
+            (x + 0.5) as i32
+        })
+    }
+
+    /// Returns the nearest f32, which is exact for magnitudes below 256.
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / 65536.0
+    }
+
+    /// Returns this number as an f64, which is always exact.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / 65536.0
+    }
+
+    /// Returns the integer part, rounded toward negative infinity.
+    pub const fn to_int(self) -> i32 {
+        self.0 >> 16
+    }
+
+    /// Returns the largest integer less than or equal to this number.
+    pub const fn floor(self) -> Fixed32 {
+        Fixed32(self.0 & !0xffff)
+    }
+
+    /// Returns the fractional part, self - self.floor(), in [0, 1).
+    pub const fn fract(self) -> Fixed32 {
+        Fixed32(self.0 & 0xffff)
+    }
+
+    /// Returns the absolute value, wrapping for `Fixed32::MIN`.
+    pub const fn abs(self) -> Fixed32 {
+        Fixed32(self.0.wrapping_abs())
+    }
+
+    /// Returns the square root, rounded down to a step, or zero for
+    /// negative numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::fixed::Fixed32;
+    /// assert!(Fixed32::from_int(9).sqrt() == Fixed32::from_int(3));
+    /// assert!(Fixed32::from_f32(0.25).sqrt() == Fixed32::from_f32(0.5));
+    /// ```
+    pub fn sqrt(self) -> Fixed32 {
+        if self.0 <= 0 {
+            return Fixed32::ZERO;
+        }
+        // The root of the raw value scaled by 2^16 is the raw root, found
+        // bit by bit from the top.
+        let n = (self.0 as u64) << 16;
+        let mut root = 0u64;
+        let mut bit = 1u64 << 46;
+        let mut rem = n;
+        while bit > rem {
+            bit >>= 2;
+        }
+        while bit != 0 {
+            if rem >= root + bit {
+                rem -= root + bit;
+                root = (root >> 1) + bit;
+            } else {
+                root >>= 1;
+            }
+            bit >>= 2;
+        }
+        Fixed32(root as i32)
+    }
+
+    /// Returns the smaller of this number and other.
+    pub fn min(self, other: Fixed32) -> Fixed32 {
+        Ord::min(self, other)
+    }
+
+    /// Returns the larger of this number and other.
+    pub fn max(self, other: Fixed32) -> Fixed32 {
+        Ord::max(self, other)
+    }
+}
+
+impl From<i32> for Fixed32 {
+    fn from(i: i32) -> Self {
+        Fixed32::from_int(i)
+    }
+}
+
+impl From<Fixed32> for f32 {
+    fn from(f: Fixed32) -> Self {
+        f.to_f32()
+    }
+}
+
+impl From<Fixed32> for f64 {
+    fn from(f: Fixed32) -> Self {
+        f.to_f64()
+    }
+}
+
+impl Add for Fixed32 {
+    type Output = Self;
+
+    fn add(self, other: Fixed32) -> Self::Output {
+        Fixed32(self.0.wrapping_add(other.0))
+    }
+}
+
+impl AddAssign for Fixed32 {
+    fn add_assign(&mut self, other: Fixed32) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Fixed32 {
+    type Output = Self;
+
+    fn sub(self, other: Fixed32) -> Self::Output {
+        Fixed32(self.0.wrapping_sub(other.0))
+    }
+}
+
+impl SubAssign for Fixed32 {
+    fn sub_assign(&mut self, other: Fixed32) {
+        *self = *self - other;
+    }
+}
+
+impl Mul for Fixed32 {
+    type Output = Self;
+
+    /// Multiplies exactly in 64 bits, then rounds to the nearest step with
+    /// ties toward positive infinity.
+    fn mul(self, other: Fixed32) -> Self::Output {
+        let p = self.0 as i64 * other.0 as i64;
+        Fixed32(((p + 0x8000) >> 16) as i32)
+    }
+}
+
+impl MulAssign for Fixed32 {
+    fn mul_assign(&mut self, other: Fixed32) {
+        *self = *self * other;
+    }
+}
+
+impl Div for Fixed32 {
+    type Output = Self;
+
+    /// Divides in 64 bits, rounding toward zero.
+    fn div(self, other: Fixed32) -> Self::Output {
+        Fixed32((((self.0 as i64) << 16) / other.0 as i64) as i32)
+    }
+}
+
+impl DivAssign for Fixed32 {
+    fn div_assign(&mut self, other: Fixed32) {
+        *self = *self / other;
+    }
+}
+
+impl Neg for Fixed32 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Fixed32(self.0.wrapping_neg())
+    }
+}
+
+macro_rules! fixed_vector {
+    ($(#[$doc:meta])* $t:ident, $f:ident, $($c:ident),+) => {
+        $(#[$doc])*
+        #[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[repr(C)]
+        pub struct $t {
+            $(
+                #[doc = concat!("The ", stringify!($c), " component.")]
+                pub $c: Fixed32,
+            )+
+        }
+
+        impl $t {
+            /// The zero vector.
+            pub const ZERO: $t = $t { $($c: Fixed32::ZERO),+ };
+
+            /// Returns a vector with the given components.
+            pub const fn new($($c: Fixed32),+) -> $t {
+                $t { $($c),+ }
+            }
+
+            /// Returns the dot product of this vector and other.
+            pub fn dot(&self, other: &$t) -> Fixed32 {
+                Fixed32::ZERO $(+ self.$c * other.$c)+
+            }
+
+            /// Returns the squared length of this vector.
+            pub fn magnitude_squared(&self) -> Fixed32 {
+                self.dot(self)
+            }
+
+            /// Returns the length of this vector.
+            pub fn magnitude(&self) -> Fixed32 {
+                self.magnitude_squared().sqrt()
+            }
+        }
+
+        impl From<$f> for $t {
+            fn from(v: $f) -> Self {
+                $t { $($c: Fixed32::from_f32(v.$c)),+ }
+            }
+        }
+
+        impl From<$t> for $f {
+            fn from(v: $t) -> Self {
+                $f { $($c: v.$c.to_f32()),+ }
+            }
+        }
+
+        impl Add for $t {
+            type Output = Self;
+
+            fn add(self, other: $t) -> Self::Output {
+                $t { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, other: $t) {
+                *self = *self + other;
+            }
+        }
+
+        impl Sub for $t {
+            type Output = Self;
+
+            fn sub(self, other: $t) -> Self::Output {
+                $t { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, other: $t) {
+                *self = *self - other;
+            }
+        }
+
+        impl Mul<Fixed32> for $t {
+            type Output = Self;
+
+            fn mul(self, other: Fixed32) -> Self::Output {
+                $t { $($c: self.$c * other),+ }
+            }
+        }
+
+        impl MulAssign<Fixed32> for $t {
+            fn mul_assign(&mut self, other: Fixed32) {
+                *self = *self * other;
+            }
+        }
+
+        impl Mul<$t> for Fixed32 {
+            type Output = $t;
+
+            fn mul(self, other: $t) -> Self::Output {
+                other * self
+            }
+        }
+
+        impl Div<Fixed32> for $t {
+            type Output = Self;
+
+            fn div(self, other: Fixed32) -> Self::Output {
+                $t { $($c: self.$c / other),+ }
+            }
+        }
+
+        impl DivAssign<Fixed32> for $t {
+            fn div_assign(&mut self, other: Fixed32) {
+                *self = *self / other;
+            }
+        }
+
+        impl Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                $t { $($c: -self.$c),+ }
+            }
+        }
+    };
+}
+
+fixed_vector!(
+    /// A two dimensional vector of Q16.16 fixed-point numbers.
+    FixedVec2, Vector2, x, y
+);
+fixed_vector!(
+    /// A three dimensional vector of Q16.16 fixed-point numbers.
+    FixedVec3, Vector3, x, y, z
+);
+
+impl FixedVec3 {
+    /// Returns the cross product of this vector and other.
+    pub fn cross(&self, other: &FixedVec3) -> FixedVec3 {
+        FixedVec3 {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+}
+
+#[test]
+fn determinism() {
+    let a = Fixed32::from_f32(2.5);
+    let b = Fixed32::from_int(-3);
+    assert!(a * b == Fixed32::from_f32(-7.5));
+    assert!(b / a == Fixed32::from_f32(-1.2));
+    assert!(Fixed32::ONE / Fixed32::from_int(3) == Fixed32(21845));
+    assert!(Fixed32::MAX + Fixed32::EPSILON == Fixed32::MIN);
+    assert!(Fixed32::from_f32(-1.25).floor() == Fixed32::from_int(-2));
+    assert!(Fixed32::from_f32(-1.25).fract() == Fixed32::from_f32(0.75));
+    assert!(Fixed32::from_f64(-0.5 / 65536.0) == Fixed32(-1));
+    assert!(Fixed32::from_int(2).sqrt() == Fixed32(92681));
+
+    let v = FixedVec3::from(Vector3::new(3.0, 0.0, 4.0));
+    assert!(v.magnitude() == Fixed32::from_int(5));
+    let x = FixedVec3::new(Fixed32::ONE, Fixed32::ZERO, Fixed32::ZERO);
+    let y = FixedVec3::new(Fixed32::ZERO, Fixed32::ONE, Fixed32::ZERO);
+    assert!(x.cross(&y).z == Fixed32::ONE);
+    assert!(Vector3::from(v * a) == Vector3::new(7.5, 0.0, 10.0));
+}
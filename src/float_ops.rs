@@ -0,0 +1,128 @@
+//! Routes the floating point functions the crate uses through `libm` when
+//! the `std` feature is disabled. With `std` linked the inherent methods on
+//! `f32` and `f64` are used instead and this module is not compiled.
+
+#[allow(dead_code)]
+pub trait FloatOps {
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn ceil(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn asin(self) -> Self;
+    fn floor(self) -> Self;
+    fn round(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn powf(self, other: Self) -> Self;
+}
+
+impl FloatOps for f32 {
+    fn sqrt(self) -> f32 {
+        libm::sqrtf(self)
+    }
+
+    fn sin(self) -> f32 {
+        libm::sinf(self)
+    }
+
+    fn cos(self) -> f32 {
+        libm::cosf(self)
+    }
+
+    fn tan(self) -> f32 {
+        libm::tanf(self)
+    }
+
+    fn ceil(self) -> f32 {
+        libm::ceilf(self)
+    }
+
+    fn atan2(self, other: f32) -> f32 {
+        libm::atan2f(self, other)
+    }
+
+    fn asin(self) -> f32 {
+        libm::asinf(self)
+    }
+
+    fn floor(self) -> f32 {
+        libm::floorf(self)
+    }
+
+    fn round(self) -> f32 {
+        libm::roundf(self)
+    }
+
+    fn exp(self) -> f32 {
+        libm::expf(self)
+    }
+
+    fn ln(self) -> f32 {
+        libm::logf(self)
+    }
+
+    fn cbrt(self) -> f32 {
+        libm::cbrtf(self)
+    }
+
+    fn powf(self, other: f32) -> f32 {
+        libm::powf(self, other)
+    }
+}
+
+impl FloatOps for f64 {
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    fn sin(self) -> f64 {
+        libm::sin(self)
+    }
+
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    fn tan(self) -> f64 {
+        libm::tan(self)
+    }
+
+    fn ceil(self) -> f64 {
+        libm::ceil(self)
+    }
+
+    fn atan2(self, other: f64) -> f64 {
+        libm::atan2(self, other)
+    }
+
+    fn asin(self) -> f64 {
+        libm::asin(self)
+    }
+
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    fn exp(self) -> f64 {
+        libm::exp(self)
+    }
+
+    fn ln(self) -> f64 {
+        libm::log(self)
+    }
+
+    fn cbrt(self) -> f64 {
+        libm::cbrt(self)
+    }
+
+    fn powf(self, other: f64) -> f64 {
+        libm::pow(self, other)
+    }
+}
@@ -0,0 +1,138 @@
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A view frustum bounded by six planes whose normals point into the frustum,
+/// stored in the order left, right, bottom, top, near, far.
+#[repr(C)]
+pub struct Frustum {
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Returns a frustum bounded by the given planes.
+    ///
+    /// # Arguments
+    ///
+    /// * `planes` - The left, right, bottom, top, near and far planes, with
+    ///   normals pointing into the frustum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::frustum::Frustum;
+    /// use math_engine::plane::Plane;
+    /// use math_engine::point3::Point3;
+    /// // The cube [-1, 1]^3.
+    /// let f = Frustum::new([
+    ///     Plane::new(1.0, 0.0, 0.0, 1.0),
+    ///     Plane::new(-1.0, 0.0, 0.0, 1.0),
+    ///     Plane::new(0.0, 1.0, 0.0, 1.0),
+    ///     Plane::new(0.0, -1.0, 0.0, 1.0),
+    ///     Plane::new(0.0, 0.0, 1.0, 1.0),
+    ///     Plane::new(0.0, 0.0, -1.0, 1.0),
+    /// ]);
+    /// assert!(f.contains_point(&Point3::new(0.5, -0.5, 0.0)));
+    /// ```
+    pub const fn new(planes: [Plane; 6]) -> Frustum {
+        Frustum { planes }
+    }
+
+    /// Returns the frustum of the projection or view-projection matrix m. See
+    /// `Matrix4::extract_frustum_planes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - A reference to a matrix4.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::frustum::Frustum;
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::point3::Point3;
+    /// let m = Matrix4::perspective_infinite_reverse_rh(1.0, 1.5, 0.1);
+    /// let f = Frustum::from_matrix(&m);
+    /// assert!(f.contains_point(&Point3::new(0.0, 0.0, -1000.0)));
+    /// assert!(!f.contains_point(&Point3::new(0.0, 0.0, 1.0)));
+    /// ```
+    pub fn from_matrix(m: &Matrix4) -> Frustum {
+        Frustum {
+            planes: m.extract_frustum_planes(),
+        }
+    }
+
+    /// Returns true if the point p lies inside or on this frustum.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point3.
+    pub fn contains_point(&self, p: &Point3) -> bool {
+        self.planes.iter().all(|f| f.point_dot(p) >= 0.0)
+    }
+
+    /// Returns true unless the sphere s lies entirely outside one of the
+    /// planes of this frustum. The test is conservative: spheres near a
+    /// corner may be reported as intersecting when they are not. The planes
+    /// are expected to be normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - A reference to a sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::frustum::Frustum;
+    /// use math_engine::plane::Plane;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// let f = Frustum::new([
+    ///     Plane::new(1.0, 0.0, 0.0, 1.0),
+    ///     Plane::new(-1.0, 0.0, 0.0, 1.0),
+    ///     Plane::new(0.0, 1.0, 0.0, 1.0),
+    ///     Plane::new(0.0, -1.0, 0.0, 1.0),
+    ///     Plane::new(0.0, 0.0, 1.0, 1.0),
+    ///     Plane::new(0.0, 0.0, -1.0, 1.0),
+    /// ]);
+    /// assert!(f.intersects_sphere(&Sphere::new(Point3::new(1.5, 0.0, 0.0), 1.0)));
+    /// assert!(!f.intersects_sphere(&Sphere::new(Point3::new(2.5, 0.0, 0.0), 1.0)));
+    /// ```
+    pub fn intersects_sphere(&self, s: &Sphere) -> bool {
+        self.planes
+            .iter()
+            .all(|f| f.point_dot(&s.center) >= -s.radius)
+    }
+
+    /// Returns true unless the box b lies entirely outside one of the planes
+    /// of this frustum. Like `intersects_sphere` the test is conservative,
+    /// testing against each plane the corner of the box furthest along its
+    /// normal.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - A reference to an aabb3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::frustum::Frustum;
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::point3::Point3;
+    /// let f = Frustum::from_matrix(&Matrix4::perspective_infinite_reverse_rh(1.0, 1.5, 0.1));
+    /// let ahead = Aabb3::new(Point3::new(-1.0, -1.0, -10.0), Point3::new(1.0, 1.0, -8.0));
+    /// let behind = Aabb3::new(Point3::new(-1.0, -1.0, 8.0), Point3::new(1.0, 1.0, 10.0));
+    /// assert!(f.intersects_aabb(&ahead) && !f.intersects_aabb(&behind));
+    /// ```
+    pub fn intersects_aabb(&self, b: &Aabb3) -> bool {
+        self.planes.iter().all(|f| {
+            let corner = Point3::new(
+                if f.x >= 0.0 { b.max.x } else { b.min.x },
+                if f.y >= 0.0 { b.max.y } else { b.min.y },
+                if f.z >= 0.0 { b.max.z } else { b.min.z },
+            );
+            f.point_dot(&corner) >= 0.0
+        })
+    }
+}
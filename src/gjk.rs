@@ -0,0 +1,573 @@
+//! Distance and penetration between convex shapes, the narrow phase of a
+//! physics engine. Shapes take part through `Support`, which returns the
+//! point of a shape furthest in a direction. GJK, after Gilbert, Johnson
+//! and Keerthi, finds the distance between separated shapes and their
+//! closest points by walking a simplex of the Minkowski difference toward
+//! the origin. When the shapes overlap, EPA, the expanding polytope
+//! algorithm, grows that simplex out to the surface of the difference to
+//! find the smallest translation that separates them.
+//!
+//! `Support` is implemented for spheres, boxes, capsules, convex hulls,
+//! triangles, segments, points and point slices.
+//!
+//! # Examples
+//!
+//! ```
+//! use math_engine::aabb3::Aabb3;
+//! use math_engine::gjk;
+//! use math_engine::point3::Point3;
+//! use math_engine::sphere::Sphere;
+//! let b = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+//! let s = Sphere::new(Point3::new(4.0, 0.0, 0.0), 1.0);
+//! let (d, on_box, on_sphere) = gjk::distance(&b, &s).unwrap();
+//! assert!((d - 2.0).abs() < 1e-5);
+//! assert!((on_box - Point3::new(1.0, 0.0, 0.0)).magnitude() < 1e-3);
+//! assert!((on_sphere - Point3::new(3.0, 0.0, 0.0)).magnitude() < 1e-3);
+//! ```
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use alloc::vec::Vec;
+
+// The most steps either algorithm takes, a bound that is only reached by
+// curved shapes converging slowly.
+const MAX_ITERATIONS: usize = 64;
+// The relative progress below which GJK and EPA stop.
+const TOLERANCE: f32 = 1.0e-5;
+// The squared distance below which the shapes are taken to touch.
+const TOUCHING: f32 = 1.0e-10;
+
+/// A convex shape described by its support mapping.
+pub trait Support {
+    /// Returns a point of the shape furthest along d, one maximizing the
+    /// dot product with d. The direction need not be normalized.
+    fn support(&self, d: &Vector3) -> Point3;
+}
+
+impl Support for Point3 {
+    fn support(&self, _d: &Vector3) -> Point3 {
+        *self
+    }
+}
+
+impl Support for [Point3] {
+    fn support(&self, d: &Vector3) -> Point3 {
+        let o = Point3::new(0.0, 0.0, 0.0);
+        self.iter()
+            .copied()
+            .max_by(|a, b| (*a - o).dot(d).total_cmp(&(*b - o).dot(d)))
+            .unwrap_or(o)
+    }
+}
+
+impl Support for Segment3 {
+    fn support(&self, d: &Vector3) -> Point3 {
+        if (self.b - self.a).dot(d) > 0.0 {
+            self.b
+        } else {
+            self.a
+        }
+    }
+}
+
+impl Support for Triangle {
+    fn support(&self, d: &Vector3) -> Point3 {
+        [self.a, self.b, self.c][..].support(d)
+    }
+}
+
+impl Support for ConvexHull {
+    fn support(&self, d: &Vector3) -> Point3 {
+        self.vertices().support(d)
+    }
+}
+
+impl Support for Sphere {
+    fn support(&self, d: &Vector3) -> Point3 {
+        self.center + d.normalize_or_zero() * self.radius
+    }
+}
+
+impl Support for Capsule {
+    fn support(&self, d: &Vector3) -> Point3 {
+        self.segment.support(d) + d.normalize_or_zero() * self.radius
+    }
+}
+
+impl Support for Aabb3 {
+    fn support(&self, d: &Vector3) -> Point3 {
+        Point3::new(
+            if d.x > 0.0 { self.max.x } else { self.min.x },
+            if d.y > 0.0 { self.max.y } else { self.min.y },
+            if d.z > 0.0 { self.max.z } else { self.min.z },
+        )
+    }
+}
+
+impl Support for Obb {
+    fn support(&self, d: &Vector3) -> Point3 {
+        let mut p = self.center;
+        for i in 0..3 {
+            let axis = self.rotation[i];
+            let e = self.half_extents[i];
+            p += if axis.dot(d) > 0.0 {
+                axis * e
+            } else {
+                axis * -e
+            };
+        }
+        p
+    }
+}
+
+// A vertex of the Minkowski difference a - b, with the points of a and b it
+// came from.
+#[derive(Copy, Clone, Debug, Default)]
+struct Vertex {
+    w: Vector3,
+    a: Point3,
+    b: Point3,
+}
+
+fn support<A: Support + ?Sized, B: Support + ?Sized>(a: &A, b: &B, d: &Vector3) -> Vertex {
+    let pa = a.support(d);
+    let pb = b.support(&-*d);
+    Vertex {
+        w: pa - pb,
+        a: pa,
+        b: pb,
+    }
+}
+
+// A simplex of up to four vertices, with the barycentric weights of its
+// point closest to the origin.
+#[derive(Copy, Clone, Debug, Default)]
+struct Simplex {
+    v: [Vertex; 4],
+    weights: [f32; 4],
+    n: usize,
+}
+
+impl Simplex {
+    fn closest(&self) -> Vector3 {
+        (0..self.n).fold(Vector3::new(0.0, 0.0, 0.0), |sum, i| {
+            sum + self.v[i].w * self.weights[i]
+        })
+    }
+
+    fn points(&self) -> (Point3, Point3) {
+        let o = Point3::new(0.0, 0.0, 0.0);
+        let (mut a, mut b) = (o, o);
+        for i in 0..self.n {
+            a += (self.v[i].a - o) * self.weights[i];
+            b += (self.v[i].b - o) * self.weights[i];
+        }
+        (a, b)
+    }
+
+    // Returns the simplex of the vertices with nonzero weight.
+    fn keep(v: &[Vertex], weights: &[f32]) -> Simplex {
+        let mut s = Simplex::default();
+        for (v, &w) in v.iter().zip(weights) {
+            if w > 0.0 {
+                s.v[s.n] = *v;
+                s.weights[s.n] = w;
+                s.n += 1;
+            }
+        }
+        s
+    }
+
+    // Returns the smallest face of this simplex holding its point closest
+    // to the origin, or None if the origin lies inside the tetrahedron.
+    fn reduce(&self) -> Option<Simplex> {
+        let v = &self.v;
+        match self.n {
+            1 => Some(Simplex::keep(&v[..1], &[1.0])),
+            2 => Some(Simplex::keep(&v[..2], &segment_weights(v[0].w, v[1].w))),
+            3 => Some(Simplex::keep(
+                &v[..3],
+                &triangle_weights(v[0].w, v[1].w, v[2].w),
+            )),
+            _ => {
+                let mut best: Option<(f32, Simplex)> = None;
+                for (i, j, k, l) in [(0, 1, 2, 3), (0, 3, 1, 2), (0, 2, 3, 1), (1, 3, 2, 0)] {
+                    // Only faces with the origin in front of them, on the
+                    // side away from the fourth vertex, can hold the point.
+                    let n = (v[j].w - v[i].w).cross(&(v[k].w - v[i].w));
+                    let origin = -n.dot(&v[i].w);
+                    let other = n.dot(&(v[l].w - v[i].w));
+                    if origin * other >= 0.0 {
+                        continue;
+                    }
+                    let face = [v[i], v[j], v[k]];
+                    let s = Simplex::keep(&face, &triangle_weights(v[i].w, v[j].w, v[k].w));
+                    let c = s.closest();
+                    let d = c.dot(&c);
+                    if best.is_none_or(|b| d < b.0) {
+                        best = Some((d, s));
+                    }
+                }
+                best.map(|b| b.1)
+            }
+        }
+    }
+}
+
+// Returns the barycentric weights of the point of segment ab closest to the
+// origin.
+fn segment_weights(a: Vector3, b: Vector3) -> [f32; 2] {
+    let ab = b - a;
+    let len2 = ab.dot(&ab);
+    if len2 == 0.0 {
+        return [1.0, 0.0];
+    }
+    let t = (-a.dot(&ab) / len2).clamp(0.0, 1.0);
+    [1.0 - t, t]
+}
+
+// Returns the barycentric weights of the point of triangle abc closest to
+// the origin, by the Voronoi region tests of Ericson's Real-Time Collision
+// Detection, 5.1.5.
+pub(crate) fn triangle_weights(a: Vector3, b: Vector3, c: Vector3) -> [f32; 3] {
+    let (ab, ac) = (b - a, c - a);
+    let (d1, d2) = (-ab.dot(&a), -ac.dot(&a));
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return [1.0, 0.0, 0.0];
+    }
+    let (d3, d4) = (-ab.dot(&b), -ac.dot(&b));
+    if d3 >= 0.0 && d4 <= d3 {
+        return [0.0, 1.0, 0.0];
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let v = d1 / (d1 - d3);
+        return [1.0 - v, v, 0.0];
+    }
+    let (d5, d6) = (-ab.dot(&c), -ac.dot(&c));
+    if d6 >= 0.0 && d5 <= d6 {
+        return [0.0, 0.0, 1.0];
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let w = d2 / (d2 - d6);
+        return [1.0 - w, 0.0, w];
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return [0.0, 1.0 - w, w];
+    }
+    let denom = 1.0 / (va + vb + vc);
+    let (v, w) = (vb * denom, vc * denom);
+    [1.0 - v - w, v, w]
+}
+
+// Runs GJK, returning the final simplex and whether the shapes overlap.
+fn gjk<A: Support + ?Sized, B: Support + ?Sized>(a: &A, b: &B) -> (Simplex, bool) {
+    let mut s = Simplex::default();
+    s.v[0] = support(a, b, &Vector3::new(1.0, 0.0, 0.0));
+    s.weights[0] = 1.0;
+    s.n = 1;
+    for _ in 0..MAX_ITERATIONS {
+        let v = s.closest();
+        let dist2 = v.dot(&v);
+        if dist2 <= TOUCHING {
+            return (s, true);
+        }
+        let w = support(a, b, &-v);
+        // Stop when the new vertex gets no closer to the origin than the
+        // simplex already is, or repeats one of its vertices.
+        if dist2 - v.dot(&w.w) <= TOLERANCE * dist2 || s.v[..s.n].iter().any(|u| u.w == w.w) {
+            return (s, false);
+        }
+        s.v[s.n] = w;
+        s.n += 1;
+        match s.reduce() {
+            Some(r) => s = r,
+            None => return (s, true),
+        }
+    }
+    (s, false)
+}
+
+/// Returns the distance between the convex shapes a and b and the closest
+/// points on each, or None if they overlap. Against curved shapes the
+/// distance converges faster than the points, which may drift along the
+/// surface by about the square root of the tolerance.
+///
+/// # Arguments
+///
+/// * `a` - A reference to the first shape.
+/// * `b` - A reference to the second shape.
+pub fn distance<A: Support + ?Sized, B: Support + ?Sized>(
+    a: &A,
+    b: &B,
+) -> Option<(f32, Point3, Point3)> {
+    let (s, overlap) = gjk(a, b);
+    if overlap {
+        return None;
+    }
+    let (pa, pb) = s.points();
+    Some((s.closest().magnitude(), pa, pb))
+}
+
+/// Returns true if the convex shapes a and b overlap or touch.
+///
+/// # Arguments
+///
+/// * `a` - A reference to the first shape.
+/// * `b` - A reference to the second shape.
+pub fn intersects<A: Support + ?Sized, B: Support + ?Sized>(a: &A, b: &B) -> bool {
+    gjk(a, b).1
+}
+
+/// How deeply two convex shapes overlap, as found by `penetration`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Penetration {
+    /// The unit direction to move the second shape in to separate it from
+    /// the first.
+    pub normal: Vector3,
+    /// The distance to move the second shape along the normal to separate
+    /// the shapes.
+    pub depth: f32,
+    /// The deepest point of the first shape inside the second.
+    pub point_a: Point3,
+    /// The deepest point of the second shape inside the first.
+    pub point_b: Point3,
+}
+
+/// Returns the smallest translation separating the convex shapes a and b,
+/// or None if they do not overlap.
+///
+/// # Arguments
+///
+/// * `a` - A reference to the first shape.
+/// * `b` - A reference to the second shape.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::gjk;
+/// use math_engine::point3::Point3;
+/// use math_engine::sphere::Sphere;
+/// let a = Sphere::new(Point3::new(0.0, 0.0, 0.0), 1.0);
+/// let b = Sphere::new(Point3::new(1.5, 0.0, 0.0), 1.0);
+/// let p = gjk::penetration(&a, &b).unwrap();
+/// assert!((p.depth - 0.5).abs() < 1e-3 && p.normal.x > 0.999);
+/// ```
+pub fn penetration<A: Support + ?Sized, B: Support + ?Sized>(a: &A, b: &B) -> Option<Penetration> {
+    let (s, overlap) = gjk(a, b);
+    if !overlap {
+        return None;
+    }
+    let mut verts: Vec<Vertex> = s.v[..s.n].to_vec();
+    if let Err(normal) = complete_tetrahedron(a, b, &mut verts) {
+        // The difference is flat, so the shapes only touch.
+        let (pa, pb) = s.points();
+        return Some(Penetration {
+            normal,
+            depth: 0.0,
+            point_a: pa,
+            point_b: pb,
+        });
+    }
+    Some(epa(a, b, verts))
+}
+
+// Grows a simplex holding the origin into a tetrahedron holding it, or
+// returns a normal of the difference if it is flat.
+fn complete_tetrahedron<A: Support + ?Sized, B: Support + ?Sized>(
+    a: &A,
+    b: &B,
+    verts: &mut Vec<Vertex>,
+) -> Result<(), Vector3> {
+    let axes = [
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ];
+    if verts.len() == 1 {
+        let found = axes
+            .iter()
+            .flat_map(|d| [*d, -*d])
+            .map(|d| support(a, b, &d))
+            .find(|w| (w.w - verts[0].w).magnitude() > TOLERANCE);
+        match found {
+            Some(w) => verts.push(w),
+            None => return Err(axes[0]),
+        }
+    }
+    if verts.len() == 2 {
+        let line = (verts[1].w - verts[0].w).normalize();
+        let (e, f) = line.any_orthonormal_pair();
+        let found = [e, f, -e, -f]
+            .iter()
+            .map(|d| support(a, b, d))
+            .find(|w| (w.w - verts[0].w).cross(&line).magnitude() > TOLERANCE);
+        match found {
+            Some(w) => verts.push(w),
+            None => return Err(e),
+        }
+    }
+    if verts.len() == 3 {
+        let n = (verts[1].w - verts[0].w)
+            .cross(&(verts[2].w - verts[0].w))
+            .normalize();
+        let found = [n, -n]
+            .iter()
+            .map(|d| support(a, b, d))
+            .find(|w| (w.w - verts[0].w).dot(&n).abs() > TOLERANCE);
+        match found {
+            Some(w) => verts.push(w),
+            None => return Err(n),
+        }
+    }
+    Ok(())
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Face {
+    v: [usize; 3],
+    normal: Vector3,
+    distance: f32,
+}
+
+fn face(verts: &[Vertex], v: [usize; 3]) -> Face {
+    let (a, b, c) = (verts[v[0]].w, verts[v[1]].w, verts[v[2]].w);
+    let normal = (b - a).cross(&(c - a)).normalize_or_zero();
+    Face {
+        v,
+        normal,
+        distance: normal.dot(&a),
+    }
+}
+
+// Expands the tetrahedron verts, which holds the origin, toward the
+// surface of the Minkowski difference until the face nearest the origin is
+// on it.
+fn epa<A: Support + ?Sized, B: Support + ?Sized>(
+    a: &A,
+    b: &B,
+    mut verts: Vec<Vertex>,
+) -> Penetration {
+    let mut faces = Vec::new();
+    for v in [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]] {
+        let mut f = face(&verts, v);
+        // Turn the face outward, away from the fourth vertex.
+        let other = verts[6 - v[0] - v[1] - v[2]].w;
+        if f.normal.dot(&(other - verts[v[0]].w)) > 0.0 {
+            f = face(&verts, [v[0], v[2], v[1]]);
+        }
+        faces.push(f);
+    }
+    let mut nearest = faces[0];
+    for _ in 0..MAX_ITERATIONS {
+        nearest = *faces
+            .iter()
+            .min_by(|f, g| f.distance.total_cmp(&g.distance))
+            .unwrap_or(&nearest);
+        let w = support(a, b, &nearest.normal);
+        if w.w.dot(&nearest.normal) - nearest.distance <= TOLERANCE * nearest.distance.max(1.0) {
+            break;
+        }
+        // Remove the faces the new vertex sees, keeping the edges of the
+        // hole they leave, which appear in only one removed face.
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        faces.retain(|f| {
+            if f.normal.dot(&(w.w - verts[f.v[0]].w)) <= 0.0 {
+                return true;
+            }
+            for (p, q) in [(f.v[0], f.v[1]), (f.v[1], f.v[2]), (f.v[2], f.v[0])] {
+                match edges.iter().position(|&e| e == (q, p)) {
+                    Some(i) => {
+                        edges.swap_remove(i);
+                    }
+                    None => edges.push((p, q)),
+                }
+            }
+            false
+        });
+        verts.push(w);
+        let n = verts.len() - 1;
+        for (p, q) in edges {
+            faces.push(face(&verts, [p, q, n]));
+        }
+        if faces.is_empty() {
+            break;
+        }
+    }
+    // The contact is the origin's projection onto the nearest face, carried
+    // back to the shapes by its barycentric weights.
+    let [i, j, k] = nearest.v;
+    let weights = triangle_weights(
+        verts[i].w - nearest.normal * nearest.distance,
+        verts[j].w - nearest.normal * nearest.distance,
+        verts[k].w - nearest.normal * nearest.distance,
+    );
+    let s = Simplex {
+        v: [verts[i], verts[j], verts[k], Vertex::default()],
+        weights: [weights[0], weights[1], weights[2], 0.0],
+        n: 3,
+    };
+    let (pa, pb) = s.points();
+    Penetration {
+        normal: nearest.normal,
+        depth: nearest.distance.max(0.0),
+        point_a: pa,
+        point_b: pb,
+    }
+}
+
+#[test]
+fn gjk_epa() {
+    // Sphere pairs have closed forms.
+    let a = Sphere::new(Point3::new(0.0, 0.0, 0.0), 1.0);
+    let b = Sphere::new(Point3::new(2.0, 3.0, 6.0), 2.0);
+    let (d, pa, pb) = distance(&a, &b).unwrap();
+    assert!((d - 4.0).abs() < 1e-3);
+    assert!((pa - Point3::new(2.0 / 7.0, 3.0 / 7.0, 6.0 / 7.0)).magnitude() < 1e-2);
+    assert!(
+        (pb - Point3::new(2.0 - 4.0 / 7.0, 3.0 - 6.0 / 7.0, 6.0 - 12.0 / 7.0)).magnitude() < 1e-2
+    );
+    // Boxes, exactly.
+    let unit = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    let far = Aabb3::new(Point3::new(3.0, 4.0, 0.5), Point3::new(5.0, 5.0, 2.0));
+    assert!((distance(&unit, &far).unwrap().0 - 13.0f32.sqrt()).abs() < 1e-5);
+    assert!(intersects(&unit, &Point3::new(0.5, 0.5, 0.5)));
+    assert!(!intersects(&unit, &far));
+    // An overlapping box pair separates along the axis of least overlap.
+    let over = Aabb3::new(Point3::new(0.8, 0.2, -0.5), Point3::new(2.0, 0.9, 0.5));
+    let p = penetration(&unit, &over).unwrap();
+    assert!(
+        (p.depth - 0.2).abs() < 1e-4 && (p.normal - Vector3::new(1.0, 0.0, 0.0)).magnitude() < 1e-4
+    );
+    assert!(penetration(&unit, &far).is_none());
+    // A rotated box against a capsule lying across its top face.
+    let obb = Obb::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 1.0, 1.0),
+        Matrix3::make_rotation_z(core::f32::consts::FRAC_PI_4),
+    );
+    let cap = Capsule::new(Point3::new(-3.0, 0.0, 1.2), Point3::new(3.0, 0.0, 1.2), 0.5);
+    let p = penetration(&obb, &cap).unwrap();
+    assert!(
+        (p.depth - 0.3).abs() < 1e-3 && (p.normal - Vector3::new(0.0, 0.0, 1.0)).magnitude() < 1e-3
+    );
+    let moved = Capsule::new(
+        cap.segment.a + p.normal * (p.depth + 0.01),
+        cap.segment.b + p.normal * (p.depth + 0.01),
+        0.5,
+    );
+    assert!(!intersects(&obb, &moved));
+    // A triangle given as a point slice, just above a box.
+    let tri = [
+        Point3::new(0.0, 0.0, 1.5),
+        Point3::new(1.0, 0.0, 1.5),
+        Point3::new(0.0, 1.0, 1.5),
+    ];
+    assert!((distance(&unit, &tri[..]).unwrap().0 - 0.5).abs() < 1e-5);
+}
@@ -0,0 +1,173 @@
+//! Half precision storage types: `F16`, an IEEE 754 binary16 number, and the
+//! vectors and color built from it. They are meant for packing data, such
+//! as vertex attributes and HDR render targets, at half the size of the f32
+//! types, so they have no arithmetic; convert to the f32 types to compute.
+//!
+//! Converting from f32 rounds to the nearest half, ties to even, with values
+//! beyond the half range becoming infinities. Every half converts to f32
+//! exactly, and converting that f32 back gives the same bits, NaN payloads
+//! included.
+
+macro_rules! half_vector {
+    ($(#[$doc:meta])* $h:ident, $t:ident, $($c:ident),+) => {
+        $(#[$doc])*
+        #[derive(Default, Copy, Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[repr(C)]
+        pub struct $h {
+            $(
+                #[doc = concat!("The ", stringify!($c), " component.")]
+                pub $c: F16,
+            )+
+        }
+
+        impl $h {
+            /// Returns the value with the given components, each rounded to
+            /// the nearest half.
+            pub fn new($($c: f32),+) -> $h {
+                $h { $($c: F16::from_f32($c)),+ }
+            }
+
+            /// Returns a raw pointer to the components, which are laid out
+            /// contiguously as half float bit patterns in declaration order.
+            pub fn as_ptr(&self) -> *const u16 {
+                (self as *const $h).cast::<u16>()
+            }
+        }
+
+        impl From<$t> for $h {
+            fn from(v: $t) -> Self {
+                $h::new($(v.$c),+)
+            }
+        }
+
+        impl From<$h> for $t {
+            fn from(v: $h) -> Self {
+                $t { $($c: v.$c.to_f32()),+ }
+            }
+        }
+    };
+}
+
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An IEEE 754 binary16 floating point number, stored as its bit pattern.
+/// It has 11 significant bits and a largest finite value of 65504. Equality
+/// compares bits, so unlike f32 a NaN equals itself and 0 differs from -0.
+#[repr(transparent)]
+pub struct F16(pub u16);
+
+impl F16 {
+    /// Returns the half nearest to f, with ties rounded to even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::half::F16;
+    /// assert!(F16::from_f32(1.0).0 == 0x3c00);
+    /// assert!(F16::from_f32(65520.0).to_f32() == f32::INFINITY);
+    /// assert!(F16::from_f32(0.1).to_f32() == 0.099975586);
+    /// ```
+    pub fn from_f32(f: f32) -> F16 {
+        let x = f.to_bits();
+        let sign = ((x >> 16) & 0x8000) as u16;
+        let exp = ((x >> 23) & 0xff) as i32;
+        let man = x & 0x007f_ffff;
+        if exp == 0xff {
+            if man == 0 {
+                return F16(sign | 0x7c00);
+            }
+            // Keep the top of the payload, and make sure some of it is set so
+            // that the result is still a NaN.
+            let m = (man >> 13) as u16;
+            return F16(sign | 0x7c00 | if m == 0 { 0x0200 } else { m });
+        }
+        let e = exp - 127 + 15;
+        if e >= 0x1f {
+            return F16(sign | 0x7c00);
+        }
+        let (h, rem, half) = if e > 0 {
+            (((e as u32) << 10) | (man >> 13), man & 0x1fff, 0x1000)
+        } else if e >= -10 {
+            // A subnormal half, from the significand with its implicit one.
+            let m = man | 0x0080_0000;
+            let shift = (14 - e) as u32;
+            (m >> shift, m & ((1 << shift) - 1), 1 << (shift - 1))
+        } else {
+            return F16(sign);
+        };
+        // A carry out of the significand correctly bumps the exponent, up to
+        // infinity.
+        let round = rem > half || (rem == half && h & 1 == 1);
+        F16(sign | (h + round as u32) as u16)
+    }
+
+    /// Returns this half as an f32, which represents it exactly.
+    pub fn to_f32(self) -> f32 {
+        let h = self.0 as u32;
+        let sign = (h & 0x8000) << 16;
+        let exp = (h >> 10) & 0x1f;
+        let man = h & 0x03ff;
+        let bits = if exp == 0x1f {
+            sign | 0x7f80_0000 | (man << 13)
+        } else if exp != 0 {
+            sign | ((exp + 112) << 23) | (man << 13)
+        } else if man == 0 {
+            sign
+        } else {
+            // Normalize the subnormal significand.
+            let shift = man.leading_zeros() - 21;
+            sign | ((113 - shift) << 23) | (((man << shift) & 0x03ff) << 13)
+        };
+        f32::from_bits(bits)
+    }
+}
+
+impl From<f32> for F16 {
+    fn from(f: f32) -> Self {
+        F16::from_f32(f)
+    }
+}
+
+impl From<F16> for f32 {
+    fn from(h: F16) -> Self {
+        h.to_f32()
+    }
+}
+
+half_vector!(
+    /// A two dimensional vector stored in half precision.
+    Vector2h, Vector2, x, y
+);
+half_vector!(
+    /// A three dimensional vector stored in half precision.
+    Vector3h, Vector3, x, y, z
+);
+half_vector!(
+    /// A four dimensional vector stored in half precision.
+    Vector4h, Vector4, x, y, z, w
+);
+half_vector!(
+    /// A color with alpha stored in half precision, the texel of an
+    /// RGBA16F render target.
+    RGBAh, RGBA, r, g, b, a
+);
+
+#[test]
+fn round_trip() {
+    for bits in 0..=u16::MAX {
+        assert!(F16::from_f32(F16(bits).to_f32()) == F16(bits));
+    }
+    // The smallest subnormal, and halfway between it and zero, which ties
+    // to even.
+    assert!(F16(1).to_f32() == 2.0f32.powi(-24));
+    assert!(F16::from_f32(2.0f32.powi(-25)) == F16(0));
+    assert!(F16::from_f32(1.5 * 2.0f32.powi(-24)) == F16(2));
+    assert!(F16::from_f32(65504.0) == F16(0x7bff));
+    assert!(F16::from_f32(-2049.0) == F16::from_f32(-2048.0));
+    assert!(F16::from_f32(f32::NAN).to_f32().is_nan());
+    let v = Vector3::new(0.5, -3.25, 1024.0);
+    assert!(Vector3::from(Vector3h::from(v)) == v);
+}
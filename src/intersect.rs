@@ -0,0 +1,509 @@
+//! One intersection query across the geometric types, `a.intersect(&b)`,
+//! returning a `Hit` that says where and how the two meet rather than only
+//! whether they do.
+//!
+//! Rays and lines can be cast against planes, spheres, boxes, triangles and
+//! frustums; there the hit's t is the parameter along the ray or line.
+//! Spheres and boxes can be tested against each other, and spheres against
+//! planes and triangles; there t is the depth of overlap and the normal the
+//! direction to push the first shape out of the second. In both cases the
+//! normal faces away from the shape passed as the argument.
+//!
+//! # Examples
+//!
+//! ```
+//! use math_engine::intersect::Intersect;
+//! use math_engine::point3::Point3;
+//! use math_engine::ray3::Ray3;
+//! use math_engine::sphere::Sphere;
+//! use math_engine::vector3::Vector3;
+//! let s = Sphere::new(Point3::new(0.0, 0.0, -5.0), 1.0);
+//! let r = Ray3::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+//! let hit = r.intersect(&s).unwrap();
+//! assert!(hit.t == 4.0 && hit.normal == Vector3::new(0.0, 0.0, 1.0));
+//! ```
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::gjk::Support;
+use crate::prelude::*;
+
+/// Where two shapes meet, as found by `Intersect::intersect`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hit {
+    /// For a ray or line, the parameter of the first point in the other
+    /// shape, so that point = origin + direction * t. For two volumes, the
+    /// depth of their overlap.
+    pub t: f32,
+    /// For a ray or line, the point where it enters the other shape. For
+    /// two volumes, a point of contact on the surface of the other shape.
+    pub point: Point3,
+    /// The unit normal of the other shape's surface at the point. It faces
+    /// back along a ray or line, and for two volumes moving the first shape
+    /// by normal * t separates them.
+    pub normal: Vector3,
+}
+
+/// Intersection of this shape with another of type Rhs.
+pub trait Intersect<Rhs = Self> {
+    /// Returns where this shape meets other, or None if they do not.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the other shape.
+    fn intersect(&self, other: &Rhs) -> Option<Hit>;
+
+    /// Returns true if this shape meets other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the other shape.
+    fn intersects(&self, other: &Rhs) -> bool {
+        self.intersect(other).is_some()
+    }
+}
+
+// A shape a ray or line can be cast against. Returns the first parameter
+// t >= t_min at which p + v t lies in the shape, and the surface normal
+// there facing against v.
+trait Cast {
+    fn cast(&self, p: &Point3, v: &Vector3, t_min: f32) -> Option<(f32, Vector3)>;
+}
+
+impl Cast for Plane {
+    fn cast(&self, p: &Point3, v: &Vector3, t_min: f32) -> Option<(f32, Vector3)> {
+        let n = self.get_normal();
+        let nv = n.dot(v);
+        if nv == 0.0 {
+            return None;
+        }
+        let t = -self.point_dot(p) / nv;
+        if t < t_min {
+            return None;
+        }
+        let n = n.normalize();
+        Some((t, if nv > 0.0 { -n } else { n }))
+    }
+}
+
+impl Cast for Sphere {
+    fn cast(&self, p: &Point3, v: &Vector3, t_min: f32) -> Option<(f32, Vector3)> {
+        let m = *p - self.center;
+        let a = v.dot(v);
+        let b = m.dot(v);
+        let c = m.dot(&m) - self.radius * self.radius;
+        let disc = b * b - a * c;
+        if a == 0.0 || disc < 0.0 {
+            return None;
+        }
+        let sq = disc.sqrt();
+        if (-b + sq) / a < t_min {
+            return None;
+        }
+        let t = ((-b - sq) / a).max(t_min);
+        let n = (*p + *v * t - self.center).normalize_or_zero();
+        Some((
+            t,
+            if n == Vector3::new(0.0, 0.0, 0.0) {
+                -v.normalize()
+            } else {
+                n
+            },
+        ))
+    }
+}
+
+// Casts against the box of half extents h centered at the origin, in the
+// box's frame.
+fn cast_box(p: &Vector3, v: &Vector3, h: &Vector3, t_min: f32) -> Option<(f32, Vector3)> {
+    let (mut t0, mut t1) = (t_min, f32::INFINITY);
+    let mut normal = -v.normalize_or_zero();
+    for i in 0..3 {
+        if v[i] == 0.0 {
+            if p[i].abs() > h[i] {
+                return None;
+            }
+            continue;
+        }
+        let inv = 1.0 / v[i];
+        let (near, far, sign) = if inv > 0.0 {
+            ((-h[i] - p[i]) * inv, (h[i] - p[i]) * inv, -1.0)
+        } else {
+            ((h[i] - p[i]) * inv, (-h[i] - p[i]) * inv, 1.0)
+        };
+        if near > t0 {
+            t0 = near;
+            normal = Vector3::new(0.0, 0.0, 0.0);
+            normal[i] = sign;
+        }
+        t1 = t1.min(far);
+        if t0 > t1 {
+            return None;
+        }
+    }
+    Some((t0, normal))
+}
+
+impl Cast for Aabb3 {
+    fn cast(&self, p: &Point3, v: &Vector3, t_min: f32) -> Option<(f32, Vector3)> {
+        cast_box(&(*p - self.center()), v, &self.half_extents(), t_min)
+    }
+}
+
+impl Cast for Obb {
+    fn cast(&self, p: &Point3, v: &Vector3, t_min: f32) -> Option<(f32, Vector3)> {
+        let (r, d) = (&self.rotation, *p - self.center);
+        let local = |u: &Vector3| Vector3::new(r[0].dot(u), r[1].dot(u), r[2].dot(u));
+        let (t, n) = cast_box(&local(&d), &local(v), &self.half_extents, t_min)?;
+        Some((t, *r * n))
+    }
+}
+
+impl Cast for Triangle {
+    fn cast(&self, p: &Point3, v: &Vector3, t_min: f32) -> Option<(f32, Vector3)> {
+        // Möller and Trumbore's test, accepting either face.
+        let (e1, e2) = (self.b - self.a, self.c - self.a);
+        let pv = v.cross(&e2);
+        let det = e1.dot(&pv);
+        if det == 0.0 {
+            return None;
+        }
+        let inv = 1.0 / det;
+        let s = *p - self.a;
+        let u = s.dot(&pv) * inv;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = s.cross(&e1);
+        let w = v.dot(&q) * inv;
+        if w < 0.0 || u + w > 1.0 {
+            return None;
+        }
+        let t = e2.dot(&q) * inv;
+        if t < t_min {
+            return None;
+        }
+        let n = self.normal();
+        Some((t, if n.dot(v) > 0.0 { -n } else { n }))
+    }
+}
+
+impl Cast for Frustum {
+    fn cast(&self, p: &Point3, v: &Vector3, t_min: f32) -> Option<(f32, Vector3)> {
+        // Clip the parameter range against each plane, whose normals point
+        // inward, keeping the plane entered last.
+        let (mut t0, mut t1) = (t_min, f32::INFINITY);
+        let mut normal = -v.normalize_or_zero();
+        for f in &self.planes {
+            let n = f.get_normal();
+            let (dist, speed) = (f.point_dot(p), n.dot(v));
+            if speed == 0.0 {
+                if dist < 0.0 {
+                    return None;
+                }
+                continue;
+            }
+            let t = -dist / speed;
+            if speed > 0.0 {
+                if t > t0 {
+                    t0 = t;
+                    normal = -n.normalize();
+                }
+            } else {
+                t1 = t1.min(t);
+            }
+            if t0 > t1 {
+                return None;
+            }
+        }
+        Some((t0, normal))
+    }
+}
+
+fn cast<S: Cast>(s: &S, p: &Point3, v: &Vector3, t_min: f32) -> Option<Hit> {
+    let (t, normal) = s.cast(p, v, t_min)?;
+    Some(Hit {
+        t,
+        point: *p + *v * t,
+        normal,
+    })
+}
+
+macro_rules! cast_impls {
+    ($($shape:ty),*) => {
+        $(
+            impl Intersect<$shape> for Ray3 {
+                fn intersect(&self, other: &$shape) -> Option<Hit> {
+                    cast(other, &self.origin, &self.direction, 0.0)
+                }
+            }
+
+            // A line has no start, so its parameter is measured from the
+            // point on it closest to the origin and may be negative.
+            impl Intersect<$shape> for Line {
+                fn intersect(&self, other: &$shape) -> Option<Hit> {
+                    cast(other, &self.get_point(), &self.get_direction(), f32::NEG_INFINITY)
+                }
+            }
+        )*
+    };
+}
+
+cast_impls!(Plane, Sphere, Aabb3, Obb, Triangle, Frustum);
+
+// Returns the overlap of a sphere with center c and radius r with the box
+// of half extents h centered at the origin, all in the box's frame.
+fn sphere_box(c: &Vector3, r: f32, h: &Vector3) -> Option<(f32, Vector3, Vector3)> {
+    let q = Vector3::new(
+        c.x.clamp(-h.x, h.x),
+        c.y.clamp(-h.y, h.y),
+        c.z.clamp(-h.z, h.z),
+    );
+    let d = *c - q;
+    let dist2 = d.dot(&d);
+    if dist2 > r * r {
+        return None;
+    }
+    if dist2 > 0.0 {
+        let dist = dist2.sqrt();
+        return Some((r - dist, q, d / dist));
+    }
+    // The center is inside: push out through the nearest face.
+    let i = (0..3)
+        .min_by(|&i, &j| (h[i] - c[i].abs()).total_cmp(&(h[j] - c[j].abs())))
+        .unwrap_or(0);
+    let sign = if c[i] >= 0.0 { 1.0 } else { -1.0 };
+    let (mut n, mut point) = (Vector3::new(0.0, 0.0, 0.0), *c);
+    n[i] = sign;
+    point[i] = h[i] * sign;
+    Some((r + h[i] - c[i].abs(), point, n))
+}
+
+impl Intersect<Sphere> for Sphere {
+    fn intersect(&self, other: &Sphere) -> Option<Hit> {
+        let d = self.center - other.center;
+        let (dist, r) = (d.magnitude(), self.radius + other.radius);
+        if dist > r {
+            return None;
+        }
+        let normal = if dist > 0.0 {
+            d / dist
+        } else {
+            Vector3::new(0.0, 1.0, 0.0)
+        };
+        Some(Hit {
+            t: r - dist,
+            point: other.center + normal * other.radius,
+            normal,
+        })
+    }
+}
+
+impl Intersect<Plane> for Sphere {
+    fn intersect(&self, other: &Plane) -> Option<Hit> {
+        let d = other.distance_to_point(&self.center);
+        if d.abs() > self.radius {
+            return None;
+        }
+        let n = other.get_normal().normalize();
+        let normal = if d < 0.0 { -n } else { n };
+        Some(Hit {
+            t: self.radius - d.abs(),
+            point: self.center - normal * d.abs(),
+            normal,
+        })
+    }
+}
+
+impl Intersect<Triangle> for Sphere {
+    fn intersect(&self, other: &Triangle) -> Option<Hit> {
+        let q = other.closest_point(&self.center);
+        let d = self.center - q;
+        let dist = d.magnitude();
+        if dist > self.radius {
+            return None;
+        }
+        let normal = if dist > 0.0 { d / dist } else { other.normal() };
+        Some(Hit {
+            t: self.radius - dist,
+            point: q,
+            normal,
+        })
+    }
+}
+
+impl Intersect<Aabb3> for Sphere {
+    fn intersect(&self, other: &Aabb3) -> Option<Hit> {
+        let c = other.center();
+        let (t, point, normal) =
+            sphere_box(&(self.center - c), self.radius, &other.half_extents())?;
+        Some(Hit {
+            t,
+            point: c + point,
+            normal,
+        })
+    }
+}
+
+impl Intersect<Obb> for Sphere {
+    fn intersect(&self, other: &Obb) -> Option<Hit> {
+        let (r, d) = (&other.rotation, self.center - other.center);
+        let local = Vector3::new(r[0].dot(&d), r[1].dot(&d), r[2].dot(&d));
+        let (t, point, normal) = sphere_box(&local, self.radius, &other.half_extents)?;
+        Some(Hit {
+            t,
+            point: other.center + *r * point,
+            normal: *r * normal,
+        })
+    }
+}
+
+impl Intersect<Aabb3> for Aabb3 {
+    fn intersect(&self, other: &Aabb3) -> Option<Hit> {
+        let (a_min, a_max): ([f32; 3], [f32; 3]) = (self.min.into(), self.max.into());
+        let (b_min, b_max): ([f32; 3], [f32; 3]) = (other.min.into(), other.max.into());
+        let overlap: [f32; 3] =
+            core::array::from_fn(|i| a_max[i].min(b_max[i]) - a_min[i].max(b_min[i]));
+        if overlap.iter().any(|o| *o < 0.0) {
+            return None;
+        }
+        // Push out along the axis of least overlap, from the middle of the
+        // overlap onto the face of other it crosses.
+        let i = (0..3)
+            .min_by(|&i, &j| overlap[i].total_cmp(&overlap[j]))
+            .unwrap_or(0);
+        let sign = if a_min[i] + a_max[i] >= b_min[i] + b_max[i] {
+            1.0
+        } else {
+            -1.0
+        };
+        let mut point: [f32; 3] =
+            core::array::from_fn(|j| (a_max[j].min(b_max[j]) + a_min[j].max(b_min[j])) * 0.5);
+        point[i] = if sign > 0.0 { b_max[i] } else { b_min[i] };
+        let mut normal = Vector3::new(0.0, 0.0, 0.0);
+        normal[i] = sign;
+        Some(Hit {
+            t: overlap[i],
+            point: Point3::new(point[0], point[1], point[2]),
+            normal,
+        })
+    }
+}
+
+impl Intersect<Obb> for Obb {
+    fn intersect(&self, other: &Obb) -> Option<Hit> {
+        let mtv = crate::sat::obb_obb(other, self)?;
+        let t = mtv.magnitude();
+        let normal = mtv / t;
+        Some(Hit {
+            t,
+            point: self.support(&-normal) + mtv,
+            normal,
+        })
+    }
+}
+
+impl Intersect<Aabb3> for Obb {
+    fn intersect(&self, other: &Aabb3) -> Option<Hit> {
+        self.intersect(&Obb::new(
+            other.center(),
+            other.half_extents(),
+            Matrix3::identity(),
+        ))
+    }
+}
+
+// Implements the reverse of a volume query by swapping the roles of the
+// two shapes: the contact moves to the other surface and the normal flips.
+macro_rules! reverse_impls {
+    ($($a:ty => $b:ty),*) => {
+        $(
+            impl Intersect<$b> for $a {
+                fn intersect(&self, other: &$b) -> Option<Hit> {
+                    let h = other.intersect(self)?;
+                    Some(Hit {
+                        t: h.t,
+                        point: h.point - h.normal * h.t,
+                        normal: -h.normal,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+reverse_impls!(Aabb3 => Sphere, Obb => Sphere, Aabb3 => Obb);
+
+#[test]
+fn intersections() {
+    let ray = Ray3::new(Point3::new(0.0, 0.0, 10.0), Vector3::new(0.0, 0.0, -2.0));
+    let line =
+        Line::from_point_direction(&Point3::new(0.0, 0.0, 10.0), &Vector3::new(0.0, 0.0, -1.0));
+    let up = Vector3::new(0.0, 0.0, 1.0);
+    // Planes are hit from either side.
+    let h = ray.intersect(&Plane::new(0.0, 0.0, -2.0, 4.0)).unwrap();
+    assert!(h.t == 4.0 && h.point == Point3::new(0.0, 0.0, 2.0) && h.normal == up);
+    // The nearest face of a box, and the near side of a sphere, even from
+    // behind along a line.
+    let b = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+    let h = ray.intersect(&b).unwrap();
+    assert!(h.t == 4.5 && h.normal == up);
+    let h = line
+        .intersect(&Sphere::new(Point3::new(0.0, 0.0, 20.0), 2.0))
+        .unwrap();
+    assert!(h.t == -22.0 && h.point == Point3::new(0.0, 0.0, 22.0) && h.normal == up);
+    assert!(!ray.intersects(&Sphere::new(Point3::new(0.0, 0.0, 20.0), 2.0)));
+    // A turned box agrees with its own ray test.
+    let o = Obb::new(
+        Point3::new(0.0, 0.0, -3.0),
+        Vector3::new(1.0, 2.0, 0.5),
+        Matrix3::make_rotation_x(0.4),
+    );
+    let h = ray.intersect(&o).unwrap();
+    let t = o.ray_intersect(&ray.origin, &ray.direction).unwrap();
+    assert!(
+        (h.t - t).abs() < 1e-5 && h.normal.z > 0.0 && (h.normal.magnitude() - 1.0).abs() < 1e-6
+    );
+    // Triangles from the back, and frustums from outside.
+    let tri = Triangle::new(
+        Point3::new(-1.0, -1.0, 0.0),
+        Point3::new(1.0, -1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let back = Ray3::new(Point3::new(0.0, 0.0, -1.0), up);
+    let h = back.intersect(&tri).unwrap();
+    assert!(h.t == 1.0 && h.normal == -up);
+    let f = Frustum::new([
+        Plane::new(1.0, 0.0, 0.0, 1.0),
+        Plane::new(-1.0, 0.0, 0.0, 1.0),
+        Plane::new(0.0, 1.0, 0.0, 1.0),
+        Plane::new(0.0, -1.0, 0.0, 1.0),
+        Plane::new(0.0, 0.0, 1.0, 1.0),
+        Plane::new(0.0, 0.0, -1.0, 1.0),
+    ]);
+    assert!(ray.intersect(&f).unwrap() == ray.intersect(&b).unwrap());
+    // Volumes: pushing the first shape along the normal by t separates it.
+    let s = Sphere::new(Point3::new(1.5, 0.0, 0.0), 1.0);
+    let h = s.intersect(&b).unwrap();
+    assert!(h.t == 0.5 && h.normal == Vector3::new(1.0, 0.0, 0.0));
+    assert!(h.point == Point3::new(1.0, 0.0, 0.0));
+    let r = b.intersect(&s).unwrap();
+    assert!(r.t == 0.5 && r.normal == -h.normal && r.point == Point3::new(0.5, 0.0, 0.0));
+    let h = s
+        .intersect(&Sphere::new(Point3::new(0.0, 0.0, 0.0), 1.0))
+        .unwrap();
+    assert!(h.t == 0.5 && h.point == Point3::new(1.0, 0.0, 0.0));
+    let h = s.intersect(&Plane::new(-1.0, 0.0, 0.0, 1.0)).unwrap();
+    assert!(h.t == 0.5 && h.normal == Vector3::new(1.0, 0.0, 0.0));
+    assert!(s.intersects(&tri) && !Sphere::new(Point3::new(0.0, 0.0, 2.0), 1.0).intersects(&tri));
+    let c = Aabb3::new(Point3::new(0.5, 0.8, -2.0), Point3::new(3.0, 3.0, 2.0));
+    let h = c.intersect(&b).unwrap();
+    assert!((h.t - 0.2).abs() < 1e-6 && h.normal == Vector3::new(0.0, 1.0, 0.0));
+    let oc = Obb::new(c.center(), c.half_extents(), Matrix3::identity());
+    let ob = Obb::new(b.center(), b.half_extents(), Matrix3::identity());
+    let h2 = oc.intersect(&ob).unwrap();
+    assert!((h2.t - h.t).abs() < 1e-6 && h2.normal == h.normal);
+    assert!(ob.intersects(&Sphere::new(Point3::new(1.5, 0.0, 0.0), 1.0)));
+}
@@ -0,0 +1,459 @@
+//! Interval arithmetic: each value is a range [lo, hi] known to contain the
+//! true value, and every operation returns a range containing every result
+//! its operands could produce. Evaluating an expression over intervals gives
+//! conservative bounds without enumerating cases, such as the region a
+//! moving object can reach during a frame, for continuous collision
+//! detection and culling.
+//!
+//! The bounds are computed with ordinary f32 rounding rather than outward
+//! rounding, so they can be off by a rounding error; pad them when exact
+//! containment matters.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A closed range of real numbers, [lo, hi].
+#[repr(C)]
+pub struct Interval {
+    /// The lower bound.
+    pub lo: f32,
+    /// The upper bound.
+    pub hi: f32,
+}
+
+impl Interval {
+    /// The interval containing every number.
+    pub const ENTIRE: Interval = Interval::new(f32::NEG_INFINITY, f32::INFINITY);
+
+    /// Returns the interval [lo, hi]. lo should not exceed hi.
+    pub const fn new(lo: f32, hi: f32) -> Interval {
+        Interval { lo, hi }
+    }
+
+    /// Returns the interval containing just x.
+    pub const fn point(x: f32) -> Interval {
+        Interval { lo: x, hi: x }
+    }
+
+    /// Returns the length of this interval.
+    pub fn width(&self) -> f32 {
+        self.hi - self.lo
+    }
+
+    /// Returns the middle of this interval.
+    pub fn midpoint(&self) -> f32 {
+        (self.lo + self.hi) * 0.5
+    }
+
+    /// Returns true if x lies in this interval.
+    pub fn contains(&self, x: f32) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Returns true if this interval and other share a number.
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.lo <= other.hi && other.lo <= self.hi
+    }
+
+    /// Returns the smallest interval containing this interval and other.
+    pub fn hull(&self, other: &Interval) -> Interval {
+        Interval::new(self.lo.min(other.lo), self.hi.max(other.hi))
+    }
+
+    /// Returns the numbers in both this interval and other, or None if
+    /// they are disjoint.
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let i = Interval::new(self.lo.max(other.lo), self.hi.min(other.hi));
+        if i.lo <= i.hi {
+            Some(i)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the absolute values of the numbers in this interval.
+    pub fn abs(&self) -> Interval {
+        if self.lo >= 0.0 {
+            *self
+        } else if self.hi <= 0.0 {
+            -*self
+        } else {
+            Interval::new(0.0, self.hi.max(-self.lo))
+        }
+    }
+
+    /// Returns the squares of the numbers in this interval. Unlike self *
+    /// self, it knows both factors are the same number, so it never goes
+    /// below zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::interval::Interval;
+    /// let i = Interval::new(-1.0, 2.0);
+    /// assert!(i.sqr() == Interval::new(0.0, 4.0));
+    /// assert!(i * i == Interval::new(-2.0, 4.0));
+    /// ```
+    pub fn sqr(&self) -> Interval {
+        let a = self.abs();
+        Interval::new(a.lo * a.lo, a.hi * a.hi)
+    }
+
+    /// Returns the square roots of the non-negative numbers in this
+    /// interval.
+    pub fn sqrt(&self) -> Interval {
+        Interval::new(self.lo.max(0.0).sqrt(), self.hi.max(0.0).sqrt())
+    }
+}
+
+impl From<f32> for Interval {
+    fn from(x: f32) -> Self {
+        Interval::point(x)
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+
+    fn add(self, other: Interval) -> Self::Output {
+        Interval::new(self.lo + other.lo, self.hi + other.hi)
+    }
+}
+
+impl AddAssign for Interval {
+    fn add_assign(&mut self, other: Interval) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+
+    fn sub(self, other: Interval) -> Self::Output {
+        Interval::new(self.lo - other.hi, self.hi - other.lo)
+    }
+}
+
+impl SubAssign for Interval {
+    fn sub_assign(&mut self, other: Interval) {
+        *self = *self - other;
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+
+    fn mul(self, other: Interval) -> Self::Output {
+        let p = [
+            self.lo * other.lo,
+            self.lo * other.hi,
+            self.hi * other.lo,
+            self.hi * other.hi,
+        ];
+        Interval::new(
+            p[0].min(p[1]).min(p[2]).min(p[3]),
+            p[0].max(p[1]).max(p[2]).max(p[3]),
+        )
+    }
+}
+
+impl Div for Interval {
+    type Output = Self;
+
+    /// Divides by every number in other. If other contains zero the
+    /// quotient is unbounded, and the result is `Interval::ENTIRE`.
+    fn div(self, other: Interval) -> Self::Output {
+        if other.contains(0.0) {
+            Interval::ENTIRE
+        } else {
+            self * Interval::new(1.0 / other.hi, 1.0 / other.lo)
+        }
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Interval::new(-self.hi, -self.lo)
+    }
+}
+
+impl Add<f32> for Interval {
+    type Output = Self;
+
+    fn add(self, other: f32) -> Self::Output {
+        Interval::new(self.lo + other, self.hi + other)
+    }
+}
+
+impl Mul<f32> for Interval {
+    type Output = Self;
+
+    fn mul(self, other: f32) -> Self::Output {
+        if other < 0.0 {
+            Interval::new(self.hi * other, self.lo * other)
+        } else {
+            Interval::new(self.lo * other, self.hi * other)
+        }
+    }
+}
+
+impl Mul<Interval> for f32 {
+    type Output = Interval;
+
+    fn mul(self, other: Interval) -> Self::Output {
+        other * self
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A three dimensional vector whose components are intervals, such as a
+/// velocity known only within bounds.
+#[repr(C)]
+pub struct IntervalVector3 {
+    /// The x component.
+    pub x: Interval,
+    /// The y component.
+    pub y: Interval,
+    /// The z component.
+    pub z: Interval,
+}
+
+impl IntervalVector3 {
+    /// Returns the vector with the given components.
+    pub const fn new(x: Interval, y: Interval, z: Interval) -> IntervalVector3 {
+        IntervalVector3 { x, y, z }
+    }
+
+    /// Returns the bounds of the dot product of this vector and other.
+    pub fn dot(&self, other: &IntervalVector3) -> Interval {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the bounds of the squared length of this vector.
+    pub fn magnitude_squared(&self) -> Interval {
+        self.x.sqr() + self.y.sqr() + self.z.sqr()
+    }
+
+    /// Returns the bounds of the length of this vector.
+    pub fn magnitude(&self) -> Interval {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Returns the bounds of the cross product of this vector and other.
+    pub fn cross(&self, other: &IntervalVector3) -> IntervalVector3 {
+        IntervalVector3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+impl From<Vector3> for IntervalVector3 {
+    fn from(v: Vector3) -> Self {
+        IntervalVector3::new(v.x.into(), v.y.into(), v.z.into())
+    }
+}
+
+impl Add for IntervalVector3 {
+    type Output = Self;
+
+    fn add(self, other: IntervalVector3) -> Self::Output {
+        IntervalVector3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for IntervalVector3 {
+    type Output = Self;
+
+    fn sub(self, other: IntervalVector3) -> Self::Output {
+        IntervalVector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<Interval> for IntervalVector3 {
+    type Output = Self;
+
+    fn mul(self, other: Interval) -> Self::Output {
+        IntervalVector3::new(self.x * other, self.y * other, self.z * other)
+    }
+}
+
+impl Mul<f32> for IntervalVector3 {
+    type Output = Self;
+
+    fn mul(self, other: f32) -> Self::Output {
+        IntervalVector3::new(self.x * other, self.y * other, self.z * other)
+    }
+}
+
+impl Neg for IntervalVector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        IntervalVector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<IntervalVector3> for Transform4 {
+    type Output = IntervalVector3;
+
+    /// Transforms the vector by the linear part of this transform, without
+    /// the translation, like `Transform4 * Vector3`.
+    fn mul(self, rhs: IntervalVector3) -> Self::Output {
+        let row = |i: usize| rhs.x * self[0][i] + rhs.y * self[1][i] + rhs.z * self[2][i];
+        IntervalVector3::new(row(0), row(1), row(2))
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An axis-aligned box stored as one interval per axis, the set of points
+/// an object can occupy. Unlike `Aabb3` it composes with interval
+/// arithmetic, so transforms and motion with uncertain parameters map it to
+/// a conservative box directly.
+#[repr(C)]
+pub struct IntervalAabb {
+    /// The extent along x.
+    pub x: Interval,
+    /// The extent along y.
+    pub y: Interval,
+    /// The extent along z.
+    pub z: Interval,
+}
+
+impl IntervalAabb {
+    /// Returns the box with the given extents.
+    pub const fn new(x: Interval, y: Interval, z: Interval) -> IntervalAabb {
+        IntervalAabb { x, y, z }
+    }
+
+    /// Returns the box containing just the point p.
+    pub fn from_point(p: &Point3) -> IntervalAabb {
+        IntervalAabb::new(p.x.into(), p.y.into(), p.z.into())
+    }
+
+    /// Returns true if the point p lies inside or on this box.
+    pub fn contains_point(&self, p: &Point3) -> bool {
+        self.x.contains(p.x) && self.y.contains(p.y) && self.z.contains(p.z)
+    }
+
+    /// Returns true if this box and other overlap or touch.
+    pub fn intersects(&self, other: &IntervalAabb) -> bool {
+        self.x.overlaps(&other.x) && self.y.overlaps(&other.y) && self.z.overlaps(&other.z)
+    }
+
+    /// Returns the smallest box containing this box and other.
+    pub fn union(&self, other: &IntervalAabb) -> IntervalAabb {
+        IntervalAabb::new(
+            self.x.hull(&other.x),
+            self.y.hull(&other.y),
+            self.z.hull(&other.z),
+        )
+    }
+
+    /// Returns the tightest box containing this box transformed by t, which
+    /// for an affine transform is the box around the transformed corners.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The transform to apply.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::prelude::*;
+    /// let b = IntervalAabb::new(Interval::new(-1.0, 1.0), Interval::new(0.0, 2.0), Interval::point(0.0));
+    /// let t = Transform4::make_translation(&Vector3::new(10.0, 0.0, 0.0));
+    /// assert!(b.transform(&t).x == Interval::new(9.0, 11.0));
+    /// ```
+    pub fn transform(&self, t: &Transform4) -> IntervalAabb {
+        let row = |i: usize| self.x * t[0][i] + self.y * t[1][i] + self.z * t[2][i] + t[3][i];
+        IntervalAabb::new(row(0), row(1), row(2))
+    }
+
+    /// Returns the box swept by this box moving with the given velocity for
+    /// every time in the interval time, the region to test for continuous
+    /// collision over a step.
+    ///
+    /// # Arguments
+    ///
+    /// * `velocity` - The bounds of the velocity.
+    /// * `time` - The time span, usually [0, dt].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::prelude::*;
+    /// let b = IntervalAabb::from_point(&Point3::new(0.0, 0.0, 0.0));
+    /// let v = IntervalVector3::from(Vector3::new(2.0, -1.0, 0.0));
+    /// let s = b.sweep(&v, Interval::new(0.0, 0.5));
+    /// assert!(s.x == Interval::new(0.0, 1.0) && s.y == Interval::new(-0.5, 0.0));
+    /// ```
+    pub fn sweep(&self, velocity: &IntervalVector3, time: Interval) -> IntervalAabb {
+        *self + *velocity * time
+    }
+}
+
+impl From<Aabb3> for IntervalAabb {
+    fn from(b: Aabb3) -> Self {
+        IntervalAabb::new(
+            Interval::new(b.min.x, b.max.x),
+            Interval::new(b.min.y, b.max.y),
+            Interval::new(b.min.z, b.max.z),
+        )
+    }
+}
+
+impl From<IntervalAabb> for Aabb3 {
+    fn from(b: IntervalAabb) -> Self {
+        Aabb3::new(
+            Point3::new(b.x.lo, b.y.lo, b.z.lo),
+            Point3::new(b.x.hi, b.y.hi, b.z.hi),
+        )
+    }
+}
+
+impl Add<IntervalVector3> for IntervalAabb {
+    type Output = Self;
+
+    /// Offsets the box by every vector in rhs, the Minkowski sum.
+    fn add(self, rhs: IntervalVector3) -> Self::Output {
+        IntervalAabb::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+#[test]
+fn bounds() {
+    let a = Interval::new(-2.0, 3.0);
+    let b = Interval::new(1.0, 4.0);
+    assert!(a + b == Interval::new(-1.0, 7.0));
+    assert!(a - b == Interval::new(-6.0, 2.0));
+    assert!(a * b == Interval::new(-8.0, 12.0));
+    assert!(b / Interval::new(2.0, 4.0) == Interval::new(0.25, 2.0));
+    assert!(b / a == Interval::ENTIRE);
+    assert!(a * -2.0 == Interval::new(-6.0, 4.0));
+    assert!(a.intersection(&Interval::new(5.0, 6.0)).is_none());
+
+    // A rotated box is bounded by its rotated corners.
+    let cube = IntervalAabb::from(Aabb3::new(
+        Point3::new(-1.0, -1.0, -1.0),
+        Point3::new(1.0, 1.0, 1.0),
+    ));
+    let r = Transform4::make_rotation_z(core::f32::consts::FRAC_PI_4);
+    let t = cube.transform(&r);
+    let s = core::f32::consts::SQRT_2;
+    assert!((t.x.hi - s).abs() < 1e-6 && (t.y.lo + s).abs() < 1e-6 && t.z == cube.z);
+    for corner in [(-1.0, -1.0, 1.0), (1.0, -1.0, -1.0), (1.0, 1.0, 1.0)] {
+        let p = r * Point3::from(corner);
+        assert!(t.contains_point(&p));
+    }
+}
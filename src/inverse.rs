@@ -0,0 +1,85 @@
+//! `try_inverse` and `try_inverse_epsilon` for the matrices and transforms
+//! that invert through their determinant.
+//!
+//! Comparing the determinant with zero does not find singular matrices: the
+//! determinant of a rank deficient matrix rounds to a small value of either
+//! sign rather than to zero, and a matrix with small entries has a small
+//! determinant whether it is singular or not. Instead the determinant is
+//! compared with the product of the lengths of the columns, the largest it
+//! can be for columns of those lengths, so the test depends on how far the
+//! columns are from being linearly dependent and not on their scale.
+
+use crate::prelude::*;
+
+macro_rules! try_inverse {
+    ($t:ident, $s:ident, $n:literal) => {
+        impl $t {
+            /// Returns the inverse, or None if it is singular to within
+            /// rounding error, that is if the absolute value of the
+            /// determinant is not greater than eight times the machine
+            /// epsilon times the product of the lengths of the columns (of
+            /// the linear part, for a transform). Unlike `inverse`, this
+            /// never returns infinities or NaNs.
+            pub fn try_inverse(&self) -> Option<$t> {
+                self.try_inverse_epsilon(0.0)
+            }
+
+            /// Returns the inverse, or None if it is singular to within
+            /// rounding error, as for `try_inverse`, or if the absolute value
+            /// of the determinant is not greater than epsilon.
+            ///
+            /// # Arguments
+            ///
+            /// * `epsilon` - The largest determinant treated as singular.
+            pub fn try_inverse_epsilon(&self, epsilon: $s) -> Option<$t> {
+                let det = self.determinant();
+                let scale: $s = (0..$n).map(|i| self[i].magnitude()).product();
+                let tolerance = epsilon.max(8.0 * $s::EPSILON * scale);
+                if det.abs() > tolerance && (1.0 / det).is_finite() {
+                    Some(self.inverse())
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+try_inverse!(Matrix2, f32, 2);
+try_inverse!(Matrix3, f32, 3);
+try_inverse!(Transform2, f32, 2);
+try_inverse!(Transform4, f32, 3);
+try_inverse!(DMatrix2, f64, 2);
+try_inverse!(DMatrix3, f64, 3);
+try_inverse!(DMatrix4, f64, 4);
+try_inverse!(DTransform4, f64, 3);
+
+#[test]
+fn singular() {
+    // Rank deficient matrices whose determinants round to small nonzero
+    // values, and a nonsingular matrix with tiny entries.
+    let m = Matrix3::new(0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9);
+    assert!(m.determinant() != 0.0 && m.try_inverse().is_none());
+    let m = DMatrix3::new(0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9);
+    assert!(m.determinant() != 0.0 && m.try_inverse().is_none());
+    let h = Transform4::new(0.1, 0.2, 0.3, 1.0, 0.4, 0.5, 0.6, 2.0, 0.7, 0.8, 0.9, 3.0);
+    assert!(h.try_inverse().is_none());
+    let h = DTransform4::new(0.1, 0.2, 0.3, 1.0, 0.4, 0.5, 0.6, 2.0, 0.7, 0.8, 0.9, 3.0);
+    assert!(h.try_inverse().is_none());
+    let ramp = DMatrix4::new(
+        0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2, 1.3, 1.4, 1.5, 1.6,
+    );
+    assert!(ramp.try_inverse().is_none());
+    assert!(Matrix2::new(0.1, 0.3, 0.2, 0.6).try_inverse().is_none());
+    assert!(DMatrix2::new(0.1, 0.3, 0.2, 0.6).try_inverse().is_none());
+    assert!(Transform2::new(0.1, 0.3, 5.0, 0.2, 0.6, 1.0)
+        .try_inverse()
+        .is_none());
+
+    assert!(Matrix3::make_scale(2.0, 0.0, 1.0).try_inverse().is_none());
+    let m = Matrix3::make_scale(2.0, 4.0, 1.0).try_inverse().unwrap();
+    assert!(m == Matrix3::make_scale(0.5, 0.25, 1.0));
+    let small = Matrix3::make_scale(1e-4, 1e-4, 1e-4);
+    assert!(small.try_inverse().unwrap() == Matrix3::make_scale(1e4, 1e4, 1e4));
+    assert!(small.try_inverse_epsilon(1e-6).is_none());
+}
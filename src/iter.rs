@@ -0,0 +1,97 @@
+//! Component iteration for the vector and color types and column and row
+//! iteration for the matrices.
+//!
+//! Vectors and colors iterate over their components by value in memory order,
+//! x, y, z, w or r, g, b, a, and can be collected from any iterator of
+//! scalars, so `v.iter().collect::<Vec<f32>>()` flattens a vector for upload
+//! and `v.iter().map(f).collect::<Vector3>()` maps one component-wise.
+
+use crate::prelude::*;
+use core::iter::FromIterator;
+
+macro_rules! components {
+    ($t:ident, $s:ty, $n:expr, $($c:ident),+) => {
+        impl $t {
+            /// Returns an iterator over the components by value, in memory
+            /// order.
+            pub fn iter(&self) -> core::array::IntoIter<$s, $n> {
+                [$(self.$c),+].into_iter()
+            }
+        }
+
+        impl IntoIterator for $t {
+            type Item = $s;
+            type IntoIter = core::array::IntoIter<$s, $n>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        /// Takes the components in memory order from the first items of the
+        /// iterator and ignores the rest. Panics if it has too few items.
+        impl FromIterator<$s> for $t {
+            fn from_iter<I: IntoIterator<Item = $s>>(iter: I) -> Self {
+                let mut iter = iter.into_iter();
+                $t {
+                    $($c: iter.next().expect(concat!(
+                        "too few items to collect into a ",
+                        stringify!($t)
+                    )),)+
+                }
+            }
+        }
+    };
+}
+
+macro_rules! columns {
+    ($t:ident, $v:ident, $n:expr) => {
+        impl $t {
+            /// Returns an iterator over the columns by value, from left to
+            /// right.
+            pub fn columns(&self) -> core::array::IntoIter<$v, $n> {
+                core::array::from_fn::<_, $n, _>(|i| self[i]).into_iter()
+            }
+
+            /// Returns an iterator over the rows by value, from top to
+            /// bottom.
+            pub fn rows(&self) -> core::array::IntoIter<$v, $n> {
+                self.transpose().columns()
+            }
+        }
+    };
+}
+
+components!(Vector2, f32, 2, x, y);
+components!(Vector3, f32, 3, x, y, z);
+components!(Vector4, f32, 4, x, y, z, w);
+components!(DVector2, f64, 2, x, y);
+components!(DVector3, f64, 3, x, y, z);
+components!(DVector4, f64, 4, x, y, z, w);
+components!(RGB, f32, 3, r, g, b);
+components!(RGBA, f32, 4, r, g, b, a);
+columns!(Matrix2, Vector2, 2);
+columns!(Matrix3, Vector3, 3);
+columns!(Matrix4, Vector4, 4);
+columns!(DMatrix2, DVector2, 2);
+columns!(DMatrix3, DVector3, 3);
+columns!(DMatrix4, DVector4, 4);
+
+#[test]
+fn collect() {
+    use alloc::vec::Vec;
+
+    let v = Vector3::new(1.0, -2.0, 3.0);
+    let flat: Vec<f32> = v.iter().collect();
+    assert!(flat == [1.0, -2.0, 3.0]);
+    assert!(v.iter().map(|c| c * 2.0).collect::<Vector3>() == v * 2.0);
+    assert!(v.into_iter().sum::<f32>() == 2.0);
+    let c: RGBA = [0.5f32; 6].into_iter().collect();
+    assert!(c == RGBA::new(0.5, 0.5, 0.5, 0.5));
+
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    assert!(m.columns().nth(1) == Some(Vector3::new(2.0, 5.0, 8.0)));
+    assert!(m.rows().nth(1) == Some(Vector3::new(4.0, 5.0, 6.0)));
+    let upload: Vec<f32> = m.columns().flatten().collect();
+    assert!(upload[..4] == [1.0, 4.0, 7.0, 2.0]);
+}
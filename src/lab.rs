@@ -0,0 +1,249 @@
+//! The CIE L*a*b* color space, in which equal distances are meant to look
+//! like equal differences. L is lightness from 0 to 100, a runs from green
+//! to red and b from blue to yellow. `Lab::delta_e` measures how different
+//! two colors look, for matching against a palette or comparing images.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A color in CIE L*a*b*.
+#[repr(C)]
+pub struct Lab {
+    /// The lightness, from 0 for black to 100 for the reference white.
+    pub l: f32,
+    /// The green (negative) to red (positive) axis.
+    pub a: f32,
+    /// The blue (negative) to yellow (positive) axis.
+    pub b: f32,
+}
+
+const DELTA: f32 = 6.0 / 29.0;
+
+// The Lab companding function, a cube root with a linear segment near zero.
+fn f(t: f32) -> f32 {
+    if t > DELTA * DELTA * DELTA {
+        t.cbrt()
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+fn f_inverse(t: f32) -> f32 {
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+impl Lab {
+    /// Returns a color with the given components.
+    pub const fn new(l: f32, a: f32, b: f32) -> Lab {
+        Lab { l, a, b }
+    }
+
+    /// Returns the Lab of an XYZ color relative to the given white point.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - A reference to the color.
+    /// * `white` - A reference to the reference white, which maps to L = 100.
+    pub fn from_xyz(c: &Xyz, white: &Xyz) -> Lab {
+        let fx = f(c.x / white.x);
+        let fy = f(c.y / white.y);
+        let fz = f(c.z / white.z);
+        Lab::new(116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+    }
+
+    /// Returns the XYZ of this color relative to the given white point, the
+    /// inverse of `Lab::from_xyz`.
+    ///
+    /// # Arguments
+    ///
+    /// * `white` - A reference to the reference white.
+    pub fn to_xyz(&self, white: &Xyz) -> Xyz {
+        let fy = (self.l + 16.0) / 116.0;
+        Xyz::new(
+            white.x * f_inverse(fy + self.a / 500.0),
+            white.y * f_inverse(fy),
+            white.z * f_inverse(fy - self.b / 200.0),
+        )
+    }
+
+    /// Returns the Lab of a linear RGB color with the sRGB primaries,
+    /// relative to the given white point.
+    ///
+    /// # Arguments
+    ///
+    /// * `rgb` - A reference to the linear color.
+    /// * `white` - A reference to the reference white, usually `Xyz::D65`,
+    ///   or `Xyz::D50` to match ICC and print workflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::lab::Lab;
+    /// use math_engine::rgb::RGB;
+    /// use math_engine::xyz::Xyz;
+    /// let white = Lab::from_rgb(&RGB::new(1.0, 1.0, 1.0), &Xyz::D50);
+    /// assert!((white.l - 100.0).abs() < 1e-3 && white.a.abs() < 1e-2 && white.b.abs() < 1e-2);
+    /// ```
+    pub fn from_rgb(rgb: &RGB, white: &Xyz) -> Lab {
+        Lab::from_xyz(&Xyz::from_rgb(rgb, white), white)
+    }
+
+    /// Returns the linear RGB color with the sRGB primaries of this color,
+    /// which is relative to white, the inverse of `Lab::from_rgb`.
+    ///
+    /// # Arguments
+    ///
+    /// * `white` - A reference to the reference white.
+    pub fn to_rgb(&self, white: &Xyz) -> RGB {
+        self.to_xyz(white).to_rgb(white)
+    }
+
+    /// Returns the CIE76 color difference, the Euclidean distance between
+    /// the two colors in Lab. It is cheap but overstates differences between
+    /// saturated colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the color to compare with.
+    pub fn delta_e76(&self, other: &Lab) -> f32 {
+        let (dl, da, db) = (self.l - other.l, self.a - other.a, self.b - other.b);
+        (dl * dl + da * da + db * db).sqrt()
+    }
+
+    /// Returns the CIEDE2000 color difference, which corrects Lab's
+    /// perceptual nonuniformities in lightness, chroma and hue. A difference
+    /// of about 1 is the smallest most observers notice.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the color to compare with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::lab::Lab;
+    /// // A pair from Sharma, Wu and Dalal's reference data.
+    /// let d = Lab::new(50.0, 2.6772, -79.7751).delta_e(&Lab::new(50.0, 0.0, -82.7485));
+    /// assert!((d - 2.0425).abs() < 1e-3);
+    /// ```
+    pub fn delta_e(&self, other: &Lab) -> f32 {
+        let pow7 = |x: f32| {
+            let x3 = x * x * x;
+            x3 * x3 * x
+        };
+        let pow7_25 = 6_103_515_625.0;
+        let c1 = (self.a * self.a + self.b * self.b).sqrt();
+        let c2 = (other.a * other.a + other.b * other.b).sqrt();
+        let c_mean7 = pow7((c1 + c2) * 0.5);
+        let g = 0.5 * (1.0 - (c_mean7 / (c_mean7 + pow7_25)).sqrt());
+        let (a1, a2) = ((1.0 + g) * self.a, (1.0 + g) * other.a);
+        let c1 = (a1 * a1 + self.b * self.b).sqrt();
+        let c2 = (a2 * a2 + other.b * other.b).sqrt();
+        let hue = |b: f32, a: f32| {
+            if a == 0.0 && b == 0.0 {
+                0.0
+            } else {
+                let h = b.atan2(a).to_degrees();
+                if h < 0.0 {
+                    h + 360.0
+                } else {
+                    h
+                }
+            }
+        };
+        let (h1, h2) = (hue(self.b, a1), hue(other.b, a2));
+
+        let dl = other.l - self.l;
+        let dc = c2 - c1;
+        let dh = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 > h1 {
+            h2 - h1 - 360.0
+        } else {
+            h2 - h1 + 360.0
+        };
+        let dh = 2.0 * (c1 * c2).sqrt() * (dh * 0.5).to_radians().sin();
+
+        let l_mean = (self.l + other.l) * 0.5;
+        let c_mean = (c1 + c2) * 0.5;
+        let h_mean = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) * 0.5
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) * 0.5
+        } else {
+            (h1 + h2 - 360.0) * 0.5
+        };
+        let cos = |deg: f32| deg.to_radians().cos();
+        let t = 1.0 - 0.17 * cos(h_mean - 30.0)
+            + 0.24 * cos(2.0 * h_mean)
+            + 0.32 * cos(3.0 * h_mean + 6.0)
+            - 0.20 * cos(4.0 * h_mean - 63.0);
+        let d_theta = 30.0 * (-((h_mean - 275.0) / 25.0) * ((h_mean - 275.0) / 25.0)).exp();
+        let c_mean7 = pow7(c_mean);
+        let rc = 2.0 * (c_mean7 / (c_mean7 + pow7_25)).sqrt();
+        let l50 = (l_mean - 50.0) * (l_mean - 50.0);
+        let sl = 1.0 + 0.015 * l50 / (20.0 + l50).sqrt();
+        let sc = 1.0 + 0.045 * c_mean;
+        let sh = 1.0 + 0.015 * c_mean * t;
+        let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+        let (l, c, h) = (dl / sl, dc / sc, dh / sh);
+        (l * l + c * c + h * h + rt * c * h).sqrt()
+    }
+}
+
+impl From<Xyz> for Lab {
+    /// Converts XYZ to Lab relative to D65.
+    fn from(c: Xyz) -> Self {
+        Lab::from_xyz(&c, &Xyz::D65)
+    }
+}
+
+impl From<Lab> for Xyz {
+    /// Converts Lab relative to D65 to XYZ.
+    fn from(c: Lab) -> Self {
+        c.to_xyz(&Xyz::D65)
+    }
+}
+
+#[test]
+fn ciede2000() {
+    // Pairs from Sharma, Wu and Dalal, "The CIEDE2000 color-difference
+    // formula: implementation notes, supplementary test data, and
+    // mathematical observations", covering the hue wraparound cases.
+    let pairs = [
+        ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+        ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+        ((50.0, 2.49, -0.001), (50.0, -2.49, 0.0011), 7.2195),
+        ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+        ((50.0, 2.5, 0.0), (50.0, 3.2592, 0.335), 1.0),
+        (
+            (60.2574, -34.0099, 36.2677),
+            (60.4626, -34.1751, 39.4387),
+            1.2644,
+        ),
+        ((2.0776, 0.0795, -1.135), (0.9033, -0.0636, -0.5514), 0.9082),
+    ];
+    for (a, b, expected) in pairs {
+        let (a, b) = (Lab::new(a.0, a.1, a.2), Lab::new(b.0, b.1, b.2));
+        assert!((a.delta_e(&b) - expected).abs() < 1e-3);
+        assert!((b.delta_e(&a) - expected).abs() < 1e-3);
+    }
+
+    let c = RGB::new(0.2, 0.5, 0.9);
+    let back = Lab::from_rgb(&c, &Xyz::D50).to_rgb(&Xyz::D50);
+    assert!(
+        (back.r - c.r).abs() < 1e-4 && (back.g - c.g).abs() < 1e-4 && (back.b - c.b).abs() < 1e-4
+    );
+}
@@ -1,35 +1,381 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod aabb2;
+pub mod aabb3;
+pub mod angle;
+#[cfg(feature = "approx")]
+mod approx_eq;
+pub mod bezier;
+pub mod blend;
+pub mod bvec;
+pub mod bvh;
+pub mod camera;
+pub mod capsule;
+pub mod circle;
+pub mod clip;
+mod color_matrix;
+mod componentwise;
+mod convert;
+pub mod convex_hull;
+pub mod convex_volume;
+pub mod decomposition;
+mod display;
+pub mod dmatrix2;
+pub mod dmatrix3;
+pub mod dmatrix4;
+pub mod dpoint2;
+pub mod dpoint3;
+pub mod dquarternion;
+pub mod dtransform4;
+pub mod dual;
+pub mod dual_quaternion;
+pub mod dvector2;
+pub mod dvector3;
+pub mod dvector4;
+pub mod euler;
+pub mod fixed;
+#[cfg(not(feature = "std"))]
+mod float_ops;
+pub mod frustum;
+pub mod gjk;
+pub mod half;
+pub mod intersect;
+pub mod interval;
+mod inverse;
+mod iter;
+pub mod lab;
 pub mod line;
 pub mod matrix2;
 pub mod matrix3;
 pub mod matrix4;
+pub mod morton;
+pub mod noise;
+pub mod obb;
+pub mod octahedral;
+pub mod packing;
+pub mod parse;
+pub mod physics;
 pub mod plane;
 pub mod point2;
 pub mod point3;
+pub mod polygon2;
+pub mod polyline;
+pub mod predicates;
+pub mod projection;
 pub mod quarternion;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod ray3;
 pub mod rgb;
 pub mod rgb_u32;
 pub mod rgb_u8;
 pub mod rgba;
+pub mod rgba_u8;
+pub mod rotor2;
+pub mod sat;
+pub mod scalar;
+pub mod sdf;
+mod segment;
+pub mod segment2;
+pub mod segment3;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod shadow;
+#[cfg(feature = "simd")]
+mod simd;
+mod smooth_damp;
+pub mod soa;
+pub mod sphere;
+pub mod statistics;
+pub mod sweep;
+pub mod tangent;
+pub mod track;
+pub mod transform2;
 pub mod transform4;
+pub mod transform_builder;
+pub mod triangle;
+pub mod twist;
+pub mod unit_quaternion;
+pub mod unit_vector3;
 pub mod vector2;
 pub mod vector3;
 pub mod vector4;
+pub mod xyz;
+pub mod ycbcr;
 
 pub mod prelude {
+    pub use crate::aabb2::*;
+    pub use crate::aabb3::*;
+    pub use crate::angle::*;
+    pub use crate::bezier::*;
+    pub use crate::blend::*;
+    pub use crate::bvec::*;
+    pub use crate::bvh::*;
+    pub use crate::camera::*;
+    pub use crate::capsule::*;
+    pub use crate::circle::*;
+    pub use crate::clip::*;
+    pub use crate::convex_hull::*;
+    pub use crate::convex_volume::*;
+    pub use crate::decomposition::*;
+    pub use crate::dmatrix2::*;
+    pub use crate::dmatrix3::*;
+    pub use crate::dmatrix4::*;
+    pub use crate::dpoint2::*;
+    // The free functions of dpoint3 share their names with the point3 ones,
+    // which keep the unqualified names.
+    pub use crate::dpoint3::DPoint3;
+    pub use crate::dquarternion::*;
+    pub use crate::dtransform4::*;
+    pub use crate::dual::*;
+    pub use crate::dual_quaternion::*;
+    pub use crate::dvector2::*;
+    pub use crate::dvector3::*;
+    pub use crate::dvector4::*;
+    pub use crate::euler::*;
+    pub use crate::fixed::*;
+    // Unused when another crate in the graph links std, which brings the
+    // inherent float methods back into scope.
+    #[cfg(not(feature = "std"))]
+    #[allow(unused_imports)]
+    pub(crate) use crate::float_ops::FloatOps as _;
+    pub use crate::frustum::*;
+    pub use crate::half::*;
+    pub use crate::intersect::*;
+    pub use crate::interval::*;
+    pub use crate::lab::*;
     pub use crate::line::*;
     pub use crate::matrix2::*;
     pub use crate::matrix3::*;
     pub use crate::matrix4::*;
+    pub use crate::noise::*;
+    pub use crate::obb::*;
+    pub use crate::parse::*;
     pub use crate::plane::*;
     pub use crate::point2::*;
     pub use crate::point3::*;
+    pub use crate::polygon2::*;
+    pub use crate::polyline::*;
+    pub use crate::projection::*;
     pub use crate::quarternion::*;
+    #[cfg(feature = "rand")]
+    pub use crate::random::*;
+    pub use crate::ray3::*;
     pub use crate::rgb::*;
     pub use crate::rgb_u32::*;
     pub use crate::rgb_u8::*;
     pub use crate::rgba::*;
+    pub use crate::rgba_u8::*;
+    pub use crate::rotor2::*;
+    pub use crate::segment2::*;
+    pub use crate::segment3::*;
+    pub use crate::soa::*;
+    pub use crate::sphere::*;
+    pub use crate::track::*;
+    pub use crate::transform2::*;
     pub use crate::transform4::*;
+    pub use crate::transform_builder::*;
+    pub use crate::triangle::*;
+    pub use crate::twist::*;
+    pub use crate::unit_quaternion::*;
+    pub use crate::unit_vector3::*;
     pub use crate::vector2::*;
     pub use crate::vector3::*;
     pub use crate::vector4::*;
+    pub use crate::xyz::*;
+    pub use crate::ycbcr::*;
+}
+
+// Every arithmetic type supports the full operator set of its kind, so that
+// code generic over one of them works for all of them.
+#[test]
+fn operator_parity() {
+    use crate::prelude::*;
+    use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+    fn linear<T, S>()
+    where
+        T: Add<Output = T> + Sub<Output = T> + Mul<S, Output = T> + Div<S, Output = T>,
+        T: AddAssign + SubAssign + MulAssign<S> + DivAssign<S>,
+        S: Mul<T, Output = T>,
+    {
+    }
+    fn vector<V>()
+    where
+        V: Neg<Output = V> + Mul<Output = V> + MulAssign,
+    {
+    }
+    fn point<P, V>()
+    where
+        P: Add<V, Output = P> + Sub<V, Output = P> + Sub<Output = V> + Neg<Output = P>,
+        P: AddAssign<V> + SubAssign<V>,
+    {
+    }
+    fn matrix<M, V>()
+    where
+        M: Neg<Output = M> + Mul<Output = M> + Mul<V, Output = V> + MulAssign,
+    {
+    }
+
+    linear::<Vector2, f32>();
+    linear::<Vector3, f32>();
+    linear::<Vector4, f32>();
+    linear::<DVector2, f64>();
+    linear::<DVector3, f64>();
+    linear::<DVector4, f64>();
+    linear::<Quarternion, f32>();
+    linear::<DQuarternion, f64>();
+    linear::<Matrix2, f32>();
+    linear::<Matrix3, f32>();
+    linear::<Matrix4, f32>();
+    linear::<DMatrix2, f64>();
+    linear::<DMatrix3, f64>();
+    linear::<DMatrix4, f64>();
+    linear::<RGB, f32>();
+    linear::<RGBA, f32>();
+    vector::<Vector2>();
+    vector::<Vector3>();
+    vector::<Vector4>();
+    vector::<DVector2>();
+    vector::<DVector3>();
+    vector::<DVector4>();
+    vector::<Quarternion>();
+    vector::<DQuarternion>();
+    point::<Point2, Vector2>();
+    point::<Point3, Vector3>();
+    point::<DPoint2, DVector2>();
+    point::<DPoint3, DVector3>();
+    matrix::<Matrix2, Vector2>();
+    matrix::<Matrix3, Vector3>();
+    matrix::<Matrix4, Vector4>();
+    matrix::<DMatrix2, DVector2>();
+    matrix::<DMatrix3, DVector3>();
+    matrix::<DMatrix4, DVector4>();
+
+    let mut m = Matrix3::identity() + Matrix3::identity();
+    m -= Matrix3::identity() * 0.5;
+    assert!(m == 1.5 * Matrix3::identity());
+    let mut p = Point2::new(1.0, 2.0);
+    p += Vector2::new(0.5, -1.0);
+    assert!(p == Point2::new(1.5, 1.0));
+    let mut v = Vector2::new(1.0, 2.0);
+    v *= 2.0;
+    v /= 4.0;
+    assert!(v == Vector2::new(0.5, 1.0));
+    let c = RGBA::new(0.2, 0.4, 0.6, 1.0) * 0.5 + RGBA::new(0.1, 0.0, 0.0, 0.0);
+    assert!(c == RGBA::new(0.2, 0.2, 0.3, 0.5));
+    assert!(
+        Matrix2::make_rotation(0.3) * Vector2::new(1.0, 0.0)
+            == Vector2::new(0.3f32.cos(), 0.3f32.sin())
+    );
+}
+
+// The double precision types mirror the methods of the single precision ones
+// and agree with them to within single precision rounding.
+#[test]
+fn method_parity() {
+    use crate::prelude::*;
+
+    fn close(a: &[f32], b: &[f32]) -> bool {
+        a.iter()
+            .zip(b)
+            .all(|(x, y)| (x - y).abs() <= 1e-5 * x.abs().max(1.0))
+    }
+    fn quat(q: Quarternion) -> [f32; 4] {
+        [q.x, q.y, q.z, q.w]
+    }
+
+    let (a, b) = (Vector3::new(0.3, -1.2, 0.8), Vector3::new(-0.5, 0.4, 2.0));
+    let (da, db) = (DVector3::from(a), DVector3::from(b));
+    let pairs = [
+        (a.slerp(&b, 0.3), da.slerp(&db, 0.3)),
+        (a.lerp(&b, 0.3), da.lerp(&db, 0.3)),
+        (a.clamp(&b, &(b + a.abs())), da.clamp(&db, &(db + da.abs()))),
+        (a.any_orthonormal_pair().1, da.any_orthonormal_pair().1),
+    ];
+    for (f, d) in pairs {
+        assert!(close(&<[f32; 3]>::from(f), &<[f32; 3]>::from(d.to_f32())));
+    }
+    let basis = a.orthonormal_basis().to_cols_array();
+    assert!(close(
+        &basis,
+        &da.orthonormal_basis().to_f32().to_cols_array()
+    ));
+    assert!((Vector2::new(1.0, 2.0).angle() - DVector2::new(1.0, 2.0).angle() as f32).abs() < 1e-6);
+    let (p, q) = (Point3::new(1.0, 2.0, 3.0), Point3::new(-1.0, 0.5, 2.0));
+    let (dp, dq) = (DPoint3::from(p), DPoint3::from(q));
+    assert!((p.distance(&q) - dp.distance(&dq) as f32).abs() < 1e-6);
+    assert!(p.midpoint(&q) == dp.midpoint(&dq).to_f32());
+
+    let r = Quarternion::from_euler(EulerOrder::ZXY, 0.1, 0.2, 0.3);
+    let dr = DQuarternion::from_euler(EulerOrder::ZXY, 0.1, 0.2, 0.3);
+    let s = Quarternion::from_axis_angle(&a.to_unit(), 1.3);
+    let ds = DQuarternion::from_axis_angle(&da.normalize(), 1.3);
+    assert!(close(&quat(r), &quat(dr.to_f32())));
+    assert!(close(
+        &quat(r.slerp(&s, 0.6)),
+        &quat(dr.slerp(&ds, 0.6).to_f32())
+    ));
+    assert!(close(
+        &quat(r.nlerp(&s, 0.6)),
+        &quat(dr.nlerp(&ds, 0.6).to_f32())
+    ));
+    assert!(close(&quat(r.inverse()), &quat(dr.inverse().to_f32())));
+    let w = Vector3::new(0.0, 1.0, 2.0);
+    let q = quat(r.integrate(&w, 0.1));
+    assert!(close(
+        &q,
+        &quat(dr.integrate(&DVector3::from(w), 0.1).to_f32())
+    ));
+    let (x, y, z) = dr.to_euler(EulerOrder::ZXY);
+    assert!(close(&[0.1, 0.2, 0.3], &[x as f32, y as f32, z as f32]));
+
+    let m = Matrix3::from_rotation_vector(&a);
+    let dm = DMatrix3::from_rotation_vector(&da);
+    assert!(close(&m.to_cols_array(), &dm.to_f32().to_cols_array()));
+    let (v, dv) = (m.ln().axial_vector(), dm.ln().axial_vector());
+    assert!(close(&<[f32; 3]>::from(v), &<[f32; 3]>::from(dv.to_f32())));
+    assert!(close(
+        &m.adjugate().to_cols_array(),
+        &dm.adjugate().to_f32().to_cols_array()
+    ));
+
+    let eye = Point3::new(1.0, 2.0, 5.0);
+    let up = Vector3::new(0.0, 1.0, 0.0);
+    let view = Matrix4::look_at_rh(&eye, &p, &up) * Matrix4::perspective_rh(1.0, 1.5, 0.1, 100.0);
+    let dview = DMatrix4::look_at_rh(&DPoint3::from(eye), &dp, &DVector3::from(up))
+        * DMatrix4::perspective_rh(1.0, 1.5, 0.1, 100.0);
+    assert!(close(
+        &view.to_cols_array(),
+        &dview.to_f32().to_cols_array()
+    ));
+    let inv = view.inverse_checked().unwrap().to_cols_array();
+    assert!(close(
+        &inv,
+        &dview.inverse_checked().unwrap().to_f32().to_cols_array()
+    ));
+
+    let h = Transform4::from_trs(&p, &s, &Vector3::new(1.0, 2.0, 3.0));
+    let dh = DTransform4::from_trs(&dp, &ds, &DVector3::new(1.0, 2.0, 3.0));
+    assert!(close(&h.to_cols_array(), &dh.to_f32().to_cols_array()));
+    let (t, r, sc) = dh.decompose();
+    assert!(close(&<[f32; 3]>::from(p), &<[f32; 3]>::from(t.to_f32())));
+    assert!(close(&quat(s), &quat(r.to_f32())));
+    assert!(close(&[1.0, 2.0, 3.0], &<[f32; 3]>::from(sc.to_f32())));
+    let g = Transform4::look_at(&eye, &p, &up);
+    let dg = DTransform4::look_at(&DPoint3::from(eye), &dp, &DVector3::from(up));
+    let (f, df) = (h.interpolate(&g, 0.4), dh.interpolate(&dg, 0.4));
+    assert!(close(&f.to_cols_array(), &df.to_f32().to_cols_array()));
+    let (dw, dv) = dg.ln();
+    let tw = g.ln();
+    assert!(close(
+        &<[f32; 3]>::from(tw.angular),
+        &<[f32; 3]>::from(dw.to_f32())
+    ));
+    assert!(close(
+        &<[f32; 3]>::from(tw.linear),
+        &<[f32; 3]>::from(dv.to_f32())
+    ));
 }
@@ -1,13 +1,17 @@
 use crate::prelude::*;
 
-#[derive(Default, Copy, Clone, Debug)]
+// A line in Plücker coordinates {v | m}, where v is the direction of the line
+// and m = p x v is its moment about the origin for any point p on the line.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct Line {
     direction: Vector3,
     moment: Vector3,
 }
 
 impl Line {
-    pub fn new(vx: f32, vy: f32, vz: f32, mx: f32, my: f32, mz: f32) -> Line {
+    pub const fn new(vx: f32, vy: f32, vz: f32, mx: f32, my: f32, mz: f32) -> Line {
         Line {
             direction: Vector3::new(vx, vy, vz),
             moment: Vector3::new(mx, my, mz),
@@ -18,7 +22,111 @@ impl Line {
         Line { direction, moment }
     }
 
-    // Transforms this line with a transformation matrix
+    /// Returns the line through p1 and p2, directed from p1 toward p2.
+    pub fn from_points(p1: &Point3, p2: &Point3) -> Line {
+        Line {
+            direction: *p2 - *p1,
+            moment: Vector3::from(*p1).cross(&Vector3::from(*p2)),
+        }
+    }
+
+    /// Returns the line through p with direction v.
+    pub fn from_point_direction(p: &Point3, v: &Vector3) -> Line {
+        Line {
+            direction: *v,
+            moment: Vector3::from(*p).cross(v),
+        }
+    }
+
+    pub fn get_direction(&self) -> Vector3 {
+        self.direction
+    }
+
+    pub fn get_moment(&self) -> Vector3 {
+        self.moment
+    }
+
+    /// Returns the point on this line closest to the origin.
+    pub fn get_point(&self) -> Point3 {
+        Point3::from(self.direction.cross(&self.moment) / self.direction.dot(&self.direction))
+    }
+
+    /// Returns the permuted dot product v1 . m2 + v2 . m1 of the two lines. It
+    /// is zero when the lines are coplanar, and otherwise its sign tells on
+    /// which side of this line other passes, as seen looking along other.
+    pub fn permuted_dot(&self, other: &Line) -> f32 {
+        self.direction.dot(&other.moment) + other.direction.dot(&self.moment)
+    }
+
+    /// Returns the distance between this line and other.
+    pub fn distance(&self, other: &Line) -> f32 {
+        let c = self.direction.cross(&other.direction);
+        let cc = c.dot(&c);
+        if cc
+            > f32::EPSILON
+                * self.direction.dot(&self.direction)
+                * other.direction.dot(&other.direction)
+        {
+            self.permuted_dot(other).abs() / cc.sqrt()
+        } else {
+            // Parallel lines: the distance from any point of one to the other.
+            point_line_distance(&self.get_point(), &other.get_point(), &other.direction)
+        }
+    }
+
+    /// Returns the closest points between this line and other, the first on
+    /// this line and the second on other, or None if the lines are parallel.
+    pub fn closest_points(&self, other: &Line) -> Option<(Point3, Point3)> {
+        let v1 = self.direction;
+        let v2 = other.direction;
+        let v12 = v1.dot(&v1);
+        let v22 = v2.dot(&v2);
+        let v1v2 = v1.dot(&v2);
+        let det = v1v2 * v1v2 - v12 * v22;
+        if det.abs() <= f32::EPSILON * v12 * v22 {
+            return None;
+        }
+        let p1 = self.get_point();
+        let p2 = other.get_point();
+        let dp = p2 - p1;
+        let dpv1 = dp.dot(&v1);
+        let dpv2 = dp.dot(&v2);
+        let t1 = (v1v2 * dpv2 - v22 * dpv1) / det;
+        let t2 = (v12 * dpv2 - v1v2 * dpv1) / det;
+        Some((p1 + v1 * t1, p2 + v2 * t2))
+    }
+
+    /// Returns the point at which this line meets the plane f, or None if
+    /// the line is parallel to the plane.
+    pub fn intersect_plane(&self, f: &Plane) -> Option<Point3> {
+        let n = f.get_normal();
+        let nv = n.dot(&self.direction);
+        if nv.abs() <= f32::EPSILON {
+            return None;
+        }
+        Some(Point3::from(
+            (n.cross(&self.moment) - self.direction * f.w) / nv,
+        ))
+    }
+
+    /// Returns the point at which this line passes through the triangle with
+    /// vertices a, b and c, or None if it misses. The line hits the triangle
+    /// exactly when its permuted dot products with the three edges agree in
+    /// sign, so either winding of the triangle is accepted.
+    pub fn intersect_triangle(&self, a: &Point3, b: &Point3, c: &Point3) -> Option<Point3> {
+        let s1 = self.permuted_dot(&Line::from_points(a, b));
+        let s2 = self.permuted_dot(&Line::from_points(b, c));
+        let s3 = self.permuted_dot(&Line::from_points(c, a));
+        let front = s1 >= 0.0 && s2 >= 0.0 && s3 >= 0.0;
+        let back = s1 <= 0.0 && s2 <= 0.0 && s3 <= 0.0;
+        if !front && !back {
+            return None;
+        }
+        let n = (*b - *a).cross(&(*c - *a));
+        self.intersect_plane(&Plane::new(n.x, n.y, n.z, -n.dot(&Vector3::from(*a))))
+    }
+
+    /// Transforms this line with a transformation matrix.
     pub fn transform(&self, h: &Transform4) -> Line {
         let v1 = h.vec_at(1).cross(&h.vec_at(2));
         let v2 = h.vec_at(2).cross(&h.vec_at(0));
@@ -28,9 +136,7 @@ impl Line {
         let adj = Matrix3::new_with_vecs(v1, v2, v3);
         let t = h.get_translation();
         let direction = *h * self.direction;
-        // TODO: fix this
         let moment = adj * self.moment + Vector3::from(t).cross(&direction);
-        //let moment = self.moment;
         Line { direction, moment }
     }
 
@@ -40,3 +146,39 @@ impl Line {
         self.moment = l.moment;
     }
 }
+
+#[test]
+fn plucker() {
+    let x = Line::from_points(&Point3::new(0.0, 0.0, 0.0), &Point3::new(1.0, 0.0, 0.0));
+    let y = Line::from_point_direction(&Point3::new(0.0, 0.0, 2.0), &Vector3::new(0.0, 1.0, 0.0));
+    assert!(x.distance(&y) == 2.0);
+    let y2 = Line::from_point_direction(&Point3::new(0.0, 0.0, -2.0), &Vector3::new(0.0, 1.0, 0.0));
+    assert!(x.permuted_dot(&y) * x.permuted_dot(&y2) < 0.0);
+    let (p, q) = x.closest_points(&y).unwrap();
+    assert!(p == Point3::new(0.0, 0.0, 0.0) && q == Point3::new(0.0, 0.0, 2.0));
+
+    let z = Line::from_point_direction(&Point3::new(0.0, 3.0, 0.0), &Vector3::new(1.0, 0.0, 0.0));
+    assert!(x.closest_points(&z).is_none());
+    assert!(x.distance(&z) == 3.0);
+
+    let down =
+        Line::from_point_direction(&Point3::new(0.25, 0.25, 5.0), &Vector3::new(0.0, 0.0, -1.0));
+    let f = Plane::new(0.0, 0.0, 1.0, -1.0);
+    assert!(down.intersect_plane(&f) == Some(Point3::new(0.25, 0.25, 1.0)));
+    let (a, b, c) = (
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    assert!(down.intersect_triangle(&a, &b, &c) == Some(Point3::new(0.25, 0.25, 0.0)));
+    assert!(down.intersect_triangle(&a, &c, &b) == Some(Point3::new(0.25, 0.25, 0.0)));
+    let miss =
+        Line::from_point_direction(&Point3::new(0.75, 0.75, 5.0), &Vector3::new(0.0, 0.0, -1.0));
+    assert!(miss.intersect_triangle(&a, &b, &c).is_none());
+
+    // Transforming the defining points and rebuilding gives the same line.
+    let h = Transform4::make_translation(&Vector3::new(1.0, 2.0, 3.0))
+        * Transform4::make_scale_xyz(2.0, 1.0, 3.0);
+    let (p1, p2) = (Point3::new(1.0, 1.0, 0.0), Point3::new(0.0, 2.0, 1.0));
+    assert!(Line::from_points(&p1, &p2).transform(&h) == Line::from_points(&(h * p1), &(h * p2)));
+}
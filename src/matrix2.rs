@@ -1,9 +1,12 @@
-use std::ops::{Div, DivAssign, Index, IndexMut, Mul, MulAssign};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 use crate::prelude::*;
 
 #[derive(Default, Copy, Clone, Debug, PartialEq)]
 /// A 2x2 matrix.
+#[repr(C)]
 pub struct Matrix2 {
     /// The column entries of the matrix.
     pub n: [Vector2; 2],
@@ -23,7 +26,7 @@ impl Matrix2 {
     /// use math_engine::matrix2::Matrix2;
     /// let m = Matrix2::new(1.0,0.0,0.0,1.0);
     /// ```
-    pub fn new(n00: f32, n01: f32, n10: f32, n11: f32) -> Matrix2 {
+    pub const fn new(n00: f32, n01: f32, n10: f32, n11: f32) -> Matrix2 {
         let n: [Vector2; 2] = [Vector2::new(n00, n10), Vector2::new(n01, n11)];
         Self { n }
     }
@@ -170,7 +173,7 @@ impl Matrix2 {
     /// use math_engine::matrix2::Matrix2;
     /// let m = Matrix2::identity();
     /// ```
-    pub fn identity() -> Matrix2 {
+    pub const fn identity() -> Matrix2 {
         Self::new(1.0, 0.0, 0.0, 1.0)
     }
 
@@ -186,10 +189,11 @@ impl Matrix2 {
     /// use math_engine::matrix2::Matrix2;
     /// let m = Matrix2::make_rotation(2.5);
     /// ```
-    pub fn make_rotation(a: f32) -> Matrix2 {
+    pub fn make_rotation(a: impl Into<Radians>) -> Matrix2 {
+        let a = a.into().0;
         let c = a.cos();
         let s = a.sin();
-        Self::new(c, -s, s, -c)
+        Self::new(c, -s, s, c)
     }
 
     /// Returns a matrix that represents a scale along the x axis.
@@ -301,25 +305,51 @@ impl Mul<f32> for Matrix2 {
     }
 }
 
+impl Mul<Matrix2> for f32 {
+    type Output = Matrix2;
+
+    fn mul(self, rhs: Matrix2) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<Vector2> for Matrix2 {
+    type Output = Vector2;
+
+    fn mul(self, other: Vector2) -> Self::Output {
+        Vector2::new(
+            self.n[0][0] * other.x + self.n[1][0] * other.y,
+            self.n[0][1] * other.x + self.n[1][1] * other.y,
+        )
+    }
+}
+
+impl Neg for Matrix2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            n: self.n.map(Neg::neg),
+        }
+    }
+}
+
 impl Mul<Matrix2> for Matrix2 {
     type Output = Self;
 
     fn mul(self, other: Matrix2) -> Self::Output {
         Self::new(
-            self.n[0][0] * other[(0, 0)] + self.n[0][1] * other[(1, 0)],
-            self.n[0][0] * other[(0, 1)] + self.n[0][1] * other[(1, 1)],
-            self.n[1][0] * other[(0, 0)] + self.n[1][1] * other[(1, 0)],
-            self.n[1][0] * other[(0, 1)] + self.n[0][1] * other[(1, 1)],
+            self.n[0][0] * other[(0, 0)] + self.n[1][0] * other[(1, 0)],
+            self.n[0][0] * other[(0, 1)] + self.n[1][0] * other[(1, 1)],
+            self.n[0][1] * other[(0, 0)] + self.n[1][1] * other[(1, 0)],
+            self.n[0][1] * other[(0, 1)] + self.n[1][1] * other[(1, 1)],
         )
     }
 }
 
 impl MulAssign<Matrix2> for Matrix2 {
     fn mul_assign(&mut self, other: Matrix2) {
-        self.n[0][0] *= other[(0, 0)] + self.n[0][1] * other[(1, 0)];
-        self.n[1][0] *= other[(0, 1)] + self.n[0][1] * other[(1, 1)];
-        self.n[0][1] *= other[(0, 0)] + self.n[1][1] * other[(1, 0)];
-        self.n[1][1] *= other[(0, 1)] + self.n[0][1] * other[(1, 1)];
+        *self = *self * other;
     }
 }
 
@@ -353,3 +383,61 @@ impl DivAssign<f32> for Matrix2 {
         self.n[1][1] /= other;
     }
 }
+
+impl Add for Matrix2 {
+    type Output = Self;
+
+    fn add(self, rhs: Matrix2) -> Self::Output {
+        Self {
+            n: core::array::from_fn(|i| self.n[i] + rhs.n[i]),
+        }
+    }
+}
+
+impl Sub for Matrix2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Matrix2) -> Self::Output {
+        Self {
+            n: core::array::from_fn(|i| self.n[i] - rhs.n[i]),
+        }
+    }
+}
+
+impl AddAssign for Matrix2 {
+    fn add_assign(&mut self, rhs: Matrix2) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Matrix2 {
+    fn sub_assign(&mut self, rhs: Matrix2) {
+        *self = *self - rhs;
+    }
+}
+
+#[test]
+fn rotation() {
+    let (s, c) = (0.3f32.sin(), 0.3f32.cos());
+    let m = Matrix2::make_rotation(0.3);
+    let want = Matrix2::new(c, -s, s, c);
+    for (i, j) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+        assert!((m[(i, j)] - want[(i, j)]).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn product() {
+    let a = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+    let b = Matrix2::new(5.0, 6.0, 7.0, 8.0);
+    assert!(a * b == Matrix2::new(19.0, 22.0, 43.0, 50.0));
+}
+
+#[test]
+fn mul_assign() {
+    let a = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+    let b = Matrix2::new(5.0, 6.0, 7.0, 8.0);
+    let mut c = a;
+    c *= b;
+    assert!(c == a * b);
+}
@@ -1,8 +1,11 @@
 use crate::prelude::*;
-use std::ops::{Div, DivAssign, Index, IndexMut, Mul, MulAssign};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
-#[derive(Default, Copy, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
 /// A 3x3 matrix.
+#[repr(C)]
 pub struct Matrix3 {
     /// The column entries of the matrix.
     n: [Vector3; 3],
@@ -22,7 +25,18 @@ impl Matrix3 {
     /// use math_engine::matrix3::Matrix3;
     /// let m = Matrix3::new(1.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,1.0);
     /// ```
-    pub fn new(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32, g: f32, h: f32, i: f32) -> Matrix3 {
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        a: f32,
+        b: f32,
+        c: f32,
+        d: f32,
+        e: f32,
+        f: f32,
+        g: f32,
+        h: f32,
+        i: f32,
+    ) -> Matrix3 {
         let n: [Vector3; 3] = [
             Vector3::new(a, d, g),
             Vector3::new(b, e, h),
@@ -74,6 +88,7 @@ impl Matrix3 {
     /// let mut m = Matrix3::new(1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0);
     /// m.set(0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0);
     /// ```
+    #[allow(clippy::too_many_arguments)]
     pub fn set(
         &mut self,
         n00: f32,
@@ -150,9 +165,9 @@ impl Matrix3 {
     /// let det = m.determinant();
     /// ```
     pub fn determinant(&self) -> f32 {
-        (self.n[0][0] * self.n[1][1] * self.n[2][2] - self.n[2][1] * self.n[1][2])
-            - self.n[1][0] * (self.n[0][1] * self.n[2][2] - self.n[2][1] * self.n[2][0])
-            + self.n[0][2] * (self.n[0][1] * self.n[1][2] - self.n[1][1] * self.n[0][2])
+        self.n[0][0] * (self.n[1][1] * self.n[2][2] - self.n[2][1] * self.n[1][2])
+            - self.n[1][0] * (self.n[0][1] * self.n[2][2] - self.n[2][1] * self.n[0][2])
+            + self.n[2][0] * (self.n[0][1] * self.n[1][2] - self.n[1][1] * self.n[0][2])
     }
 
     /// Returns the inverse of this matrix.
@@ -208,6 +223,128 @@ impl Matrix3 {
         )
     }
 
+    /// Returns the trace of this matrix, the sum of its diagonal entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// let m = Matrix3::new(1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0);
+    /// assert!(m.trace() == 15.0);
+    /// ```
+    pub fn trace(&self) -> f32 {
+        self.n[0][0] + self.n[1][1] + self.n[2][2]
+    }
+
+    /// Returns the cofactor matrix, whose entry (i, j) is the signed minor
+    /// of this matrix obtained by removing row i and column j. For a
+    /// transform m, `m.cofactor_matrix()` transforms normals as
+    /// `det(m) * m.inverse().transpose()` does, and stays valid when m is
+    /// singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// let m = Matrix3::make_scale(2.0, 3.0, 4.0);
+    /// assert!(m.cofactor_matrix() == Matrix3::make_scale(12.0, 8.0, 6.0));
+    /// ```
+    pub fn cofactor_matrix(&self) -> Matrix3 {
+        let a = self[0];
+        let b = self[1];
+        let c = self[2];
+        Self::new_with_vecs(b.cross(&c), c.cross(&a), a.cross(&b))
+    }
+
+    /// Returns the adjugate of this matrix, the transpose of its cofactor
+    /// matrix, so that `m * m.adjugate()` is `det(m)` times the identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// let m = Matrix3::new(2.0,1.0,0.0,0.0,1.0,3.0,1.0,0.0,1.0);
+    /// let p = m * m.adjugate();
+    /// assert!(p == Matrix3::identity() * m.determinant());
+    /// ```
+    pub fn adjugate(&self) -> Matrix3 {
+        self.cofactor_matrix().transpose()
+    }
+
+    /// Returns the outer product a * b^T, whose entry (i, j) is a[i] * b[j].
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - A reference to a vector3, the column factor.
+    /// * `b` - A reference to a vector3, the row factor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::vector3::Vector3;
+    /// let m = Matrix3::outer_product(&Vector3::new(1.0,2.0,3.0), &Vector3::new(0.0,1.0,0.0));
+    /// assert!(m[1] == Vector3::new(1.0,2.0,3.0) && m[0] == Vector3::default());
+    /// ```
+    pub fn outer_product(a: &Vector3, b: &Vector3) -> Matrix3 {
+        Self::new_with_vecs(*a * b.x, *a * b.y, *a * b.z)
+    }
+
+    /// Returns the diagonal matrix with the entries of v on its diagonal.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A reference to a vector3.
+    pub fn from_diagonal(v: &Vector3) -> Matrix3 {
+        Self::new(v.x, 0.0, 0.0, 0.0, v.y, 0.0, 0.0, 0.0, v.z)
+    }
+
+    /// Returns the diagonal entries of this matrix.
+    pub fn diagonal(&self) -> Vector3 {
+        Vector3::new(self.n[0][0], self.n[1][1], self.n[2][2])
+    }
+
+    /// Returns the row at index i.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The row index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::vector3::Vector3;
+    /// let m = Matrix3::new(1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0);
+    /// assert!(m.row(1) == Vector3::new(4.0,5.0,6.0));
+    /// ```
+    pub fn row(&self, i: usize) -> Vector3 {
+        Vector3::new(self.n[0][i], self.n[1][i], self.n[2][i])
+    }
+
+    /// Sets the row at index i to v.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The row index.
+    /// * `v` - The new row.
+    pub fn set_row(&mut self, i: usize, v: &Vector3) {
+        self.n[0][i] = v.x;
+        self.n[1][i] = v.y;
+        self.n[2][i] = v.z;
+    }
+
+    /// Returns the matrix with the rows a, b and c.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first row.
+    /// * `b` - The second row.
+    /// * `c` - The third row.
+    pub fn from_rows(a: &Vector3, b: &Vector3, c: &Vector3) -> Matrix3 {
+        Self::new(a.x, a.y, a.z, b.x, b.y, b.z, c.x, c.y, c.z)
+    }
+
     /// Returns 3x3 identity matrix.
     ///
     /// # Examples
@@ -216,11 +353,28 @@ impl Matrix3 {
     /// use math_engine::matrix3::Matrix3;
     /// let m = Matrix3::identity();
     /// ```
-    pub fn identity() -> Matrix3 {
-        Self::new(1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0)
+    pub const fn identity() -> Matrix3 {
+        Self::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0)
     }
 
-    pub fn make_rotation(a: f32, v: &Vector3) -> Matrix3 {
+    /// Returns the rotation through a radians about the unit vector v.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle in radians.
+    /// * `v` - The unit length axis of rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::unit_vector3::UnitVector3;
+    /// let m = Matrix3::make_rotation(0.7, &UnitVector3::x_axis());
+    /// let n = Matrix3::make_rotation_x(0.7);
+    /// assert!((0..3).all(|i| (m[i] - n[i]).magnitude() < 1e-6));
+    /// ```
+    pub fn make_rotation(a: impl Into<Radians>, v: &UnitVector3) -> Matrix3 {
+        let a = a.into().0;
         let c = a.cos();
         let s = a.sin();
         let d = 1.0 - c;
@@ -243,25 +397,119 @@ impl Matrix3 {
         )
     }
 
-    pub fn make_rotation_x(a: f32) -> Matrix3 {
+    /// Returns the rotation through the angle a about the x axis. A plain
+    /// `f32` is taken as radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle, as `Radians`, `Degrees` or an `f32` in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::angle::Degrees;
+    /// use math_engine::matrix3::Matrix3;
+    /// let m = Matrix3::make_rotation_x(Degrees(90.0));
+    /// let n = Matrix3::make_rotation_x(core::f32::consts::FRAC_PI_2);
+    /// assert!(m == n);
+    /// ```
+    pub fn make_rotation_x(a: impl Into<Radians>) -> Matrix3 {
+        let a = a.into().0;
         let c = a.cos();
         let s = a.sin();
         Self::new(1.0, 0.0, 0.0, 0.0, c, -s, 0.0, s, c)
     }
 
-    fn make_rotation_y(a: f32) -> Matrix3 {
+    pub fn make_rotation_y(a: impl Into<Radians>) -> Matrix3 {
+        let a = a.into().0;
         let c = a.cos();
         let s = a.sin();
-        Self::new(c, 0.0, s, 0.0, 1.0, 0.0, -s, 0.0, s)
+        Self::new(c, 0.0, s, 0.0, 1.0, 0.0, -s, 0.0, c)
     }
 
-    fn make_rotation_z(a: f32) -> Matrix3 {
+    pub fn make_rotation_z(a: impl Into<Radians>) -> Matrix3 {
+        let a = a.into().0;
         let c = a.cos();
         let s = a.sin();
-        Self::new(c, 0.0, s, 0.0, 1.0, 0.0, -s, 0.0, s)
+        Self::new(c, -s, 0.0, s, c, 0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Returns the rotation matrix composed of rotations through x, y and z
+    /// radians about the x, y and z axes, applied in the given order.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order in which the rotations are applied.
+    /// * `x` - The angle about the x axis in radians.
+    /// * `y` - The angle about the y axis in radians.
+    /// * `z` - The angle about the z axis in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::euler::EulerOrder;
+    /// use math_engine::matrix3::Matrix3;
+    /// let m = Matrix3::from_euler(EulerOrder::XYZ, 0.1, 0.2, 0.3);
+    /// let n = Matrix3::make_rotation_z(0.3) * Matrix3::make_rotation_y(0.2) * Matrix3::make_rotation_x(0.1);
+    /// assert!(m == n);
+    /// ```
+    pub fn from_euler(
+        order: EulerOrder,
+        x: impl Into<Radians>,
+        y: impl Into<Radians>,
+        z: impl Into<Radians>,
+    ) -> Matrix3 {
+        let x = x.into().0;
+        let y = y.into().0;
+        let z = z.into().0;
+        let r = [
+            Self::make_rotation_x(x),
+            Self::make_rotation_y(y),
+            Self::make_rotation_z(z),
+        ];
+        let [i, j, k] = order.axes();
+        r[k] * r[j] * r[i]
     }
 
-    fn make_skew(angle: f32, a: &Vector3, b: &Vector3) -> Matrix3 {
+    /// Returns the angles (x, y, z) in radians about the x, y and z axes that
+    /// `Matrix3::from_euler` turns back into this rotation matrix. The angle
+    /// about the middle axis lies in [-pi/2, pi/2] and the others in
+    /// [-pi, pi]. At the singularity where the middle angle is +-pi/2 only
+    /// the sum or difference of the other two is determined, and the angle
+    /// about the last axis is returned as 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order in which the rotations are applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::euler::EulerOrder;
+    /// use math_engine::matrix3::Matrix3;
+    /// let m = Matrix3::from_euler(EulerOrder::ZXY, 0.1, 0.2, 0.3);
+    /// let (x, y, z) = m.to_euler(EulerOrder::ZXY);
+    /// assert!((x - 0.1).abs() < 1e-6 && (y - 0.2).abs() < 1e-6 && (z - 0.3).abs() < 1e-6);
+    /// ```
+    pub fn to_euler(&self, order: EulerOrder) -> (f32, f32, f32) {
+        let [i, j, k] = order.axes();
+        let p = order.parity();
+        let m = |r: usize, c: usize| self[(r, c)];
+        let sb = (-p * m(k, i)).clamp(-1.0, 1.0);
+        let b = sb.asin();
+        let (a, c) = if sb.abs() < 1.0 - 1.0e-6 {
+            ((p * m(k, j)).atan2(m(k, k)), (p * m(j, i)).atan2(m(i, i)))
+        } else {
+            ((-p * m(j, k)).atan2(m(j, j)), 0.0)
+        };
+        let mut angles = [0.0; 3];
+        angles[i] = a;
+        angles[j] = b;
+        angles[k] = c;
+        (angles[0], angles[1], angles[2])
+    }
+
+    pub fn make_skew(angle: f32, a: &Vector3, b: &Vector3) -> Matrix3 {
         let t = angle.tan();
         let x = a.x * t;
         let y = a.y * t;
@@ -270,16 +518,16 @@ impl Matrix3 {
             x * b.x + 1.0,
             x * b.y,
             x * b.z,
-            y * b.x + 1.0,
-            y * b.y,
+            y * b.x,
+            y * b.y + 1.0,
             y * b.z,
-            z * b.x + 1.0,
+            z * b.x,
             z * b.y,
-            z * b.z,
+            z * b.z + 1.0,
         )
     }
 
-    fn make_scale_vec(s: f32, a: &Vector3) -> Matrix3 {
+    pub fn make_scale_vec(s: f32, a: &Vector3) -> Matrix3 {
         let ss = s - 1.0;
         let x = a.x * ss;
         let y = a.y * ss;
@@ -300,7 +548,13 @@ impl Matrix3 {
         )
     }
 
-    fn make_involution(a: &Vector3) -> Matrix3 {
+    /// Returns the involution through the unit vector a, the half turn
+    /// about a.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The unit length axis.
+    pub fn make_involution(a: &UnitVector3) -> Matrix3 {
         let x = a.x * 2.0;
         let y = a.y * 2.0;
         let z = a.z * 2.0;
@@ -320,23 +574,38 @@ impl Matrix3 {
         )
     }
 
-    fn make_scale(sx: f32, sy: f32, sz: f32) -> Matrix3 {
+    pub fn make_scale(sx: f32, sy: f32, sz: f32) -> Matrix3 {
         Self::new(sx, 0.0, 0.0, 0.0, sy, 0.0, 0.0, 0.0, sz)
     }
 
-    fn make_scale_x(sx: f32) -> Matrix3 {
+    pub fn make_scale_x(sx: f32) -> Matrix3 {
         Self::new(sx, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0)
     }
 
-    fn make_scale_y(sy: f32) -> Matrix3 {
+    pub fn make_scale_y(sy: f32) -> Matrix3 {
         Self::new(1.0, 0.0, 0.0, 0.0, sy, 0.0, 0.0, 0.0, 1.0)
     }
 
-    fn make_scale_z(sz: f32) -> Matrix3 {
+    pub fn make_scale_z(sz: f32) -> Matrix3 {
         Self::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, sz)
     }
 
-    fn make_reflection(a: &Vector3) -> Matrix3 {
+    /// Returns the reflection through the plane through the origin
+    /// perpendicular to the unit vector a.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The unit length normal of the mirror plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::vector3::Vector3;
+    /// let m = Matrix3::make_reflection(&Vector3::new(0.0, 0.0, 2.0).to_unit());
+    /// assert!(m * Vector3::new(1.0, 2.0, 3.0) == Vector3::new(1.0, 2.0, -3.0));
+    /// ```
+    pub fn make_reflection(a: &UnitVector3) -> Matrix3 {
         let x = a.x * -2.0;
         let y = a.y * -2.0;
         let z = a.z * -2.0;
@@ -356,6 +625,124 @@ impl Matrix3 {
             z * a.z + 1.0,
         )
     }
+
+    /// Returns the cross product matrix of v, the skew-symmetric matrix with
+    /// `Matrix3::make_cross_product(v) * u == v.cross(u)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A reference to a vector3.
+    pub fn make_cross_product(v: &Vector3) -> Matrix3 {
+        Self::new(0.0, -v.z, v.y, v.z, 0.0, -v.x, -v.y, v.x, 0.0)
+    }
+
+    /// Returns the vector v whose cross product matrix is the skew-symmetric
+    /// part of this matrix, (m - m^T) / 2.
+    pub fn axial_vector(&self) -> Vector3 {
+        Vector3::new(
+            self[(2, 1)] - self[(1, 2)],
+            self[(0, 2)] - self[(2, 0)],
+            self[(1, 0)] - self[(0, 1)],
+        ) * 0.5
+    }
+
+    /// Returns the rotation through |w| radians about w, the exponential of
+    /// the rotation vector w.
+    ///
+    /// # Arguments
+    ///
+    /// * `w` - The rotation vector, the axis scaled by the angle.
+    pub fn from_rotation_vector(w: &Vector3) -> Matrix3 {
+        let angle = w.magnitude();
+        if angle < 1.0e-6 {
+            // The first order term of the exponential series.
+            let k = Self::make_cross_product(w);
+            return Self::new_with_vecs(
+                Vector3::new(1.0, 0.0, 0.0) + k[0],
+                Vector3::new(0.0, 1.0, 0.0) + k[1],
+                Vector3::new(0.0, 0.0, 1.0) + k[2],
+            );
+        }
+        Self::make_rotation(angle, &UnitVector3::new_unchecked(*w / angle))
+    }
+
+    /// Returns the rotation vector of this rotation matrix, the axis scaled
+    /// by the angle, with the angle in [0, π].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::vector3::Vector3;
+    /// let w = Vector3::new(0.3, -1.2, 0.8);
+    /// let r = Matrix3::from_rotation_vector(&w);
+    /// assert!((r.to_rotation_vector() - w).magnitude() < 1e-5);
+    /// ```
+    pub fn to_rotation_vector(&self) -> Vector3 {
+        let s = self.axial_vector();
+        let sin = s.magnitude();
+        let cos = (self.trace() - 1.0) * 0.5;
+        let angle = sin.atan2(cos);
+        if cos >= 0.0 {
+            if sin < 1.0e-6 {
+                return s;
+            }
+            return s * (angle / sin);
+        }
+        // Near a half turn sin loses the axis, so it is read from the
+        // symmetric part (m + m^T) / 2 - cos * I = (1 - cos) * u * u^T
+        // instead, and s only supplies the sign.
+        let b = |i: usize, j: usize| (self[(i, j)] + self[(j, i)]) * 0.5;
+        let d = Vector3::new(b(0, 0) - cos, b(1, 1) - cos, b(2, 2) - cos);
+        let axis = if d.x >= d.y && d.x >= d.z {
+            Vector3::new(d.x, b(1, 0), b(2, 0))
+        } else if d.y >= d.z {
+            Vector3::new(b(0, 1), d.y, b(2, 1))
+        } else {
+            Vector3::new(b(0, 2), b(1, 2), d.z)
+        }
+        .normalize();
+        if axis.dot(&s) < 0.0 {
+            axis * -angle
+        } else {
+            axis * angle
+        }
+    }
+
+    /// Returns the matrix exponential of this skew-symmetric matrix, the
+    /// rotation given by Rodrigues' formula. Only the skew-symmetric part of
+    /// the matrix is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::vector3::Vector3;
+    /// let k = Matrix3::make_cross_product(&Vector3::new(0.0, 0.0, 0.5));
+    /// let r = k.exp();
+    /// let z = Matrix3::make_rotation_z(0.5);
+    /// assert!((0..3).all(|i| (r[i] - z[i]).magnitude() < 1e-6));
+    /// ```
+    pub fn exp(&self) -> Matrix3 {
+        Self::from_rotation_vector(&self.axial_vector())
+    }
+
+    /// Returns the matrix logarithm of this rotation matrix, the
+    /// skew-symmetric matrix k with the smallest angle such that
+    /// `k.exp() == m`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// let r = Matrix3::make_rotation_x(0.6) * Matrix3::make_rotation_y(-0.4);
+    /// let half = (r.ln() * 0.5).exp();
+    /// let p = half * half;
+    /// assert!((0..3).all(|i| (p[i] - r[i]).magnitude() < 1e-5));
+    /// ```
+    pub fn ln(&self) -> Matrix3 {
+        Self::make_cross_product(&self.to_rotation_vector())
+    }
 }
 
 impl Index<usize> for Matrix3 {
@@ -406,6 +793,24 @@ impl Mul<f32> for Matrix3 {
     }
 }
 
+impl Mul<Matrix3> for f32 {
+    type Output = Matrix3;
+
+    fn mul(self, rhs: Matrix3) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Neg for Matrix3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            n: self.n.map(Neg::neg),
+        }
+    }
+}
+
 impl Mul<Vector3> for Matrix3 {
     type Output = Vector3;
 
@@ -444,10 +849,10 @@ impl Mul<Matrix3> for Matrix3 {
             self.n[0][2] * other[(0, 0)]
                 + self.n[1][2] * other[(1, 0)]
                 + self.n[2][2] * other[(2, 0)],
-            self.n[1][2] * other[(0, 1)]
+            self.n[0][2] * other[(0, 1)]
                 + self.n[1][2] * other[(1, 1)]
                 + self.n[2][2] * other[(2, 1)],
-            self.n[1][2] * other[(0, 2)]
+            self.n[0][2] * other[(0, 2)]
                 + self.n[1][2] * other[(1, 2)]
                 + self.n[2][2] * other[(2, 2)],
         )
@@ -456,33 +861,7 @@ impl Mul<Matrix3> for Matrix3 {
 
 impl MulAssign<Matrix3> for Matrix3 {
     fn mul_assign(&mut self, other: Matrix3) {
-        self.n[0][0] = self.n[0][0] * other[(0, 0)]
-            + self.n[1][0] * other[(1, 0)]
-            + self.n[2][0] * other[(2, 0)];
-        self.n[1][0] = self.n[0][0] * other[(0, 1)]
-            + self.n[1][0] * other[(1, 1)]
-            + self.n[2][0] * other[(2, 1)];
-        self.n[2][0] = self.n[0][0] * other[(0, 2)]
-            + self.n[1][0] * other[(1, 2)]
-            + self.n[2][0] * other[(2, 2)];
-        self.n[1][0] = self.n[0][1] * other[(0, 0)]
-            + self.n[1][1] * other[(1, 0)]
-            + self.n[2][1] * other[(2, 0)];
-        self.n[1][1] = self.n[0][1] * other[(0, 1)]
-            + self.n[1][1] * other[(1, 1)]
-            + self.n[2][1] * other[(2, 1)];
-        self.n[1][2] = self.n[0][1] * other[(0, 2)]
-            + self.n[1][1] * other[(1, 2)]
-            + self.n[2][1] * other[(2, 2)];
-        self.n[2][0] = self.n[0][2] * other[(0, 0)]
-            + self.n[1][2] * other[(1, 0)]
-            + self.n[2][2] * other[(2, 0)];
-        self.n[2][1] = self.n[1][2] * other[(0, 1)]
-            + self.n[1][2] * other[(1, 1)]
-            + self.n[2][2] * other[(2, 1)];
-        self.n[2][2] = self.n[1][2] * other[(0, 2)]
-            + self.n[1][2] * other[(1, 2)]
-            + self.n[2][2] * other[(2, 2)];
+        *self = *self * other;
     }
 }
 
@@ -533,3 +912,109 @@ impl DivAssign<f32> for Matrix3 {
         self.n[2][2] *= other;
     }
 }
+
+impl Add for Matrix3 {
+    type Output = Self;
+
+    fn add(self, rhs: Matrix3) -> Self::Output {
+        Self {
+            n: core::array::from_fn(|i| self.n[i] + rhs.n[i]),
+        }
+    }
+}
+
+impl Sub for Matrix3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Matrix3) -> Self::Output {
+        Self {
+            n: core::array::from_fn(|i| self.n[i] - rhs.n[i]),
+        }
+    }
+}
+
+impl AddAssign for Matrix3 {
+    fn add_assign(&mut self, rhs: Matrix3) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Matrix3 {
+    fn sub_assign(&mut self, rhs: Matrix3) {
+        *self = *self - rhs;
+    }
+}
+
+#[test]
+fn euler_round_trip() {
+    let orders = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+    ];
+    let close = |a: &Matrix3, b: &Matrix3| (0..3).all(|i| (a[i] - b[i]).magnitude() < 1e-5);
+    for order in orders {
+        for (x, y, z) in [(0.3, -0.4, 2.0), (-2.5, 1.2, -0.7), (0.5, 1.5707964, -0.2)] {
+            let m = Matrix3::from_euler(order, x, y, z);
+            let (a, b, c) = m.to_euler(order);
+            assert!(close(&Matrix3::from_euler(order, a, b, c), &m));
+        }
+        // Gimbal lock: the middle rotation is a quarter turn.
+        let mut angles = [0.4, -0.9, 1.3];
+        angles[order.axes()[1]] = -core::f32::consts::FRAC_PI_2;
+        let m = Matrix3::from_euler(order, angles[0], angles[1], angles[2]);
+        let (a, b, c) = m.to_euler(order);
+        assert!(close(&Matrix3::from_euler(order, a, b, c), &m));
+    }
+}
+
+#[test]
+fn determinant() {
+    let m = Matrix3::new(2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 4.0);
+    assert!(m.determinant() == 18.0);
+}
+
+#[test]
+fn identity_matrix() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert!(Matrix3::identity() * v == v);
+}
+
+#[test]
+fn axis_rotations() {
+    let (s, c) = (0.5f32.sin(), 0.5f32.cos());
+    let y = Matrix3::make_rotation_y(0.5) * Vector3::new(1.0, 0.0, 1.0);
+    assert!((y - Vector3::new(c + s, 0.0, c - s)).magnitude() < 1e-6);
+    let z = Matrix3::make_rotation_z(0.5) * Vector3::new(1.0, 1.0, 0.0);
+    assert!((z - Vector3::new(c - s, s + c, 0.0)).magnitude() < 1e-6);
+}
+
+#[test]
+fn skew() {
+    // Skewing x by y at 45 degrees moves points along x by their height.
+    let (x, y) = (Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    let m = Matrix3::make_skew(core::f32::consts::FRAC_PI_4, &x, &y);
+    assert!((m * x - x).magnitude() < 1e-6);
+    assert!((m * Vector3::new(0.0, 2.0, 3.0) - Vector3::new(2.0, 2.0, 3.0)).magnitude() < 1e-6);
+}
+
+#[test]
+fn product() {
+    let a = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+    let b = Matrix3::new(2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 4.0);
+    let v = Vector3::new(1.0, -2.0, 3.0);
+    assert!((a * b) * v == a * (b * v));
+}
+
+#[test]
+fn mul_assign() {
+    let a = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+    let b = Matrix3::new(2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 4.0);
+    let v = Vector3::new(1.0, -2.0, 3.0);
+    let mut c = a;
+    c *= b;
+    assert!(c * v == (a * b) * v);
+}
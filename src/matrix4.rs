@@ -1,13 +1,32 @@
 use crate::prelude::*;
-use std::ops::{Div, DivAssign, Index, IndexMut, Mul, MulAssign};
+#[cfg(feature = "simd")]
+use crate::simd;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
-#[derive(Default, Copy, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+#[cfg_attr(feature = "simd", repr(align(16)))]
 pub struct Matrix4 {
     n: [Vector4; 4],
 }
 
 impl Matrix4 {
-    pub fn new(
+    /// Returns a matrix initialized with the sixteen entries supplied in
+    /// row-major order, so that the first four arguments form the first row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// let m = Matrix4::new(
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// );
+    /// assert!(m.at(0, 3) == 4.0 && m.at(3, 0) == 13.0);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
         a: f32,
         b: f32,
         c: f32,
@@ -34,12 +53,14 @@ impl Matrix4 {
         Self { n }
     }
 
-    fn new_with_vecs(a: Vector4, b: Vector4, c: Vector4, d: Vector4) -> Matrix4 {
+    /// Returns a matrix with the columns a, b, c and d.
+    pub fn new_with_vecs(a: Vector4, b: Vector4, c: Vector4, d: Vector4) -> Matrix4 {
         let n: [Vector4; 4] = [a, b, c, d];
         Self { n }
     }
 
-    fn at(&self, i: usize, j: usize) -> f32 {
+    /// Returns the entry at row i and column j.
+    pub fn at(&self, i: usize, j: usize) -> f32 {
         self[j][i]
     }
 
@@ -47,17 +68,43 @@ impl Matrix4 {
         Vector3::new(self[i].x, self[i].y, self[i].z)
     }
 
-    fn vec_at(&self, i: usize) -> Vector4 {
+    /// Returns the column at index i.
+    pub fn vec_at(&self, i: usize) -> Vector4 {
         self[i]
     }
 
-    fn determinant(&self) -> f32 {
-        (self.n[0][0] * self.n[1][1] * self.n[2][2] - self.n[2][1] * self.n[1][2])
-            - self.n[1][0] * (self.n[0][1] * self.n[2][2] - self.n[2][1] * self.n[2][0])
-            + self.n[0][2] * (self.n[0][1] * self.n[1][2] - self.n[1][1] * self.n[0][2])
+    /// Returns the determinant of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// let m = Matrix4::new(
+    ///     2.0, 0.0, 0.0, 1.0, 0.0, 3.0, 0.0, 0.0, 0.0, 0.0, 4.0, 0.0, 1.0, 0.0, 0.0, 5.0,
+    /// );
+    /// assert!(m.determinant() == 108.0);
+    /// ```
+    pub fn determinant(&self) -> f32 {
+        let a = self.vec3_at(0);
+        let b = self.vec3_at(1);
+        let c = self.vec3_at(2);
+        let d = self.vec3_at(3);
+
+        let x = self.n[0][3];
+        let y = self.n[1][3];
+        let z = self.n[2][3];
+        let w = self.n[3][3];
+
+        let s = a.cross(&b);
+        let t = c.cross(&d);
+        let u = a * y - b * x;
+        let v = c * w - d * z;
+        s.dot(&v) + t.dot(&u)
     }
 
-    fn inverse(&self) -> Matrix4 {
+    /// Returns the inverse of this matrix, computed from its cofactors. The
+    /// matrix must be invertible.
+    pub fn inverse(&self) -> Matrix4 {
         let a = self.vec3_at(0);
         let b = self.vec3_at(1);
         let c = self.vec3_at(2);
@@ -102,7 +149,117 @@ impl Matrix4 {
         )
     }
 
-    fn transpose(&self) -> Matrix4 {
+    /// Returns the inverse of this matrix, or None if it is singular. This is
+    /// the same as `Matrix4::inverse_checked`, and unlike `inverse` it never
+    /// returns a matrix of infinities or NaNs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// let ramp = Matrix4::new(
+    ///     0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2, 1.3, 1.4, 1.5, 1.6,
+    /// );
+    /// assert!(ramp.try_inverse().is_none());
+    /// ```
+    pub fn try_inverse(&self) -> Option<Matrix4> {
+        self.inverse_checked()
+    }
+
+    /// Returns the inverse of this matrix, or None if it is singular, as for
+    /// `try_inverse`, or if the absolute value of the determinant is not
+    /// greater than epsilon.
+    ///
+    /// # Arguments
+    ///
+    /// * `epsilon` - The largest determinant treated as singular.
+    pub fn try_inverse_epsilon(&self, epsilon: f32) -> Option<Matrix4> {
+        if self.determinant().abs() > epsilon {
+            self.inverse_checked()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the inverse of this matrix, or None if it is singular.
+    ///
+    /// The inverse is found from an LU decomposition with scaled partial
+    /// pivoting: each pivot is the entry largest relative to the rest of its
+    /// row, so the result stays accurate for badly scaled matrices where
+    /// `inverse` loses precision or overflows. The matrix is treated as
+    /// singular when the best relative pivot falls below `f32::EPSILON`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// let m = Matrix4::make_scale(1.0e15, 1.0e-15, 1.0e15);
+    /// let inv = m.inverse_checked().unwrap();
+    /// assert!(inv == Matrix4::make_scale(1.0e-15, 1.0e15, 1.0e-15));
+    /// assert!(Matrix4::make_scale(1.0, 0.0, 1.0).inverse_checked().is_none());
+    /// ```
+    #[allow(clippy::needless_range_loop)]
+    pub fn inverse_checked(&self) -> Option<Matrix4> {
+        // The rows of the matrix, overwritten by L below the diagonal (with
+        // an implicit unit diagonal) and by U on and above it.
+        let mut a = [self.row(0), self.row(1), self.row(2), self.row(3)];
+        let mut perm = [0, 1, 2, 3];
+        let mut scale = [0.0; 4];
+        for (s, r) in scale.iter_mut().zip(a.iter()) {
+            *s = r.x.abs().max(r.y.abs()).max(r.z.abs()).max(r.w.abs());
+            if *s == 0.0 {
+                return None;
+            }
+        }
+
+        for k in 0..4 {
+            let mut p = k;
+            let mut best = 0.0;
+            for r in k..4 {
+                let v = a[r][k].abs() / scale[r];
+                if v > best {
+                    best = v;
+                    p = r;
+                }
+            }
+            if best <= f32::EPSILON {
+                return None;
+            }
+            a.swap(p, k);
+            perm.swap(p, k);
+            scale.swap(p, k);
+            for r in k + 1..4 {
+                let f = a[r][k] / a[k][k];
+                a[r][k] = f;
+                for c in k + 1..4 {
+                    a[r][c] -= f * a[k][c];
+                }
+            }
+        }
+
+        // Solve L U x = P e_j for each column j of the inverse.
+        let mut inv = Matrix4::default();
+        for j in 0..4 {
+            let mut x = Vector4::default();
+            for i in 0..4 {
+                x[i] = if perm[i] == j { 1.0 } else { 0.0 };
+                for c in 0..i {
+                    x[i] -= a[i][c] * x[c];
+                }
+            }
+            for i in (0..4).rev() {
+                for c in i + 1..4 {
+                    x[i] -= a[i][c] * x[c];
+                }
+                x[i] /= a[i][i];
+            }
+            inv[j] = x;
+        }
+        Some(inv)
+    }
+
+    /// Returns the transpose of this matrix.
+    pub fn transpose(&self) -> Matrix4 {
         Self::new(
             self.n[0][0],
             self.n[0][1],
@@ -123,11 +280,577 @@ impl Matrix4 {
         )
     }
 
-    fn identity() -> Matrix4 {
+    /// Returns the 4x4 identity matrix.
+    pub const fn identity() -> Matrix4 {
         Self::new(
             1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
         )
     }
+
+    /// Sets this matrix to the identity.
+    pub fn set_identity(&mut self) {
+        *self = Self::identity();
+    }
+
+    /// Returns the trace of this matrix, the sum of its diagonal entries.
+    pub fn trace(&self) -> f32 {
+        self.n[0][0] + self.n[1][1] + self.n[2][2] + self.n[3][3]
+    }
+
+    /// Returns the row at index i.
+    pub fn row(&self, i: usize) -> Vector4 {
+        Vector4::new(self.n[0][i], self.n[1][i], self.n[2][i], self.n[3][i])
+    }
+
+    /// Returns the rotation through the angle a about the x axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle, as `Radians`, `Degrees` or an `f32` in radians.
+    pub fn make_rotation_x(a: impl Into<Radians>) -> Matrix4 {
+        Self::from(Matrix3::make_rotation_x(a))
+    }
+
+    /// Returns the rotation through the angle a about the y axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle, as `Radians`, `Degrees` or an `f32` in radians.
+    pub fn make_rotation_y(a: impl Into<Radians>) -> Matrix4 {
+        Self::from(Matrix3::make_rotation_y(a))
+    }
+
+    /// Returns the rotation through the angle a about the z axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle, as `Radians`, `Degrees` or an `f32` in radians.
+    pub fn make_rotation_z(a: impl Into<Radians>) -> Matrix4 {
+        Self::from(Matrix3::make_rotation_z(a))
+    }
+
+    /// Returns the rotation through the angle a about the unit vector v.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle, as `Radians`, `Degrees` or an `f32` in radians.
+    /// * `v` - The unit length axis of rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::unit_vector3::UnitVector3;
+    /// let m = Matrix4::make_rotation(0.3, &UnitVector3::y_axis());
+    /// assert!(m == Matrix4::make_rotation_y(0.3));
+    /// ```
+    pub fn make_rotation(a: impl Into<Radians>, v: &UnitVector3) -> Matrix4 {
+        Self::from(Matrix3::make_rotation(a, v))
+    }
+
+    /// Returns the scale by sx, sy and sz along the x, y and z axes.
+    pub fn make_scale(sx: f32, sy: f32, sz: f32) -> Matrix4 {
+        Self::new(
+            sx, 0.0, 0.0, 0.0, 0.0, sy, 0.0, 0.0, 0.0, 0.0, sz, 0.0, 0.0, 0.0, 0.0, 1.0,
+        )
+    }
+
+    /// Returns the scale by sx along the x axis.
+    pub fn make_scale_x(sx: f32) -> Matrix4 {
+        Self::make_scale(sx, 1.0, 1.0)
+    }
+
+    /// Returns the scale by sy along the y axis.
+    pub fn make_scale_y(sy: f32) -> Matrix4 {
+        Self::make_scale(1.0, sy, 1.0)
+    }
+
+    /// Returns the scale by sz along the z axis.
+    pub fn make_scale_z(sz: f32) -> Matrix4 {
+        Self::make_scale(1.0, 1.0, sz)
+    }
+
+    /// Returns the translation by v.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// use math_engine::vector4::Vector4;
+    /// let m = Matrix4::make_translation(&Vector3::new(1.0, 2.0, 3.0));
+    /// assert!(m * Point3::new(1.0, 1.0, 1.0) == Vector4::new(2.0, 3.0, 4.0, 1.0));
+    /// ```
+    pub fn make_translation(v: &Vector3) -> Matrix4 {
+        Self::new(
+            1.0, 0.0, 0.0, v.x, 0.0, 1.0, 0.0, v.y, 0.0, 0.0, 1.0, v.z, 0.0, 0.0, 0.0, 1.0,
+        )
+    }
+
+    /// Returns a right-handed view matrix for a camera at eye looking toward
+    /// target. The camera looks down its negative z axis with up along
+    /// positive y, as in OpenGL.
+    ///
+    /// # Arguments
+    ///
+    /// * `eye` - The position of the camera.
+    /// * `target` - The point the camera looks at.
+    /// * `up` - The approximate up direction, not parallel to target - eye.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// use math_engine::vector4::Vector4;
+    /// let eye = Point3::new(0.0, 0.0, 5.0);
+    /// let m = Matrix4::look_at_rh(&eye, &Point3::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 1.0, 0.0));
+    /// assert!(m * Point3::new(0.0, 0.0, 0.0) == Vector4::new(0.0, 0.0, -5.0, 1.0));
+    /// ```
+    pub fn look_at_rh(eye: &Point3, target: &Point3, up: &Vector3) -> Matrix4 {
+        let f = (*target - *eye).normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(&f);
+        Self::view(&s, &u, &-f, eye)
+    }
+
+    /// Returns a left-handed view matrix for a camera at eye looking toward
+    /// target. The camera looks down its positive z axis with up along
+    /// positive y, as in Direct3D.
+    ///
+    /// # Arguments
+    ///
+    /// * `eye` - The position of the camera.
+    /// * `target` - The point the camera looks at.
+    /// * `up` - The approximate up direction, not parallel to target - eye.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// use math_engine::vector4::Vector4;
+    /// let eye = Point3::new(0.0, 0.0, 5.0);
+    /// let m = Matrix4::look_at_lh(&eye, &Point3::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 1.0, 0.0));
+    /// assert!(m * Point3::new(0.0, 0.0, 0.0) == Vector4::new(0.0, 0.0, 5.0, 1.0));
+    /// ```
+    pub fn look_at_lh(eye: &Point3, target: &Point3, up: &Vector3) -> Matrix4 {
+        let f = (*target - *eye).normalize();
+        let s = up.cross(&f).normalize();
+        let u = f.cross(&s);
+        Self::view(&s, &u, &f, eye)
+    }
+
+    /// Returns a right-handed perspective projection with a vertical field of
+    /// view fovy in radians, mapping depth from near to far onto [0, 1] as in
+    /// Vulkan, Direct3D and wgpu.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::point3::Point3;
+    /// let m = Matrix4::perspective_rh(1.0, 1.5, 1.0, 100.0);
+    /// let v = m * Point3::new(0.0, 0.0, -1.0);
+    /// assert!(v.z / v.w == 0.0);
+    /// ```
+    pub fn perspective_rh(fovy: f32, aspect: f32, near: f32, far: f32) -> Matrix4 {
+        let d = far / (near - far);
+        Self::perspective(fovy, aspect, d, near * d, -1.0)
+    }
+
+    /// Returns a left-handed perspective projection mapping depth from near
+    /// to far onto [0, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    pub fn perspective_lh(fovy: f32, aspect: f32, near: f32, far: f32) -> Matrix4 {
+        let d = far / (far - near);
+        Self::perspective(fovy, aspect, d, -near * d, 1.0)
+    }
+
+    /// Returns a right-handed perspective projection with reversed depth,
+    /// mapping the near plane to 1 and the far plane to 0. Paired with a
+    /// floating point depth buffer cleared to 0 and a greater-than depth test
+    /// this spreads precision far more evenly than the classic form.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::point3::Point3;
+    /// let m = Matrix4::perspective_reverse_rh(1.0, 1.5, 1.0, 100.0);
+    /// let v = m * Point3::new(0.0, 0.0, -1.0);
+    /// assert!((v.z / v.w - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn perspective_reverse_rh(fovy: f32, aspect: f32, near: f32, far: f32) -> Matrix4 {
+        let d = near / (far - near);
+        Self::perspective(fovy, aspect, d, far * d, -1.0)
+    }
+
+    /// Returns a left-handed perspective projection with reversed depth,
+    /// mapping the near plane to 1 and the far plane to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    pub fn perspective_reverse_lh(fovy: f32, aspect: f32, near: f32, far: f32) -> Matrix4 {
+        let d = near / (far - near);
+        Self::perspective(fovy, aspect, -d, far * d, 1.0)
+    }
+
+    /// Returns a right-handed perspective projection with the far plane at
+    /// infinity, mapping the near plane to 0 and infinity to 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    pub fn perspective_infinite_rh(fovy: f32, aspect: f32, near: f32) -> Matrix4 {
+        Self::perspective(fovy, aspect, -1.0, -near, -1.0)
+    }
+
+    /// Returns a left-handed perspective projection with the far plane at
+    /// infinity, mapping the near plane to 0 and infinity to 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    pub fn perspective_infinite_lh(fovy: f32, aspect: f32, near: f32) -> Matrix4 {
+        Self::perspective(fovy, aspect, 1.0, -near, 1.0)
+    }
+
+    /// Returns a right-handed perspective projection with reversed depth and
+    /// the far plane at infinity, mapping the near plane to 1 and infinity to
+    /// 0. This is the usual choice for reversed-Z rendering.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::point3::Point3;
+    /// let m = Matrix4::perspective_infinite_reverse_rh(1.0, 1.5, 0.1);
+    /// let v = m * Point3::new(0.0, 0.0, -0.1);
+    /// assert!(v.z / v.w == 1.0);
+    /// let v = m * Point3::new(0.0, 0.0, -1.0e30);
+    /// assert!(v.z / v.w < 1.0e-6);
+    /// ```
+    pub fn perspective_infinite_reverse_rh(fovy: f32, aspect: f32, near: f32) -> Matrix4 {
+        Self::perspective(fovy, aspect, 0.0, near, -1.0)
+    }
+
+    /// Returns a left-handed perspective projection with reversed depth and
+    /// the far plane at infinity, mapping the near plane to 1 and infinity to
+    /// 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `fovy` - The vertical field of view in radians.
+    /// * `aspect` - The width of the viewport divided by its height.
+    /// * `near` - The distance to the near plane.
+    pub fn perspective_infinite_reverse_lh(fovy: f32, aspect: f32, near: f32) -> Matrix4 {
+        Self::perspective(fovy, aspect, 0.0, near, 1.0)
+    }
+
+    /// Returns a right-handed orthographic projection of the box bounded by
+    /// left, right, bottom, top and the near and far distances, mapping depth
+    /// onto [0, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The x coordinate of the left plane.
+    /// * `right` - The x coordinate of the right plane.
+    /// * `bottom` - The y coordinate of the bottom plane.
+    /// * `top` - The y coordinate of the top plane.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector4::Vector4;
+    /// let m = Matrix4::orthographic_rh(-2.0, 2.0, -1.0, 1.0, 0.0, 10.0);
+    /// assert!(m * Point3::new(2.0, -1.0, -10.0) == Vector4::new(1.0, -1.0, 1.0, 1.0));
+    /// ```
+    pub fn orthographic_rh(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Matrix4 {
+        let d = 1.0 / (near - far);
+        Self::orthographic(left, right, bottom, top, d, near * d)
+    }
+
+    /// Returns a left-handed orthographic projection mapping depth onto
+    /// [0, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The x coordinate of the left plane.
+    /// * `right` - The x coordinate of the right plane.
+    /// * `bottom` - The y coordinate of the bottom plane.
+    /// * `top` - The y coordinate of the top plane.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    pub fn orthographic_lh(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Matrix4 {
+        let d = 1.0 / (far - near);
+        Self::orthographic(left, right, bottom, top, d, -near * d)
+    }
+
+    /// Returns a right-handed orthographic projection with reversed depth,
+    /// mapping the near plane to 1 and the far plane to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The x coordinate of the left plane.
+    /// * `right` - The x coordinate of the right plane.
+    /// * `bottom` - The y coordinate of the bottom plane.
+    /// * `top` - The y coordinate of the top plane.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    pub fn orthographic_reverse_rh(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Matrix4 {
+        let d = 1.0 / (far - near);
+        Self::orthographic(left, right, bottom, top, d, far * d)
+    }
+
+    /// Returns a left-handed orthographic projection with reversed depth,
+    /// mapping the near plane to 1 and the far plane to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The x coordinate of the left plane.
+    /// * `right` - The x coordinate of the right plane.
+    /// * `bottom` - The y coordinate of the bottom plane.
+    /// * `top` - The y coordinate of the top plane.
+    /// * `near` - The distance to the near plane.
+    /// * `far` - The distance to the far plane.
+    pub fn orthographic_reverse_lh(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Matrix4 {
+        let d = 1.0 / (far - near);
+        Self::orthographic(left, right, bottom, top, -d, far * d)
+    }
+
+    // Returns the perspective projection whose depth row is (0 0 a b) and
+    // whose last row is (0 0 w 0), w being -1 for right-handed and 1 for
+    // left-handed view spaces.
+    fn perspective(fovy: f32, aspect: f32, a: f32, b: f32, w: f32) -> Matrix4 {
+        let f = 1.0 / (fovy * 0.5).tan();
+        Self::new(
+            f / aspect,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            f,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            a,
+            b,
+            0.0,
+            0.0,
+            w,
+            0.0,
+        )
+    }
+
+    // Returns the orthographic projection whose depth row is (0 0 a b).
+    fn orthographic(left: f32, right: f32, bottom: f32, top: f32, a: f32, b: f32) -> Matrix4 {
+        let w = 1.0 / (right - left);
+        let h = 1.0 / (top - bottom);
+        Self::new(
+            2.0 * w,
+            0.0,
+            0.0,
+            -(right + left) * w,
+            0.0,
+            2.0 * h,
+            0.0,
+            -(top + bottom) * h,
+            0.0,
+            0.0,
+            a,
+            b,
+            0.0,
+            0.0,
+            0.0,
+            1.0,
+        )
+    }
+
+    // Returns the matrix whose rows are the camera axes x, y and z, moving
+    // the eye to the origin.
+    fn view(x: &Vector3, y: &Vector3, z: &Vector3, eye: &Point3) -> Matrix4 {
+        let e = Vector3::from(*eye);
+        Self::new(
+            x.x,
+            x.y,
+            x.z,
+            -x.dot(&e),
+            y.x,
+            y.y,
+            y.z,
+            -y.dot(&e),
+            z.x,
+            z.y,
+            z.z,
+            -z.dot(&e),
+            0.0,
+            0.0,
+            0.0,
+            1.0,
+        )
+    }
+
+    /// Returns the six clipping planes of the frustum described by this
+    /// projection or view-projection matrix, using the Gribb–Hartmann
+    /// method. The planes are normalized, their normals point into the
+    /// frustum and they are ordered left, right, bottom, top, near, far as in
+    /// `Frustum`. The matrix is expected to map depth onto [0, 1]; with a
+    /// reversed depth projection the near and far planes trade places. A far
+    /// plane at infinity is returned as (0, 0, 0, w) with w positive, which
+    /// every point lies in front of.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::point3::Point3;
+    /// let m = Matrix4::perspective_rh(core::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+    /// let planes = m.extract_frustum_planes();
+    /// assert!(planes[4].point_dot(&Point3::new(0.0, 0.0, -1.0)).abs() < 1e-6);
+    /// assert!(planes[5].point_dot(&Point3::new(0.0, 0.0, -10.0)).abs() < 1e-5);
+    /// assert!(planes.iter().all(|f| f.point_dot(&Point3::new(0.0, 0.0, -5.0)) > 0.0));
+    /// ```
+    pub fn extract_frustum_planes(&self) -> [Plane; 6] {
+        let row = |i: usize| Vector4::new(self[(i, 0)], self[(i, 1)], self[(i, 2)], self[(i, 3)]);
+        let (r0, r1, r2, r3) = (row(0), row(1), row(2), row(3));
+        let plane = |v: Vector4| {
+            let f = Plane::new(v.x, v.y, v.z, v.w);
+            if f.get_normal().magnitude() > 0.0 {
+                f.normalize()
+            } else {
+                f
+            }
+        };
+        [
+            plane(r3 + r0),
+            plane(r3 - r0),
+            plane(r3 + r1),
+            plane(r3 - r1),
+            plane(r2),
+            plane(r3 - r2),
+        ]
+    }
+
+    /// Transforms every vector in vs in place by this matrix. With the
+    /// `simd` feature enabled the columns stay in registers across the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `vs` - The vectors to transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::vector4::Vector4;
+    /// let m = Matrix4::identity() * 2.0;
+    /// let mut vs = [Vector4::new(1.0, 2.0, 3.0, 1.0); 8];
+    /// m.transform_slice(&mut vs);
+    /// assert!(vs[7] == Vector4::new(2.0, 4.0, 6.0, 2.0));
+    /// ```
+    pub fn transform_slice(&self, vs: &mut [Vector4]) {
+        #[cfg(feature = "simd")]
+        simd::mul_vec_slice(&self.n, vs);
+        #[cfg(not(feature = "simd"))]
+        for v in vs.iter_mut() {
+            *v = *self * *v;
+        }
+    }
+}
+
+impl From<Matrix3> for Matrix4 {
+    /// Returns the 4x4 matrix with m as its upper left 3x3 block.
+    fn from(m: Matrix3) -> Self {
+        Matrix4::new_with_vecs(
+            Vector4::new(m[0].x, m[0].y, m[0].z, 0.0),
+            Vector4::new(m[1].x, m[1].y, m[1].z, 0.0),
+            Vector4::new(m[2].x, m[2].y, m[2].z, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+}
+
+impl From<Transform4> for Matrix4 {
+    /// Returns the 4x4 matrix of h, with the fourth row [0 0 0 1].
+    fn from(h: Transform4) -> Self {
+        Matrix4::new_with_vecs(
+            Vector4::new(h[0].x, h[0].y, h[0].z, 0.0),
+            Vector4::new(h[1].x, h[1].y, h[1].z, 0.0),
+            Vector4::new(h[2].x, h[2].y, h[2].z, 0.0),
+            Vector4::new(h[3].x, h[3].y, h[3].z, 1.0),
+        )
+    }
 }
 
 impl Index<(usize, usize)> for Matrix4 {
@@ -173,6 +896,16 @@ impl Mul<Vector3> for Matrix4 {
     }
 }
 
+impl Neg for Matrix4 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            n: self.n.map(Neg::neg),
+        }
+    }
+}
+
 impl Mul<Point3> for Matrix4 {
     type Output = Vector4;
 
@@ -224,8 +957,56 @@ impl Mul<f32> for Matrix4 {
     }
 }
 
+impl Mul<Matrix4> for f32 {
+    type Output = Matrix4;
+
+    fn mul(self, rhs: Matrix4) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<Vector4> for Matrix4 {
+    type Output = Vector4;
+
+    #[cfg(feature = "simd")]
+    fn mul(self, rhs: Vector4) -> Self::Output {
+        simd::mul_vec(&self.n, &rhs)
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn mul(self, rhs: Vector4) -> Self::Output {
+        Vector4::new(
+            self.n[0][0] * rhs.x
+                + self.n[1][0] * rhs.y
+                + self.n[2][0] * rhs.z
+                + self.n[3][0] * rhs.w,
+            self.n[0][1] * rhs.x
+                + self.n[1][1] * rhs.y
+                + self.n[2][1] * rhs.z
+                + self.n[3][1] * rhs.w,
+            self.n[0][2] * rhs.x
+                + self.n[1][2] * rhs.y
+                + self.n[2][2] * rhs.z
+                + self.n[3][2] * rhs.w,
+            self.n[0][3] * rhs.x
+                + self.n[1][3] * rhs.y
+                + self.n[2][3] * rhs.z
+                + self.n[3][3] * rhs.w,
+        )
+    }
+}
+
 impl Mul<Matrix4> for Matrix4 {
     type Output = Self;
+
+    #[cfg(feature = "simd")]
+    fn mul(self, rhs: Matrix4) -> Self::Output {
+        Self {
+            n: simd::mul_cols(&self.n, &rhs.n),
+        }
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn mul(self, rhs: Matrix4) -> Self::Output {
         Self::new(
             self.n[0][0] * rhs[(0, 0)]
@@ -298,70 +1079,7 @@ impl Mul<Matrix4> for Matrix4 {
 
 impl MulAssign<Matrix4> for Matrix4 {
     fn mul_assign(&mut self, rhs: Matrix4) {
-        self.n[0][0] = self.n[0][0] * rhs[(0, 0)]
-            + self.n[1][0] * rhs[(1, 0)]
-            + self.n[2][0] * rhs[(2, 0)]
-            + self.n[3][0] * rhs[(3, 0)];
-        self.n[1][0] = self.n[0][0] * rhs[(0, 1)]
-            + self.n[1][0] * rhs[(1, 1)]
-            + self.n[2][0] * rhs[(2, 1)]
-            + self.n[3][0] * rhs[(3, 1)];
-        self.n[2][0] = self.n[0][0] * rhs[(0, 2)]
-            + self.n[1][0] * rhs[(1, 2)]
-            + self.n[2][0] * rhs[(2, 2)]
-            + self.n[3][0] * rhs[(3, 2)];
-        self.n[3][0] = self.n[0][0] * rhs[(0, 3)]
-            + self.n[1][0] * rhs[(1, 3)]
-            + self.n[2][0] * rhs[(2, 3)]
-            + self.n[3][0] * rhs[(3, 3)];
-        self.n[0][1] = self.n[0][1] * rhs[(0, 0)]
-            + self.n[1][1] * rhs[(1, 0)]
-            + self.n[2][1] * rhs[(2, 0)]
-            + self.n[3][1] * rhs[(3, 0)];
-        self.n[1][1] = self.n[0][1] * rhs[(0, 1)]
-            + self.n[1][1] * rhs[(1, 1)]
-            + self.n[2][1] * rhs[(2, 1)]
-            + self.n[3][1] * rhs[(3, 1)];
-        self.n[2][1] = self.n[0][1] * rhs[(0, 2)]
-            + self.n[1][1] * rhs[(1, 2)]
-            + self.n[2][1] * rhs[(2, 2)]
-            + self.n[3][1] * rhs[(3, 2)];
-        self.n[3][1] = self.n[0][1] * rhs[(0, 3)]
-            + self.n[1][1] * rhs[(1, 3)]
-            + self.n[2][1] * rhs[(2, 3)]
-            + self.n[3][1] * rhs[(3, 3)];
-        self.n[0][2] = self.n[0][2] * rhs[(0, 0)]
-            + self.n[1][2] * rhs[(1, 0)]
-            + self.n[2][2] * rhs[(2, 0)]
-            + self.n[3][2] * rhs[(3, 0)];
-        self.n[1][2] = self.n[0][2] * rhs[(0, 1)]
-            + self.n[1][2] * rhs[(1, 1)]
-            + self.n[2][2] * rhs[(2, 1)]
-            + self.n[3][2] * rhs[(3, 1)];
-        self.n[2][2] = self.n[0][2] * rhs[(0, 2)]
-            + self.n[1][2] * rhs[(1, 2)]
-            + self.n[2][2] * rhs[(2, 2)]
-            + self.n[3][2] * rhs[(3, 2)];
-        self.n[3][2] = self.n[0][2] * rhs[(0, 3)]
-            + self.n[1][2] * rhs[(1, 3)]
-            + self.n[2][2] * rhs[(2, 3)]
-            + self.n[3][2] * rhs[(3, 3)];
-        self.n[0][3] = self.n[0][3] * rhs[(0, 0)]
-            + self.n[1][3] * rhs[(1, 0)]
-            + self.n[2][3] * rhs[(2, 0)]
-            + self.n[3][3] * rhs[(3, 0)];
-        self.n[1][3] = self.n[0][3] * rhs[(0, 1)]
-            + self.n[1][3] * rhs[(1, 1)]
-            + self.n[2][3] * rhs[(2, 1)]
-            + self.n[3][3] * rhs[(3, 1)];
-        self.n[2][3] = self.n[0][3] * rhs[(0, 2)]
-            + self.n[1][3] * rhs[(1, 2)]
-            + self.n[2][3] * rhs[(2, 2)]
-            + self.n[3][3] * rhs[(3, 2)];
-        self.n[3][3] = self.n[0][3] * rhs[(0, 3)]
-            + self.n[1][3] * rhs[(1, 3)]
-            + self.n[2][3] * rhs[(2, 3)]
-            + self.n[3][3] * rhs[(3, 3)];
+        *self = *self * rhs;
     }
 }
 
@@ -392,22 +1110,22 @@ impl Div<f32> for Matrix4 {
     fn div(self, rhs: f32) -> Self::Output {
         let s = 1.0 / rhs;
         Self::new(
-            self.n[0][0] / s,
-            self.n[1][0] / s,
-            self.n[2][0] / s,
-            self.n[3][0] / s,
-            self.n[0][1] / s,
-            self.n[1][1] / s,
-            self.n[2][1] / s,
-            self.n[3][1] / s,
-            self.n[0][2] / s,
-            self.n[1][2] / s,
-            self.n[2][2] / s,
-            self.n[3][2] / s,
-            self.n[0][3] / s,
-            self.n[1][3] / s,
-            self.n[2][3] / s,
-            self.n[3][3] / s,
+            self.n[0][0] * s,
+            self.n[1][0] * s,
+            self.n[2][0] * s,
+            self.n[3][0] * s,
+            self.n[0][1] * s,
+            self.n[1][1] * s,
+            self.n[2][1] * s,
+            self.n[3][1] * s,
+            self.n[0][2] * s,
+            self.n[1][2] * s,
+            self.n[2][2] * s,
+            self.n[3][2] * s,
+            self.n[0][3] * s,
+            self.n[1][3] * s,
+            self.n[2][3] * s,
+            self.n[3][3] * s,
         )
     }
 }
@@ -432,3 +1150,271 @@ impl DivAssign<f32> for Matrix4 {
         self.n[3][3] /= rhs;
     }
 }
+
+impl Add for Matrix4 {
+    type Output = Self;
+
+    fn add(self, rhs: Matrix4) -> Self::Output {
+        Self {
+            n: core::array::from_fn(|i| self.n[i] + rhs.n[i]),
+        }
+    }
+}
+
+impl Sub for Matrix4 {
+    type Output = Self;
+
+    fn sub(self, rhs: Matrix4) -> Self::Output {
+        Self {
+            n: core::array::from_fn(|i| self.n[i] - rhs.n[i]),
+        }
+    }
+}
+
+impl AddAssign for Matrix4 {
+    fn add_assign(&mut self, rhs: Matrix4) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Matrix4 {
+    fn sub_assign(&mut self, rhs: Matrix4) {
+        *self = *self - rhs;
+    }
+}
+
+#[test]
+fn mul() {
+    let a = Matrix4::new(
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    );
+    let b = a.transpose();
+    let want = Matrix4::new(
+        30.0, 70.0, 110.0, 150.0, 70.0, 174.0, 278.0, 382.0, 110.0, 278.0, 446.0, 614.0, 150.0,
+        382.0, 614.0, 846.0,
+    );
+    let c = a * b;
+    for i in 0..4 {
+        assert!(c[i] == want[i]);
+    }
+    assert!(a * Vector4::new(1.0, 0.0, 0.0, 1.0) == Vector4::new(5.0, 13.0, 21.0, 29.0));
+}
+
+#[test]
+fn layout() {
+    use core::mem::size_of;
+    assert!(size_of::<Vector2>() == 8 && size_of::<Vector3>() == 12);
+    assert!(size_of::<Vector4>() == 16 && size_of::<Point3>() == 12);
+    assert!(size_of::<Matrix3>() == 36 && size_of::<Matrix4>() == 64);
+    assert!(size_of::<Transform4>() == 48 && size_of::<Quarternion>() == 16);
+    assert!(size_of::<RGB>() == 12 && size_of::<RGBA>() == 16);
+    let m = Matrix4::new(
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    );
+    let a = m.to_cols_array();
+    let raw = unsafe { core::slice::from_raw_parts(m.as_ptr(), 16) };
+    assert!(raw == a);
+}
+
+#[test]
+fn projection_depth() {
+    let depth = |m: Matrix4, z: f32| {
+        let v = m * Point3::new(0.0, 0.0, z);
+        v.z / v.w
+    };
+    let close = |a: f32, b: f32| (a - b).abs() < 1e-5;
+    let (n, f) = (0.5, 50.0);
+    assert!(close(
+        depth(Matrix4::perspective_rh(1.0, 1.0, n, f), -n),
+        0.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_rh(1.0, 1.0, n, f), -f),
+        1.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_lh(1.0, 1.0, n, f), n),
+        0.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_lh(1.0, 1.0, n, f), f),
+        1.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_reverse_rh(1.0, 1.0, n, f), -n),
+        1.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_reverse_rh(1.0, 1.0, n, f), -f),
+        0.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_reverse_lh(1.0, 1.0, n, f), n),
+        1.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_reverse_lh(1.0, 1.0, n, f), f),
+        0.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_infinite_rh(1.0, 1.0, n), -n),
+        0.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_infinite_rh(1.0, 1.0, n), -1.0e7),
+        1.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_infinite_lh(1.0, 1.0, n), n),
+        0.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_infinite_lh(1.0, 1.0, n), 1.0e7),
+        1.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_infinite_reverse_lh(1.0, 1.0, n), n),
+        1.0
+    ));
+    assert!(close(
+        depth(Matrix4::perspective_infinite_reverse_lh(1.0, 1.0, n), 1.0e7),
+        0.0
+    ));
+    assert!(close(
+        depth(Matrix4::orthographic_lh(-1.0, 1.0, -1.0, 1.0, n, f), n),
+        0.0
+    ));
+    assert!(close(
+        depth(Matrix4::orthographic_lh(-1.0, 1.0, -1.0, 1.0, n, f), f),
+        1.0
+    ));
+    assert!(close(
+        depth(
+            Matrix4::orthographic_reverse_rh(-1.0, 1.0, -1.0, 1.0, n, f),
+            -n
+        ),
+        1.0
+    ));
+    assert!(close(
+        depth(
+            Matrix4::orthographic_reverse_rh(-1.0, 1.0, -1.0, 1.0, n, f),
+            -f
+        ),
+        0.0
+    ));
+    assert!(close(
+        depth(
+            Matrix4::orthographic_reverse_lh(-1.0, 1.0, -1.0, 1.0, n, f),
+            n
+        ),
+        1.0
+    ));
+    assert!(close(
+        depth(
+            Matrix4::orthographic_reverse_lh(-1.0, 1.0, -1.0, 1.0, n, f),
+            f
+        ),
+        0.0
+    ));
+
+    // A point on the top edge of the field of view lands on y = 1.
+    let m = Matrix4::perspective_rh(core::f32::consts::FRAC_PI_2, 2.0, n, f);
+    let v = m * Point3::new(4.0, 2.0, -2.0);
+    assert!(close(v.x / v.w, 1.0) && close(v.y / v.w, 1.0));
+}
+
+#[test]
+fn determinant_and_inverse() {
+    let m = Matrix4::new(
+        2.0, -1.0, 0.5, 3.0, 1.0, 4.0, -2.0, 0.0, 0.0, 1.5, 1.0, -1.0, 0.5, 0.0, 2.0, 1.0,
+    );
+    // The Laplace expansion along the first row.
+    let minor = |r: [usize; 3], c: [usize; 3]| {
+        Matrix3::new(
+            m.at(r[0], c[0]),
+            m.at(r[0], c[1]),
+            m.at(r[0], c[2]),
+            m.at(r[1], c[0]),
+            m.at(r[1], c[1]),
+            m.at(r[1], c[2]),
+            m.at(r[2], c[0]),
+            m.at(r[2], c[1]),
+            m.at(r[2], c[2]),
+        )
+        .determinant()
+    };
+    let rows = [1, 2, 3];
+    let det = m.at(0, 0) * minor(rows, [1, 2, 3]) - m.at(0, 1) * minor(rows, [0, 2, 3])
+        + m.at(0, 2) * minor(rows, [0, 1, 3])
+        - m.at(0, 3) * minor(rows, [0, 1, 2]);
+    assert!((m.determinant() - det).abs() < 1e-4);
+    let id = m * m.inverse();
+    for i in 0..4 {
+        assert!((id[i] - Matrix4::identity()[i]).magnitude() < 1e-5);
+    }
+    let h = Transform4::make_translation(&Vector3::new(1.0, -2.0, 3.0))
+        * Transform4::make_rotation_z(0.4);
+    let p = Point3::new(0.5, 1.0, -1.5);
+    let q = h * p;
+    assert!(Matrix4::from(h) * p == Vector4::new(q.x, q.y, q.z, 1.0));
+}
+
+#[test]
+fn inverse_checked() {
+    let m = Matrix4::new(
+        0.0, 2.0, 1.0, 4.0, 1.0, 0.0, 3.0, -1.0, 2.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0,
+    );
+    let inv = m.inverse_checked().unwrap();
+    let id = m * inv;
+    for i in 0..4 {
+        assert!((id[i] - Matrix4::identity()[i]).magnitude() < 1e-5);
+    }
+
+    // Rows of wildly different magnitude overflow the cofactor method.
+    let d = Matrix4::make_scale(1.0e12, 1.0e-12, 1.0e12) * m;
+    let inv = d.inverse_checked().unwrap();
+    let id = d * inv;
+    for i in 0..4 {
+        assert!((id[i] - Matrix4::identity()[i]).magnitude() < 1e-4);
+    }
+
+    let mut s = m;
+    s[3] = s[0] * 2.0 - s[1];
+    assert!(s.inverse_checked().is_none());
+}
+
+#[test]
+fn determinant() {
+    // The x and w rows couple through the corner entries, which a 3x3
+    // expansion never sees.
+    let m = Matrix4::new(
+        2.0, 0.0, 0.0, 1.0, 0.0, 3.0, 0.0, 0.0, 0.0, 0.0, 4.0, 0.0, 1.0, 0.0, 0.0, 1.0,
+    );
+    assert!(m.determinant() == 12.0);
+}
+
+#[test]
+fn scalar_division() {
+    let m = Matrix4::new(
+        4.0, 0.0, 0.0, 2.0, 0.0, 4.0, 0.0, 0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+    ) / 2.0;
+    assert!(m[(0, 0)] == 2.0 && m[(0, 3)] == 1.0 && m[(3, 3)] == 0.5);
+}
+
+#[test]
+fn mul_assign() {
+    let a = Matrix4::new(
+        1.0, 2.0, 0.0, 1.0, 0.0, 1.0, 3.0, 0.0, 2.0, 0.0, 1.0, 4.0, 0.0, 0.0, 0.0, 1.0,
+    );
+    let b = Matrix4::new(
+        2.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 2.0, 0.0, 3.0, 1.0, 0.0, 1.0, 0.0, 0.0, 1.0,
+    );
+    let mut c = a;
+    c *= b;
+    let ab = a * b;
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!(c[(i, j)] == ab[(i, j)]);
+        }
+    }
+}
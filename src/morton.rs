@@ -0,0 +1,282 @@
+//! Space-filling curve codes for grid coordinates. Sorting points by their
+//! Morton (Z-order) or Hilbert code puts points that are close in space
+//! close in the sorted order, which is what BVH builders, spatial hashes and
+//! chunk streaming need for locality.
+//!
+//! Morton codes interleave the coordinate bits, x in the lowest bit, and are
+//! cheap to compute. Hilbert codes cost more but never jump between distant
+//! cells, so runs of consecutive codes form tighter clusters. Two
+//! dimensional codes take 32 bits per axis and three dimensional codes 21,
+//! so every code fits a u64. Points are quantized to the grid over a
+//! bounding box with `quantize3`, or encoded directly with `morton3_point`
+//! and `hilbert3_point`.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use crate::scalar::clamp01;
+
+// Spreads the bits of x out to every second bit.
+fn part1by1(x: u32) -> u64 {
+    let mut x = x as u64;
+    x = (x | x << 16) & 0x0000_ffff_0000_ffff;
+    x = (x | x << 8) & 0x00ff_00ff_00ff_00ff;
+    x = (x | x << 4) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | x << 2) & 0x3333_3333_3333_3333;
+    (x | x << 1) & 0x5555_5555_5555_5555
+}
+
+// Gathers every second bit of x, the inverse of part1by1.
+fn compact1by1(x: u64) -> u32 {
+    let mut x = x & 0x5555_5555_5555_5555;
+    x = (x | x >> 1) & 0x3333_3333_3333_3333;
+    x = (x | x >> 2) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | x >> 4) & 0x00ff_00ff_00ff_00ff;
+    x = (x | x >> 8) & 0x0000_ffff_0000_ffff;
+    (x | x >> 16) as u32
+}
+
+// Spreads the low 21 bits of x out to every third bit.
+fn part1by2(x: u32) -> u64 {
+    let mut x = (x & 0x1f_ffff) as u64;
+    x = (x | x << 32) & 0x001f_0000_0000_ffff;
+    x = (x | x << 16) & 0x001f_0000_ff00_00ff;
+    x = (x | x << 8) & 0x100f_00f0_0f00_f00f;
+    x = (x | x << 4) & 0x10c3_0c30_c30c_30c3;
+    (x | x << 2) & 0x1249_2492_4924_9249
+}
+
+// Gathers every third bit of x, the inverse of part1by2.
+fn compact1by2(x: u64) -> u32 {
+    let mut x = x & 0x1249_2492_4924_9249;
+    x = (x | x >> 2) & 0x10c3_0c30_c30c_30c3;
+    x = (x | x >> 4) & 0x100f_00f0_0f00_f00f;
+    x = (x | x >> 8) & 0x001f_0000_ff00_00ff;
+    x = (x | x >> 16) & 0x001f_0000_0000_ffff;
+    ((x | x >> 32) & 0x1f_ffff) as u32
+}
+
+/// Returns the Morton code of the grid cell (x, y), the bits of x and y
+/// interleaved with x in the lowest bit.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::morton::{morton2, morton2_decode};
+/// assert!(morton2(0b11, 0b01) == 0b0111);
+/// assert!(morton2_decode(morton2(12345, 67890)) == (12345, 67890));
+/// ```
+pub fn morton2(x: u32, y: u32) -> u64 {
+    part1by1(x) | part1by1(y) << 1
+}
+
+/// Returns the grid cell (x, y) with the given Morton code.
+pub fn morton2_decode(code: u64) -> (u32, u32) {
+    (compact1by1(code), compact1by1(code >> 1))
+}
+
+/// Returns the Morton code of the grid cell (x, y, z), the low 21 bits of
+/// each coordinate interleaved with x in the lowest bit.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::morton::{morton3, morton3_decode};
+/// assert!(morton3(1, 0, 1) == 0b101);
+/// assert!(morton3_decode(morton3(1, 2, 0x1f_ffff)) == (1, 2, 0x1f_ffff));
+/// ```
+pub fn morton3(x: u32, y: u32, z: u32) -> u64 {
+    part1by2(x) | part1by2(y) << 1 | part1by2(z) << 2
+}
+
+/// Returns the grid cell (x, y, z) with the given Morton code.
+pub fn morton3_decode(code: u64) -> (u32, u32, u32) {
+    (
+        compact1by2(code),
+        compact1by2(code >> 1),
+        compact1by2(code >> 2),
+    )
+}
+
+// Converts grid coordinates of the given number of bits to the transposed
+// Hilbert index in place, after Skilling, "Programming the Hilbert curve".
+// Afterwards bit k of every axis, read from the first axis to the last,
+// holds the next digits of the index from the top.
+fn axes_to_transpose<const N: usize>(x: &mut [u32; N], bits: u32) {
+    let m = 1u32 << (bits - 1);
+    let mut q = m;
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..N {
+            if x[i] & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+    for i in 1..N {
+        x[i] ^= x[i - 1];
+    }
+    let mut t = 0;
+    let mut q = m;
+    while q > 1 {
+        if x[N - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for c in x.iter_mut() {
+        *c ^= t;
+    }
+}
+
+// The inverse of axes_to_transpose.
+fn transpose_to_axes<const N: usize>(x: &mut [u32; N], bits: u32) {
+    let t = x[N - 1] >> 1;
+    for i in (1..N).rev() {
+        x[i] ^= x[i - 1];
+    }
+    x[0] ^= t;
+    let mut q = 2u64;
+    while q != 1 << bits {
+        let (q32, p) = (q as u32, (q - 1) as u32);
+        for i in (0..N).rev() {
+            if x[i] & q32 != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+        q <<= 1;
+    }
+}
+
+/// Returns the index of the grid cell (x, y) along the Hilbert curve over a
+/// 2^bits by 2^bits grid. Consecutive indices are always adjacent cells.
+///
+/// # Arguments
+///
+/// * `x` - The column, less than 2^bits.
+/// * `y` - The row, less than 2^bits.
+/// * `bits` - The bits per axis, from 1 to 32.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::morton::hilbert2;
+/// // The order 1 curve visits (0, 0), (0, 1), (1, 1), (1, 0).
+/// assert!(hilbert2(0, 1, 1) == 1 && hilbert2(1, 1, 1) == 2 && hilbert2(1, 0, 1) == 3);
+/// ```
+pub fn hilbert2(x: u32, y: u32, bits: u32) -> u64 {
+    let mut a = [x, y];
+    axes_to_transpose(&mut a, bits);
+    morton2(a[1], a[0])
+}
+
+/// Returns the grid cell (x, y) at the given index along the Hilbert curve
+/// over a 2^bits by 2^bits grid, the inverse of `hilbert2`.
+pub fn hilbert2_decode(index: u64, bits: u32) -> (u32, u32) {
+    let (y, x) = morton2_decode(index);
+    let mut a = [x, y];
+    transpose_to_axes(&mut a, bits);
+    (a[0], a[1])
+}
+
+/// Returns the index of the grid cell (x, y, z) along the Hilbert curve over
+/// a grid of 2^bits cells per side.
+///
+/// # Arguments
+///
+/// * `x` - The x coordinate, less than 2^bits.
+/// * `y` - The y coordinate, less than 2^bits.
+/// * `z` - The z coordinate, less than 2^bits.
+/// * `bits` - The bits per axis, from 1 to 21.
+pub fn hilbert3(x: u32, y: u32, z: u32, bits: u32) -> u64 {
+    let mut a = [x, y, z];
+    axes_to_transpose(&mut a, bits);
+    morton3(a[2], a[1], a[0])
+}
+
+/// Returns the grid cell (x, y, z) at the given index along the Hilbert
+/// curve over a grid of 2^bits cells per side, the inverse of `hilbert3`.
+pub fn hilbert3_decode(index: u64, bits: u32) -> (u32, u32, u32) {
+    let (z, y, x) = morton3_decode(index);
+    let mut a = [x, y, z];
+    transpose_to_axes(&mut a, bits);
+    (a[0], a[1], a[2])
+}
+
+/// Returns the cell of the 21 bit per axis grid spanning bounds that
+/// contains p. Points outside bounds are clamped to its faces.
+///
+/// # Arguments
+///
+/// * `p` - The point to quantize.
+/// * `bounds` - The box the grid spans, usually around every point sorted.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::prelude::*;
+/// use math_engine::morton::quantize3;
+/// let b = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 2.0, 4.0));
+/// assert!(quantize3(&Point3::new(1.0, 0.0, 9.0), &b) == [0x1f_ffff, 0, 0x1f_ffff]);
+/// ```
+pub fn quantize3(p: &Point3, bounds: &Aabb3) -> [u32; 3] {
+    let max = 0x1f_ffff as f32;
+    let axis = |v: f32, lo: f32, hi: f32| {
+        if hi > lo {
+            (clamp01((v - lo) / (hi - lo)) * max).round() as u32
+        } else {
+            0
+        }
+    };
+    [
+        axis(p.x, bounds.min.x, bounds.max.x),
+        axis(p.y, bounds.min.y, bounds.max.y),
+        axis(p.z, bounds.min.z, bounds.max.z),
+    ]
+}
+
+/// Returns the Morton code of p quantized to the grid spanning bounds.
+pub fn morton3_point(p: &Point3, bounds: &Aabb3) -> u64 {
+    let [x, y, z] = quantize3(p, bounds);
+    morton3(x, y, z)
+}
+
+/// Returns the Hilbert index of p quantized to the grid spanning bounds.
+pub fn hilbert3_point(p: &Point3, bounds: &Aabb3) -> u64 {
+    let [x, y, z] = quantize3(p, bounds);
+    hilbert3(x, y, z, 21)
+}
+
+#[test]
+fn curves() {
+    // Every cell of a small grid round trips, and consecutive Hilbert
+    // indices are neighbors.
+    let bits = 3;
+    let mut last = (0, 0, 0);
+    for i in 0..1u64 << (3 * bits) {
+        let (x, y, z) = hilbert3_decode(i, bits);
+        assert!(hilbert3(x, y, z, bits) == i);
+        assert!(morton3_decode(morton3(x, y, z)) == (x, y, z));
+        if i > 0 {
+            let d = x.abs_diff(last.0) + y.abs_diff(last.1) + z.abs_diff(last.2);
+            assert!(d == 1);
+        }
+        last = (x, y, z);
+    }
+    for i in 0..1u64 << 8 {
+        let (x, y) = hilbert2_decode(i, 4);
+        assert!(hilbert2(x, y, 4) == i);
+    }
+    assert!(hilbert2_decode(u64::MAX, 32) == (u32::MAX, 0));
+    assert!(morton2_decode(morton2(u32::MAX, 7)) == (u32::MAX, 7));
+}
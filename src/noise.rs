@@ -0,0 +1,429 @@
+//! Gradient noise for terrain, textures and procedural animation: Ken
+//! Perlin's improved noise and simplex noise in two and three dimensions,
+//! with fractal sums of octaves over either.
+//!
+//! The noise is a smooth pseudo-random function of position that is zero at
+//! the integer lattice (for Perlin noise) and varies on a scale of about one
+//! unit, with values in [-1, 1]. A `GradientNoise` holds the permutation
+//! table the gradients are hashed with, so the same seed always gives the
+//! same noise on every platform.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use core::ops::Mul;
+
+/// Perlin and simplex noise over a seeded permutation table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GradientNoise {
+    // The permutation of 0..256, repeated so that hashes of neighboring
+    // cells can index past 255 without wrapping.
+    perm: [u8; 512],
+}
+
+impl Default for GradientNoise {
+    /// Returns the noise for seed 0.
+    fn default() -> Self {
+        GradientNoise::new(0)
+    }
+}
+
+// The quintic fade curve 6t⁵ - 15t⁴ + 10t³ of improved Perlin noise, whose
+// first and second derivatives vanish at 0 and 1.
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + t * (b - a)
+}
+
+// Returns the dot product of one of eight gradients, chosen by the hash,
+// with (x, y).
+fn grad2(hash: u8, x: f32, y: f32) -> f32 {
+    match hash & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y,
+    }
+}
+
+// Returns the dot product of one of the twelve gradients to the edge
+// midpoints of a cube, chosen by the hash, with (x, y, z).
+fn grad3(hash: u8, x: f32, y: f32, z: f32) -> f32 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
+
+// Scales bringing the extremes of simplex noise to about ±1. Perlin noise
+// reaches ±1 as it is; all four are clamped against rounding.
+const SIMPLEX2_SCALE: f32 = 70.0;
+const SIMPLEX3_SCALE: f32 = 76.0;
+
+// The skew and unskew factors between the simplex grids and the square or
+// cubic lattices, (√3 - 1)/2, (3 - √3)/6, 1/3 and 1/6.
+const F2: f32 = 0.366_025_42;
+const G2: f32 = 0.211_324_87;
+const F3: f32 = 1.0 / 3.0;
+const G3: f32 = 1.0 / 6.0;
+
+impl GradientNoise {
+    /// Returns the noise whose permutation table is shuffled by the given
+    /// seed. Different seeds give unrelated noise.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed of the permutation table.
+    pub fn new(seed: u64) -> GradientNoise {
+        let mut table = [0u8; 256];
+        for (i, p) in table.iter_mut().enumerate() {
+            *p = i as u8;
+        }
+        // Fisher-Yates with splitmix64, so the table does not depend on an
+        // outside random number generator.
+        let mut state = seed;
+        let mut next = || {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        for i in (1..256).rev() {
+            let j = (next() % (i as u64 + 1)) as usize;
+            table.swap(i, j);
+        }
+        let mut perm = [0u8; 512];
+        for (i, p) in perm.iter_mut().enumerate() {
+            *p = table[i & 255];
+        }
+        GradientNoise { perm }
+    }
+
+    fn hash(&self, i: i32) -> usize {
+        self.perm[(i & 255) as usize] as usize
+    }
+
+    /// Returns the 2D improved Perlin noise at p, in [-1, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to the position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::noise::GradientNoise;
+    /// use math_engine::vector2::Vector2;
+    /// let noise = GradientNoise::new(42);
+    /// assert!(noise.perlin2(&Vector2::new(3.0, -7.0)) == 0.0);
+    /// let n = noise.perlin2(&Vector2::new(3.3, -7.6));
+    /// assert!((-1.0..=1.0).contains(&n));
+    /// ```
+    pub fn perlin2(&self, p: &Vector2) -> f32 {
+        let (xf, yf) = (p.x.floor(), p.y.floor());
+        let (xi, yi) = (xf as i32, yf as i32);
+        let (x, y) = (p.x - xf, p.y - yf);
+        let (u, v) = (fade(x), fade(y));
+        let a = self.hash(xi) + (yi & 255) as usize;
+        let b = self.hash(xi + 1) + (yi & 255) as usize;
+        let (aa, ab) = (self.perm[a], self.perm[a + 1]);
+        let (ba, bb) = (self.perm[b], self.perm[b + 1]);
+        let n = lerp(
+            lerp(grad2(aa, x, y), grad2(ba, x - 1.0, y), u),
+            lerp(grad2(ab, x, y - 1.0), grad2(bb, x - 1.0, y - 1.0), u),
+            v,
+        );
+        n.clamp(-1.0, 1.0)
+    }
+
+    /// Returns the 3D improved Perlin noise at p, in [-1, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to the position.
+    pub fn perlin3(&self, p: &Vector3) -> f32 {
+        let (xf, yf, zf) = (p.x.floor(), p.y.floor(), p.z.floor());
+        let (xi, yi, zi) = (xf as i32, yf as i32, zf as i32);
+        let (x, y, z) = (p.x - xf, p.y - yf, p.z - zf);
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+        let (yi, zi) = ((yi & 255) as usize, (zi & 255) as usize);
+        let a = self.hash(xi) + yi;
+        let (aa, ab) = (self.perm[a] as usize + zi, self.perm[a + 1] as usize + zi);
+        let b = self.hash(xi + 1) + yi;
+        let (ba, bb) = (self.perm[b] as usize + zi, self.perm[b + 1] as usize + zi);
+        let g = |i: usize, dx: f32, dy: f32, dz: f32| grad3(self.perm[i], x - dx, y - dy, z - dz);
+        let n = lerp(
+            lerp(
+                lerp(g(aa, 0.0, 0.0, 0.0), g(ba, 1.0, 0.0, 0.0), u),
+                lerp(g(ab, 0.0, 1.0, 0.0), g(bb, 1.0, 1.0, 0.0), u),
+                v,
+            ),
+            lerp(
+                lerp(g(aa + 1, 0.0, 0.0, 1.0), g(ba + 1, 1.0, 0.0, 1.0), u),
+                lerp(g(ab + 1, 0.0, 1.0, 1.0), g(bb + 1, 1.0, 1.0, 1.0), u),
+                v,
+            ),
+            w,
+        );
+        n.clamp(-1.0, 1.0)
+    }
+
+    /// Returns the 2D simplex noise at p, in [-1, 1]. Simplex noise has
+    /// fewer directional artifacts than Perlin noise and is cheaper in
+    /// higher dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to the position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::noise::GradientNoise;
+    /// use math_engine::vector2::Vector2;
+    /// let noise = GradientNoise::new(42);
+    /// let a = noise.simplex2(&Vector2::new(0.5, 0.25));
+    /// let b = noise.simplex2(&Vector2::new(0.5001, 0.25));
+    /// assert!((a - b).abs() < 1e-2);
+    /// ```
+    pub fn simplex2(&self, p: &Vector2) -> f32 {
+        // Skew to find the cell of the triangle grid, then unskew to get the
+        // offset from the cell's origin.
+        let s = (p.x + p.y) * F2;
+        let (i, j) = ((p.x + s).floor(), (p.y + s).floor());
+        let t = (i + j) * G2;
+        let (x0, y0) = (p.x - (i - t), p.y - (j - t));
+        let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+        let corners = [
+            (x0, y0, 0, 0),
+            (x0 - i1 as f32 + G2, y0 - j1 as f32 + G2, i1, j1),
+            (x0 - 1.0 + 2.0 * G2, y0 - 1.0 + 2.0 * G2, 1, 1),
+        ];
+        let (i, j) = (i as i32, j as i32);
+        let mut n = 0.0;
+        for (x, y, di, dj) in corners {
+            let t = 0.5 - x * x - y * y;
+            if t > 0.0 {
+                let h = self.perm[self.hash(i + di) + ((j + dj) & 255) as usize];
+                n += t * t * t * t * grad3(h % 12, x, y, 0.0);
+            }
+        }
+        (n * SIMPLEX2_SCALE).clamp(-1.0, 1.0)
+    }
+
+    /// Returns the 3D simplex noise at p, in [-1, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to the position.
+    pub fn simplex3(&self, p: &Vector3) -> f32 {
+        let s = (p.x + p.y + p.z) * F3;
+        let (i, j, k) = ((p.x + s).floor(), (p.y + s).floor(), (p.z + s).floor());
+        let t = (i + j + k) * G3;
+        let (x0, y0, z0) = (p.x - (i - t), p.y - (j - t), p.z - (k - t));
+        // The cube splits into six tetrahedra, picked by the order of the
+        // offsets; the second and third corners step along the largest
+        // offsets first.
+        let (o1, o2) = if x0 >= y0 {
+            if y0 >= z0 {
+                ((1, 0, 0), (1, 1, 0))
+            } else if x0 >= z0 {
+                ((1, 0, 0), (1, 0, 1))
+            } else {
+                ((0, 0, 1), (1, 0, 1))
+            }
+        } else if y0 < z0 {
+            ((0, 0, 1), (0, 1, 1))
+        } else if x0 < z0 {
+            ((0, 1, 0), (0, 1, 1))
+        } else {
+            ((0, 1, 0), (1, 1, 0))
+        };
+        let corner = |o: (i32, i32, i32), g: f32| {
+            (
+                x0 - o.0 as f32 + g,
+                y0 - o.1 as f32 + g,
+                z0 - o.2 as f32 + g,
+                o,
+            )
+        };
+        let corners = [
+            corner((0, 0, 0), 0.0),
+            corner(o1, G3),
+            corner(o2, 2.0 * G3),
+            corner((1, 1, 1), 3.0 * G3),
+        ];
+        let (i, j, k) = (i as i32, j as i32, k as i32);
+        let mut n = 0.0;
+        for (x, y, z, (di, dj, dk)) in corners {
+            let t = 0.5 - x * x - y * y - z * z;
+            if t > 0.0 {
+                let h = self.perm[self.hash(i + di)
+                    + self.perm[self.hash(j + dj) + ((k + dk) & 255) as usize] as usize];
+                n += t * t * t * t * grad3(h % 12, x, y, z);
+            }
+        }
+        (n * SIMPLEX3_SCALE).clamp(-1.0, 1.0)
+    }
+}
+
+/// Settings for summing octaves of noise, each at a higher frequency and
+/// lower amplitude than the last, into fractal noise with detail at many
+/// scales.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::noise::{Fractal, GradientNoise};
+/// use math_engine::vector3::Vector3;
+/// let noise = GradientNoise::new(7);
+/// let height = Fractal::default().fbm(Vector3::new(0.3, 1.7, 2.2), |p| noise.perlin3(&p));
+/// assert!((-1.0..=1.0).contains(&height));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fractal {
+    /// The number of octaves summed.
+    pub octaves: u32,
+    /// The factor the frequency grows by from one octave to the next.
+    pub lacunarity: f32,
+    /// The factor the amplitude shrinks by from one octave to the next,
+    /// also called persistence.
+    pub gain: f32,
+}
+
+impl Default for Fractal {
+    /// Returns five octaves doubling in frequency and halving in amplitude.
+    fn default() -> Self {
+        Fractal::new(5, 2.0, 0.5)
+    }
+}
+
+impl Fractal {
+    /// Returns the settings for the given octaves, lacunarity and gain.
+    pub const fn new(octaves: u32, lacunarity: f32, gain: f32) -> Fractal {
+        Fractal {
+            octaves,
+            lacunarity,
+            gain,
+        }
+    }
+
+    // Returns the amplitude weighted sum of f over the octaves, divided by
+    // the sum of the amplitudes.
+    fn sum<P: Copy + Mul<f32, Output = P>>(&self, p: P, f: impl Fn(P) -> f32) -> f32 {
+        let (mut total, mut norm) = (0.0, 0.0);
+        let (mut frequency, mut amplitude) = (1.0, 1.0);
+        for _ in 0..self.octaves {
+            total += amplitude * f(p * frequency);
+            norm += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.gain;
+        }
+        if norm == 0.0 {
+            0.0
+        } else {
+            total / norm
+        }
+    }
+
+    /// Returns fractional Brownian motion, the plain sum of the octaves of
+    /// noise, in [-1, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The position, a `Vector2` or `Vector3`.
+    /// * `noise` - The noise function to sum.
+    pub fn fbm<P: Copy + Mul<f32, Output = P>>(&self, p: P, noise: impl Fn(P) -> f32) -> f32 {
+        self.sum(p, noise)
+    }
+
+    /// Returns ridged noise, the sum of octaves of (1 - |noise|)², in
+    /// [0, 1]. The zero crossings of the noise become sharp crests, like
+    /// mountain ridges.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The position, a `Vector2` or `Vector3`.
+    /// * `noise` - The noise function to sum.
+    pub fn ridged<P: Copy + Mul<f32, Output = P>>(&self, p: P, noise: impl Fn(P) -> f32) -> f32 {
+        self.sum(p, |p| {
+            let r = 1.0 - noise(p).abs();
+            r * r
+        })
+    }
+
+    /// Returns turbulence, the sum of octaves of |noise|, in [0, 1]. The
+    /// zero crossings of the noise become creases, as in fire and smoke.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The position, a `Vector2` or `Vector3`.
+    /// * `noise` - The noise function to sum.
+    pub fn turbulence<P: Copy + Mul<f32, Output = P>>(
+        &self,
+        p: P,
+        noise: impl Fn(P) -> f32,
+    ) -> f32 {
+        self.sum(p, |p| noise(p).abs())
+    }
+}
+
+#[test]
+fn noise_properties() {
+    let noise = GradientNoise::new(1234);
+    assert!(noise == GradientNoise::new(1234) && noise != GradientNoise::new(1235));
+    let (mut lo, mut hi) = (f32::INFINITY, f32::NEG_INFINITY);
+    let mut track = |n: f32| {
+        lo = lo.min(n);
+        hi = hi.max(n);
+    };
+    for i in 0..80 {
+        for j in 0..80 {
+            let p = Vector2::new(i as f32 * 0.173 - 5.0, j as f32 * 0.131 - 3.0);
+            let q = Vector3::new(p.x, p.y, (i * j) as f32 * 0.0173);
+            let step = 1e-3;
+            for f in [
+                |n: &GradientNoise, p: Vector2, _: Vector3| n.perlin2(&p),
+                |n: &GradientNoise, p: Vector2, _: Vector3| n.simplex2(&p),
+                |n: &GradientNoise, _: Vector2, q: Vector3| n.perlin3(&q),
+                |n: &GradientNoise, _: Vector2, q: Vector3| n.simplex3(&q),
+            ] {
+                let a = f(&noise, p, q);
+                track(a);
+                // Continuous, with a bounded slope.
+                let d = Vector2::new(step, step);
+                let b = f(&noise, p + d, q + Vector3::new(step, step, step));
+                assert!((a - b).abs() < 0.02);
+            }
+        }
+    }
+    // The noise fills most of its range without leaving it.
+    assert!(lo >= -1.0 && hi <= 1.0 && lo < -0.5 && hi > 0.5);
+    // Perlin noise is zero on the lattice.
+    assert!(noise.perlin3(&Vector3::new(4.0, -2.0, 9.0)) == 0.0);
+
+    let fractal = Fractal::default();
+    let p = Vector2::new(0.37, 0.81);
+    let ridged = fractal.ridged(p, |p| noise.simplex2(&p));
+    let turbulence = fractal.turbulence(p, |p| noise.simplex2(&p));
+    assert!((0.0..=1.0).contains(&ridged) && (0.0..=1.0).contains(&turbulence));
+    // One octave is the noise itself.
+    let single = Fractal::new(1, 2.0, 0.5).fbm(p, |p| noise.perlin2(&p));
+    assert!(single == noise.perlin2(&p));
+}
@@ -0,0 +1,392 @@
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An oriented bounding box described by its center, its half size along
+/// each of its local axes and a rotation whose columns are those axes.
+#[repr(C)]
+pub struct Obb {
+    /// The center of the box.
+    pub center: Point3,
+    /// Half the size of the box along each local axis.
+    pub half_extents: Vector3,
+    /// The orientation of the box. Its columns are the unit length local axes.
+    pub rotation: Matrix3,
+}
+
+// Guards the separating axis tests against the cross product of two nearly
+// parallel edges degenerating to a zero axis.
+const EPSILON: f32 = 1.0e-6;
+
+impl Obb {
+    /// Returns a box with the given center, half extents and rotation.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The center of the box.
+    /// * `half_extents` - Half the size of the box along each local axis.
+    /// * `rotation` - A rotation matrix whose columns are the local axes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::obb::Obb;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// let b = Obb::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 2.0, 3.0), Matrix3::identity());
+    /// assert!(b.half_extents.y == 2.0);
+    /// ```
+    pub const fn new(center: Point3, half_extents: Vector3, rotation: Matrix3) -> Obb {
+        Obb {
+            center,
+            half_extents,
+            rotation,
+        }
+    }
+
+    /// Returns a box with the given center and half extents, oriented by the
+    /// unit quaternion q.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The center of the box.
+    /// * `half_extents` - Half the size of the box along each local axis.
+    /// * `q` - A unit quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::obb::Obb;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::quarternion::Quarternion;
+    /// use math_engine::vector3::Vector3;
+    /// let q = Quarternion::new(0.0, 0.0, 0.0, 1.0);
+    /// let b = Obb::new_with_quarternion(Point3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), &q);
+    /// ```
+    pub fn new_with_quarternion(center: Point3, half_extents: Vector3, q: &Quarternion) -> Obb {
+        Obb {
+            center,
+            half_extents,
+            rotation: q.get_rotation_matrix(),
+        }
+    }
+
+    /// Returns the box that results from transforming the axis-aligned box b
+    /// by h. The scale of each column of h is folded into the half extents, so
+    /// h is expected to be a rotation combined with a (possibly non-uniform)
+    /// scale and a translation, without skew.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - A reference to an aabb3.
+    /// * `h` - A reference to a transform4.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::obb::Obb;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// let b = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+    /// let h = Transform4::make_translation(&Vector3::new(5.0, 0.0, 0.0))
+    ///     * Transform4::make_scale_xyz(2.0, 1.0, 1.0);
+    /// let o = Obb::from_aabb(&b, &h);
+    /// assert!(o.center == Point3::new(5.0, 0.0, 0.0));
+    /// assert!(o.half_extents == Vector3::new(2.0, 1.0, 1.0));
+    /// ```
+    pub fn from_aabb(b: &Aabb3, h: &Transform4) -> Obb {
+        let e = b.half_extents();
+        let mut half_extents = Vector3::default();
+        let mut rotation = Matrix3::identity();
+        for i in 0..3 {
+            let s = h[i].magnitude();
+            half_extents[i] = e[i] * s;
+            if s > 0.0 {
+                rotation[i] = h[i] / s;
+            }
+        }
+        Obb {
+            center: *h * b.center(),
+            half_extents,
+            rotation,
+        }
+    }
+
+    /// Returns a box enclosing the points, aligned with their principal axes,
+    /// or None if there are none. The axes follow the spread of the points,
+    /// which fits elongated and rotated shapes far more tightly than an
+    /// axis-aligned box, though the result is not the smallest possible box.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points to enclose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::obb::Obb;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// // A long thin rod along the diagonal of the xy plane.
+    /// let r = Matrix3::make_rotation_z(core::f32::consts::FRAC_PI_4);
+    /// let points: Vec<Point3> = (0..=10)
+    ///     .flat_map(|i| {
+    ///         let x = i as f32 - 5.0;
+    ///         [Point3::from(r * Vector3::new(x, 0.5, 0.0)), Point3::from(r * Vector3::new(x, -0.5, 0.0))]
+    ///     })
+    ///     .collect();
+    /// let b = Obb::fit(&points).unwrap();
+    /// assert!((b.half_extents - Vector3::new(5.0, 0.5, 0.0)).magnitude() < 1e-4);
+    /// assert!(points.iter().all(|p| b.contains_point(p)));
+    /// ```
+    pub fn fit(points: &[Point3]) -> Option<Obb> {
+        let (_, rotation) = crate::statistics::principal_axes(points)?;
+        let mut min = Vector3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY);
+        let mut max = -min;
+        for p in points {
+            let local = rotation.transpose() * Vector3::from(*p);
+            for i in 0..3 {
+                min[i] = min[i].min(local[i]);
+                max[i] = max[i].max(local[i]);
+            }
+        }
+        Some(Obb {
+            center: Point3::from(rotation * ((min + max) * 0.5)),
+            half_extents: (max - min) * 0.5,
+            rotation,
+        })
+    }
+
+    /// Returns true if the point p lies inside or on this box.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::obb::Obb;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// let b = Obb::new(
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(2.0, 0.5, 0.5),
+    ///     Matrix3::make_rotation_z(core::f32::consts::FRAC_PI_2),
+    /// );
+    /// assert!(b.contains_point(&Point3::new(0.0, 1.5, 0.0)));
+    /// assert!(!b.contains_point(&Point3::new(1.5, 0.0, 0.0)));
+    /// ```
+    pub fn contains_point(&self, p: &Point3) -> bool {
+        let d = *p - self.center;
+        (0..3).all(|i| d.dot(&self.rotation[i]).abs() <= self.half_extents[i])
+    }
+
+    /// Returns true if this box and other overlap, using the separating axis
+    /// test on the face normals of both boxes and the cross products of
+    /// their edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to an obb.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::obb::Obb;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// let e = Vector3::new(1.0, 1.0, 1.0);
+    /// let a = Obb::new(Point3::new(0.0, 0.0, 0.0), e, Matrix3::identity());
+    /// let b = Obb::new(Point3::new(2.3, 0.0, 0.0), e, Matrix3::make_rotation_z(0.785398));
+    /// assert!(a.intersects_obb(&b));
+    /// let c = Obb::new(Point3::new(2.5, 0.0, 0.0), e, Matrix3::make_rotation_z(0.785398));
+    /// assert!(!a.intersects_obb(&c));
+    /// ```
+    pub fn intersects_obb(&self, other: &Obb) -> bool {
+        let a = &self.half_extents;
+        let b = &other.half_extents;
+
+        // Rotation taking other into the frame of self, and its absolute value.
+        let mut r = [[0.0f32; 3]; 3];
+        let mut abs_r = [[0.0f32; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = self.rotation[i].dot(&other.rotation[j]);
+                abs_r[i][j] = r[i][j].abs() + EPSILON;
+            }
+        }
+
+        let d = other.center - self.center;
+        let t = [
+            d.dot(&self.rotation[0]),
+            d.dot(&self.rotation[1]),
+            d.dot(&self.rotation[2]),
+        ];
+
+        // Face normals of self.
+        for i in 0..3 {
+            let ra = a[i];
+            let rb = b[0] * abs_r[i][0] + b[1] * abs_r[i][1] + b[2] * abs_r[i][2];
+            if t[i].abs() > ra + rb {
+                return false;
+            }
+        }
+
+        // Face normals of other.
+        for j in 0..3 {
+            let ra = a[0] * abs_r[0][j] + a[1] * abs_r[1][j] + a[2] * abs_r[2][j];
+            let rb = b[j];
+            if (t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j]).abs() > ra + rb {
+                return false;
+            }
+        }
+
+        // Cross products of an edge of self with an edge of other.
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = a[i1] * abs_r[i2][j] + a[i2] * abs_r[i1][j];
+                let rb = b[j1] * abs_r[i][j2] + b[j2] * abs_r[i][j1];
+                if (t[i2] * r[i1][j] - t[i1] * r[i2][j]).abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns true if this box and the axis-aligned box b overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - A reference to an aabb3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::aabb3::Aabb3;
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::obb::Obb;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// let b = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+    /// let o = Obb::new(Point3::new(2.0, 0.0, 0.0), Vector3::new(1.2, 0.1, 0.1), Matrix3::identity());
+    /// assert!(o.intersects_aabb(&b));
+    /// ```
+    pub fn intersects_aabb(&self, b: &Aabb3) -> bool {
+        self.intersects_obb(&Obb::new(b.center(), b.half_extents(), Matrix3::identity()))
+    }
+
+    /// Returns the parameter t at which the ray starting at p with direction v
+    /// first hits this box, or None if it misses. The returned t is 0 when p
+    /// already lies inside the box. The hit point is p + v * t.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The origin of the ray.
+    /// * `v` - The direction of the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::obb::Obb;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector3::Vector3;
+    /// let b = Obb::new(Point3::new(5.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), Matrix3::identity());
+    /// let t = b.ray_intersect(&Point3::new(0.0, 0.0, 0.0), &Vector3::new(1.0, 0.0, 0.0));
+    /// assert!(t == Some(4.0));
+    /// ```
+    pub fn ray_intersect(&self, p: &Point3, v: &Vector3) -> Option<f32> {
+        let d = *p - self.center;
+        let mut t_min = 0.0f32;
+        let mut t_max = f32::MAX;
+        // v need not be normalized, so parallel is judged relative to its length.
+        let parallel = EPSILON * v.magnitude();
+        for i in 0..3 {
+            let axis = self.rotation[i];
+            let o = d.dot(&axis);
+            let dir = v.dot(&axis);
+            let e = self.half_extents[i];
+            if dir.abs() <= parallel {
+                if o.abs() > e {
+                    return None;
+                }
+            } else {
+                let t1 = (-e - o) / dir;
+                let t2 = (e - o) / dir;
+                t_min = t_min.max(t1.min(t2));
+                t_max = t_max.min(t1.max(t2));
+                if t_min > t_max {
+                    return None;
+                }
+            }
+        }
+        Some(t_min)
+    }
+}
+
+#[test]
+fn rotated_separation() {
+    // Two thin boxes crossed at right angles, stacked along z.
+    let e = Vector3::new(2.0, 0.1, 0.1);
+    let a = Obb::new(Point3::new(0.0, 0.0, 0.0), e, Matrix3::identity());
+    let b = Obb::new(
+        Point3::new(0.0, 0.0, 0.15),
+        e,
+        Matrix3::make_rotation_z(core::f32::consts::FRAC_PI_2),
+    );
+    assert!(a.intersects_obb(&b));
+    let b = Obb::new(
+        Point3::new(0.0, 0.0, 0.25),
+        e,
+        Matrix3::make_rotation_z(core::f32::consts::FRAC_PI_2),
+    );
+    assert!(!a.intersects_obb(&b));
+
+    let h = Transform4::make_rotation_y(0.7)
+        * Transform4::make_translation(&Vector3::new(0.0, 3.0, 0.0));
+    let o = Obb::from_aabb(
+        &Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0)),
+        &h,
+    );
+    assert!(o.contains_point(&(h * Point3::new(0.9, 0.9, -0.9))));
+    assert!(!o.contains_point(&(h * Point3::new(1.1, 0.0, 0.0))));
+}
+
+#[test]
+fn unnormalized_ray() {
+    // A short direction is not parallel to the axis it points along, and a
+    // long one barely off an axis is.
+    let b = Obb::new(
+        Point3::new(5.0, 0.0, 0.0),
+        Vector3::new(1.0, 1.0, 1.0),
+        Matrix3::make_rotation_z(0.3),
+    );
+    let p = Point3::new(0.0, 0.0, 0.0);
+    let t = b.ray_intersect(&p, &Vector3::new(1.0, 0.0, 0.0)).unwrap();
+    let short = b
+        .ray_intersect(&p, &Vector3::new(1.0e-7, 0.0, 0.0))
+        .unwrap();
+    assert!((short * 1.0e-7 - t).abs() < 1e-5);
+    let o = Obb::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 1.0, 1.0),
+        Matrix3::identity(),
+    );
+    let long = Vector3::new(1.0e7, 1.0, 0.0);
+    assert!(o
+        .ray_intersect(&Point3::new(-5.0, 0.5, 0.0), &long)
+        .is_some());
+    assert!(o
+        .ray_intersect(&Point3::new(-5.0, 1.5, 0.0), &long)
+        .is_none());
+}
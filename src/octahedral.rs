@@ -0,0 +1,144 @@
+//! Octahedral encoding of unit vectors, after Cigolle et al., "A Survey of
+//! Efficient Representations for Independent Unit Vectors". The sphere is
+//! projected onto an octahedron, whose lower half is folded over the upper
+//! half and flattened into the square [-1, 1]², so a direction takes two
+//! numbers instead of three and the error is spread evenly over the sphere.
+//! G-buffers and vertex streams store normals this way.
+//!
+//! `encode_octahedral16` further quantizes the two numbers to 8 bits each,
+//! choosing among the nearby grid points the one that decodes closest to
+//! the input, for a worst case error of under a degree in 16 bits.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+
+// Returns 1 for zero and positive numbers and -1 for negative numbers,
+// unlike signum, which gives -1 for -0.
+fn sign_not_zero(v: f32) -> f32 {
+    if v >= 0.0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+// Folds a point of the square's lower half back over the upper half, and
+// unfolds it again, the operation being its own inverse.
+fn fold(p: Vector2) -> Vector2 {
+    Vector2::new(
+        (1.0 - p.y.abs()) * sign_not_zero(p.x),
+        (1.0 - p.x.abs()) * sign_not_zero(p.y),
+    )
+}
+
+/// Returns the octahedral encoding of the direction of v, a point in
+/// [-1, 1]². v need not be normalized but must not be zero.
+///
+/// # Arguments
+///
+/// * `v` - A reference to the direction to encode.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::octahedral::{decode_octahedral, encode_octahedral};
+/// use math_engine::vector2::Vector2;
+/// use math_engine::vector3::Vector3;
+/// assert!(encode_octahedral(&Vector3::new(0.0, 0.0, 1.0)) == Vector2::new(0.0, 0.0));
+/// let v = Vector3::new(0.6, -0.48, -0.64);
+/// assert!((*decode_octahedral(&encode_octahedral(&v)) - v).magnitude() < 1e-6);
+/// ```
+pub fn encode_octahedral(v: &Vector3) -> Vector2 {
+    let p = Vector2::new(v.x, v.y) / (v.x.abs() + v.y.abs() + v.z.abs());
+    if v.z < 0.0 {
+        fold(p)
+    } else {
+        p
+    }
+}
+
+/// Returns the unit vector with the given octahedral encoding.
+///
+/// # Arguments
+///
+/// * `e` - A reference to a point in [-1, 1]².
+pub fn decode_octahedral(e: &Vector2) -> UnitVector3 {
+    let z = 1.0 - e.x.abs() - e.y.abs();
+    let p = if z < 0.0 { fold(*e) } else { *e };
+    UnitVector3::new_normalize(Vector3::new(p.x, p.y, z))
+}
+
+// Converts a number in [-1, 1] to an 8 bit signed normalized value.
+fn snorm8(v: f32) -> i8 {
+    (v.clamp(-1.0, 1.0) * 127.0).round() as i8
+}
+
+fn from_snorm8(q: i8) -> f32 {
+    (q as f32 / 127.0).max(-1.0)
+}
+
+/// Returns the octahedral encoding of the direction of v packed into 16
+/// bits, x in the low byte and y in the high byte, each an 8 bit signed
+/// normalized number. Of the four grid points around the exact encoding it
+/// picks the one that decodes closest to v.
+///
+/// # Arguments
+///
+/// * `v` - A reference to the direction to encode.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::octahedral::{decode_octahedral16, encode_octahedral16};
+/// use math_engine::vector3::Vector3;
+/// let v = Vector3::new(0.6, -0.48, -0.64);
+/// let d = decode_octahedral16(encode_octahedral16(&v));
+/// assert!(d.dot(&v) > 0.9999);
+/// ```
+pub fn encode_octahedral16(v: &Vector3) -> u16 {
+    let e = encode_octahedral(v);
+    let n = v.normalize();
+    let mut best = (f32::NEG_INFINITY, 0);
+    for (fx, fy) in [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)] {
+        let q = [
+            snorm8(((e.x * 127.0).floor() + fx) / 127.0),
+            snorm8(((e.y * 127.0).floor() + fy) / 127.0),
+        ];
+        let packed = q[0] as u8 as u16 | (q[1] as u8 as u16) << 8;
+        let d = decode_octahedral16(packed).dot(&n);
+        if d > best.0 {
+            best = (d, packed);
+        }
+    }
+    best.1
+}
+
+/// Returns the unit vector with the given 16 bit octahedral encoding.
+pub fn decode_octahedral16(packed: u16) -> UnitVector3 {
+    let x = from_snorm8(packed as u8 as i8);
+    let y = from_snorm8((packed >> 8) as u8 as i8);
+    decode_octahedral(&Vector2::new(x, y))
+}
+
+#[test]
+fn octahedral_error() {
+    // Sweep the sphere, including the folded lower half and the poles.
+    let mut worst: f32 = 1.0;
+    for i in 0..=40 {
+        for j in 0..80 {
+            let theta = core::f32::consts::PI * i as f32 / 40.0;
+            let phi = core::f32::consts::TAU * j as f32 / 80.0;
+            let v = Vector3::new(
+                theta.sin() * phi.cos(),
+                theta.sin() * phi.sin(),
+                theta.cos(),
+            );
+            assert!(decode_octahedral(&encode_octahedral(&v)).dot(&v) > 1.0 - 1e-6);
+            worst = worst.min(decode_octahedral16(encode_octahedral16(&v)).dot(&v));
+        }
+    }
+    // cos(1°) is 0.99985.
+    assert!(worst > 0.99985);
+}
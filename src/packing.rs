@@ -0,0 +1,378 @@
+//! Packing of vectors and colors into the compact integer formats GPUs read
+//! from vertex buffers and textures, so buffers can be filled from the math
+//! types directly.
+//!
+//! Normalized formats store a number in [0, 1] (unorm) or [-1, 1] (snorm)
+//! as an integer scaled by the largest value of its bits, rounding to the
+//! nearest integer. Inputs outside the range are clamped. Decoding follows
+//! the D3D and Vulkan rules, so the most negative snorm integer also maps
+//! to -1. Components are packed from the lowest bits up, x or r first,
+//! which matches the GPU formats on little-endian machines: `pack4x8unorm`
+//! is R8G8B8A8_UNORM and `pack_10_10_10_2_snorm` is A2B10G10R10_SNORM_PACK32.
+//!
+//! HDR colors can be packed with a shared exponent, as RGB9E5, the
+//! E5B9G9R9_UFLOAT_PACK32 texture format, or as the RGBE of Radiance `.hdr`
+//! files, whose bytes are r, g, b, e in memory order.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+
+// Returns v in [0, 1] as an unsigned integer of the given bits.
+fn unorm(v: f32, bits: u32) -> u32 {
+    let max = ((1u64 << bits) - 1) as f32;
+    (v.clamp(0.0, 1.0) * max).round() as u32
+}
+
+fn from_unorm(u: u32, bits: u32) -> f32 {
+    let max = ((1u64 << bits) - 1) as f32;
+    (u & max as u32) as f32 / max
+}
+
+// Returns v in [-1, 1] as a two's complement integer of the given bits, in
+// the low bits of the result.
+fn snorm(v: f32, bits: u32) -> u32 {
+    let max = ((1u32 << (bits - 1)) - 1) as f32;
+    let q = (v.clamp(-1.0, 1.0) * max).round() as i32;
+    q as u32 & ((1u64 << bits) - 1) as u32
+}
+
+fn from_snorm(u: u32, bits: u32) -> f32 {
+    let max = ((1u32 << (bits - 1)) - 1) as f32;
+    // Sign extend by shifting the field to the top and back.
+    let q = ((u << (32 - bits)) as i32) >> (32 - bits);
+    (q as f32 / max).max(-1.0)
+}
+
+/// Returns the components of v, each in [0, 1], packed into four 8 bit
+/// unsigned normalized numbers.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::packing::{pack4x8unorm, unpack4x8unorm};
+/// use math_engine::vector4::Vector4;
+/// let p = pack4x8unorm(&Vector4::new(1.0, 0.0, 0.5, 2.0));
+/// assert!(p == 0xff80_00ff);
+/// assert!(unpack4x8unorm(p) == Vector4::new(1.0, 0.0, 128.0 / 255.0, 1.0));
+/// ```
+pub fn pack4x8unorm(v: &Vector4) -> u32 {
+    unorm(v.x, 8) | unorm(v.y, 8) << 8 | unorm(v.z, 8) << 16 | unorm(v.w, 8) << 24
+}
+
+/// Returns the vector packed by `pack4x8unorm`.
+pub fn unpack4x8unorm(p: u32) -> Vector4 {
+    Vector4::new(
+        from_unorm(p, 8),
+        from_unorm(p >> 8, 8),
+        from_unorm(p >> 16, 8),
+        from_unorm(p >> 24, 8),
+    )
+}
+
+/// Returns the components of v, each in [-1, 1], packed into four 8 bit
+/// signed normalized numbers.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::packing::{pack4x8snorm, unpack4x8snorm};
+/// use math_engine::vector4::Vector4;
+/// let p = pack4x8snorm(&Vector4::new(1.0, -1.0, 0.0, -0.5));
+/// assert!(p == 0xc0_00_81_7f);
+/// assert!(unpack4x8snorm(p).w == -64.0 / 127.0);
+/// ```
+pub fn pack4x8snorm(v: &Vector4) -> u32 {
+    snorm(v.x, 8) | snorm(v.y, 8) << 8 | snorm(v.z, 8) << 16 | snorm(v.w, 8) << 24
+}
+
+/// Returns the vector packed by `pack4x8snorm`.
+pub fn unpack4x8snorm(p: u32) -> Vector4 {
+    Vector4::new(
+        from_snorm(p, 8),
+        from_snorm(p >> 8, 8),
+        from_snorm(p >> 16, 8),
+        from_snorm(p >> 24, 8),
+    )
+}
+
+/// Returns the components of v, each in [0, 1], packed into two 16 bit
+/// unsigned normalized numbers.
+pub fn pack2x16unorm(v: &Vector2) -> u32 {
+    unorm(v.x, 16) | unorm(v.y, 16) << 16
+}
+
+/// Returns the vector packed by `pack2x16unorm`.
+pub fn unpack2x16unorm(p: u32) -> Vector2 {
+    Vector2::new(from_unorm(p, 16), from_unorm(p >> 16, 16))
+}
+
+/// Returns the components of v, each in [-1, 1], packed into two 16 bit
+/// signed normalized numbers.
+pub fn pack2x16snorm(v: &Vector2) -> u32 {
+    snorm(v.x, 16) | snorm(v.y, 16) << 16
+}
+
+/// Returns the vector packed by `pack2x16snorm`.
+pub fn unpack2x16snorm(p: u32) -> Vector2 {
+    Vector2::new(from_snorm(p, 16), from_snorm(p >> 16, 16))
+}
+
+/// Returns the components of v packed into two half floats, rounded to the
+/// nearest half, the R16G16_SFLOAT format.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::packing::{pack2x16float, unpack2x16float};
+/// use math_engine::vector2::Vector2;
+/// assert!(pack2x16float(&Vector2::new(1.0, -2.0)) == 0xc000_3c00);
+/// assert!(unpack2x16float(0xc000_3c00) == Vector2::new(1.0, -2.0));
+/// ```
+pub fn pack2x16float(v: &Vector2) -> u32 {
+    F16::from_f32(v.x).0 as u32 | (F16::from_f32(v.y).0 as u32) << 16
+}
+
+/// Returns the vector packed by `pack2x16float`.
+pub fn unpack2x16float(p: u32) -> Vector2 {
+    Vector2::new(F16(p as u16).to_f32(), F16((p >> 16) as u16).to_f32())
+}
+
+/// Returns the components of v, each in [-1, 1], packed into three 10 bit
+/// and one 2 bit signed normalized numbers, the usual format for normals
+/// and tangents with a handedness sign in w.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::packing::{pack_10_10_10_2_snorm, unpack_10_10_10_2_snorm};
+/// use math_engine::vector4::Vector4;
+/// let v = Vector4::new(0.6, -0.8, 0.0, -1.0);
+/// let u = unpack_10_10_10_2_snorm(pack_10_10_10_2_snorm(&v));
+/// assert!((u.x - v.x).abs() <= 0.5 / 511.0 && u.w == -1.0);
+/// ```
+pub fn pack_10_10_10_2_snorm(v: &Vector4) -> u32 {
+    snorm(v.x, 10) | snorm(v.y, 10) << 10 | snorm(v.z, 10) << 20 | snorm(v.w, 2) << 30
+}
+
+/// Returns the vector packed by `pack_10_10_10_2_snorm`.
+pub fn unpack_10_10_10_2_snorm(p: u32) -> Vector4 {
+    Vector4::new(
+        from_snorm(p, 10),
+        from_snorm(p >> 10, 10),
+        from_snorm(p >> 20, 10),
+        from_snorm(p >> 30, 2),
+    )
+}
+
+/// Returns the components of v, each in [0, 1], packed into three 10 bit
+/// and one 2 bit unsigned normalized numbers.
+pub fn pack_10_10_10_2_unorm(v: &Vector4) -> u32 {
+    unorm(v.x, 10) | unorm(v.y, 10) << 10 | unorm(v.z, 10) << 20 | unorm(v.w, 2) << 30
+}
+
+/// Returns the vector packed by `pack_10_10_10_2_unorm`.
+pub fn unpack_10_10_10_2_unorm(p: u32) -> Vector4 {
+    Vector4::new(
+        from_unorm(p, 10),
+        from_unorm(p >> 10, 10),
+        from_unorm(p >> 20, 10),
+        from_unorm(p >> 30, 2),
+    )
+}
+
+/// Returns the unit vector n packed into the 10-10-10-2 signed normalized
+/// format with a w of zero.
+pub fn pack_normal(n: &Vector3) -> u32 {
+    pack_10_10_10_2_snorm(&Vector4::new(n.x, n.y, n.z, 0.0))
+}
+
+/// Returns the normal packed by `pack_normal`, renormalized to undo the
+/// quantization's change in length.
+pub fn unpack_normal(p: u32) -> UnitVector3 {
+    let v = unpack_10_10_10_2_snorm(p);
+    UnitVector3::new_normalize(Vector3::new(v.x, v.y, v.z))
+}
+
+/// Returns the color packed into four 8 bit unsigned normalized channels,
+/// the R8G8B8A8_UNORM format. The channels are stored as they are, without
+/// sRGB encoding.
+pub fn pack_rgba8(c: &RGBA) -> u32 {
+    pack4x8unorm(&Vector4::new(c.r, c.g, c.b, c.a))
+}
+
+/// Returns the color packed by `pack_rgba8`.
+pub fn unpack_rgba8(p: u32) -> RGBA {
+    let v = unpack4x8unorm(p);
+    RGBA::new(v.x, v.y, v.z, v.w)
+}
+
+/// Returns the color packed into 10 bit unsigned normalized color channels
+/// and a 2 bit alpha, the A2B10G10R10_UNORM_PACK32 format.
+pub fn pack_rgb10_a2(c: &RGBA) -> u32 {
+    pack_10_10_10_2_unorm(&Vector4::new(c.r, c.g, c.b, c.a))
+}
+
+/// Returns the color packed by `pack_rgb10_a2`.
+pub fn unpack_rgb10_a2(p: u32) -> RGBA {
+    let v = unpack_10_10_10_2_unorm(p);
+    RGBA::new(v.x, v.y, v.z, v.w)
+}
+
+// Returns 2^e, for e in the range of f64 normal numbers.
+fn pow2(e: i32) -> f64 {
+    f64::from_bits(((e + 1023) as u64) << 52)
+}
+
+// Returns floor(log2(v)) of a positive normal number.
+fn log2_floor(v: f64) -> i32 {
+    ((v.to_bits() >> 52) & 0x7ff) as i32 - 1023
+}
+
+const RGB9E5_MANTISSA_BITS: i32 = 9;
+const RGB9E5_EXP_BIAS: i32 = 15;
+const RGB9E5_MAX_EXP: i32 = 31;
+
+/// Returns the color packed into three 9 bit mantissas sharing a 5 bit
+/// exponent, the E5B9G9R9_UFLOAT_PACK32 format, following the rounding of
+/// the EXT_texture_shared_exponent specification. Negative channels are
+/// clamped to zero and channels above 65408, the largest the format holds,
+/// to 65408. Small channels lose precision next to a large one.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::packing::{pack_rgb9e5, unpack_rgb9e5};
+/// use math_engine::rgb::RGB;
+/// let c = unpack_rgb9e5(pack_rgb9e5(&RGB::new(16.0, 1.0, 0.25)));
+/// assert!(c == RGB::new(16.0, 1.0, 0.25));
+/// ```
+pub fn pack_rgb9e5(c: &RGB) -> u32 {
+    let n = RGB9E5_MANTISSA_BITS;
+    let max = pow2(RGB9E5_MAX_EXP - RGB9E5_EXP_BIAS) * ((1 << n) - 1) as f64 / (1 << n) as f64;
+    let clamp = |v: f32| (v as f64).clamp(0.0, max);
+    let (r, g, b) = (clamp(c.r), clamp(c.g), clamp(c.b));
+    let largest = r.max(g).max(b);
+    let floor_log2 = if largest < pow2(-RGB9E5_EXP_BIAS) {
+        -RGB9E5_EXP_BIAS - 1
+    } else {
+        log2_floor(largest)
+    };
+    let mut exp = floor_log2 + 1 + RGB9E5_EXP_BIAS;
+    // Rounding the largest channel up can carry it out of its 9 bits.
+    if (largest / pow2(exp - RGB9E5_EXP_BIAS - n) + 0.5).floor() as u32 == 1 << n {
+        exp += 1;
+    }
+    let scale = pow2(RGB9E5_EXP_BIAS + n - exp);
+    let mantissa = |v: f64| (v * scale + 0.5).floor() as u32;
+    mantissa(r) | mantissa(g) << 9 | mantissa(b) << 18 | (exp as u32) << 27
+}
+
+/// Returns the color packed by `pack_rgb9e5`.
+pub fn unpack_rgb9e5(p: u32) -> RGB {
+    let exp = (p >> 27) as i32;
+    let scale = pow2(exp - RGB9E5_EXP_BIAS - RGB9E5_MANTISSA_BITS);
+    let channel = |shift: u32| ((p >> shift) & 0x1ff) as f64 * scale;
+    RGB::new(channel(0) as f32, channel(9) as f32, channel(18) as f32)
+}
+
+const RGBE_EXP_BIAS: i32 = 128;
+const RGBE_MAX_EXP: i32 = 255;
+
+/// Returns the color packed into three 8 bit mantissas sharing an 8 bit
+/// exponent, the RGBE pixels of Radiance `.hdr` files, with r in the lowest
+/// byte and the exponent in the highest. Negative and NaN channels are
+/// clamped to zero and channels above 255 * 2^119, the largest the format
+/// holds, including infinities, to 255 * 2^119. Colors whose largest
+/// channel is under 1e-32 pack as black.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::packing::{pack_rgbe, unpack_rgbe};
+/// use math_engine::rgb::RGB;
+/// let p = pack_rgbe(&RGB::new(1000.0, 1.0, 0.0));
+/// let c = unpack_rgbe(p);
+/// assert!((c.r - 1000.0).abs() < 1000.0 / 128.0 && c.g < 4.0 && c.b < 4.0);
+/// ```
+pub fn pack_rgbe(c: &RGB) -> u32 {
+    // The largest mantissa under the largest exponent byte, 255.
+    let max = pow2(RGBE_MAX_EXP - RGBE_EXP_BIAS - 8) * 255.0;
+    let clamp = |v: f32| {
+        if v.is_nan() {
+            0.0
+        } else {
+            (v as f64).clamp(0.0, max)
+        }
+    };
+    let (r, g, b) = (clamp(c.r), clamp(c.g), clamp(c.b));
+    let largest = r.max(g).max(b);
+    if largest < 1e-32 {
+        return 0;
+    }
+    // largest = m * 2^exp with m in [0.5, 1), scaled so m maps to [128, 256).
+    let exp = log2_floor(largest) + 1;
+    let scale = pow2(8 - exp);
+    let byte = |v: f64| (v * scale) as u32;
+    byte(r) | byte(g) << 8 | byte(b) << 16 | ((exp + RGBE_EXP_BIAS) as u32) << 24
+}
+
+/// Returns the color packed by `pack_rgbe`, taking each mantissa at the
+/// middle of the interval it stands for, as Radiance does.
+pub fn unpack_rgbe(p: u32) -> RGB {
+    let exp = (p >> 24) as i32;
+    if exp == 0 {
+        return RGB::new(0.0, 0.0, 0.0);
+    }
+    let scale = pow2(exp - RGBE_EXP_BIAS - 8);
+    let channel = |shift: u32| (((p >> shift) & 0xff) as f64 + 0.5) * scale;
+    RGB::new(channel(0) as f32, channel(8) as f32, channel(16) as f32)
+}
+
+#[test]
+fn packing_round_trips() {
+    // Decoding then re-encoding is the identity, except for the most
+    // negative snorm integers, 512 and -2, which are skipped here.
+    for q in 0..1024u32 {
+        let p = q | q << 10 | q << 20 | (q & 3) << 30;
+        assert!(pack_10_10_10_2_unorm(&unpack_10_10_10_2_unorm(p)) == p);
+        if q != 512 && q & 3 != 2 {
+            assert!(pack_10_10_10_2_snorm(&unpack_10_10_10_2_snorm(p)) == p);
+        }
+    }
+    // The most negative integer decodes to -1, which encodes as its
+    // neighbor.
+    assert!(from_snorm(0x200, 10) == -1.0 && snorm(-1.0, 10) == 0x201);
+    assert!(unpack2x16snorm(pack2x16snorm(&Vector2::new(-1.0, 0.25))).x == -1.0);
+    assert!(unpack2x16unorm(pack2x16unorm(&Vector2::new(0.5, 1.0))).y == 1.0);
+
+    let n = Vector3::new(0.48, -0.6, 0.64);
+    assert!(unpack_normal(pack_normal(&n)).dot(&n) > 0.99999);
+    let c = RGBA::new(0.2, 0.4, 0.6, 1.0);
+    assert!(
+        unpack_rgba8(pack_rgba8(&c)) == RGBA::new(51.0 / 255.0, 102.0 / 255.0, 153.0 / 255.0, 1.0)
+    );
+    assert!(unpack_rgb10_a2(pack_rgb10_a2(&c)).a == 1.0);
+
+    // Shared exponents keep the largest channel to within its mantissa's
+    // precision across the whole HDR range.
+    for v in [1e-4f32, 0.3, 1.0, 7.5, 300.0, 60000.0] {
+        let c = RGB::new(v, v * 0.5, 0.0);
+        let e = unpack_rgb9e5(pack_rgb9e5(&c));
+        assert!((e.r - v).abs() <= v / 256.0 && e.b == 0.0);
+        let e = unpack_rgbe(pack_rgbe(&c));
+        assert!((e.r - v).abs() <= v / 128.0);
+    }
+    assert!(unpack_rgb9e5(pack_rgb9e5(&RGB::new(1e9, -1.0, 0.0))).r == 65408.0);
+    assert!(unpack_rgb9e5(pack_rgb9e5(&RGB::new(0.0, 0.0, 0.0))) == RGB::new(0.0, 0.0, 0.0));
+    assert!(pack_rgbe(&RGB::new(0.0, 0.0, 0.0)) == 0);
+    // Colors beyond the format, even infinite ones, keep the top exponent
+    // byte rather than wrapping it.
+    let top = pack_rgbe(&RGB::new(f32::MAX, f32::INFINITY, 1.0));
+    assert!(top >> 24 == 255 && top & 0xffff == 0xffff);
+    assert!(unpack_rgbe(top).r > 1e38 && unpack_rgbe(top).r.is_finite());
+    assert!(pack_rgbe(&RGB::new(f32::NAN, 0.0, 0.0)) == 0);
+    // 511.7 rounds its mantissa up to 512 and so to the next exponent.
+    assert!(unpack_rgb9e5(pack_rgb9e5(&RGB::new(511.7, 0.0, 0.0))).r == 512.0);
+}
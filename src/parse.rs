@@ -0,0 +1,183 @@
+//! Parsing of vectors, points, quaternions, matrices and colors from text,
+//! through `FromStr`, so that config files and command line tools can read
+//! math values directly.
+//!
+//! The components are numbers separated by commas or whitespace, and any
+//! parentheses, brackets or semicolons around or between them are ignored,
+//! so `"1.0, 2.0, 3.0"`, `"(1 2 3)"` and `"[1, 2, 3]"` all parse as the same
+//! `Vector3`. Matrices are read in row-major order, the way they are written
+//! and the way `Display` prints them. Colors additionally accept the hex
+//! forms `#rgb`, `#rrggbb` and, for `RGBA`, `#rgba` and `#rrggbbaa`.
+
+use crate::prelude::*;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The ways text can fail to parse as a math value.
+pub enum ParseError {
+    /// A component is not a valid number.
+    InvalidNumber,
+    /// The text has a different number of components than the type.
+    WrongCount {
+        /// The number of components the type has.
+        expected: usize,
+        /// The number of components found.
+        found: usize,
+    },
+    /// A color in `#` notation does not have 3, 4, 6 or 8 hex digits, or
+    /// has an alpha channel where none is allowed.
+    InvalidHex,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidNumber => f.write_str("invalid number"),
+            ParseError::WrongCount { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)
+            }
+            ParseError::InvalidHex => f.write_str("invalid hex color"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+// Reads exactly N numbers from s, skipping separators and brackets.
+fn scalars<S: FromStr + Default + Copy, const N: usize>(s: &str) -> Result<[S; N], ParseError> {
+    let mut a = [S::default(); N];
+    let mut found = 0;
+    let tokens = s
+        .split(|c: char| c.is_whitespace() || ",;()[]".contains(c))
+        .filter(|t| !t.is_empty());
+    for t in tokens {
+        if found < N {
+            a[found] = t.parse().map_err(|_| ParseError::InvalidNumber)?;
+        }
+        found += 1;
+    }
+    if found == N {
+        Ok(a)
+    } else {
+        Err(ParseError::WrongCount { expected: N, found })
+    }
+}
+
+// Reads a color in # notation as r, g, b, a in [0, 1], with an alpha of 1
+// when the notation has none.
+pub(crate) fn hex_color(s: &str, alpha: bool) -> Result<[f32; 4], ParseError> {
+    let digits = s.trim().strip_prefix('#').ok_or(ParseError::InvalidHex)?;
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ParseError::InvalidHex);
+    }
+    let (width, count) = match digits.len() {
+        3 => (1, 3),
+        4 if alpha => (1, 4),
+        6 => (2, 3),
+        8 if alpha => (2, 4),
+        _ => return Err(ParseError::InvalidHex),
+    };
+    let mut c = [1.0; 4];
+    for (i, channel) in c.iter_mut().enumerate().take(count) {
+        let v = u32::from_str_radix(&digits[i * width..(i + 1) * width], 16)
+            .map_err(|_| ParseError::InvalidHex)?;
+        // A single digit d stands for the byte dd, that is d * 17.
+        let byte = if width == 1 { v * 17 } else { v };
+        *channel = byte as f32 / 255.0;
+    }
+    Ok(c)
+}
+
+macro_rules! components {
+    ($t:ident, $s:ty, $n:expr) => {
+        impl FromStr for $t {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                scalars::<$s, $n>(s).map($t::from)
+            }
+        }
+    };
+}
+
+macro_rules! rows {
+    ($t:ident, $s:ty, $n:expr) => {
+        impl FromStr for $t {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                scalars::<$s, { $n * $n }>(s).map(|a| $t::from(a).transpose())
+            }
+        }
+    };
+}
+
+components!(Vector2, f32, 2);
+components!(Vector3, f32, 3);
+components!(Vector4, f32, 4);
+components!(Point2, f32, 2);
+components!(Point3, f32, 3);
+components!(Quarternion, f32, 4);
+rows!(Matrix2, f32, 2);
+rows!(Matrix3, f32, 3);
+rows!(Matrix4, f32, 4);
+components!(DVector2, f64, 2);
+components!(DVector3, f64, 3);
+components!(DVector4, f64, 4);
+components!(DPoint2, f64, 2);
+components!(DPoint3, f64, 3);
+components!(DQuarternion, f64, 4);
+rows!(DMatrix2, f64, 2);
+rows!(DMatrix3, f64, 3);
+rows!(DMatrix4, f64, 4);
+
+impl FromStr for RGB {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim_start().starts_with('#') {
+            let [r, g, b, _] = hex_color(s, false)?;
+            Ok(RGB::new(r, g, b))
+        } else {
+            scalars::<f32, 3>(s).map(RGB::from)
+        }
+    }
+}
+
+impl FromStr for RGBA {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim_start().starts_with('#') {
+            hex_color(s, true).map(RGBA::from)
+        } else {
+            scalars::<f32, 4>(s).map(RGBA::from)
+        }
+    }
+}
+
+#[test]
+fn parsing() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert!("1.0, 2.0, 3.0".parse() == Ok(v));
+    assert!("(1 2 3)".parse() == Ok(v));
+    assert!(" [1,2 , 3] ".parse() == Ok(v));
+    assert!(
+        "1 2".parse::<Vector3>()
+            == Err(ParseError::WrongCount {
+                expected: 3,
+                found: 2
+            })
+    );
+    assert!("1 x 3".parse::<Vector3>() == Err(ParseError::InvalidNumber));
+
+    let m = Matrix3::new(1.0, -2.0, 3.0, 4.0, 5.0, 6.5, 7.0, 8.0, 9.0);
+    assert!(alloc::format!("{}", m).parse() == Ok(m));
+
+    assert!("#ff8800".parse() == Ok(RGB::new(1.0, 0x88 as f32 / 255.0, 0.0)));
+    assert!("#f80".parse::<RGB>() == "#ff8800".parse::<RGB>());
+    assert!("#ff880080".parse::<RGB>() == Err(ParseError::InvalidHex));
+    assert!("#00000000".parse() == Ok(RGBA::new(0.0, 0.0, 0.0, 0.0)));
+    assert!("#12345g".parse::<RGB>() == Err(ParseError::InvalidHex));
+}
@@ -0,0 +1,201 @@
+//! Inertia tensors for rigid-body simulation. Each function returns the
+//! tensor of a uniform solid of the given mass about its center of mass, in
+//! the body's local frame, as a `Matrix3`. `parallel_axis` moves a tensor
+//! to another point and `rotate_inertia` turns it into world space, so that
+//! compound bodies can be built by summing the tensors of their parts.
+//!
+//! Cylinders and capsules have their axis along y.
+
+use crate::prelude::*;
+use core::f32::consts::PI;
+
+/// Returns the inertia tensor of a solid box about its center.
+///
+/// # Arguments
+///
+/// * `mass` - The mass of the box.
+/// * `half_extents` - A reference to the half widths of the box along x, y
+///   and z.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::physics::box_inertia;
+/// use math_engine::vector3::Vector3;
+/// // A 2 x 2 x 2 cube of mass 3 has moment m (2² + 2²) / 12 = 2 about each axis.
+/// let i = box_inertia(3.0, &Vector3::new(1.0, 1.0, 1.0));
+/// assert!(i.diagonal() == Vector3::new(2.0, 2.0, 2.0));
+/// ```
+pub fn box_inertia(mass: f32, half_extents: &Vector3) -> Matrix3 {
+    let h = *half_extents * *half_extents;
+    Matrix3::make_scale(h.y + h.z, h.x + h.z, h.x + h.y) * (mass / 3.0)
+}
+
+/// Returns the inertia tensor of a solid sphere about its center.
+///
+/// # Arguments
+///
+/// * `mass` - The mass of the sphere.
+/// * `radius` - The radius of the sphere.
+pub fn sphere_inertia(mass: f32, radius: f32) -> Matrix3 {
+    let i = 0.4 * mass * radius * radius;
+    Matrix3::make_scale(i, i, i)
+}
+
+/// Returns the inertia tensor of a solid cylinder about its center, with
+/// its axis along y.
+///
+/// # Arguments
+///
+/// * `mass` - The mass of the cylinder.
+/// * `radius` - The radius of the cylinder.
+/// * `half_height` - Half the length of the cylinder along its axis.
+pub fn cylinder_inertia(mass: f32, radius: f32, half_height: f32) -> Matrix3 {
+    let r2 = radius * radius;
+    let side = mass * (r2 / 4.0 + half_height * half_height / 3.0);
+    Matrix3::make_scale(side, 0.5 * mass * r2, side)
+}
+
+/// Returns the inertia tensor of a solid capsule about its center, with its
+/// axis along y. The mass is shared between the cylinder and the two
+/// hemispherical caps by volume.
+///
+/// # Arguments
+///
+/// * `mass` - The mass of the capsule.
+/// * `radius` - The radius of the capsule.
+/// * `half_height` - Half the length of the core segment, not counting the
+///   caps.
+pub fn capsule_inertia(mass: f32, radius: f32, half_height: f32) -> Matrix3 {
+    let (r, h) = (radius, half_height);
+    let r2 = r * r;
+    let cylinder = PI * r2 * 2.0 * h;
+    let caps = 4.0 / 3.0 * PI * r2 * r;
+    // A capsule without radius is a thin rod, all cylinder.
+    let mc = if r > 0.0 {
+        mass * cylinder / (cylinder + caps)
+    } else {
+        mass
+    };
+    let ms = mass - mc;
+    // Each cap's moment about a perpendicular axis through its flat face
+    // is 2/5 m r², moved out past the end of the cylinder.
+    let side = mc * (r2 / 4.0 + h * h / 3.0) + ms * (0.4 * r2 + h * h + 0.75 * h * r);
+    let axial = mc * r2 * 0.5 + ms * 0.4 * r2;
+    Matrix3::make_scale(side, axial, side)
+}
+
+/// Returns the center of mass of a set of point masses and their inertia
+/// tensor about it. An empty set, or one without mass, gives the origin
+/// and a zero tensor.
+///
+/// # Arguments
+///
+/// * `points` - The positions of the masses.
+/// * `masses` - The mass at each position.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::physics::point_set_inertia;
+/// use math_engine::point3::Point3;
+/// // A dumbbell along x does not resist spinning about x.
+/// let points = [Point3::new(-1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)];
+/// let (center, i) = point_set_inertia(&points, &[1.0, 1.0]);
+/// assert!(center == Point3::new(0.0, 0.0, 0.0));
+/// assert!(i.diagonal() == math_engine::vector3::Vector3::new(0.0, 2.0, 2.0));
+/// ```
+pub fn point_set_inertia(points: &[Point3], masses: &[f32]) -> (Point3, Matrix3) {
+    let mut total = 0.0;
+    let mut moment = Vector3::new(0.0, 0.0, 0.0);
+    for (p, m) in points.iter().zip(masses) {
+        total += m;
+        moment += Vector3::new(p.x, p.y, p.z) * *m;
+    }
+    if total == 0.0 {
+        return (
+            Point3::new(0.0, 0.0, 0.0),
+            Matrix3::make_scale(0.0, 0.0, 0.0),
+        );
+    }
+    let c = moment / total;
+    let center = Point3::new(c.x, c.y, c.z);
+    let mut inertia = Matrix3::make_scale(0.0, 0.0, 0.0);
+    for (p, m) in points.iter().zip(masses) {
+        inertia += point_tensor(*m, &(*p - center));
+    }
+    (center, inertia)
+}
+
+// Returns the inertia tensor of a point mass m at offset d, m (|d|² E - d dᵀ).
+fn point_tensor(m: f32, d: &Vector3) -> Matrix3 {
+    let d2 = d.dot(d);
+    (Matrix3::make_scale(d2, d2, d2) - Matrix3::outer_product(d, d)) * m
+}
+
+/// Returns the inertia tensor about a point offset from the center of mass,
+/// by the parallel axis theorem. The tensor given must be about the center
+/// of mass.
+///
+/// # Arguments
+///
+/// * `inertia` - A reference to the tensor about the center of mass.
+/// * `mass` - The mass of the body.
+/// * `offset` - A reference to the vector from the new point to the center
+///   of mass.
+pub fn parallel_axis(inertia: &Matrix3, mass: f32, offset: &Vector3) -> Matrix3 {
+    *inertia + point_tensor(mass, offset)
+}
+
+/// Returns the inertia tensor rotated from the body's frame into the world,
+/// R I Rᵀ.
+///
+/// # Arguments
+///
+/// * `inertia` - A reference to the tensor in the body's frame.
+/// * `rotation` - A reference to the rotation from the body's frame to the
+///   world.
+pub fn rotate_inertia(inertia: &Matrix3, rotation: &Matrix3) -> Matrix3 {
+    *rotation * *inertia * rotation.transpose()
+}
+
+#[test]
+fn inertia() {
+    let close = |a: &Matrix3, b: &Matrix3| (0..3).all(|i| (a[i] - b[i]).magnitude() < 1e-4);
+    // Point samples of a solid approach its tensor.
+    let n = 20;
+    let (mut points, half) = (alloc::vec::Vec::new(), Vector3::new(1.0, 2.0, 0.5));
+    for i in 0..n {
+        for j in 0..n {
+            for k in 0..n {
+                let f = |i: usize, h: f32| ((i as f32 + 0.5) / n as f32 * 2.0 - 1.0) * h;
+                points.push(Point3::new(f(i, half.x) + 3.0, f(j, half.y), f(k, half.z)));
+            }
+        }
+    }
+    let masses = alloc::vec![6.0 / points.len() as f32; points.len()];
+    let (center, sampled) = point_set_inertia(&points, &masses);
+    assert!((center - Point3::new(3.0, 0.0, 0.0)).magnitude() < 1e-3);
+    let exact = box_inertia(6.0, &half);
+    assert!((0..3).all(|i| (sampled[i] - exact[i]).magnitude() < 0.05));
+    // A capsule with no core is a sphere, and with no radius a thin rod.
+    assert!(close(
+        &capsule_inertia(2.0, 1.5, 0.0),
+        &sphere_inertia(2.0, 1.5)
+    ));
+    assert!(close(
+        &capsule_inertia(3.0, 0.0, 2.0),
+        &cylinder_inertia(3.0, 0.0, 2.0)
+    ));
+    // A sphere's tensor is the same in every frame, and the trace is kept
+    // under rotation.
+    let r = Matrix3::make_rotation(0.7, &Vector3::new(1.0, 2.0, 2.0).to_unit());
+    let s = sphere_inertia(2.0, 1.0);
+    assert!(close(&rotate_inertia(&s, &r), &s));
+    let b = rotate_inertia(&exact, &r);
+    assert!((b.trace() - exact.trace()).abs() < 1e-4);
+    // Moving a rod's axis from its center to its end: m L² / 12 to m L² / 3.
+    let rod = cylinder_inertia(3.0, 0.0, 1.0);
+    let end = parallel_axis(&rod, 3.0, &Vector3::new(0.0, 1.0, 0.0));
+    assert!((end[(0, 0)] - 4.0).abs() < 1e-5 && end[(1, 1)] == 0.0);
+}
@@ -1,7 +1,9 @@
 use crate::prelude::*;
-use std::ops::Mul;
+use core::ops::Mul;
 
-#[derive(Default, Copy, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct Plane {
     pub x: f32,
     pub y: f32,
@@ -9,12 +11,30 @@ pub struct Plane {
     pub w: f32,
 }
 
+/// The side of a plane a point lies on, as returned by `Plane::classify_point`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PlaneSide {
+    /// In the half-space the normal points into.
+    Front,
+    /// In the half-space opposite the normal.
+    Back,
+    /// On the plane, within the given tolerance.
+    On,
+}
+
 impl Plane {
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Plane {
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Plane {
         Plane { x, y, z, w }
     }
 
-    pub fn new_with_vec(v: &Vector3, d: f32) -> Plane {
+    /// Returns the plane with the unit normal v and distance d, the same as
+    /// `Plane::new_with_normal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - The unit length normal.
+    /// * `d` - The signed distance of the origin from the plane.
+    pub fn new_with_vec(v: &UnitVector3, d: f32) -> Plane {
         Plane {
             x: v.x,
             y: v.y,
@@ -23,8 +43,50 @@ impl Plane {
         }
     }
 
+    /// Returns the plane with the unit normal n and distance d, whose
+    /// distances are exact without normalizing.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The unit length normal.
+    /// * `d` - The signed distance of the origin from the plane.
+    pub fn new_with_normal(n: &UnitVector3, d: f32) -> Plane {
+        Plane::new_with_vec(n, d)
+    }
+
+    /// Returns the plane through the points a, b and c, with a unit normal
+    /// pointing toward the side from which a, b, c appear counterclockwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a, b and c are collinear, so that they span no plane.
+    pub fn from_points(a: &Point3, b: &Point3, c: &Point3) -> Plane {
+        let n = (*b - *a).cross(&(*c - *a)).to_unit();
+        Plane::from_point_normal(a, &n)
+    }
+
+    /// Returns the plane through the point p with the unit normal n.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A point on the plane.
+    /// * `n` - The unit length normal.
+    pub fn from_point_normal(p: &Point3, n: &UnitVector3) -> Plane {
+        Plane::new_with_vec(n, -(n.x * p.x + n.y * p.y + n.z * p.z))
+    }
+
     pub fn get_normal(&self) -> Vector3 {
-        Vector3::new(self.x, self.x, self.x)
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    /// Returns this plane scaled so that its normal has unit length.
+    pub fn normalize(&self) -> Plane {
+        let m = self.get_normal().magnitude();
+        Plane::new(self.x / m, self.y / m, self.z / m, self.w / m)
+    }
+
+    pub fn normalize_mut(&mut self) {
+        *self = self.normalize();
     }
 
     pub fn vec_dot(&self, v: &Vector3) -> f32 {
@@ -34,6 +96,81 @@ impl Plane {
     pub fn point_dot(&self, v: &Point3) -> f32 {
         self.x * v.x + self.y * v.y + self.z * v.z + self.w
     }
+
+    /// Returns the signed distance from p to this plane, positive on the side
+    /// the normal points into. The normal does not need to have unit length.
+    pub fn distance_to_point(&self, p: &Point3) -> f32 {
+        self.point_dot(p) / self.get_normal().magnitude()
+    }
+
+    /// Returns the point on this plane closest to p.
+    pub fn project_point(&self, p: &Point3) -> Point3 {
+        let n = self.get_normal();
+        *p - n * (self.point_dot(p) / n.dot(&n))
+    }
+
+    /// Returns which side of this plane p lies on. Points whose distance to
+    /// the plane is at most epsilon are classified as `PlaneSide::On`.
+    pub fn classify_point(&self, p: &Point3, epsilon: f32) -> PlaneSide {
+        let d = self.distance_to_point(p);
+        if d > epsilon {
+            PlaneSide::Front
+        } else if d < -epsilon {
+            PlaneSide::Back
+        } else {
+            PlaneSide::On
+        }
+    }
+
+    /// Returns the mirror image of the point p through this plane.
+    pub fn reflect_point(&self, p: &Point3) -> Point3 {
+        let n = self.get_normal();
+        *p - n * (2.0 * self.point_dot(p) / n.dot(&n))
+    }
+
+    /// Returns the mirror image of the direction v through this plane. Unlike
+    /// points, directions are unaffected by the offset of the plane.
+    pub fn reflect_vector(&self, v: &Vector3) -> Vector3 {
+        let n = self.get_normal();
+        *v - n * (2.0 * self.vec_dot(v) / n.dot(&n))
+    }
+
+    /// Returns the plane minimizing the sum of squared distances to the
+    /// points, or None if there are fewer than three or they are collinear.
+    /// The plane passes through the centroid, and its unit normal is the
+    /// direction in which the points vary least; which way it faces is
+    /// arbitrary.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points to fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::plane::Plane;
+    /// use math_engine::point3::Point3;
+    /// let points = [
+    ///     Point3::new(0.0, 0.0, 1.1),
+    ///     Point3::new(4.0, 0.0, 0.9),
+    ///     Point3::new(0.0, 4.0, 0.9),
+    ///     Point3::new(4.0, 4.0, 1.1),
+    /// ];
+    /// let f = Plane::fit(&points).unwrap();
+    /// assert!(f.get_normal().z.abs() > 0.999);
+    /// assert!(f.distance_to_point(&Point3::new(2.0, 2.0, 1.0)).abs() < 1e-5);
+    /// ```
+    pub fn fit(points: &[Point3]) -> Option<Plane> {
+        if points.len() < 3 {
+            return None;
+        }
+        let (values, vectors) = crate::statistics::principal_axes(points)?;
+        if values[1] <= f32::EPSILON * values[0] {
+            return None;
+        }
+        let centroid = crate::statistics::mean(points)?;
+        Some(Plane::from_point_normal(&centroid, &vectors[2].to_unit()))
+    }
 }
 
 impl Mul<Transform4> for Plane {
@@ -49,34 +186,122 @@ impl Mul<Transform4> for Plane {
     }
 }
 
-// calculates the point p at which three planes f1, f2, and f3 intersect and returns
-// true if such a point exists. Returns false if the normal vectors are not linearly independent
+/// Returns the point at which the three planes f1, f2 and f3 meet, or None
+/// if their normals are not linearly independent.
+///
+/// # Arguments
+///
+/// * `f1` - A reference to the first plane.
+/// * `f2` - A reference to the second plane.
+/// * `f3` - A reference to the third plane.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::plane::{three_planes_intersect, Plane};
+/// use math_engine::point3::Point3;
+/// let x = Plane::new(1.0, 0.0, 0.0, -1.0);
+/// let y = Plane::new(0.0, 2.0, 0.0, -4.0);
+/// let z = Plane::new(0.0, 0.0, 1.0, 3.0);
+/// assert!(three_planes_intersect(&x, &y, &z) == Some(Point3::new(1.0, 2.0, -3.0)));
+/// assert!(three_planes_intersect(&x, &y, &x).is_none());
+/// ```
 pub fn three_planes_intersect(f1: &Plane, f2: &Plane, f3: &Plane) -> Option<Point3> {
     let n1 = f1.get_normal();
     let n2 = f2.get_normal();
     let n3 = f3.get_normal();
     let n1xn2 = n1.cross(&n2);
     let det = n1xn2.dot(&n3);
-    if det.abs() <= f32::MIN {
+    if det.abs() <= f32::EPSILON * n1.magnitude() * n2.magnitude() * n3.magnitude() {
         return None;
     }
     Some(Point3::from(
-        n3.cross(&n2) * f1.w + n1.cross(&n3) * f2.w - n1xn2 * f3.w / det,
+        (n3.cross(&n2) * f1.w + n1.cross(&n3) * f2.w - n1xn2 * f3.w) / det,
     ))
 }
 
-// calculates the line determined by the point p and the direction v at which
-// two planes f1 and f2 intersect and returns true if it exists. Returns false
-// if the normal vectors are parallel.
-pub fn two_planes_intersect(f1: &Plane, f2: &Plane, v: &Vector3) -> Option<Point3> {
+/// Returns the line along which the planes f1 and f2 meet, directed along
+/// the cross product of their normals, or None if they are parallel.
+///
+/// # Arguments
+///
+/// * `f1` - A reference to the first plane.
+/// * `f2` - A reference to the second plane.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::plane::{two_planes_intersect, Plane};
+/// use math_engine::point3::Point3;
+/// use math_engine::vector3::Vector3;
+/// let x = Plane::new(1.0, 0.0, 0.0, -1.0);
+/// let z = Plane::new(0.0, 0.0, 1.0, 0.0);
+/// let l = two_planes_intersect(&z, &x).unwrap();
+/// assert!(l.get_direction() == Vector3::new(0.0, 1.0, 0.0));
+/// assert!(l.get_point() == Point3::new(1.0, 0.0, 0.0));
+/// ```
+pub fn two_planes_intersect(f1: &Plane, f2: &Plane) -> Option<Line> {
     let n1 = f1.get_normal();
     let n2 = f2.get_normal();
-
-    let det = n1.cross(&n2).dot(v);
-    if det.abs() <= f32::MIN {
+    let v = n1.cross(&n2);
+    if v.dot(&v) <= f32::EPSILON * n1.dot(&n1) * n2.dot(&n2) {
         return None;
     }
-    Some(Point3::from(
-        (v.cross(&n2) * f1.w + n1.cross(v) * f2.w) / det,
-    ))
+    Some(Line::new_with_vecs(v, n2 * f1.w - n1 * f2.w))
+}
+
+#[test]
+fn queries() {
+    let f = Plane::from_points(
+        &Point3::new(0.0, 0.0, 2.0),
+        &Point3::new(1.0, 0.0, 2.0),
+        &Point3::new(0.0, 1.0, 2.0),
+    );
+    assert!(f == Plane::new(0.0, 0.0, 1.0, -2.0));
+    assert!(f.get_normal() == Vector3::new(0.0, 0.0, 1.0));
+
+    let g = Plane::new(0.0, 0.0, 4.0, -8.0);
+    assert!(g.normalize() == f);
+    assert!(g.distance_to_point(&Point3::new(5.0, 1.0, -1.0)) == -3.0);
+
+    let p = Point3::new(3.0, 4.0, 5.0);
+    assert!(f.project_point(&p) == Point3::new(3.0, 4.0, 2.0));
+    assert!(g.reflect_point(&p) == Point3::new(3.0, 4.0, -1.0));
+    assert!(f.reflect_vector(&Vector3::new(1.0, 0.0, 1.0)) == Vector3::new(1.0, 0.0, -1.0));
+
+    assert!(f.classify_point(&p, 0.001) == PlaneSide::Front);
+    assert!(f.classify_point(&Point3::new(0.0, 0.0, 1.0), 0.001) == PlaneSide::Back);
+    assert!(f.classify_point(&Point3::new(9.0, 9.0, 2.0), 0.001) == PlaneSide::On);
+}
+
+#[test]
+fn fitting() {
+    // Points scattered over a tilted plane are fit exactly, and noise
+    // symmetric about it does not move it.
+    let f = Plane::from_point_normal(
+        &Point3::new(1.0, 2.0, 3.0),
+        &Vector3::new(1.0, 2.0, 2.0).to_unit(),
+    );
+    let (u, v) = (Vector3::new(2.0, -1.0, 0.0), Vector3::new(2.0, 4.0, -5.0));
+    let mut points = alloc::vec::Vec::new();
+    for i in 0..20 {
+        let (s, t) = ((i % 5) as f32 - 2.0, (i / 5) as f32 - 1.5);
+        let p = Point3::new(1.0, 2.0, 3.0) + u * s + v * t;
+        points.push(p + f.get_normal() * 0.01);
+        points.push(p - f.get_normal() * 0.01);
+    }
+    let g = Plane::fit(&points).unwrap();
+    let g = if g.vec_dot(&f.get_normal()) < 0.0 {
+        Plane::new(-g.x, -g.y, -g.z, -g.w)
+    } else {
+        g
+    };
+    assert!((g.get_normal() - f.get_normal()).magnitude() < 1e-4);
+    assert!(g.point_dot(&Point3::new(1.0, 2.0, 3.0)).abs() < 1e-4);
+    // Collinear and too few points have no plane.
+    let line: alloc::vec::Vec<Point3> = (0..4)
+        .map(|i| Point3::new(i as f32, 2.0 * i as f32, 0.0))
+        .collect();
+    assert!(Plane::fit(&line).is_none());
+    assert!(Plane::fit(&points[..2]).is_none());
 }
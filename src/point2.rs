@@ -1,15 +1,20 @@
 use crate::prelude::*;
-use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
-#[derive(Default, Copy, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A three dimensional positional vector having float components
 /// x, and y. It's w coordinated it's assumed to be 0.
+#[repr(C)]
 pub struct Point2 {
     pub x: f32,
     pub y: f32,
 }
 
 impl Point2 {
+    /// The zero point, the origin.
+    pub const ZERO: Point2 = Point2::new(0.0, 0.0);
+
     /// Returns a positional vector initialized with the floating point components x, and y.
     ///
     /// # Arguments
@@ -23,9 +28,43 @@ impl Point2 {
     /// use math_engine::point2::Point2;
     /// let p = Point2::new(1.0,0.0);
     /// ```
-    pub fn new(x: f32, y: f32) -> Point2 {
+    pub const fn new(x: f32, y: f32) -> Point2 {
         Point2 { x, y }
     }
+
+    /// Returns the point with every component set to s.
+    pub const fn splat(s: f32) -> Point2 {
+        Point2::new(s, s)
+    }
+
+    /// Returns the distance between this point and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a point2.
+    pub fn distance(&self, other: &Point2) -> f32 {
+        (*self - *other).magnitude()
+    }
+
+    /// Returns the squared distance between this point and other, which
+    /// orders points by distance without a square root.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a point2.
+    pub fn distance_squared(&self, other: &Point2) -> f32 {
+        let d = *self - *other;
+        d.dot(&d)
+    }
+
+    /// Returns the point halfway between this point and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a point2.
+    pub fn midpoint(&self, other: &Point2) -> Point2 {
+        Point2::new((self.x + other.x) * 0.5, (self.y + other.y) * 0.5)
+    }
 }
 
 impl Add<Vector2> for Point2 {
@@ -44,6 +83,14 @@ impl Sub<Vector2> for Point2 {
     }
 }
 
+impl Neg for Point2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
 impl Sub<Point2> for Point2 {
     type Output = Vector2;
 
@@ -57,7 +104,7 @@ impl Mul<Matrix4> for Vector2 {
 
     fn mul(self, other: Matrix4) -> Self::Output {
         Vector4::new(
-            other[(0, 0)] * self.x + other[(0, 1)] * self.y,
+            other[(0, 0)] * self.x + other[(1, 0)] * self.y,
             other[(0, 1)] * self.x + other[(1, 1)] * self.y,
             other[(0, 2)] * self.x + other[(1, 2)] * self.y,
             other[(0, 3)] * self.x + other[(1, 3)] * self.y,
@@ -70,10 +117,35 @@ impl Mul<Matrix4> for Point2 {
 
     fn mul(self, other: Matrix4) -> Self::Output {
         Vector4::new(
-            other[(0, 0)] * self.x + other[(0, 1)] * self.y + other[(3, 0)],
+            other[(0, 0)] * self.x + other[(1, 0)] * self.y + other[(3, 0)],
             other[(0, 1)] * self.x + other[(1, 1)] * self.y + other[(3, 1)],
             other[(0, 2)] * self.x + other[(1, 2)] * self.y + other[(3, 2)],
             other[(0, 3)] * self.x + other[(1, 3)] * self.y + other[(3, 3)],
         )
     }
 }
+
+impl AddAssign<Vector2> for Point2 {
+    fn add_assign(&mut self, rhs: Vector2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl SubAssign<Vector2> for Point2 {
+    fn sub_assign(&mut self, rhs: Vector2) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+#[test]
+fn row_vector_product() {
+    let m = Matrix4::new(
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    );
+    let v = Vector2::new(1.0, 2.0) * m;
+    assert!(v.x == 11.0 && v.y == 14.0 && v.z == 17.0 && v.w == 20.0);
+    let p = Point2::new(1.0, 2.0) * m;
+    assert!(p.x == 24.0 && p.y == 28.0 && p.z == 32.0 && p.w == 36.0);
+}
@@ -1,9 +1,11 @@
 use crate::prelude::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A three dimensional positional vector having float components
 /// x, y, and z. It's w coordinated it's assumed to be 0.
+#[repr(C)]
 pub struct Point3 {
     /// The x component.
     pub x: f32,
@@ -14,6 +16,9 @@ pub struct Point3 {
 }
 
 impl Point3 {
+    /// The zero point, the origin.
+    pub const ZERO: Point3 = Point3::new(0.0, 0.0, 0.0);
+
     /// Returns a positional vector initialized with the floating point components x, y, and z.
     ///
     /// # Arguments
@@ -28,9 +33,57 @@ impl Point3 {
     /// use math_engine::point3::Point3;
     /// let point3 = Point3::new(1.0,0.0,0.0);
     /// ```
-    pub fn new(x: f32, y: f32, z: f32) -> Point3 {
+    pub const fn new(x: f32, y: f32, z: f32) -> Point3 {
         Point3 { x, y, z }
     }
+
+    /// Returns the point with every component set to s.
+    pub const fn splat(s: f32) -> Point3 {
+        Point3::new(s, s, s)
+    }
+
+    /// Returns the distance between this point and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a point3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// let a = Point3::new(1.0, 2.0, 3.0);
+    /// let b = Point3::new(3.0, 5.0, -3.0);
+    /// assert!(a.distance(&b) == 7.0);
+    /// assert!(a.distance_squared(&b) == 49.0);
+    /// ```
+    pub fn distance(&self, other: &Point3) -> f32 {
+        (*self - *other).magnitude()
+    }
+
+    /// Returns the squared distance between this point and other, which
+    /// orders points by distance without a square root.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a point3.
+    pub fn distance_squared(&self, other: &Point3) -> f32 {
+        let d = *self - *other;
+        d.dot(&d)
+    }
+
+    /// Returns the point halfway between this point and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a point3.
+    pub fn midpoint(&self, other: &Point3) -> Point3 {
+        Point3::new(
+            (self.x + other.x) * 0.5,
+            (self.y + other.y) * 0.5,
+            (self.z + other.z) * 0.5,
+        )
+    }
 }
 
 impl Add<Vector3> for Point3 {
@@ -49,6 +102,14 @@ impl Sub<Vector3> for Point3 {
     }
 }
 
+impl Neg for Point3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
 impl Sub for Point3 {
     type Output = Vector3;
 
@@ -62,7 +123,7 @@ impl Mul<Matrix4> for Vector3 {
 
     fn mul(self, other: Matrix4) -> Vector4 {
         Vector4::new(
-            other[(0, 0)] * self.x + other[(0, 1)] * self.y + other[(2, 0)] * self.z,
+            other[(0, 0)] * self.x + other[(1, 0)] * self.y + other[(2, 0)] * self.z,
             other[(0, 1)] * self.x + other[(1, 1)] * self.y + other[(2, 1)] * self.z,
             other[(0, 2)] * self.x + other[(1, 2)] * self.y + other[(2, 2)] * self.z,
             other[(0, 3)] * self.x + other[(1, 3)] * self.y + other[(2, 3)] * self.z,
@@ -76,7 +137,7 @@ impl Mul<Matrix4> for Point3 {
     fn mul(self, other: Matrix4) -> Self::Output {
         Vector4::new(
             other[(0, 0)] * self.x
-                + other[(0, 1)] * self.y
+                + other[(1, 0)] * self.y
                 + other[(2, 0)] * self.z
                 + other[(3, 0)],
             other[(0, 1)] * self.x
@@ -108,8 +169,8 @@ impl From<Vector3> for Point3 {
 // Returns the distance between the point q and the line determined by the point
 // p and the direction v.
 pub fn point_line_distance(q: &Point3, p: &Point3, v: &Vector3) -> f32 {
-    let a = (*q - *p).cross(&v);
-    f32::sqrt(a.dot(&a) / v.dot(&v))
+    let a = (*q - *p).cross(v);
+    f32::sqrt(a.dot(&a) / v.dot(v))
 }
 
 // Returns the distance between two lines determined by the points p1 and p2 and the
@@ -117,22 +178,22 @@ pub fn point_line_distance(q: &Point3, p: &Point3, v: &Vector3) -> f32 {
 pub fn line_line_distance(p1: &Point3, v1: &Vector3, p2: &Point3, v2: &Vector3) -> f32 {
     let dp = *p2 - *p1;
 
-    let v12 = v1.dot(&v1);
-    let v22 = v2.dot(&v2);
-    let v1v2 = v1.dot(&v2);
+    let v12 = v1.dot(v1);
+    let v22 = v2.dot(v2);
+    let v1v2 = v1.dot(v2);
 
     let mut det = v1v2 * v1v2 - v12 * v22;
 
     if det.abs() > f32::MIN {
         det = 1.0 / det;
 
-        let dpv1 = dp.dot(&v1);
-        let dpv2 = dp.dot(&v2);
+        let dpv1 = dp.dot(v1);
+        let dpv2 = dp.dot(v2);
         let t1 = (v1v2 * dpv2 - v22 * dpv1) * det;
         let t2 = (v12 * dpv2 - v1v2 * dpv1) * det;
         (dp + *v2 * t2 - *v1 * t1).magnitude()
     } else {
-        let a = dp.cross(&v1);
+        let a = dp.cross(v1);
         f32::sqrt(a.dot(&a) / v12)
     }
 }
@@ -148,3 +209,79 @@ pub fn plane_line_intersect(p: &Point3, v: &Vector3, f: &Plane) -> Option<Point3
         None
     }
 }
+
+/// Returns the centroid of the points, their average, or None if there are
+/// none.
+///
+/// # Arguments
+///
+/// * `points` - A slice of points.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point3::{centroid, Point3};
+/// let ps = [Point3::new(0.0, 0.0, 0.0), Point3::new(4.0, 0.0, 0.0), Point3::new(2.0, 6.0, 3.0)];
+/// assert!(centroid(&ps) == Some(Point3::new(2.0, 2.0, 1.0)));
+/// assert!(centroid(&[]).is_none());
+/// ```
+pub fn centroid(points: &[Point3]) -> Option<Point3> {
+    if points.is_empty() {
+        return None;
+    }
+    let sum = points
+        .iter()
+        .fold(Vector3::default(), |s, p| s + Vector3::new(p.x, p.y, p.z));
+    let c = sum / points.len() as f32;
+    Some(Point3::new(c.x, c.y, c.z))
+}
+
+/// Returns the component-wise minimum and maximum of the points, the corners
+/// of their axis-aligned bounding box, or None if there are none.
+///
+/// # Arguments
+///
+/// * `points` - A slice of points.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point3::{bounding_points, Point3};
+/// let ps = [Point3::new(1.0, -2.0, 0.0), Point3::new(-1.0, 4.0, 2.0)];
+/// let (lo, hi) = bounding_points(&ps).unwrap();
+/// assert!(lo == Point3::new(-1.0, -2.0, 0.0) && hi == Point3::new(1.0, 4.0, 2.0));
+/// ```
+pub fn bounding_points(points: &[Point3]) -> Option<(Point3, Point3)> {
+    let (first, rest) = points.split_first()?;
+    Some(
+        rest.iter()
+            .fold((*first, *first), |(lo, hi), p| (lo.min(p), hi.max(p))),
+    )
+}
+
+impl AddAssign<Vector3> for Point3 {
+    fn add_assign(&mut self, rhs: Vector3) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl SubAssign<Vector3> for Point3 {
+    fn sub_assign(&mut self, rhs: Vector3) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+#[test]
+fn row_vector_product() {
+    let m = Matrix4::new(
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    );
+    let v = Vector3::new(1.0, 2.0, 3.0) * m;
+    assert!(v.x == 38.0 && v.y == 44.0 && v.z == 50.0 && v.w == 56.0);
+    let p = Point3::new(1.0, 2.0, 3.0) * m;
+    assert!(p.x == 51.0 && p.y == 58.0 && p.z == 65.0 && p.w == 72.0);
+}
@@ -0,0 +1,317 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::predicates::{orient2d, point_in_triangle};
+use crate::prelude::*;
+use alloc::vec::Vec;
+
+/// A closed polygon in the plane given by its corners in order, with an edge
+/// from the last corner back to the first.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polygon2 {
+    points: Vec<Point2>,
+}
+
+impl Polygon2 {
+    /// Returns the polygon with the given corners in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The corners of the polygon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point2::Point2;
+    /// use math_engine::polygon2::Polygon2;
+    /// let p = Polygon2::new(vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0), Point2::new(0.0, 1.0)]);
+    /// assert!(p.points().len() == 3);
+    /// ```
+    pub fn new(points: Vec<Point2>) -> Polygon2 {
+        Polygon2 { points }
+    }
+
+    /// Returns the corners of this polygon.
+    pub fn points(&self) -> &[Point2] {
+        &self.points
+    }
+
+    /// Returns the area of this polygon, positive if its corners run
+    /// counterclockwise and negative if they run clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point2::Point2;
+    /// use math_engine::polygon2::Polygon2;
+    /// let mut p = Polygon2::new(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(4.0, 0.0),
+    ///     Point2::new(4.0, 3.0),
+    ///     Point2::new(0.0, 3.0),
+    /// ]);
+    /// assert!(p.signed_area() == 12.0);
+    /// p.reverse();
+    /// assert!(p.signed_area() == -12.0 && p.area() == 12.0);
+    /// ```
+    pub fn signed_area(&self) -> f32 {
+        let n = self.points.len();
+        if n < 3 {
+            return 0.0;
+        }
+        // Measured from the first corner, which keeps the terms small for
+        // polygons far from the origin.
+        let o = self.points[0];
+        let mut sum = 0.0;
+        for i in 1..n - 1 {
+            sum += (self.points[i] - o).perp_dot(&(self.points[i + 1] - o));
+        }
+        sum * 0.5
+    }
+
+    /// Returns the area of this polygon regardless of its winding.
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    /// Returns true if the corners of this polygon run counterclockwise.
+    pub fn is_counterclockwise(&self) -> bool {
+        self.signed_area() > 0.0
+    }
+
+    /// Reverses the order of the corners, flipping the winding.
+    pub fn reverse(&mut self) {
+        self.points.reverse();
+    }
+
+    /// Returns true if the point p lies inside this polygon, by its winding
+    /// number, so regions a self-intersecting polygon winds around more than
+    /// once are inside. Points on the boundary may fall either way.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point2::Point2;
+    /// use math_engine::polygon2::Polygon2;
+    /// // An L shape.
+    /// let p = Polygon2::new(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(2.0, 0.0),
+    ///     Point2::new(2.0, 1.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(0.0, 2.0),
+    /// ]);
+    /// assert!(p.contains_point(&Point2::new(0.5, 1.5)));
+    /// assert!(!p.contains_point(&Point2::new(1.5, 1.5)));
+    /// ```
+    pub fn contains_point(&self, p: &Point2) -> bool {
+        let n = self.points.len();
+        let mut winding = 0;
+        for i in 0..n {
+            let (a, b) = (self.points[i], self.points[(i + 1) % n]);
+            // Count the edges crossing the horizontal ray to the right of p,
+            // upward crossings with p on their left and downward ones with p
+            // on their right.
+            if a.y <= p.y {
+                if b.y > p.y && orient2d(a, b, *p) > 0.0 {
+                    winding += 1;
+                }
+            } else if b.y <= p.y && orient2d(a, b, *p) < 0.0 {
+                winding -= 1;
+            }
+        }
+        winding != 0
+    }
+
+    /// Returns true if this polygon is convex: it turns the same way at
+    /// every corner and goes around only once. Corners where it runs
+    /// straight on are allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point2::Point2;
+    /// use math_engine::polygon2::Polygon2;
+    /// let square = Polygon2::new(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(0.0, 1.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(1.0, 0.0),
+    /// ]);
+    /// assert!(square.is_convex());
+    /// let dart = Polygon2::new(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(2.0, 1.0),
+    ///     Point2::new(0.0, 2.0),
+    ///     Point2::new(1.0, 1.0),
+    /// ]);
+    /// assert!(!dart.is_convex());
+    /// ```
+    pub fn is_convex(&self) -> bool {
+        let n = self.points.len();
+        if n < 3 {
+            return false;
+        }
+        let (mut left, mut right) = (false, false);
+        let mut turning = 0.0;
+        for i in 0..n {
+            let (a, b, c) = (
+                self.points[i],
+                self.points[(i + 1) % n],
+                self.points[(i + 2) % n],
+            );
+            let s = orient2d(a, b, c);
+            left |= s > 0.0;
+            right |= s < 0.0;
+            let (u, v) = (b - a, c - b);
+            turning += u.perp_dot(&v).atan2(u.dot(&v));
+        }
+        // A star turns one way throughout but winds around twice or more.
+        !(left && right) && (turning.abs() - core::f32::consts::TAU).abs() < 0.1
+    }
+
+    /// Returns a triangulation of this polygon by ear clipping, as triples of
+    /// indices into `points` wound the same way as the polygon. A simple
+    /// polygon of n corners gives n - 2 triangles, fewer where corners lie in
+    /// line with their neighbours, which are dropped. Self-intersecting polygons still give
+    /// triangles covering them, though they may overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point2::Point2;
+    /// use math_engine::polygon2::Polygon2;
+    /// let p = Polygon2::new(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(2.0, 0.0),
+    ///     Point2::new(2.0, 1.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(0.0, 2.0),
+    /// ]);
+    /// let triangles = p.triangulate();
+    /// assert!(triangles.len() == 4);
+    /// let area: f32 = triangles
+    ///     .iter()
+    ///     .map(|t| Polygon2::new(t.iter().map(|&i| p.points()[i]).collect()).signed_area())
+    ///     .sum();
+    /// assert!(area == p.signed_area());
+    /// ```
+    pub fn triangulate(&self) -> Vec<[usize; 3]> {
+        let mut remaining: Vec<usize> = (0..self.points.len()).collect();
+        let mut triangles = Vec::new();
+        let sign = if self.signed_area() < 0.0 { -1.0 } else { 1.0 };
+        let mut i = 0;
+        // The number of corners tried since an ear was last clipped.
+        let mut tried = 0;
+        while remaining.len() > 2 {
+            let m = remaining.len();
+            let (prev, cur, next) = (
+                remaining[(i + m - 1) % m],
+                remaining[i % m],
+                remaining[(i + 1) % m],
+            );
+            let (a, b, c) = (self.points[prev], self.points[cur], self.points[next]);
+            let turn = orient2d(a, b, c) * sign;
+            if turn == 0.0 {
+                // A straight corner adds nothing; drop it.
+                remaining.remove(i % m);
+                tried = 0;
+                continue;
+            }
+            // An ear is a convex corner whose triangle holds no other corner.
+            // When none is left, as in a self-intersecting polygon, clip the
+            // corner anyway so the loop ends.
+            let ear = turn > 0.0
+                && !remaining.iter().any(|&j| {
+                    let p = self.points[j];
+                    p != a && p != b && p != c && point_in_triangle(p, a, b, c)
+                });
+            if ear || tried >= m {
+                triangles.push([prev, cur, next]);
+                remaining.remove(i % m);
+                tried = 0;
+            } else {
+                i += 1;
+                tried += 1;
+            }
+        }
+        triangles
+    }
+}
+
+impl From<Vec<Point2>> for Polygon2 {
+    fn from(points: Vec<Point2>) -> Self {
+        Polygon2::new(points)
+    }
+}
+
+#[test]
+fn polygons() {
+    // A comb of teeth pointing up, with many reflex corners.
+    let mut points = alloc::vec![Point2::new(10.0, 0.0), Point2::new(10.0, 1.0)];
+    for k in (0..5).rev() {
+        let x = 2.0 * k as f32;
+        points.push(Point2::new(x + 2.0, 1.0));
+        points.push(Point2::new(x + 1.0, 3.0));
+    }
+    points.push(Point2::new(0.0, 1.0));
+    points.push(Point2::new(0.0, 0.0));
+    points.dedup();
+    let comb = Polygon2::new(points);
+    assert!(comb.is_counterclockwise() && !comb.is_convex());
+    assert!(comb.area() == 20.0);
+    assert!(comb.contains_point(&Point2::new(3.0, 2.5)));
+    assert!(!comb.contains_point(&Point2::new(4.0, 2.5)));
+
+    let mut reversed = comb.clone();
+    reversed.reverse();
+    assert!(!reversed.is_counterclockwise() && reversed.signed_area() == -20.0);
+    for p in [comb, reversed] {
+        let triangles = p.triangulate();
+        // Clipping teeth leaves corners along the base in line, which are
+        // dropped, so there may be fewer than n - 2 triangles.
+        assert!(triangles.len() <= p.points().len() - 2);
+        let mut area = 0.0;
+        for t in &triangles {
+            let tri = Polygon2::new(t.iter().map(|&i| p.points()[i]).collect());
+            assert!(tri.signed_area() * p.signed_area() > 0.0);
+            area += tri.signed_area();
+        }
+        assert!((area - p.signed_area()).abs() < 1e-4);
+    }
+    // Corners in line with their neighbours still make a convex polygon.
+    let strip = Polygon2::new(alloc::vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ]);
+    assert!(strip.is_convex() && strip.area() == 2.0);
+    let area: f32 = strip
+        .triangulate()
+        .iter()
+        .map(|t| Polygon2::new(t.iter().map(|&i| strip.points()[i]).collect()).area())
+        .sum();
+    assert!(area == 2.0);
+
+    // A pentagram turns one way at every corner but is not convex.
+    let star: Vec<Point2> = (0..5)
+        .map(|i| {
+            let a = core::f32::consts::TAU * (2 * i) as f32 / 5.0;
+            Point2::new(a.cos(), a.sin())
+        })
+        .collect();
+    let star = Polygon2::new(star);
+    assert!(!star.is_convex());
+    assert!(star.contains_point(&Point2::new(0.0, 0.0)));
+    assert!(star.triangulate().len() == 3);
+    assert!(Polygon2::new(Vec::new()).triangulate().is_empty());
+}
@@ -0,0 +1,225 @@
+//! Polylines, open paths of straight segments through a sequence of points,
+//! for path following and road or route math. Positions along a polyline
+//! are given as distances from its first point, measured along the path.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use alloc::vec;
+use alloc::vec::Vec;
+
+macro_rules! polyline {
+    ($name:ident, $p:ident) => {
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        /// An open path of straight segments joining a sequence of points,
+        /// storing the distance along the path to each point.
+        pub struct $name {
+            points: Vec<$p>,
+            // The distance along the path to each point, starting at 0.
+            distances: Vec<f32>,
+        }
+
+        impl $name {
+            /// Returns the polyline through the given points in order.
+            ///
+            /// # Arguments
+            ///
+            /// * `points` - The points to join, at least one.
+            ///
+            /// # Panics
+            ///
+            /// Panics if points is empty.
+            pub fn new(points: Vec<$p>) -> $name {
+                assert!(!points.is_empty(), "a polyline needs at least one point");
+                let mut distances = Vec::with_capacity(points.len());
+                let mut total = 0.0;
+                distances.push(total);
+                for w in points.windows(2) {
+                    total += w[0].distance(&w[1]);
+                    distances.push(total);
+                }
+                $name { points, distances }
+            }
+
+            /// Returns the points of the polyline.
+            pub fn points(&self) -> &[$p] {
+                &self.points
+            }
+
+            /// Returns the total length of the polyline.
+            pub fn length(&self) -> f32 {
+                self.distances[self.distances.len() - 1]
+            }
+
+            /// Returns the point at the given distance along the polyline.
+            /// Distances before the start or past the end give the first or
+            /// last point.
+            ///
+            /// # Arguments
+            ///
+            /// * `d` - The distance from the first point along the path.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use math_engine::point2::Point2;
+            /// use math_engine::polyline::Polyline2;
+            /// let path = Polyline2::new(vec![
+            ///     Point2::new(0.0, 0.0),
+            ///     Point2::new(4.0, 0.0),
+            ///     Point2::new(4.0, 4.0),
+            /// ]);
+            /// assert!(path.point_at_distance(6.0) == Point2::new(4.0, 2.0));
+            /// ```
+            pub fn point_at_distance(&self, d: f32) -> $p {
+                let d = d.clamp(0.0, self.length());
+                // The first point past d ends the segment d falls in.
+                let i = self.distances.partition_point(|&x| x <= d);
+                if i == self.points.len() {
+                    return self.points[i - 1];
+                }
+                let (d0, d1) = (self.distances[i - 1], self.distances[i]);
+                self.points[i - 1].lerp(&self.points[i], (d - d0) / (d1 - d0))
+            }
+
+            /// Returns the distance along the polyline and the position of
+            /// the point on it nearest to p.
+            ///
+            /// # Arguments
+            ///
+            /// * `p` - A reference to the point to project.
+            pub fn closest_point(&self, p: &$p) -> (f32, $p) {
+                let mut best = (0.0, self.points[0], p.distance_squared(&self.points[0]));
+                for (i, w) in self.points.windows(2).enumerate() {
+                    let (t, q) = closest_on_segment!(p, &w[0], &w[1]);
+                    let d = p.distance_squared(&q);
+                    if d < best.2 {
+                        let along =
+                            self.distances[i] + t * (self.distances[i + 1] - self.distances[i]);
+                        best = (along, q, d);
+                    }
+                }
+                (best.0, best.1)
+            }
+
+            /// Returns the polyline through points evenly spaced along this
+            /// one, starting at its first point and ending at its last. The
+            /// last gap is shorter than the spacing unless the length is a
+            /// multiple of it.
+            ///
+            /// # Arguments
+            ///
+            /// * `spacing` - The distance between consecutive points, greater
+            ///   than 0.
+            pub fn resample(&self, spacing: f32) -> $name {
+                let length = self.length();
+                let count = (length / spacing).ceil() as usize;
+                let mut points: Vec<$p> = (0..count)
+                    .map(|i| self.point_at_distance(i as f32 * spacing))
+                    .collect();
+                points.push(self.points[self.points.len() - 1]);
+                $name::new(points)
+            }
+
+            /// Returns the polyline simplified by the Ramer–Douglas–Peucker
+            /// algorithm, keeping the fewest of its points that stay within
+            /// epsilon of every point dropped. The end points are always kept.
+            ///
+            /// # Arguments
+            ///
+            /// * `epsilon` - The largest distance a dropped point may lie from
+            ///   the simplified path.
+            pub fn simplify(&self, epsilon: f32) -> $name {
+                let n = self.points.len();
+                if n < 3 {
+                    return self.clone();
+                }
+                let mut keep = vec![false; n];
+                keep[0] = true;
+                keep[n - 1] = true;
+                let mut spans = vec![(0, n - 1)];
+                while let Some((a, b)) = spans.pop() {
+                    let (s, e) = (&self.points[a], &self.points[b]);
+                    let mut far = (0, epsilon * epsilon);
+                    for i in a + 1..b {
+                        let q = &self.points[i];
+                        let d = q.distance_squared(&closest_on_segment!(q, s, e).1);
+                        if d > far.1 {
+                            far = (i, d);
+                        }
+                    }
+                    if far.0 != 0 {
+                        keep[far.0] = true;
+                        spans.push((a, far.0));
+                        spans.push((far.0, b));
+                    }
+                }
+                let points = self
+                    .points
+                    .iter()
+                    .zip(keep)
+                    .filter_map(|(p, k)| k.then_some(*p))
+                    .collect();
+                $name::new(points)
+            }
+        }
+    };
+}
+
+// Returns the parameter and position of the point on the segment from a to
+// b nearest to p.
+macro_rules! closest_on_segment {
+    ($p:expr, $a:expr, $b:expr) => {{
+        let (a, b) = ($a, $b);
+        let ab = *b - *a;
+        let len2 = ab.dot(&ab);
+        let t = if len2 > 0.0 {
+            ((*$p - *a).dot(&ab) / len2).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        (t, *a + ab * t)
+    }};
+}
+
+polyline!(Polyline2, Point2);
+polyline!(Polyline3, Point3);
+
+#[test]
+fn polylines() {
+    // An L of length 5: 3 along x, then 2 along y.
+    let l = Polyline2::new(vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(3.0, 0.0),
+        Point2::new(3.0, 2.0),
+    ]);
+    assert!(l.length() == 5.0);
+    assert!(l.point_at_distance(-1.0) == Point2::new(0.0, 0.0));
+    assert!(l.point_at_distance(1.5) == Point2::new(1.5, 0.0));
+    assert!(l.point_at_distance(3.0) == Point2::new(3.0, 0.0));
+    assert!(l.point_at_distance(4.0) == Point2::new(3.0, 1.0));
+    assert!(l.point_at_distance(9.0) == Point2::new(3.0, 2.0));
+    assert!(l.closest_point(&Point2::new(5.0, 1.0)) == (4.0, Point2::new(3.0, 1.0)));
+    assert!(l.closest_point(&Point2::new(1.0, -2.0)) == (1.0, Point2::new(1.0, 0.0)));
+    let r = l.resample(2.0);
+    assert!(r.points().len() == 4 && r.points()[2] == Point2::new(3.0, 1.0));
+    assert!(r.points()[3] == Point2::new(3.0, 2.0));
+    let single = Polyline2::new(vec![Point2::new(1.0, 1.0)]);
+    assert!(single.length() == 0.0 && single.point_at_distance(1.0) == Point2::new(1.0, 1.0));
+    assert!(single.resample(1.0).points().len() == 1);
+
+    // A noisy straight line simplifies to its end points, while a real
+    // corner survives.
+    let noisy = Polyline3::new(
+        (0..=10)
+            .map(|i| Point3::new(i as f32, if i % 2 == 0 { 0.01 } else { -0.01 }, 0.0))
+            .chain([Point3::new(10.0, 5.0, 0.0)])
+            .collect(),
+    );
+    let simple = noisy.simplify(0.1);
+    assert!(simple.points().len() == 3);
+    assert!(simple.points()[1] == Point3::new(10.0, 0.01, 0.0));
+    assert!(noisy.simplify(0.001).points().len() == noisy.points().len());
+}
@@ -0,0 +1,482 @@
+//! Robust geometric predicates: orientation and in-circle/in-sphere tests
+//! whose sign is always exactly right, after Shewchuk's "Adaptive Precision
+//! Floating-Point Arithmetic and Fast Robust Geometric Predicates".
+//!
+//! Each test first evaluates its determinant in plain f64 and compares it
+//! with a bound on the rounding error, which settles nearly every input at
+//! the cost of a few extra operations. Only when the determinant is too
+//! close to zero to trust is it recomputed exactly, with floating-point
+//! expansions: sums of non-overlapping f64 terms that represent the result
+//! without any rounding. Algorithms that branch on these signs, such as
+//! Delaunay triangulation and convex hulls, then stay consistent on
+//! degenerate and nearly degenerate input.
+//!
+//! The points are converted to f64, which is exact for f32 points, so both
+//! precisions can be passed. The returned value approximates the
+//! determinant, but its sign is exact; zero means exactly degenerate.
+
+use crate::prelude::*;
+use alloc::vec::Vec;
+
+const EPSILON: f64 = f64::EPSILON * 0.5;
+const CCW_ERRBOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const O3D_ERRBOUND: f64 = (7.0 + 56.0 * EPSILON) * EPSILON;
+const ICC_ERRBOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+const ISP_ERRBOUND: f64 = (16.0 + 224.0 * EPSILON) * EPSILON;
+
+/// Returns a positive value if a, b and c are in counterclockwise order, a
+/// negative value if they are clockwise, and zero if they are collinear.
+/// The value approximates twice the signed area of the triangle abc.
+///
+/// # Arguments
+///
+/// * `a` - The first point.
+/// * `b` - The second point.
+/// * `c` - The third point.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::predicates::orient2d;
+/// use math_engine::point2::Point2;
+/// assert!(orient2d(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0), Point2::new(0.0, 1.0)) > 0.0);
+/// // Collinear, although 0.1 and 0.3 are not exact in binary.
+/// assert!(orient2d([0.1, 0.1], [0.2, 0.2], [0.3, 0.3]) == 0.0);
+/// ```
+pub fn orient2d(a: impl Into<DPoint2>, b: impl Into<DPoint2>, c: impl Into<DPoint2>) -> f64 {
+    let (a, b, c) = (a.into(), b.into(), c.into());
+    let left = (a.x - c.x) * (b.y - c.y);
+    let right = (a.y - c.y) * (b.x - c.x);
+    let det = left - right;
+    if det.abs() >= CCW_ERRBOUND * (left.abs() + right.abs()) {
+        return det;
+    }
+    let acx = diff(a.x, c.x);
+    let bcy = diff(b.y, c.y);
+    let acy = diff(a.y, c.y);
+    let bcx = diff(b.x, c.x);
+    estimate(&sub(&mul(&acx, &bcy), &mul(&acy, &bcx)))
+}
+
+/// Returns a positive value if d lies below the plane through a, b and c,
+/// where below is the side from which a, b and c appear clockwise, a
+/// negative value if it lies above, and zero if the four points are
+/// coplanar. The value approximates six times the signed volume of the
+/// tetrahedron abcd.
+///
+/// # Arguments
+///
+/// * `a` - The first point of the plane.
+/// * `b` - The second point of the plane.
+/// * `c` - The third point of the plane.
+/// * `d` - The point to test.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::predicates::orient3d;
+/// use math_engine::point3::Point3;
+/// let (a, b, c) = ([0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]);
+/// assert!(orient3d(a, b, c, [0.0, 0.0, -1.0]) > 0.0);
+/// assert!(orient3d(a, b, c, Point3::new(0.3, 0.7, 0.0)) == 0.0);
+/// ```
+pub fn orient3d(
+    a: impl Into<DPoint3>,
+    b: impl Into<DPoint3>,
+    c: impl Into<DPoint3>,
+    d: impl Into<DPoint3>,
+) -> f64 {
+    let (a, b, c, d) = (a.into(), b.into(), c.into(), d.into());
+    let (adx, ady, adz) = (a.x - d.x, a.y - d.y, a.z - d.z);
+    let (bdx, bdy, bdz) = (b.x - d.x, b.y - d.y, b.z - d.z);
+    let (cdx, cdy, cdz) = (c.x - d.x, c.y - d.y, c.z - d.z);
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let det = adz * (bdxcdy - cdxbdy) + bdz * (cdxady - adxcdy) + cdz * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * adz.abs()
+        + (cdxady.abs() + adxcdy.abs()) * bdz.abs()
+        + (adxbdy.abs() + bdxady.abs()) * cdz.abs();
+    if det.abs() >= O3D_ERRBOUND * permanent {
+        return det;
+    }
+    let [ad, bd, cd] = [a, b, c].map(|p| [diff(p.x, d.x), diff(p.y, d.y), diff(p.z, d.z)]);
+    let cross = |p: &[Vec<f64>; 3], q: &[Vec<f64>; 3]| sub(&mul(&p[0], &q[1]), &mul(&q[0], &p[1]));
+    let det = add(
+        &add(
+            &mul(&ad[2], &cross(&bd, &cd)),
+            &mul(&bd[2], &cross(&cd, &ad)),
+        ),
+        &mul(&cd[2], &cross(&ad, &bd)),
+    );
+    estimate(&det)
+}
+
+/// Returns a positive value if d lies inside the circle through a, b and c,
+/// a negative value if it lies outside, and zero if the four points are
+/// cocircular. a, b and c must be in counterclockwise order, or the sign is
+/// reversed.
+///
+/// # Arguments
+///
+/// * `a` - The first point of the circle.
+/// * `b` - The second point of the circle.
+/// * `c` - The third point of the circle.
+/// * `d` - The point to test.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::predicates::incircle;
+/// let (a, b, c) = ([1.0, 0.0], [0.0, 1.0], [-1.0, 0.0]);
+/// assert!(incircle(a, b, c, [0.0, 0.5]) > 0.0);
+/// assert!(incircle(a, b, c, [0.0, -1.0]) == 0.0);
+/// assert!(incircle(a, b, c, [2.0, 0.0]) < 0.0);
+/// ```
+pub fn incircle(
+    a: impl Into<DPoint2>,
+    b: impl Into<DPoint2>,
+    c: impl Into<DPoint2>,
+    d: impl Into<DPoint2>,
+) -> f64 {
+    let (a, b, c, d) = (a.into(), b.into(), c.into(), d.into());
+    let (adx, ady) = (a.x - d.x, a.y - d.y);
+    let (bdx, bdy) = (b.x - d.x, b.y - d.y);
+    let (cdx, cdy) = (c.x - d.x, c.y - d.y);
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let alift = adx * adx + ady * ady;
+    let blift = bdx * bdx + bdy * bdy;
+    let clift = cdx * cdx + cdy * cdy;
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+        + (cdxady.abs() + adxcdy.abs()) * blift
+        + (adxbdy.abs() + bdxady.abs()) * clift;
+    if det.abs() >= ICC_ERRBOUND * permanent {
+        return det;
+    }
+    let [ad, bd, cd] = [a, b, c].map(|p| [diff(p.x, d.x), diff(p.y, d.y)]);
+    let lift = |p: &[Vec<f64>; 2]| add(&mul(&p[0], &p[0]), &mul(&p[1], &p[1]));
+    let cross = |p: &[Vec<f64>; 2], q: &[Vec<f64>; 2]| sub(&mul(&p[0], &q[1]), &mul(&q[0], &p[1]));
+    let det = add(
+        &add(
+            &mul(&lift(&ad), &cross(&bd, &cd)),
+            &mul(&lift(&bd), &cross(&cd, &ad)),
+        ),
+        &mul(&lift(&cd), &cross(&ad, &bd)),
+    );
+    estimate(&det)
+}
+
+/// Returns a positive value if e lies inside the sphere through a, b, c and
+/// d, a negative value if it lies outside, and zero if the five points are
+/// cospherical. The points a, b, c and d must be ordered so that
+/// `orient3d(a, b, c, d)` is positive, or the sign is reversed.
+///
+/// # Arguments
+///
+/// * `a` - The first point of the sphere.
+/// * `b` - The second point of the sphere.
+/// * `c` - The third point of the sphere.
+/// * `d` - The fourth point of the sphere.
+/// * `e` - The point to test.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::predicates::{insphere, orient3d};
+/// let (a, b, c, d) = ([1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, -1.0]);
+/// assert!(orient3d(a, b, c, d) > 0.0);
+/// assert!(insphere(a, b, c, d, [0.0, 0.0, 0.0]) > 0.0);
+/// assert!(insphere(a, b, c, d, [0.0, 0.0, 1.0]) == 0.0);
+/// assert!(insphere(a, b, c, d, [0.0, 2.0, 0.0]) < 0.0);
+/// ```
+pub fn insphere(
+    a: impl Into<DPoint3>,
+    b: impl Into<DPoint3>,
+    c: impl Into<DPoint3>,
+    d: impl Into<DPoint3>,
+    e: impl Into<DPoint3>,
+) -> f64 {
+    let (e, points) = (e.into(), [a.into(), b.into(), c.into(), d.into()]);
+    let [a, b, c, d] = points.map(|p| [p.x - e.x, p.y - e.y, p.z - e.z]);
+    // Evaluates the determinant by cofactors along the lift column, and with
+    // abs set the same expression with every term made non-negative, which
+    // scales the error bound.
+    let det_of = |abs: bool| {
+        let m = |x: f64| if abs { x.abs() } else { x };
+        let s = if abs { 1.0 } else { -1.0 };
+        let cross = |u: &[f64; 3], v: &[f64; 3]| m(u[0] * v[1]) + s * m(v[0] * u[1]);
+        let minor = |p: &[f64; 3], q: &[f64; 3], r: &[f64; 3]| {
+            m(p[2]) * cross(q, r) + s * m(q[2]) * cross(p, r) + m(r[2]) * cross(p, q)
+        };
+        let lift = |p: &[f64; 3]| p[0] * p[0] + p[1] * p[1] + p[2] * p[2];
+        lift(&d) * minor(&a, &b, &c)
+            + s * lift(&c) * minor(&a, &b, &d)
+            + lift(&b) * minor(&a, &c, &d)
+            + s * lift(&a) * minor(&b, &c, &d)
+    };
+    let det = det_of(false);
+    if det.abs() >= ISP_ERRBOUND * det_of(true) {
+        return det;
+    }
+    let [a, b, c, d] = points.map(|p| [diff(p.x, e.x), diff(p.y, e.y), diff(p.z, e.z)]);
+    let cross = |p: &[Vec<f64>; 3], q: &[Vec<f64>; 3]| sub(&mul(&p[0], &q[1]), &mul(&q[0], &p[1]));
+    let minor = |p: &[Vec<f64>; 3], q: &[Vec<f64>; 3], r: &[Vec<f64>; 3]| {
+        add(
+            &sub(&mul(&p[2], &cross(q, r)), &mul(&q[2], &cross(p, r))),
+            &mul(&r[2], &cross(p, q)),
+        )
+    };
+    let lift = |p: &[Vec<f64>; 3]| {
+        add(
+            &add(&mul(&p[0], &p[0]), &mul(&p[1], &p[1])),
+            &mul(&p[2], &p[2]),
+        )
+    };
+    let det = sub(
+        &add(
+            &sub(
+                &mul(&lift(&d), &minor(&a, &b, &c)),
+                &mul(&lift(&c), &minor(&a, &b, &d)),
+            ),
+            &mul(&lift(&b), &minor(&a, &c, &d)),
+        ),
+        &mul(&lift(&a), &minor(&b, &c, &d)),
+    );
+    estimate(&det)
+}
+
+/// Returns true if p lies inside or on the triangle abc, which may wind
+/// either way. Built on `orient2d`, so points on an edge are always found
+/// on it.
+///
+/// # Arguments
+///
+/// * `p` - The point to test.
+/// * `a` - The first corner.
+/// * `b` - The second corner.
+/// * `c` - The third corner.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point2::Point2;
+/// use math_engine::predicates::point_in_triangle;
+/// let (a, b, c) = (Point2::new(0.0, 0.0), Point2::new(4.0, 0.0), Point2::new(0.0, 4.0));
+/// assert!(point_in_triangle(Point2::new(1.0, 1.0), a, b, c));
+/// assert!(point_in_triangle(Point2::new(2.0, 2.0), c, b, a));
+/// assert!(!point_in_triangle(Point2::new(2.5, 2.0), a, b, c));
+/// ```
+pub fn point_in_triangle(
+    p: impl Into<DPoint2>,
+    a: impl Into<DPoint2>,
+    b: impl Into<DPoint2>,
+    c: impl Into<DPoint2>,
+) -> bool {
+    let (p, a, b, c) = (p.into(), a.into(), b.into(), c.into());
+    let signs = [orient2d(a, b, p), orient2d(b, c, p), orient2d(c, a, p)];
+    signs.iter().all(|s| *s >= 0.0) || signs.iter().all(|s| *s <= 0.0)
+}
+
+/// Returns true if p lies inside or on the convex polygon with the given
+/// corners, in order around it either way. Collinear and repeated corners
+/// are allowed; an empty polygon contains nothing.
+///
+/// # Arguments
+///
+/// * `p` - The point to test.
+/// * `polygon` - The corners of the polygon.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point2::Point2;
+/// use math_engine::predicates::point_in_convex_polygon;
+/// let hexagon = [
+///     Point2::new(2.0, 0.0),
+///     Point2::new(1.0, 2.0),
+///     Point2::new(-1.0, 2.0),
+///     Point2::new(-2.0, 0.0),
+///     Point2::new(-1.0, -2.0),
+///     Point2::new(1.0, -2.0),
+/// ];
+/// assert!(point_in_convex_polygon(Point2::new(1.5, 0.9), &hexagon));
+/// assert!(!point_in_convex_polygon(Point2::new(1.5, 1.1), &hexagon));
+/// ```
+pub fn point_in_convex_polygon<P: Copy + Into<DPoint2>>(
+    p: impl Into<DPoint2>,
+    polygon: &[P],
+) -> bool {
+    let p = p.into();
+    let n = polygon.len();
+    let (mut left, mut right) = (false, false);
+    for i in 0..n {
+        let s = orient2d(polygon[i], polygon[(i + 1) % n], p);
+        left |= s > 0.0;
+        right |= s < 0.0;
+    }
+    // A polygon collapsed to a point or segment, with the point on its
+    // line, has every orientation zero; the point must then lie between
+    // two of its corners.
+    if !left && !right {
+        return (0..n).any(|i| {
+            let (a, b): (DPoint2, DPoint2) = (polygon[i].into(), polygon[(i + 1) % n].into());
+            (p.x - a.x) * (p.x - b.x) <= 0.0 && (p.y - a.y) * (p.y - b.y) <= 0.0
+        });
+    }
+    !(left && right)
+}
+
+// Expansion arithmetic. An expansion is a list of f64 terms, in increasing
+// order of magnitude and with no two overlapping bits, whose exact sum is
+// the value it represents. Zero terms are dropped as they appear.
+
+// Returns a + b as an exact pair (sum, rounding error).
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let bv = x - a;
+    let av = x - bv;
+    (x, (a - av) + (b - bv))
+}
+
+// Returns a * b as an exact pair (product, rounding error), using Dekker's
+// splitting so that no fused multiply-add is needed.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let split = |v: f64| {
+        let c = 134217729.0 * v;
+        let hi = c - (c - v);
+        (hi, v - hi)
+    };
+    let x = a * b;
+    let (ahi, alo) = split(a);
+    let (bhi, blo) = split(b);
+    let err = ((x - ahi * bhi) - alo * bhi) - ahi * blo;
+    (x, alo * blo - err)
+}
+
+// Returns a - b exactly.
+fn diff(a: f64, b: f64) -> Vec<f64> {
+    let (x, y) = two_sum(a, -b);
+    [y, x].into_iter().filter(|&t| t != 0.0).collect()
+}
+
+// Returns e + b.
+fn grow(e: &[f64], b: f64) -> Vec<f64> {
+    let mut out = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &t in e {
+        let (s, h) = two_sum(q, t);
+        if h != 0.0 {
+            out.push(h);
+        }
+        q = s;
+    }
+    if q != 0.0 {
+        out.push(q);
+    }
+    out
+}
+
+fn add(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |acc, &b| grow(&acc, b))
+}
+
+fn sub(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |acc, &b| grow(&acc, -b))
+}
+
+// Returns e * b.
+fn scale(e: &[f64], b: f64) -> Vec<f64> {
+    let mut out = Vec::with_capacity(2 * e.len());
+    let mut push = |h: f64| {
+        if h != 0.0 {
+            out.push(h);
+        }
+    };
+    let Some((&first, rest)) = e.split_first() else {
+        return Vec::new();
+    };
+    let (mut q, h) = two_product(first, b);
+    push(h);
+    for &t in rest {
+        let (p1, p0) = two_product(t, b);
+        let (s, h) = two_sum(q, p0);
+        push(h);
+        let (s, h) = two_sum(p1, s);
+        push(h);
+        q = s;
+    }
+    push(q);
+    out
+}
+
+fn mul(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(Vec::new(), |acc, &b| add(&acc, &scale(e, b)))
+}
+
+// Returns the sum of the terms, whose sign is that of the largest term and
+// so of the exact value.
+fn estimate(e: &[f64]) -> f64 {
+    e.iter().sum()
+}
+
+#[test]
+fn near_degenerate() {
+    // Points on the line y = x near (0.5, 0.5), perturbed by single ulps,
+    // where the plain f64 determinant gets the sign wrong.
+    let step = f64::EPSILON * 0.5;
+    let (b, c) = (DPoint2::new(12.0, 12.0), DPoint2::new(24.0, 24.0));
+    for i in 0..64 {
+        for j in 0..64 {
+            let a = DPoint2::new(0.5 + i as f64 * step, 0.5 + j as f64 * step);
+            let o = orient2d(a, b, c);
+            assert!(if i == j {
+                o == 0.0
+            } else {
+                o != 0.0 && (o > 0.0) == (j > i)
+            });
+        }
+    }
+
+    // Lifting a point off a plane by the smallest possible amount.
+    let (a, b, c) = ([0.1, 0.2, 0.3], [1.7, 0.2, 0.3], [0.1, 2.9, 0.3]);
+    assert!(orient3d(a, b, c, [0.5, 0.5, 0.3]) == 0.0);
+    assert!(orient3d(a, b, c, [0.5, 0.5, f64::from_bits(0.3f64.to_bits() + 1)]) < 0.0);
+    assert!(orient3d(a, b, c, [0.5, 0.5, f64::from_bits(0.3f64.to_bits() - 1)]) > 0.0);
+
+    // The corners of a unit square far from the origin are cocircular, and
+    // moving one outward by an ulp is detected.
+    let t = 1e15;
+    let (a, b, c) = ([t, t], [t + 1.0, t], [t + 1.0, t + 1.0]);
+    assert!(incircle(a, b, c, [t, t + 1.0]) == 0.0);
+    assert!(incircle(a, b, c, [t, t + 1.125]) < 0.0);
+    // Likewise for the corners of a cube.
+    let (a, b, c, d) = ([t, t, t], [t + 1.0, t, t], [t, t + 1.0, t], [t, t, t + 1.0]);
+    assert!(orient3d(a, b, c, d) < 0.0);
+    assert!(insphere(b, a, c, d, [t + 1.0, t + 1.0, t + 1.0]) == 0.0);
+    assert!(insphere(b, a, c, d, [t + 1.0, t + 1.0, t + 1.125]) < 0.0);
+    assert!(insphere(b, a, c, d, [t + 1.0, t + 1.0, t + 0.875]) > 0.0);
+}
+
+#[test]
+fn containment() {
+    let p = Point2::new;
+    // Points a single ulp either side of a diagonal edge.
+    let (a, b, c) = (p(0.0, 0.0), p(1.0, 1.0), p(1.0, 0.0));
+    let x = 0.3f32;
+    let above = f32::from_bits(x.to_bits() + 1);
+    assert!(point_in_triangle(p(x, x), a, b, c));
+    assert!(point_in_triangle(p(x, x), c, b, a));
+    assert!(!point_in_triangle(p(x, above), a, b, c));
+    let square = [p(0.0, 0.0), p(1.0, 0.0), p(1.0, 1.0), p(0.0, 1.0)];
+    assert!(point_in_convex_polygon(p(x, x), &square));
+    assert!(point_in_convex_polygon(p(1.0, 0.5), &square));
+    assert!(!point_in_convex_polygon(p(1.0, 1.5), &square));
+    // Degenerate polygons: a segment with a collinear corner, and nothing.
+    let segment = [p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0)];
+    assert!(point_in_convex_polygon(p(1.5, 1.5), &segment));
+    assert!(!point_in_convex_polygon(p(3.0, 3.0), &segment));
+    assert!(!point_in_convex_polygon(p(0.0, 0.0), &[] as &[Point2]));
+}
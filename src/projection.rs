@@ -0,0 +1,209 @@
+//! Mapping points between world space and window coordinates, in the manner
+//! of `gluProject` and `gluUnProject`.
+//!
+//! A viewport is given as a `Vector4` holding its x and y origin, width and
+//! height. Window coordinates have their origin at the bottom-left corner of
+//! the viewport, and window depth equals normalized device depth, which is
+//! [0, 1] for the projections built by `Matrix4`.
+
+use crate::prelude::*;
+
+/// Returns the window coordinates of the point p, transformed by model_view
+/// and projection and mapped into viewport. Points on the plane through the
+/// eye, which have no projection, map to non-finite coordinates.
+///
+/// # Arguments
+///
+/// * `p` - A reference to a point3.
+/// * `model_view` - The model-view matrix.
+/// * `projection` - The projection matrix.
+/// * `viewport` - The viewport as (x, y, width, height).
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::matrix4::Matrix4;
+/// use math_engine::point3::Point3;
+/// use math_engine::projection::project;
+/// use math_engine::vector4::Vector4;
+/// let proj = Matrix4::perspective_rh(1.0, 2.0, 1.0, 10.0);
+/// let viewport = Vector4::new(0.0, 0.0, 800.0, 400.0);
+/// let w = project(&Point3::new(0.0, 0.0, -1.0), &Matrix4::identity(), &proj, &viewport);
+/// assert!(w == Point3::new(400.0, 200.0, 0.0));
+/// ```
+pub fn project(
+    p: &Point3,
+    model_view: &Matrix4,
+    projection: &Matrix4,
+    viewport: &Vector4,
+) -> Point3 {
+    let v = *projection * (*model_view * *p);
+    let ndc = Point3::new(v.x / v.w, v.y / v.w, v.z / v.w);
+    Point3::new(
+        viewport.x + viewport.z * (ndc.x + 1.0) * 0.5,
+        viewport.y + viewport.w * (ndc.y + 1.0) * 0.5,
+        ndc.z,
+    )
+}
+
+/// Returns the world space point whose window coordinates are win, undoing
+/// `project`. Returns None if the combined matrix is singular or win lies at
+/// infinity, e.g. at depth 1 of an infinite far projection.
+///
+/// # Arguments
+///
+/// * `win` - The window coordinates and depth.
+/// * `model_view` - The model-view matrix.
+/// * `projection` - The projection matrix.
+/// * `viewport` - The viewport as (x, y, width, height).
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::matrix4::Matrix4;
+/// use math_engine::point3::Point3;
+/// use math_engine::projection::{project, unproject};
+/// use math_engine::vector4::Vector4;
+/// let mv = Matrix4::look_at_rh(
+///     &Point3::new(0.0, 0.0, 5.0),
+///     &Point3::new(0.0, 0.0, 0.0),
+///     &math_engine::vector3::Vector3::new(0.0, 1.0, 0.0),
+/// );
+/// let proj = Matrix4::perspective_rh(1.0, 2.0, 1.0, 10.0);
+/// let viewport = Vector4::new(0.0, 0.0, 800.0, 400.0);
+/// let p = Point3::new(0.5, -0.25, 1.0);
+/// let w = project(&p, &mv, &proj, &viewport);
+/// let q = unproject(&w, &mv, &proj, &viewport).unwrap();
+/// assert!((q - p).magnitude() < 1e-4);
+/// ```
+pub fn unproject(
+    win: &Point3,
+    model_view: &Matrix4,
+    projection: &Matrix4,
+    viewport: &Vector4,
+) -> Option<Point3> {
+    let v = (*projection * *model_view).try_inverse()? * ndc(win, viewport);
+    if v.w == 0.0 {
+        return None;
+    }
+    Some(Point3::new(v.x / v.w, v.y / v.w, v.z / v.w))
+}
+
+/// Returns the world space ray from the near plane through the window
+/// coordinates x and y, for picking objects under the cursor. The ray starts
+/// on the near plane and points away from the camera, for classic, reversed
+/// and infinite depth projections alike. Returns None if either matrix is
+/// singular.
+///
+/// # Arguments
+///
+/// * `x` - The window x coordinate.
+/// * `y` - The window y coordinate, measured up from the bottom.
+/// * `model_view` - The model-view matrix.
+/// * `projection` - The projection matrix.
+/// * `viewport` - The viewport as (x, y, width, height).
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::matrix4::Matrix4;
+/// use math_engine::point3::Point3;
+/// use math_engine::projection::pick_ray;
+/// use math_engine::vector4::Vector4;
+/// let proj = Matrix4::perspective_infinite_reverse_rh(1.0, 2.0, 0.5);
+/// let viewport = Vector4::new(0.0, 0.0, 800.0, 400.0);
+/// let r = pick_ray(400.0, 200.0, &Matrix4::identity(), &proj, &viewport).unwrap();
+/// assert!((r.origin - Point3::new(0.0, 0.0, -0.5)).magnitude() < 1e-6);
+/// assert!(r.direction.x == 0.0 && r.direction.y == 0.0 && r.direction.z < 0.0);
+/// ```
+pub fn pick_ray(
+    x: f32,
+    y: f32,
+    model_view: &Matrix4,
+    projection: &Matrix4,
+    viewport: &Vector4,
+) -> Option<Ray3> {
+    let to_world = model_view.try_inverse()?;
+    // Unproject into view space, where the camera sits at the origin looking
+    // along z, to tell the near end of the ray from the far one.
+    let inv = projection.try_inverse()?;
+    let a = inv * ndc(&Point3::new(x, y, 0.0), viewport);
+    let b = inv * ndc(&Point3::new(x, y, 1.0), viewport);
+    let finite = |v: &Vector4| v.w.abs() > f32::EPSILON * v.magnitude();
+    let (origin, direction) = match (finite(&a), finite(&b)) {
+        (true, true) => {
+            let pa = Point3::new(a.x / a.w, a.y / a.w, a.z / a.w);
+            let pb = Point3::new(b.x / b.w, b.y / b.w, b.z / b.w);
+            if pa.z.abs() <= pb.z.abs() {
+                (pa, pb - pa)
+            } else {
+                (pb, pa - pb)
+            }
+        }
+        (true, false) => near_and_infinity(&a, &b),
+        (false, true) => near_and_infinity(&b, &a),
+        (false, false) => return None,
+    };
+    let o = to_world * origin;
+    let d = to_world * direction;
+    Some(Ray3::new(
+        Point3::new(o.x / o.w, o.y / o.w, o.z / o.w),
+        Vector3::new(d.x, d.y, d.z),
+    ))
+}
+
+// Returns the normalized device coordinates of the window coordinates win.
+fn ndc(win: &Point3, viewport: &Vector4) -> Vector4 {
+    Vector4::new(
+        2.0 * (win.x - viewport.x) / viewport.z - 1.0,
+        2.0 * (win.y - viewport.y) / viewport.w - 1.0,
+        win.z,
+        1.0,
+    )
+}
+
+// Returns the ray from the finite homogeneous view space point near toward
+// the point at infinity far, given as a direction of either sign.
+fn near_and_infinity(near: &Vector4, far: &Vector4) -> (Point3, Vector3) {
+    let p = Point3::new(near.x / near.w, near.y / near.w, near.z / near.w);
+    let mut d = Vector3::new(far.x, far.y, far.z);
+    if d.dot(&Vector3::from(p)) < 0.0 {
+        d *= -1.0;
+    }
+    (p, d)
+}
+
+#[test]
+fn picking() {
+    let mv = Matrix4::look_at_rh(
+        &Point3::new(3.0, 1.0, 4.0),
+        &Point3::new(0.0, 0.0, 0.0),
+        &Vector3::new(0.0, 1.0, 0.0),
+    );
+    let viewport = Vector4::new(10.0, 20.0, 640.0, 480.0);
+    let target = Point3::new(0.2, 0.3, -0.1);
+    for proj in [
+        Matrix4::perspective_rh(1.0, 4.0 / 3.0, 0.5, 50.0),
+        Matrix4::perspective_reverse_rh(1.0, 4.0 / 3.0, 0.5, 50.0),
+        Matrix4::perspective_infinite_rh(1.0, 4.0 / 3.0, 0.5),
+        Matrix4::perspective_infinite_reverse_rh(1.0, 4.0 / 3.0, 0.5),
+        Matrix4::orthographic_rh(-4.0, 4.0, -3.0, 3.0, 0.5, 50.0),
+        Matrix4::orthographic_reverse_rh(-4.0, 4.0, -3.0, 3.0, 0.5, 50.0),
+    ] {
+        let w = project(&target, &mv, &proj, &viewport);
+        let r = pick_ray(w.x, w.y, &mv, &proj, &viewport).unwrap();
+        // The ray passes through the target, in front of its origin.
+        let t = (target - r.origin).dot(&r.direction) / r.direction.dot(&r.direction);
+        assert!(t > 0.0);
+        assert!((r.at(t) - target).magnitude() < 1e-3);
+    }
+
+    // A rank deficient matrix whose determinant rounds to a nonzero value.
+    let ramp = Matrix4::new(
+        0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2, 1.3, 1.4, 1.5, 1.6,
+    );
+    let proj = Matrix4::perspective_rh(1.0, 4.0 / 3.0, 0.5, 50.0);
+    assert!(pick_ray(100.0, 100.0, &ramp, &proj, &viewport).is_none());
+    assert!(pick_ray(100.0, 100.0, &mv, &ramp, &viewport).is_none());
+    assert!(unproject(&Point3::new(100.0, 100.0, 0.5), &ramp, &proj, &viewport).is_none());
+}
@@ -1,9 +1,11 @@
 use crate::prelude::*;
-use std::ops::{Mul, MulAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-#[derive(Default, Copy, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Represents a hamiltonian quaternion having the form xi + yj + zk + w.
 /// https://en.wikipedia.org/wiki/Quaternion
+#[repr(C)]
 pub struct Quarternion {
     /// The x coordinate of the vector part.
     pub x: f32,
@@ -16,6 +18,14 @@ pub struct Quarternion {
 }
 
 impl Quarternion {
+    /// The identity quaternion 1, representing no rotation.
+    pub const IDENTITY: Quarternion = Quarternion {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 1.0,
+    };
+
     /// Returns a quaternion initialized with the floating point components x, y, z, and w.
     ///
     /// # Arguments
@@ -31,7 +41,7 @@ impl Quarternion {
     /// use math_engine::quarternion::Quarternion;
     /// let q = Quarternion::new(1.0,0.0,0.0,1.0);
     /// ```
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Quarternion {
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Quarternion {
         Quarternion { x, y, z, w }
     }
 
@@ -104,20 +114,356 @@ impl Quarternion {
         }
     }
 
+    /// Returns the identity quaternion, representing no rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// let q = Quarternion::identity();
+    /// assert!(q == Quarternion::IDENTITY);
+    /// ```
+    pub const fn identity() -> Quarternion {
+        Self::IDENTITY
+    }
+
+    /// Returns the dot product of the quaternion with other, treating both as
+    /// 4D vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// let q = Quarternion::new(1.0, 2.0, 3.0, 4.0);
+    /// assert!(q.dot(&q) == 30.0);
+    /// ```
+    pub fn dot(&self, other: &Quarternion) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Returns the magnitude, or norm, of the quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// let q = Quarternion::new(1.0, 1.0, 1.0, 1.0);
+    /// assert!(q.magnitude() == 2.0);
+    /// ```
+    pub fn magnitude(&self) -> f32 {
+        f32::sqrt(self.dot(self))
+    }
+
+    /// Returns the quaternion scaled to unit magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// let q = Quarternion::new(0.0, 0.0, 3.0, 4.0).normalize();
+    /// assert!(q == Quarternion::new(0.0, 0.0, 0.6, 0.8));
+    /// ```
+    pub fn normalize(&self) -> Quarternion {
+        *self / self.magnitude()
+    }
+
+    /// Scales the quaternion to unit magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// let mut q = Quarternion::new(0.0, 0.0, 3.0, 4.0);
+    /// q.normalize_mut();
+    /// assert!(q.magnitude() == 1.0);
+    /// ```
+    pub fn normalize_mut(&mut self) {
+        *self = self.normalize();
+    }
+
+    /// Returns this quaternion normalized to unit length, or None if its
+    /// length is zero or not finite, where `normalize` would return NaNs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// assert!(Quarternion::new(0.0, 0.0, 0.0, 0.0).try_normalize().is_none());
+    /// assert!(Quarternion::new(0.0, 0.0, 0.0, 2.0).try_normalize() == Some(Quarternion::IDENTITY));
+    /// ```
+    pub fn try_normalize(&self) -> Option<Quarternion> {
+        let m = self.magnitude();
+        if m > 0.0 && m.is_finite() && (1.0 / m).is_finite() {
+            Some(*self / m)
+        } else {
+            None
+        }
+    }
+
+    /// Returns this quaternion normalized to unit length, or the zero
+    /// quaternion if it cannot be normalized, see `try_normalize`.
+    pub fn normalize_or_zero(&self) -> Quarternion {
+        self.try_normalize()
+            .unwrap_or(Quarternion::new(0.0, 0.0, 0.0, 0.0))
+    }
+
+    /// Returns the conjugate of the quaternion, which negates the vector
+    /// part. For a unit quaternion this is the inverse rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// let q = Quarternion::new(1.0, 2.0, 3.0, 4.0);
+    /// assert!(q.conjugate() == Quarternion::new(-1.0, -2.0, -3.0, 4.0));
+    /// ```
+    pub fn conjugate(&self) -> Quarternion {
+        Quarternion::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the multiplicative inverse of the quaternion, the conjugate
+    /// divided by the squared magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// let q = Quarternion::new(0.0, 0.0, 0.0, 2.0);
+    /// assert!(q.inverse() == Quarternion::new(-0.0, -0.0, -0.0, 0.5));
+    /// assert!(q * q.inverse() == Quarternion::IDENTITY);
+    /// ```
+    pub fn inverse(&self) -> Quarternion {
+        self.conjugate() / self.dot(self)
+    }
+
+    /// Returns the rotation interpolated linearly between this unit
+    /// quaternion at t = 0 and other at t = 1, then normalized. It takes the
+    /// shorter way round, like `slerp`, but does not turn at constant speed.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the unit quaternion at t = 1.
+    /// * `t` - The interpolation parameter.
+    pub fn nlerp(&self, other: &Quarternion, t: f32) -> Quarternion {
+        let other = if self.dot(other) < 0.0 {
+            -*other
+        } else {
+            *other
+        };
+        (*self * (1.0 - t) + other * t).normalize()
+    }
+
+    /// Returns the rotation interpolated along the shorter great arc between
+    /// this unit quaternion at t = 0 and other at t = 1, turning at constant
+    /// angular speed.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the unit quaternion at t = 1.
+    /// * `t` - The interpolation parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// let a = Quarternion::from_rotation_z(0.0);
+    /// let b = Quarternion::from_rotation_z(2.0);
+    /// let q = a.slerp(&b, 0.25);
+    /// assert!((q - Quarternion::from_rotation_z(0.5)).magnitude() < 1e-6);
+    /// ```
+    pub fn slerp(&self, other: &Quarternion, t: f32) -> Quarternion {
+        let mut cos = self.dot(other);
+        let other = if cos < 0.0 {
+            cos = -cos;
+            -*other
+        } else {
+            *other
+        };
+        // Nearly equal rotations divide by a vanishing sine.
+        if cos > 0.9995 {
+            return self.nlerp(&other, t);
+        }
+        let sin = (1.0 - cos * cos).sqrt();
+        let angle = sin.atan2(cos);
+        (*self * ((1.0 - t) * angle).sin() + other * (t * angle).sin()) / sin
+    }
+
+    /// Returns the unit quaternion representing a rotation through angle
+    /// radians about axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - A reference to the unit length axis of rotation.
+    /// * `angle` - The angle in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// use math_engine::unit_vector3::UnitVector3;
+    /// use math_engine::vector3::Vector3;
+    /// let q = Quarternion::from_axis_angle(&UnitVector3::z_axis(), core::f32::consts::PI);
+    /// let v = q.transform(&Vector3::new(1.0, 0.0, 0.0));
+    /// assert!((v - Vector3::new(-1.0, 0.0, 0.0)).magnitude() < 1e-6);
+    /// ```
+    pub fn from_axis_angle(axis: &UnitVector3, angle: impl Into<Radians>) -> Quarternion {
+        let angle = angle.into().0;
+        let h = angle * 0.5;
+        Quarternion::new_with_vec_and_scalar(&(**axis * h.sin()), h.cos())
+    }
+
+    /// Returns the unit axis and the angle in radians, in [0, 2pi], of the
+    /// rotation represented by the quaternion, undoing
+    /// `Quarternion::from_axis_angle`. The quaternion need not be normalized.
+    /// For a rotation through a zero angle the x axis is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// use math_engine::vector3::Vector3;
+    /// let q = Quarternion::from_axis_angle(&Vector3::new(0.0, 1.0, 0.0).to_unit(), 0.5);
+    /// let (axis, angle) = q.to_axis_angle();
+    /// assert!((axis - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-6);
+    /// assert!((angle - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn to_axis_angle(&self) -> (Vector3, f32) {
+        let v = self.get_vector_part();
+        let s = v.magnitude();
+        if s <= f32::EPSILON * self.w.abs() {
+            return (Vector3::new(1.0, 0.0, 0.0), 0.0);
+        }
+        (v / s, 2.0 * s.atan2(self.w))
+    }
+
+    /// Returns the unit quaternion representing a rotation through angle
+    /// radians about the x axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// let q = Quarternion::from_rotation_x(1.0);
+    /// assert!(q.x == 0.5f32.sin() && q.w == 0.5f32.cos());
+    /// ```
+    pub fn from_rotation_x(angle: impl Into<Radians>) -> Quarternion {
+        let angle = angle.into().0;
+        let h = angle * 0.5;
+        Quarternion::new(h.sin(), 0.0, 0.0, h.cos())
+    }
+
+    /// Returns the unit quaternion representing a rotation through angle
+    /// radians about the y axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    pub fn from_rotation_y(angle: impl Into<Radians>) -> Quarternion {
+        let angle = angle.into().0;
+        let h = angle * 0.5;
+        Quarternion::new(0.0, h.sin(), 0.0, h.cos())
+    }
+
+    /// Returns the unit quaternion representing a rotation through angle
+    /// radians about the z axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    pub fn from_rotation_z(angle: impl Into<Radians>) -> Quarternion {
+        let angle = angle.into().0;
+        let h = angle * 0.5;
+        Quarternion::new(0.0, 0.0, h.sin(), h.cos())
+    }
+
+    /// Returns the unit quaternion composed of rotations through x, y and z
+    /// radians about the x, y and z axes, applied in the given order.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order in which the rotations are applied.
+    /// * `x` - The angle about the x axis in radians.
+    /// * `y` - The angle about the y axis in radians.
+    /// * `z` - The angle about the z axis in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::euler::EulerOrder;
+    /// use math_engine::quarternion::Quarternion;
+    /// let q = Quarternion::from_euler(EulerOrder::ZYX, 0.0, 0.0, 1.0);
+    /// assert!(q == Quarternion::from_rotation_z(1.0));
+    /// ```
+    pub fn from_euler(
+        order: EulerOrder,
+        x: impl Into<Radians>,
+        y: impl Into<Radians>,
+        z: impl Into<Radians>,
+    ) -> Quarternion {
+        let x = x.into().0;
+        let y = y.into().0;
+        let z = z.into().0;
+        let q = [
+            Self::from_rotation_x(x),
+            Self::from_rotation_y(y),
+            Self::from_rotation_z(z),
+        ];
+        let [i, j, k] = order.axes();
+        q[k] * q[j] * q[i]
+    }
+
+    /// Returns the angles (x, y, z) in radians about the x, y and z axes that
+    /// `Quarternion::from_euler` turns back into this rotation. See
+    /// `Matrix3::to_euler` for the ranges of the angles. The quaternion is
+    /// expected to be normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order in which the rotations are applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::euler::EulerOrder;
+    /// use math_engine::quarternion::Quarternion;
+    /// let q = Quarternion::from_euler(EulerOrder::YXZ, 0.1, 0.2, 0.3);
+    /// let (x, y, z) = q.to_euler(EulerOrder::YXZ);
+    /// assert!((x - 0.1).abs() < 1e-6 && (y - 0.2).abs() < 1e-6 && (z - 0.3).abs() < 1e-6);
+    /// ```
+    pub fn to_euler(&self, order: EulerOrder) -> (f32, f32, f32) {
+        self.get_rotation_matrix().to_euler(order)
+    }
+
     pub fn get_vector_part(&self) -> Vector3 {
-        Vector3::new(self.x, self.x, self.x)
+        Vector3::new(self.x, self.y, self.z)
     }
 
-    /// Returns a converted quaternion to a 3x3 matrix.
+    /// Returns the 3x3 rotation matrix of the rotation represented by the
+    /// quaternion. The quaternion does not have to be normalized.
     /// # Examples
     ///
     /// ```
     /// use math_engine::quarternion::Quarternion;
     /// use math_engine::matrix3::Matrix3;
-    /// let q = Quarternion::new_with_scalar(1.0);
+    /// let q = Quarternion::new_with_scalar(2.0);
     /// let m = q.get_rotation_matrix();
+    /// assert!(m == Matrix3::identity());
     /// ```
     pub fn get_rotation_matrix(&self) -> Matrix3 {
+        self.scaled_rotation_matrix(2.0 / self.dot(self))
+    }
+
+    // Returns the rotation matrix of the quaternion with s = 2 / |q|^2 given,
+    // so that callers which know the quaternion is normalized can pass 2.
+    pub(crate) fn scaled_rotation_matrix(&self, s: f32) -> Matrix3 {
         let x2 = self.x * self.x;
         let y2 = self.y * self.y;
         let z2 = self.z * self.z;
@@ -128,15 +474,15 @@ impl Quarternion {
         let wy = self.w * self.y;
         let wz = self.w * self.z;
         Matrix3::new(
-            1.0 - 2.0 * (y2 + z2),
-            2.0 * (xy - wz),
-            2.0 * (xz + wy),
-            2.0 * (xy + wz),
-            1.0 - 2.0 * (x2 + z2),
-            2.0 * (yz - wx),
-            2.0 * (xz - wy),
-            2.0 * (yz + wx),
-            1.0 - 2.0 * (x2 + y2),
+            1.0 - s * (y2 + z2),
+            s * (xy - wz),
+            s * (xz + wy),
+            s * (xy + wz),
+            1.0 - s * (x2 + z2),
+            s * (yz - wx),
+            s * (xz - wy),
+            s * (yz + wx),
+            1.0 - s * (x2 + y2),
         )
     }
 
@@ -188,7 +534,8 @@ impl Quarternion {
         }
     }
 
-    /// Returns the transformation of the vector v with the quaternion.
+    /// Returns the vector v rotated by the rotation the quaternion
+    /// represents. The quaternion does not have to be normalized.
     ///
     /// # Arguments
     ///
@@ -203,9 +550,80 @@ impl Quarternion {
     /// let v = q.transform(&Vector3::new(1.0,0.0,0.0));
     /// ```
     pub fn transform(&self, v: &Vector3) -> Vector3 {
+        self.sandwich(v) / self.dot(self)
+    }
+
+    // Returns q v q*, which is v rotated and scaled by |q|^2.
+    pub(crate) fn sandwich(&self, v: &Vector3) -> Vector3 {
         let b = self.get_vector_part();
         let b2 = b.x * b.x + b.y * b.y + b.z * b.z;
-        *v * (self.w * self.w - b2) + b * (v.dot(&b) * 2.0) + b.cross(&v) * (self.w * 2.0)
+        *v * (self.w * self.w - b2) + b * (v.dot(&b) * 2.0) + b.cross(v) * (self.w * 2.0)
+    }
+
+    /// Returns the rate of change of this orientation while it spins with
+    /// the angular velocity ω, the quaternion ½ ω q, where ω is taken as a
+    /// pure quaternion in world space.
+    ///
+    /// # Arguments
+    ///
+    /// * `angular_velocity` - The angular velocity in world space, in
+    ///   radians per unit time about its direction.
+    pub fn derivative(&self, angular_velocity: &Vector3) -> Quarternion {
+        Quarternion::new_with_vec(angular_velocity) * *self * 0.5
+    }
+
+    /// Returns this orientation stepped forward by dt while spinning with
+    /// the angular velocity ω, for physics integrators. The step applies the
+    /// exact rotation through |ω| dt about ω, the exponential map, which
+    /// unlike adding `derivative` times dt stays on the unit sphere for any
+    /// step size. The result is renormalized so rounding does not build up
+    /// over many steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `angular_velocity` - The angular velocity in world space, in
+    ///   radians per unit time about its direction.
+    /// * `dt` - The time step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// use math_engine::vector3::Vector3;
+    /// // A quarter turn per second about z, stepped at 60 Hz for a second.
+    /// let w = Vector3::new(0.0, 0.0, core::f32::consts::FRAC_PI_2);
+    /// let mut q = Quarternion::IDENTITY;
+    /// for _ in 0..60 {
+    ///     q = q.integrate(&w, 1.0 / 60.0);
+    /// }
+    /// let x = q.transform(&Vector3::new(1.0, 0.0, 0.0));
+    /// assert!((x - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-5);
+    /// ```
+    pub fn integrate(&self, angular_velocity: &Vector3, dt: f32) -> Quarternion {
+        let half = angular_velocity.magnitude() * dt * 0.5;
+        // sin(half) / |ω|, from its series for small angles.
+        let s = if half < 1.0e-4 {
+            dt * 0.5 * (1.0 - half * half / 6.0)
+        } else {
+            half.sin() / angular_velocity.magnitude()
+        };
+        let step = Quarternion::new_with_vec_and_scalar(&(*angular_velocity * s), half.cos());
+        (step * *self).normalize()
+    }
+
+    /// Returns a raw pointer to the components of this quaternion, which are
+    /// laid out contiguously as x, y, z, w.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// let v = Quarternion::new(1.0, 2.0, 3.0, 4.0);
+    /// let p = v.as_ptr();
+    /// assert!(unsafe { *p.add(3) } == 4.0);
+    /// ```
+    pub fn as_ptr(&self) -> *const f32 {
+        (self as *const Quarternion).cast::<f32>()
     }
 }
 
@@ -224,10 +642,7 @@ impl Mul<Quarternion> for Quarternion {
 
 impl MulAssign<Quarternion> for Quarternion {
     fn mul_assign(&mut self, rhs: Quarternion) {
-        self.x = self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y;
-        self.y = self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x;
-        self.z = self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w;
-        self.w = self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z;
+        *self = *self * rhs;
     }
 }
 
@@ -239,3 +654,167 @@ impl MulAssign<f32> for Quarternion {
         self.w *= rhs;
     }
 }
+
+impl Mul<f32> for Quarternion {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Quarternion::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+impl Mul<Quarternion> for f32 {
+    type Output = Quarternion;
+
+    fn mul(self, rhs: Quarternion) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Div<f32> for Quarternion {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        let s = 1.0 / rhs;
+        Quarternion::new(self.x * s, self.y * s, self.z * s, self.w * s)
+    }
+}
+
+impl DivAssign<f32> for Quarternion {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
+impl Add for Quarternion {
+    type Output = Self;
+
+    fn add(self, rhs: Quarternion) -> Self::Output {
+        Quarternion::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+impl AddAssign for Quarternion {
+    fn add_assign(&mut self, rhs: Quarternion) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Quarternion {
+    type Output = Self;
+
+    fn sub(self, rhs: Quarternion) -> Self::Output {
+        Quarternion::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
+
+impl Neg for Quarternion {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl SubAssign for Quarternion {
+    fn sub_assign(&mut self, rhs: Quarternion) {
+        *self = *self - rhs;
+    }
+}
+
+#[test]
+fn algebra() {
+    let p = Quarternion::new(1.0, -2.0, 0.5, 3.0);
+    let q = Quarternion::new(-0.5, 1.0, 2.0, -1.0);
+    let pq = p * q;
+    // |pq| = |p||q| and (pq)^-1 = q^-1 p^-1.
+    assert!((pq.magnitude() - p.magnitude() * q.magnitude()).abs() < 1e-4);
+    let a = pq.inverse();
+    let b = q.inverse() * p.inverse();
+    assert!((a - b).magnitude() < 1e-6);
+    assert!((p * p.inverse() - Quarternion::IDENTITY).magnitude() < 1e-6);
+    assert!(p + q - q == p);
+    assert!(p * 2.0 / 2.0 == p);
+    // A unit quaternion and its conjugate rotate in opposite directions.
+    let r = Quarternion::new(0.3, 0.1, -0.4, 0.8).normalize();
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert!((r.conjugate().transform(&r.transform(&v)) - v).magnitude() < 1e-5);
+}
+
+#[test]
+fn axis_angle() {
+    let close = |a: &Matrix3, b: &Matrix3| (0..3).all(|i| (a[i] - b[i]).magnitude() < 1e-5);
+    assert!(close(
+        &Quarternion::from_rotation_x(0.7).get_rotation_matrix(),
+        &Matrix3::make_rotation_x(0.7)
+    ));
+    assert!(close(
+        &Quarternion::from_rotation_y(-1.1).get_rotation_matrix(),
+        &Matrix3::make_rotation_y(-1.1)
+    ));
+    assert!(close(
+        &Quarternion::from_rotation_z(2.9).get_rotation_matrix(),
+        &Matrix3::make_rotation_z(2.9)
+    ));
+    let axis = Vector3::new(1.0, -2.0, 2.0).to_unit();
+    assert!(close(
+        &Quarternion::from_axis_angle(&axis, 4.0).get_rotation_matrix(),
+        &Matrix3::make_rotation(4.0, &axis)
+    ));
+    let (a, angle) = (Quarternion::from_axis_angle(&axis, 4.0) * 3.0).to_axis_angle();
+    assert!((a - *axis).magnitude() < 1e-5 && (angle - 4.0).abs() < 1e-5);
+    assert!(Quarternion::IDENTITY.to_axis_angle().1 == 0.0);
+}
+
+#[test]
+fn euler() {
+    let q = Quarternion::from_euler(EulerOrder::XZY, 0.4, -1.0, 2.2);
+    let m = Matrix3::from_euler(EulerOrder::XZY, 0.4, -1.0, 2.2);
+    let r = q.get_rotation_matrix();
+    assert!((0..3).all(|i| (r[i] - m[i]).magnitude() < 1e-5));
+}
+
+#[test]
+fn integration() {
+    let q = Quarternion::from_euler(EulerOrder::XYZ, 0.3, -0.8, 1.4);
+    let w = Vector3::new(0.7, -0.2, 1.5);
+    // The step agrees with the derivative to first order.
+    let h = 1e-3;
+    let fd = (q.integrate(&w, h) - q.integrate(&w, -h)) / (2.0 * h);
+    assert!((fd - q.derivative(&w)).magnitude() < 1e-3);
+    // A constant spin for a time t is the rotation through |ω| t about ω,
+    // whatever the step count.
+    let mut p = q;
+    for _ in 0..100 {
+        p = p.integrate(&w, 0.02);
+    }
+    let exact = Quarternion::from_axis_angle(&w.to_unit(), w.magnitude() * 2.0) * q;
+    assert!(p.dot(&exact).abs() > 1.0 - 1e-5);
+    assert!(q.integrate(&Vector3::new(0.0, 0.0, 0.0), 0.5) == q.normalize());
+}
+
+#[test]
+fn vector_part() {
+    let q = Quarternion::new(1.0, 2.0, 3.0, 4.0);
+    assert!(q.get_vector_part() == Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn mul_assign() {
+    let a = Quarternion::new(1.0, 2.0, 3.0, 4.0);
+    let b = Quarternion::new(-2.0, 0.5, 1.0, 3.0);
+    let mut c = a;
+    c *= b;
+    let ab = a * b;
+    assert!(c.get_vector_part() == ab.get_vector_part() && c.w == ab.w);
+}
@@ -0,0 +1,248 @@
+//! Random vectors, points, rotations and colors through the `rand` crate,
+//! for procedural generation and Monte Carlo sampling. Enabled by the
+//! `rand` feature.
+//!
+//! `Standard` samples each component uniformly from [0, 1), as `rand` does
+//! for floats, which makes random colors and points in the unit square or
+//! cube. The unit structs here sample the usual geometric distributions,
+//! each uniform over its shape.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use core::f32::consts::TAU;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+/// Samples directions uniformly from the unit circle, as `Vector2`.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::random::UnitCircle;
+/// use math_engine::vector2::Vector2;
+/// use rand::rngs::SmallRng;
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = SmallRng::seed_from_u64(7);
+/// let v: Vector2 = rng.sample(UnitCircle);
+/// assert!((v.magnitude() - 1.0).abs() < 1e-6);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnitCircle;
+
+/// Samples directions uniformly from the unit sphere, as `Vector3` or
+/// `UnitVector3`.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::random::UnitSphere;
+/// use math_engine::unit_vector3::UnitVector3;
+/// use rand::rngs::SmallRng;
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = SmallRng::seed_from_u64(7);
+/// let n: UnitVector3 = rng.sample(UnitSphere);
+/// assert!((n.magnitude() - 1.0).abs() < 1e-6);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnitSphere;
+
+/// Samples points uniformly from the disk of radius 1 about the origin, as
+/// `Point2` or `Vector2`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnitDisk;
+
+/// Samples points uniformly from the ball of radius 1 about the origin, as
+/// `Point3` or `Vector3`.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point3::Point3;
+/// use math_engine::random::UnitBall;
+/// use rand::rngs::SmallRng;
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = SmallRng::seed_from_u64(7);
+/// let p: Point3 = rng.sample(UnitBall);
+/// assert!(p.x * p.x + p.y * p.y + p.z * p.z <= 1.0);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnitBall;
+
+/// Samples rotations uniformly over all orientations, as `Quarternion` or
+/// `UnitQuaternion`, with Shoemake's method.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::random::UniformRotation;
+/// use math_engine::unit_quaternion::UnitQuaternion;
+/// use rand::rngs::SmallRng;
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = SmallRng::seed_from_u64(7);
+/// let q: UnitQuaternion = rng.sample(UniformRotation);
+/// assert!((q.as_quarternion().magnitude() - 1.0).abs() < 1e-6);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UniformRotation;
+
+// Returns a number uniform in [-1, 1).
+fn signed<R: Rng + ?Sized>(rng: &mut R) -> f32 {
+    rng.gen::<f32>() * 2.0 - 1.0
+}
+
+impl Distribution<Vector2> for UnitCircle {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+        let a = rng.gen::<f32>() * TAU;
+        Vector2::new(a.cos(), a.sin())
+    }
+}
+
+impl Distribution<Vector3> for UnitSphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        // By Archimedes' hat-box theorem z is uniform on the sphere.
+        let z = signed(rng);
+        let a = rng.gen::<f32>() * TAU;
+        let r = (1.0 - z * z).max(0.0).sqrt();
+        Vector3::new(r * a.cos(), r * a.sin(), z)
+    }
+}
+
+impl Distribution<UnitVector3> for UnitSphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UnitVector3 {
+        UnitVector3::new_normalize(rng.sample::<Vector3, _>(UnitSphere))
+    }
+}
+
+impl Distribution<Vector2> for UnitDisk {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+        // Rejection from the square accepts π/4 of the samples.
+        loop {
+            let v = Vector2::new(signed(rng), signed(rng));
+            if v.x * v.x + v.y * v.y <= 1.0 {
+                return v;
+            }
+        }
+    }
+}
+
+impl Distribution<Point2> for UnitDisk {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point2 {
+        let v: Vector2 = rng.sample(UnitDisk);
+        Point2::new(v.x, v.y)
+    }
+}
+
+impl Distribution<Vector3> for UnitBall {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        // Rejection from the cube accepts π/6 of the samples.
+        loop {
+            let v = Vector3::new(signed(rng), signed(rng), signed(rng));
+            if v.dot(&v) <= 1.0 {
+                return v;
+            }
+        }
+    }
+}
+
+impl Distribution<Point3> for UnitBall {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point3 {
+        let v: Vector3 = rng.sample(UnitBall);
+        Point3::new(v.x, v.y, v.z)
+    }
+}
+
+impl Distribution<Quarternion> for UniformRotation {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quarternion {
+        let u: f32 = rng.gen();
+        let (a, b) = (rng.gen::<f32>() * TAU, rng.gen::<f32>() * TAU);
+        let (s, t) = ((1.0 - u).sqrt(), u.sqrt());
+        Quarternion::new(s * a.sin(), s * a.cos(), t * b.sin(), t * b.cos())
+    }
+}
+
+impl Distribution<UnitQuaternion> for UniformRotation {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UnitQuaternion {
+        UnitQuaternion::new_normalize(rng.sample(UniformRotation))
+    }
+}
+
+impl Distribution<Vector3> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        let (x, y, z) = rng.gen();
+        Vector3 { x, y, z }
+    }
+}
+
+impl Distribution<DVector3> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DVector3 {
+        let (x, y, z) = rng.gen();
+        DVector3 { x, y, z }
+    }
+}
+
+impl Distribution<RGB> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RGB {
+        let (r, g, b) = rng.gen();
+        RGB::new(r, g, b)
+    }
+}
+
+impl Distribution<RGBA> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RGBA {
+        let (r, g, b, a) = rng.gen();
+        RGBA::new(r, g, b, a)
+    }
+}
+
+impl Distribution<RGBu8> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RGBu8 {
+        let (r, g, b) = rng.gen();
+        RGBu8::new(r, g, b)
+    }
+}
+
+impl Distribution<RGBAu8> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RGBAu8 {
+        let (r, g, b, a) = rng.gen();
+        RGBAu8::new(r, g, b, a)
+    }
+}
+
+#[test]
+fn uniformity() {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+    let mut rng = SmallRng::seed_from_u64(1);
+    let n = 20_000;
+    // The mean of a uniform distribution over a shape symmetric about the
+    // origin is the origin, and on the unit sphere each squared component
+    // averages 1/3.
+    let (mut mean, mut squares) = (Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 0.0));
+    for _ in 0..n {
+        let v: Vector3 = rng.sample(UnitSphere);
+        assert!((v.magnitude() - 1.0).abs() < 1e-5);
+        mean += v;
+        squares += v * v;
+    }
+    mean /= n as f32;
+    squares /= n as f32;
+    assert!(mean.magnitude() < 0.03);
+    assert!((squares.x - 1.0 / 3.0).abs() < 0.02 && (squares.z - 1.0 / 3.0).abs() < 0.02);
+    // Half the volume of the unit ball lies outside radius 0.5^(1/3).
+    let outside = (0..n)
+        .filter(|_| rng.sample::<Vector3, _>(UnitBall).magnitude() > 0.5f32.cbrt())
+        .count();
+    assert!((outside as f32 / n as f32 - 0.5).abs() < 0.02);
+    // Uniform rotations move the z axis uniformly over the sphere.
+    let mut z_mean = 0.0;
+    for _ in 0..n {
+        let q: UnitQuaternion = rng.sample(UniformRotation);
+        assert!((q.as_quarternion().magnitude() - 1.0).abs() < 1e-5);
+        z_mean += q.transform(&Vector3::new(0.0, 0.0, 1.0)).z;
+    }
+    assert!((z_mean / n as f32).abs() < 0.03);
+    let c: RGBA = rng.gen();
+    assert!((0.0..1.0).contains(&c.r) && (0.0..1.0).contains(&c.a));
+}
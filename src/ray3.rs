@@ -0,0 +1,53 @@
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A half-line starting at origin and extending along direction.
+#[repr(C)]
+pub struct Ray3 {
+    /// The start of the ray.
+    pub origin: Point3,
+    /// The direction of the ray. It is not required to have unit length.
+    pub direction: Vector3,
+}
+
+impl Ray3 {
+    /// Returns a ray starting at origin and extending along direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The start of the ray.
+    /// * `direction` - The direction of the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::ray3::Ray3;
+    /// use math_engine::vector3::Vector3;
+    /// let r = Ray3::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+    /// assert!(r.direction.z == -1.0);
+    /// ```
+    pub const fn new(origin: Point3, direction: Vector3) -> Ray3 {
+        Ray3 { origin, direction }
+    }
+
+    /// Returns the point origin + direction * t along this ray.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The parameter along the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::ray3::Ray3;
+    /// use math_engine::vector3::Vector3;
+    /// let r = Ray3::new(Point3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0));
+    /// assert!(r.at(1.5) == Point3::new(1.0, 3.0, 0.0));
+    /// ```
+    pub fn at(&self, t: f32) -> Point3 {
+        self.origin + self.direction * t
+    }
+}
@@ -1,9 +1,21 @@
+use crate::parse::hex_color;
 use crate::prelude::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
+use crate::scalar;
+use alloc::format;
+use alloc::string::String;
+use core::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A color having floating point red, green, and blue components
 /// in the range [0.0, 1.0]. It is assumed it's alpha component is 1.0.
+///
+/// The components are stored as given, in whichever space the caller works
+/// in. Lighting and blending math should be done on linear values; colors
+/// from images, hex codes and color pickers are usually sRGB encoded and
+/// should go through `srgb_to_linear` first, and back through
+/// `linear_to_srgb` for display.
+#[repr(C)]
 pub struct RGB {
     /// The red component.
     pub r: f32,
@@ -29,28 +41,255 @@ impl RGB {
     /// let rgb = RGB::new(1.0,1.0,1.0);
     /// assert!(rgb.r == 1.0 && rgb.g == 1.0 && rgb.b == 1.0);
     /// ```
-    pub fn new(r: f32, g: f32, b: f32) -> RGB {
-        Self {
-            r: r,
-            g: g,
-            b: b,
-        }
+    pub const fn new(r: f32, g: f32, b: f32) -> RGB {
+        Self { r, g, b }
+    }
+
+    /// Returns the color with the hex code 0xrrggbb, as written in CSS and
+    /// theme files. The channels are taken as they are, so a web color
+    /// gives sRGB encoded channels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb::RGB;
+    /// const ORANGE: RGB = RGB::from_hex(0xff8000);
+    /// assert!(ORANGE == RGB::new(1.0, 128.0 / 255.0, 0.0));
+    /// ```
+    pub const fn from_hex(hex: u32) -> RGB {
+        RGB::new(
+            ((hex >> 16) & 0xff) as f32 / 255.0,
+            ((hex >> 8) & 0xff) as f32 / 255.0,
+            (hex & 0xff) as f32 / 255.0,
+        )
     }
 
+    /// Returns the color written as `#rrggbb` or `#rgb`, with surrounding
+    /// whitespace ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The hex color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::parse::ParseError;
+    /// use math_engine::rgb::RGB;
+    /// assert!(RGB::from_hex_str("#ff8000") == Ok(RGB::from_hex(0xff8000)));
+    /// assert!(RGB::from_hex_str("#f80") == Ok(RGB::from_hex(0xff8800)));
+    /// assert!(RGB::from_hex_str("ff8000") == Err(ParseError::InvalidHex));
+    /// assert!(RGB::from_hex_str("#ff800080") == Err(ParseError::InvalidHex));
+    /// ```
+    pub fn from_hex_str(s: &str) -> Result<RGB, ParseError> {
+        let [r, g, b, _] = hex_color(s, false)?;
+        Ok(RGB::new(r, g, b))
+    }
+
+    /// Returns this color as a lowercase `#rrggbb` string, with each
+    /// channel clamped to [0, 1] and rounded to the nearest byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb::RGB;
+    /// assert!(RGB::new(1.0, 0.5, 0.0).to_hex_string() == "#ff8000");
+    /// assert!(RGB::from_hex_str("#1a2b3c").unwrap().to_hex_string() == "#1a2b3c");
+    /// ```
+    pub fn to_hex_string(&self) -> String {
+        format!(
+            "#{:02x}{:02x}{:02x}",
+            hex_byte(self.r),
+            hex_byte(self.g),
+            hex_byte(self.b)
+        )
+    }
+
+    #[allow(non_snake_case)]
     pub fn White() -> RGB {
         let r = 1.0;
         let g = 1.0;
         let b = 1.0;
-        RGB{
-            r,
-            g,
-            b,
+        RGB { r, g, b }
+    }
+
+    /// Returns this sRGB encoded color decoded to linear light, using the
+    /// exact piecewise sRGB transfer function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb::RGB;
+    /// let c = RGB::new(0.5, 1.0, 0.02).srgb_to_linear();
+    /// assert!((c.r - 0.214_041).abs() < 1e-6 && c.g == 1.0 && c.b == 0.02 / 12.92);
+    /// ```
+    pub fn srgb_to_linear(&self) -> RGB {
+        RGB::new(
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+        )
+    }
+
+    /// Returns this linear color encoded with the sRGB transfer function,
+    /// the inverse of `srgb_to_linear`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb::RGB;
+    /// let c = RGB::new(0.2, 0.5, 0.8);
+    /// let d = c.linear_to_srgb().srgb_to_linear();
+    /// assert!((d.r - c.r).abs() < 1e-6 && (d.g - c.g).abs() < 1e-6 && (d.b - c.b).abs() < 1e-6);
+    /// ```
+    pub fn linear_to_srgb(&self) -> RGB {
+        RGB::new(
+            linear_to_srgb(self.r),
+            linear_to_srgb(self.g),
+            linear_to_srgb(self.b),
+        )
+    }
+
+    /// Returns this color with each channel clamped between the
+    /// corresponding channels of lo and hi.
+    ///
+    /// # Arguments
+    ///
+    /// * `lo` - The lower bounds.
+    /// * `hi` - The upper bounds.
+    pub fn clamp(&self, lo: &RGB, hi: &RGB) -> RGB {
+        RGB::new(
+            self.r.clamp(lo.r, hi.r),
+            self.g.clamp(lo.g, hi.g),
+            self.b.clamp(lo.b, hi.b),
+        )
+    }
+
+    /// Returns this color with each channel clamped to [0, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb::RGB;
+    /// assert!(RGB::new(1.5, -0.25, 0.5).clamp01() == RGB::new(1.0, 0.0, 0.5));
+    /// ```
+    pub fn clamp01(&self) -> RGB {
+        RGB::new(
+            scalar::clamp01(self.r),
+            scalar::clamp01(self.g),
+            scalar::clamp01(self.b),
+        )
+    }
+
+    /// Returns this color with each channel clamped to [0, 1], as a
+    /// shader's saturate does; the same as `clamp01`.
+    pub fn saturate(&self) -> RGB {
+        self.clamp01()
+    }
+
+    /// Returns the relative luminance of this linear color, with the
+    /// Rec. 709 weights of the sRGB primaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb::RGB;
+    /// assert!((RGB::new(1.0, 1.0, 1.0).luminance() - 1.0).abs() < 1e-6);
+    /// assert!(RGB::new(0.0, 1.0, 0.0).luminance() == 0.7152);
+    /// ```
+    pub fn luminance(&self) -> f32 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
+    }
+
+    /// Returns the gray with the luminance of this color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb::RGB;
+    /// assert!(RGB::new(0.0, 1.0, 0.0).grayscale() == RGB::new(0.7152, 0.7152, 0.7152));
+    /// ```
+    pub fn grayscale(&self) -> RGB {
+        let y = self.luminance();
+        RGB::new(y, y, y)
+    }
+
+    /// Returns the largest channel, the brightness HDR colors are usually
+    /// normalized or tonemapped by.
+    pub fn max_component(&self) -> f32 {
+        self.r.max(self.g).max(self.b)
+    }
+
+    /// Returns true if no channel is infinite or NaN.
+    pub fn is_finite(&self) -> bool {
+        self.r.is_finite() && self.g.is_finite() && self.b.is_finite()
+    }
+
+    /// Returns the color interpolated linearly between this color at t = 0 and
+    /// other at t = 1. Each component is interpolated independently, in
+    /// whatever space the color is stored in.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color at t = 1.
+    /// * `t` - The interpolation parameter.
+    pub fn lerp(&self, other: &RGB, t: f32) -> RGB {
+        RGB {
+            r: scalar::lerp(self.r, other.r, t),
+            g: scalar::lerp(self.g, other.g, t),
+            b: scalar::lerp(self.b, other.b, t),
         }
     }
+
+    /// Returns a raw pointer to the components of this color, which are
+    /// laid out contiguously as r, g, b.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb::RGB;
+    /// let v = RGB::new(0.25, 0.5, 1.0);
+    /// let p = v.as_ptr();
+    /// assert!(unsafe { *p.add(2) } == 1.0);
+    /// ```
+    pub fn as_ptr(&self) -> *const f32 {
+        (self as *const RGB).cast::<f32>()
+    }
 }
 
-pub const WHITE: RGB = RGB{ r: 1.0, g: 1.0, b: 1.0 };
-pub const BLACK: RGB = RGB{ r: 0.0, g: 0.0, b: 0.0 };
+// Returns a channel in [0, 1] as the nearest byte, for hex codes.
+pub(crate) fn hex_byte(c: f32) -> u8 {
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+// Decodes an sRGB encoded channel to linear light.
+pub(crate) fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// Encodes a linear channel with the sRGB transfer function.
+pub(crate) fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+pub const WHITE: RGB = RGB {
+    r: 1.0,
+    g: 1.0,
+    b: 1.0,
+};
+pub const BLACK: RGB = RGB {
+    r: 0.0,
+    g: 0.0,
+    b: 0.0,
+};
 
 impl Index<usize> for RGB {
     type Output = f32;
@@ -90,9 +329,9 @@ impl Div<f32> for RGB {
 impl DivAssign<f32> for RGB {
     fn div_assign(&mut self, rhs: f32) {
         let s = 1.0 / rhs;
-        self.r = self.r * s;
-        self.g = self.g * s;
-        self.b = self.b * s;
+        self.r *= s;
+        self.g *= s;
+        self.b *= s;
     }
 }
 
@@ -124,9 +363,9 @@ impl Sub for RGB {
 
 impl SubAssign for RGB {
     fn sub_assign(&mut self, rhs: Self) {
-        self.r = self.r - rhs.r;
-        self.g = self.g - rhs.g;
-        self.b = self.b - rhs.b;
+        self.r -= rhs.r;
+        self.g -= rhs.g;
+        self.b -= rhs.b;
     }
 }
 
@@ -138,6 +377,14 @@ impl Mul<f32> for RGB {
     }
 }
 
+impl Mul<RGB> for f32 {
+    type Output = RGB;
+
+    fn mul(self, rhs: RGB) -> Self::Output {
+        rhs * self
+    }
+}
+
 impl Mul for RGB {
     type Output = Self;
 
@@ -148,23 +395,23 @@ impl Mul for RGB {
 
 impl MulAssign for RGB {
     fn mul_assign(&mut self, rhs: RGB) {
-        self.r = self.r * rhs.r;
-        self.g = self.g * rhs.g;
-        self.b = self.b * rhs.b;
+        self.r *= rhs.r;
+        self.g *= rhs.g;
+        self.b *= rhs.b;
     }
 }
 
 impl MulAssign<f32> for RGB {
     fn mul_assign(&mut self, rhs: f32) {
-        self.r = self.r * rhs;
-        self.g = self.g * rhs;
-        self.b = self.b * rhs;
+        self.r *= rhs;
+        self.g *= rhs;
+        self.b *= rhs;
     }
 }
 
-impl Into<RGBA> for RGB {
-    fn into(self) -> RGBA {
-        RGBA::new(self.r, self.g, self.b, 1.0)
+impl From<RGB> for RGBA {
+    fn from(rhs: RGB) -> Self {
+        RGBA::new(rhs.r, rhs.g, rhs.b, 1.0)
     }
 }
 
@@ -180,26 +427,25 @@ impl From<[f32; 3]> for RGB {
     }
 }
 
-impl Into<[f32; 3]> for RGB {
-    fn into(self) -> [f32; 3] {
-        [self.r, self.g, self.b]
+impl From<RGB> for [f32; 3] {
+    fn from(rhs: RGB) -> Self {
+        [rhs.r, rhs.g, rhs.b]
     }
 }
 
-impl Into<u32> for RGB {
-    // TODO: look into if I need to do a ceil or floor
-    fn into(self) -> u32 {
-        let r = (self.r * 255.0).ceil() as u32;
-        let g = (self.g * 255.0).ceil() as u32;
-        let b = (self.b * 255.0).ceil() as u32;
-        let a = 0;
-        (r&0xFF)<<0 | (g&0xFF)<<8 | (b&0xFF)<<16 | (a&0xFF)<<24
+impl From<RGB> for u32 {
+    /// Packs the channels as 0x00RRGGBB, the hex code of the color, clamping
+    /// them to [0, 1] and rounding to the nearest byte. The channels keep
+    /// their space, so an sRGB encoded color gives sRGB bytes.
+    fn from(rhs: RGB) -> Self {
+        u32::from_be_bytes([0, hex_byte(rhs.r), hex_byte(rhs.g), hex_byte(rhs.b)])
     }
 }
 
 impl From<u32> for RGB {
+    /// Reads the color from 0x00RRGGBB, as `RGB::from_hex`, ignoring the
+    /// top byte.
     fn from(rhs: u32) -> Self {
-        let s = rhs as f32;
-        RGB::new(s / 255.0, s / 255.0, s / 255.0)
+        RGB::from_hex(rhs)
     }
 }
@@ -1,8 +1,9 @@
 use crate::prelude::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A RGBU encapsulates a color having unsigned int red, green, and blue.
+#[repr(C)]
 pub struct RGBu32 {
     /// The red component.
     pub r: u32,
@@ -13,9 +14,78 @@ pub struct RGBu32 {
 }
 
 impl RGBu32 {
-    pub fn new(r: u32, g: u32, b: u32) -> RGBu32 {
+    pub const fn new(r: u32, g: u32, b: u32) -> RGBu32 {
         Self { r, g, b }
     }
+
+    /// Returns the component-wise sum of this color and other, clamped to the range of u32.
+    pub fn saturating_add(&self, other: &RGBu32) -> RGBu32 {
+        RGBu32::new(
+            self.r.saturating_add(other.r),
+            self.g.saturating_add(other.g),
+            self.b.saturating_add(other.b),
+        )
+    }
+
+    /// Returns the component-wise sum of this color and other, wrapped around modulo 2^32.
+    pub fn wrapping_add(&self, other: &RGBu32) -> RGBu32 {
+        RGBu32::new(
+            self.r.wrapping_add(other.r),
+            self.g.wrapping_add(other.g),
+            self.b.wrapping_add(other.b),
+        )
+    }
+
+    /// Returns the component-wise difference of this color and other, clamped to the range of u32.
+    pub fn saturating_sub(&self, other: &RGBu32) -> RGBu32 {
+        RGBu32::new(
+            self.r.saturating_sub(other.r),
+            self.g.saturating_sub(other.g),
+            self.b.saturating_sub(other.b),
+        )
+    }
+
+    /// Returns the component-wise difference of this color and other, wrapped around modulo 2^32.
+    pub fn wrapping_sub(&self, other: &RGBu32) -> RGBu32 {
+        RGBu32::new(
+            self.r.wrapping_sub(other.r),
+            self.g.wrapping_sub(other.g),
+            self.b.wrapping_sub(other.b),
+        )
+    }
+
+    /// Returns the component-wise product of this color and other, clamped to the range of u32.
+    pub fn saturating_mul(&self, other: &RGBu32) -> RGBu32 {
+        RGBu32::new(
+            self.r.saturating_mul(other.r),
+            self.g.saturating_mul(other.g),
+            self.b.saturating_mul(other.b),
+        )
+    }
+
+    /// Returns the component-wise product of this color and other, wrapped around modulo 2^32.
+    pub fn wrapping_mul(&self, other: &RGBu32) -> RGBu32 {
+        RGBu32::new(
+            self.r.wrapping_mul(other.r),
+            self.g.wrapping_mul(other.g),
+            self.b.wrapping_mul(other.b),
+        )
+    }
+
+    /// Returns a raw pointer to the components of this color, which are
+    /// laid out contiguously as r, g, b.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb_u32::RGBu32;
+    /// let v = RGBu32::new(1, 2, 3);
+    /// let p = v.as_ptr();
+    /// assert!(unsafe { *p.add(2) } == 3);
+    /// ```
+    pub fn as_ptr(&self) -> *const u32 {
+        (self as *const RGBu32).cast::<u32>()
+    }
 }
 
 impl From<RGB> for RGBu32 {
@@ -23,3 +93,22 @@ impl From<RGB> for RGBu32 {
         RGBu32::new(rhs.r as u32, rhs.g as u32, rhs.b as u32)
     }
 }
+
+impl From<RGBu8> for RGBu32 {
+    /// Widens each component, so that arithmetic on the result cannot
+    /// overflow where the bytes would.
+    fn from(rhs: RGBu8) -> Self {
+        RGBu32::new(rhs.r.into(), rhs.g.into(), rhs.b.into())
+    }
+}
+
+#[test]
+fn widening() {
+    let a = RGBu8::new(250, 10, 128);
+    let sum = RGBu32::from(a).wrapping_add(&RGBu32::from(a));
+    assert!(sum == RGBu32::new(500, 20, 256));
+    assert!(RGBu8::try_from(sum).is_err());
+    assert!(RGBu8::try_from(RGBu32::new(250, 10, 128)) == Ok(a));
+    assert!(a.saturating_sub(&RGBu8::new(255, 5, 0)) == RGBu8::new(0, 5, 128));
+    assert!(a.wrapping_mul(&RGBu8::new(2, 2, 2)) == RGBu8::new(244, 20, 0));
+}
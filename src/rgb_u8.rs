@@ -1,8 +1,11 @@
 use crate::prelude::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
+use core::num::TryFromIntError;
 
 #[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A RGBU encapsulates a color having unsigned byte red, green, and blue.
+/// Byte colors, as stored in images, are normally sRGB encoded.
+#[repr(C)]
 pub struct RGBu8 {
     /// The red component.
     pub r: u8,
@@ -13,16 +16,142 @@ pub struct RGBu8 {
 }
 
 impl RGBu8 {
-    pub fn new(r: u8, g: u8, b: u8) -> RGBu8 {
+    pub const fn new(r: u8, g: u8, b: u8) -> RGBu8 {
         Self { r, g, b }
     }
+
+    /// Returns this sRGB encoded color decoded to linear light, as floats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb::RGB;
+    /// use math_engine::rgb_u8::RGBu8;
+    /// let c = RGBu8::new(255, 0, 188).srgb_to_linear();
+    /// assert!(c.r == 1.0 && c.g == 0.0 && (c.b - 0.502_886).abs() < 1e-5);
+    /// assert!(RGBu8::linear_to_srgb(&c) == RGBu8::new(255, 0, 188));
+    /// ```
+    pub fn srgb_to_linear(&self) -> RGB {
+        (RGB::new(self.r as f32, self.g as f32, self.b as f32) / 255.0).srgb_to_linear()
+    }
+
+    /// Returns the linear color encoded with the sRGB transfer function and
+    /// rounded to bytes, clamping components outside [0, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `linear` - A reference to the linear color.
+    pub fn linear_to_srgb(linear: &RGB) -> RGBu8 {
+        let byte = |c: f32| (crate::rgb::linear_to_srgb(c.clamp(0.0, 1.0)) * 255.0).round() as u8;
+        RGBu8::new(byte(linear.r), byte(linear.g), byte(linear.b))
+    }
+
+    /// Returns the component-wise sum of this color and other, clamped to the range of u8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb_u8::RGBu8;
+    /// let c = RGBu8::new(200, 100, 0).saturating_add(&RGBu8::new(100, 100, 100));
+    /// assert!(c == RGBu8::new(255, 200, 100));
+    /// ```
+    pub fn saturating_add(&self, other: &RGBu8) -> RGBu8 {
+        RGBu8::new(
+            self.r.saturating_add(other.r),
+            self.g.saturating_add(other.g),
+            self.b.saturating_add(other.b),
+        )
+    }
+
+    /// Returns the component-wise sum of this color and other, wrapped around modulo 2^8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb_u8::RGBu8;
+    /// let c = RGBu8::new(200, 100, 0).wrapping_add(&RGBu8::new(100, 100, 100));
+    /// assert!(c == RGBu8::new(44, 200, 100));
+    /// ```
+    pub fn wrapping_add(&self, other: &RGBu8) -> RGBu8 {
+        RGBu8::new(
+            self.r.wrapping_add(other.r),
+            self.g.wrapping_add(other.g),
+            self.b.wrapping_add(other.b),
+        )
+    }
+
+    /// Returns the component-wise difference of this color and other, clamped to the range of u8.
+    pub fn saturating_sub(&self, other: &RGBu8) -> RGBu8 {
+        RGBu8::new(
+            self.r.saturating_sub(other.r),
+            self.g.saturating_sub(other.g),
+            self.b.saturating_sub(other.b),
+        )
+    }
+
+    /// Returns the component-wise difference of this color and other, wrapped around modulo 2^8.
+    pub fn wrapping_sub(&self, other: &RGBu8) -> RGBu8 {
+        RGBu8::new(
+            self.r.wrapping_sub(other.r),
+            self.g.wrapping_sub(other.g),
+            self.b.wrapping_sub(other.b),
+        )
+    }
+
+    /// Returns the component-wise product of this color and other, clamped to the range of u8.
+    pub fn saturating_mul(&self, other: &RGBu8) -> RGBu8 {
+        RGBu8::new(
+            self.r.saturating_mul(other.r),
+            self.g.saturating_mul(other.g),
+            self.b.saturating_mul(other.b),
+        )
+    }
+
+    /// Returns the component-wise product of this color and other, wrapped around modulo 2^8.
+    pub fn wrapping_mul(&self, other: &RGBu8) -> RGBu8 {
+        RGBu8::new(
+            self.r.wrapping_mul(other.r),
+            self.g.wrapping_mul(other.g),
+            self.b.wrapping_mul(other.b),
+        )
+    }
+
+    /// Returns a raw pointer to the components of this color, which are
+    /// laid out contiguously as r, g, b.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb_u8::RGBu8;
+    /// let v = RGBu8::new(1, 2, 3);
+    /// let p = v.as_ptr();
+    /// assert!(unsafe { *p.add(2) } == 3);
+    /// ```
+    pub fn as_ptr(&self) -> *const u8 {
+        (self as *const RGBu8).cast::<u8>()
+    }
 }
 
 impl From<RGB> for RGBu8 {
+    /// Scales the channels to bytes without changing their space, truncating;
+    /// use `RGBu8::linear_to_srgb` to encode a linear color.
     fn from(rhs: RGB) -> Self {
-        let r = ((rhs.r * 255.00)) as u8;
-        let g = ((rhs.g * 255.00)) as u8;
-        let b = ((rhs.b * 255.00)) as u8;
+        let r = (rhs.r * 255.00) as u8;
+        let g = (rhs.g * 255.00) as u8;
+        let b = (rhs.b * 255.00) as u8;
         RGBu8::new(r, g, b)
     }
 }
+
+impl TryFrom<RGBu32> for RGBu8 {
+    type Error = TryFromIntError;
+
+    /// Narrows each component, failing if any does not fit in a byte.
+    fn try_from(rhs: RGBu32) -> Result<Self, Self::Error> {
+        Ok(RGBu8::new(
+            u8::try_from(rhs.r)?,
+            u8::try_from(rhs.g)?,
+            u8::try_from(rhs.b)?,
+        ))
+    }
+}
@@ -1,9 +1,17 @@
+use crate::parse::hex_color;
 use crate::prelude::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
+use crate::rgb::{hex_byte, linear_to_srgb, srgb_to_linear};
+use crate::scalar;
+use alloc::format;
+use alloc::string::String;
+use core::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A color having floating point red, green, blue, and alpha components
-/// in the range [0.0, 1.0].
+/// in the range [0.0, 1.0]. As with `RGB`, the color channels are in
+/// whichever space the caller works in; alpha is always linear.
+#[repr(C)]
 pub struct RGBA {
     /// The red component.
     pub r: f32,
@@ -25,18 +33,503 @@ impl RGBA {
     /// * `b` - Blue color component in the range of [0.0, 1.0].
     /// * `a` - Alpha color component in the range of [0.0, 1.0].
     ///
+    /// The components are stored as given; use `saturate` to bring them
+    /// into range.
+    ///
     /// # Examples
     ///
     /// ```
     /// use math_engine::rgba::RGBA;
     /// let rgba = RGBA::new(1.0,1.0,1.0,1.0);
+    /// assert!(RGBA::new(2.0, 0.5, 0.0, 1.0).r == 2.0);
+    /// ```
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> RGBA {
+        Self { r, g, b, a }
+    }
+
+    /// Returns this color with each component clamped between the
+    /// corresponding components of lo and hi.
+    ///
+    /// # Arguments
+    ///
+    /// * `lo` - The lower bounds.
+    /// * `hi` - The upper bounds.
+    pub fn clamp(&self, lo: &RGBA, hi: &RGBA) -> RGBA {
+        RGBA::new(
+            self.r.clamp(lo.r, hi.r),
+            self.g.clamp(lo.g, hi.g),
+            self.b.clamp(lo.b, hi.b),
+            self.a.clamp(lo.a, hi.a),
+        )
+    }
+
+    /// Returns this color with each component clamped to [0, 1].
+    pub fn clamp01(&self) -> RGBA {
+        RGBA::new(
+            scalar::clamp01(self.r),
+            scalar::clamp01(self.g),
+            scalar::clamp01(self.b),
+            scalar::clamp01(self.a),
+        )
+    }
+
+    /// Returns this color with each component clamped to [0, 1], as a
+    /// shader's saturate does; the same as `clamp01`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgba::RGBA;
+    /// let c = RGBA::new(1.5, -0.25, 0.5, 2.0).saturate();
+    /// assert!(c == RGBA::new(1.0, 0.0, 0.5, 1.0));
+    /// ```
+    pub fn saturate(&self) -> RGBA {
+        self.clamp01()
+    }
+
+    /// Returns the relative luminance of the color channels, ignoring alpha.
+    /// See `RGB::luminance`.
+    pub fn luminance(&self) -> f32 {
+        RGB::from(*self).luminance()
+    }
+
+    /// Returns the gray with the luminance of this color, keeping alpha.
+    pub fn grayscale(&self) -> RGBA {
+        let y = self.luminance();
+        RGBA::new(y, y, y, self.a)
+    }
+
+    /// Returns the largest color channel, ignoring alpha.
+    pub fn max_component(&self) -> f32 {
+        self.r.max(self.g).max(self.b)
+    }
+
+    /// Returns true if no component, alpha included, is infinite or NaN.
+    pub fn is_finite(&self) -> bool {
+        self.r.is_finite() && self.g.is_finite() && self.b.is_finite() && self.a.is_finite()
+    }
+
+    /// Returns the color with the hex code 0xrrggbbaa, alpha in the lowest
+    /// byte as in CSS.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgba::RGBA;
+    /// const SHADOW: RGBA = RGBA::from_hex(0x000000ff);
+    /// assert!(SHADOW == RGBA::new(0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub const fn from_hex(hex: u32) -> RGBA {
+        RGBA {
+            r: (hex >> 24) as f32 / 255.0,
+            g: ((hex >> 16) & 0xff) as f32 / 255.0,
+            b: ((hex >> 8) & 0xff) as f32 / 255.0,
+            a: (hex & 0xff) as f32 / 255.0,
+        }
+    }
+
+    /// Returns the color written as `#rrggbbaa`, `#rgba`, or without alpha
+    /// as `#rrggbb` or `#rgb` for an opaque color, with surrounding
+    /// whitespace ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The hex color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::parse::ParseError;
+    /// use math_engine::rgba::RGBA;
+    /// assert!(RGBA::from_hex_str("#ff800080") == Ok(RGBA::from_hex(0xff800080)));
+    /// assert!(RGBA::from_hex_str("#ff8000") == Ok(RGBA::from_hex(0xff8000ff)));
+    /// assert!(RGBA::from_hex_str("#ff80008") == Err(ParseError::InvalidHex));
+    /// ```
+    pub fn from_hex_str(s: &str) -> Result<RGBA, ParseError> {
+        hex_color(s, true).map(RGBA::from)
+    }
+
+    /// Returns this color as a lowercase `#rrggbbaa` string, with each
+    /// component clamped to [0, 1] and rounded to the nearest byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgba::RGBA;
+    /// assert!(RGBA::new(1.0, 0.5, 0.0, 0.25).to_hex_string() == "#ff800040");
+    /// ```
+    pub fn to_hex_string(&self) -> String {
+        format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            hex_byte(self.r),
+            hex_byte(self.g),
+            hex_byte(self.b),
+            hex_byte(self.a)
+        )
+    }
+
+    /// Returns this color with its sRGB encoded channels decoded to linear
+    /// light. Alpha is left as it is.
+    pub fn srgb_to_linear(&self) -> RGBA {
+        RGBA {
+            r: srgb_to_linear(self.r),
+            g: srgb_to_linear(self.g),
+            b: srgb_to_linear(self.b),
+            a: self.a,
+        }
+    }
+
+    /// Returns this color with its linear channels encoded with the sRGB
+    /// transfer function. Alpha is left as it is.
+    pub fn linear_to_srgb(&self) -> RGBA {
+        RGBA {
+            r: linear_to_srgb(self.r),
+            g: linear_to_srgb(self.g),
+            b: linear_to_srgb(self.b),
+            a: self.a,
+        }
+    }
+
+    /// Returns the color interpolated linearly between this color at t = 0 and
+    /// other at t = 1. Each component is interpolated independently, in
+    /// whatever space the color is stored in.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color at t = 1.
+    /// * `t` - The interpolation parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgba::RGBA;
+    /// let c = RGBA::new(0.0, 0.0, 1.0, 1.0).lerp(&RGBA::new(1.0, 0.0, 0.0, 0.0), 0.5);
+    /// assert!(c == RGBA::new(0.5, 0.0, 0.5, 0.5));
+    /// ```
+    pub fn lerp(&self, other: &RGBA, t: f32) -> RGBA {
+        RGBA {
+            r: scalar::lerp(self.r, other.r, t),
+            g: scalar::lerp(self.g, other.g, t),
+            b: scalar::lerp(self.b, other.b, t),
+            a: scalar::lerp(self.a, other.a, t),
+        }
+    }
+
+    /// Returns this color with its color channels multiplied by alpha, the
+    /// premultiplied form the Porter–Duff operators and most GPU blending
+    /// work with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgba::RGBA;
+    /// let c = RGBA::new(1.0, 0.5, 0.0, 0.5).premultiply();
+    /// assert!(c == RGBA::new(0.5, 0.25, 0.0, 0.5));
+    /// assert!(c.unpremultiply() == RGBA::new(1.0, 0.5, 0.0, 0.5));
+    /// ```
+    pub fn premultiply(&self) -> RGBA {
+        RGBA {
+            r: self.r * self.a,
+            g: self.g * self.a,
+            b: self.b * self.a,
+            a: self.a,
+        }
+    }
+
+    /// Returns this premultiplied color with its color channels divided by
+    /// alpha again, the inverse of `RGBA::premultiply`. A fully transparent
+    /// color has no color to recover and gives transparent black.
+    pub fn unpremultiply(&self) -> RGBA {
+        if self.a == 0.0 {
+            return RGBA::default();
+        }
+        RGBA {
+            r: self.r / self.a,
+            g: self.g / self.a,
+            b: self.b / self.a,
+            a: self.a,
+        }
+    }
+
+    // Returns the Porter–Duff composite fa * self + fb * dst of premultiplied
+    // colors.
+    fn porter_duff(&self, dst: &RGBA, fa: f32, fb: f32) -> RGBA {
+        RGBA {
+            r: fa * self.r + fb * dst.r,
+            g: fa * self.g + fb * dst.g,
+            b: fa * self.b + fb * dst.b,
+            a: fa * self.a + fb * dst.a,
+        }
+    }
+
+    /// Returns this color composited over dst, the usual layering of one
+    /// translucent image on another. Both colors, and the result, are
+    /// premultiplied.
+    ///
+    /// # Arguments
+    ///
+    /// * `dst` - A reference to the premultiplied color beneath.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgba::RGBA;
+    /// let red = RGBA::new(1.0, 0.0, 0.0, 0.5).premultiply();
+    /// let blue = RGBA::new(0.0, 0.0, 1.0, 1.0);
+    /// assert!(red.over(&blue) == RGBA::new(0.5, 0.0, 0.5, 1.0));
+    /// ```
+    pub fn over(&self, dst: &RGBA) -> RGBA {
+        self.porter_duff(dst, 1.0, 1.0 - self.a)
+    }
+
+    /// Returns the part of this color that lies inside dst, as when masking
+    /// an image by another's coverage. Both colors, and the result, are
+    /// premultiplied. The method is named with a raw identifier, as `in` is
+    /// a keyword: `src.r#in(&dst)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dst` - A reference to the premultiplied mask color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgba::RGBA;
+    /// let src = RGBA::new(1.0, 1.0, 1.0, 1.0);
+    /// let mask = RGBA::new(0.0, 0.0, 0.0, 0.25);
+    /// assert!(src.r#in(&mask) == RGBA::new(0.25, 0.25, 0.25, 0.25));
     /// ```
-    pub fn new(r: f32, g: f32, b: f32, a: f32) -> RGBA {
-        Self {
-            r: r % 1.1,
-            g: g % 1.1,
-            b: b % 1.1,
-            a: a % 1.1,
+    pub fn r#in(&self, dst: &RGBA) -> RGBA {
+        self.porter_duff(dst, dst.a, 0.0)
+    }
+
+    /// Returns the part of this color that lies outside dst. Both colors,
+    /// and the result, are premultiplied.
+    ///
+    /// # Arguments
+    ///
+    /// * `dst` - A reference to the premultiplied mask color.
+    pub fn out(&self, dst: &RGBA) -> RGBA {
+        self.porter_duff(dst, 1.0 - dst.a, 0.0)
+    }
+
+    /// Returns the part of this color inside dst composited over dst, which
+    /// keeps the coverage of dst. Both colors, and the result, are
+    /// premultiplied.
+    ///
+    /// # Arguments
+    ///
+    /// * `dst` - A reference to the premultiplied color beneath.
+    pub fn atop(&self, dst: &RGBA) -> RGBA {
+        self.porter_duff(dst, dst.a, 1.0 - self.a)
+    }
+
+    /// Returns the parts of this color and of dst that do not overlap each
+    /// other. Both colors, and the result, are premultiplied.
+    ///
+    /// # Arguments
+    ///
+    /// * `dst` - A reference to the other premultiplied color.
+    pub fn xor(&self, dst: &RGBA) -> RGBA {
+        self.porter_duff(dst, 1.0 - dst.a, 1.0 - self.a)
+    }
+
+    /// Returns a raw pointer to the components of this color, which are
+    /// laid out contiguously as r, g, b, a.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgba::RGBA;
+    /// let v = RGBA::new(0.25, 0.5, 0.75, 1.0);
+    /// let p = v.as_ptr();
+    /// assert!(unsafe { *p.add(3) } == 1.0);
+    /// ```
+    pub fn as_ptr(&self) -> *const f32 {
+        (self as *const RGBA).cast::<f32>()
+    }
+}
+
+impl Index<usize> for RGBA {
+    type Output = f32;
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.r,
+            1 => &self.g,
+            2 => &self.b,
+            3 => &self.a,
+            _ => panic!("RGBA index out of range: {}", i),
         }
     }
 }
+
+impl IndexMut<usize> for RGBA {
+    fn index_mut(&mut self, i: usize) -> &mut f32 {
+        match i {
+            0 => &mut self.r,
+            1 => &mut self.g,
+            2 => &mut self.b,
+            3 => &mut self.a,
+            _ => panic!("RGBA index out of range: {}", i),
+        }
+    }
+}
+
+impl Add for RGBA {
+    type Output = Self;
+
+    fn add(self, rhs: RGBA) -> Self::Output {
+        RGBA {
+            r: self.r + rhs.r,
+            g: self.g + rhs.g,
+            b: self.b + rhs.b,
+            a: self.a + rhs.a,
+        }
+    }
+}
+
+impl Sub for RGBA {
+    type Output = Self;
+
+    fn sub(self, rhs: RGBA) -> Self::Output {
+        RGBA {
+            r: self.r - rhs.r,
+            g: self.g - rhs.g,
+            b: self.b - rhs.b,
+            a: self.a - rhs.a,
+        }
+    }
+}
+
+impl Mul<f32> for RGBA {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        RGBA {
+            r: self.r * rhs,
+            g: self.g * rhs,
+            b: self.b * rhs,
+            a: self.a * rhs,
+        }
+    }
+}
+
+impl Mul<RGBA> for f32 {
+    type Output = RGBA;
+
+    fn mul(self, rhs: RGBA) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul for RGBA {
+    type Output = Self;
+
+    fn mul(self, rhs: RGBA) -> Self::Output {
+        RGBA {
+            r: self.r * rhs.r,
+            g: self.g * rhs.g,
+            b: self.b * rhs.b,
+            a: self.a * rhs.a,
+        }
+    }
+}
+
+impl Div<f32> for RGBA {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        RGBA {
+            r: self.r / rhs,
+            g: self.g / rhs,
+            b: self.b / rhs,
+            a: self.a / rhs,
+        }
+    }
+}
+
+impl AddAssign for RGBA {
+    fn add_assign(&mut self, rhs: RGBA) {
+        self.r += rhs.r;
+        self.g += rhs.g;
+        self.b += rhs.b;
+        self.a += rhs.a;
+    }
+}
+
+impl SubAssign for RGBA {
+    fn sub_assign(&mut self, rhs: RGBA) {
+        self.r -= rhs.r;
+        self.g -= rhs.g;
+        self.b -= rhs.b;
+        self.a -= rhs.a;
+    }
+}
+
+impl MulAssign for RGBA {
+    fn mul_assign(&mut self, rhs: RGBA) {
+        self.r *= rhs.r;
+        self.g *= rhs.g;
+        self.b *= rhs.b;
+        self.a *= rhs.a;
+    }
+}
+
+impl MulAssign<f32> for RGBA {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.r *= rhs;
+        self.g *= rhs;
+        self.b *= rhs;
+        self.a *= rhs;
+    }
+}
+
+impl DivAssign<f32> for RGBA {
+    fn div_assign(&mut self, rhs: f32) {
+        self.r /= rhs;
+        self.g /= rhs;
+        self.b /= rhs;
+        self.a /= rhs;
+    }
+}
+
+#[test]
+fn porter_duff() {
+    let src = RGBA::new(0.8, 0.4, 0.2, 0.5).premultiply();
+    let dst = RGBA::new(0.2, 0.6, 1.0, 0.75).premultiply();
+    // Coverage combines the same way for every color.
+    assert!(src.over(&dst).a == 0.875);
+    assert!(src.r#in(&dst).a == 0.375);
+    assert!(src.out(&dst).a == 0.125);
+    assert!(src.atop(&dst).a == 0.75);
+    assert!(src.xor(&dst).a == 0.5);
+    // In and out split the source, and atop is in plus dst out of source.
+    let split = src.r#in(&dst) + src.out(&dst);
+    assert!((split.r - src.r).abs() < 1e-6 && split.a == src.a);
+    let atop = src.r#in(&dst) + dst.out(&src);
+    assert!((atop.g - src.atop(&dst).g).abs() < 1e-6);
+    // Transparent layers leave the other color alone.
+    let clear = RGBA::default();
+    assert!(src.over(&clear) == src && clear.over(&dst) == dst);
+    assert!(clear.unpremultiply() == clear);
+}
+
+#[test]
+fn components() {
+    // Components are kept as given and indexed in r, g, b, a order.
+    let mut c = RGBA::new(1.5, -0.5, 0.25, 0.75);
+    assert!(c[0] == 1.5 && c[1] == -0.5 && c[3] == 0.75);
+    c[2] = 0.5;
+    assert!(
+        c.clamp(
+            &RGBA::new(0.0, 0.0, 0.0, 0.0),
+            &RGBA::new(1.0, 1.0, 0.4, 0.5)
+        ) == RGBA::new(1.0, 0.0, 0.4, 0.5)
+    );
+    assert!(c.saturate() == RGBA::new(1.0, 0.0, 0.5, 0.75));
+    assert!(c.max_component() == 1.5 && c.is_finite());
+    assert!(!RGBA::new(0.0, 0.0, 0.0, f32::NAN).is_finite());
+    let gray = c.clamp01().grayscale();
+    assert!(gray.r == gray.b && gray.a == 0.75);
+    assert!((gray.r - RGB::from(c.clamp01()).luminance()).abs() < 1e-6);
+}
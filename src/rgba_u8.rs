@@ -0,0 +1,130 @@
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A color having unsigned byte red, green, blue, and alpha components, the
+/// layout of 8 bit RGBA images. As with `RGBu8`, the color channels are
+/// normally sRGB encoded and alpha is linear.
+///
+/// Packed u32 colors are read with the first named channel in the most
+/// significant byte, the way they are written as hex literals: `0xff8000ff`
+/// is opaque orange in RGBA8888 and `0xffff8000` is the same color in
+/// ARGB8888. Use `packing::pack_rgba8` instead for the in-memory byte order
+/// of GPU formats.
+#[repr(C)]
+pub struct RGBAu8 {
+    /// The red component.
+    pub r: u8,
+    /// The green component.
+    pub g: u8,
+    /// The blue component.
+    pub b: u8,
+    /// The alpha component.
+    pub a: u8,
+}
+
+impl RGBAu8 {
+    /// Returns a color with the given r, g, b, a components.
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> RGBAu8 {
+        Self { r, g, b, a }
+    }
+
+    /// Returns the color packed as 0xRRGGBBAA.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgba_u8::RGBAu8;
+    /// let c = RGBAu8::from_rgba8888(0xff80_0040);
+    /// assert!(c == RGBAu8::new(255, 128, 0, 64));
+    /// assert!(c.to_rgba8888() == 0xff80_0040);
+    /// ```
+    pub const fn from_rgba8888(packed: u32) -> RGBAu8 {
+        let [r, g, b, a] = packed.to_be_bytes();
+        RGBAu8::new(r, g, b, a)
+    }
+
+    /// Returns the color packed as 0xAARRGGBB, the order of Windows and
+    /// many 2D APIs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgba_u8::RGBAu8;
+    /// let c = RGBAu8::from_argb8888(0x40ff_8000);
+    /// assert!(c == RGBAu8::new(255, 128, 0, 64));
+    /// assert!(c.to_argb8888() == 0x40ff_8000);
+    /// ```
+    pub const fn from_argb8888(packed: u32) -> RGBAu8 {
+        let [a, r, g, b] = packed.to_be_bytes();
+        RGBAu8::new(r, g, b, a)
+    }
+
+    /// Returns this color packed as 0xRRGGBBAA.
+    pub const fn to_rgba8888(&self) -> u32 {
+        u32::from_be_bytes([self.r, self.g, self.b, self.a])
+    }
+
+    /// Returns this color packed as 0xAARRGGBB.
+    pub const fn to_argb8888(&self) -> u32 {
+        u32::from_be_bytes([self.a, self.r, self.g, self.b])
+    }
+
+    /// Returns a raw pointer to the components of this color, which are
+    /// laid out contiguously as r, g, b, a.
+    pub fn as_ptr(&self) -> *const u8 {
+        (self as *const RGBAu8).cast::<u8>()
+    }
+}
+
+impl From<RGBA> for RGBAu8 {
+    /// Scales the components to bytes without changing their space,
+    /// clamping them to [0, 1] and rounding to the nearest byte.
+    fn from(rhs: RGBA) -> Self {
+        let byte = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        RGBAu8::new(byte(rhs.r), byte(rhs.g), byte(rhs.b), byte(rhs.a))
+    }
+}
+
+impl From<RGBAu8> for RGBA {
+    /// Scales the bytes to [0, 1] without changing their space.
+    fn from(rhs: RGBAu8) -> Self {
+        RGBA {
+            r: rhs.r as f32 / 255.0,
+            g: rhs.g as f32 / 255.0,
+            b: rhs.b as f32 / 255.0,
+            a: rhs.a as f32 / 255.0,
+        }
+    }
+}
+
+impl From<RGBu8> for RGBAu8 {
+    /// Returns the color as opaque.
+    fn from(rhs: RGBu8) -> Self {
+        RGBAu8::new(rhs.r, rhs.g, rhs.b, 255)
+    }
+}
+
+impl From<RGBAu8> for RGBu8 {
+    /// Drops the alpha component.
+    fn from(rhs: RGBAu8) -> Self {
+        RGBu8::new(rhs.r, rhs.g, rhs.b)
+    }
+}
+
+#[test]
+fn packed_orders() {
+    let c = RGBAu8::new(0x12, 0x34, 0x56, 0x78);
+    assert!(c.to_rgba8888() == 0x1234_5678 && c.to_argb8888() == 0x7812_3456);
+    assert!(RGBAu8::from_rgba8888(c.to_rgba8888()) == c);
+    assert!(RGBAu8::from_argb8888(c.to_argb8888()) == c);
+    // Every byte survives the trip through floats.
+    for v in 0..=255u8 {
+        let c = RGBAu8::new(v, 255 - v, v / 2, v);
+        assert!(RGBAu8::from(RGBA::from(c)) == c);
+    }
+    let out_of_range = RGBA::new(-0.5, 0.5, 2.0, 1.0);
+    assert!(RGBAu8::from(out_of_range) == RGBAu8::new(0, 128, 255, 255));
+    assert!(RGB::from(0x00ff_8000) == RGB::from_hex(0xff8000));
+    assert!(u32::from(RGB::new(1.0, 0.5, 0.0)) == 0x00ff_8000);
+}
@@ -0,0 +1,192 @@
+use crate::prelude::*;
+use core::ops::{Mul, MulAssign};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A 2D rotation stored as the unit complex number re + im * i, that is
+/// cos(angle) + sin(angle) * i. Composing two rotations multiplies them, and
+/// rotating a vector costs four multiplies, with no matrix needed.
+#[repr(C)]
+pub struct Rotor2 {
+    /// The cosine of the angle.
+    pub re: f32,
+    /// The sine of the angle.
+    pub im: f32,
+}
+
+impl Rotor2 {
+    /// Returns a rotor with the given components, which should have unit
+    /// magnitude.
+    ///
+    /// # Arguments
+    ///
+    /// * `re` - The cosine of the angle.
+    /// * `im` - The sine of the angle.
+    pub const fn new(re: f32, im: f32) -> Rotor2 {
+        Rotor2 { re, im }
+    }
+
+    /// Returns the rotor for no rotation.
+    pub const fn identity() -> Rotor2 {
+        Rotor2 { re: 1.0, im: 0.0 }
+    }
+
+    /// Returns the counterclockwise rotation through the angle a.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle, as `Radians`, `Degrees` or an `f32` in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rotor2::Rotor2;
+    /// use math_engine::vector2::Vector2;
+    /// let r = Rotor2::from_angle(core::f32::consts::FRAC_PI_2);
+    /// let v = r.rotate(&Vector2::new(1.0, 0.0));
+    /// assert!((v - Vector2::new(0.0, 1.0)).magnitude() < 1e-6);
+    /// ```
+    pub fn from_angle(a: impl Into<Radians>) -> Rotor2 {
+        let a = a.into().0;
+        Rotor2 {
+            re: a.cos(),
+            im: a.sin(),
+        }
+    }
+
+    /// Returns the rotation that turns the direction of a into the direction
+    /// of b.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - A nonzero vector2.
+    /// * `b` - A nonzero vector2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rotor2::Rotor2;
+    /// use math_engine::vector2::Vector2;
+    /// let r = Rotor2::from_vectors(&Vector2::new(2.0, 0.0), &Vector2::new(0.0, -3.0));
+    /// assert!((r.angle() + core::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    /// ```
+    pub fn from_vectors(a: &Vector2, b: &Vector2) -> Rotor2 {
+        Rotor2 {
+            re: a.dot(b),
+            im: a.x * b.y - a.y * b.x,
+        }
+        .normalize()
+    }
+
+    /// Returns the angle of this rotation in the range (-π, π].
+    pub fn angle(&self) -> f32 {
+        self.im.atan2(self.re)
+    }
+
+    /// Returns this rotor scaled to unit magnitude, which removes the drift
+    /// that builds up over many compositions.
+    pub fn normalize(&self) -> Rotor2 {
+        let m = (self.re * self.re + self.im * self.im).sqrt();
+        Rotor2 {
+            re: self.re / m,
+            im: self.im / m,
+        }
+    }
+
+    /// Returns the inverse rotation, the complex conjugate.
+    pub fn inverse(&self) -> Rotor2 {
+        Rotor2 {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// Returns the vector v rotated by this rotor.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A reference to a vector2.
+    pub fn rotate(&self, v: &Vector2) -> Vector2 {
+        Vector2::new(self.re * v.x - self.im * v.y, self.im * v.x + self.re * v.y)
+    }
+
+    /// Returns the rotation matrix of this rotor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix2::Matrix2;
+    /// use math_engine::rotor2::Rotor2;
+    /// let m = Rotor2::from_angle(0.4).to_matrix2();
+    /// assert!(m == Matrix2::make_rotation(0.4));
+    /// ```
+    pub fn to_matrix2(&self) -> Matrix2 {
+        Matrix2::new(self.re, -self.im, self.im, self.re)
+    }
+
+    /// Returns the rotation interpolated at constant angular speed between
+    /// this rotor at t = 0 and other at t = 1, along the shorter arc.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The rotor at t = 1.
+    /// * `t` - The interpolation parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rotor2::Rotor2;
+    /// let a = Rotor2::from_angle(3.0);
+    /// let b = Rotor2::from_angle(-3.0);
+    /// let m = a.slerp(&b, 0.5);
+    /// assert!((m.angle().abs() - core::f32::consts::PI).abs() < 1e-5);
+    /// ```
+    pub fn slerp(&self, other: &Rotor2, t: f32) -> Rotor2 {
+        let delta = (self.inverse() * *other).angle();
+        *self * Rotor2::from_angle(delta * t)
+    }
+}
+
+impl Default for Rotor2 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Mul<Rotor2> for Rotor2 {
+    type Output = Rotor2;
+
+    /// Composes two rotations by complex multiplication.
+    fn mul(self, rhs: Rotor2) -> Self::Output {
+        Rotor2 {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+impl MulAssign<Rotor2> for Rotor2 {
+    fn mul_assign(&mut self, rhs: Rotor2) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Vector2> for Rotor2 {
+    type Output = Vector2;
+
+    fn mul(self, rhs: Vector2) -> Self::Output {
+        self.rotate(&rhs)
+    }
+}
+
+#[test]
+fn composition() {
+    let a = Rotor2::from_angle(0.7);
+    let b = Rotor2::from_angle(-1.9);
+    assert!(((a * b).angle() + 1.2).abs() < 1e-6);
+    let v = Vector2::new(2.0, -1.0);
+    assert!(((a * b) * v - a.rotate(&b.rotate(&v))).magnitude() < 1e-6);
+    assert!((a.inverse() * (a * v) - v).magnitude() < 1e-6);
+    let q = a.slerp(&b, 0.25);
+    assert!((q.angle() - (0.7 - 2.6 * 0.25)).abs() < 1e-6);
+}
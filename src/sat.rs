@@ -0,0 +1,261 @@
+//! Overlap tests by the separating axis theorem: two convex shapes are
+//! disjoint exactly when their projections onto some axis are, and for
+//! boxes, triangles and polygons only the face normals and the cross
+//! products of edge pairs need be tried. Unlike the boolean tests on the
+//! shapes themselves, these return the minimum translation vector, the
+//! shortest move of the second shape that separates it from the first,
+//! which is enough for simple collision response without `gjk`.
+//!
+//! Shapes that only touch count as disjoint.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use core::ops::{Mul, Neg};
+
+// The squared length below which a cross product of nearly parallel edges
+// is dropped as an axis.
+const EPSILON: f32 = 1.0e-10;
+
+// Returns the shortest translation along the unit axes that separates the
+// shapes a and b, given their projections onto each axis, or None if some
+// axis separates them.
+fn separate<V>(
+    axes: impl Iterator<Item = V>,
+    project_a: impl Fn(&V) -> (f32, f32),
+    project_b: impl Fn(&V) -> (f32, f32),
+) -> Option<V>
+where
+    V: Copy + Mul<f32, Output = V> + Neg<Output = V>,
+{
+    let mut best: Option<(f32, V)> = None;
+    for axis in axes {
+        let (a_min, a_max) = project_a(&axis);
+        let (b_min, b_max) = project_b(&axis);
+        // Pushing b forward along the axis clears a_max, pushing it back
+        // clears a_min; the shorter push wins.
+        let (forward, back) = (a_max - b_min, b_max - a_min);
+        let (depth, direction) = if forward < back {
+            (forward, axis)
+        } else {
+            (back, -axis)
+        };
+        if depth <= 0.0 {
+            return None;
+        }
+        if best.is_none_or(|b| depth < b.0) {
+            best = Some((depth, direction));
+        }
+    }
+    best.map(|(depth, direction)| direction * depth)
+}
+
+// Returns the interval an oriented box covers along the unit axis n.
+fn project_obb(b: &Obb, n: &Vector3) -> (f32, f32) {
+    let c = (b.center - Point3::new(0.0, 0.0, 0.0)).dot(n);
+    let r = (0..3).fold(0.0, |r, i| {
+        r + b.half_extents[i] * b.rotation[i].dot(n).abs()
+    });
+    (c - r, c + r)
+}
+
+// Returns the interval the points cover along the axis n.
+fn project_points<P, V>(points: &[P], n: &V, dot: impl Fn(&P, &V) -> f32) -> (f32, f32) {
+    points.iter().fold((f32::MAX, f32::MIN), |(lo, hi), p| {
+        let d = dot(p, n);
+        (lo.min(d), hi.max(d))
+    })
+}
+
+fn dot3(p: &Point3, n: &Vector3) -> f32 {
+    p.x * n.x + p.y * n.y + p.z * n.z
+}
+
+// Returns the cross products of each of the edges a with each of the edges
+// b that are long enough to use as axes, normalized.
+fn edge_axes<'a>(a: &'a [Vector3], b: &'a [Vector3]) -> impl Iterator<Item = Vector3> + 'a {
+    a.iter()
+        .flat_map(move |u| b.iter().map(move |v| u.cross(v)))
+        .filter(|n| n.dot(n) > EPSILON)
+        .map(|n| n.normalize())
+}
+
+/// Returns the minimum translation vector that moves the box b out of the
+/// box a, or None if they do not overlap.
+///
+/// # Arguments
+///
+/// * `a` - A reference to the first box.
+/// * `b` - A reference to the box to move.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::matrix3::Matrix3;
+/// use math_engine::obb::Obb;
+/// use math_engine::point3::Point3;
+/// use math_engine::sat;
+/// use math_engine::vector3::Vector3;
+/// let e = Vector3::new(1.0, 1.0, 1.0);
+/// let a = Obb::new(Point3::new(0.0, 0.0, 0.0), e, Matrix3::identity());
+/// let b = Obb::new(Point3::new(1.5, 0.2, 0.0), e, Matrix3::identity());
+/// let mtv = sat::obb_obb(&a, &b).unwrap();
+/// assert!((mtv - Vector3::new(0.5, 0.0, 0.0)).magnitude() < 1e-6);
+/// ```
+pub fn obb_obb(a: &Obb, b: &Obb) -> Option<Vector3> {
+    let ea = [a.rotation[0], a.rotation[1], a.rotation[2]];
+    let eb = [b.rotation[0], b.rotation[1], b.rotation[2]];
+    let axes = ea.into_iter().chain(eb).chain(edge_axes(&ea, &eb));
+    separate(axes, |n| project_obb(a, n), |n| project_obb(b, n))
+}
+
+/// Returns the minimum translation vector that moves the triangle out of
+/// the box a, or None if they do not overlap.
+///
+/// # Arguments
+///
+/// * `a` - A reference to the box.
+/// * `triangle` - A reference to the corners of the triangle to move.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::matrix3::Matrix3;
+/// use math_engine::obb::Obb;
+/// use math_engine::point3::Point3;
+/// use math_engine::sat;
+/// use math_engine::vector3::Vector3;
+/// let a = Obb::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), Matrix3::identity());
+/// let t = [
+///     Point3::new(-2.0, -2.0, 0.75),
+///     Point3::new(2.0, -2.0, 0.75),
+///     Point3::new(0.0, 2.0, 0.75),
+/// ];
+/// let mtv = sat::obb_triangle(&a, &t).unwrap();
+/// assert!((mtv - Vector3::new(0.0, 0.0, 0.25)).magnitude() < 1e-6);
+/// ```
+pub fn obb_triangle(a: &Obb, triangle: &[Point3; 3]) -> Option<Vector3> {
+    let [p, q, r] = *triangle;
+    let ea = [a.rotation[0], a.rotation[1], a.rotation[2]];
+    let et = [q - p, r - q, p - r];
+    let normal = et[0].cross(&et[1]);
+    let axes = ea
+        .into_iter()
+        .chain((normal.dot(&normal) > EPSILON).then(|| normal.normalize()))
+        .chain(edge_axes(&ea, &et));
+    separate(
+        axes,
+        |n| project_obb(a, n),
+        |n| project_points(triangle, n, dot3),
+    )
+}
+
+// Returns the unit normals of the edges of a polygon, skipping repeated
+// corners.
+fn edge_normals(poly: &[Point2]) -> impl Iterator<Item = Vector2> + '_ {
+    (0..poly.len())
+        .map(move |i| (poly[(i + 1) % poly.len()] - poly[i]).perp())
+        .filter(|n| n.dot(n) > EPSILON)
+        .map(|n| n.normalize())
+}
+
+/// Returns the minimum translation vector that moves the convex polygon b
+/// out of the convex polygon a, or None if they do not overlap. The
+/// polygons may wind either way.
+///
+/// # Arguments
+///
+/// * `a` - The corners of the first polygon, in order around it.
+/// * `b` - The corners of the polygon to move, in order around it.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point2::Point2;
+/// use math_engine::sat;
+/// use math_engine::vector2::Vector2;
+/// let square = [
+///     Point2::new(0.0, 0.0),
+///     Point2::new(2.0, 0.0),
+///     Point2::new(2.0, 2.0),
+///     Point2::new(0.0, 2.0),
+/// ];
+/// let triangle = [Point2::new(1.0, 1.5), Point2::new(3.0, 3.0), Point2::new(1.0, 3.0)];
+/// let mtv = sat::polygon_polygon(&square, &triangle).unwrap();
+/// assert!((mtv - Vector2::new(0.0, 0.5)).magnitude() < 1e-6);
+/// ```
+pub fn polygon_polygon(a: &[Point2], b: &[Point2]) -> Option<Vector2> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let dot2 = |p: &Point2, n: &Vector2| p.x * n.x + p.y * n.y;
+    separate(
+        edge_normals(a).chain(edge_normals(b)),
+        |n| project_points(a, n, dot2),
+        |n| project_points(b, n, dot2),
+    )
+}
+
+#[test]
+fn separating_axes() {
+    // Rotated boxes agree with the boolean test, and the move separates them.
+    let e = Vector3::new(1.0, 1.0, 1.0);
+    let a = Obb::new(Point3::new(0.0, 0.0, 0.0), e, Matrix3::identity());
+    let r = Matrix3::make_rotation(0.6, &Vector3::new(1.0, 2.0, 3.0).to_unit());
+    for x in [1.0, 1.8, 2.2, 2.6, 3.0] {
+        let b = Obb::new(Point3::new(x, 0.3, -0.2), e, r);
+        let mtv = obb_obb(&a, &b);
+        assert!(mtv.is_some() == a.intersects_obb(&b));
+        if let Some(mtv) = mtv {
+            let moved = Obb::new(b.center + mtv * 1.001, e, r);
+            assert!(obb_obb(&a, &moved).is_none());
+            assert!(obb_obb(&a, &Obb::new(b.center + mtv * 0.9, e, r)).is_some());
+        }
+    }
+    // Edge against edge: two boxes turned 45 degrees about crossing axes.
+    let x = Obb::new(
+        Point3::new(0.0, 0.0, 0.0),
+        e,
+        Matrix3::make_rotation_x(core::f32::consts::FRAC_PI_4),
+    );
+    let y = Obb::new(
+        Point3::new(0.0, 2.7, 0.0),
+        e,
+        Matrix3::make_rotation_z(core::f32::consts::FRAC_PI_4),
+    );
+    let mtv = obb_obb(&x, &y).unwrap();
+    assert!(mtv.y > 0.0 && (mtv.magnitude() - (2.0 * 2.0f32.sqrt() - 2.7)).abs() < 1e-4);
+    // A triangle beside the box, and one passing through a corner.
+    let beside = [
+        Point3::new(1.1, 0.0, 0.0),
+        Point3::new(3.0, 0.0, 0.0),
+        Point3::new(1.1, 0.0, 2.0),
+    ];
+    assert!(obb_triangle(&a, &beside).is_none());
+    let corner = [
+        Point3::new(0.8, 0.8, 0.8),
+        Point3::new(3.0, 1.0, 1.0),
+        Point3::new(1.0, 3.0, 1.0),
+    ];
+    let mtv = obb_triangle(&a, &corner).unwrap();
+    let moved = corner.map(|p| p + mtv * 1.001);
+    assert!(obb_triangle(&a, &moved).is_none());
+    // Polygons wound either way give the same answer.
+    let square = [
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 2.0),
+        Point2::new(0.0, 2.0),
+    ];
+    let mut other = [
+        Point2::new(1.7, 0.5),
+        Point2::new(4.0, 0.5),
+        Point2::new(4.0, 1.0),
+    ];
+    let mtv = polygon_polygon(&square, &other).unwrap();
+    assert!((mtv - Vector2::new(0.3, 0.0)).magnitude() < 1e-5);
+    other.reverse();
+    assert!(polygon_polygon(&square, &other) == Some(mtv));
+    assert!(polygon_polygon(&square, &other.map(|p| p + Vector2::new(0.31, 0.0))).is_none());
+}
@@ -0,0 +1,221 @@
+//! Interpolation, easing and comparison helpers for plain `f32` values, the
+//! scalar building blocks the vector and color types are interpolated with.
+
+use crate::angle::Radians;
+use core::f32::consts::PI;
+
+/// Returns the value interpolated linearly between a at t = 0 and b at t = 1.
+/// The result is exactly a at t = 0 and exactly b at t = 1, and t outside
+/// [0, 1] extrapolates.
+///
+/// # Arguments
+///
+/// * `a` - The value at t = 0.
+/// * `b` - The value at t = 1.
+/// * `t` - The interpolation parameter.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::scalar::lerp;
+/// assert!(lerp(2.0, 6.0, 0.25) == 3.0);
+/// assert!(lerp(0.1, 0.7, 1.0) == 0.7);
+/// ```
+pub fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    (1.0 - t) * a + t * b
+}
+
+/// Returns the parameter t for which `lerp(a, b, t)` is v, the inverse of
+/// `lerp`. The result is infinite or NaN when a equals b.
+///
+/// # Arguments
+///
+/// * `a` - The value at t = 0.
+/// * `b` - The value at t = 1.
+/// * `v` - The value to locate.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::scalar::inverse_lerp;
+/// assert!(inverse_lerp(2.0, 6.0, 3.0) == 0.25);
+/// ```
+pub fn inverse_lerp(a: f32, b: f32, v: f32) -> f32 {
+    (v - a) / (b - a)
+}
+
+/// Returns v mapped linearly from the range [in_min, in_max] to the range
+/// [out_min, out_max]. Values outside the input range are extrapolated, not
+/// clamped.
+///
+/// # Arguments
+///
+/// * `v` - The value to map.
+/// * `in_min`, `in_max` - The input range.
+/// * `out_min`, `out_max` - The output range.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::scalar::remap;
+/// assert!(remap(5.0, 0.0, 10.0, 100.0, 200.0) == 150.0);
+/// ```
+pub fn remap(v: f32, in_min: f32, in_max: f32, out_min: f32, out_max: f32) -> f32 {
+    lerp(out_min, out_max, inverse_lerp(in_min, in_max, v))
+}
+
+/// Returns v clamped to the range [0, 1]. NaN is passed through.
+pub fn clamp01(v: f32) -> f32 {
+    v.clamp(0.0, 1.0)
+}
+
+/// Returns the Hermite interpolation 3t² - 2t³ of x between edge0 and edge1,
+/// where t is x mapped to [0, 1] and clamped, matching GLSL's `smoothstep`.
+///
+/// # Arguments
+///
+/// * `edge0` - The value of x at which the result is 0.
+/// * `edge1` - The value of x at which the result is 1.
+/// * `x` - The input value.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::scalar::smoothstep;
+/// assert!(smoothstep(0.0, 2.0, -1.0) == 0.0);
+/// assert!(smoothstep(0.0, 2.0, 1.0) == 0.5);
+/// assert!(smoothstep(0.0, 2.0, 3.0) == 1.0);
+/// ```
+pub fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = clamp01(inverse_lerp(edge0, edge1, x));
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Returns Perlin's 6t⁵ - 15t⁴ + 10t³ of x between edge0 and edge1, where t
+/// is x mapped to [0, 1] and clamped. Unlike `smoothstep`, its second
+/// derivative is also zero at both edges.
+///
+/// # Arguments
+///
+/// * `edge0` - The value of x at which the result is 0.
+/// * `edge1` - The value of x at which the result is 1.
+/// * `x` - The input value.
+pub fn smootherstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = clamp01(inverse_lerp(edge0, edge1, x));
+    t * t * t * (t * (6.0 * t - 15.0) + 10.0)
+}
+
+/// Returns the equivalent of the angle a, in radians, in the range (-π, π].
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::scalar::wrap_angle;
+/// use core::f32::consts::PI;
+/// assert!((wrap_angle(3.0 * PI) - PI).abs() < 1e-5);
+/// ```
+pub fn wrap_angle(a: f32) -> f32 {
+    Radians(a).wrap().0
+}
+
+/// Returns true if a and b differ by at most epsilon, measured absolutely
+/// near zero and relative to the larger magnitude elsewhere, so that one
+/// tolerance works for both small and large values.
+///
+/// # Arguments
+///
+/// * `a` - The first value.
+/// * `b` - The second value.
+/// * `epsilon` - The tolerance.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::scalar::approx_eq;
+/// assert!(approx_eq(0.1 + 0.2, 0.3, 1e-6));
+/// assert!(approx_eq(1.0e6, 1.0e6 + 0.5, 1e-6));
+/// assert!(!approx_eq(1.0, 1.1, 1e-6));
+/// ```
+pub fn approx_eq(a: f32, b: f32, epsilon: f32) -> bool {
+    a == b || (a - b).abs() <= epsilon * a.abs().max(b.abs()).max(1.0)
+}
+
+/// Returns current moved one time step toward target by a critically damped
+/// spring, updating the spring's velocity, as Unity's `Mathf.SmoothDamp`
+/// does. The value arrives in about the smoothing time without passing the
+/// target.
+///
+/// # Arguments
+///
+/// * `current` - The value to move.
+/// * `target` - The value to move toward.
+/// * `velocity` - The velocity of the spring, zero to start from rest and
+///   kept by the caller between steps.
+/// * `smooth_time` - About the time taken to reach the target.
+/// * `max_speed` - The largest speed to move at, `f32::INFINITY` for no
+///   limit.
+/// * `dt` - The length of the time step.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::scalar::smooth_damp;
+/// let (mut zoom, mut velocity) = (1.0, 0.0);
+/// for _ in 0..120 {
+///     zoom = smooth_damp(zoom, 2.0, &mut velocity, 0.3, f32::INFINITY, 1.0 / 60.0);
+/// }
+/// assert!((zoom - 2.0).abs() < 1e-3);
+/// ```
+pub fn smooth_damp(
+    current: f32,
+    target: f32,
+    velocity: &mut f32,
+    smooth_time: f32,
+    max_speed: f32,
+    dt: f32,
+) -> f32 {
+    crate::smooth_damp::damp(
+        current,
+        target,
+        velocity,
+        smooth_time,
+        max_speed,
+        dt,
+        |a, b| a * b,
+    )
+}
+
+/// Returns the angle d in degrees converted to radians.
+pub fn to_radians(d: f32) -> f32 {
+    d * (PI / 180.0)
+}
+
+/// Returns the angle r in radians converted to degrees.
+pub fn to_degrees(r: f32) -> f32 {
+    r * (180.0 / PI)
+}
+
+#[test]
+fn easing() {
+    for i in 0..=10 {
+        let x = i as f32 * 0.1;
+        assert!(approx_eq(
+            smoothstep(0.0, 1.0, x) + smoothstep(0.0, 1.0, 1.0 - x),
+            1.0,
+            1e-6
+        ));
+        assert!(approx_eq(
+            smootherstep(0.0, 1.0, x) + smootherstep(0.0, 1.0, 1.0 - x),
+            1.0,
+            1e-6
+        ));
+        assert!(approx_eq(
+            inverse_lerp(-3.0, 5.0, lerp(-3.0, 5.0, x)),
+            x,
+            1e-6
+        ));
+    }
+    assert!(smootherstep(1.0, 3.0, 2.0) == 0.5);
+    assert!(approx_eq(to_degrees(to_radians(37.0)), 37.0, 1e-6));
+    assert!(approx_eq(wrap_angle(-PI), PI, 1e-6));
+}
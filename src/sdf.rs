@@ -0,0 +1,314 @@
+//! Signed distance functions, for raymarching and procedural modeling. Each
+//! returns the distance from a point to the surface of a shape, negative
+//! inside it, and the combinators build new fields from old ones. Most of
+//! the formulas follow Inigo Quilez's catalogue of distance functions.
+//!
+//! Shapes the crate has a type for take that type. The torus and cone sit at
+//! the origin around the y axis; to place one elsewhere, carry the point
+//! into its frame with the inverse of the placing transform. The results of
+//! the combinators are bounds on the distance rather than exact distances,
+//! which is still enough for sphere tracing.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+
+/// Returns the signed distance from p to the sphere s.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point3::Point3;
+/// use math_engine::sdf;
+/// use math_engine::sphere::Sphere;
+/// let s = Sphere::new(Point3::new(1.0, 0.0, 0.0), 2.0);
+/// assert!(sdf::sphere(&Point3::new(5.0, 0.0, 0.0), &s) == 2.0);
+/// assert!(sdf::sphere(&Point3::new(1.0, 0.0, 0.0), &s) == -2.0);
+/// ```
+pub fn sphere(p: &Point3, s: &Sphere) -> f32 {
+    p.distance(&s.center) - s.radius
+}
+
+/// Returns the signed distance from p to the box b. Inside, it is the
+/// distance to the nearest face.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::aabb3::Aabb3;
+/// use math_engine::point3::Point3;
+/// use math_engine::sdf;
+/// let b = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+/// assert!(sdf::cuboid(&Point3::new(4.0, 5.0, 0.0), &b) == 5.0);
+/// assert!(sdf::cuboid(&Point3::new(0.5, 0.0, 0.0), &b) == -0.5);
+/// ```
+pub fn cuboid(p: &Point3, b: &Aabb3) -> f32 {
+    let q = (*p - b.center()).abs() - b.half_extents();
+    q.max(&Vector3::new(0.0, 0.0, 0.0)).magnitude() + q.max_element().min(0.0)
+}
+
+/// Returns the signed distance from p to the box b with its edges and
+/// corners rounded to the given radius, keeping its outer size.
+///
+/// # Arguments
+///
+/// * `p` - A reference to a point3.
+/// * `b` - The bounds of the box.
+/// * `radius` - The radius of the rounding, at most the smallest half extent.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::aabb3::Aabb3;
+/// use math_engine::point3::Point3;
+/// use math_engine::sdf;
+/// let b = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+/// // The faces stay put but the corners are cut back.
+/// assert!(sdf::rounded_cuboid(&Point3::new(3.0, 0.0, 0.0), &b, 0.5) == 2.0);
+/// let corner = sdf::rounded_cuboid(&Point3::new(1.0, 1.0, 1.0), &b, 0.5);
+/// assert!((corner - (0.75f32.sqrt() - 0.5)).abs() < 1e-6);
+/// ```
+pub fn rounded_cuboid(p: &Point3, b: &Aabb3, radius: f32) -> f32 {
+    let r = Vector3::new(radius, radius, radius);
+    cuboid(p, &Aabb3::new(b.min + r, b.max - r)) - radius
+}
+
+/// Returns the signed distance from p to the capsule c.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::capsule::Capsule;
+/// use math_engine::point3::Point3;
+/// use math_engine::sdf;
+/// let c = Capsule::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 4.0, 0.0), 1.0);
+/// assert!(sdf::capsule(&Point3::new(3.0, 2.0, 0.0), &c) == 2.0);
+/// assert!(sdf::capsule(&Point3::new(0.0, 6.0, 0.0), &c) == 1.0);
+/// ```
+pub fn capsule(p: &Point3, c: &Capsule) -> f32 {
+    c.segment.distance_to_point(p) - c.radius
+}
+
+/// Returns the signed distance from p to the torus centered at the origin
+/// whose ring lies in the xz plane.
+///
+/// # Arguments
+///
+/// * `p` - A reference to a point3.
+/// * `major` - The radius of the ring, from the origin to the center of the
+///   tube.
+/// * `minor` - The radius of the tube.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point3::Point3;
+/// use math_engine::sdf;
+/// assert!(sdf::torus(&Point3::new(0.0, 0.0, 0.0), 3.0, 1.0) == 2.0);
+/// assert!(sdf::torus(&Point3::new(3.0, 0.0, 0.0), 3.0, 1.0) == -1.0);
+/// ```
+pub fn torus(p: &Point3, major: f32, minor: f32) -> f32 {
+    let ring = Vector2::new(p.x, p.z).magnitude() - major;
+    Vector2::new(ring, p.y).magnitude() - minor
+}
+
+/// Returns the signed distance from p to the solid cone whose base is the
+/// disk of the given radius centered at the origin in the xz plane, and
+/// whose apex is at the given height up the y axis.
+///
+/// # Arguments
+///
+/// * `p` - A reference to a point3.
+/// * `radius` - The radius of the base.
+/// * `height` - The height of the apex above the base.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point3::Point3;
+/// use math_engine::sdf;
+/// assert!(sdf::cone(&Point3::new(0.0, 5.0, 0.0), 1.0, 2.0) == 3.0);
+/// assert!(sdf::cone(&Point3::new(0.0, -1.0, 0.0), 1.0, 2.0) == 1.0);
+/// assert!(sdf::cone(&Point3::new(0.0, 0.5, 0.0), 1.0, 2.0) < 0.0);
+/// ```
+pub fn cone(p: &Point3, radius: f32, height: f32) -> f32 {
+    // Work in the half-plane through the axis, with the apex at the origin
+    // and the slanted side running to q.
+    let q = Vector2::new(radius, -height);
+    let w = Vector2::new(Vector2::new(p.x, p.z).magnitude(), p.y - height);
+    let a = w - q * (w.dot(&q) / q.dot(&q)).clamp(0.0, 1.0);
+    let b = w - Vector2::new(q.x * (w.x / q.x).clamp(0.0, 1.0), q.y);
+    let d = a.dot(&a).min(b.dot(&b));
+    // Inside lies below the slant and above the base.
+    let s = (w.x * q.y - w.y * q.x).min(w.y - q.y);
+    if s > 0.0 {
+        -d.sqrt()
+    } else {
+        d.sqrt()
+    }
+}
+
+/// Returns the signed distance from p to the plane f, positive on the side
+/// its normal points into. The normal does not need to have unit length.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::plane::Plane;
+/// use math_engine::point3::Point3;
+/// use math_engine::sdf;
+/// let f = Plane::new(0.0, 2.0, 0.0, -2.0);
+/// assert!(sdf::plane(&Point3::new(7.0, 4.0, 0.0), &f) == 3.0);
+/// ```
+pub fn plane(p: &Point3, f: &Plane) -> f32 {
+    f.distance_to_point(p)
+}
+
+/// Returns the distance to the union of two shapes, given the distances a
+/// and b to each.
+pub fn union(a: f32, b: f32) -> f32 {
+    a.min(b)
+}
+
+/// Returns the distance to the intersection of two shapes, given the
+/// distances a and b to each.
+pub fn intersect(a: f32, b: f32) -> f32 {
+    a.max(b)
+}
+
+/// Returns the distance to the shape at distance a with the shape at
+/// distance b cut out of it.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point3::Point3;
+/// use math_engine::sdf;
+/// use math_engine::sphere::Sphere;
+/// // A ball with a bite out of its side.
+/// let ball = Sphere::new(Point3::new(0.0, 0.0, 0.0), 2.0);
+/// let bite = Sphere::new(Point3::new(2.0, 0.0, 0.0), 1.0);
+/// let d = |p: Point3| sdf::subtract(sdf::sphere(&p, &ball), sdf::sphere(&p, &bite));
+/// assert!(d(Point3::new(-1.0, 0.0, 0.0)) < 0.0);
+/// assert!(d(Point3::new(1.5, 0.0, 0.0)) > 0.0);
+/// ```
+pub fn subtract(a: f32, b: f32) -> f32 {
+    a.max(-b)
+}
+
+/// Returns the smooth minimum of a and b: their minimum, blended into a
+/// rounded fillet where they differ by less than k. It never exceeds the
+/// minimum, which it undercuts by at most k / 4.
+///
+/// # Arguments
+///
+/// * `a` - The first value.
+/// * `b` - The second value.
+/// * `k` - The width of the blend, zero for a sharp minimum.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::sdf::smooth_min;
+/// assert!(smooth_min(1.0, 3.0, 0.5) == 1.0);
+/// assert!(smooth_min(1.0, 1.0, 0.5) == 0.875);
+/// ```
+pub fn smooth_min(a: f32, b: f32, k: f32) -> f32 {
+    if k <= 0.0 {
+        return a.min(b);
+    }
+    let h = (k - (a - b).abs()).max(0.0) / k;
+    a.min(b) - h * h * k * 0.25
+}
+
+/// Returns the distance to the union of two shapes with the seam between
+/// them filleted over the width k.
+pub fn smooth_union(a: f32, b: f32, k: f32) -> f32 {
+    smooth_min(a, b, k)
+}
+
+/// Returns the distance to the intersection of two shapes with the edge
+/// where they meet rounded over the width k.
+pub fn smooth_intersect(a: f32, b: f32, k: f32) -> f32 {
+    -smooth_min(-a, -b, k)
+}
+
+/// Returns the distance to the shape at distance a with the shape at
+/// distance b cut out of it, the rim of the cut rounded over the width k.
+pub fn smooth_subtract(a: f32, b: f32, k: f32) -> f32 {
+    -smooth_min(-a, b, k)
+}
+
+#[test]
+fn distance_fields() {
+    // Each field is zero on its surface and changes no faster than the
+    // point moves, checked along a spiral of directions out from inside.
+    let golden = core::f32::consts::PI * (3.0 - 5.0f32.sqrt());
+    // Each field with a point inside its shape.
+    type Field<'a> = (&'a dyn Fn(&Point3) -> f32, Point3);
+    let fields: [Field; 5] = [
+        (
+            &|p| {
+                cuboid(
+                    p,
+                    &Aabb3::new(Point3::new(-1.0, -2.0, -0.5), Point3::new(1.0, 2.0, 0.5)),
+                )
+            },
+            Point3::new(0.0, 0.0, 0.0),
+        ),
+        (
+            &|p| {
+                rounded_cuboid(
+                    p,
+                    &Aabb3::new(Point3::new(-1.0, -2.0, -0.5), Point3::new(1.0, 2.0, 0.5)),
+                    0.3,
+                )
+            },
+            Point3::new(0.0, 0.0, 0.0),
+        ),
+        (
+            &|p| {
+                capsule(
+                    p,
+                    &Capsule::new(Point3::new(-1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0), 0.5),
+                )
+            },
+            Point3::new(0.0, 0.5, 0.0),
+        ),
+        (&|p| torus(p, 2.0, 0.5), Point3::new(2.0, 0.0, 0.0)),
+        (&|p| cone(p, 1.0, 2.0), Point3::new(0.0, 0.5, 0.0)),
+    ];
+    for (f, inside) in fields {
+        assert!(f(&inside) < 0.0);
+        for i in 0..64 {
+            let z = 1.0 - 2.0 * (i as f32 + 0.5) / 64.0;
+            let (r, phi) = ((1.0 - z * z).sqrt(), golden * i as f32);
+            let v = Vector3::new(r * phi.cos(), r * phi.sin(), z);
+            // March out from inside to the surface, which must be found at
+            // the sign change.
+            let (mut lo, mut hi) = (0.0, 10.0);
+            for _ in 0..40 {
+                let mid = 0.5 * (lo + hi);
+                if f(&(inside + v * mid)) < 0.0 {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            let surface = inside + v * lo;
+            assert!(f(&surface).abs() < 1e-4);
+            // A point is no further from the surface than from the surface
+            // point just found, and the field is 1-Lipschitz.
+            let (p, q) = (surface + v * 0.7, surface + v * 0.2);
+            assert!((f(&p) - f(&q)).abs() <= 0.5 + 1e-4);
+            assert!(f(&p) <= 0.7 + 1e-4);
+        }
+    }
+    // The smooth combinators meet the sharp ones away from the blend.
+    assert!(smooth_union(-1.0, 2.0, 0.5) == union(-1.0, 2.0));
+    assert!(smooth_intersect(-1.0, 2.0, 0.5) == intersect(-1.0, 2.0));
+    assert!(smooth_subtract(-1.0, -2.0, 0.5) == subtract(-1.0, -2.0));
+    assert!((smooth_intersect(1.0, 1.0, 0.4) - 1.1).abs() < 1e-6);
+    assert!(smooth_union(0.0, 0.0, 0.0) == 0.0);
+}
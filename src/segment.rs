@@ -0,0 +1,182 @@
+//! The parts of `Segment2` and `Segment3` that do not depend on the
+//! dimension, generated for both from one definition.
+
+macro_rules! segment {
+    ($name:ident, $p:ident, $doc:literal) => {
+        #[derive(Default, Copy, Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[doc = $doc]
+        #[repr(C)]
+        pub struct $name {
+            /// The start of the segment.
+            pub a: $p,
+            /// The end of the segment.
+            pub b: $p,
+        }
+
+        impl $name {
+            /// Returns a segment between the points a and b.
+            ///
+            /// # Arguments
+            ///
+            /// * `a` - The start of the segment.
+            /// * `b` - The end of the segment.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use math_engine::point3::Point3;
+            /// use math_engine::segment3::Segment3;
+            /// let s = Segment3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0));
+            /// assert!(s.length() == 1.0);
+            /// ```
+            pub const fn new(a: $p, b: $p) -> $name {
+                $name { a, b }
+            }
+
+            /// Returns the length of this segment.
+            pub fn length(&self) -> f32 {
+                (self.b - self.a).magnitude()
+            }
+
+            /// Returns the point on this segment at parameter t, where 0 is a
+            /// and 1 is b.
+            ///
+            /// # Arguments
+            ///
+            /// * `t` - The parameter along the segment.
+            pub fn at(&self, t: f32) -> $p {
+                self.a + (self.b - self.a) * t
+            }
+
+            /// Returns the point on this segment closest to p.
+            ///
+            /// # Arguments
+            ///
+            /// * `p` - A reference to a point.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use math_engine::point3::Point3;
+            /// use math_engine::segment3::Segment3;
+            /// let s = Segment3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0));
+            /// assert!(s.closest_point(&Point3::new(1.0, 3.0, 0.0)) == Point3::new(1.0, 0.0, 0.0));
+            /// assert!(s.closest_point(&Point3::new(-1.0, 3.0, 0.0)) == Point3::new(0.0, 0.0, 0.0));
+            /// ```
+            pub fn closest_point(&self, p: &$p) -> $p {
+                let d = self.b - self.a;
+                let dd = d.dot(&d);
+                if dd <= f32::EPSILON {
+                    return self.a;
+                }
+                self.at(((*p - self.a).dot(&d) / dd).clamp(0.0, 1.0))
+            }
+
+            /// Returns the distance from p to the closest point of this
+            /// segment.
+            ///
+            /// # Arguments
+            ///
+            /// * `p` - A reference to a point.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use math_engine::point3::Point3;
+            /// use math_engine::segment3::Segment3;
+            /// let s = Segment3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0));
+            /// assert!(s.distance_to_point(&Point3::new(1.0, 3.0, 0.0)) == 3.0);
+            /// ```
+            pub fn distance_to_point(&self, p: &$p) -> f32 {
+                (*p - self.closest_point(p)).magnitude()
+            }
+
+            /// Returns the pair of closest points between this segment and
+            /// other, the first lying on this segment and the second on
+            /// other. When the segments cross both points are the crossing
+            /// point, and when they are parallel one of the many closest
+            /// pairs is returned.
+            ///
+            /// # Arguments
+            ///
+            /// * `other` - A reference to a segment.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use math_engine::point3::Point3;
+            /// use math_engine::segment3::Segment3;
+            /// let s = Segment3::new(Point3::new(-1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0));
+            /// let t = Segment3::new(Point3::new(0.0, -1.0, 2.0), Point3::new(0.0, 1.0, 2.0));
+            /// let (p, q) = s.closest_points(&t);
+            /// assert!(p == Point3::new(0.0, 0.0, 0.0) && q == Point3::new(0.0, 0.0, 2.0));
+            /// ```
+            pub fn closest_points(&self, other: &$name) -> ($p, $p) {
+                let d1 = self.b - self.a;
+                let d2 = other.b - other.a;
+                let r = self.a - other.a;
+                let a = d1.dot(&d1);
+                let e = d2.dot(&d2);
+                let f = d2.dot(&r);
+
+                let (s, t);
+                if a <= f32::EPSILON && e <= f32::EPSILON {
+                    s = 0.0;
+                    t = 0.0;
+                } else if a <= f32::EPSILON {
+                    s = 0.0;
+                    t = (f / e).clamp(0.0, 1.0);
+                } else {
+                    let c = d1.dot(&r);
+                    if e <= f32::EPSILON {
+                        t = 0.0;
+                        s = (-c / a).clamp(0.0, 1.0);
+                    } else {
+                        let b = d1.dot(&d2);
+                        let denom = a * e - b * b;
+                        let s0 = if denom != 0.0 {
+                            ((b * f - c * e) / denom).clamp(0.0, 1.0)
+                        } else {
+                            0.0
+                        };
+                        let t0 = (b * s0 + f) / e;
+                        if t0 < 0.0 {
+                            t = 0.0;
+                            s = (-c / a).clamp(0.0, 1.0);
+                        } else if t0 > 1.0 {
+                            t = 1.0;
+                            s = ((b - c) / a).clamp(0.0, 1.0);
+                        } else {
+                            t = t0;
+                            s = s0;
+                        }
+                    }
+                }
+                (self.a + d1 * s, other.a + d2 * t)
+            }
+
+            /// Returns the shortest distance between this segment and other.
+            ///
+            /// # Arguments
+            ///
+            /// * `other` - A reference to a segment.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use math_engine::point3::Point3;
+            /// use math_engine::segment3::Segment3;
+            /// let s = Segment3::new(Point3::new(-1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0));
+            /// let t = Segment3::new(Point3::new(0.0, -1.0, 2.0), Point3::new(0.0, 1.0, 2.0));
+            /// assert!(s.distance(&t) == 2.0);
+            /// ```
+            pub fn distance(&self, other: &$name) -> f32 {
+                let (p, q) = self.closest_points(other);
+                (q - p).magnitude()
+            }
+        }
+    };
+}
+
+pub(crate) use segment;
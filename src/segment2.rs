@@ -0,0 +1,58 @@
+use crate::prelude::*;
+use crate::segment::segment;
+
+segment!(
+    Segment2,
+    Point2,
+    "A line segment in the plane between the end points a and b."
+);
+
+impl Segment2 {
+    /// Returns the point at which this segment meets other, or None if they
+    /// do not meet. When the two overlap along a common line, the point of
+    /// the overlap nearest to a is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a segment2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point2::Point2;
+    /// use math_engine::segment2::Segment2;
+    /// let s = Segment2::new(Point2::new(0.0, 0.0), Point2::new(4.0, 4.0));
+    /// let t = Segment2::new(Point2::new(0.0, 4.0), Point2::new(4.0, 0.0));
+    /// assert!(s.intersect_segment(&t) == Some(Point2::new(2.0, 2.0)));
+    /// let u = Segment2::new(Point2::new(3.0, 0.0), Point2::new(4.0, 0.0));
+    /// assert!(s.intersect_segment(&u).is_none());
+    /// ```
+    pub fn intersect_segment(&self, other: &Segment2) -> Option<Point2> {
+        let d1 = self.b - self.a;
+        let d2 = other.b - other.a;
+        let r = other.a - self.a;
+        let denom = d1.perp_dot(&d2);
+        if denom != 0.0 {
+            let s = r.perp_dot(&d2) / denom;
+            let t = r.perp_dot(&d1) / denom;
+            if (0.0..=1.0).contains(&s) && (0.0..=1.0).contains(&t) {
+                return Some(self.at(s));
+            }
+            return None;
+        }
+        // Parallel: only segments on a common line can meet, along the
+        // overlap of other's span, measured along this segment.
+        let len2 = d1.dot(&d1);
+        if len2 == 0.0 {
+            return (other.distance_to_point(&self.a) == 0.0).then_some(self.a);
+        }
+        if r.perp_dot(&d1) != 0.0 {
+            return None;
+        }
+        let t0 = r.dot(&d1) / len2;
+        let t1 = (other.b - self.a).dot(&d1) / len2;
+        let lo = t0.min(t1).max(0.0);
+        let hi = t0.max(t1).min(1.0);
+        (lo <= hi).then(|| self.at(lo))
+    }
+}
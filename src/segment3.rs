@@ -0,0 +1,8 @@
+use crate::prelude::*;
+use crate::segment::segment;
+
+segment!(
+    Segment3,
+    Point3,
+    "A line segment in 3D space between the end points a and b."
+);
@@ -0,0 +1,308 @@
+//! Serde support for the matrix types, enabled with the `serde` feature.
+//!
+//! Vectors, points, quaternions, planes and colors derive `Serialize` and
+//! `Deserialize` directly and are written as structs of their named
+//! components, e.g. `{"x":1.0,"y":2.0,"z":3.0}`.
+//!
+//! Matrices are written as a flat array of their entries in column-major
+//! order, which is the same order `Matrix4::to_cols_array` returns:
+//!
+//! * `Matrix2` as 4 entries, `Matrix3` as 9 and `Matrix4` as 16.
+//! * `Transform2` as 6 entries, the two rows of each of its three columns
+//!   (the implicit third row `[0 0 1]` is not stored).
+//! * `Transform4` as 12 entries, the three rows of each of its four columns
+//!   (the implicit fourth row `[0 0 0 1]` is not stored).
+//!
+//! The double precision types follow the same layout with `f64` entries.
+//!
+//! `UnitVector3` and `UnitQuaternion` are written as the vector or
+//! quaternion they wrap. Reading one back fails unless its magnitude is
+//! within `UNIT_EPSILON` of 1, so that deserialized data cannot break the
+//! unit length the type guarantees.
+//!
+//! When a more readable form is wanted, a field can opt into a struct of
+//! named column vectors with `#[serde(with = "math_engine::serialization::structured")]`,
+//! which writes e.g. a `Matrix3` as
+//! `{"col0":{"x":1.0,"y":0.0,"z":0.0},"col1":{...},"col2":{...}}`.
+
+use crate::prelude::*;
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A matrix type that can be written in the structured, named column form
+/// used by the `structured` module.
+pub trait StructuredForm: Sized {
+    /// The struct of named columns written in place of the matrix.
+    type Repr: Serialize + DeserializeOwned;
+
+    /// Returns the named column form of this matrix.
+    fn to_repr(&self) -> Self::Repr;
+
+    /// Returns the matrix described by the named column form r.
+    fn from_repr(r: Self::Repr) -> Self;
+}
+
+/// Serializes a matrix as a struct of named column vectors. Use with
+/// `#[serde(with = "math_engine::serialization::structured")]`.
+pub mod structured {
+    use super::StructuredForm;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T: StructuredForm, S: Serializer>(m: &T, s: S) -> Result<S::Ok, S::Error> {
+        m.to_repr().serialize(s)
+    }
+
+    pub fn deserialize<'de, T: StructuredForm, D: Deserializer<'de>>(d: D) -> Result<T, D::Error> {
+        T::Repr::deserialize(d).map(T::from_repr)
+    }
+}
+
+macro_rules! column_major {
+    ($t:ty, $s:ty, $len:expr, $to:expr, $from:expr) => {
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                let to: fn(&$t) -> [$s; $len] = $to;
+                to(self).serialize(s)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                let from: fn([$s; $len]) -> $t = $from;
+                <[$s; $len]>::deserialize(d).map(from)
+            }
+        }
+    };
+}
+
+macro_rules! structured {
+    ($t:ty, $repr:ident, $v:ty, $($col:ident: $i:expr),+) => {
+        #[derive(Serialize, Deserialize)]
+        pub struct $repr {
+            $(pub $col: $v,)+
+        }
+
+        impl StructuredForm for $t {
+            type Repr = $repr;
+
+            fn to_repr(&self) -> $repr {
+                $repr { $($col: self[$i],)+ }
+            }
+
+            fn from_repr(r: $repr) -> $t {
+                let mut m = <$t>::default();
+                $(m[$i] = r.$col;)+
+                m
+            }
+        }
+    };
+}
+
+/// The largest difference from 1 accepted in the magnitude of a unit type
+/// being deserialized.
+pub const UNIT_EPSILON: f32 = 1e-5;
+
+macro_rules! unit {
+    ($t:ident, $inner:ty, $what:literal) => {
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                self.into_inner().serialize(s)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                let v = <$inner>::deserialize(d)?;
+                $t::try_new(v, UNIT_EPSILON)
+                    .ok_or_else(|| D::Error::custom(concat!("expected a unit length ", $what)))
+            }
+        }
+    };
+}
+
+column_major!(Matrix2, f32, 4, |m| [m[0].x, m[0].y, m[1].x, m[1].y], |a| {
+    Matrix2::new_with_vecs(Vector2::new(a[0], a[1]), Vector2::new(a[2], a[3]))
+});
+column_major!(
+    Matrix3,
+    f32,
+    9,
+    |m| [m[0].x, m[0].y, m[0].z, m[1].x, m[1].y, m[1].z, m[2].x, m[2].y, m[2].z],
+    |a| Matrix3::new_with_vecs(
+        Vector3::new(a[0], a[1], a[2]),
+        Vector3::new(a[3], a[4], a[5]),
+        Vector3::new(a[6], a[7], a[8]),
+    )
+);
+column_major!(Matrix4, f32, 16, |m| m.to_cols_array(), |a| {
+    Matrix4::from_cols_array(&a)
+});
+column_major!(
+    Transform2,
+    f32,
+    6,
+    |m| [m[0].x, m[0].y, m[1].x, m[1].y, m[2].x, m[2].y],
+    |a| Transform2::new_with_vecs(
+        Vector2::new(a[0], a[1]),
+        Vector2::new(a[2], a[3]),
+        Point2::new(a[4], a[5]),
+    )
+);
+column_major!(
+    Transform4,
+    f32,
+    12,
+    |m| [
+        m[0].x, m[0].y, m[0].z, m[1].x, m[1].y, m[1].z, m[2].x, m[2].y, m[2].z, m[3].x, m[3].y,
+        m[3].z,
+    ],
+    |a| Transform4::new_with_vecs(
+        Vector3::new(a[0], a[1], a[2]),
+        Vector3::new(a[3], a[4], a[5]),
+        Vector3::new(a[6], a[7], a[8]),
+        Point3::new(a[9], a[10], a[11]),
+    )
+);
+column_major!(
+    DMatrix2,
+    f64,
+    4,
+    |m| [m[0].x, m[0].y, m[1].x, m[1].y],
+    |a| DMatrix2::new_with_vecs(DVector2::new(a[0], a[1]), DVector2::new(a[2], a[3]))
+);
+column_major!(
+    DMatrix3,
+    f64,
+    9,
+    |m| [m[0].x, m[0].y, m[0].z, m[1].x, m[1].y, m[1].z, m[2].x, m[2].y, m[2].z],
+    |a| DMatrix3::new_with_vecs(
+        DVector3::new(a[0], a[1], a[2]),
+        DVector3::new(a[3], a[4], a[5]),
+        DVector3::new(a[6], a[7], a[8]),
+    )
+);
+column_major!(
+    DMatrix4,
+    f64,
+    16,
+    |m| [
+        m[0].x, m[0].y, m[0].z, m[0].w, m[1].x, m[1].y, m[1].z, m[1].w, m[2].x, m[2].y, m[2].z,
+        m[2].w, m[3].x, m[3].y, m[3].z, m[3].w,
+    ],
+    |a| DMatrix4::new_with_vecs(
+        DVector4::new(a[0], a[1], a[2], a[3]),
+        DVector4::new(a[4], a[5], a[6], a[7]),
+        DVector4::new(a[8], a[9], a[10], a[11]),
+        DVector4::new(a[12], a[13], a[14], a[15]),
+    )
+);
+column_major!(
+    DTransform4,
+    f64,
+    12,
+    |m| [
+        m[0].x, m[0].y, m[0].z, m[1].x, m[1].y, m[1].z, m[2].x, m[2].y, m[2].z, m[3].x, m[3].y,
+        m[3].z,
+    ],
+    |a| DTransform4::new_with_vecs(
+        DVector3::new(a[0], a[1], a[2]),
+        DVector3::new(a[3], a[4], a[5]),
+        DVector3::new(a[6], a[7], a[8]),
+        DPoint3::new(a[9], a[10], a[11]),
+    )
+);
+
+unit!(UnitVector3, Vector3, "vector");
+unit!(UnitQuaternion, Quarternion, "quaternion");
+
+structured!(Matrix2, Matrix2Columns, Vector2, col0: 0, col1: 1);
+structured!(Matrix3, Matrix3Columns, Vector3, col0: 0, col1: 1, col2: 2);
+structured!(Matrix4, Matrix4Columns, Vector4, col0: 0, col1: 1, col2: 2, col3: 3);
+structured!(Transform2, Transform2Columns, Vector2, col0: 0, col1: 1, col2: 2);
+structured!(Transform4, Transform4Columns, Vector3, col0: 0, col1: 1, col2: 2, col3: 3);
+structured!(DMatrix2, DMatrix2Columns, DVector2, col0: 0, col1: 1);
+structured!(DMatrix3, DMatrix3Columns, DVector3, col0: 0, col1: 1, col2: 2);
+structured!(DMatrix4, DMatrix4Columns, DVector4, col0: 0, col1: 1, col2: 2, col3: 3);
+structured!(DTransform4, DTransform4Columns, DVector3, col0: 0, col1: 1, col2: 2, col3: 3);
+
+#[test]
+fn round_trip() {
+    let h = Transform4::make_rotation_x(0.5)
+        * Transform4::make_translation(&Vector3::new(1.0, 2.0, 3.0));
+    let json = serde_json::to_string(&h).unwrap();
+    let back: Transform4 = serde_json::from_str(&json).unwrap();
+    for i in 0..4 {
+        assert!(back[i] == h[i]);
+    }
+
+    let m = Matrix4::identity();
+    let json = serde_json::to_string(&m).unwrap();
+    assert!(json == "[1.0,0.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,0.0,1.0]");
+
+    #[derive(Serialize, Deserialize)]
+    struct Node {
+        #[serde(with = "structured")]
+        basis: Matrix2,
+        color: RGB,
+    }
+    let node = Node {
+        basis: Matrix2::identity(),
+        color: RGB::new(1.0, 0.5, 0.0),
+    };
+    let json = serde_json::to_string(&node).unwrap();
+    assert!(
+        json == r#"{"basis":{"col0":{"x":1.0,"y":0.0},"col1":{"x":0.0,"y":1.0}},"color":{"r":1.0,"g":0.5,"b":0.0}}"#
+    );
+    let back: Node = serde_json::from_str(&json).unwrap();
+    assert!(back.basis == node.basis && back.color == node.color);
+
+    // Unit vectors and quaternions keep their format, but only unit ones
+    // read back.
+    let n = UnitVector3::new_normalize(Vector3::new(0.0, 3.0, 4.0));
+    let json = serde_json::to_string(&n).unwrap();
+    assert!(json == serde_json::to_string(&n.into_inner()).unwrap());
+    assert!(serde_json::from_str::<UnitVector3>(&json).unwrap() == n);
+    assert!(serde_json::from_str::<UnitVector3>(r#"{"x":0.0,"y":3.0,"z":4.0}"#).is_err());
+    let q = UnitQuaternion::new_normalize(Quarternion::new(1.0, 2.0, -2.0, 4.0));
+    let json = serde_json::to_string(&q).unwrap();
+    assert!(json == serde_json::to_string(&q.into_inner()).unwrap());
+    assert!(serde_json::from_str::<UnitQuaternion>(&json).unwrap() == q);
+    let long = r#"{"x":1.0,"y":2.0,"z":-2.0,"w":4.0}"#;
+    assert!(serde_json::from_str::<UnitQuaternion>(long).is_err());
+
+    // Decompositions are structs of their factors.
+    let svd = Matrix3::new(2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0).svd();
+    let json = serde_json::to_string(&svd).unwrap();
+    assert!(serde_json::from_str::<Svd3>(&json).unwrap() == svd);
+
+    // Shapes built on vectors of points.
+    let path = Polyline2::new(alloc::vec![Point2::new(0.0, 0.0), Point2::new(3.0, 4.0)]);
+    let json = serde_json::to_string(&path).unwrap();
+    assert!(serde_json::from_str::<Polyline2>(&json).unwrap() == path);
+    let corners = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let hull = ConvexHull::new(&corners).unwrap();
+    let json = serde_json::to_string(&hull).unwrap();
+    assert!(serde_json::from_str::<ConvexHull>(&json).unwrap() == hull);
+    let square = Polygon2::new(alloc::vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ]);
+    let json = serde_json::to_string(&square).unwrap();
+    assert!(serde_json::from_str::<Polygon2>(&json).unwrap() == square);
+    let slab = ConvexVolume::new(alloc::vec![
+        Plane::new(0.0, 0.0, 1.0, 1.0),
+        Plane::new(0.0, 0.0, -1.0, 1.0),
+    ]);
+    let json = serde_json::to_string(&slab).unwrap();
+    assert!(serde_json::from_str::<ConvexVolume>(&json).unwrap() == slab);
+    let batch = Vector3xN::from(&[Vector3::new(1.0, 2.0, 3.0), Vector3::new(-4.0, 0.5, 6.0)][..]);
+    let json = serde_json::to_string(&batch).unwrap();
+    assert!(serde_json::from_str::<Vector3xN>(&json).unwrap() == batch);
+}
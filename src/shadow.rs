@@ -0,0 +1,171 @@
+//! Shadow projection: flattening geometry onto a plane for planar projected
+//! shadows, and fitting the light's orthographic projection tightly around
+//! a slice of the view frustum for cascaded shadow maps.
+
+use crate::plane::three_planes_intersect;
+use crate::prelude::*;
+
+impl Matrix4 {
+    /// Returns the matrix projecting points onto the plane f along the lines
+    /// through the light, to draw geometry flattened into its shadow. The
+    /// light is a homogeneous point: w = 1 for a point light at (x, y, z),
+    /// and w = 0 for a directional light shining along -(x, y, z). The
+    /// results have a w other than 1, so divide by it, and the light must not
+    /// lie on the plane.
+    ///
+    /// # Arguments
+    ///
+    /// * `light` - The homogeneous position of the light.
+    /// * `f` - The plane receiving the shadow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix4::Matrix4;
+    /// use math_engine::plane::Plane;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::vector4::Vector4;
+    /// // A light overhead at height 4 casts the point at height 2 onto the
+    /// // ground twice as far out.
+    /// let m = Matrix4::shadow_onto_plane(&Vector4::new(0.0, 4.0, 0.0, 1.0), &Plane::new(0.0, 1.0, 0.0, 0.0));
+    /// let v = m * Point3::new(1.0, 2.0, 0.0);
+    /// assert!(Point3::new(v.x / v.w, v.y / v.w, v.z / v.w) == Point3::new(2.0, 0.0, 0.0));
+    /// ```
+    pub fn shadow_onto_plane(light: &Vector4, f: &Plane) -> Matrix4 {
+        let p = Vector4::new(f.x, f.y, f.z, f.w);
+        let d = p.dot(light);
+        let mut m = Matrix4::identity() * d;
+        for c in 0..4 {
+            m[c] -= *light * p[c];
+        }
+        m
+    }
+}
+
+/// Returns the view-projection matrix of a directional light shining along
+/// direction, cropped to the smallest orthographic box around the frustum
+/// slice that its shadow map has to cover, or None if the slice has no far
+/// plane. The box is aligned with the light's view, and its near plane is
+/// pulled back toward the light by caster_distance so that objects outside
+/// the slice can still cast shadows into it.
+///
+/// # Arguments
+///
+/// * `slice` - The part of the view frustum covered by one cascade.
+/// * `direction` - The direction the light shines in.
+/// * `caster_distance` - How far toward the light to extend the box.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::frustum::Frustum;
+/// use math_engine::matrix4::Matrix4;
+/// use math_engine::point3::Point3;
+/// use math_engine::shadow::cascade_crop;
+/// use math_engine::vector3::Vector3;
+/// // The first cascade covers the view out to 10 units.
+/// let slice = Frustum::from_matrix(&Matrix4::perspective_rh(1.0, 1.5, 0.1, 10.0));
+/// let m = cascade_crop(&slice, &Vector3::new(1.0, -1.0, 0.0), 20.0).unwrap();
+/// let v = m * Point3::new(0.0, 0.0, -5.0);
+/// assert!(v.x.abs() < 1.0 && v.y.abs() < 1.0 && v.z > 0.0 && v.z < 1.0);
+/// ```
+pub fn cascade_crop(slice: &Frustum, direction: &Vector3, caster_distance: f32) -> Option<Matrix4> {
+    let corners = frustum_corners(slice)?;
+    let center = corners
+        .iter()
+        .fold(Vector3::new(0.0, 0.0, 0.0), |s, p| s + Vector3::from(*p))
+        / 8.0;
+    let center = Point3::from(center);
+    // Any up direction will do for an orthographic view, as long as it is
+    // not parallel to the light.
+    let d = direction.normalize();
+    let up = if d.y.abs() < 0.99 {
+        Vector3::new(0.0, 1.0, 0.0)
+    } else {
+        Vector3::new(1.0, 0.0, 0.0)
+    };
+    let view = Matrix4::from(Transform4::look_at_inverse(&center, &(center + d), &up));
+    let mut min = Vector3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY);
+    let mut max = -min;
+    for p in &corners {
+        let v = view * *p;
+        let v = Vector3::new(v.x, v.y, v.z);
+        min = min.min(&v);
+        max = max.max(&v);
+    }
+    // The light looks down its negative z axis, so depths are -z.
+    let crop =
+        Matrix4::orthographic_rh(min.x, max.x, min.y, max.y, -max.z - caster_distance, -min.z);
+    Some(crop * view)
+}
+
+// Returns the eight corners of the frustum f, or None if it is unbounded
+// or degenerate.
+fn frustum_corners(f: &Frustum) -> Option<[Point3; 8]> {
+    let mut corners = [Point3::default(); 8];
+    for (i, corner) in corners.iter_mut().enumerate() {
+        *corner = three_planes_intersect(
+            &f.planes[i & 1],
+            &f.planes[2 + (i >> 1 & 1)],
+            &f.planes[4 + (i >> 2 & 1)],
+        )?;
+    }
+    Some(corners)
+}
+
+#[test]
+fn shadows() {
+    // A point light and a sun both drop points onto a tilted ground along
+    // the line to the light.
+    let ground = Plane::from_point_normal(
+        &Point3::new(0.0, -1.0, 0.0),
+        &Vector3::new(0.2, 1.0, -0.1).to_unit(),
+    );
+    let p = Point3::new(0.5, 1.0, 2.0);
+    for light in [
+        Vector4::new(1.0, 6.0, 3.0, 1.0),
+        Vector4::new(1.0, 2.0, 0.5, 0.0),
+    ] {
+        let v = Matrix4::shadow_onto_plane(&light, &ground) * p;
+        let s = Point3::new(v.x / v.w, v.y / v.w, v.z / v.w);
+        assert!(ground.point_dot(&s).abs() < 1e-5);
+        let to_light = if light.w == 0.0 {
+            Vector3::new(light.x, light.y, light.z)
+        } else {
+            Point3::new(light.x, light.y, light.z) - s
+        };
+        assert!((p - s).cross(&to_light).magnitude() < 1e-4);
+    }
+
+    // The crop holds the slice's corners inside the clip volume and touches
+    // its sides.
+    let view = Matrix4::look_at_rh(
+        &Point3::new(3.0, 2.0, 1.0),
+        &Point3::new(0.0, 0.0, -4.0),
+        &Vector3::new(0.0, 1.0, 0.0),
+    );
+    let slice = Frustum::from_matrix(&(Matrix4::perspective_rh(1.0, 1.5, 2.0, 12.0) * view));
+    for direction in [Vector3::new(-0.3, -1.0, 0.2), Vector3::new(0.0, -1.0, 0.0)] {
+        let m = cascade_crop(&slice, &direction, 5.0).unwrap();
+        let mut lo = Vector3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY);
+        let mut hi = -lo;
+        for c in frustum_corners(&slice).unwrap() {
+            let v = m * c;
+            let v = Vector3::new(v.x, v.y, v.z) / v.w;
+            lo = lo.min(&v);
+            hi = hi.max(&v);
+        }
+        let slack = 1e-4;
+        assert!((lo - Vector3::new(-1.0, -1.0, lo.z)).magnitude() < slack);
+        assert!((hi - Vector3::new(1.0, 1.0, 1.0)).magnitude() < slack);
+        // The near plane is pulled back toward the light, in front of every
+        // corner.
+        assert!(lo.z > 0.0);
+        // Points further along the light than the slice map beyond it.
+        let v = m * (Point3::new(0.0, 0.0, -4.0) + direction.normalize() * 100.0);
+        assert!(v.z / v.w > 1.0);
+    }
+    // An infinite frustum has no far corners to fit.
+    let open = Frustum::from_matrix(&Matrix4::perspective_infinite_rh(1.0, 1.0, 0.1));
+    assert!(cascade_crop(&open, &Vector3::new(0.0, -1.0, 0.0), 0.0).is_none());
+}
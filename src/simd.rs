@@ -0,0 +1,130 @@
+//! SIMD kernels backing the `simd` feature: SSE on x86_64 and NEON on
+//! aarch64, both part of the baseline of their targets. Other targets fall
+//! back to the scalar column sums.
+//!
+//! Matrices are stored column-major, so every product below is a sum of
+//! matrix columns scaled by a splatted vector component. Both `Vector4` and
+//! `Matrix4` are `#[repr(C, align(16))]` when the feature is enabled, which
+//! lets each column be moved in and out of a register with a single aligned
+//! load or store.
+
+use crate::vector4::Vector4;
+
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn load(v: &Vector4) -> __m128 {
+    // SAFETY: `Vector4` is four contiguous f32 aligned to 16 bytes, and SSE
+    // is part of the x86_64 baseline.
+    unsafe { _mm_load_ps((v as *const Vector4).cast::<f32>()) }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn store(r: __m128) -> Vector4 {
+    let mut v = Vector4::default();
+    // SAFETY: see `load`.
+    unsafe { _mm_store_ps((&mut v as *mut Vector4).cast::<f32>(), r) };
+    v
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn combine(c: &[__m128; 4], v: &Vector4) -> __m128 {
+    // SAFETY: SSE is part of the x86_64 baseline.
+    unsafe {
+        let x = _mm_mul_ps(c[0], _mm_set1_ps(v.x));
+        let y = _mm_mul_ps(c[1], _mm_set1_ps(v.y));
+        let z = _mm_mul_ps(c[2], _mm_set1_ps(v.z));
+        let w = _mm_mul_ps(c[3], _mm_set1_ps(v.w));
+        _mm_add_ps(_mm_add_ps(x, y), _mm_add_ps(z, w))
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+fn load(v: &Vector4) -> float32x4_t {
+    // SAFETY: `Vector4` is four contiguous f32, and NEON is part of the
+    // aarch64 baseline.
+    unsafe { vld1q_f32((v as *const Vector4).cast::<f32>()) }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+fn store(r: float32x4_t) -> Vector4 {
+    let mut v = Vector4::default();
+    // SAFETY: see `load`.
+    unsafe { vst1q_f32((&mut v as *mut Vector4).cast::<f32>(), r) };
+    v
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+fn combine(c: &[float32x4_t; 4], v: &Vector4) -> float32x4_t {
+    // SAFETY: NEON is part of the aarch64 baseline. The products are summed
+    // in the same order as the SSE kernel, without fusing, so both targets
+    // round alike.
+    unsafe {
+        let x = vmulq_n_f32(c[0], v.x);
+        let y = vmulq_n_f32(c[1], v.y);
+        let z = vmulq_n_f32(c[2], v.z);
+        let w = vmulq_n_f32(c[3], v.w);
+        vaddq_f32(vaddq_f32(x, y), vaddq_f32(z, w))
+    }
+}
+
+/// Returns the columns of the product of the matrices with columns `a` and `b`.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+pub(crate) fn mul_cols(a: &[Vector4; 4], b: &[Vector4; 4]) -> [Vector4; 4] {
+    let c = [load(&a[0]), load(&a[1]), load(&a[2]), load(&a[3])];
+    [
+        store(combine(&c, &b[0])),
+        store(combine(&c, &b[1])),
+        store(combine(&c, &b[2])),
+        store(combine(&c, &b[3])),
+    ]
+}
+
+/// Returns the product of the matrix with columns `a` and the vector `v`.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+pub(crate) fn mul_vec(a: &[Vector4; 4], v: &Vector4) -> Vector4 {
+    let c = [load(&a[0]), load(&a[1]), load(&a[2]), load(&a[3])];
+    store(combine(&c, v))
+}
+
+/// Transforms every vector in `vs` in place by the matrix with columns `a`,
+/// keeping the columns resident in registers for the whole batch.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+pub(crate) fn mul_vec_slice(a: &[Vector4; 4], vs: &mut [Vector4]) {
+    let c = [load(&a[0]), load(&a[1]), load(&a[2]), load(&a[3])];
+    for v in vs.iter_mut() {
+        *v = store(combine(&c, v));
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+pub(crate) fn mul_cols(a: &[Vector4; 4], b: &[Vector4; 4]) -> [Vector4; 4] {
+    [
+        mul_vec(a, &b[0]),
+        mul_vec(a, &b[1]),
+        mul_vec(a, &b[2]),
+        mul_vec(a, &b[3]),
+    ]
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+pub(crate) fn mul_vec(a: &[Vector4; 4], v: &Vector4) -> Vector4 {
+    a[0] * v.x + a[1] * v.y + a[2] * v.z + a[3] * v.w
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+pub(crate) fn mul_vec_slice(a: &[Vector4; 4], vs: &mut [Vector4]) {
+    for v in vs.iter_mut() {
+        *v = mul_vec(a, v);
+    }
+}
@@ -0,0 +1,207 @@
+//! Critically damped spring smoothing, the `SmoothDamp` of Unity, for
+//! following a moving target with cameras and UI without hand-tuned lerps.
+//! Each call advances a value toward its target by one time step, carrying
+//! the spring's velocity between calls in a variable the caller owns. The
+//! value arrives in about the smoothing time without overshooting.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use core::ops::{Add, Mul, Sub};
+
+// Advances current toward target by dt with a critically damped spring of
+// the given smoothing time, updating velocity. The exponential decay is the
+// Padé-like approximation Game Programming Gems 4 gives, and the change is
+// limited to what max_speed allows over the smoothing time.
+pub(crate) fn damp<T>(
+    current: T,
+    target: T,
+    velocity: &mut T,
+    smooth_time: f32,
+    max_speed: f32,
+    dt: f32,
+    dot: impl Fn(T, T) -> f32,
+) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>,
+{
+    let smooth_time = smooth_time.max(1e-4);
+    let omega = 2.0 / smooth_time;
+    let x = omega * dt;
+    let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+    let mut change = current - target;
+    let max_change = max_speed * smooth_time;
+    let length = dot(change, change).sqrt();
+    if length > max_change {
+        change = change * (max_change / length);
+    }
+    let temp = (*velocity + change * omega) * dt;
+    *velocity = (*velocity - temp * omega) * decay;
+    let output = current - change + (change + temp) * decay;
+    // Stop at the target rather than pass it.
+    if dot(target - current, output - target) > 0.0 {
+        *velocity = *velocity * 0.0;
+        return target;
+    }
+    output
+}
+
+impl Vector2 {
+    /// Returns this vector moved one time step toward target by a
+    /// critically damped spring, updating the spring's velocity.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - A reference to the vector to move toward.
+    /// * `velocity` - The velocity of the spring, zero to start from rest
+    ///   and kept by the caller between steps.
+    /// * `smooth_time` - About the time taken to reach the target.
+    /// * `max_speed` - The largest speed to move at, `f32::INFINITY` for no
+    ///   limit.
+    /// * `dt` - The length of the time step.
+    pub fn smooth_damp(
+        &self,
+        target: &Vector2,
+        velocity: &mut Vector2,
+        smooth_time: f32,
+        max_speed: f32,
+        dt: f32,
+    ) -> Vector2 {
+        damp(
+            *self,
+            *target,
+            velocity,
+            smooth_time,
+            max_speed,
+            dt,
+            |a, b| a.dot(&b),
+        )
+    }
+}
+
+impl Vector3 {
+    /// Returns this vector moved one time step toward target by a
+    /// critically damped spring, updating the spring's velocity.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - A reference to the vector to move toward.
+    /// * `velocity` - The velocity of the spring, zero to start from rest
+    ///   and kept by the caller between steps.
+    /// * `smooth_time` - About the time taken to reach the target.
+    /// * `max_speed` - The largest speed to move at, `f32::INFINITY` for no
+    ///   limit.
+    /// * `dt` - The length of the time step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::vector3::Vector3;
+    /// let target = Vector3::new(10.0, 0.0, 0.0);
+    /// let (mut position, mut velocity) = (Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 0.0));
+    /// for _ in 0..60 {
+    ///     position = position.smooth_damp(&target, &mut velocity, 0.25, f32::INFINITY, 1.0 / 60.0);
+    ///     assert!(position.x <= target.x);
+    /// }
+    /// assert!((position - target).magnitude() < 0.1);
+    /// ```
+    pub fn smooth_damp(
+        &self,
+        target: &Vector3,
+        velocity: &mut Vector3,
+        smooth_time: f32,
+        max_speed: f32,
+        dt: f32,
+    ) -> Vector3 {
+        damp(
+            *self,
+            *target,
+            velocity,
+            smooth_time,
+            max_speed,
+            dt,
+            |a, b| a.dot(&b),
+        )
+    }
+}
+
+impl Quarternion {
+    /// Returns this rotation moved one time step toward target by a
+    /// critically damped spring on the quaternion's components, updating the
+    /// spring's velocity. The target is flipped into the hemisphere of this
+    /// rotation so the spring takes the short way round, and the result is
+    /// normalized. Both rotations should be unit quaternions.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - A reference to the rotation to move toward.
+    /// * `velocity` - The rate of change of the components, zero to start
+    ///   from rest and kept by the caller between steps.
+    /// * `smooth_time` - About the time taken to reach the target.
+    /// * `max_speed` - The largest rate of change of the components,
+    ///   `f32::INFINITY` for no limit.
+    /// * `dt` - The length of the time step.
+    pub fn smooth_damp(
+        &self,
+        target: &Quarternion,
+        velocity: &mut Quarternion,
+        smooth_time: f32,
+        max_speed: f32,
+        dt: f32,
+    ) -> Quarternion {
+        let target = if self.dot(target) < 0.0 {
+            -*target
+        } else {
+            *target
+        };
+        damp(
+            *self,
+            target,
+            velocity,
+            smooth_time,
+            max_speed,
+            dt,
+            |a, b| a.dot(&b),
+        )
+        .normalize()
+    }
+}
+
+#[test]
+fn smooth_damping() {
+    use crate::scalar::smooth_damp;
+    // The spring approaches from one side without overshooting, and a
+    // smaller smoothing time gets closer sooner.
+    let step = |smooth_time: f32| {
+        let (mut x, mut v) = (0.0, 0.0);
+        for _ in 0..30 {
+            let next = smooth_damp(x, 1.0, &mut v, smooth_time, f32::INFINITY, 1.0 / 60.0);
+            assert!(next >= x && next <= 1.0);
+            x = next;
+        }
+        x
+    };
+    assert!(step(0.1) > step(0.3));
+    assert!(step(0.1) > 0.99);
+    // The speed limit holds.
+    let (mut x, mut v) = (0.0, 0.0);
+    for _ in 0..10 {
+        let next = smooth_damp(x, 100.0, &mut v, 0.1, 5.0, 0.1);
+        assert!(next - x <= 0.5 + 1e-5);
+        x = next;
+    }
+    // Already at the target stays there at rest.
+    let mut v = Vector2::new(0.0, 0.0);
+    let p = Vector2::new(1.0, 2.0).smooth_damp(&Vector2::new(1.0, 2.0), &mut v, 0.2, 10.0, 0.1);
+    assert!(p == Vector2::new(1.0, 2.0) && v == Vector2::new(0.0, 0.0));
+    // Rotations take the short way to a target given in the far hemisphere.
+    let a = Quarternion::from_rotation_z(0.0);
+    let b = -Quarternion::from_rotation_z(1.0);
+    let (mut q, mut w) = (a, Quarternion::new(0.0, 0.0, 0.0, 0.0));
+    for _ in 0..120 {
+        q = q.smooth_damp(&b, &mut w, 0.2, f32::INFINITY, 1.0 / 60.0);
+        assert!((q.magnitude() - 1.0).abs() < 1e-5);
+    }
+    assert!(q.dot(&b).abs() > 0.9999);
+}
@@ -0,0 +1,324 @@
+//! Structure-of-arrays batches of vectors, for particle systems and other
+//! code that applies the same operation to many vectors at once. Each
+//! component is stored in its own contiguous array, so the loops over a
+//! batch read and write whole lanes of floats and compile to packed SIMD
+//! arithmetic, which the interleaved layout of a `Vec<Vector3>` prevents.
+//!
+//! Operations between two batches pair their vectors by index and panic if
+//! the batches differ in length.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{AddAssign, MulAssign, SubAssign};
+
+macro_rules! soa {
+    ($name:ident, $v:ident, $($c:ident),+) => {
+        #[derive(Clone, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        /// A batch of vectors stored as one array per component.
+        pub struct $name {
+            $($c: Vec<f32>,)+
+        }
+
+        impl $name {
+            /// Returns an empty batch.
+            pub fn new() -> $name {
+                $name::default()
+            }
+
+            /// Returns a batch of n zero vectors.
+            pub fn zeros(n: usize) -> $name {
+                $name {
+                    $($c: vec![0.0; n],)+
+                }
+            }
+
+            /// Returns the number of vectors in the batch.
+            pub fn len(&self) -> usize {
+                soa!(@first self, $($c),+).len()
+            }
+
+            /// Returns true if the batch holds no vectors.
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Appends the vector v to the batch.
+            pub fn push(&mut self, v: $v) {
+                $(self.$c.push(v.$c);)+
+            }
+
+            /// Returns the vector at index i.
+            ///
+            /// # Panics
+            ///
+            /// Panics if i is out of bounds.
+            pub fn get(&self, i: usize) -> $v {
+                $v::new($(self.$c[i]),+)
+            }
+
+            /// Replaces the vector at index i with v.
+            ///
+            /// # Panics
+            ///
+            /// Panics if i is out of bounds.
+            pub fn set(&mut self, i: usize, v: $v) {
+                $(self.$c[i] = v.$c;)+
+            }
+
+            /// Returns the vectors of the batch in the interleaved layout.
+            pub fn to_vec(&self) -> Vec<$v> {
+                (0..self.len()).map(|i| self.get(i)).collect()
+            }
+
+            /// Adds other scaled by s to this batch, the update p += v * dt
+            /// that moves particles along their velocities.
+            ///
+            /// # Arguments
+            ///
+            /// * `other` - A batch of the same length.
+            /// * `s` - The scale applied to other.
+            pub fn add_scaled(&mut self, other: &$name, s: f32) {
+                assert_eq!(self.len(), other.len(), "batches differ in length");
+                $(
+                    for (a, b) in self.$c.iter_mut().zip(&other.$c) {
+                        *a += b * s;
+                    }
+                )+
+            }
+
+            /// Returns the dot product of each vector in this batch with the
+            /// vector at the same index in other.
+            ///
+            /// # Arguments
+            ///
+            /// * `other` - A batch of the same length.
+            pub fn dot(&self, other: &$name) -> Vec<f32> {
+                assert_eq!(self.len(), other.len(), "batches differ in length");
+                let mut out = vec![0.0; self.len()];
+                $(
+                    for (o, (a, b)) in out.iter_mut().zip(self.$c.iter().zip(&other.$c)) {
+                        *o += a * b;
+                    }
+                )+
+                out
+            }
+
+            /// Scales every vector to unit length, leaving zero vectors as
+            /// they are, like `normalize_or_zero`.
+            pub fn normalize(&mut self) {
+                let mut scale = self.dot(self);
+                for s in scale.iter_mut() {
+                    *s = if *s > 0.0 { 1.0 / s.sqrt() } else { 0.0 };
+                }
+                $(
+                    for (a, s) in self.$c.iter_mut().zip(&scale) {
+                        *a *= s;
+                    }
+                )+
+            }
+        }
+
+        impl From<&[$v]> for $name {
+            fn from(vs: &[$v]) -> Self {
+                $name {
+                    $($c: vs.iter().map(|v| v.$c).collect(),)+
+                }
+            }
+        }
+
+        impl AddAssign<&$name> for $name {
+            fn add_assign(&mut self, other: &$name) {
+                assert_eq!(self.len(), other.len(), "batches differ in length");
+                $(
+                    for (a, b) in self.$c.iter_mut().zip(&other.$c) {
+                        *a += b;
+                    }
+                )+
+            }
+        }
+
+        impl SubAssign<&$name> for $name {
+            fn sub_assign(&mut self, other: &$name) {
+                assert_eq!(self.len(), other.len(), "batches differ in length");
+                $(
+                    for (a, b) in self.$c.iter_mut().zip(&other.$c) {
+                        *a -= b;
+                    }
+                )+
+            }
+        }
+
+        impl MulAssign<&$name> for $name {
+            /// Multiplies the vectors component-wise.
+            fn mul_assign(&mut self, other: &$name) {
+                assert_eq!(self.len(), other.len(), "batches differ in length");
+                $(
+                    for (a, b) in self.$c.iter_mut().zip(&other.$c) {
+                        *a *= b;
+                    }
+                )+
+            }
+        }
+
+        impl MulAssign<f32> for $name {
+            fn mul_assign(&mut self, s: f32) {
+                $(
+                    for a in self.$c.iter_mut() {
+                        *a *= s;
+                    }
+                )+
+            }
+        }
+    };
+    (@first $s:ident, $c:ident $(, $rest:ident)*) => {
+        $s.$c
+    };
+}
+
+soa!(Vector3xN, Vector3, x, y, z);
+soa!(Vector4xN, Vector4, x, y, z, w);
+
+impl Vector3xN {
+    /// Transforms every vector in the batch as a direction, by the linear
+    /// part of h only, as `h * v` does for a `Vector3`.
+    ///
+    /// # Arguments
+    ///
+    /// * `h` - A reference to a transform4.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::soa::Vector3xN;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// let mut vs = Vector3xN::from(&[Vector3::new(1.0, 2.0, 3.0); 100][..]);
+    /// vs.transform(&Transform4::make_scale_xyz(2.0, 1.0, 1.0));
+    /// assert!(vs.get(99) == Vector3::new(2.0, 2.0, 3.0));
+    /// ```
+    pub fn transform(&mut self, h: &Transform4) {
+        self.apply(h, false);
+    }
+
+    /// Transforms every vector in the batch as the position of a point, by
+    /// the whole of h, as `h * p` does for a `Point3`.
+    ///
+    /// # Arguments
+    ///
+    /// * `h` - A reference to a transform4.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::soa::Vector3xN;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// let mut ps = Vector3xN::zeros(1000);
+    /// ps.transform_points(&Transform4::make_translation(&Vector3::new(0.0, 5.0, 0.0)));
+    /// assert!(ps.get(500) == Vector3::new(0.0, 5.0, 0.0));
+    /// ```
+    pub fn transform_points(&mut self, h: &Transform4) {
+        self.apply(h, true);
+    }
+
+    // Transforms the batch by the columns of h, adding the translation for
+    // points.
+    fn apply(&mut self, h: &Transform4, translate: bool) {
+        let (a, b, c) = (h[0], h[1], h[2]);
+        let t = if translate {
+            h[3]
+        } else {
+            Vector3::new(0.0, 0.0, 0.0)
+        };
+        for ((x, y), z) in self.x.iter_mut().zip(&mut self.y).zip(&mut self.z) {
+            let (vx, vy, vz) = (*x, *y, *z);
+            *x = a.x * vx + b.x * vy + c.x * vz + t.x;
+            *y = a.y * vx + b.y * vy + c.y * vz + t.y;
+            *z = a.z * vx + b.z * vy + c.z * vz + t.z;
+        }
+    }
+}
+
+impl Vector4xN {
+    /// Transforms every vector in the batch by h as homogeneous
+    /// coordinates: the translation is added scaled by w, and w is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `h` - A reference to a transform4.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::soa::Vector4xN;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// use math_engine::vector4::Vector4;
+    /// let mut vs = Vector4xN::from(&[Vector4::new(1.0, 0.0, 0.0, 1.0), Vector4::new(1.0, 0.0, 0.0, 0.0)][..]);
+    /// vs.transform(&Transform4::make_translation(&Vector3::new(0.0, 2.0, 0.0)));
+    /// assert!(vs.get(0) == Vector4::new(1.0, 2.0, 0.0, 1.0));
+    /// assert!(vs.get(1) == Vector4::new(1.0, 0.0, 0.0, 0.0));
+    /// ```
+    pub fn transform(&mut self, h: &Transform4) {
+        let (a, b, c, t) = (h[0], h[1], h[2], h[3]);
+        let xyz = self.x.iter_mut().zip(&mut self.y).zip(&mut self.z);
+        for (((x, y), z), w) in xyz.zip(&self.w) {
+            let (vx, vy, vz) = (*x, *y, *z);
+            *x = a.x * vx + b.x * vy + c.x * vz + t.x * w;
+            *y = a.y * vx + b.y * vy + c.y * vz + t.y * w;
+            *z = a.z * vx + b.z * vy + c.z * vz + t.z * w;
+        }
+    }
+}
+
+#[test]
+fn batches() {
+    // Every batch operation agrees with the same operation on each vector.
+    let vs: Vec<Vector3> = (0..37)
+        .map(|i| {
+            let f = i as f32;
+            Vector3::new(f.sin() * 3.0, f.cos() - 0.5, 0.1 * f - 2.0)
+        })
+        .collect();
+    let us: Vec<Vector3> = vs
+        .iter()
+        .map(|v| v.cross(&Vector3::new(0.3, 1.0, -0.2)))
+        .collect();
+    let (mut a, b) = (Vector3xN::from(&vs[..]), Vector3xN::from(&us[..]));
+    assert!(a.len() == 37 && a.to_vec() == vs);
+
+    let dots = a.dot(&b);
+    for i in 0..vs.len() {
+        assert!((dots[i] - vs[i].dot(&us[i])).abs() < 1e-5);
+    }
+    a.add_scaled(&b, 0.5);
+    a += &b;
+    a -= &b;
+    a *= 2.0;
+    a.transform(&Transform4::make_rotation_z(0.3));
+    let h = Transform4::make_translation(&Vector3::new(1.0, 2.0, 3.0))
+        * Transform4::make_rotation_x(1.1);
+    a.transform_points(&h);
+    a.normalize();
+    for i in 0..vs.len() {
+        let v = Transform4::make_rotation_z(0.3) * ((vs[i] + us[i] * 0.5) * 2.0);
+        let v = Vector3::from(h * Point3::from(v)).normalize();
+        assert!((a.get(i) - v).magnitude() < 1e-5);
+    }
+
+    let mut c = Vector4xN::zeros(3);
+    c.set(1, Vector4::new(0.0, 3.0, 4.0, 1.0));
+    c.push(Vector4::new(2.0, 0.0, 0.0, 0.0));
+    c *= &Vector4xN::from(&[Vector4::new(1.0, 1.0, 1.0, 1.0); 4][..]);
+    c.normalize();
+    assert!(c.get(0) == Vector4::new(0.0, 0.0, 0.0, 0.0));
+    assert!((c.get(1) - Vector4::new(0.0, 3.0, 4.0, 1.0) / 26.0f32.sqrt()).magnitude() < 1e-6);
+    c.transform(&h);
+    assert!(c.get(3) == Vector4::new(1.0, 0.0, 0.0, 0.0));
+    assert!(Vector3xN::new().is_empty());
+}
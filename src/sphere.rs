@@ -0,0 +1,430 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+use alloc::vec::Vec;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A sphere described by its center point and radius, typically used as a
+/// bounding volume.
+#[repr(C)]
+pub struct Sphere {
+    /// The center of the sphere.
+    pub center: Point3,
+    /// The radius of the sphere.
+    pub radius: f32,
+}
+
+impl Sphere {
+    /// Returns a sphere with the given center and radius.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The center of the sphere.
+    /// * `radius` - The radius of the sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// let s = Sphere::new(Point3::new(0.0, 0.0, 0.0), 1.0);
+    /// assert!(s.radius == 1.0);
+    /// ```
+    pub const fn new(center: Point3, radius: f32) -> Sphere {
+        Sphere { center, radius }
+    }
+
+    /// Returns true if the point p lies inside or on this sphere.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// let s = Sphere::new(Point3::new(0.0, 0.0, 0.0), 1.0);
+    /// assert!(s.contains_point(&Point3::new(0.5, 0.5, 0.0)));
+    /// assert!(!s.contains_point(&Point3::new(1.0, 1.0, 0.0)));
+    /// ```
+    pub fn contains_point(&self, p: &Point3) -> bool {
+        let d = *p - self.center;
+        d.dot(&d) <= self.radius * self.radius
+    }
+
+    /// Returns true if the sphere other lies entirely inside this sphere.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// let s = Sphere::new(Point3::new(0.0, 0.0, 0.0), 2.0);
+    /// assert!(s.contains_sphere(&Sphere::new(Point3::new(1.0, 0.0, 0.0), 1.0)));
+    /// assert!(!s.contains_sphere(&Sphere::new(Point3::new(1.5, 0.0, 0.0), 1.0)));
+    /// ```
+    pub fn contains_sphere(&self, other: &Sphere) -> bool {
+        (other.center - self.center).magnitude() + other.radius <= self.radius
+    }
+
+    /// Returns true if this sphere and other overlap or touch.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// let a = Sphere::new(Point3::new(0.0, 0.0, 0.0), 1.0);
+    /// let b = Sphere::new(Point3::new(1.5, 0.0, 0.0), 1.0);
+    /// assert!(a.intersects_sphere(&b));
+    /// ```
+    pub fn intersects_sphere(&self, other: &Sphere) -> bool {
+        let d = other.center - self.center;
+        let r = self.radius + other.radius;
+        d.dot(&d) <= r * r
+    }
+
+    /// Returns true if the plane f passes through this sphere, under the
+    /// assumption that the normal of f has unit length.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A reference to a plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::plane::Plane;
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// let s = Sphere::new(Point3::new(0.0, 0.0, 0.5), 1.0);
+    /// assert!(s.intersects_plane(&Plane::new(0.0, 0.0, 1.0, 0.0)));
+    /// assert!(!s.intersects_plane(&Plane::new(0.0, 0.0, 1.0, -2.0)));
+    /// ```
+    pub fn intersects_plane(&self, f: &Plane) -> bool {
+        f.point_dot(&self.center).abs() <= self.radius
+    }
+
+    /// Returns the parameter t at which the ray starting at p with direction v
+    /// first hits this sphere, or None if it misses. The returned t is 0 when
+    /// p already lies inside the sphere. The direction need not be normalized;
+    /// the hit point is p + v * t.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The origin of the ray.
+    /// * `v` - The direction of the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// use math_engine::vector3::Vector3;
+    /// let s = Sphere::new(Point3::new(0.0, 0.0, 5.0), 1.0);
+    /// let t = s.ray_intersect(&Point3::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 0.0, 1.0));
+    /// assert!(t == Some(4.0));
+    /// ```
+    pub fn ray_intersect(&self, p: &Point3, v: &Vector3) -> Option<f32> {
+        let m = *p - self.center;
+        let a = v.dot(v);
+        let b = m.dot(v);
+        let c = m.dot(&m) - self.radius * self.radius;
+        if c > 0.0 && b > 0.0 {
+            return None;
+        }
+        let disc = b * b - a * c;
+        if disc < 0.0 || a == 0.0 {
+            return None;
+        }
+        let t = (-b - disc.sqrt()) / a;
+        Some(t.max(0.0))
+    }
+
+    /// Returns the smallest sphere enclosing both this sphere and other.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// let a = Sphere::new(Point3::new(-1.0, 0.0, 0.0), 1.0);
+    /// let b = Sphere::new(Point3::new(1.0, 0.0, 0.0), 1.0);
+    /// let s = a.merge(&b);
+    /// assert!(s.center == Point3::new(0.0, 0.0, 0.0) && s.radius == 2.0);
+    /// ```
+    pub fn merge(&self, other: &Sphere) -> Sphere {
+        let d = other.center - self.center;
+        let dist = d.magnitude();
+        if dist + other.radius <= self.radius {
+            return *self;
+        }
+        if dist + self.radius <= other.radius {
+            return *other;
+        }
+        let radius = (dist + self.radius + other.radius) * 0.5;
+        let center = self.center + d * ((radius - self.radius) / dist);
+        Sphere { center, radius }
+    }
+
+    /// Returns this sphere transformed by h. Since a sphere stays a sphere
+    /// only under uniform scale, the radius is scaled by the largest scale
+    /// factor of h so the result still bounds the transformed volume.
+    ///
+    /// # Arguments
+    ///
+    /// * `h` - A reference to a transform4.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// let s = Sphere::new(Point3::new(1.0, 0.0, 0.0), 1.0);
+    /// let h = Transform4::make_translation(&Vector3::new(0.0, 2.0, 0.0))
+    ///     * Transform4::make_scale_xyz(1.0, 3.0, 2.0);
+    /// let t = s.transform(&h);
+    /// assert!(t.center == Point3::new(1.0, 2.0, 0.0) && t.radius == 3.0);
+    /// ```
+    pub fn transform(&self, h: &Transform4) -> Sphere {
+        let sx = h[0].dot(&h[0]);
+        let sy = h[1].dot(&h[1]);
+        let sz = h[2].dot(&h[2]);
+        Sphere {
+            center: *h * self.center,
+            radius: self.radius * sx.max(sy).max(sz).sqrt(),
+        }
+    }
+
+    /// Transforms this sphere by h.
+    ///
+    /// # Arguments
+    ///
+    /// * `h` - A reference to a transform4.
+    pub fn transform_mut(&mut self, h: &Transform4) {
+        *self = self.transform(h);
+    }
+
+    /// Returns the smallest sphere enclosing all the points, by Welzl's
+    /// algorithm in its iterative move-to-front form. The points are visited
+    /// in a fixed shuffled order, which keeps the expected running time
+    /// linear. An empty slice gives a zero sphere at the origin.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points to enclose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// let points = [
+    ///     Point3::new(-1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.0, 0.5, 0.0),
+    ///     Point3::new(0.0, 0.0, -0.9),
+    /// ];
+    /// let s = Sphere::minimal_bounding(&points);
+    /// assert!((s.center - Point3::new(0.0, 0.0, 0.0)).magnitude() < 1e-6);
+    /// assert!((s.radius - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn minimal_bounding(points: &[Point3]) -> Sphere {
+        let mut p: Vec<Point3> = points.to_vec();
+        // A fixed xorshift keeps the result reproducible while breaking up
+        // sorted input, on which the algorithm would be quadratic.
+        let mut state = 0x9e37_79b9_u32;
+        for i in (1..p.len()).rev() {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            p.swap(i, state as usize % (i + 1));
+        }
+        let Some(&first) = p.first() else {
+            return Sphere::default();
+        };
+        let outside = |s: &Sphere, q: &Point3| s.center.distance(q) > s.radius * (1.0 + 1.0e-5);
+        let mut s = Sphere::new(first, 0.0);
+        for i in 1..p.len() {
+            if !outside(&s, &p[i]) {
+                continue;
+            }
+            // p[i] is on the boundary of the sphere of the first i + 1.
+            s = Sphere::new(p[i], 0.0);
+            for j in 0..i {
+                if !outside(&s, &p[j]) {
+                    continue;
+                }
+                s = sphere_2(&p[i], &p[j]);
+                for k in 0..j {
+                    if !outside(&s, &p[k]) {
+                        continue;
+                    }
+                    s = sphere_3(&p[i], &p[j], &p[k]);
+                    for l in 0..k {
+                        if outside(&s, &p[l]) {
+                            s = sphere_4(&p[i], &p[j], &p[k], &p[l]);
+                        }
+                    }
+                }
+            }
+        }
+        s
+    }
+
+    /// Returns a sphere enclosing all the points by Ritter's method: a sphere
+    /// across two far apart points, grown to take in each point left outside.
+    /// It takes two passes over the points and is typically within a few
+    /// percent of the smallest sphere. An empty slice gives a zero sphere at
+    /// the origin.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points to enclose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::sphere::Sphere;
+    /// let points = [
+    ///     Point3::new(-1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.0, 0.5, 0.0),
+    ///     Point3::new(0.0, 0.0, -0.9),
+    /// ];
+    /// let s = Sphere::approximate_bounding(&points);
+    /// assert!(points.iter().all(|p| s.contains_point(p)) && s.radius < 1.1);
+    /// ```
+    pub fn approximate_bounding(points: &[Point3]) -> Sphere {
+        let Some(first) = points.first() else {
+            return Sphere::default();
+        };
+        let furthest = |from: &Point3| {
+            points
+                .iter()
+                .copied()
+                .max_by(|a, b| {
+                    a.distance_squared(from)
+                        .total_cmp(&b.distance_squared(from))
+                })
+                .unwrap_or(*from)
+        };
+        let a = furthest(first);
+        let b = furthest(&a);
+        let mut s = sphere_2(&a, &b);
+        for p in points {
+            let d = s.center.distance(p);
+            if d > s.radius {
+                let r = (s.radius + d) * 0.5;
+                s.center = s.center + (*p - s.center) * ((r - s.radius) / d);
+                s.radius = r;
+            }
+        }
+        s
+    }
+}
+
+// Returns the sphere with a and b at opposite ends of a diameter.
+fn sphere_2(a: &Point3, b: &Point3) -> Sphere {
+    Sphere::new(a.midpoint(b), a.distance(b) * 0.5)
+}
+
+// Returns the smallest sphere with a, b and c on its boundary: the one
+// through their circumcircle, or for collinear points the sphere across the
+// furthest pair.
+fn sphere_3(a: &Point3, b: &Point3, c: &Point3) -> Sphere {
+    let (ab, ac) = (*b - *a, *c - *a);
+    let n = ab.cross(&ac);
+    let n2 = n.dot(&n);
+    if n2 <= f32::EPSILON * ab.dot(&ab) * ac.dot(&ac) {
+        return [sphere_2(a, b), sphere_2(a, c), sphere_2(b, c)]
+            .into_iter()
+            .max_by(|s, t| s.radius.total_cmp(&t.radius))
+            .unwrap_or_default();
+    }
+    let to_center = (n.cross(&ab) * ac.dot(&ac) + ac.cross(&n) * ab.dot(&ab)) / (2.0 * n2);
+    Sphere::new(*a + to_center, to_center.magnitude())
+}
+
+// Returns the sphere through a, b, c and d, or for coplanar points the
+// smallest of the spheres through three of them that holds the fourth.
+fn sphere_4(a: &Point3, b: &Point3, c: &Point3, d: &Point3) -> Sphere {
+    let (ab, ac, ad) = (*b - *a, *c - *a, *d - *a);
+    let det = ab.dot(&ac.cross(&ad));
+    let scale = ab.magnitude() * ac.magnitude() * ad.magnitude();
+    if det.abs() <= f32::EPSILON * scale {
+        return [
+            (sphere_3(a, b, c), d),
+            (sphere_3(a, b, d), c),
+            (sphere_3(a, c, d), b),
+            (sphere_3(b, c, d), a),
+        ]
+        .into_iter()
+        .filter(|(s, p)| s.center.distance(p) <= s.radius * (1.0 + 1.0e-5))
+        .map(|(s, _)| s)
+        .min_by(|s, t| s.radius.total_cmp(&t.radius))
+        .unwrap_or_else(|| sphere_3(a, b, c));
+    }
+    let to_center =
+        (ac.cross(&ad) * ab.dot(&ab) + ad.cross(&ab) * ac.dot(&ac) + ab.cross(&ac) * ad.dot(&ad))
+            / (2.0 * det);
+    Sphere::new(*a + to_center, to_center.magnitude())
+}
+
+#[test]
+fn bounding_spheres() {
+    // Points on a known sphere, with others inside, in a sorted order.
+    let golden = core::f32::consts::PI * (3.0 - 5.0f32.sqrt());
+    let center = Point3::new(3.0, -2.0, 1.0);
+    let mut points = Vec::new();
+    for i in 0..300 {
+        let z = 1.0 - 2.0 * (i as f32 + 0.5) / 300.0;
+        let (r, phi) = ((1.0 - z * z).sqrt(), golden * i as f32);
+        let v = Vector3::new(r * phi.cos(), r * phi.sin(), z);
+        points.push(center + v * if i % 3 == 0 { 2.0 } else { 1.5 });
+    }
+    let s = Sphere::minimal_bounding(&points);
+    assert!((s.center - center).magnitude() < 1e-3 && (s.radius - 2.0).abs() < 1e-3);
+    assert!(points
+        .iter()
+        .all(|p| s.center.distance(p) <= s.radius * (1.0 + 1.0e-4)));
+    let r = Sphere::approximate_bounding(&points);
+    assert!(r.radius >= s.radius * 0.999 && r.radius < s.radius * 1.2);
+    assert!(points
+        .iter()
+        .all(|p| r.center.distance(p) <= r.radius * (1.0 + 1.0e-4)));
+    // Degenerate input: one point, collinear points, coplanar points.
+    let one = Sphere::minimal_bounding(&[center]);
+    assert!(one.center == center && one.radius == 0.0);
+    let line: Vec<Point3> = (0..10).map(|i| Point3::new(i as f32, 0.0, 0.0)).collect();
+    let s = Sphere::minimal_bounding(&line);
+    assert!(
+        (s.center - Point3::new(4.5, 0.0, 0.0)).magnitude() < 1e-5 && (s.radius - 4.5).abs() < 1e-5
+    );
+    let square: Vec<Point3> = (0..4)
+        .map(|i| Point3::new((i & 1) as f32 * 2.0, (i >> 1) as f32 * 2.0, 0.0))
+        .collect();
+    let s = Sphere::minimal_bounding(&square);
+    assert!((s.center - Point3::new(1.0, 1.0, 0.0)).magnitude() < 1e-5);
+    assert!((s.radius - 2.0f32.sqrt()).abs() < 1e-5);
+    assert!(Sphere::minimal_bounding(&[]) == Sphere::default());
+}
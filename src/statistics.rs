@@ -0,0 +1,112 @@
+//! Descriptive statistics of point sets: the mean, the covariance matrix and
+//! the principal axes along which the points spread.
+
+use crate::prelude::*;
+
+/// Returns the mean of the points, or None if there are none.
+///
+/// # Arguments
+///
+/// * `points` - The points to average.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point3::Point3;
+/// use math_engine::statistics::mean;
+/// let points = [Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 4.0, 6.0)];
+/// assert!(mean(&points) == Some(Point3::new(1.0, 2.0, 3.0)));
+/// assert!(mean(&[]).is_none());
+/// ```
+pub fn mean(points: &[Point3]) -> Option<Point3> {
+    if points.is_empty() {
+        return None;
+    }
+    let sum = points
+        .iter()
+        .fold(Vector3::new(0.0, 0.0, 0.0), |s, p| s + Vector3::from(*p));
+    Some(Point3::from(sum / points.len() as f32))
+}
+
+/// Returns the covariance matrix of the points about their mean, or None if
+/// there are none. The sum of outer products is divided by the number of
+/// points, so a single point has a zero covariance.
+///
+/// # Arguments
+///
+/// * `points` - The points to measure.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::matrix3::Matrix3;
+/// use math_engine::point3::Point3;
+/// use math_engine::statistics::covariance;
+/// let points = [Point3::new(-1.0, 0.0, 5.0), Point3::new(1.0, 0.0, 5.0)];
+/// let m = covariance(&points).unwrap();
+/// assert!(m == Matrix3::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+/// ```
+pub fn covariance(points: &[Point3]) -> Option<Matrix3> {
+    let c = mean(points)?;
+    let mut m = Matrix3::default();
+    for p in points {
+        let d = *p - c;
+        m += Matrix3::outer_product(&d, &d);
+    }
+    Some(m / points.len() as f32)
+}
+
+/// Returns the variances of the points along their principal axes, in
+/// descending order, and a rotation whose columns are those axes, or None if
+/// there are no points. The first axis is the direction in which the points
+/// spread most and the last the one in which they spread least.
+///
+/// # Arguments
+///
+/// * `points` - The points to analyse.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point3::Point3;
+/// use math_engine::statistics::principal_axes;
+/// let points = [
+///     Point3::new(-3.0, -3.0, 0.0),
+///     Point3::new(3.0, 3.0, 0.0),
+///     Point3::new(-1.0, 1.0, 0.0),
+///     Point3::new(1.0, -1.0, 0.0),
+/// ];
+/// let (variances, axes) = principal_axes(&points).unwrap();
+/// assert!(variances.x > variances.y && variances.z.abs() < 1e-6);
+/// // The points spread most along the diagonal x = y.
+/// assert!((axes[0].x - axes[0].y).abs() < 1e-5);
+/// ```
+pub fn principal_axes(points: &[Point3]) -> Option<(Vector3, Matrix3)> {
+    Some(covariance(points)?.symmetric_eigen())
+}
+
+#[test]
+fn point_statistics() {
+    // Points spread along three perpendicular axes by different amounts.
+    let r = Matrix3::make_rotation(
+        0.7,
+        &UnitVector3::new_normalize(Vector3::new(1.0, 2.0, 3.0)),
+    );
+    let c = Point3::new(4.0, -2.0, 1.0);
+    let mut points = alloc::vec::Vec::new();
+    for (axis, e) in [(0, 3.0), (1, 2.0), (2, 1.0)] {
+        points.push(c + r[axis] * e);
+        points.push(c - r[axis] * e);
+    }
+    let m = mean(&points).unwrap();
+    assert!(m.distance(&c) < 1e-5);
+    let cov = covariance(&points).unwrap();
+    assert!((0..3).all(|i| (cov[i] - cov.transpose()[i]).magnitude() < 1e-6));
+    let (variances, axes) = principal_axes(&points).unwrap();
+    assert!((variances - Vector3::new(3.0, 4.0 / 3.0, 1.0 / 3.0)).magnitude() < 1e-4);
+    for i in 0..3 {
+        assert!(axes[i].dot(&r[i]).abs() > 0.9999);
+    }
+    assert!((axes.determinant() - 1.0).abs() < 1e-5);
+    assert!(covariance(&points[..1]) == Some(Matrix3::default()));
+}
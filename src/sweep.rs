@@ -0,0 +1,434 @@
+//! Time of impact queries for continuous collision detection. A shape that
+//! moves far in one step can pass clean through a thin obstacle without
+//! overlapping it at either end of the step; these queries sweep the shape
+//! along its displacement instead and report where the first contact
+//! happens.
+//!
+//! Each query takes the displacement v over the step and returns the
+//! fraction t of it, in [0, 1], at which the shapes first touch, together
+//! with the unit contact normal, which points from the obstacle toward the
+//! moving shape. The moving shape is then at its start plus v * t. Shapes
+//! that already overlap give t = 0.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::gjk;
+use crate::prelude::*;
+
+// The squared length below which a direction is treated as zero.
+const EPSILON: f32 = 1.0e-12;
+
+// Returns the unit normal of the triangle, turned to face p, or a zero
+// vector if the triangle is degenerate.
+fn facing_normal(p: &Point3, tri: &Triangle) -> Vector3 {
+    let n = tri.normal();
+    if n.dot(&(*p - tri.a)) < 0.0 {
+        -n
+    } else {
+        n
+    }
+}
+
+// Returns the unit vector from q to p, or the fallback if they coincide.
+fn direction(p: &Point3, q: &Point3, fallback: &Vector3) -> Vector3 {
+    let d = *p - *q;
+    if d.dot(&d) > EPSILON {
+        d.normalize()
+    } else {
+        *fallback
+    }
+}
+
+// Returns the smallest t >= 0 at which o + v t comes within r of the
+// segment ab, for a point starting outside that distance.
+fn ray_capsule(o: &Point3, v: &Vector3, a: &Point3, b: &Point3, r: f32) -> Option<f32> {
+    let mut best = [a, b]
+        .iter()
+        .filter_map(|c| Sphere::new(**c, r).ray_intersect(o, v))
+        .min_by(f32::total_cmp);
+    // The side of the infinite cylinder about ab, by Ericson's Real-Time
+    // Collision Detection, 5.3.7, kept only between the caps.
+    let (d, m) = (*b - *a, *o - *a);
+    let (dd, nd, md) = (d.dot(&d), v.dot(&d), m.dot(&d));
+    let qa = dd * v.dot(v) - nd * nd;
+    if qa > EPSILON {
+        let qb = dd * m.dot(v) - nd * md;
+        let qc = dd * (m.dot(&m) - r * r) - md * md;
+        let disc = qb * qb - qa * qc;
+        if disc >= 0.0 {
+            let t = (-qb - disc.sqrt()) / qa;
+            let s = md + t * nd;
+            if t >= 0.0 && (0.0..=dd).contains(&s) && best.is_none_or(|b| t < b) {
+                best = Some(t);
+            }
+        }
+    }
+    best
+}
+
+/// Returns the time and normal at which the sphere s, moving by v, first
+/// touches the plane f from either side, or None if it does not within the
+/// step.
+///
+/// # Arguments
+///
+/// * `s` - A reference to the moving sphere.
+/// * `v` - A reference to the displacement of the sphere over the step.
+/// * `f` - A reference to the plane.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::plane::Plane;
+/// use math_engine::point3::Point3;
+/// use math_engine::sphere::Sphere;
+/// use math_engine::sweep;
+/// use math_engine::vector3::Vector3;
+/// let ground = Plane::new(0.0, 1.0, 0.0, 0.0);
+/// let ball = Sphere::new(Point3::new(0.0, 5.0, 0.0), 1.0);
+/// // Far enough in one step to pass straight through the ground.
+/// let (t, n) = sweep::sphere_plane(&ball, &Vector3::new(0.0, -8.0, 0.0), &ground).unwrap();
+/// assert!(t == 0.5 && n == Vector3::new(0.0, 1.0, 0.0));
+/// ```
+pub fn sphere_plane(s: &Sphere, v: &Vector3, f: &Plane) -> Option<(f32, Vector3)> {
+    let n = f.get_normal().normalize();
+    let d = f.distance_to_point(&s.center);
+    let n = if d < 0.0 { -n } else { n };
+    let (d, speed) = (d.abs(), -n.dot(v));
+    if d <= s.radius {
+        return Some((0.0, n));
+    }
+    if speed <= 0.0 {
+        return None;
+    }
+    let t = (d - s.radius) / speed;
+    (t <= 1.0).then_some((t, n))
+}
+
+/// Returns the time and normal at which the sphere a, moving by va, first
+/// touches the sphere b, moving by vb, or None if they do not within the
+/// step. The normal points from b toward a.
+///
+/// # Arguments
+///
+/// * `a` - A reference to the first sphere.
+/// * `va` - A reference to the displacement of a over the step.
+/// * `b` - A reference to the second sphere.
+/// * `vb` - A reference to the displacement of b over the step.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point3::Point3;
+/// use math_engine::sphere::Sphere;
+/// use math_engine::sweep;
+/// use math_engine::vector3::Vector3;
+/// let a = Sphere::new(Point3::new(0.0, 0.0, 0.0), 1.0);
+/// let b = Sphere::new(Point3::new(10.0, 0.0, 0.0), 1.0);
+/// let (va, vb) = (Vector3::new(4.0, 0.0, 0.0), Vector3::new(-4.0, 0.0, 0.0));
+/// let (t, n) = sweep::sphere_sphere(&a, &va, &b, &vb).unwrap();
+/// assert!(t == 1.0 && n == Vector3::new(-1.0, 0.0, 0.0));
+/// ```
+pub fn sphere_sphere(a: &Sphere, va: &Vector3, b: &Sphere, vb: &Vector3) -> Option<(f32, Vector3)> {
+    // Sweep a's center, moving relative to b, against b grown by a's radius.
+    let v = *va - *vb;
+    let grown = Sphere::new(b.center, a.radius + b.radius);
+    let t = if grown.contains_point(&a.center) {
+        0.0
+    } else {
+        grown.ray_intersect(&a.center, &v)?
+    };
+    if t > 1.0 {
+        return None;
+    }
+    let n = direction(
+        &(a.center + *va * t),
+        &(b.center + *vb * t),
+        &-v.normalize_or_zero(),
+    );
+    Some((t, n))
+}
+
+/// Returns the time and normal at which the sphere s, moving by v, first
+/// touches the triangle, or None if it does not within the step.
+///
+/// # Arguments
+///
+/// * `s` - A reference to the moving sphere.
+/// * `v` - A reference to the displacement of the sphere over the step.
+/// * `triangle` - A reference to the triangle.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point3::Point3;
+/// use math_engine::sphere::Sphere;
+/// use math_engine::sweep;
+/// use math_engine::triangle::Triangle;
+/// use math_engine::vector3::Vector3;
+/// let t = Triangle::new(
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(4.0, 0.0, 0.0),
+///     Point3::new(0.0, 4.0, 0.0),
+/// );
+/// let s = Sphere::new(Point3::new(1.0, 1.0, 3.0), 1.0);
+/// let (time, n) = sweep::sphere_triangle(&s, &Vector3::new(0.0, 0.0, -4.0), &t).unwrap();
+/// assert!(time == 0.5 && n == Vector3::new(0.0, 0.0, 1.0));
+/// ```
+pub fn sphere_triangle(s: &Sphere, v: &Vector3, triangle: &Triangle) -> Option<(f32, Vector3)> {
+    let (c, r) = (s.center, s.radius);
+    let against = -v.normalize_or_zero();
+    let closest = triangle.closest_point(&c);
+    if (c - closest).magnitude() <= r {
+        let fallback = facing_normal(&c, triangle);
+        return Some((0.0, direction(&c, &closest, &fallback)));
+    }
+    // The face: the sphere's lowest point reaching the plane inside the
+    // triangle.
+    let n = facing_normal(&c, triangle);
+    let speed = -n.dot(v);
+    if speed > 0.0 {
+        let t = (n.dot(&(c - triangle.a)) - r) / speed;
+        let p = c + *v * t - n * r;
+        let w = gjk::triangle_weights(triangle.a - p, triangle.b - p, triangle.c - p);
+        if (0.0..=1.0).contains(&t) && w.iter().all(|w| *w > 0.0) {
+            return Some((t, n));
+        }
+    }
+    // Otherwise an edge or corner: the center reaching the capsule of
+    // radius r about one of the edges.
+    let corners = <[Point3; 3]>::from(*triangle);
+    let t = (0..3)
+        .filter_map(|i| ray_capsule(&c, v, &corners[i], &corners[(i + 1) % 3], r))
+        .min_by(f32::total_cmp)
+        .filter(|t| *t <= 1.0)?;
+    let at = c + *v * t;
+    Some((t, direction(&at, &triangle.closest_point(&at), &against)))
+}
+
+/// Returns the time and normal at which the box a, moving by va, first
+/// touches the box b, moving by vb, or None if they do not within the step.
+/// The normal points from b toward a, along the axis of the face hit.
+///
+/// # Arguments
+///
+/// * `a` - A reference to the first box.
+/// * `va` - A reference to the displacement of a over the step.
+/// * `b` - A reference to the second box.
+/// * `vb` - A reference to the displacement of b over the step.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::aabb3::Aabb3;
+/// use math_engine::point3::Point3;
+/// use math_engine::sweep;
+/// use math_engine::vector3::Vector3;
+/// let a = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+/// let wall = Aabb3::new(Point3::new(3.0, -5.0, -5.0), Point3::new(3.5, 5.0, 5.0));
+/// let still = Vector3::new(0.0, 0.0, 0.0);
+/// let (t, n) = sweep::aabb_aabb(&a, &Vector3::new(8.0, 1.0, 0.0), &wall, &still).unwrap();
+/// assert!(t == 0.25 && n == Vector3::new(-1.0, 0.0, 0.0));
+/// ```
+pub fn aabb_aabb(a: &Aabb3, va: &Vector3, b: &Aabb3, vb: &Vector3) -> Option<(f32, Vector3)> {
+    // Work in b's frame, where only a moves.
+    let v = *va - *vb;
+    let (a_min, a_max): ([f32; 3], [f32; 3]) = (a.min.into(), a.max.into());
+    let (b_min, b_max): ([f32; 3], [f32; 3]) = (b.min.into(), b.max.into());
+    let axis = |i: usize, sign: f32| {
+        let mut n = Vector3::new(0.0, 0.0, 0.0);
+        n[i] = sign;
+        n
+    };
+    if a.intersects_aabb(b) {
+        // Already overlapping: report the axis of least overlap.
+        let (i, sign) = (0..3)
+            .flat_map(|i| {
+                [
+                    (i, b_max[i] - a_min[i], 1.0),
+                    (i, a_max[i] - b_min[i], -1.0),
+                ]
+            })
+            .min_by(|p, q| p.1.total_cmp(&q.1))
+            .map(|(i, _, sign)| (i, sign))
+            .unwrap_or((0, 1.0));
+        return Some((0.0, axis(i, sign)));
+    }
+    // The boxes touch while every axis overlaps: from the latest entry into
+    // overlap to the earliest exit.
+    let (mut first, mut last) = (0.0f32, 1.0f32);
+    let mut normal = None;
+    for i in 0..3 {
+        if v[i] == 0.0 {
+            if a_max[i] < b_min[i] || a_min[i] > b_max[i] {
+                return None;
+            }
+            continue;
+        }
+        let (enter, exit, sign) = if v[i] > 0.0 {
+            (
+                (b_min[i] - a_max[i]) / v[i],
+                (b_max[i] - a_min[i]) / v[i],
+                -1.0,
+            )
+        } else {
+            (
+                (b_max[i] - a_min[i]) / v[i],
+                (b_min[i] - a_max[i]) / v[i],
+                1.0,
+            )
+        };
+        if normal.is_none() || enter > first {
+            first = first.max(enter);
+            normal = Some(axis(i, sign));
+        }
+        last = last.min(exit);
+        if first > last {
+            return None;
+        }
+    }
+    normal.map(|n| (first, n))
+}
+
+/// Returns the time and normal at which the capsule c, moving by v, first
+/// touches the triangle, or None if it does not within the step.
+///
+/// # Arguments
+///
+/// * `c` - A reference to the moving capsule.
+/// * `v` - A reference to the displacement of the capsule over the step.
+/// * `triangle` - A reference to the triangle.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::capsule::Capsule;
+/// use math_engine::point3::Point3;
+/// use math_engine::sweep;
+/// use math_engine::triangle::Triangle;
+/// use math_engine::vector3::Vector3;
+/// let t = Triangle::new(
+///     Point3::new(-5.0, 0.0, -5.0),
+///     Point3::new(5.0, 0.0, -5.0),
+///     Point3::new(0.0, 0.0, 5.0),
+/// );
+/// // A capsule lying flat, falling onto the triangle.
+/// let c = Capsule::new(Point3::new(-1.0, 3.0, 0.0), Point3::new(1.0, 3.0, 0.0), 0.5);
+/// let (time, n) = sweep::capsule_triangle(&c, &Vector3::new(0.0, -5.0, 0.0), &t).unwrap();
+/// assert!((time - 0.5).abs() < 1e-6 && (n - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-4);
+/// ```
+pub fn capsule_triangle(c: &Capsule, v: &Vector3, triangle: &Triangle) -> Option<(f32, Vector3)> {
+    let (p, q, r) = (c.segment.a, c.segment.b, c.radius);
+    let against = -v.normalize_or_zero();
+    let normal_at = |t: f32| {
+        let moved = Segment3::new(p + *v * t, q + *v * t);
+        match gjk::distance(&moved, triangle) {
+            Some((_, on_segment, on_triangle)) => direction(&on_segment, &on_triangle, &against),
+            None => facing_normal(&moved.at(0.5), triangle),
+        }
+    };
+    if gjk::distance(&c.segment, triangle).is_none_or(|d| d.0 <= r) {
+        return Some((0.0, normal_at(0.0)));
+    }
+    // The capsule's first contact is one of: an end cap against the
+    // triangle, a corner of the triangle against the capsule, or an edge of
+    // the triangle against the capsule's core between the caps.
+    let corners = <[Point3; 3]>::from(*triangle);
+    let mut best = [p, q]
+        .iter()
+        .filter_map(|e| sphere_triangle(&Sphere::new(*e, r), v, triangle))
+        .map(|hit| hit.0)
+        .chain(
+            corners
+                .iter()
+                .filter_map(|x| ray_capsule(x, &-*v, &p, &q, r)),
+        )
+        .min_by(f32::total_cmp);
+    let d1 = q - p;
+    for i in 0..3 {
+        let (a, b) = (corners[i], corners[(i + 1) % 3]);
+        let d2 = b - a;
+        let n = d1.cross(&d2);
+        if n.dot(&n) <= EPSILON {
+            continue;
+        }
+        // The lines stay parallel to the common normal n, so their distance
+        // along it changes linearly; solve for it reaching r.
+        let n = n.normalize();
+        let (h, speed) = (n.dot(&(p - a)), n.dot(v));
+        if h.abs() <= r || h * speed >= 0.0 {
+            continue;
+        }
+        let t = (h - r * h.signum()) / -speed;
+        if best.is_some_and(|b| t >= b) {
+            continue;
+        }
+        // Keep it only if the closest points of the lines then lie within
+        // both edges.
+        let w = p + *v * t - a;
+        let (aa, bb, ee) = (d1.dot(&d1), d1.dot(&d2), d2.dot(&d2));
+        let (cc, ff) = (d1.dot(&w), d2.dot(&w));
+        let denom = aa * ee - bb * bb;
+        let s = (bb * ff - cc * ee) / denom;
+        let u = (aa * ff - bb * cc) / denom;
+        if (0.0..=1.0).contains(&s) && (0.0..=1.0).contains(&u) {
+            best = Some(t);
+        }
+    }
+    let t = best.filter(|t| *t <= 1.0)?;
+    Some((t, normal_at(t)))
+}
+
+#[test]
+fn sweeps() {
+    let down = Vector3::new(0.0, 0.0, -4.0);
+    let tri = Triangle::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(4.0, 0.0, 0.0),
+        Point3::new(0.0, 4.0, 0.0),
+    );
+    // A sphere passing by a corner grazes it at the time the distance to
+    // the corner first reaches its radius.
+    let s = Sphere::new(Point3::new(-0.6, -0.8, 3.0), 1.0);
+    let (t, n) = sphere_triangle(&s, &down, &tri).unwrap();
+    assert!((t - 0.75).abs() < 1e-5 && (n - Vector3::new(-0.6, -0.8, 0.0)).magnitude() < 1e-4);
+    // Missing entirely, or stopping short.
+    let s = Sphere::new(Point3::new(-2.0, -2.0, 3.0), 1.0);
+    assert!(sphere_triangle(&s, &down, &tri).is_none());
+    let s = Sphere::new(Point3::new(1.0, 1.0, 6.0), 1.0);
+    assert!(sphere_triangle(&s, &down, &tri).is_none());
+    // Tunnelling spheres and already overlapping ones.
+    let a = Sphere::new(Point3::new(0.0, 0.0, 0.0), 0.5);
+    let b = Sphere::new(Point3::new(5.0, 0.0, 0.0), 0.5);
+    let zero = Vector3::new(0.0, 0.0, 0.0);
+    let (t, n) = sphere_sphere(&a, &Vector3::new(10.0, 0.0, 0.0), &b, &zero).unwrap();
+    assert!(t == 0.4 && n == Vector3::new(-1.0, 0.0, 0.0));
+    assert!(sphere_sphere(&a, &Vector3::new(3.0, 0.0, 0.0), &b, &zero).is_none());
+    assert!(sphere_sphere(&a, &zero, &a, &zero).unwrap().0 == 0.0);
+    let (t, n) = sphere_plane(&a, &zero, &Plane::new(0.0, 0.0, -2.0, 0.5)).unwrap();
+    assert!(t == 0.0 && n == Vector3::new(0.0, 0.0, -1.0));
+    // Boxes moving along a diagonal, hitting the side face second.
+    let a = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    let b = Aabb3::new(Point3::new(2.0, 3.0, 0.0), Point3::new(3.0, 4.0, 1.0));
+    let (t, n) = aabb_aabb(&a, &Vector3::new(4.0, 4.0, 0.0), &b, &zero).unwrap();
+    assert!(t == 0.5 && n == Vector3::new(0.0, -1.0, 0.0));
+    assert!(aabb_aabb(&a, &Vector3::new(4.0, 0.0, 0.0), &b, &zero).is_none());
+    // A capsule hanging over the triangle's edge lands on the face with the
+    // end above it, and one lying across the triangle beneath it strikes
+    // its edges with the core.
+    let c = Capsule::new(
+        Point3::new(2.0, -1.0, 1.0),
+        Point3::new(2.0, 1.0, 1.0),
+        0.25,
+    );
+    let (t, n) = capsule_triangle(&c, &Vector3::new(0.0, 0.0, -2.0), &tri).unwrap();
+    assert!((t - 0.375).abs() < 1e-5 && (n - Vector3::new(0.0, 0.0, 1.0)).magnitude() < 1e-4);
+    let c = Capsule::new(
+        Point3::new(-1.0, 2.0, -1.0),
+        Point3::new(5.0, 2.0, -1.0),
+        0.25,
+    );
+    let (t, n) = capsule_triangle(&c, &Vector3::new(0.0, 0.0, 2.0), &tri).unwrap();
+    assert!((t - 0.375).abs() < 1e-5 && (n - Vector3::new(0.0, 0.0, -1.0)).magnitude() < 1e-4);
+}
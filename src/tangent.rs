@@ -0,0 +1,148 @@
+//! Tangent space for normal mapping. The tangent and bitangent of a surface
+//! are the directions in which its texture coordinates u and v increase;
+//! together with the normal they form the TBN basis that carries normal map
+//! samples into object space.
+//!
+//! Meshes store one tangent per vertex as a `Vector4`: a unit tangent
+//! orthogonal to the vertex normal, with the handedness sign in w, from
+//! which the bitangent is rebuilt as `n.cross(&t) * w`. Per-triangle
+//! tangents from `triangle_tangents` are summed over the triangles around
+//! each vertex and then reduced to that form by `tangent_frame`.
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float_ops::FloatOps as _;
+use crate::prelude::*;
+
+/// Returns the tangent and bitangent of the triangle with corners p and
+/// texture coordinates uv, or None if its texture coordinates are collinear.
+/// They are the rates of change of position with u and with v, so they are
+/// neither of unit length nor, when the texture is sheared, orthogonal.
+///
+/// # Arguments
+///
+/// * `p` - The corners of the triangle.
+/// * `uv` - The texture coordinates of each corner.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::point3::Point3;
+/// use math_engine::tangent::triangle_tangents;
+/// use math_engine::vector2::Vector2;
+/// use math_engine::vector3::Vector3;
+/// let p = [Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0), Point3::new(0.0, 0.0, -2.0)];
+/// let uv = [Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0)];
+/// let (t, b) = triangle_tangents(&p, &uv).unwrap();
+/// assert!(t == Vector3::new(2.0, 0.0, 0.0) && b == Vector3::new(0.0, 0.0, -2.0));
+/// ```
+pub fn triangle_tangents(p: &[Point3; 3], uv: &[Vector2; 3]) -> Option<(Vector3, Vector3)> {
+    let (e1, e2) = (p[1] - p[0], p[2] - p[0]);
+    let (d1, d2) = (uv[1] - uv[0], uv[2] - uv[0]);
+    let det = d1.perp_dot(&d2);
+    if det == 0.0 {
+        return None;
+    }
+    let t = (e1 * d2.y - e2 * d1.y) / det;
+    let b = (e2 * d1.x - e1 * d2.x) / det;
+    Some((t, b))
+}
+
+/// Returns the tangent t made orthogonal to the unit normal n by
+/// Gram-Schmidt and normalized, or a zero vector if t is parallel to n.
+///
+/// # Arguments
+///
+/// * `n` - The unit normal.
+/// * `t` - The tangent.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::tangent::orthogonalize;
+/// use math_engine::vector3::Vector3;
+/// let t = orthogonalize(&Vector3::new(0.0, 1.0, 0.0), &Vector3::new(3.0, 4.0, 0.0));
+/// assert!(t == Vector3::new(1.0, 0.0, 0.0));
+/// ```
+pub fn orthogonalize(n: &Vector3, t: &Vector3) -> Vector3 {
+    (*t - *n * n.dot(t)).normalize_or_zero()
+}
+
+/// Returns 1 if the normal n, tangent t and bitangent b form a right-handed
+/// basis and -1 if they form a left-handed one, as where a texture is
+/// mirrored.
+///
+/// # Arguments
+///
+/// * `n` - The normal.
+/// * `t` - The tangent.
+/// * `b` - The bitangent.
+pub fn handedness(n: &Vector3, t: &Vector3, b: &Vector3) -> f32 {
+    if n.cross(t).dot(b) < 0.0 {
+        -1.0
+    } else {
+        1.0
+    }
+}
+
+/// Returns the tangent t orthogonalized against the unit normal n, with the
+/// handedness of the basis n, t, b in w.
+///
+/// # Arguments
+///
+/// * `n` - The unit normal.
+/// * `t` - The tangent.
+/// * `b` - The bitangent.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::tangent::tangent_frame;
+/// use math_engine::vector3::Vector3;
+/// use math_engine::vector4::Vector4;
+/// let n = Vector3::new(0.0, 0.0, 1.0);
+/// let frame = tangent_frame(&n, &Vector3::new(1.0, 0.0, 0.2), &Vector3::new(0.0, -1.0, 0.0));
+/// assert!(frame == Vector4::new(1.0, 0.0, 0.0, -1.0));
+/// ```
+pub fn tangent_frame(n: &Vector3, t: &Vector3, b: &Vector3) -> Vector4 {
+    let t = orthogonalize(n, t);
+    Vector4::new(t.x, t.y, t.z, handedness(n, &t, b))
+}
+
+#[test]
+fn tangent_space() {
+    // A triangle on the plane x + y + z = 1, textured through a rotation and a
+    // mirror; the frame must carry uv directions back onto the surface.
+    let n = Vector3::new(1.0, 1.0, 1.0).normalize();
+    let (u, v) = (
+        Vector3::new(1.0, -1.0, 0.0).normalize(),
+        Vector3::new(1.0, 1.0, -2.0).normalize(),
+    );
+    let o = Point3::new(1.0, 0.0, 0.0);
+    for mirror in [1.0, -1.0] {
+        let (c, s) = (0.6, 0.8);
+        let texture = |q: Vector3| {
+            Vector2::new(
+                c * q.dot(&u) - s * q.dot(&v),
+                mirror * (s * q.dot(&u) + c * q.dot(&v)),
+            )
+        };
+        let p = [o, o + u * 2.0, o + v * 3.0];
+        let uv = p.map(|q| texture(q - o));
+        let (t, b) = triangle_tangents(&p, &uv).unwrap();
+        // Moving along t changes only u, at unit rate, and b only v.
+        assert!((texture(t) - Vector2::new(1.0, 0.0)).magnitude() < 1e-5);
+        assert!((texture(b) - Vector2::new(0.0, 1.0)).magnitude() < 1e-5);
+        let frame = tangent_frame(&n, &t, &b);
+        assert!(frame.w == mirror);
+        let t = Vector3::new(frame.x, frame.y, frame.z);
+        assert!(t.dot(&n).abs() < 1e-6 && (t.magnitude() - 1.0).abs() < 1e-6);
+        assert!((n.cross(&t) * frame.w - b.normalize()).magnitude() < 1e-5);
+    }
+    let flat = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(2.0, 2.0),
+    ];
+    assert!(triangle_tangents(&[o, o + u, o + v], &flat).is_none());
+}
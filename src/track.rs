@@ -0,0 +1,271 @@
+//! Keyframe animation tracks, the evaluation core of an animation system. A
+//! `Track` holds a value at a sorted list of key times and samples it in
+//! between. Each key chooses how the segment after it is interpolated.
+//!
+//! Values implement `Animatable`, which blends them by weights summing to 1.
+//! Colors, vectors and scalars blend component by component. Rotations
+//! blend as quaternions turned into one hemisphere and then normalized.
+//! Transforms blend their translation, rotation and scale separately.
+
+use crate::prelude::*;
+use alloc::vec::Vec;
+
+/// How a keyframe's value changes on the way to the next key.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    /// Hold the value until the next key.
+    Step,
+    /// Blend linearly to the next value.
+    #[default]
+    Linear,
+    /// Follow a Catmull-Rom spline through the neighbouring keys, which is
+    /// smooth across keys.
+    Cubic,
+    /// Turn along the great arc to the next rotation at constant speed.
+    /// Values other than rotations blend linearly.
+    Slerp,
+}
+
+/// A value that a `Track` can animate.
+pub trait Animatable: Copy {
+    /// Returns the blend of values with the given weights, which sum to 1.
+    /// Weights may be negative, as spline weights are.
+    fn blend(values: &[Self], weights: &[f32]) -> Self;
+
+    /// Returns the value interpolated between this value at t = 0 and other
+    /// at t = 1, turning at constant speed if it is a rotation.
+    fn slerp(&self, other: &Self, t: f32) -> Self {
+        Self::blend(&[*self, *other], &[1.0 - t, t])
+    }
+}
+
+macro_rules! animatable {
+    ($($t:ty),*) => {
+        $(
+            impl Animatable for $t {
+                fn blend(values: &[$t], weights: &[f32]) -> $t {
+                    values
+                        .iter()
+                        .zip(weights)
+                        .skip(1)
+                        .fold(values[0] * weights[0], |sum, (v, w)| sum + *v * *w)
+                }
+            }
+        )*
+    };
+}
+
+animatable!(f32, Vector2, Vector3, Vector4, RGB, RGBA);
+
+impl Animatable for Quarternion {
+    fn blend(values: &[Quarternion], weights: &[f32]) -> Quarternion {
+        let first = values[0];
+        let mut sum = Quarternion::new(0.0, 0.0, 0.0, 0.0);
+        for (v, w) in values.iter().zip(weights) {
+            let v = if first.dot(v) < 0.0 { -*v } else { *v };
+            sum += v * *w;
+        }
+        sum.normalize()
+    }
+
+    fn slerp(&self, other: &Quarternion, t: f32) -> Quarternion {
+        Quarternion::slerp(self, other, t)
+    }
+}
+
+impl Animatable for Transform4 {
+    fn blend(values: &[Transform4], weights: &[f32]) -> Transform4 {
+        let mut translation = Vector3::new(0.0, 0.0, 0.0);
+        let mut scale = Vector3::new(0.0, 0.0, 0.0);
+        let mut rotation = Quarternion::new(0.0, 0.0, 0.0, 0.0);
+        let mut first = None;
+        for (h, w) in values.iter().zip(weights) {
+            let (t, r, s) = h.decompose();
+            let first = *first.get_or_insert(r);
+            let r = if first.dot(&r) < 0.0 { -r } else { r };
+            translation += Vector3::new(t.x, t.y, t.z) * *w;
+            rotation += r * *w;
+            scale += s * *w;
+        }
+        let rotation = rotation.normalize();
+        let t = Point3::new(translation.x, translation.y, translation.z);
+        Transform4::from_trs(&t, &rotation, &scale)
+    }
+
+    fn slerp(&self, other: &Transform4, t: f32) -> Transform4 {
+        let (ta, ra, sa) = self.decompose();
+        let (tb, rb, sb) = other.decompose();
+        Transform4::from_trs(&ta.lerp(&tb, t), &ra.slerp(&rb, t), &sa.lerp(&sb, t))
+    }
+}
+
+/// A value at a point in time on a `Track`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Keyframe<T> {
+    /// The time of the key.
+    pub time: f32,
+    /// The value at that time.
+    pub value: T,
+    /// How the value changes from this key to the next.
+    pub interpolation: Interpolation,
+}
+
+/// Keyframes of a value sorted by time, sampled at any time in between.
+///
+/// # Examples
+///
+/// ```
+/// use math_engine::track::{Interpolation, Track};
+/// use math_engine::vector3::Vector3;
+/// let mut track = Track::new();
+/// track.insert(0.0, Vector3::new(0.0, 0.0, 0.0), Interpolation::Linear);
+/// track.insert(2.0, Vector3::new(4.0, 0.0, 0.0), Interpolation::Step);
+/// track.insert(3.0, Vector3::new(0.0, 1.0, 0.0), Interpolation::Linear);
+/// assert!(track.sample(0.5) == Some(Vector3::new(1.0, 0.0, 0.0)));
+/// assert!(track.sample(2.9) == Some(Vector3::new(4.0, 0.0, 0.0)));
+/// assert!(track.sample(5.0) == Some(Vector3::new(0.0, 1.0, 0.0)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Track<T> {
+    keys: Vec<Keyframe<T>>,
+}
+
+impl<T: Animatable> Track<T> {
+    /// Returns a track with no keys.
+    pub const fn new() -> Track<T> {
+        Track { keys: Vec::new() }
+    }
+
+    /// Adds a key, keeping the keys sorted by time. A key already at the
+    /// same time is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time of the key.
+    /// * `value` - The value at that time.
+    /// * `interpolation` - How the value changes from this key to the next.
+    pub fn insert(&mut self, time: f32, value: T, interpolation: Interpolation) {
+        let key = Keyframe {
+            time,
+            value,
+            interpolation,
+        };
+        let i = self.keys.partition_point(|k| k.time < time);
+        match self.keys.get_mut(i) {
+            Some(k) if k.time == time => *k = key,
+            _ => self.keys.insert(i, key),
+        }
+    }
+
+    /// Returns the keys sorted by time.
+    pub fn keys(&self) -> &[Keyframe<T>] {
+        &self.keys
+    }
+
+    /// Returns the time from the first key to the last, 0 for a track with
+    /// fewer than two keys.
+    pub fn duration(&self) -> f32 {
+        match (self.keys.first(), self.keys.last()) {
+            (Some(a), Some(b)) => b.time - a.time,
+            _ => 0.0,
+        }
+    }
+
+    /// Returns the value at the given time, or None if the track has no
+    /// keys. Times before the first key or after the last hold the value of
+    /// that key.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time to sample at.
+    pub fn sample(&self, time: f32) -> Option<T> {
+        let keys = &self.keys;
+        let first = keys.first()?;
+        // The first key after time ends the segment time falls in.
+        let i = keys.partition_point(|k| k.time <= time);
+        if i == 0 {
+            return Some(first.value);
+        }
+        if i == keys.len() {
+            return Some(keys[i - 1].value);
+        }
+        let (a, b) = (&keys[i - 1], &keys[i]);
+        let u = (time - a.time) / (b.time - a.time);
+        Some(match a.interpolation {
+            Interpolation::Step => a.value,
+            Interpolation::Linear => T::blend(&[a.value, b.value], &[1.0 - u, u]),
+            Interpolation::Slerp => a.value.slerp(&b.value, u),
+            Interpolation::Cubic => {
+                // The spline's tangents at a and b span the neighbouring
+                // keys, scaled for unevenly spaced times. A missing
+                // neighbour is taken to repeat the end key one segment on.
+                let span = b.time - a.time;
+                let (prev, t0) = match i.checked_sub(2) {
+                    Some(j) => (keys[j].value, keys[j].time),
+                    None => (a.value, a.time - span),
+                };
+                let (next, t3) = match keys.get(i + 1) {
+                    Some(k) => (k.value, k.time),
+                    None => (b.value, b.time + span),
+                };
+                let s1 = span / (b.time - t0);
+                let s2 = span / (t3 - a.time);
+                let (u2, u3) = (u * u, u * u * u);
+                let h00 = 2.0 * u3 - 3.0 * u2 + 1.0;
+                let h10 = u3 - 2.0 * u2 + u;
+                let h01 = -2.0 * u3 + 3.0 * u2;
+                let h11 = u3 - u2;
+                T::blend(
+                    &[prev, a.value, b.value, next],
+                    &[-h10 * s1, h00 - h11 * s2, h01 + h10 * s1, h11 * s2],
+                )
+            }
+        })
+    }
+}
+
+#[test]
+fn tracks() {
+    let mut track = Track::new();
+    assert!(track.sample(1.0).is_none() && track.duration() == 0.0);
+    for (t, v) in [(1.0, 1.0), (0.0, 0.0), (3.0, 2.0), (2.0, 2.0)] {
+        track.insert(t, v, Interpolation::Cubic);
+    }
+    track.insert(3.0, 3.0, Interpolation::Cubic);
+    assert!(track.keys().len() == 4 && track.duration() == 3.0);
+    assert!(track.keys().windows(2).all(|w| w[0].time < w[1].time));
+    // A Catmull-Rom spline passes through its keys and reproduces straight
+    // lines between evenly spaced ones.
+    assert!(track.sample(1.0) == Some(1.0) && track.sample(3.0) == Some(3.0));
+    assert!((track.sample(1.5).unwrap() - 1.5).abs() < 1e-6);
+    // It is smooth across keys.
+    let h = 1e-3;
+    let slope = |t: f32| (track.sample(t + h).unwrap() - track.sample(t - h).unwrap()) / (2.0 * h);
+    assert!((slope(2.0 - 0.01) - slope(2.0 + 0.01)).abs() < 0.05);
+
+    let mut spin = Track::new();
+    spin.insert(0.0, Quarternion::from_rotation_z(0.0), Interpolation::Slerp);
+    spin.insert(1.0, Quarternion::from_rotation_z(2.0), Interpolation::Slerp);
+    let q = spin.sample(0.25).unwrap();
+    assert!((q - Quarternion::from_rotation_z(0.5)).magnitude() < 1e-6);
+
+    let mut rig = Track::new();
+    let a = Transform4::from_trs(
+        &Point3::new(0.0, 0.0, 0.0),
+        &Quarternion::from_rotation_y(0.0),
+        &Vector3::new(1.0, 1.0, 1.0),
+    );
+    let b = Transform4::from_trs(
+        &Point3::new(2.0, 0.0, 0.0),
+        &Quarternion::from_rotation_y(1.0),
+        &Vector3::new(3.0, 3.0, 3.0),
+    );
+    rig.insert(0.0, a, Interpolation::Linear);
+    rig.insert(1.0, b, Interpolation::Linear);
+    let (t, r, s) = rig.sample(0.5).unwrap().decompose();
+    assert!((t - Point3::new(1.0, 0.0, 0.0)).magnitude() < 1e-5);
+    assert!((s - Vector3::new(2.0, 2.0, 2.0)).magnitude() < 1e-5);
+    assert!(r.dot(&Quarternion::from_rotation_y(0.5)).abs() > 1.0 - 1e-5);
+}
@@ -0,0 +1,255 @@
+use crate::prelude::*;
+use core::ops::{Index, IndexMut, Mul, MulAssign};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+/// A 2D affine transform, the 2D counterpart of `Transform4`. It is stored as
+/// three column vectors, the two columns of its linear part and the
+/// translation, with the implicit third row `[0 0 1]`.
+#[repr(C)]
+pub struct Transform2 {
+    n: [Vector2; 3],
+}
+
+impl Transform2 {
+    /// Returns the transform whose first two rows are [a b c] and [d e f].
+    ///
+    /// # Arguments
+    ///
+    /// * `a`, `b`, `d`, `e` - The entries of the linear part.
+    /// * `c`, `f` - The translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::transform2::Transform2;
+    /// let h = Transform2::new(1.0, 0.0, 5.0, 0.0, 1.0, 6.0);
+    /// assert!(h.at(1, 2) == 6.0);
+    /// ```
+    pub const fn new(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Transform2 {
+        Transform2 {
+            n: [Vector2::new(a, d), Vector2::new(b, e), Vector2::new(c, f)],
+        }
+    }
+
+    /// Returns the transform with the columns a and b and the translation p.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The image of the x axis.
+    /// * `b` - The image of the y axis.
+    /// * `p` - The translation.
+    pub fn new_with_vecs(a: Vector2, b: Vector2, p: Point2) -> Transform2 {
+        Transform2 {
+            n: [a, b, Vector2::new(p.x, p.y)],
+        }
+    }
+
+    /// Returns the transform that applies the linear map m and then
+    /// translates by p.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - A reference to a matrix2.
+    /// * `p` - The translation.
+    pub fn from_matrix2(m: &Matrix2, p: &Point2) -> Transform2 {
+        Self::new_with_vecs(m[0], m[1], *p)
+    }
+
+    /// Returns the identity transform.
+    pub const fn identity() -> Transform2 {
+        Self::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0)
+    }
+
+    /// Returns the column at index i, where column 2 is the translation.
+    pub fn vec_at(&self, i: usize) -> Vector2 {
+        self[i]
+    }
+
+    /// Returns the entry at row i and column j.
+    pub fn at(&self, i: usize, j: usize) -> f32 {
+        self[j][i]
+    }
+
+    /// Returns the linear part of this transform.
+    pub fn get_matrix(&self) -> Matrix2 {
+        Matrix2::new_with_vecs(self.n[0], self.n[1])
+    }
+
+    /// Returns the translation of this transform.
+    pub fn get_translation(&self) -> Point2 {
+        Point2::new(self.n[2].x, self.n[2].y)
+    }
+
+    /// Sets the translation of this transform to p.
+    pub fn set_translation(&mut self, p: &Point2) {
+        self.n[2] = Vector2::new(p.x, p.y);
+    }
+
+    /// Returns the determinant of the linear part.
+    pub fn determinant(&self) -> f32 {
+        self.n[0].x * self.n[1].y - self.n[1].x * self.n[0].y
+    }
+
+    /// Returns the inverse transform. The linear part must be invertible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point2::Point2;
+    /// use math_engine::transform2::Transform2;
+    /// use math_engine::vector2::Vector2;
+    /// let h = Transform2::make_translation(&Vector2::new(3.0, -1.0))
+    ///     * Transform2::make_rotation(0.6)
+    ///     * Transform2::make_scale_xy(2.0, 0.5);
+    /// let p = Point2::new(1.0, 2.0);
+    /// let q = h.inverse() * (h * p);
+    /// assert!((q - p).magnitude() < 1e-5);
+    /// ```
+    pub fn inverse(&self) -> Transform2 {
+        let inv_det = 1.0 / self.determinant();
+        let a = Vector2::new(self.n[1].y, -self.n[0].y) * inv_det;
+        let b = Vector2::new(-self.n[1].x, self.n[0].x) * inv_det;
+        let t = self.n[2];
+        let p = Point2::new(-(a.x * t.x + b.x * t.y), -(a.y * t.x + b.y * t.y));
+        Self::new_with_vecs(a, b, p)
+    }
+
+    /// Returns the translation by v.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - The translation.
+    pub fn make_translation(v: &Vector2) -> Transform2 {
+        Self::new(1.0, 0.0, v.x, 0.0, 1.0, v.y)
+    }
+
+    /// Returns the counterclockwise rotation through the angle a about the
+    /// origin.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle, as `Radians`, `Degrees` or an `f32` in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::angle::Degrees;
+    /// use math_engine::transform2::Transform2;
+    /// use math_engine::vector2::Vector2;
+    /// let v = Transform2::make_rotation(Degrees(90.0)) * Vector2::new(1.0, 0.0);
+    /// assert!((v - Vector2::new(0.0, 1.0)).magnitude() < 1e-6);
+    /// ```
+    pub fn make_rotation(a: impl Into<Radians>) -> Transform2 {
+        let a = a.into().0;
+        let c = a.cos();
+        let s = a.sin();
+        Self::new(c, -s, 0.0, s, c, 0.0)
+    }
+
+    /// Returns the uniform scale by s.
+    pub fn make_scale(s: f32) -> Transform2 {
+        Self::make_scale_xy(s, s)
+    }
+
+    /// Returns the scale by sx along the x axis and sy along the y axis.
+    pub fn make_scale_xy(sx: f32, sy: f32) -> Transform2 {
+        Self::new(sx, 0.0, 0.0, 0.0, sy, 0.0)
+    }
+
+    /// Returns the shear that adds k times y to x.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point2::Point2;
+    /// use math_engine::transform2::Transform2;
+    /// let p = Transform2::make_shear_x(0.5) * Point2::new(1.0, 2.0);
+    /// assert!(p == Point2::new(2.0, 2.0));
+    /// ```
+    pub fn make_shear_x(k: f32) -> Transform2 {
+        Self::new(1.0, k, 0.0, 0.0, 1.0, 0.0)
+    }
+
+    /// Returns the shear that adds k times x to y.
+    pub fn make_shear_y(k: f32) -> Transform2 {
+        Self::new(1.0, 0.0, 0.0, k, 1.0, 0.0)
+    }
+}
+
+impl Mul<Vector2> for Transform2 {
+    type Output = Vector2;
+
+    fn mul(self, rhs: Vector2) -> Self::Output {
+        self.n[0] * rhs.x + self.n[1] * rhs.y
+    }
+}
+
+impl Mul<Point2> for Transform2 {
+    type Output = Point2;
+
+    fn mul(self, rhs: Point2) -> Self::Output {
+        let v = self.n[0] * rhs.x + self.n[1] * rhs.y + self.n[2];
+        Point2::new(v.x, v.y)
+    }
+}
+
+impl Mul<Transform2> for Transform2 {
+    type Output = Transform2;
+
+    /// Composes two transforms, so that `a * b` applies b first.
+    fn mul(self, rhs: Transform2) -> Self::Output {
+        let t = self * rhs.get_translation();
+        Self::new_with_vecs(self * rhs.n[0], self * rhs.n[1], t)
+    }
+}
+
+impl MulAssign<Transform2> for Transform2 {
+    fn mul_assign(&mut self, rhs: Transform2) {
+        *self = *self * rhs;
+    }
+}
+
+impl Index<usize> for Transform2 {
+    type Output = Vector2;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.n[i]
+    }
+}
+
+impl IndexMut<usize> for Transform2 {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.n[i]
+    }
+}
+
+impl Index<(usize, usize)> for Transform2 {
+    type Output = f32;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        &self.n[j][i]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Transform2 {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        &mut self.n[j][i]
+    }
+}
+
+#[test]
+fn composition() {
+    let r = Transform2::make_rotation(1.1);
+    let s = Transform2::make_shear_y(0.3);
+    let t = Transform2::make_translation(&Vector2::new(-2.0, 4.0));
+    let h = t * r * s;
+    let p = Point2::new(0.5, -1.5);
+    let q = t * (r * (s * p));
+    assert!(((h * p) - q).magnitude() < 1e-5);
+    let id = h * h.inverse();
+    for i in 0..3 {
+        assert!((id[i] - Transform2::identity()[i]).magnitude() < 1e-5);
+    }
+    let v = Vector2::new(1.0, 1.0);
+    assert!((h * v - (h * Point2::new(1.0, 1.0) - h * Point2::new(0.0, 0.0))).magnitude() < 1e-5);
+}
@@ -1,13 +1,15 @@
 use crate::prelude::*;
-use std::ops::{Div, DivAssign, Index, IndexMut, Mul, MulAssign};
+use core::ops::{Div, DivAssign, Index, IndexMut, Mul, MulAssign};
 
-#[derive(Default, Copy, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
 pub struct Transform4 {
     n: [Vector3; 4],
 }
 
 impl Transform4 {
-    pub fn new(
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
         a: f32,
         b: f32,
         c: f32,
@@ -44,7 +46,7 @@ impl Transform4 {
     }
 
     pub fn get_translation(&self) -> Point3 {
-        Point3::new(self.n[0][3], self.n[1][3], self.n[2][3])
+        Point3::new(self.n[3][0], self.n[3][1], self.n[3][2])
     }
 
     pub fn set_translation(&mut self, p: &Point3) {
@@ -90,10 +92,28 @@ impl Transform4 {
         )
     }
 
-    pub fn identity() -> Transform4 {
+    pub const fn identity() -> Transform4 {
         Self::new(1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0)
     }
 
+    /// Returns a builder for composing a transform step by step. See
+    /// `TransformBuilder` for the order in which the steps apply.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// let h = Transform4::builder()
+    ///     .translate(&Vector3::new(1.0, 0.0, 0.0))
+    ///     .rotate_y(0.5)
+    ///     .scale(2.0)
+    ///     .build();
+    /// ```
+    pub fn builder() -> TransformBuilder {
+        TransformBuilder::new()
+    }
+
     // Returns 4x4 transformation matrix that represents a reflection
     // through the plane f. The plane f is assumed to be normalized.
     pub fn make_plane_reflection(f: &Plane) -> Transform4 {
@@ -119,7 +139,13 @@ impl Transform4 {
         )
     }
 
-    pub fn make_vec_reflection(v: &Vector3) -> Transform4 {
+    /// Returns the reflection through the plane through the origin
+    /// perpendicular to the unit vector v.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - The unit length normal of the mirror plane.
+    pub fn make_vec_reflection(v: &UnitVector3) -> Transform4 {
         let x = v.x * -2.0;
         let y = v.y * -2.0;
         let z = v.z * -2.0;
@@ -186,7 +212,13 @@ impl Transform4 {
         Self::new(1.0, 0.0, 0.0, v.x, 0.0, 1.0, 0.0, v.y, 0.0, 0.0, 1.0, v.z)
     }
 
-    pub fn make_involution(v: &Vector3) -> Transform4 {
+    /// Returns the involution through the unit vector v, the half turn
+    /// about v.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - The unit length axis.
+    pub fn make_involution(v: &UnitVector3) -> Transform4 {
         let x = v.x * 2.0;
         let y = v.y * 2.0;
         let z = v.z * 2.0;
@@ -230,25 +262,35 @@ impl Transform4 {
         )
     }
 
-    pub fn make_rotation_x(angle: f32) -> Transform4 {
+    pub fn make_rotation_x(angle: impl Into<Radians>) -> Transform4 {
+        let angle = angle.into().0;
         let c = angle.cos();
         let s = angle.sin();
         Self::new(1.0, 0.0, 0.0, 0.0, 0.0, c, -s, 0.0, 0.0, s, c, 0.0)
     }
 
-    pub fn make_rotation_y(angle: f32) -> Transform4 {
+    pub fn make_rotation_y(angle: impl Into<Radians>) -> Transform4 {
+        let angle = angle.into().0;
         let c = angle.cos();
         let s = angle.sin();
         Self::new(c, 0.0, s, 0.0, 0.0, 1.0, 0.0, 0.0, -s, 0.0, c, 0.0)
     }
 
-    pub fn make_rotation_z(angle: f32) -> Transform4 {
+    pub fn make_rotation_z(angle: impl Into<Radians>) -> Transform4 {
+        let angle = angle.into().0;
         let c = angle.cos();
         let s = angle.sin();
         Self::new(c, -s, 0.0, 0.0, s, c, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0)
     }
 
-    pub fn make_rotation(angle: f32, v: &Vector3) -> Transform4 {
+    /// Returns the rotation through angle radians about the unit vector v.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    /// * `v` - The unit length axis of rotation.
+    pub fn make_rotation(angle: impl Into<Radians>, v: &UnitVector3) -> Transform4 {
+        let angle = angle.into().0;
         let c = angle.cos();
         let s = angle.sin();
         let d = 1.0 - c;
@@ -273,6 +315,324 @@ impl Transform4 {
             0.0,
         )
     }
+
+    /// Returns the transform that scales by s, then rotates by the unit
+    /// quaternion r and finally translates to t.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The translation.
+    /// * `r` - A unit quaternion giving the rotation.
+    /// * `s` - The scale along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::quarternion::Quarternion;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// let h = Transform4::from_trs(
+    ///     &Point3::new(1.0, 2.0, 3.0),
+    ///     &Quarternion::new(0.0, 0.0, 0.0, 1.0),
+    ///     &Vector3::new(2.0, 2.0, 2.0),
+    /// );
+    /// assert!(h * Point3::new(1.0, 1.0, 1.0) == Point3::new(3.0, 4.0, 5.0));
+    /// ```
+    pub fn from_trs(t: &Point3, r: &Quarternion, s: &Vector3) -> Transform4 {
+        let m = r.get_rotation_matrix();
+        Self::new_with_vecs(m[0] * s.x, m[1] * s.y, m[2] * s.z, *t)
+    }
+
+    /// Splits this transform into a translation, a rotation and a scale such
+    /// that `Transform4::from_trs` rebuilds it. A reflection is folded into a
+    /// negative x scale. Transforms containing skew have no such
+    /// decomposition, and the rotation returned for them is approximate.
+    ///
+    /// An axis scaled to zero has no direction to recover. Its scale is 0
+    /// and the rotation is completed from the remaining axes: a missing axis
+    /// is the cross product of the other two, and when two or all three are
+    /// missing any rotation taking the x, y and z axes onto the surviving
+    /// directions is returned, or the identity if none survive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// let h = Transform4::make_translation(&Vector3::new(1.0, 2.0, 3.0))
+    ///     * Transform4::make_scale_xyz(1.0, 2.0, 3.0);
+    /// let (t, r, s) = h.decompose();
+    /// assert!(t == Point3::new(1.0, 2.0, 3.0));
+    /// assert!(r.w == 1.0);
+    /// assert!(s == Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn decompose(&self) -> (Point3, Quarternion, Vector3) {
+        let mut s = Vector3::new(
+            self.n[0].magnitude(),
+            self.n[1].magnitude(),
+            self.n[2].magnitude(),
+        );
+        if self.determinant() < 0.0 {
+            s.x = -s.x;
+        }
+        let tiny = f32::EPSILON * s.x.abs().max(s.y).max(s.z);
+        let mut axes = [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ];
+        let mut kept = 0;
+        for i in 0..3 {
+            if s[i].abs() > tiny {
+                axes[i] = self.n[i] / s[i];
+                kept += 1;
+            }
+        }
+        // Rebuild the missing axes so that the three stay right-handed.
+        if kept == 2 {
+            let k = (0..3).find(|&i| s[i].abs() <= tiny).unwrap();
+            let c = axes[(k + 1) % 3].cross(&axes[(k + 2) % 3]);
+            match c.try_normalize() {
+                Some(c) => axes[k] = c,
+                None => kept = 1,
+            }
+        }
+        if kept == 1 {
+            let k = (0..3).find(|&i| s[i].abs() > tiny).unwrap();
+            let (t, b) = axes[k].any_orthonormal_pair();
+            axes[(k + 1) % 3] = t;
+            axes[(k + 2) % 3] = b;
+        }
+        let m = Matrix3::new_with_vecs(axes[0], axes[1], axes[2]);
+        let mut r = Quarternion::default();
+        r.set_rotation_matrix(&m);
+        (self.get_translation(), r, s)
+    }
+
+    /// Returns the rigid transform that rotates by the unit quaternion q and
+    /// then translates to t.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - A unit quaternion giving the rotation.
+    /// * `t` - The translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::quarternion::Quarternion;
+    /// use math_engine::transform4::Transform4;
+    /// let q = Quarternion::new(0.0, 0.0, 0.0, 1.0);
+    /// let h = Transform4::from_rotation_translation(&q, &Point3::new(1.0, 2.0, 3.0));
+    /// assert!(h.get_translation() == Point3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn from_rotation_translation(q: &Quarternion, t: &Point3) -> Transform4 {
+        let m = q.get_rotation_matrix();
+        Self::new_with_vecs(m[0], m[1], m[2], *t)
+    }
+
+    /// Returns the rotation and translation of this transform, undoing
+    /// `Transform4::from_rotation_translation`. The transform is assumed to
+    /// be rigid; use `Transform4::decompose` when it may contain scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// let h = Transform4::make_translation(&Vector3::new(1.0, 2.0, 3.0))
+    ///     * Transform4::make_rotation_z(0.5);
+    /// let (q, t) = h.to_rotation_translation();
+    /// assert!(t == Point3::new(1.0, 2.0, 3.0));
+    /// assert!((q.z - 0.25f32.sin()).abs() < 1e-6);
+    /// ```
+    pub fn to_rotation_translation(&self) -> (Quarternion, Point3) {
+        let mut q = Quarternion::default();
+        q.set_rotation_matrix(&Matrix3::new_with_vecs(self.n[0], self.n[1], self.n[2]));
+        (q, self.get_translation())
+    }
+
+    /// Returns the rigid transform placing a right-handed camera at eye
+    /// looking toward target, mapping camera space to world space. The camera
+    /// looks down its negative z axis, so the result is the inverse of
+    /// `Matrix4::look_at_rh`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eye` - The position of the camera.
+    /// * `target` - The point the camera looks at.
+    /// * `up` - The approximate up direction, not parallel to target - eye.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// let h = Transform4::look_at(
+    ///     &Point3::new(0.0, 0.0, 5.0),
+    ///     &Point3::new(0.0, 0.0, 0.0),
+    ///     &Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// assert!(h * Point3::new(0.0, 0.0, -5.0) == Point3::new(0.0, 0.0, 0.0));
+    /// ```
+    pub fn look_at(eye: &Point3, target: &Point3, up: &Vector3) -> Transform4 {
+        let f = (*target - *eye).normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(&f);
+        Self::new_with_vecs(s, u, -f, *eye)
+    }
+
+    /// Returns the inverse of `Transform4::look_at`, the view transform
+    /// mapping world space to the space of a right-handed camera at eye.
+    /// Since the rotation part is orthonormal it is inverted by transposing.
+    ///
+    /// # Arguments
+    ///
+    /// * `eye` - The position of the camera.
+    /// * `target` - The point the camera looks at.
+    /// * `up` - The approximate up direction, not parallel to target - eye.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// let h = Transform4::look_at_inverse(
+    ///     &Point3::new(0.0, 0.0, 5.0),
+    ///     &Point3::new(0.0, 0.0, 0.0),
+    ///     &Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// assert!(h * Point3::new(0.0, 0.0, 0.0) == Point3::new(0.0, 0.0, -5.0));
+    /// ```
+    pub fn look_at_inverse(eye: &Point3, target: &Point3, up: &Vector3) -> Transform4 {
+        let f = (*target - *eye).normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(&f);
+        let e = Vector3::from(*eye);
+        Self::new(
+            s.x,
+            s.y,
+            s.z,
+            -s.dot(&e),
+            u.x,
+            u.y,
+            u.z,
+            -u.dot(&e),
+            -f.x,
+            -f.y,
+            -f.z,
+            f.dot(&e),
+        )
+    }
+
+    /// Returns the rigid transform produced by the twist t, the exponential
+    /// map from se(3). The rotation is `Matrix3::from_rotation_vector` of the
+    /// angular part and the translation is the linear part carried along the
+    /// screw motion.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - A reference to a twist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::twist::Twist;
+    /// use math_engine::vector3::Vector3;
+    /// // A half turn about z while moving along x traces half a circle.
+    /// let t = Twist::new(
+    ///     Vector3::new(0.0, 0.0, core::f32::consts::PI),
+    ///     Vector3::new(1.0, 0.0, 0.0),
+    /// );
+    /// let p = Transform4::exp(&t).get_translation();
+    /// assert!((p - Point3::new(0.0, 2.0 / core::f32::consts::PI, 0.0)).magnitude() < 1e-6);
+    /// ```
+    pub fn exp(t: &Twist) -> Transform4 {
+        let w = t.angular;
+        let r = Matrix3::from_rotation_vector(&w);
+        let theta2 = w.dot(&w);
+        let (a, b) = if theta2 < 1.0e-6 {
+            (0.5 - theta2 / 24.0, 1.0 / 6.0 - theta2 / 120.0)
+        } else {
+            let theta = theta2.sqrt();
+            (
+                (1.0 - theta.cos()) / theta2,
+                (theta - theta.sin()) / (theta2 * theta),
+            )
+        };
+        let wv = w.cross(&t.linear);
+        let p = t.linear + wv * a + w.cross(&wv) * b;
+        Self::new_with_vecs(r[0], r[1], r[2], Point3::new(p.x, p.y, p.z))
+    }
+
+    /// Returns the twist that produces this rigid transform, the logarithm
+    /// map to se(3). The rotation angle of the result is in [0, π].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// let h = Transform4::make_translation(&Vector3::new(1.0, 2.0, 0.0))
+    ///     * Transform4::make_rotation_y(1.1);
+    /// let g = Transform4::exp(&h.ln());
+    /// assert!((0..4).all(|i| (g[i] - h[i]).magnitude() < 1e-5));
+    /// ```
+    pub fn ln(&self) -> Twist {
+        let r = Matrix3::new_with_vecs(self[0], self[1], self[2]);
+        let w = r.to_rotation_vector();
+        let theta2 = w.dot(&w);
+        let c = if theta2 < 1.0e-6 {
+            1.0 / 12.0 + theta2 / 720.0
+        } else {
+            let theta = theta2.sqrt();
+            (1.0 - theta * theta.sin() / (2.0 * (1.0 - theta.cos()))) / theta2
+        };
+        let p = self[3];
+        let wp = w.cross(&p);
+        Twist::new(w, p - wp * 0.5 + w.cross(&wp) * c)
+    }
+
+    /// Returns the transform interpolated between this transform at t = 0
+    /// and other at t = 1 along a screw motion, turning about and sliding
+    /// along a single axis at constant speed, as `DualQuaternion::sclerp`
+    /// does. Unlike interpolating rotation and translation separately, a
+    /// body spinning about an off-center point follows the arc it would
+    /// really sweep. Scale is interpolated linearly.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the transform at t = 1.
+    /// * `t` - The interpolation parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::transform4::Transform4;
+    /// use math_engine::vector3::Vector3;
+    /// // A door hinged at the origin swings its handle around an arc.
+    /// let open = Transform4::make_rotation_y(core::f32::consts::FRAC_PI_2);
+    /// let half = Transform4::identity().interpolate(&open, 0.5);
+    /// let handle = half * Point3::new(1.0, 0.0, 0.0);
+    /// assert!((Vector3::new(handle.x, handle.y, handle.z).magnitude() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn interpolate(&self, other: &Transform4, t: f32) -> Transform4 {
+        let (ta, ra, sa) = self.decompose();
+        let (tb, rb, sb) = other.decompose();
+        let a = DualQuaternion::from_rotation_translation(&ra, &ta);
+        let b = DualQuaternion::from_rotation_translation(&rb, &tb);
+        let m = a.sclerp(&b, t).normalize();
+        Transform4::from_trs(&m.get_translation(), &m.get_rotation(), &sa.lerp(&sb, t))
+    }
 }
 
 impl Mul<Vector3> for Transform4 {
@@ -307,12 +667,12 @@ impl Mul<Matrix3> for Transform4 {
             self.n[0][0] * rhs[(0, 0)] + self.n[1][0] * rhs[(1, 0)] + self.n[2][0] * rhs[(2, 0)],
             self.n[0][0] * rhs[(0, 1)] + self.n[1][0] * rhs[(1, 1)] + self.n[2][0] * rhs[(2, 1)],
             self.n[0][0] * rhs[(0, 2)] + self.n[1][0] * rhs[(1, 2)] + self.n[2][0] * rhs[(2, 2)],
-            self.n[0][0] * rhs[(0, 0)] + self.n[1][1] * rhs[(1, 0)] + self.n[2][1] * rhs[(2, 0)],
-            self.n[0][0] * rhs[(0, 1)] + self.n[1][1] * rhs[(1, 1)] + self.n[2][1] * rhs[(2, 1)],
-            self.n[0][0] * rhs[(0, 2)] + self.n[1][1] * rhs[(1, 2)] + self.n[2][1] * rhs[(2, 2)],
-            self.n[0][0] * rhs[(0, 0)] + self.n[1][2] * rhs[(1, 0)] + self.n[2][2] * rhs[(2, 0)],
-            self.n[0][0] * rhs[(0, 1)] + self.n[1][2] * rhs[(1, 1)] + self.n[2][2] * rhs[(2, 1)],
-            self.n[0][0] * rhs[(0, 2)] + self.n[1][2] * rhs[(1, 2)] + self.n[2][2] * rhs[(2, 2)],
+            self.n[0][1] * rhs[(0, 0)] + self.n[1][1] * rhs[(1, 0)] + self.n[2][1] * rhs[(2, 0)],
+            self.n[0][1] * rhs[(0, 1)] + self.n[1][1] * rhs[(1, 1)] + self.n[2][1] * rhs[(2, 1)],
+            self.n[0][1] * rhs[(0, 2)] + self.n[1][1] * rhs[(1, 2)] + self.n[2][1] * rhs[(2, 2)],
+            self.n[0][2] * rhs[(0, 0)] + self.n[1][2] * rhs[(1, 0)] + self.n[2][2] * rhs[(2, 0)],
+            self.n[0][2] * rhs[(0, 1)] + self.n[1][2] * rhs[(1, 1)] + self.n[2][2] * rhs[(2, 1)],
+            self.n[0][2] * rhs[(0, 2)] + self.n[1][2] * rhs[(1, 2)] + self.n[2][2] * rhs[(2, 2)],
         )
     }
 }
@@ -355,15 +715,15 @@ impl Mul<Transform4> for Transform4 {
             self.n[0][1] * rhs[(0, 1)] + self.n[1][1] * rhs[(1, 1)] + self.n[2][1] * rhs[(2, 1)],
             self.n[0][1] * rhs[(0, 2)] + self.n[1][1] * rhs[(1, 2)] + self.n[2][1] * rhs[(2, 2)],
             self.n[0][1] * rhs[(0, 3)]
-                + self.n[1][0] * rhs[(1, 3)]
-                + self.n[2][0] * rhs[(2, 3)]
+                + self.n[1][1] * rhs[(1, 3)]
+                + self.n[2][1] * rhs[(2, 3)]
                 + self.n[3][1],
             self.n[0][2] * rhs[(0, 0)] + self.n[1][2] * rhs[(1, 0)] + self.n[2][2] * rhs[(2, 0)],
             self.n[0][2] * rhs[(0, 1)] + self.n[1][2] * rhs[(1, 1)] + self.n[2][2] * rhs[(2, 1)],
             self.n[0][2] * rhs[(0, 2)] + self.n[1][2] * rhs[(1, 2)] + self.n[2][2] * rhs[(2, 2)],
             self.n[0][2] * rhs[(0, 3)]
-                + self.n[1][0] * rhs[(1, 3)]
-                + self.n[2][0] * rhs[(2, 3)]
+                + self.n[1][2] * rhs[(1, 3)]
+                + self.n[2][2] * rhs[(2, 3)]
                 + self.n[3][2],
         )
     }
@@ -439,36 +799,7 @@ impl DivAssign<f32> for Transform4 {
 
 impl MulAssign<Transform4> for Transform4 {
     fn mul_assign(&mut self, rhs: Transform4) {
-        self.n[0][0] =
-            self.n[0][0] * rhs[(0, 0)] + self.n[1][0] * rhs[(1, 0)] + self.n[2][0] * rhs[(2, 0)];
-        self.n[1][0] =
-            self.n[0][0] * rhs[(0, 1)] + self.n[1][0] * rhs[(1, 1)] + self.n[2][0] * rhs[(2, 1)];
-        self.n[2][0] =
-            self.n[0][0] * rhs[(0, 2)] + self.n[1][0] * rhs[(1, 2)] + self.n[2][0] * rhs[(2, 2)];
-        self.n[3][0] = self.n[0][0] * rhs[(0, 3)]
-            + self.n[1][0] * rhs[(1, 3)]
-            + self.n[2][0] * rhs[(2, 3)]
-            + self.n[3][0];
-        self.n[0][1] =
-            self.n[0][1] * rhs[(0, 0)] + self.n[1][1] * rhs[(1, 0)] + self.n[2][1] * rhs[(2, 0)];
-        self.n[1][1] =
-            self.n[0][1] * rhs[(0, 1)] + self.n[1][1] * rhs[(1, 1)] + self.n[2][1] * rhs[(2, 1)];
-        self.n[2][1] =
-            self.n[0][1] * rhs[(0, 2)] + self.n[1][1] * rhs[(1, 2)] + self.n[2][1] * rhs[(2, 2)];
-        self.n[3][1] = self.n[0][1] * rhs[(0, 3)]
-            + self.n[1][0] * rhs[(1, 3)]
-            + self.n[2][0] * rhs[(2, 3)]
-            + self.n[3][1];
-        self.n[0][2] =
-            self.n[0][2] * rhs[(0, 0)] + self.n[1][2] * rhs[(1, 0)] + self.n[2][2] * rhs[(2, 0)];
-        self.n[1][2] =
-            self.n[0][2] * rhs[(0, 1)] + self.n[1][2] * rhs[(1, 1)] + self.n[2][2] * rhs[(2, 1)];
-        self.n[2][2] =
-            self.n[0][2] * rhs[(0, 2)] + self.n[1][2] * rhs[(1, 2)] + self.n[2][2] * rhs[(2, 2)];
-        self.n[3][2] = self.n[0][2] * rhs[(0, 3)]
-            + self.n[1][0] * rhs[(1, 3)]
-            + self.n[2][0] * rhs[(2, 3)]
-            + self.n[3][2];
+        *self = *self * rhs;
     }
 }
 
@@ -488,3 +819,125 @@ impl MulAssign<f32> for Transform4 {
         self.n[3][2] *= rhs;
     }
 }
+
+#[test]
+fn look_at() {
+    let eye = Point3::new(1.0, 2.0, 3.0);
+    let target = Point3::new(-2.0, 0.5, 4.0);
+    let up = Vector3::new(0.0, 1.0, 0.0);
+    let h = Transform4::look_at(&eye, &target, &up);
+    let v = Transform4::look_at_inverse(&eye, &target, &up);
+    let m = Matrix4::look_at_rh(&eye, &target, &up);
+    let p = Point3::new(0.3, -0.7, 2.0);
+    let q = v * p;
+    let r = m * p;
+    assert!((q.x - r.x).abs() < 1e-5 && (q.y - r.y).abs() < 1e-5 && (q.z - r.z).abs() < 1e-5);
+    let back = h * q;
+    assert!((back - p).magnitude() < 1e-5);
+    // The target lies straight ahead on the negative z axis of the camera.
+    let t = v * target;
+    assert!(t.x.abs() < 1e-5 && t.y.abs() < 1e-5 && t.z < 0.0);
+}
+
+#[test]
+fn trs_round_trip() {
+    let h = Transform4::make_translation(&Vector3::new(-1.0, 4.0, 2.5))
+        * Transform4::make_rotation(1.2, &Vector3::new(1.0, 2.0, -1.0).to_unit())
+        * Transform4::make_scale_xyz(-2.0, 0.5, 3.0);
+    let (t, r, s) = h.decompose();
+    let g = Transform4::from_trs(&t, &r, &s);
+    for i in 0..4 {
+        assert!((g[i] - h[i]).magnitude() < 1e-5);
+    }
+    assert!((r.x * r.x + r.y * r.y + r.z * r.z + r.w * r.w - 1.0).abs() < 1e-5);
+
+    // Flattening one, two or all three axes still gives a unit rotation
+    // that rebuilds the transform.
+    let rotate = Transform4::make_rotation(0.7, &Vector3::new(2.0, -1.0, 1.0).to_unit());
+    for (sx, sy, sz) in [(2.0, 0.0, 3.0), (0.0, 0.0, 3.0), (0.0, 0.0, 0.0)] {
+        let h = rotate * Transform4::make_scale_xyz(sx, sy, sz);
+        let (t, r, s) = h.decompose();
+        assert!((r.x * r.x + r.y * r.y + r.z * r.z + r.w * r.w - 1.0).abs() < 1e-5);
+        let g = Transform4::from_trs(&t, &r, &s);
+        for i in 0..4 {
+            assert!((g[i] - h[i]).magnitude() < 1e-5);
+        }
+    }
+}
+
+#[test]
+fn rigid_round_trip() {
+    let h = Transform4::make_translation(&Vector3::new(3.0, -1.0, 0.5))
+        * Transform4::make_rotation(2.5, &Vector3::new(-1.0, 0.5, 2.0).to_unit());
+    let (q, t) = h.to_rotation_translation();
+    let g = Transform4::from_rotation_translation(&q, &t);
+    for i in 0..4 {
+        assert!((g[i] - h[i]).magnitude() < 1e-5);
+    }
+}
+
+#[test]
+fn exp_ln() {
+    let h = Transform4::make_translation(&Vector3::new(-1.0, 0.5, 3.0))
+        * Transform4::make_rotation(2.9, &Vector3::new(1.0, -1.0, 2.0).to_unit());
+    let close = |a: &Transform4, b: &Transform4| (0..4).all(|i| (a[i] - b[i]).magnitude() < 1e-4);
+    let t = h.ln();
+    assert!(close(&Transform4::exp(&t), &h));
+    let half = Transform4::exp(&(t * 0.5));
+    assert!(close(&(half * half), &h));
+    // A pure translation has a zero angular part.
+    let g = Transform4::make_translation(&Vector3::new(1.0, 2.0, 3.0));
+    assert!(g.ln() == Twist::new(Vector3::default(), Vector3::new(1.0, 2.0, 3.0)));
+    // A half turn, where the rotation axis comes from the symmetric part.
+    let f = Transform4::make_rotation(
+        core::f32::consts::PI,
+        &Vector3::new(0.0, 3.0, 4.0).to_unit(),
+    );
+    assert!(close(&Transform4::exp(&f.ln()), &f));
+}
+
+#[test]
+fn screw_interpolation() {
+    let close = |a: &Transform4, b: &Transform4| (0..4).all(|i| (a[i] - b[i]).magnitude() < 1e-4);
+    let a = Transform4::make_translation(&Vector3::new(1.0, 0.0, 2.0))
+        * Transform4::make_rotation(0.4, &Vector3::new(1.0, 1.0, 0.0).to_unit());
+    let b = Transform4::make_translation(&Vector3::new(-2.0, 3.0, 0.0))
+        * Transform4::make_rotation(2.2, &Vector3::new(0.0, 1.0, 2.0).to_unit());
+    assert!(close(&a.interpolate(&b, 0.0), &a));
+    assert!(close(&a.interpolate(&b, 1.0), &b));
+    // Equal steps of a screw motion compose: the midpoint taken twice from
+    // a reaches b.
+    let step = a.inverse() * a.interpolate(&b, 0.5);
+    assert!(close(&(a * step * step), &b));
+    // Scale goes linearly while the rigid part moves along the screw.
+    let s = a * Transform4::make_scale_xyz(2.0, 2.0, 2.0);
+    let (_, _, scale) = s.interpolate(&b, 0.5).decompose();
+    assert!((scale - Vector3::new(1.5, 1.5, 1.5)).magnitude() < 1e-5);
+}
+
+#[test]
+fn translation() {
+    let h = Transform4::new(1.0, 0.0, 0.0, 5.0, 0.0, 1.0, 0.0, 6.0, 0.0, 0.0, 1.0, 7.0);
+    assert!(h.get_translation() == Point3::new(5.0, 6.0, 7.0));
+}
+
+#[test]
+fn products() {
+    let a = Transform4::new(1.0, 2.0, 0.0, 1.0, 0.0, 1.0, 3.0, -2.0, 2.0, 0.0, 1.0, 4.0);
+    let b = Transform4::new(2.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 2.0, 0.0, 3.0, 1.0, 5.0);
+    let p = Point3::new(1.0, -2.0, 3.0);
+    assert!((a * b) * p == a * (b * p));
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+    let v = Vector3::new(1.0, -2.0, 3.0);
+    assert!((a * m) * v == a * (m * v));
+}
+
+#[test]
+fn mul_assign() {
+    let a = Transform4::new(1.0, 2.0, 0.0, 1.0, 0.0, 1.0, 3.0, -2.0, 2.0, 0.0, 1.0, 4.0);
+    let b = Transform4::new(2.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 2.0, 0.0, 3.0, 1.0, 5.0);
+    let p = Point3::new(1.0, -2.0, 3.0);
+    let mut c = a;
+    c *= b;
+    assert!(c * p == (a * b) * p);
+}
@@ -0,0 +1,153 @@
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// Builds a `Transform4` from a sequence of elementary steps.
+///
+/// The steps are applied to points in the order they are called: each step
+/// acts on the result of the steps before it, in the parent space. So
+///
+/// ```
+/// use math_engine::point3::Point3;
+/// use math_engine::transform4::Transform4;
+/// use math_engine::vector3::Vector3;
+/// let h = Transform4::builder()
+///     .scale(2.0)
+///     .rotate_z(core::f32::consts::FRAC_PI_2)
+///     .translate(&Vector3::new(5.0, 0.0, 0.0))
+///     .build();
+/// let p = h * Point3::new(1.0, 0.0, 0.0);
+/// assert!((p - Point3::new(5.0, 2.0, 0.0)).magnitude() < 1e-6);
+/// ```
+///
+/// first doubles the point to (2, 0, 0), then rotates it to (0, 2, 0) and
+/// finally moves it to (5, 2, 0). The result is the product of the step
+/// matrices in reverse call order, here `T * R * S`.
+pub struct TransformBuilder {
+    h: Transform4,
+}
+
+impl TransformBuilder {
+    /// Returns a builder starting from the identity transform.
+    pub const fn new() -> TransformBuilder {
+        TransformBuilder {
+            h: Transform4::identity(),
+        }
+    }
+
+    /// Appends the transform h as the next step.
+    ///
+    /// # Arguments
+    ///
+    /// * `h` - A reference to a transform4.
+    pub fn then(self, h: &Transform4) -> TransformBuilder {
+        TransformBuilder { h: *h * self.h }
+    }
+
+    /// Appends a translation by v.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - The translation.
+    pub fn translate(self, v: &Vector3) -> TransformBuilder {
+        self.then(&Transform4::make_translation(v))
+    }
+
+    /// Appends a rotation through angle radians about the unit vector axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    /// * `axis` - The unit length axis of rotation.
+    pub fn rotate(self, angle: impl Into<Radians>, axis: &UnitVector3) -> TransformBuilder {
+        self.then(&Transform4::make_rotation(angle, axis))
+    }
+
+    /// Appends a rotation through angle radians about the x axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    pub fn rotate_x(self, angle: impl Into<Radians>) -> TransformBuilder {
+        self.then(&Transform4::make_rotation_x(angle))
+    }
+
+    /// Appends a rotation through angle radians about the y axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    pub fn rotate_y(self, angle: impl Into<Radians>) -> TransformBuilder {
+        self.then(&Transform4::make_rotation_y(angle))
+    }
+
+    /// Appends a rotation through angle radians about the z axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    pub fn rotate_z(self, angle: impl Into<Radians>) -> TransformBuilder {
+        self.then(&Transform4::make_rotation_z(angle))
+    }
+
+    /// Appends the rotation described by the unit quaternion q.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - A unit quaternion.
+    pub fn rotate_quarternion(self, q: &Quarternion) -> TransformBuilder {
+        let m = q.get_rotation_matrix();
+        self.then(&Transform4::new_with_vecs(
+            m[0],
+            m[1],
+            m[2],
+            Point3::default(),
+        ))
+    }
+
+    /// Appends a uniform scale by s.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The scale factor.
+    pub fn scale(self, s: f32) -> TransformBuilder {
+        self.then(&Transform4::make_scale_xyz(s, s, s))
+    }
+
+    /// Appends a scale by sx, sy and sz along the x, y and z axes.
+    ///
+    /// # Arguments
+    ///
+    /// * `sx` - The scale along the x axis.
+    /// * `sy` - The scale along the y axis.
+    /// * `sz` - The scale along the z axis.
+    pub fn scale_xyz(self, sx: f32, sy: f32, sz: f32) -> TransformBuilder {
+        self.then(&Transform4::make_scale_xyz(sx, sy, sz))
+    }
+
+    /// Returns the transform built so far.
+    pub fn build(&self) -> Transform4 {
+        self.h
+    }
+}
+
+impl Default for TransformBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn order() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    let h = TransformBuilder::new()
+        .scale_xyz(1.0, 2.0, 3.0)
+        .rotate_y(0.4)
+        .translate(&v)
+        .build();
+    let g = Transform4::make_translation(&v)
+        * Transform4::make_rotation_y(0.4)
+        * Transform4::make_scale_xyz(1.0, 2.0, 3.0);
+    for i in 0..4 {
+        assert!((g[i] - h[i]).magnitude() < 1e-6);
+    }
+}
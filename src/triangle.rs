@@ -0,0 +1,87 @@
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A triangle described by its three corners. Its front face is the one
+/// from which the corners appear counterclockwise.
+#[repr(C)]
+pub struct Triangle {
+    /// The first corner.
+    pub a: Point3,
+    /// The second corner.
+    pub b: Point3,
+    /// The third corner.
+    pub c: Point3,
+}
+
+impl Triangle {
+    /// Returns the triangle with corners a, b and c.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first corner.
+    /// * `b` - The second corner.
+    /// * `c` - The third corner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::triangle::Triangle;
+    /// let t = Triangle::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0));
+    /// assert!(t.b.x == 1.0);
+    /// ```
+    pub const fn new(a: Point3, b: Point3, c: Point3) -> Triangle {
+        Triangle { a, b, c }
+    }
+
+    /// Returns the unit normal of the front face of this triangle, or a zero
+    /// vector if it is degenerate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::triangle::Triangle;
+    /// use math_engine::vector3::Vector3;
+    /// let t = Triangle::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0));
+    /// assert!(t.normal() == Vector3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn normal(&self) -> Vector3 {
+        (self.b - self.a)
+            .cross(&(self.c - self.a))
+            .normalize_or_zero()
+    }
+
+    /// Returns the point of this triangle closest to p.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A reference to a point3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::point3::Point3;
+    /// use math_engine::triangle::Triangle;
+    /// let t = Triangle::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0));
+    /// assert!(t.closest_point(&Point3::new(0.5, 0.5, 3.0)) == Point3::new(0.5, 0.5, 0.0));
+    /// assert!(t.closest_point(&Point3::new(3.0, 3.0, 0.0)) == Point3::new(1.0, 1.0, 0.0));
+    /// ```
+    pub fn closest_point(&self, p: &Point3) -> Point3 {
+        let w = crate::gjk::triangle_weights(self.a - *p, self.b - *p, self.c - *p);
+        self.a + (self.b - self.a) * w[1] + (self.c - self.a) * w[2]
+    }
+}
+
+impl From<[Point3; 3]> for Triangle {
+    fn from(p: [Point3; 3]) -> Self {
+        Triangle::new(p[0], p[1], p[2])
+    }
+}
+
+impl From<Triangle> for [Point3; 3] {
+    fn from(t: Triangle) -> Self {
+        [t.a, t.b, t.c]
+    }
+}
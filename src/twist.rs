@@ -0,0 +1,64 @@
+use crate::prelude::*;
+use core::ops::{Add, Mul, Sub};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The twist coordinates of a rigid motion, an element of the Lie algebra
+/// se(3). A twist is a constant angular and linear velocity held for unit
+/// time; `Transform4::exp` turns it into the rigid transform it produces
+/// and `Transform4::ln` recovers it.
+///
+/// Twists add and scale like vectors, so scaling the logarithm of a
+/// transform by t and exponentiating moves a fraction t of the way along
+/// its screw motion.
+#[repr(C)]
+pub struct Twist {
+    /// The rotation vector, the axis scaled by the angle in radians.
+    pub angular: Vector3,
+    /// The linear velocity, in the frame the transform maps into.
+    pub linear: Vector3,
+}
+
+impl Twist {
+    /// Returns a twist with the given angular and linear parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `angular` - The rotation vector.
+    /// * `linear` - The linear velocity.
+    pub const fn new(angular: Vector3, linear: Vector3) -> Twist {
+        Twist { angular, linear }
+    }
+}
+
+impl Add for Twist {
+    type Output = Self;
+
+    fn add(self, other: Twist) -> Self::Output {
+        Twist::new(self.angular + other.angular, self.linear + other.linear)
+    }
+}
+
+impl Sub for Twist {
+    type Output = Self;
+
+    fn sub(self, other: Twist) -> Self::Output {
+        Twist::new(self.angular - other.angular, self.linear - other.linear)
+    }
+}
+
+impl Mul<f32> for Twist {
+    type Output = Self;
+
+    fn mul(self, s: f32) -> Self::Output {
+        Twist::new(self.angular * s, self.linear * s)
+    }
+}
+
+impl Mul<Twist> for f32 {
+    type Output = Twist;
+
+    fn mul(self, rhs: Twist) -> Self::Output {
+        rhs * self
+    }
+}
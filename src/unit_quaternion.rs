@@ -0,0 +1,284 @@
+use crate::prelude::*;
+use core::ops::{Mul, MulAssign};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// A quaternion known to have unit magnitude, and so to represent a pure
+/// rotation. The constructors normalize or verify their input, which lets
+/// `get_rotation_matrix` and `transform` skip the division by the squared
+/// magnitude that the general `Quarternion` versions perform.
+#[repr(transparent)]
+pub struct UnitQuaternion(Quarternion);
+
+impl UnitQuaternion {
+    /// Returns the unit quaternion q / |q|.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - A nonzero quaternion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if q cannot be normalized, see `try_new_normalize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// use math_engine::unit_quaternion::UnitQuaternion;
+    /// let u = UnitQuaternion::new_normalize(Quarternion::new(0.0, 0.0, 3.0, 4.0));
+    /// assert!(u.into_inner() == Quarternion::new(0.0, 0.0, 0.6, 0.8));
+    /// ```
+    pub fn new_normalize(q: Quarternion) -> UnitQuaternion {
+        Self::try_new_normalize(q).expect("cannot normalize a zero or non-finite quaternion")
+    }
+
+    /// Returns the unit quaternion q / |q|, or None if the magnitude of q is
+    /// not greater than `f32::EPSILON` or is not finite, where the direction
+    /// of q is lost to rounding or undefined.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - A quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// use math_engine::unit_quaternion::UnitQuaternion;
+    /// let u = UnitQuaternion::try_new_normalize(Quarternion::new(0.0, 0.0, 3.0, 4.0));
+    /// assert!(u.unwrap().into_inner() == Quarternion::new(0.0, 0.0, 0.6, 0.8));
+    /// assert!(UnitQuaternion::try_new_normalize(Quarternion::default()).is_none());
+    /// ```
+    pub fn try_new_normalize(q: Quarternion) -> Option<UnitQuaternion> {
+        let m = q.magnitude();
+        if m > f32::EPSILON && m.is_finite() {
+            Some(UnitQuaternion(q / m))
+        } else {
+            None
+        }
+    }
+
+    /// Returns q as a unit quaternion if its magnitude is within epsilon of
+    /// 1, or None otherwise. The quaternion is used as given, not
+    /// renormalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - A quaternion.
+    /// * `epsilon` - The tolerance on the magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::quarternion::Quarternion;
+    /// use math_engine::unit_quaternion::UnitQuaternion;
+    /// assert!(UnitQuaternion::try_new(Quarternion::new(0.0, 0.6, 0.0, 0.8), 1e-6).is_some());
+    /// assert!(UnitQuaternion::try_new(Quarternion::new(0.0, 0.6, 0.0, 0.9), 1e-6).is_none());
+    /// ```
+    pub fn try_new(q: Quarternion, epsilon: f32) -> Option<UnitQuaternion> {
+        if (q.magnitude() - 1.0).abs() <= epsilon {
+            Some(UnitQuaternion(q))
+        } else {
+            None
+        }
+    }
+
+    /// Returns q as a unit quaternion without checking its magnitude. The
+    /// caller is responsible for q being normalized; otherwise the rotations
+    /// computed from the result are scaled by |q|^2.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - A unit quaternion.
+    pub fn new_unchecked(q: Quarternion) -> UnitQuaternion {
+        UnitQuaternion(q)
+    }
+
+    /// Returns the identity rotation.
+    pub const fn identity() -> UnitQuaternion {
+        UnitQuaternion(Quarternion::IDENTITY)
+    }
+
+    /// Returns the rotation through angle radians about axis. The axis does
+    /// not need to be normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - A nonzero axis of rotation.
+    /// * `angle` - The angle in radians.
+    pub fn from_axis_angle(axis: &Vector3, angle: impl Into<Radians>) -> UnitQuaternion {
+        UnitQuaternion(Quarternion::from_axis_angle(&axis.to_unit(), angle))
+    }
+
+    /// Returns the rotation through angle radians about the x axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    pub fn from_rotation_x(angle: impl Into<Radians>) -> UnitQuaternion {
+        UnitQuaternion(Quarternion::from_rotation_x(angle))
+    }
+
+    /// Returns the rotation through angle radians about the y axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    pub fn from_rotation_y(angle: impl Into<Radians>) -> UnitQuaternion {
+        UnitQuaternion(Quarternion::from_rotation_y(angle))
+    }
+
+    /// Returns the rotation through angle radians about the z axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle in radians.
+    pub fn from_rotation_z(angle: impl Into<Radians>) -> UnitQuaternion {
+        UnitQuaternion(Quarternion::from_rotation_z(angle))
+    }
+
+    /// Returns the rotation composed of Euler angles applied in the given
+    /// order, see `Quarternion::from_euler`.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order in which the rotations are applied.
+    /// * `x` - The angle about the x axis in radians.
+    /// * `y` - The angle about the y axis in radians.
+    /// * `z` - The angle about the z axis in radians.
+    pub fn from_euler(
+        order: EulerOrder,
+        x: impl Into<Radians>,
+        y: impl Into<Radians>,
+        z: impl Into<Radians>,
+    ) -> UnitQuaternion {
+        UnitQuaternion(Quarternion::from_euler(order, x, y, z))
+    }
+
+    /// Returns the rotation represented by the rotation matrix m.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - An orthonormal rotation matrix.
+    pub fn from_rotation_matrix(m: &Matrix3) -> UnitQuaternion {
+        let mut q = Quarternion::default();
+        q.set_rotation_matrix(m);
+        UnitQuaternion::new_normalize(q)
+    }
+
+    /// Returns the underlying quaternion.
+    pub fn into_inner(self) -> Quarternion {
+        self.0
+    }
+
+    /// Returns a reference to the underlying quaternion.
+    pub fn as_quarternion(&self) -> &Quarternion {
+        &self.0
+    }
+
+    /// Returns the inverse rotation, which for a unit quaternion is its
+    /// conjugate.
+    pub fn inverse(&self) -> UnitQuaternion {
+        UnitQuaternion(self.0.conjugate())
+    }
+
+    /// Returns the rotation matrix of this rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::matrix3::Matrix3;
+    /// use math_engine::unit_quaternion::UnitQuaternion;
+    /// let m = UnitQuaternion::from_rotation_x(0.5).get_rotation_matrix();
+    /// let n = Matrix3::make_rotation_x(0.5);
+    /// assert!((0..3).all(|i| (m[i] - n[i]).magnitude() < 1e-6));
+    /// ```
+    pub fn get_rotation_matrix(&self) -> Matrix3 {
+        self.0.scaled_rotation_matrix(2.0)
+    }
+
+    /// Returns the vector v rotated by this rotation.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A 3D vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::unit_quaternion::UnitQuaternion;
+    /// use math_engine::vector3::Vector3;
+    /// let u = UnitQuaternion::from_rotation_z(core::f32::consts::FRAC_PI_2);
+    /// let v = u.transform(&Vector3::new(1.0, 0.0, 0.0));
+    /// assert!((v - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-6);
+    /// ```
+    pub fn transform(&self, v: &Vector3) -> Vector3 {
+        self.0.sandwich(v)
+    }
+
+    /// Returns the axis and angle of this rotation, see
+    /// `Quarternion::to_axis_angle`.
+    pub fn to_axis_angle(&self) -> (Vector3, f32) {
+        self.0.to_axis_angle()
+    }
+
+    /// Returns the Euler angles of this rotation, see `Quarternion::to_euler`.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order in which the rotations are applied.
+    pub fn to_euler(&self, order: EulerOrder) -> (f32, f32, f32) {
+        self.0.to_euler(order)
+    }
+}
+
+impl Default for UnitQuaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<UnitQuaternion> for Quarternion {
+    fn from(u: UnitQuaternion) -> Self {
+        u.0
+    }
+}
+
+impl Mul<UnitQuaternion> for UnitQuaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: UnitQuaternion) -> Self::Output {
+        UnitQuaternion(self.0 * rhs.0)
+    }
+}
+
+impl MulAssign<UnitQuaternion> for UnitQuaternion {
+    fn mul_assign(&mut self, rhs: UnitQuaternion) {
+        *self = *self * rhs;
+    }
+}
+
+#[test]
+fn matches_general() {
+    let u = UnitQuaternion::from_axis_angle(&Vector3::new(1.0, 2.0, -2.0), 1.3);
+    let q = u.into_inner() * 3.0;
+    let v = Vector3::new(0.5, -1.0, 2.0);
+    assert!((u.transform(&v) - q.transform(&v)).magnitude() < 1e-5);
+    let (a, b) = (u.get_rotation_matrix(), q.get_rotation_matrix());
+    assert!((0..3).all(|i| (a[i] - b[i]).magnitude() < 1e-5));
+    assert!((u.inverse().transform(&u.transform(&v)) - v).magnitude() < 1e-5);
+    let w = u * UnitQuaternion::from_rotation_y(0.4);
+    assert!((w.into_inner().magnitude() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn normalize_checked() {
+    let tiny = Quarternion::new(0.0, 1e-8, 0.0, 0.0);
+    assert!(UnitQuaternion::try_new_normalize(tiny).is_none());
+    let nan = Quarternion::new(f32::NAN, 0.0, 0.0, 1.0);
+    assert!(UnitQuaternion::try_new_normalize(nan).is_none());
+    let inf = Quarternion::new(f32::INFINITY, 0.0, 0.0, 1.0);
+    assert!(UnitQuaternion::try_new_normalize(inf).is_none());
+    let small = UnitQuaternion::try_new_normalize(Quarternion::new(0.0, 3e-6, 0.0, 4e-6));
+    assert!((small.unwrap().into_inner().magnitude() - 1.0).abs() < 1e-6);
+}
@@ -0,0 +1,135 @@
+use crate::prelude::*;
+use core::ops::Deref;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// A three dimensional direction vector known to have unit length.
+///
+/// Functions that assume a unit length argument, such as
+/// `Vector3::project` or `Matrix3::make_rotation`, take a `UnitVector3` so
+/// that the assumption is checked by the compiler rather than stated in a doc
+/// comment. It dereferences to `Vector3` for read access.
+#[repr(transparent)]
+pub struct UnitVector3(Vector3);
+
+impl UnitVector3 {
+    /// Returns the unit vector v / |v|.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A nonzero vector3.
+    ///
+    /// # Panics
+    ///
+    /// Panics if v cannot be normalized, see `try_new_normalize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::unit_vector3::UnitVector3;
+    /// use math_engine::vector3::Vector3;
+    /// let u = UnitVector3::new_normalize(Vector3::new(0.0, 3.0, 4.0));
+    /// assert!(*u == Vector3::new(0.0, 0.6, 0.8));
+    /// ```
+    pub fn new_normalize(v: Vector3) -> UnitVector3 {
+        Self::try_new_normalize(v).expect("cannot normalize a zero or non-finite vector")
+    }
+
+    /// Returns the unit vector v / |v|, or None if the length of v is not
+    /// greater than `f32::EPSILON` or is not finite, where the direction of
+    /// v is lost to rounding or undefined.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A vector3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::unit_vector3::UnitVector3;
+    /// use math_engine::vector3::Vector3;
+    /// let u = UnitVector3::try_new_normalize(Vector3::new(0.0, 3.0, 4.0));
+    /// assert!(*u.unwrap() == Vector3::new(0.0, 0.6, 0.8));
+    /// assert!(UnitVector3::try_new_normalize(Vector3::default()).is_none());
+    /// ```
+    pub fn try_new_normalize(v: Vector3) -> Option<UnitVector3> {
+        let m = v.magnitude();
+        if m > f32::EPSILON && m.is_finite() {
+            Some(UnitVector3(v / m))
+        } else {
+            None
+        }
+    }
+
+    /// Returns v as a unit vector if its length is within epsilon of 1, or
+    /// None otherwise. The vector is used as given, not renormalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A vector3.
+    /// * `epsilon` - The tolerance on the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::unit_vector3::UnitVector3;
+    /// use math_engine::vector3::Vector3;
+    /// assert!(UnitVector3::try_new(Vector3::new(0.6, 0.8, 0.0), 1e-6).is_some());
+    /// assert!(UnitVector3::try_new(Vector3::new(0.6, 0.9, 0.0), 1e-6).is_none());
+    /// ```
+    pub fn try_new(v: Vector3, epsilon: f32) -> Option<UnitVector3> {
+        if (v.magnitude() - 1.0).abs() <= epsilon {
+            Some(UnitVector3(v))
+        } else {
+            None
+        }
+    }
+
+    /// Returns v as a unit vector without checking its length. The caller is
+    /// responsible for v having unit length.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A unit length vector3.
+    pub fn new_unchecked(v: Vector3) -> UnitVector3 {
+        UnitVector3(v)
+    }
+
+    /// Returns the unit vector along the x axis.
+    pub fn x_axis() -> UnitVector3 {
+        UnitVector3(Vector3::new(1.0, 0.0, 0.0))
+    }
+
+    /// Returns the unit vector along the y axis.
+    pub fn y_axis() -> UnitVector3 {
+        UnitVector3(Vector3::new(0.0, 1.0, 0.0))
+    }
+
+    /// Returns the unit vector along the z axis.
+    pub fn z_axis() -> UnitVector3 {
+        UnitVector3(Vector3::new(0.0, 0.0, 1.0))
+    }
+
+    /// Returns the underlying vector.
+    pub fn into_inner(self) -> Vector3 {
+        self.0
+    }
+
+    /// Returns the unit vector pointing the opposite way.
+    pub fn negate(&self) -> UnitVector3 {
+        UnitVector3(-self.0)
+    }
+}
+
+impl Deref for UnitVector3 {
+    type Target = Vector3;
+
+    fn deref(&self) -> &Vector3 {
+        &self.0
+    }
+}
+
+impl From<UnitVector3> for Vector3 {
+    fn from(u: UnitVector3) -> Self {
+        u.0
+    }
+}
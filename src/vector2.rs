@@ -1,9 +1,13 @@
 use crate::prelude::*;
-use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 #[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A three dimensional direction vector having float components
 /// x, and y. It's w coordinated it's assumed to be 0.
+#[repr(C)]
 pub struct Vector2 {
     /// The x component.
     pub x: f32,
@@ -12,6 +16,18 @@ pub struct Vector2 {
 }
 
 impl Vector2 {
+    /// The zero vector.
+    pub const ZERO: Vector2 = Vector2::new(0.0, 0.0);
+
+    /// The vector with every component 1.
+    pub const ONE: Vector2 = Vector2::new(1.0, 1.0);
+
+    /// The unit vector along the x axis.
+    pub const X: Vector2 = Vector2::new(1.0, 0.0);
+
+    /// The unit vector along the y axis.
+    pub const Y: Vector2 = Vector2::new(0.0, 1.0);
+
     /// Returns a vector initialized with the floating point components x, and y.
     ///
     /// # Arguments
@@ -25,10 +41,15 @@ impl Vector2 {
     /// use math_engine::vector2::Vector2;
     /// let v = Vector2::new(1.0,0.0);
     /// ```
-    pub fn new(x: f32, y: f32) -> Vector2 {
+    pub const fn new(x: f32, y: f32) -> Vector2 {
         Self { x, y }
     }
 
+    /// Returns the vector with every component set to s.
+    pub const fn splat(s: f32) -> Vector2 {
+        Vector2::new(s, s)
+    }
+
     /// Returns the dot product between this vector and other.
     ///
     /// # Arguments
@@ -98,22 +119,51 @@ impl Vector2 {
         *self - *other * self.dot(other)
     }
 
-    /// Returns the result of reflecting this vector around other
+    /// Returns this vector reflected about the plane with the given normal,
+    /// v - 2 (v · n) n, as GLSL's `reflect`. A vector pointing into a surface
+    /// comes back out of it with its tangential part unchanged.
     ///
     /// # Arguments
     ///
-    /// * `other` - A reference to a vector2.
+    /// * `normal` - A reference to a unit vector2.
+    pub fn reflect(&self, normal: &Vector2) -> Vector2 {
+        *self - *normal * (2.0 * self.dot(normal))
+    }
+
+    /// Returns the direction this unit vector takes after refracting through
+    /// a surface with the given normal, as GLSL's `refract`, or None on total
+    /// internal reflection. The normal should face against this vector.
     ///
-    /// # Examples
+    /// # Arguments
     ///
-    /// ```
-    /// use math_engine::vector2::Vector2;
-    /// let v1 = Vector2::new(1.0,0.0);
-    /// let v2 = Vector2::new(1.0,0.0);
-    /// let v3 = v1.reflect(&v2);
-    /// ```
-    pub fn reflect(&self, other: &Vector2) -> Vector2 {
-        (*self - *other) * 2.0 * self.dot(other)
+    /// * `normal` - A reference to a unit vector2.
+    /// * `eta` - The ratio of the refractive index this vector travels in to
+    ///   the refractive index of the surface.
+    pub fn refract(&self, normal: &Vector2, eta: f32) -> Option<Vector2> {
+        let d = self.dot(normal);
+        let k = 1.0 - eta * eta * (1.0 - d * d);
+        if k < 0.0 {
+            None
+        } else {
+            Some(*self * eta - *normal * (eta * d + k.sqrt()))
+        }
+    }
+
+    /// Returns this normal if it faces against the incident vector, that is
+    /// if reference · incident is negative, and the negated normal otherwise,
+    /// as GLSL's `faceforward`.
+    ///
+    /// # Arguments
+    ///
+    /// * `incident` - The incident direction.
+    /// * `reference` - The normal whose facing is tested, usually the
+    ///   geometric normal when this is an interpolated one.
+    pub fn faceforward(&self, incident: &Vector2, reference: &Vector2) -> Vector2 {
+        if reference.dot(incident) < 0.0 {
+            *self
+        } else {
+            -*self
+        }
     }
 
     /// Returns this vector multiplied by the inverse of it's magnitude
@@ -145,6 +195,101 @@ impl Vector2 {
         self.x /= m;
         self.y /= m;
     }
+
+    /// Returns this vector normalized to unit length, or None if its length
+    /// is zero or not finite, where `normalize` would return NaNs.
+    pub fn try_normalize(&self) -> Option<Vector2> {
+        let m = self.magnitude();
+        if m > 0.0 && m.is_finite() && (1.0 / m).is_finite() {
+            Some(*self / m)
+        } else {
+            None
+        }
+    }
+
+    /// Returns this vector normalized to unit length, or the zero vector if
+    /// it cannot be normalized, see `try_normalize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::vector2::Vector2;
+    /// assert!(Vector2::new(0.0, 0.0).normalize_or_zero() == Vector2::new(0.0, 0.0));
+    /// ```
+    pub fn normalize_or_zero(&self) -> Vector2 {
+        self.try_normalize().unwrap_or_default()
+    }
+
+    /// Returns this vector rotated a quarter turn counterclockwise, (-y, x).
+    pub fn perp(&self) -> Vector2 {
+        Vector2::new(-self.y, self.x)
+    }
+
+    /// Returns the perp dot product of this vector and other, the z
+    /// component of their 3D cross product. It is positive when other lies
+    /// counterclockwise of this vector and zero when they are parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a vector2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::vector2::Vector2;
+    /// let a = Vector2::new(2.0, 0.0);
+    /// assert!(a.perp_dot(&Vector2::new(1.0, 3.0)) == 6.0);
+    /// assert!(a.perp_dot(&Vector2::new(1.0, -3.0)) == -6.0);
+    /// ```
+    pub fn perp_dot(&self, other: &Vector2) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns the angle from the positive x axis to this vector, in radians
+    /// in the range (-π, π].
+    pub fn angle(&self) -> f32 {
+        self.y.atan2(self.x)
+    }
+
+    /// Returns the signed angle that turns this vector to the direction of
+    /// other, in radians in the range (-π, π], positive counterclockwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to a vector2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::vector2::Vector2;
+    /// let a = Vector2::new(1.0, 1.0);
+    /// let b = Vector2::new(-2.0, 2.0);
+    /// assert!((a.angle_between(&b) - core::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    /// assert!((b.angle_between(&a) + core::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    /// ```
+    pub fn angle_between(&self, other: &Vector2) -> f32 {
+        self.perp_dot(other).atan2(self.dot(other))
+    }
+
+    /// Returns this vector rotated counterclockwise through the angle a.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle, as `Radians`, `Degrees` or an `f32` in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::angle::Degrees;
+    /// use math_engine::vector2::Vector2;
+    /// let v = Vector2::new(1.0, 0.0).rotate(Degrees(90.0));
+    /// assert!((v - Vector2::new(0.0, 1.0)).magnitude() < 1e-6);
+    /// ```
+    pub fn rotate(&self, a: impl Into<Radians>) -> Vector2 {
+        let a = a.into();
+        let (s, c) = (a.sin(), a.cos());
+        Vector2::new(c * self.x - s * self.y, s * self.x + c * self.y)
+    }
 }
 
 impl Index<usize> for Vector2 {
@@ -182,6 +327,14 @@ impl Mul<f32> for Vector2 {
     }
 }
 
+impl Mul<Vector2> for f32 {
+    type Output = Vector2;
+
+    fn mul(self, rhs: Vector2) -> Self::Output {
+        rhs * self
+    }
+}
+
 impl Mul<Vector2> for Vector2 {
     type Output = Self;
 
@@ -224,3 +377,46 @@ impl Sub<Vector2> for Vector2 {
         Vector2::new(self.x - other.x, self.y - other.y)
     }
 }
+
+impl Neg for Vector2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Vector2 {
+    fn add_assign(&mut self, rhs: Vector2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl SubAssign for Vector2 {
+    fn sub_assign(&mut self, rhs: Vector2) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl MulAssign<f32> for Vector2 {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl MulAssign<Vector2> for Vector2 {
+    fn mul_assign(&mut self, rhs: Vector2) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+    }
+}
+
+impl DivAssign<f32> for Vector2 {
+    fn div_assign(&mut self, rhs: f32) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
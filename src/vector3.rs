@@ -1,11 +1,14 @@
 use crate::prelude::*;
-use rand::Rng;
-use rand::distributions::{Distribution, Standard};
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
+use crate::scalar;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A three dimensional direction vector having float components
 /// x, y, and z. It's w coordinated it's assumed to be 0.
+#[repr(C)]
 pub struct Vector3 {
     /// The x component.
     pub x: f32,
@@ -16,6 +19,21 @@ pub struct Vector3 {
 }
 
 impl Vector3 {
+    /// The zero vector.
+    pub const ZERO: Vector3 = Vector3::new(0.0, 0.0, 0.0);
+
+    /// The vector with every component 1.
+    pub const ONE: Vector3 = Vector3::new(1.0, 1.0, 1.0);
+
+    /// The unit vector along the x axis.
+    pub const X: Vector3 = Vector3::new(1.0, 0.0, 0.0);
+
+    /// The unit vector along the y axis.
+    pub const Y: Vector3 = Vector3::new(0.0, 1.0, 0.0);
+
+    /// The unit vector along the z axis.
+    pub const Z: Vector3 = Vector3::new(0.0, 0.0, 1.0);
+
     /// Returns a directional vector initialized with the floating point components x, y, and z.
     ///
     /// # Arguments
@@ -31,10 +49,23 @@ impl Vector3 {
     /// let vec3 = Vector3::new(1.0,0.0,0.0);
     /// assert!(vec3.x == 1.0 && vec3.y == 0.0 && vec3.z == 0.0);
     /// ```
-    pub fn new(x: f32, y: f32, z: f32) -> Vector3 {
+    pub const fn new(x: f32, y: f32, z: f32) -> Vector3 {
         Self { x, y, z }
     }
 
+    /// Returns the vector with every component set to s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::vector3::Vector3;
+    /// const HALF: Vector3 = Vector3::splat(0.5);
+    /// assert!(HALF == Vector3::ONE * 0.5);
+    /// ```
+    pub const fn splat(s: f32) -> Vector3 {
+        Vector3::new(s, s, s)
+    }
+
     /// Returns the dot product between this vector and other.
     ///
     /// # Arguments
@@ -84,64 +115,124 @@ impl Vector3 {
         Self::new(
             self.y * other.z - self.z * other.y,
             self.z * other.x - self.x * other.z,
-            self.x * other.y - self.y * other.z,
+            self.x * other.y - self.y * other.x,
         )
     }
 
-    /// Returns the projection of this vector onto other, under
-    /// the assumption that the magnitude of other is 1.
+    /// Returns the projection of this vector onto the unit vector other.
     ///
     /// # Arguments
     ///
-    /// * `other` - A reference to a vector3.
+    /// * `other` - A reference to a unit vector3.
     ///
     /// # Examples
     ///
     /// ```
     /// use math_engine::vector3::Vector3;
     /// let v1 = Vector3::new(1.0,0.0,0.0);
-    /// let v2 = Vector3::new(1.0,0.0,1.0);
+    /// let v2 = Vector3::new(1.0,0.0,1.0).to_unit();
     /// let v3 = v1.project(&v2);
+    /// assert!((v3 - Vector3::new(0.5, 0.0, 0.5)).magnitude() < 1e-6);
     /// ```
-    pub fn project(&self, other: &Vector3) -> Vector3 {
-        *other * self.dot(other)
+    pub fn project(&self, other: &UnitVector3) -> Vector3 {
+        **other * self.dot(other)
     }
 
-    /// Returns the rejection of this vector from other, under
-    /// the assumption that the magnitude of other is 1.
+    /// Returns the rejection of this vector from the unit vector other.
     ///
     /// # Arguments
     ///
-    /// * `other` - A reference to a vector3.
+    /// * `other` - A reference to a unit vector3.
     ///
     /// # Examples
     ///
     /// ```
     /// use math_engine::vector3::Vector3;
     /// let v1 = Vector3::new(1.0,0.0,0.0);
-    /// let v2 = Vector3::new(1.0,0.0,1.0);
+    /// let v2 = Vector3::new(1.0,0.0,1.0).to_unit();
     /// let v3 = v1.reject(&v2);
+    /// assert!((v3 - Vector3::new(0.5, 0.0, -0.5)).magnitude() < 1e-6);
     /// ```
-    pub fn reject(&self, other: &Vector3) -> Vector3 {
-        *self - *other * self.dot(other)
+    pub fn reject(&self, other: &UnitVector3) -> Vector3 {
+        *self - **other * self.dot(other)
     }
 
-    /// Returns the result of reflecting this vector around other
+    /// Returns this vector reflected about the plane with the given normal,
+    /// v - 2 (v · n) n, as GLSL's `reflect`. A vector pointing into a surface
+    /// comes back out of it with its tangential part unchanged.
     ///
     /// # Arguments
     ///
-    /// * `other` - A reference to a vector3.
+    /// * `normal` - A reference to a unit vector3.
     ///
     /// # Examples
     ///
     /// ```
     /// use math_engine::vector3::Vector3;
-    /// let v1 = Vector3::new(1.0,0.0,0.0);
-    /// let v2 = Vector3::new(1.0,0.0,1.0);
-    /// let v3 = v1.reflect(&v2);
+    /// let n = Vector3::new(0.0, 1.0, 0.0).to_unit();
+    /// let v = Vector3::new(1.0, -2.0, 0.5).reflect(&n);
+    /// assert!(v == Vector3::new(1.0, 2.0, 0.5));
+    /// ```
+    pub fn reflect(&self, normal: &UnitVector3) -> Vector3 {
+        *self - **normal * (2.0 * self.dot(normal))
+    }
+
+    /// Returns the direction this unit vector takes after refracting through
+    /// a surface with the given normal, as GLSL's `refract`, or None on total
+    /// internal reflection. The normal should face against this vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - A reference to a unit vector3.
+    /// * `eta` - The ratio of the refractive index this vector travels in to
+    ///   the refractive index of the surface.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::vector3::Vector3;
+    /// let n = Vector3::new(0.0, 0.0, 1.0).to_unit();
+    /// let i = Vector3::new(0.8, 0.0, -0.6);
+    /// // Light entering glass bends toward the normal, by Snell's law.
+    /// let t = i.refract(&n, 1.0 / 1.5).unwrap();
+    /// assert!((t.x - 0.8 / 1.5).abs() < 1e-6 && (t.magnitude() - 1.0).abs() < 1e-6);
+    /// // Leaving it at the same angle reflects totally instead.
+    /// assert!(i.refract(&n, 1.5).is_none());
     /// ```
-    pub fn reflect(&self, other: &Vector3) -> Vector3 {
-        (*self - *other) * 2.0 * self.dot(other)
+    pub fn refract(&self, normal: &UnitVector3, eta: f32) -> Option<Vector3> {
+        let d = self.dot(normal);
+        let k = 1.0 - eta * eta * (1.0 - d * d);
+        if k < 0.0 {
+            None
+        } else {
+            Some(*self * eta - **normal * (eta * d + k.sqrt()))
+        }
+    }
+
+    /// Returns this normal if it faces against the incident vector, that is
+    /// if reference · incident is negative, and the negated normal otherwise,
+    /// as GLSL's `faceforward`.
+    ///
+    /// # Arguments
+    ///
+    /// * `incident` - The incident direction.
+    /// * `reference` - The normal whose facing is tested, usually the
+    ///   geometric normal when this is an interpolated one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::vector3::Vector3;
+    /// let n = Vector3::new(0.0, 0.0, 1.0);
+    /// let incident = Vector3::new(0.3, 0.0, 1.0);
+    /// assert!(n.faceforward(&incident, &n) == Vector3::new(0.0, 0.0, -1.0));
+    /// ```
+    pub fn faceforward(&self, incident: &Vector3, reference: &Vector3) -> Vector3 {
+        if reference.dot(incident) < 0.0 {
+            *self
+        } else {
+            -*self
+        }
     }
 
     /// Returns this vector multiplied by the inverse of it's magnitude
@@ -159,6 +250,24 @@ impl Vector3 {
         *self / self.magnitude()
     }
 
+    /// Returns this vector normalized to unit length, as a `UnitVector3`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this vector is zero, too short to normalize or not finite.
+    /// `UnitVector3::try_new_normalize` returns None instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::vector3::Vector3;
+    /// let u = Vector3::new(0.0, 0.0, 2.0).to_unit();
+    /// assert!(*u == Vector3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn to_unit(&self) -> UnitVector3 {
+        UnitVector3::new_normalize(*self)
+    }
+
     /// Multiplies this vector by the inverse of it's magnitude
     /// normalizing to unit length.
     ///
@@ -176,6 +285,116 @@ impl Vector3 {
         self.y /= m;
         self.z /= m;
     }
+
+    /// Returns this vector normalized to unit length, or None if its length
+    /// is zero or not finite, where `normalize` would return NaNs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::vector3::Vector3;
+    /// assert!(Vector3::new(0.0, 3.0, 4.0).try_normalize() == Some(Vector3::new(0.0, 0.6, 0.8)));
+    /// assert!(Vector3::default().try_normalize().is_none());
+    /// ```
+    pub fn try_normalize(&self) -> Option<Vector3> {
+        let m = self.magnitude();
+        if m > 0.0 && m.is_finite() && (1.0 / m).is_finite() {
+            Some(*self / m)
+        } else {
+            None
+        }
+    }
+
+    /// Returns this vector normalized to unit length, or the zero vector if
+    /// it cannot be normalized, see `try_normalize`.
+    pub fn normalize_or_zero(&self) -> Vector3 {
+        self.try_normalize().unwrap_or_default()
+    }
+
+    /// Returns the vector interpolated along the great arc between this
+    /// vector at t = 0 and other at t = 1, turning at constant angular speed.
+    /// The length is interpolated linearly, so unit vectors stay unit
+    /// length. Opposite vectors turn about an arbitrary perpendicular axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The vector at t = 1.
+    /// * `t` - The interpolation parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::vector3::Vector3;
+    /// let a = Vector3::new(1.0, 0.0, 0.0);
+    /// let b = Vector3::new(0.0, 1.0, 0.0);
+    /// let h = core::f32::consts::FRAC_1_SQRT_2;
+    /// assert!((a.slerp(&b, 0.5) - Vector3::new(h, h, 0.0)).magnitude() < 1e-6);
+    /// ```
+    pub fn slerp(&self, other: &Vector3, t: f32) -> Vector3 {
+        let (ma, mb) = (self.magnitude(), other.magnitude());
+        if ma == 0.0 || mb == 0.0 {
+            return self.lerp(other, t);
+        }
+        let a = *self / ma;
+        let b = *other / mb;
+        let cos = a.dot(&b);
+        let sin = a.cross(&b).magnitude();
+        let dir = if sin > 1e-6 {
+            let angle = sin.atan2(cos);
+            (a * ((1.0 - t) * angle).sin() + b * (t * angle).sin()) / sin
+        } else if cos > 0.0 {
+            a.lerp(&b, t).normalize_or_zero()
+        } else {
+            let c = a.any_orthonormal_pair().0;
+            let angle = t * core::f32::consts::PI;
+            a * angle.cos() + c * angle.sin()
+        };
+        dir * scalar::lerp(ma, mb, t)
+    }
+
+    /// Returns two unit vectors that together with this unit vector form a
+    /// right-handed orthonormal basis, so that the first crossed with the
+    /// second is this vector. It uses the branchless construction of Duff et
+    /// al., "Building an Orthonormal Basis, Revisited" (2017), which is
+    /// continuous everywhere except across the plane z = 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::vector3::Vector3;
+    /// let n = Vector3::new(1.0, 2.0, -2.0) / 3.0;
+    /// let (t, b) = n.any_orthonormal_pair();
+    /// assert!(t.dot(&n).abs() < 1e-6 && b.dot(&n).abs() < 1e-6 && t.dot(&b).abs() < 1e-6);
+    /// assert!((t.cross(&b) - n).magnitude() < 1e-6);
+    /// ```
+    pub fn any_orthonormal_pair(&self) -> (Vector3, Vector3) {
+        let sign = 1.0f32.copysign(self.z);
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            Vector3::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Vector3::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+
+    /// Returns the rotation whose columns are `any_orthonormal_pair` and
+    /// this unit vector, which maps the z axis to this vector. It takes a
+    /// direction sampled about the z axis, such as a point on a cone or
+    /// hemisphere, to the same direction about this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::vector3::Vector3;
+    /// let n = Vector3::new(0.0, -0.6, 0.8);
+    /// let m = n.orthonormal_basis();
+    /// assert!((m * Vector3::new(0.0, 0.0, 1.0) - n).magnitude() < 1e-6);
+    /// assert!((m.determinant() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn orthonormal_basis(&self) -> Matrix3 {
+        let (t, b) = self.any_orthonormal_pair();
+        Matrix3::new_with_vecs(t, b, *self)
+    }
 }
 
 impl Index<usize> for Vector3 {
@@ -247,6 +466,14 @@ impl Sub for Vector3 {
     }
 }
 
+impl Neg for Vector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
 impl SubAssign for Vector3 {
     fn sub_assign(&mut self, other: Self) {
         *self = Self {
@@ -265,6 +492,14 @@ impl Mul<f32> for Vector3 {
     }
 }
 
+impl Mul<Vector3> for f32 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Self::Output {
+        rhs * self
+    }
+}
+
 impl Mul for Vector3 {
     type Output = Self;
 
@@ -287,9 +522,7 @@ impl Mul<Matrix3> for Vector3 {
 
 impl MulAssign<Matrix3> for Vector3 {
     fn mul_assign(&mut self, other: Matrix3) {
-        self.x = other[(0, 0)] * self.x + other[(0, 1)] * self.y + other[(0, 2)] * self.z;
-        self.y = other[(1, 0)] * self.x + other[(1, 1)] * self.y + other[(1, 2)] * self.z;
-        self.z = other[(2, 0)] * self.x + other[(2, 1)] * self.y + other[(2, 2)] * self.z;
+        *self = *self * other;
     }
 }
 
@@ -319,22 +552,55 @@ impl From<Point3> for Vector3 {
     }
 }
 
-impl Distribution<Vector3> for Standard {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
-        let (x, y, z) = rng.gen();
-        Vector3 {
-           x,
-           y,
-           z,
-        }
-    }
-}
-
 #[test]
 fn add() {
     let a = Vector3::new(1.0, 2.0, 3.0);
     let b = Vector3::new(2.0, 3.0, 4.0);
-    let result = a + b;
     let want = Vector3::new(3.0, 5.0, 7.0);
     assert!((a + b) == want);
 }
+
+#[test]
+fn slerp() {
+    let a = Vector3::new(0.0, 0.0, 2.0);
+    let b = Vector3::new(0.0, 3.0, 0.0);
+    for i in 0..=8 {
+        let t = i as f32 / 8.0;
+        let v = a.slerp(&b, t);
+        assert!((v.magnitude() - (2.0 + t)).abs() < 1e-5);
+        assert!((v.y.atan2(v.z) - t * core::f32::consts::FRAC_PI_2).abs() < 1e-5);
+    }
+    let c = a.slerp(&-a, 0.5);
+    assert!(c.dot(&a).abs() < 1e-5 && (c.magnitude() - 2.0).abs() < 1e-5);
+    assert!(a.slerp(&(a * 3.0), 0.5) == a * 2.0);
+}
+
+#[test]
+fn neg_and_scalar_left() {
+    let v = Vector3::new(1.0, -2.0, 3.0);
+    assert!(-v == Vector3::new(-1.0, 2.0, -3.0));
+    assert!(2.0 * v == v * 2.0);
+    assert!(-Matrix3::identity() == Matrix3::identity() * -1.0);
+    assert!(0.5 * Matrix3::identity() == Matrix3::identity() * 0.5);
+    assert!(-Point3::new(1.0, 0.0, -1.0) == Point3::new(-1.0, 0.0, 1.0));
+    assert!(-Quarternion::IDENTITY == Quarternion::new(0.0, 0.0, 0.0, -1.0));
+    assert!(2.0 * Quarternion::IDENTITY == Quarternion::IDENTITY * 2.0);
+}
+
+#[test]
+fn cross_product() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(-2.0, 0.5, 4.0);
+    let c = a.cross(&b);
+    assert!(c == Vector3::new(6.5, -10.0, 4.5));
+    assert!(c.dot(&a) == 0.0 && c.dot(&b) == 0.0);
+}
+
+#[test]
+fn mul_assign_matrix() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+    let mut v = Vector3::new(1.0, -2.0, 3.0);
+    let want = v * m;
+    v *= m;
+    assert!(v == want);
+}
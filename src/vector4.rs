@@ -1,6 +1,14 @@
-use std::ops::{Add, Div, Index, IndexMut, Mul, MulAssign, Sub};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::prelude::*;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 #[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+#[cfg_attr(feature = "simd", repr(align(16)))]
 pub struct Vector4 {
     pub x: f32,
     pub y: f32,
@@ -9,10 +17,33 @@ pub struct Vector4 {
 }
 
 impl Vector4 {
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Vector4 {
+    /// The zero vector.
+    pub const ZERO: Vector4 = Vector4::new(0.0, 0.0, 0.0, 0.0);
+
+    /// The vector with every component 1.
+    pub const ONE: Vector4 = Vector4::new(1.0, 1.0, 1.0, 1.0);
+
+    /// The unit vector along the x axis.
+    pub const X: Vector4 = Vector4::new(1.0, 0.0, 0.0, 0.0);
+
+    /// The unit vector along the y axis.
+    pub const Y: Vector4 = Vector4::new(0.0, 1.0, 0.0, 0.0);
+
+    /// The unit vector along the z axis.
+    pub const Z: Vector4 = Vector4::new(0.0, 0.0, 1.0, 0.0);
+
+    /// The unit vector along the w axis.
+    pub const W: Vector4 = Vector4::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Vector4 {
         Self { x, y, z, w }
     }
 
+    /// Returns the vector with every component set to s.
+    pub const fn splat(s: f32) -> Vector4 {
+        Vector4::new(s, s, s, s)
+    }
+
     pub fn dot(&self, rhs: &Vector4) -> f32 {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
     }
@@ -40,6 +71,23 @@ impl Vector4 {
         self.z /= m;
         self.w /= m;
     }
+
+    /// Returns this vector normalized to unit length, or None if its length
+    /// is zero or not finite, where `normalize` would return NaNs.
+    pub fn try_normalize(&self) -> Option<Vector4> {
+        let m = self.magnitude();
+        if m > 0.0 && m.is_finite() && (1.0 / m).is_finite() {
+            Some(*self / m)
+        } else {
+            None
+        }
+    }
+
+    /// Returns this vector normalized to unit length, or the zero vector if
+    /// it cannot be normalized, see `try_normalize`.
+    pub fn normalize_or_zero(&self) -> Vector4 {
+        self.try_normalize().unwrap_or_default()
+    }
 }
 
 impl Index<usize> for Vector4 {
@@ -85,6 +133,14 @@ impl Mul<f32> for Vector4 {
     }
 }
 
+impl Mul<Vector4> for f32 {
+    type Output = Vector4;
+
+    fn mul(self, rhs: Vector4) -> Self::Output {
+        rhs * self
+    }
+}
+
 impl Mul<Vector4> for Vector4 {
     type Output = Self;
 
@@ -132,6 +188,14 @@ impl Sub<Vector4> for Vector4 {
     }
 }
 
+impl Neg for Vector4 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
 impl MulAssign<Vector4> for Vector4 {
     fn mul_assign(&mut self, rhs: Vector4) {
         self.x *= rhs.x;
@@ -149,3 +213,30 @@ impl MulAssign<f32> for Vector4 {
         self.w *= rhs;
     }
 }
+
+impl AddAssign for Vector4 {
+    fn add_assign(&mut self, rhs: Vector4) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+        self.w += rhs.w;
+    }
+}
+
+impl SubAssign for Vector4 {
+    fn sub_assign(&mut self, rhs: Vector4) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+        self.w -= rhs.w;
+    }
+}
+
+impl DivAssign<f32> for Vector4 {
+    fn div_assign(&mut self, rhs: f32) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+        self.w /= rhs;
+    }
+}
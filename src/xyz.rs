@@ -0,0 +1,145 @@
+//! The CIE 1931 XYZ color space, the device independent space the other
+//! color spaces are defined through. Y is luminance, and the white point of
+//! a viewing condition is given as the XYZ of its reference white.
+
+use crate::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A color in CIE XYZ, with Y the relative luminance, 1 for the reference
+/// white.
+#[repr(C)]
+pub struct Xyz {
+    /// The X tristimulus value.
+    pub x: f32,
+    /// The Y tristimulus value, the luminance.
+    pub y: f32,
+    /// The Z tristimulus value.
+    pub z: f32,
+}
+
+// Linear sRGB to XYZ under D65, from the sRGB primaries.
+const RGB_TO_XYZ: Matrix3 = Matrix3::new(
+    0.412_456_4,
+    0.357_576_1,
+    0.180_437_5,
+    0.212_672_9,
+    0.715_152_2,
+    0.072_175,
+    0.019_333_9,
+    0.119_192,
+    0.950_304_1,
+);
+
+const XYZ_TO_RGB: Matrix3 = Matrix3::new(
+    3.240_454_2,
+    -1.537_138_5,
+    -0.498_531_4,
+    -0.969_266,
+    1.876_010_8,
+    0.041_556,
+    0.055_643_4,
+    -0.204_025_9,
+    1.057_225_2,
+);
+
+// The Bradford cone response matrix, for chromatic adaptation.
+const BRADFORD: Matrix3 = Matrix3::new(
+    0.895_1, 0.266_4, -0.161_4, -0.750_2, 1.713_5, 0.036_7, 0.038_9, -0.068_5, 1.029_6,
+);
+
+const BRADFORD_INVERSE: Matrix3 = Matrix3::new(
+    0.986_992_9,
+    -0.147_054_3,
+    0.159_962_7,
+    0.432_305_3,
+    0.518_360_3,
+    0.049_291_2,
+    -0.008_528_7,
+    0.040_042_8,
+    0.968_486_7,
+);
+
+impl Xyz {
+    /// The CIE standard illuminant D65, average daylight, the white point of
+    /// sRGB.
+    pub const D65: Xyz = Xyz::new(0.950_47, 1.0, 1.088_83);
+    /// The CIE standard illuminant D50, horizon light, the white point of
+    /// print and of the ICC profile connection space.
+    pub const D50: Xyz = Xyz::new(0.964_22, 1.0, 0.825_21);
+
+    /// Returns a color with the given tristimulus values.
+    pub const fn new(x: f32, y: f32, z: f32) -> Xyz {
+        Xyz { x, y, z }
+    }
+
+    /// Returns the XYZ of a linear RGB color with the sRGB primaries,
+    /// adapted from its D65 white to white with the Bradford transform, so
+    /// that white RGB maps to white. Encoded sRGB values must be linearized
+    /// first.
+    ///
+    /// # Arguments
+    ///
+    /// * `rgb` - A reference to the linear color.
+    /// * `white` - A reference to the white point to express the color
+    ///   under, usually `Xyz::D65`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb::RGB;
+    /// use math_engine::xyz::Xyz;
+    /// let w = Xyz::from_rgb(&RGB::new(1.0, 1.0, 1.0), &Xyz::D50);
+    /// assert!((w.x - Xyz::D50.x).abs() < 1e-4 && (w.z - Xyz::D50.z).abs() < 1e-4);
+    /// ```
+    pub fn from_rgb(rgb: &RGB, white: &Xyz) -> Xyz {
+        let v = RGB_TO_XYZ * Vector3::new(rgb.r, rgb.g, rgb.b);
+        Xyz::new(v.x, v.y, v.z).adapt(&Xyz::D65, white)
+    }
+
+    /// Returns the linear RGB color with the sRGB primaries of this color,
+    /// which is expressed under white, the inverse of `Xyz::from_rgb`.
+    /// Colors outside the sRGB gamut have components outside [0, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `white` - A reference to the white point this color is under.
+    pub fn to_rgb(&self, white: &Xyz) -> RGB {
+        let d65 = self.adapt(white, &Xyz::D65);
+        let v = XYZ_TO_RGB * Vector3::new(d65.x, d65.y, d65.z);
+        RGB::new(v.x, v.y, v.z)
+    }
+
+    /// Returns this color, seen under the white point from, as it appears
+    /// under the white point to, using the Bradford chromatic adaptation
+    /// transform.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - A reference to the white point the color is under.
+    /// * `to` - A reference to the white point to adapt to.
+    pub fn adapt(&self, from: &Xyz, to: &Xyz) -> Xyz {
+        if from == to {
+            return *self;
+        }
+        let cone = |c: &Xyz| BRADFORD * Vector3::new(c.x, c.y, c.z);
+        let (s, d) = (cone(from), cone(to));
+        let c = cone(self);
+        let v = BRADFORD_INVERSE * Vector3::new(c.x * d.x / s.x, c.y * d.y / s.y, c.z * d.z / s.z);
+        Xyz::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<RGB> for Xyz {
+    /// Converts a linear sRGB color to XYZ under D65.
+    fn from(rgb: RGB) -> Self {
+        Xyz::from_rgb(&rgb, &Xyz::D65)
+    }
+}
+
+impl From<Xyz> for RGB {
+    /// Converts XYZ under D65 to linear sRGB.
+    fn from(c: Xyz) -> Self {
+        c.to_rgb(&Xyz::D65)
+    }
+}
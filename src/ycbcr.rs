@@ -0,0 +1,169 @@
+//! The YCbCr encoding of video, a luma and two color difference channels,
+//! as defined by ITU-R BT.601 for standard definition and BT.709 for high
+//! definition. It works on gamma encoded R'G'B', so the colors going in and
+//! out are sRGB-like encoded colors, not linear ones.
+//!
+//! All three channels are stored normalized to [0, 1] the way 8 bit video
+//! stores them divided by 255, with the chroma channels centered on 128/255.
+//! Full range uses all of [0, 1], as JPEG does. Limited range keeps luma to
+//! [16/255, 235/255] and chroma to [16/255, 240/255], as broadcast video and
+//! most video files do.
+
+use crate::prelude::*;
+
+/// The luma coefficients the encoding is based on.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum YCbCrMatrix {
+    /// ITU-R BT.601, standard definition video and JPEG.
+    Bt601,
+    /// ITU-R BT.709, high definition video.
+    #[default]
+    Bt709,
+}
+
+impl YCbCrMatrix {
+    // Returns the red and blue luma weights; green's makes up the rest.
+    fn weights(&self) -> (f32, f32) {
+        match self {
+            YCbCrMatrix::Bt601 => (0.299, 0.114),
+            YCbCrMatrix::Bt709 => (0.2126, 0.0722),
+        }
+    }
+}
+
+/// The range of values the channels are quantized to.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum YCbCrRange {
+    /// Luma and chroma span all of [0, 1].
+    Full,
+    /// Luma spans [16/255, 235/255] and chroma [16/255, 240/255], leaving
+    /// headroom and footroom as broadcast video does.
+    #[default]
+    Limited,
+}
+
+impl YCbCrRange {
+    // Returns the offset and scale of luma, then the scale of chroma.
+    fn scales(&self) -> (f32, f32, f32) {
+        match self {
+            YCbCrRange::Full => (0.0, 1.0, 1.0),
+            YCbCrRange::Limited => (16.0 / 255.0, 219.0 / 255.0, 224.0 / 255.0),
+        }
+    }
+}
+
+// Where the chroma channels are centered, 128 in 8 bit video.
+const CHROMA_OFFSET: f32 = 128.0 / 255.0;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A color in YCbCr, each channel normalized to [0, 1].
+#[repr(C)]
+pub struct YCbCr {
+    /// The luma.
+    pub y: f32,
+    /// The blue difference chroma.
+    pub cb: f32,
+    /// The red difference chroma.
+    pub cr: f32,
+}
+
+impl YCbCr {
+    /// Returns a color with the given channels.
+    pub const fn new(y: f32, cb: f32, cr: f32) -> YCbCr {
+        YCbCr { y, cb, cr }
+    }
+
+    /// Returns the YCbCr encoding of a gamma encoded RGB color.
+    ///
+    /// # Arguments
+    ///
+    /// * `rgb` - A reference to the gamma encoded color.
+    /// * `matrix` - The standard whose luma coefficients to use.
+    /// * `range` - The range to quantize to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb::RGB;
+    /// use math_engine::ycbcr::{YCbCr, YCbCrMatrix, YCbCrRange};
+    /// // Pure red in 8 bit BT.601 studio video is (81, 90, 240).
+    /// let c = YCbCr::from_rgb(&RGB::new(1.0, 0.0, 0.0), YCbCrMatrix::Bt601, YCbCrRange::Limited);
+    /// let bytes = [c.y, c.cb, c.cr].map(|v| (v * 255.0).round());
+    /// assert!(bytes == [81.0, 90.0, 240.0]);
+    /// ```
+    pub fn from_rgb(rgb: &RGB, matrix: YCbCrMatrix, range: YCbCrRange) -> YCbCr {
+        let (kr, kb) = matrix.weights();
+        let (offset, luma, chroma) = range.scales();
+        let y = kr * rgb.r + (1.0 - kr - kb) * rgb.g + kb * rgb.b;
+        let pb = (rgb.b - y) / (2.0 * (1.0 - kb));
+        let pr = (rgb.r - y) / (2.0 * (1.0 - kr));
+        YCbCr::new(
+            offset + luma * y,
+            CHROMA_OFFSET + chroma * pb,
+            CHROMA_OFFSET + chroma * pr,
+        )
+    }
+
+    /// Returns the gamma encoded RGB color of this YCbCr color, the inverse
+    /// of `YCbCr::from_rgb`. Values outside the range give components
+    /// outside [0, 1], which are left unclamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The standard whose luma coefficients the color uses.
+    /// * `range` - The range the color is quantized to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_engine::rgb::RGB;
+    /// use math_engine::ycbcr::{YCbCr, YCbCrMatrix, YCbCrRange};
+    /// let black = YCbCr::new(16.0 / 255.0, 128.0 / 255.0, 128.0 / 255.0);
+    /// let c = black.to_rgb(YCbCrMatrix::Bt709, YCbCrRange::Limited);
+    /// assert!(c.r.abs() < 1e-6 && c.g.abs() < 1e-6 && c.b.abs() < 1e-6);
+    /// ```
+    pub fn to_rgb(&self, matrix: YCbCrMatrix, range: YCbCrRange) -> RGB {
+        let (kr, kb) = matrix.weights();
+        let (offset, luma, chroma) = range.scales();
+        let y = (self.y - offset) / luma;
+        let pb = (self.cb - CHROMA_OFFSET) / chroma;
+        let pr = (self.cr - CHROMA_OFFSET) / chroma;
+        let r = y + 2.0 * (1.0 - kr) * pr;
+        let b = y + 2.0 * (1.0 - kb) * pb;
+        let g = (y - kr * r - kb * b) / (1.0 - kr - kb);
+        RGB::new(r, g, b)
+    }
+}
+
+#[test]
+fn ycbcr_round_trip() {
+    let matrices = [YCbCrMatrix::Bt601, YCbCrMatrix::Bt709];
+    let ranges = [YCbCrRange::Full, YCbCrRange::Limited];
+    for matrix in matrices {
+        for range in ranges {
+            let white = YCbCr::from_rgb(&RGB::new(1.0, 1.0, 1.0), matrix, range);
+            let top = if range == YCbCrRange::Full {
+                1.0
+            } else {
+                235.0 / 255.0
+            };
+            assert!((white.y - top).abs() < 1e-6);
+            assert!((white.cb - CHROMA_OFFSET).abs() < 1e-6);
+            assert!((white.cr - CHROMA_OFFSET).abs() < 1e-6);
+            for c in [RGB::new(0.2, 0.5, 0.9), RGB::new(1.0, 0.0, 0.3)] {
+                let back = YCbCr::from_rgb(&c, matrix, range).to_rgb(matrix, range);
+                assert!((back.r - c.r).abs() < 1e-5);
+                assert!((back.g - c.g).abs() < 1e-5);
+                assert!((back.b - c.b).abs() < 1e-5);
+            }
+        }
+    }
+    // BT.709 weights green more heavily than BT.601.
+    let green = RGB::new(0.0, 1.0, 0.0);
+    let y601 = YCbCr::from_rgb(&green, YCbCrMatrix::Bt601, YCbCrRange::Full).y;
+    let y709 = YCbCr::from_rgb(&green, YCbCrMatrix::Bt709, YCbCrRange::Full).y;
+    assert!((y601 - 0.587).abs() < 1e-6 && (y709 - 0.7152).abs() < 1e-6);
+}